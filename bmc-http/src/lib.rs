@@ -41,25 +41,36 @@
 
 pub mod cache;
 pub mod credentials;
+pub mod message_registry;
+pub mod redfish_error;
+pub mod response;
 
-#[cfg(feature = "reqwest")]
 mod schema;
+mod session;
 
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::error::Error as StdError;
 use std::fmt;
 use std::future::Future;
 use std::sync::Arc;
 use std::sync::RwLock;
 
+use crate::cache::CarCache;
 use crate::cache::TypeErasedCarCache;
+use crate::response::GetResponse;
 
+use http::header::ACCEPT_LANGUAGE;
+use http::header::IF_MATCH;
 use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
 use nv_redfish_core::query::ExpandQuery;
 use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::BoxTryStream;
 use nv_redfish_core::EntityTypeRef;
@@ -68,13 +79,24 @@ use nv_redfish_core::FilterQuery;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
 use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UpdateMethod;
 use nv_redfish_core::UploadReader;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use url::Url;
 
 #[doc(inline)]
 pub use credentials::BmcCredentials;
+#[doc(inline)]
+pub use message_registry::MessageRegistries;
+#[doc(inline)]
+pub use message_registry::MessageRegistry;
+#[doc(inline)]
+pub use redfish_error::ExtendedInfo;
+#[doc(inline)]
+pub use redfish_error::RedfishError;
 
 #[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
@@ -83,6 +105,8 @@ pub use nv_redfish_core::HttpPushUriUpdateRequest;
 #[doc(inline)]
 pub use nv_redfish_core::UploadStream;
 
+#[doc(inline)]
+pub use nv_redfish_core::MultipartRequest;
 #[doc(inline)]
 pub use nv_redfish_core::MultipartUpdateRequest;
 
@@ -101,10 +125,18 @@ pub trait HttpClient: Send + Sync {
         credentials: &BmcCredentials,
         etag: Option<ODataETag>,
         custom_headers: &HeaderMap,
-    ) -> impl Future<Output = Result<T, Self::Error>> + Send
+    ) -> impl Future<Output = Result<GetResponse<T>, Self::Error>> + Send
     where
         T: DeserializeOwned + Send + Sync;
 
+    /// Perform an HTTP HEAD request.
+    fn head(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
     /// Perform an HTTP POST request.
     fn post<B, T>(
         &self,
@@ -144,6 +176,19 @@ pub trait HttpClient: Send + Sync {
         T: DeserializeOwned + Send + Sync,
         V: Serialize + Send + Sync;
 
+    /// Performs a `multipart/form-data` upload with credentials and headers.
+    ///
+    /// Parts are sent in the order of the request.
+    fn post_multipart<T>(
+        &self,
+        url: Url,
+        request: MultipartRequest,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<ModificationResponse<T>, Self::Error>> + Send
+    where
+        T: DeserializeOwned + Send + Sync;
+
     /// Performs a deprecated `UpdateService` raw `HttpPushUri` upload with
     /// credentials and headers.
     ///
@@ -179,6 +224,7 @@ pub trait HttpClient: Send + Sync {
     fn delete<T>(
         &self,
         url: Url,
+        etag: ODataETag,
         credentials: &BmcCredentials,
         custom_headers: &HeaderMap,
     ) -> impl Future<Output = Result<ModificationResponse<T>, Self::Error>> + Send
@@ -192,6 +238,14 @@ pub trait HttpClient: Send + Sync {
         credentials: &BmcCredentials,
         custom_headers: &HeaderMap,
     ) -> impl Future<Output = Result<BoxTryStream<T, Self::Error>, Self::Error>> + Send;
+
+    /// Perform an HTTP GET request and stream the response body.
+    fn download(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error>> + Send;
 }
 
 /// HTTP-based BMC implementation that wraps an [`HttpClient`].
@@ -216,6 +270,8 @@ pub struct HttpBmc<C: HttpClient> {
     credentials: RwLock<Arc<BmcCredentials>>,
     cache: RwLock<TypeErasedCarCache<Url>>,
     etags: RwLock<HashMap<Url, ODataETag>>,
+    // Methods from `Allow` headers, bounded by the cache capacity.
+    allowed_methods: RwLock<CarCache<Url, AllowedMethods>>,
     custom_headers: HeaderMap,
 
     // Response bodies and ETags are enabled or disabled together because a
@@ -332,11 +388,23 @@ where
             credentials: RwLock::new(Arc::new(credentials)),
             cache: RwLock::new(TypeErasedCarCache::new(cache_settings.capacity)),
             etags: RwLock::new(HashMap::new()),
+            allowed_methods: RwLock::new(CarCache::new(cache_settings.capacity)),
             custom_headers,
             cache_enabled: cache_settings.capacity > 0,
         }
     }
 
+    /// Request human-readable strings, such as `Message` and
+    /// `Resolution` of Redfish errors, in `language` (for example,
+    /// `de` or `ja, en;q=0.5`). The value is sent in the
+    /// `Accept-Language` header of all requests. BMCs that don't
+    /// support the language respond in their default language.
+    #[must_use]
+    pub fn with_accept_language(mut self, language: HeaderValue) -> Self {
+        self.custom_headers.insert(ACCEPT_LANGUAGE, language);
+        self
+    }
+
     /// Replace the credentials used for subsequent requests.
     ///
     /// Existing cache and ETag state is preserved.
@@ -511,7 +579,8 @@ impl Default for CacheSettings {
 impl CacheSettings {
     /// Define capacity of the cache measured in number of items.
     ///
-    /// A capacity of 0 disables caching.
+    /// A capacity of 0 disables caching. The capacity also bounds the
+    /// number of resources whose `Allow` header is remembered.
     #[must_use]
     pub const fn with_capacity(capacity: usize) -> Self {
         Self { capacity }
@@ -568,6 +637,7 @@ where
     /// - Retrieving cached `ETag` before request
     /// - Sending conditional GET with If-None-Match
     /// - Handling 304 Not Modified responses from cache
+    /// - Following `Members@odata.nextLink` of paged collections
     /// - Updating cache and `ETag` storage on success
     #[allow(clippy::significant_drop_tightening)]
     async fn get_with_cache<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
@@ -593,7 +663,7 @@ where
         let credentials = self.read_credentials();
 
         // Perform GET request
-        match self
+        let response = match self
             .client
             .get::<T>(
                 endpoint_url,
@@ -603,15 +673,29 @@ where
             )
             .await
         {
-            Ok(response) if !self.cache_enabled => {
+            Ok(GetResponse {
+                entity,
+                etag,
+                allow,
+            }) => {
+                self.store_allowed_methods(&cache_key, allow)?;
+                self.append_next_pages(entity, &credentials)
+                    .await
+                    .map(|entity| (entity, etag))
+            }
+            Err(e) => Err(e),
+        };
+        match response {
+            Ok((response, _)) if !self.cache_enabled => {
                 // With capacity zero, `put_typed` stores no representation and always returns
                 // `None`, and we can return early with the response entity.
                 Ok(Arc::new(response))
             }
-            Ok(response) => {
+            Ok((response, header_etag)) => {
                 let entity = Arc::new(response);
-                // Update cache if entity has etag
-                if let Some(etag) = entity.etag() {
+                // Update cache if entity has etag. `ETag` header is
+                // used if the entity doesn't keep `@odata.etag`.
+                if let Some(etag) = entity.etag().cloned().or(header_etag) {
                     let mut cache = self
                         .cache
                         .write()
@@ -627,13 +711,15 @@ where
                     {
                         etags.remove(&evicted_url);
                     }
-                    etags.insert(cache_key.clone(), etag.clone());
+                    etags.insert(cache_key.clone(), etag);
                 }
                 Ok(entity)
             }
             Err(e) => {
                 // Handle 304 Not Modified - return from cache
                 if e.is_cached() {
+                    #[cfg(feature = "tracing")]
+                    tracing::trace!(url = %cache_key, "not modified, using cached entity");
                     let mut cache = self
                         .cache
                         .write()
@@ -648,6 +734,55 @@ where
             }
         }
     }
+
+    /// Remember methods from `Allow` header of GET response of
+    /// `endpoint_url`. Methods are kept for the resource regardless of
+    /// query of the request. Like cached responses, at most cache
+    /// capacity resources are remembered.
+    fn store_allowed_methods(
+        &self,
+        endpoint_url: &Url,
+        allow: Option<AllowedMethods>,
+    ) -> Result<(), C::Error> {
+        if let Some(allow) = allow {
+            let mut resource_url = endpoint_url.clone();
+            resource_url.set_query(None);
+            self.allowed_methods
+                .write()
+                .map_err(|e| C::Error::cache_error(e.to_string()))?
+                .put(resource_url, allow);
+        }
+        Ok(())
+    }
+
+    /// Fetch remaining pages of collection members linked by
+    /// `Members@odata.nextLink` and append them to `entity`.
+    async fn append_next_pages<T: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        mut entity: T,
+        credentials: &BmcCredentials,
+    ) -> Result<T, C::Error> {
+        let mut visited = HashSet::new();
+        while let Some(next) = entity.next_page_link() {
+            // Some services link the last page to itself.
+            if !visited.insert(next.clone()) {
+                break;
+            }
+            #[cfg(feature = "tracing")]
+            tracing::trace!(next_link = %next, "following Members@odata.nextLink");
+            let page = self
+                .client
+                .get::<T>(
+                    self.redfish_endpoint.with_odata_id(next),
+                    credentials,
+                    None,
+                    &self.custom_headers,
+                )
+                .await?;
+            entity.append_page(page.entity);
+        }
+        Ok(entity)
+    }
 }
 
 impl<C: HttpClient> Bmc for HttpBmc<C>
@@ -664,6 +799,51 @@ where
         self.get_with_cache(endpoint_url).await
     }
 
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        let credentials = self.read_credentials();
+        match self
+            .client
+            .get::<T>(
+                endpoint_url.clone(),
+                credentials.as_ref(),
+                Some(etag.clone()),
+                &self.custom_headers,
+            )
+            .await
+        {
+            Ok(response) => {
+                self.store_allowed_methods(&endpoint_url, response.allow)?;
+                self.append_next_pages(response.entity, &credentials)
+                    .await
+                    .map(|entity| Some(Arc::new(entity)))
+            }
+            Err(e) if e.is_cached() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn head(&self, id: &ODataId) -> Result<(), Self::Error> {
+        let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        let credentials = self.read_credentials();
+        self.client
+            .head(endpoint_url, credentials.as_ref(), &self.custom_headers)
+            .await
+    }
+
+    fn allowed_methods(&self, id: &ODataId) -> Option<AllowedMethods> {
+        let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        self.allowed_methods
+            .write()
+            .ok()?
+            .get(&endpoint_url)
+            .copied()
+    }
+
     async fn expand<T: Expandable + 'static>(
         &self,
         id: &ODataId,
@@ -724,14 +904,56 @@ where
             .await
     }
 
+    async fn update_with_method<
+        V: Sync + Send + Serialize,
+        R: Sync + Send + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        v: &V,
+        method: UpdateMethod,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        if method == UpdateMethod::Patch {
+            return self.update(id, etag, v).await;
+        }
+        let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        let etag = etag
+            .cloned()
+            .unwrap_or_else(|| ODataETag::from(String::from("*")));
+        let credentials = self.read_credentials();
+        let mut headers = self.custom_headers.clone();
+        if let Ok(etag) = HeaderValue::from_str(&etag.to_string()) {
+            headers.insert(IF_MATCH, etag);
+        }
+        if method == UpdateMethod::PostWithMethodOverride {
+            headers.insert(
+                HeaderName::from_static("x-http-method-override"),
+                HeaderValue::from_static("PATCH"),
+            );
+        }
+        self.client
+            .post(endpoint_url, v, credentials.as_ref(), &headers)
+            .await
+    }
+
     async fn delete<T: Sync + Send + for<'de> Deserialize<'de>>(
         &self,
         id: &ODataId,
+        etag: Option<&ODataETag>,
     ) -> Result<ModificationResponse<T>, Self::Error> {
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        let etag = etag
+            .cloned()
+            .unwrap_or_else(|| ODataETag::from(String::from("*")));
         let credentials = self.read_credentials();
         self.client
-            .delete(endpoint_url, credentials.as_ref(), &self.custom_headers)
+            .delete(
+                endpoint_url,
+                etag,
+                credentials.as_ref(),
+                &self.custom_headers,
+            )
             .await
     }
 
@@ -783,6 +1005,31 @@ where
             .await
     }
 
+    async fn multipart_upload<R>(
+        &self,
+        uri: &str,
+        request: MultipartRequest,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_same_origin_uri_reference(UriReference(uri))
+            .map_err(C::Error::rejected_uri_reference)?;
+
+        let credentials = self.read_credentials();
+
+        self.client
+            .post_multipart(
+                endpoint_url,
+                request,
+                credentials.as_ref(),
+                &self.custom_headers,
+            )
+            .await
+    }
+
     #[cfg(feature = "update-service-deprecated")]
     async fn http_push_uri_update<U, R>(
         &self,
@@ -822,6 +1069,53 @@ where
         self.get_with_cache(endpoint_url).await
     }
 
+    /// Pages are requested without `If-None-Match` and are not stored
+    /// in the cache.
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_odata_id_and_query(id, &query.to_query_string());
+        let credentials = self.read_credentials();
+
+        self.client
+            .get::<T>(
+                endpoint_url,
+                credentials.as_ref(),
+                None,
+                &self.custom_headers,
+            )
+            .await
+            .map(|response| Arc::new(response.entity))
+    }
+
+    /// Responses to `$select` contain only a part of the entity, so
+    /// they are requested without `If-None-Match` and are not stored
+    /// in the cache.
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: SelectQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_odata_id_and_query(id, &query.to_query_string());
+        let credentials = self.read_credentials();
+
+        self.client
+            .get::<T>(
+                endpoint_url,
+                credentials.as_ref(),
+                None,
+                &self.custom_headers,
+            )
+            .await
+            .map(|response| Arc::new(response.entity))
+    }
+
     async fn stream<T: Send + Sized + for<'de> Deserialize<'de>>(
         &self,
         uri: &str,
@@ -836,6 +1130,18 @@ where
             .sse(endpoint_url, credentials.as_ref(), &self.custom_headers)
             .await
     }
+
+    async fn download(&self, uri: &str) -> Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_same_origin_uri_reference(UriReference(uri))
+            .map_err(C::Error::rejected_uri_reference)?;
+
+        let credentials = self.read_credentials();
+        self.client
+            .download(endpoint_url, credentials.as_ref(), &self.custom_headers)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message registries.
+//!
+//! A `MessageId` has form `Prefix.Major.Minor.Key` (for example,
+//! `Base.1.8.PropertyValueNotInList`). It is resolved against the
+//! registry with the same prefix and major version; registries are
+//! backward compatible within a major version. Registries are
+//! provided by the caller, for example downloaded from the
+//! `Location` of `MessageRegistryFile` resources of the BMC or from
+//! the DMTF registry repository.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Message of a registry.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RegistryMessage {
+    /// Message text with `%1`, `%2`, ... placeholders for arguments.
+    pub message: String,
+    /// Recommended actions to resolve the condition.
+    #[serde(default)]
+    pub resolution: Option<String>,
+    /// Severity of the message.
+    #[serde(default)]
+    pub message_severity: Option<String>,
+    /// Number of arguments of the message.
+    #[serde(default)]
+    pub number_of_args: Option<usize>,
+}
+
+impl RegistryMessage {
+    /// Message text with placeholders replaced by `args`.
+    /// Placeholders without corresponding argument are kept.
+    #[must_use]
+    pub fn format<S: AsRef<str>>(&self, args: &[S]) -> String {
+        let mut result = String::with_capacity(self.message.len());
+        let mut rest = self.message.as_str();
+        while let Some(pos) = rest.find('%') {
+            result.push_str(&rest[..pos]);
+            let after = &rest[pos + 1..];
+            let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+            let arg = after[..digits]
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| n.checked_sub(1))
+                .and_then(|idx| args.get(idx));
+            if let Some(arg) = arg {
+                result.push_str(arg.as_ref());
+            } else {
+                result.push('%');
+                result.push_str(&after[..digits]);
+            }
+            rest = &after[digits..];
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+/// Message registry (`MessageRegistry` schema).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageRegistry {
+    /// Prefix of message identifiers (for example, `Base`).
+    pub registry_prefix: String,
+    /// Version of the registry (for example, `1.8.0`).
+    pub registry_version: String,
+    /// RFC 5646 language of the messages (for example, `en`).
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Messages by key.
+    pub messages: HashMap<String, RegistryMessage>,
+}
+
+impl MessageRegistry {
+    /// Parse registry JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid message registry.
+    pub fn parse(json: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(json)
+    }
+
+    fn major_version(&self) -> &str {
+        self.registry_version.split('.').next().unwrap_or_default()
+    }
+
+    fn has_language(&self, language: &str) -> bool {
+        self.language.as_deref().is_some_and(|own| {
+            primary_language(own).eq_ignore_ascii_case(primary_language(language))
+        })
+    }
+}
+
+/// Primary subtag of the language tag, for example, `de` of `de-CH`.
+fn primary_language(tag: &str) -> &str {
+    tag.split(['-', '_']).next().unwrap_or_default()
+}
+
+/// Set of message registries used to resolve message identifiers.
+#[derive(Debug, Clone, Default)]
+pub struct MessageRegistries {
+    registries: Vec<MessageRegistry>,
+    language: Option<String>,
+}
+
+impl MessageRegistries {
+    /// Create empty set of registries.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add registry to the set. Registry added later takes precedence
+    /// over registries with the same prefix and major version.
+    pub fn add(&mut self, registry: MessageRegistry) {
+        self.registries.push(registry);
+    }
+
+    /// Prefer registries in `language` (for example, the language
+    /// requested from the BMC with `Accept-Language`). Registries in
+    /// other languages are still used for messages missing in the
+    /// preferred ones.
+    #[must_use]
+    pub fn with_language(self, language: impl Into<String>) -> Self {
+        Self {
+            language: Some(language.into()),
+            ..self
+        }
+    }
+
+    /// Find message by `MessageId`.
+    #[must_use]
+    pub fn find(&self, message_id: &str) -> Option<&RegistryMessage> {
+        let mut parts = message_id.split('.');
+        let prefix = parts.next()?;
+        let major = parts.next()?;
+        let key = message_id.rsplit('.').next()?;
+        let mut candidates = self
+            .registries
+            .iter()
+            .rev()
+            .filter(|r| r.registry_prefix == prefix && r.major_version() == major);
+        self.language
+            .as_deref()
+            .and_then(|language| {
+                candidates
+                    .clone()
+                    .filter(|r| r.has_language(language))
+                    .find_map(|r| r.messages.get(key))
+            })
+            .or_else(|| candidates.find_map(|r| r.messages.get(key)))
+    }
+
+    /// Message text for `MessageId` with placeholders replaced by
+    /// `args`.
+    #[must_use]
+    pub fn resolve<S: AsRef<str>>(&self, message_id: &str, args: &[S]) -> Option<String> {
+        self.find(message_id).map(|m| m.format(args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = r##"{
+        "@odata.type": "#MessageRegistry.v1_6_0.MessageRegistry",
+        "Id": "Base.1.8.0",
+        "Language": "en",
+        "RegistryPrefix": "Base",
+        "RegistryVersion": "1.8.0",
+        "Messages": {
+            "PropertyValueNotInList": {
+                "Message": "The value %1 for the property %2 is not in the list of acceptable values.",
+                "MessageSeverity": "Warning",
+                "NumberOfArgs": 2,
+                "Resolution": "Choose a value from the enumeration list."
+            }
+        }
+    }"##;
+
+    #[test]
+    fn resolve_message_with_args() {
+        let mut registries = MessageRegistries::new();
+        registries.add(MessageRegistry::parse(BASE.as_bytes()).expect("valid registry"));
+
+        assert_eq!(
+            registries
+                .resolve("Base.1.4.PropertyValueNotInList", &["Foo", "BootSourceOverrideTarget"])
+                .as_deref(),
+            Some(
+                "The value Foo for the property BootSourceOverrideTarget is not in the list of acceptable values."
+            )
+        );
+        assert_eq!(
+            registries
+                .resolve("Base.1.8.PropertyValueNotInList", &["Foo"])
+                .as_deref(),
+            Some("The value Foo for the property %2 is not in the list of acceptable values.")
+        );
+        assert!(registries.find("Base.2.0.PropertyValueNotInList").is_none());
+        assert!(registries
+            .find("Other.1.8.PropertyValueNotInList")
+            .is_none());
+    }
+
+    #[test]
+    fn prefer_registry_in_language() {
+        let german = BASE
+            .replace(r#""Language": "en""#, r#""Language": "de""#)
+            .replace(
+                "The value %1 for the property %2 is not in the list of acceptable values.",
+                "Der Wert %1 der Eigenschaft %2 ist nicht zulässig.",
+            );
+        let mut registries = MessageRegistries::new().with_language("de-DE");
+        registries.add(MessageRegistry::parse(german.as_bytes()).expect("valid registry"));
+        registries.add(MessageRegistry::parse(BASE.as_bytes()).expect("valid registry"));
+
+        assert_eq!(
+            registries
+                .resolve("Base.1.8.PropertyValueNotInList", &["Foo", "Bar"])
+                .as_deref(),
+            Some("Der Wert Foo der Eigenschaft Bar ist nicht zulässig.")
+        );
+    }
+}
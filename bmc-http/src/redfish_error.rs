@@ -0,0 +1,230 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redfish error response body.
+
+use crate::message_registry::MessageRegistries;
+use crate::schema::redfish::redfish_error::RedfishErrorContents;
+use serde::Deserialize;
+
+/// Entry of `@Message.ExtendedInfo`.
+#[doc(inline)]
+pub use crate::schema::redfish::message::Message as ExtendedInfo;
+
+/// Contents of Redfish error response (`error` property of the
+/// `RedfishError` schema). `@Message.ExtendedInfo` is an annotation
+/// in the schema, so it is not part of the generated type.
+#[derive(Debug, Deserialize)]
+pub struct RedfishError {
+    #[serde(flatten)]
+    contents: RedfishErrorContents,
+    #[serde(rename = "@Message.ExtendedInfo", default)]
+    extended_info: Vec<ExtendedInfo>,
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    error: RedfishError,
+}
+
+impl RedfishError {
+    /// Parse response body. Returns `None` if the body is not a
+    /// Redfish error.
+    #[must_use]
+    pub fn parse(body: &str) -> Option<Self> {
+        serde_json::from_str::<Envelope>(body)
+            .ok()
+            .map(|envelope| envelope.error)
+    }
+
+    /// `MessageId` of the error.
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.contents.code
+    }
+
+    /// Human-readable error message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.contents.message
+    }
+
+    /// Messages with details of the error.
+    #[must_use]
+    pub fn extended_info(&self) -> &[ExtendedInfo] {
+        &self.extended_info
+    }
+
+    /// Message of the error code resolved with `registries`, or the
+    /// message of the response if the code is not found.
+    #[must_use]
+    pub fn resolved_message(&self, registries: &MessageRegistries) -> String {
+        registries
+            .resolve::<&str>(self.code(), &[])
+            .unwrap_or_else(|| self.message().to_string())
+    }
+
+    /// Message of the response, localized if the BMC supports the
+    /// language requested with `Accept-Language`, or the message of
+    /// the error code resolved with `registries` if the response has
+    /// no message.
+    #[must_use]
+    pub fn localized_message(&self, registries: &MessageRegistries) -> String {
+        if self.message().is_empty() {
+            self.resolved_message(registries)
+        } else {
+            self.message().to_string()
+        }
+    }
+}
+
+impl ExtendedInfo {
+    /// Message resolved with `registries` using `MessageArgs`.
+    /// Message provided by the BMC is returned if the `MessageId` is
+    /// not found.
+    #[must_use]
+    pub fn resolved_message(&self, registries: &MessageRegistries) -> Option<String> {
+        registries
+            .resolve(
+                &self.message_id,
+                self.message_args.as_deref().unwrap_or_default(),
+            )
+            .or_else(|| self.message.clone())
+    }
+
+    /// Message provided by the BMC, localized if the BMC supports the
+    /// language requested with `Accept-Language`. Message resolved
+    /// with `registries` is returned if the BMC provides no message.
+    #[must_use]
+    pub fn localized_message(&self, registries: &MessageRegistries) -> Option<String> {
+        self.message.clone().or_else(|| {
+            registries.resolve(
+                &self.message_id,
+                self.message_args.as_deref().unwrap_or_default(),
+            )
+        })
+    }
+
+    /// Resolution provided by the BMC or, if missing, by the message
+    /// registry.
+    #[must_use]
+    pub fn resolution(&self, registries: &MessageRegistries) -> Option<String> {
+        self.resolution.clone().or_else(|| {
+            registries
+                .find(&self.message_id)
+                .and_then(|m| m.resolution.clone())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message_registry::MessageRegistry;
+    use serde_json::json;
+    use serde_json::Value as JsonValue;
+
+    fn extended_info(value: JsonValue) -> ExtendedInfo {
+        serde_json::from_value(value).expect("valid extended info")
+    }
+
+    #[test]
+    fn parse_error_with_extended_info() {
+        let body = r#"{
+            "error": {
+                "code": "Base.1.8.GeneralError",
+                "message": "A general error has occurred.",
+                "@Message.ExtendedInfo": [{
+                    "MessageId": "Base.1.8.PropertyValueNotInList",
+                    "Message": "The value Foo for the property BootSourceOverrideTarget is not in the list of acceptable values.",
+                    "MessageArgs": ["Foo", "BootSourceOverrideTarget"],
+                    "MessageSeverity": "Warning",
+                    "Resolution": "Choose a value from the enumeration list."
+                }]
+            }
+        }"#;
+        let error = RedfishError::parse(body).expect("valid error body");
+        assert_eq!(error.code(), "Base.1.8.GeneralError");
+        assert_eq!(error.extended_info().len(), 1);
+        let info = &error.extended_info()[0];
+        assert_eq!(info.message_id, "Base.1.8.PropertyValueNotInList");
+        assert_eq!(
+            info.message_args.as_deref(),
+            Some(&["Foo".to_string(), "BootSourceOverrideTarget".to_string()][..])
+        );
+
+        assert!(RedfishError::parse("<html>Not Found</html>").is_none());
+    }
+
+    #[test]
+    fn resolve_extended_info_with_registry() {
+        let registry = r#"{
+            "RegistryPrefix": "Base",
+            "RegistryVersion": "1.8.0",
+            "Messages": {
+                "PropertyMissing": {
+                    "Message": "The property %1 is a required property and must be included in the request.",
+                    "Resolution": "Ensure that the property is in the request body and has a valid value."
+                }
+            }
+        }"#;
+        let mut registries = MessageRegistries::new();
+        registries.add(MessageRegistry::parse(registry.as_bytes()).expect("valid registry"));
+        let info = extended_info(json!({
+            "MessageId": "Base.1.8.PropertyMissing",
+            "MessageArgs": ["UserName"]
+        }));
+        assert_eq!(
+            info.resolved_message(&registries).as_deref(),
+            Some(
+                "The property UserName is a required property and must be included in the request."
+            )
+        );
+        assert_eq!(
+            info.resolution(&registries).as_deref(),
+            Some("Ensure that the property is in the request body and has a valid value.")
+        );
+    }
+
+    #[test]
+    fn localized_message_prefers_bmc_message() {
+        let registries = MessageRegistries::new();
+        let mut info = extended_info(json!({
+            "MessageId": "Base.1.8.PropertyMissing",
+            "Message": "Die Eigenschaft UserName fehlt.",
+            "MessageArgs": ["UserName"]
+        }));
+        assert_eq!(
+            info.localized_message(&registries).as_deref(),
+            Some("Die Eigenschaft UserName fehlt.")
+        );
+        info.message = None;
+        assert_eq!(info.localized_message(&registries), None);
+
+        let error = RedfishError::parse(
+            r#"{
+                "error": {
+                    "code": "Base.1.8.GeneralError",
+                    "message": "Ein allgemeiner Fehler ist aufgetreten."
+                }
+            }"#,
+        )
+        .expect("valid error body");
+        assert_eq!(
+            error.localized_message(&registries),
+            "Ein allgemeiner Fehler ist aufgetreten."
+        );
+    }
+}
@@ -15,20 +15,32 @@
 
 //! Implementation of [`HttpClient`] trait using reqwest crate.
 
+use std::convert::TryFrom as _;
 use std::error::Error as StdErr;
 use std::fmt;
 use std::future::ready;
 use std::sync::Arc;
 use std::time::Duration;
-
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+use crate::response;
+use crate::response::allowed_methods_from_headers;
+use crate::response::auth_token_from_headers;
+use crate::response::etag_from_headers;
+use crate::response::inject_etag;
+use crate::response::retry_after_from_headers;
+use crate::response::GetResponse;
 use crate::schema::redfish::message::Message;
-use crate::schema::redfish::redfish_error::RedfishError;
+use crate::schema::redfish::redfish_error::RedfishError as RedfishErrorSchema;
 use crate::BmcCredentials;
 use crate::CacheableError;
 use crate::HttpClient;
 #[cfg(feature = "update-service-deprecated")]
 use crate::HttpPushUriUpdateRequest;
+use crate::MultipartRequest;
 use crate::MultipartUpdateRequest;
+use crate::RedfishError;
 use crate::RejectedUriReferenceError;
 use crate::RequestError;
 
@@ -39,9 +51,11 @@ use futures_util::StreamExt as _;
 use http::header;
 use http::HeaderMap;
 use nv_redfish_core::AsyncTask;
+use nv_redfish_core::BmcErrorInfo;
 use nv_redfish_core::BoxTryStream;
 use nv_redfish_core::DataStream;
 use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartPart;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
 use nv_redfish_core::OemMultipartPart;
@@ -56,7 +70,6 @@ use reqwest::Client as ReqwestClient;
 use reqwest::Error as ReqwestError;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use tokio::time::sleep;
 use tokio::time::timeout;
 use tokio_util::compat::FuturesAsyncReadCompatExt as _;
 use tokio_util::io::ReaderStream;
@@ -77,6 +90,22 @@ pub enum BmcError {
         status: reqwest::StatusCode,
         /// Text in the response.
         text: String,
+        /// Redfish error parsed from the text of the response.
+        redfish_error: Option<Box<RedfishError>>,
+        /// Delay in seconds requested by the `Retry-After` header of
+        /// the response.
+        retry_after: Option<u16>,
+    },
+    /// Write was rejected because the entity was changed since the
+    /// `ETag` sent in `If-Match` was fetched (`412 Precondition
+    /// Failed`).
+    PreconditionFailed {
+        /// URL in request that caused error.
+        url: url::Url,
+        /// Text in the response.
+        text: String,
+        /// Redfish error parsed from the text of the response.
+        redfish_error: Option<Box<RedfishError>>,
     },
     /// SSE stream error.
     SseStreamError(sse_stream::Error),
@@ -100,6 +129,13 @@ pub enum BmcError {
         /// Idle duration that elapsed with no event.
         idle: Duration,
     },
+    /// Response body exceeded the configured maximum payload size.
+    PayloadTooLarge {
+        /// URL in request that caused error.
+        url: url::Url,
+        /// Configured byte limit that was exceeded.
+        limit: usize,
+    },
 }
 
 impl From<reqwest::Error> for BmcError {
@@ -131,16 +167,59 @@ impl RequestError for BmcError {
     }
 }
 
+impl BmcErrorInfo for BmcError {
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::ReqwestError(e) => e.status().map(|status| status.as_u16()),
+            Self::InvalidResponse { status, .. } => Some(status.as_u16()),
+            Self::PreconditionFailed { .. } => Some(412),
+            _ => None,
+        }
+    }
+
+    fn is_transport(&self) -> bool {
+        match self {
+            Self::ReqwestError(e) => e.is_connect() || e.is_timeout(),
+            Self::SseIdleTimeout { .. } => true,
+            _ => false,
+        }
+    }
+
+    fn odata_id(&self) -> Option<ODataId> {
+        match self {
+            Self::ReqwestError(e) => e.url().map(|url| ODataId::from(url.path().to_string())),
+            Self::InvalidResponse { url, .. }
+            | Self::PreconditionFailed { url, .. }
+            | Self::PayloadTooLarge { url, .. } => Some(ODataId::from(url.path().to_string())),
+            _ => None,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::InvalidResponse { retry_after, .. } => {
+                retry_after.map(|secs| Duration::from_secs(secs.into()))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for BmcError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::ReqwestError(e) => write!(f, "HTTP client error: {e:?}"),
-            Self::InvalidResponse { url, status, text } => {
+            Self::InvalidResponse {
+                url, status, text, ..
+            } => {
                 write!(
                     f,
                     "Invalid HTTP response - url: {url} status: {status} text: {text}"
                 )
             }
+            Self::PreconditionFailed { url, text, .. } => {
+                write!(f, "Precondition failed - url: {url} text: {text}")
+            }
             Self::CacheMiss => write!(f, "Resource not found in cache"),
             Self::CacheError(r) => write!(f, "Error occurred in cache {r:?}"),
             Self::JsonError(e) => write!(
@@ -161,6 +240,10 @@ impl fmt::Display for BmcError {
             Self::SseIdleTimeout { idle } => {
                 write!(f, "SSE stream idle for longer than {idle:?}")
             }
+            Self::PayloadTooLarge { url, limit } => write!(
+                f,
+                "Response payload exceeded maximum size of {limit} bytes - url: {url}"
+            ),
         }
     }
 }
@@ -178,6 +261,44 @@ impl StdErr for BmcError {
 }
 
 impl BmcError {
+    /// Redfish error from the body of unsuccessful response, if the
+    /// body is a Redfish error.
+    #[must_use]
+    pub fn redfish_error(&self) -> Option<&RedfishError> {
+        match self {
+            Self::InvalidResponse { redfish_error, .. }
+            | Self::PreconditionFailed { redfish_error, .. } => redfish_error.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Error for unsuccessful `response`.
+    async fn invalid_response(response: reqwest::Response) -> Self {
+        let url = response.url().clone();
+        let status = response.status();
+        let retry_after = retry_after_from_headers(response.headers())
+            .map(|delay| u16::try_from(delay.as_secs()).unwrap_or(u16::MAX));
+        let text = response.text().await.unwrap_or_else(|_| "<no data>".into());
+        let redfish_error = RedfishError::parse(&text).map(Box::new);
+        Self::InvalidResponse {
+            url,
+            status,
+            text,
+            redfish_error,
+            retry_after,
+        }
+    }
+
+    /// Error for `412 Precondition Failed` response with body `text`.
+    fn precondition_failed(url: url::Url, text: String) -> Self {
+        let redfish_error = RedfishError::parse(&text).map(Box::new);
+        Self::PreconditionFailed {
+            url,
+            text,
+            redfish_error,
+        }
+    }
+
     /// Returns `true` if this error should terminate an SSE stream.
     ///
     /// A JSON decode error is scoped to a single event and is therefore
@@ -297,89 +418,42 @@ fn event_to_item<T: DeserializeOwned>(
     }
 }
 
-/// Classifier deciding whether a response should be retried.
-///
-/// Receives the request and the response, returns `true` if the request
-/// should be retried.
-type RetryClassifier =
-    dyn Fn(&reqwest::Request, &reqwest::Response) -> bool + Send + Sync + 'static;
+/// Hook that is called with every request before it is sent.
+type BeforeRequestHook = dyn Fn(&mut reqwest::Request) + Send + Sync + 'static;
 
-/// Retry policy with a configurable delay between attempts.
+/// Hook that may modify every request before it is sent, for example,
+/// to inject custom headers.
 ///
-/// While retries remain, the classifier is called for every received HTTP
-/// response, regardless of the request method, and decides whether to retry.
-/// Transport and connection errors are returned immediately. Requests with
-/// non-clonable (streaming) bodies, such as multipart uploads, are sent exactly
-/// once and never retried.
+/// Hooks that observe requests independently of the transport belong
+/// to the `Interceptors` layer of `nv-redfish`.
 ///
 /// # Examples
 ///
-/// Retry only `GET` requests that return `503 Service Unavailable`. The
-/// classifier returns `false` for every other method, so requests such as
-/// `POST`, `PATCH`, or `DELETE` are never retried:
-///
 /// ```rust
-/// use nv_redfish_bmc_http::reqwest::{ClientParams, RetryPolicy};
-/// use std::time::Duration;
-///
-/// let policy = RetryPolicy::new(|request, response| {
-///     *request.method() == http::Method::GET
-///         && response.status() == http::StatusCode::SERVICE_UNAVAILABLE
-/// })
-/// .max_retries(3)
-/// .delay(Duration::from_millis(500));
+/// use nv_redfish_bmc_http::reqwest::ClientParams;
 ///
-/// let params = ClientParams::new().retry(policy);
+/// let params = ClientParams::new().before_request(|request| {
+///     request
+///         .headers_mut()
+///         .insert("X-Request-Source", http::HeaderValue::from_static("inventory"));
+/// });
 /// ```
 #[derive(Clone)]
-pub struct RetryPolicy {
-    /// Number of extra attempts after the first one.
-    max_retries: u32,
-    /// Fixed sleep between attempts; `None` retries immediately.
-    delay: Option<Duration>,
-    /// Decides whether a response should be retried.
-    classifier: Arc<RetryClassifier>,
-}
+pub struct BeforeRequest(Arc<BeforeRequestHook>);
 
-impl RetryPolicy {
-    /// Creates a policy that retries responses accepted by `classifier`.
-    ///
-    /// By default no retries are performed; configure them with
-    /// [`Self::max_retries`] and [`Self::delay`].
-    #[must_use]
-    pub fn new<F>(classifier: F) -> Self
+impl BeforeRequest {
+    /// Creates hook from function `hook`.
+    pub fn new<F>(hook: F) -> Self
     where
-        F: Fn(&reqwest::Request, &reqwest::Response) -> bool + Send + Sync + 'static,
+        F: Fn(&mut reqwest::Request) + Send + Sync + 'static,
     {
-        Self {
-            max_retries: 0,
-            delay: None,
-            classifier: Arc::new(classifier),
-        }
-    }
-
-    /// Maximum number of extra attempts after the initial request.
-    #[must_use]
-    pub const fn max_retries(mut self, max_retries: u32) -> Self {
-        self.max_retries = max_retries;
-        self
-    }
-
-    /// Fixed delay to sleep between attempts.
-    #[must_use]
-    pub const fn delay(mut self, delay: Duration) -> Self {
-        self.delay = Some(delay);
-        self
+        Self(Arc::new(hook))
     }
 }
 
-impl fmt::Debug for RetryPolicy {
+impl fmt::Debug for BeforeRequest {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("RetryPolicy")
-            .field("max_retries", &self.max_retries)
-            .field("delay", &self.delay)
-            .field("classifier", &"<closure>")
-            .finish()
+        f.write_str("BeforeRequest")
     }
 }
 
@@ -426,10 +500,16 @@ pub struct ClientParams {
     pub default_headers: Option<HeaderMap>,
     /// Forces use of rust TLS, enabled by default
     pub use_rust_tls: bool,
-    /// Retry policy for received responses, `None` disables retries
-    pub retry: Option<RetryPolicy>,
+    /// Maximum size of a response body, `None` disables the limit.
+    ///
+    /// Responses with a larger body, such as huge expanded collections
+    /// or metric reports, fail with [`BmcError::PayloadTooLarge`]
+    /// before the whole body is buffered.
+    pub max_payload_bytes: Option<usize>,
     /// SSE-specific limits applied by [`Client::sse`].
     pub sse: SseOptions,
+    /// Hooks called with every request before it is sent.
+    pub before_request: Vec<BeforeRequest>,
 }
 
 /// Limits applied to Server-Sent Event streams opened by [`Client::sse`].
@@ -466,8 +546,9 @@ impl Default for ClientParams {
             pool_max_idle_per_host: Some(1),
             default_headers: None,
             use_rust_tls: true,
-            retry: None,
+            max_payload_bytes: None,
             sse: SseOptions::default(),
+            before_request: Vec::new(),
         }
     }
 }
@@ -549,10 +630,25 @@ impl ClientParams {
         self
     }
 
-    /// Sets the [`RetryPolicy`] applied to every request.
+    /// Sets the maximum size of a response body.
+    ///
+    /// See [`Self::max_payload_bytes`].
     #[must_use]
-    pub fn retry(mut self, retry: RetryPolicy) -> Self {
-        self.retry = Some(retry);
+    pub const fn max_payload_bytes(mut self, bytes: usize) -> Self {
+        self.max_payload_bytes = Some(bytes);
+        self
+    }
+
+    /// Adds a hook that is called with every request before it is
+    /// sent.
+    ///
+    /// See [`BeforeRequest`].
+    #[must_use]
+    pub fn before_request<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&mut reqwest::Request) + Send + Sync + 'static,
+    {
+        self.before_request.push(BeforeRequest::new(hook));
         self
     }
 
@@ -583,8 +679,9 @@ impl ClientParams {
 #[derive(Clone)]
 pub struct Client {
     inner: ReqwestClient,
-    retry: Option<RetryPolicy>,
+    max_payload_bytes: Option<usize>,
     sse: SseOptions,
+    before_request: Vec<BeforeRequest>,
 }
 
 impl Client {
@@ -655,8 +752,9 @@ impl Client {
 
         Ok(Self {
             inner: builder.build()?,
-            retry: params.retry,
+            max_payload_bytes: params.max_payload_bytes,
             sse: params.sse,
+            before_request: params.before_request,
         })
     }
 
@@ -673,68 +771,122 @@ impl Client {
     pub fn with_client(client: ReqwestClient) -> Self {
         Self {
             inner: client,
-            retry: None,
+            max_payload_bytes: None,
             sse: SseOptions::default(),
+            before_request: Vec::new(),
         }
     }
 }
 
 impl Client {
-    /// Sends the request, retrying according to the configured [`RetryPolicy`].
-    ///
-    /// Transport errors are returned immediately. Requests with streaming
-    /// bodies cannot be cloned and are sent exactly once.
-    async fn send(&self, request: reqwest::Request) -> Result<reqwest::Response, BmcError> {
-        let Some(policy) = &self.retry else {
-            return Ok(self.inner.execute(request).await?);
-        };
-
-        let mut attempt: u32 = 0;
-        let mut current = request;
-        loop {
-            let is_last = attempt >= policy.max_retries;
-            // try_clone() returns None for streaming bodies, which therefore
-            // get a single attempt.
-            let next = if is_last { None } else { current.try_clone() };
-            let response = self.inner.execute(current).await?;
-            match next {
-                // The clone is identical to the request just sent, so the
-                // classifier sees what went over the wire.
-                Some(next_request) if (policy.classifier)(&next_request, &response) => {
-                    if let Some(delay) = policy.delay {
-                        sleep(delay).await;
-                    }
-                    current = next_request;
-                    attempt += 1;
-                }
-                _ => return Ok(response),
-            }
+    /// Executes a single request. [`BeforeRequest`] hooks are called
+    /// for every executed request.
+    async fn execute(
+        &self,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response, ReqwestError> {
+        for hook in &self.before_request {
+            (hook.0)(&mut request);
         }
+        #[cfg(feature = "tracing")]
+        let (method, url, started) = (
+            request.method().clone(),
+            request.url().clone(),
+            Instant::now(),
+        );
+        let result = self.inner.execute(request).await;
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(response) => tracing::debug!(
+                method = %method,
+                odata_id = url.path(),
+                status = response.status().as_u16(),
+                elapsed = ?started.elapsed(),
+                "request completed"
+            ),
+            Err(err) => tracing::debug!(
+                method = %method,
+                odata_id = url.path(),
+                elapsed = ?started.elapsed(),
+                error = %err,
+                "request failed"
+            ),
+        }
+        result
+    }
+
+    /// Sends the request.
+    async fn send(&self, request: reqwest::Request) -> Result<reqwest::Response, BmcError> {
+        Ok(self.execute(request).await?)
     }
 
-    async fn handle_response<T>(&self, response: reqwest::Response) -> Result<T, BmcError>
+    async fn handle_response<T>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<GetResponse<T>, BmcError>
     where
         T: DeserializeOwned,
     {
         if !response.status().is_success() {
-            return Err(BmcError::InvalidResponse {
-                url: response.url().clone(),
-                status: response.status(),
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(BmcError::invalid_response(response).await);
         }
 
-        let headers = response.headers().clone();
+        let etag_header = etag_from_headers(response.headers());
+        let allow = allowed_methods_from_headers(response.headers());
+        let bytes = self.read_body(response).await?;
+
+        let Some(etag) = etag_header else {
+            // Deserialize directly from the body without building
+            // intermediate JSON tree. It matters for large payloads.
+            return serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(
+                &bytes,
+            ))
+            .map(|entity| GetResponse {
+                entity,
+                etag: None,
+                allow,
+            })
+            .map_err(BmcError::JsonError);
+        };
 
-        let etag_header = etag_from_headers(&headers);
+        let mut value: serde_json::Value =
+            serde_json::from_slice(&bytes).map_err(BmcError::DecodeError)?;
+        inject_etag(&etag, &mut value);
 
-        let mut value: serde_json::Value = response.json().await.map_err(BmcError::ReqwestError)?;
+        serde_path_to_error::deserialize(value)
+            .map(|entity| GetResponse {
+                entity,
+                etag: Some(etag),
+                allow,
+            })
+            .map_err(BmcError::JsonError)
+    }
 
-        if let Some(etag) = etag_header {
-            inject_etag(&etag, &mut value);
+    /// Read body of the response. If the maximum payload size is
+    /// configured, reading is stopped as soon as the limit is
+    /// exceeded.
+    async fn read_body(&self, mut response: reqwest::Response) -> Result<Bytes, BmcError> {
+        let Some(limit) = self.max_payload_bytes else {
+            return response.bytes().await.map_err(BmcError::ReqwestError);
+        };
+        let too_large = |url: &url::Url| BmcError::PayloadTooLarge {
+            url: url.clone(),
+            limit,
+        };
+        if response
+            .content_length()
+            .is_some_and(|len| usize::try_from(len).unwrap_or(usize::MAX) > limit)
+        {
+            return Err(too_large(response.url()));
         }
-
-        serde_path_to_error::deserialize(value).map_err(BmcError::JsonError)
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(BmcError::ReqwestError)? {
+            if body.len() + chunk.len() > limit {
+                return Err(too_large(response.url()));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body.into())
     }
 
     async fn handle_modification_response<T>(
@@ -747,12 +899,12 @@ impl Client {
         let status = response.status();
         let url = response.url().clone();
         let headers = response.headers().clone();
+        if status == reqwest::StatusCode::PRECONDITION_FAILED {
+            let text = response.text().await.unwrap_or_else(|_| "<no data>".into());
+            return Err(BmcError::precondition_failed(url, text));
+        }
         if !status.is_success() {
-            return Err(BmcError::InvalidResponse {
-                url,
-                status,
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(BmcError::invalid_response(response).await);
         }
 
         let etag = etag_from_headers(&headers);
@@ -771,6 +923,8 @@ impl Client {
                         url,
                         status,
                         text: String::from("202 Accepted without Location header"),
+                        redfish_error: None,
+                        retry_after: None,
                     });
                 };
 
@@ -780,7 +934,7 @@ impl Client {
                 }))
             }
             reqwest::StatusCode::OK | reqwest::StatusCode::CREATED => {
-                let bytes = response.bytes().await.map_err(BmcError::ReqwestError)?;
+                let bytes = self.read_body(response).await?;
                 if !bytes.is_empty() {
                     let value: serde_json::Value =
                         serde_json::from_slice(&bytes).map_err(BmcError::DecodeError)?;
@@ -827,6 +981,8 @@ impl Client {
                 url,
                 status,
                 text: format!("Unexpected successful status code: {status}"),
+                redfish_error: None,
+                retry_after: None,
             }),
         }
     }
@@ -842,11 +998,7 @@ impl Client {
         let url = response.url().clone();
         let headers = response.headers().clone();
         if !status.is_success() {
-            return Err(BmcError::InvalidResponse {
-                url,
-                status,
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(BmcError::invalid_response(response).await);
         }
 
         let Some(auth_token) = auth_token_from_headers(&headers) else {
@@ -854,6 +1006,8 @@ impl Client {
                 url,
                 status,
                 text: String::from("session creation response missing X-Auth-Token header"),
+                redfish_error: None,
+                retry_after: None,
             });
         };
 
@@ -864,18 +1018,22 @@ impl Client {
                 url,
                 status,
                 text: String::from("session creation response missing Location header"),
+                redfish_error: None,
+                retry_after: None,
             });
         };
 
         match status {
             reqwest::StatusCode::OK | reqwest::StatusCode::CREATED => {
                 let etag = etag_from_headers(&headers);
-                let bytes = response.bytes().await.map_err(BmcError::ReqwestError)?;
+                let bytes = self.read_body(response).await?;
                 if bytes.is_empty() {
                     return Err(BmcError::InvalidResponse {
                         url,
                         status,
                         text: String::from("session creation response missing entity body"),
+                        redfish_error: None,
+                        retry_after: None,
                     });
                 }
 
@@ -897,16 +1055,22 @@ impl Client {
                 url,
                 status,
                 text: String::from("session creation returned 202 Accepted without session entity"),
+                redfish_error: None,
+                retry_after: None,
             }),
             reqwest::StatusCode::NO_CONTENT => Err(BmcError::InvalidResponse {
                 url,
                 status,
                 text: String::from("session creation returned 204 No Content"),
+                redfish_error: None,
+                retry_after: None,
             }),
             _ => Err(BmcError::InvalidResponse {
                 url,
                 status,
                 text: format!("Unexpected successful status code for session creation: {status}"),
+                redfish_error: None,
+                retry_after: None,
             }),
         }
     }
@@ -927,100 +1091,22 @@ fn same_origin_redirect_policy(redirect_policy: RedirectPolicy) -> RedirectPolic
     })
 }
 
-/// Resolve a Redfish `Location` header into a same-origin path and query.
-///
-/// HTTP defines `Location` as a URI reference, so values may be absolute,
-/// root-relative, path-relative, or query-only. Resolution must use the final
-/// response URL; treating a relative value as a path rooted at the configured
-/// BMC endpoint can target a different resource. The returned `ODataId` keeps
-/// only path and query because fragments are not sent in subsequent HTTP
-/// requests and transport always uses the configured BMC origin.
+/// Resolve a Redfish `Location` header into a same-origin path and
+/// query. See [`crate::response::location_from_headers`].
 fn location_from_headers(
     headers: &HeaderMap,
     response_url: &Url,
     status: reqwest::StatusCode,
 ) -> Result<Option<ODataId>, BmcError> {
-    let invalid_response = |text: &'static str| BmcError::InvalidResponse {
-        url: response_url.clone(),
-        status,
-        text: text.to_string(),
-    };
-
-    let Some(value) = headers.get(header::LOCATION) else {
-        return Ok(None);
-    };
-
-    let raw = value
-        .to_str()
-        .map_err(|_| invalid_response("Location header is not valid text"))?;
-
-    let raw = raw.trim();
-
-    // Joining either value would resolve back to the response resource, which
-    // cannot identify a newly created session or asynchronous task monitor.
-    if raw.is_empty() || raw.starts_with('#') {
-        return Err(invalid_response(
-            "Location header does not identify a resource",
-        ));
-    }
-
-    let resolved = response_url
-        .join(raw)
-        .map_err(|_| invalid_response("Location header is not a valid URI reference"))?;
-
-    // Redfish follow-up requests carry BMC credentials. Reject another origin
-    // before reducing the URL to an OData path and losing that distinction.
-    if resolved.origin() != response_url.origin() {
-        return Err(invalid_response(
-            "Location header resolves to a different origin",
-        ));
-    }
-
-    let mut path_and_query = resolved.path().to_string();
-
-    // Preserve the query separately from the path so later polling or deletion
-    // sends it as a query instead of percent-encoded path text.
-    if let Some(query) = resolved.query() {
-        path_and_query.push('?');
-        path_and_query.push_str(query);
-    }
-
-    Ok(Some(path_and_query.into()))
-}
-
-fn auth_token_from_headers(headers: &HeaderMap) -> Option<String> {
-    headers
-        .get("x-auth-token")
-        .and_then(|value| value.to_str().ok())
-        .map(ToString::to_string)
-}
-
-fn etag_from_headers(headers: &HeaderMap) -> Option<ODataETag> {
-    headers
-        .get(header::ETAG)
-        .and_then(|value| value.to_str().ok())
-        .map(|v| v.to_string().into())
-}
-
-fn retry_after_from_headers(headers: &HeaderMap) -> Option<Duration> {
-    headers
-        .get(header::RETRY_AFTER)
-        .and_then(|value| value.to_str().ok())
-        // RFC 9110 defines the numeric Retry-After form as delay-seconds.
-        // This helper handles that form and leaves HTTP-date support out of scope.
-        .and_then(|v| v.trim().parse::<u64>().ok())
-        .map(Duration::from_secs)
-}
-
-fn inject_etag(etag: &ODataETag, body: &mut serde_json::Value) {
-    if let Some(obj) = body.as_object_mut() {
-        let etag_value = serde_json::Value::String(etag.to_string());
-
-        // Handles both absent and null values
-        obj.entry("@odata.etag")
-            .and_modify(|v| *v = etag_value.clone())
-            .or_insert(etag_value);
-    }
+    response::location_from_headers(headers, response_url).map_err(|text| {
+        BmcError::InvalidResponse {
+            url: response_url.clone(),
+            status,
+            text: text.to_string(),
+            redfish_error: None,
+            retry_after: None,
+        }
+    })
 }
 
 /// DSP0266 7.11, Table 10 allows actions without response bodies to return
@@ -1038,7 +1124,7 @@ fn is_redfish_success_response(value: &serde_json::Value) -> bool {
         return true;
     }
 
-    let Ok(response) = <RedfishError as serde::Deserialize>::deserialize(value) else {
+    let Ok(response) = <RedfishErrorSchema as serde::Deserialize>::deserialize(value) else {
         return false;
     };
 
@@ -1069,7 +1155,7 @@ impl HttpClient for Client {
         credentials: &BmcCredentials,
         etag: Option<ODataETag>,
         custom_headers: &HeaderMap,
-    ) -> Result<T, Self::Error>
+    ) -> Result<GetResponse<T>, Self::Error>
     where
         T: DeserializeOwned,
     {
@@ -1084,6 +1170,28 @@ impl HttpClient for Client {
         self.handle_response(response).await
     }
 
+    async fn head(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<(), Self::Error> {
+        let request =
+            auth_headers(self.inner.head(url), credentials).headers(custom_headers.clone());
+
+        let response = self.send(request.build()?).await?;
+        if !response.status().is_success() {
+            return Err(BmcError::InvalidResponse {
+                url: response.url().clone(),
+                status: response.status(),
+                text: "<no data>".into(),
+                redfish_error: None,
+                retry_after: None,
+            });
+        }
+        Ok(())
+    }
+
     async fn post<B, T>(
         &self,
         url: Url,
@@ -1147,14 +1255,16 @@ impl HttpClient for Client {
     async fn delete<T>(
         &self,
         url: Url,
+        etag: ODataETag,
         credentials: &BmcCredentials,
         custom_headers: &HeaderMap,
     ) -> Result<ModificationResponse<T>, Self::Error>
     where
         T: DeserializeOwned + Send + Sync,
     {
-        let request =
-            auth_headers(self.inner.delete(url), credentials).headers(custom_headers.clone());
+        let request = auth_headers(self.inner.delete(url), credentials)
+            .headers(custom_headers.clone())
+            .header(header::IF_MATCH, etag.to_string());
 
         let response = self.send(request.build()?).await?;
         self.handle_modification_response(response).await
@@ -1210,6 +1320,36 @@ impl HttpClient for Client {
         self.handle_modification_response(response).await
     }
 
+    async fn post_multipart<T>(
+        &self,
+        url: Url,
+        multipart_request: MultipartRequest,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        T: DeserializeOwned + Send + Sync,
+    {
+        let MultipartRequest {
+            parts,
+            upload_timeout,
+        } = multipart_request;
+
+        let mut form = Form::new();
+        for part in parts {
+            let (name, part) = build_part(part)?;
+            form = form.part(name, part);
+        }
+
+        let request = auth_headers(self.inner.post(url), credentials)
+            .headers(custom_headers.clone())
+            .multipart(form)
+            .timeout(upload_timeout);
+
+        let response = self.send(request.build()?).await?;
+        self.handle_modification_response(response).await
+    }
+
     #[cfg(feature = "update-service-deprecated")]
     async fn post_http_push_uri_update<U, T>(
         &self,
@@ -1266,11 +1406,7 @@ impl HttpClient for Client {
         let response = self.send(request.build()?).await?;
 
         if !response.status().is_success() {
-            return Err(BmcError::InvalidResponse {
-                url: response.url().clone(),
-                status: response.status(),
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(BmcError::invalid_response(response).await);
         }
 
         let capped = cap_event_bytes(response.bytes_stream(), self.sse.max_event_bytes);
@@ -1308,6 +1444,31 @@ impl HttpClient for Client {
 
         Ok(Box::pin(guarded))
     }
+
+    /// The client timeout applies to the whole download, including
+    /// reading of the body.
+    async fn download(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        let request = auth_headers(self.inner.get(url), credentials)
+            .headers(custom_headers.clone())
+            .header(header::ACCEPT, "*/*");
+
+        let response = self.send(request.build()?).await?;
+
+        if !response.status().is_success() {
+            return Err(BmcError::invalid_response(response).await);
+        }
+
+        Ok(Box::pin(response.bytes_stream().map(|chunk| {
+            chunk
+                .map(|bytes| bytes.to_vec())
+                .map_err(BmcError::ReqwestError)
+        })))
+    }
 }
 
 fn build_update_parameters_part<V>(update_parameters: &V) -> Result<Part, BmcError>
@@ -1362,6 +1523,33 @@ fn build_oem_part(part: OemMultipartPart) -> Result<(String, Part), BmcError> {
     Ok((name, part))
 }
 
+fn build_part(part: MultipartPart) -> Result<(String, Part), BmcError> {
+    let MultipartPart {
+        name,
+        reader,
+        file_name,
+        content_type,
+        content_length,
+    } = part;
+
+    let body = reqwest::Body::wrap_stream(ReaderStream::new(reader.compat()));
+
+    let mut part = match content_length {
+        Some(length) => Part::stream_with_length(body, length),
+        None => Part::stream(body),
+    };
+
+    if let Some(file_name) = file_name {
+        part = part.file_name(file_name);
+    }
+
+    if let Some(content_type) = content_type {
+        part = part.mime_str(&content_type)?;
+    }
+
+    Ok((name, part))
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error as StdError;
@@ -1428,6 +1616,8 @@ mod tests {
             url: "http://example.com/redfish/v1".parse().unwrap(),
             status: mock_response.status(),
             text: "".into(),
+            redfish_error: None,
+            retry_after: None,
         };
         assert!(error.is_cached());
 
@@ -1500,14 +1690,15 @@ mod tests {
         let client = Client::with_params(params)?;
         let (credentials, headers) = session_auth();
 
-        let response: serde_json::Value = client
-            .get(
+        let response = client
+            .get::<serde_json::Value>(
                 Url::parse(&format!("{}{source_path}", mock_server.uri()))?,
                 &credentials,
                 None,
                 &headers,
             )
-            .await?;
+            .await?
+            .entity;
 
         assert_eq!(response["redirected"], true);
         mock_server.verify().await;
@@ -1587,116 +1778,62 @@ mod tests {
         Ok(())
     }
 
-    /// Retry policy used in tests: retries GET requests on 503 responses.
-    fn test_retry_policy(max_retries: u32, delay: Option<Duration>) -> RetryPolicy {
-        let policy = RetryPolicy::new(|request, response| {
-            *request.method() == http::Method::GET
-                && response.status() == http::StatusCode::SERVICE_UNAVAILABLE
-        })
-        .max_retries(max_retries);
-        match delay {
-            Some(delay) => policy.delay(delay),
-            None => policy,
-        }
-    }
-
-    /// Mounts mocks that respond with `unavailable` 503s followed by a 200.
-    async fn mount_unavailable_then_ok(
-        mock_server: &MockServer,
-        resource_path: &str,
-        unavailable: u64,
-    ) {
-        Mock::given(method("GET"))
-            .and(path(resource_path))
-            .respond_with(ResponseTemplate::new(503))
-            .up_to_n_times(unavailable)
-            .expect(unavailable)
-            .mount(mock_server)
-            .await;
+    #[tokio::test]
+    async fn test_retry_after_is_reported() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1/TaskService/TaskMonitors/1";
 
         Mock::given(method("GET"))
             .and(path(resource_path))
-            .respond_with(
-                ResponseTemplate::new(200)
-                    .set_body_json(serde_json::json!({ "@odata.id": resource_path })),
-            )
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "1"))
             .expect(1)
-            .mount(mock_server)
+            .mount(&mock_server)
             .await;
-    }
 
-    #[tokio::test]
-    async fn test_get_is_retried_on_retryable_status() -> Result<(), Box<dyn StdError>> {
-        let mock_server = MockServer::start().await;
-        let resource_path = "/redfish/v1";
-        mount_unavailable_then_ok(&mock_server, resource_path, 2).await;
-
-        let client = Client::with_params(ClientParams::new().retry(test_retry_policy(2, None)))?;
+        let client = Client::new()?;
         let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
 
-        let response: serde_json::Value = client
-            .get(
+        let error = client
+            .get::<serde_json::Value>(
                 Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
                 &credentials,
                 None,
                 &HeaderMap::new(),
             )
-            .await?;
+            .await
+            .err()
+            .ok_or("503 must fail")?;
 
-        assert_eq!(response["@odata.id"], resource_path);
+        assert_eq!(error.status_code(), Some(503));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(1)));
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_post_is_not_retried() -> Result<(), Box<dyn StdError>> {
+    async fn test_before_request_hooks_modify_requests() -> Result<(), Box<dyn StdError>> {
         let mock_server = MockServer::start().await;
-        let resource_path = "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset";
+        let resource_path = "/redfish/v1/Systems";
 
-        // The policy retries 503s, but only for GET requests. A POST returning
-        // 503 must therefore reach the server exactly once.
-        Mock::given(method("POST"))
+        Mock::given(method("GET"))
             .and(path(resource_path))
-            .respond_with(ResponseTemplate::new(503))
+            .and(header("X-Request-Source", "test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "@odata.id": resource_path,
+            })))
             .expect(1)
             .mount(&mock_server)
             .await;
 
-        let client = Client::with_params(ClientParams::new().retry(test_retry_policy(3, None)))?;
+        let client = Client::with_params(ClientParams::new().before_request(|request| {
+            request
+                .headers_mut()
+                .insert("X-Request-Source", HeaderValue::from_static("test"));
+        }))?;
         let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
 
-        let response = client
-            .post::<_, serde_json::Value>(
-                Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
-                &serde_json::json!({ "ResetType": "ForceRestart" }),
-                &credentials,
-                &HeaderMap::new(),
-            )
-            .await;
-
-        assert!(matches!(
-            response,
-            Err(BmcError::InvalidResponse { status, .. })
-                if status == reqwest::StatusCode::SERVICE_UNAVAILABLE
-        ));
-
-        Ok(())
-    }
-
-    #[tokio::test]
-    async fn test_retry_delay_is_observed() -> Result<(), Box<dyn StdError>> {
-        let mock_server = MockServer::start().await;
-        let resource_path = "/redfish/v1";
-        mount_unavailable_then_ok(&mock_server, resource_path, 2).await;
-
-        let delay = Duration::from_millis(100);
-        let client =
-            Client::with_params(ClientParams::new().retry(test_retry_policy(2, Some(delay))))?;
-        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
-
-        let started = std::time::Instant::now();
-        let response: serde_json::Value = client
-            .get(
+        client
+            .get::<serde_json::Value>(
                 Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
                 &credentials,
                 None,
@@ -1704,67 +1841,6 @@ mod tests {
             )
             .await?;
 
-        // Two retries mean two sleeps; only assert the lower bound to keep
-        // the test robust on slow CI machines.
-        assert!(started.elapsed() >= Duration::from_millis(180));
-        assert_eq!(response["@odata.id"], resource_path);
-
-        Ok(())
-    }
-
-    #[tokio::test]
-    async fn test_streaming_body_is_not_retried() -> Result<(), Box<dyn StdError>> {
-        let mock_server = MockServer::start().await;
-        let upload_path = "/redfish/v1/UpdateService/update-multipart";
-
-        // Exactly one request must reach the server even though the policy
-        // retries every 503: try_clone() returns None for streaming bodies,
-        // which therefore get a single attempt.
-        Mock::given(method("POST"))
-            .and(path(upload_path))
-            .respond_with(ResponseTemplate::new(503))
-            .expect(1)
-            .mount(&mock_server)
-            .await;
-
-        let policy = RetryPolicy::new(|_request, response| {
-            response.status() == http::StatusCode::SERVICE_UNAVAILABLE
-        })
-        .max_retries(3);
-        let client = Client::with_params(ClientParams::new().retry(policy))?;
-        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
-
-        let params = MultipartParameters {
-            force_update: true,
-            targets: vec!["/redfish/v1/Systems/1".to_string()],
-        };
-
-        let update_stream =
-            DataStream::new("firmware.bin", Cursor::new(b"firmware-bytes".to_vec()))
-                .with_content_length(14);
-
-        let update_request = MultipartUpdateRequest {
-            update_parameters: &params,
-            update_stream,
-            oem_parts: vec![],
-            upload_timeout: Duration::from_secs(600),
-        };
-
-        let response = client
-            .post_multipart_update::<_, _, serde_json::Value>(
-                Url::parse(&format!("{}{upload_path}", mock_server.uri()))?,
-                update_request,
-                &credentials,
-                &HeaderMap::new(),
-            )
-            .await;
-
-        assert!(matches!(
-            response,
-            Err(BmcError::InvalidResponse { status, .. })
-                if status == reqwest::StatusCode::SERVICE_UNAVAILABLE
-        ));
-
         Ok(())
     }
 
@@ -1905,6 +1981,56 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_multipart_sends_parts_in_order() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let upload_path = "/redfish/v1/Managers/1/Certificates";
+
+        Mock::given(method("POST"))
+            .and(path(upload_path))
+            .and(|request: &Request| {
+                let body = String::from_utf8_lossy(&request.body);
+                let certificate = body.find("name=\"Certificate\"; filename=\"cert.pem\"");
+                let kind = body.find("name=\"CertificateType\"");
+                body.contains("Content-Type: application/x-pem-file")
+                    && body.contains("-----BEGIN CERTIFICATE-----")
+                    && matches!((certificate, kind), (Some(c), Some(k)) if c < k)
+            })
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new()?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let request = MultipartRequest {
+            parts: vec![
+                MultipartPart::new(
+                    "Certificate",
+                    Cursor::new(b"-----BEGIN CERTIFICATE-----".to_vec()),
+                )
+                .with_file_name("cert.pem")
+                .with_content_type("application/x-pem-file"),
+                MultipartPart::new("CertificateType", Cursor::new(b"PEM".to_vec())),
+            ],
+            upload_timeout: Duration::from_secs(60),
+        };
+
+        let response = client
+            .post_multipart::<serde_json::Value>(
+                Url::parse(&format!("{}{upload_path}", mock_server.uri()))?,
+                request,
+                &credentials,
+                &HeaderMap::new(),
+            )
+            .await?;
+
+        assert!(matches!(response, ModificationResponse::Empty));
+
+        Ok(())
+    }
+
     fn multipart_body_contains(request: &Request, file_name: &str, file_body: &str) -> bool {
         let Some(content_type) = request
             .headers
@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interpretation of Redfish HTTP response headers.
+//!
+//! These helpers don't depend on HTTP client implementation and are
+//! shared by all [`crate::HttpClient`] implementations.
+
+use std::time::Duration;
+
+use http::header;
+use http::HeaderMap;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use serde_json::Value as JsonValue;
+use url::Url;
+
+/// Resolve a Redfish `Location` header into a same-origin path and query.
+///
+/// HTTP defines `Location` as a URI reference, so values may be absolute,
+/// root-relative, path-relative, or query-only. Resolution must use the final
+/// response URL; treating a relative value as a path rooted at the configured
+/// BMC endpoint can target a different resource. The returned `ODataId` keeps
+/// only path and query because fragments are not sent in subsequent HTTP
+/// requests and transport always uses the configured BMC origin.
+///
+/// # Errors
+///
+/// Returns the reason if the header is present but doesn't identify
+/// a resource of the same origin.
+pub fn location_from_headers(
+    headers: &HeaderMap,
+    response_url: &Url,
+) -> Result<Option<ODataId>, &'static str> {
+    let Some(value) = headers.get(header::LOCATION) else {
+        return Ok(None);
+    };
+
+    let raw = value
+        .to_str()
+        .map_err(|_| "Location header is not valid text")?;
+
+    let raw = raw.trim();
+
+    // Joining either value would resolve back to the response resource, which
+    // cannot identify a newly created session or asynchronous task monitor.
+    if raw.is_empty() || raw.starts_with('#') {
+        return Err("Location header does not identify a resource");
+    }
+
+    let resolved = response_url
+        .join(raw)
+        .map_err(|_| "Location header is not a valid URI reference")?;
+
+    // Redfish follow-up requests carry BMC credentials. Reject another origin
+    // before reducing the URL to an OData path and losing that distinction.
+    if resolved.origin() != response_url.origin() {
+        return Err("Location header resolves to a different origin");
+    }
+
+    let mut path_and_query = resolved.path().to_string();
+
+    // Preserve the query separately from the path so later polling or deletion
+    // sends it as a query instead of percent-encoded path text.
+    if let Some(query) = resolved.query() {
+        path_and_query.push('?');
+        path_and_query.push_str(query);
+    }
+
+    Ok(Some(path_and_query.into()))
+}
+
+/// Session token from `X-Auth-Token` header.
+#[must_use]
+pub fn auth_token_from_headers(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("x-auth-token")
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
+
+/// Entity of a successful GET response.
+#[derive(Debug)]
+pub struct GetResponse<T> {
+    /// Deserialized body of the response.
+    pub entity: T,
+    /// Value of `ETag` header of the response. It is also injected
+    /// into `@odata.etag` of the body, but `T` may not keep it.
+    pub etag: Option<ODataETag>,
+    /// Methods listed in `Allow` header of the response.
+    pub allow: Option<AllowedMethods>,
+}
+
+/// Value of `ETag` header.
+#[must_use]
+pub fn etag_from_headers(headers: &HeaderMap) -> Option<ODataETag> {
+    headers
+        .get(header::ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(|v| v.to_string().into())
+}
+
+/// Methods listed in `Allow` header.
+#[must_use]
+pub fn allowed_methods_from_headers(headers: &HeaderMap) -> Option<AllowedMethods> {
+    // Allow may be split into several header lines.
+    let mut values = headers
+        .get_all(header::ALLOW)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .peekable();
+    values.peek()?;
+    Some(AllowedMethods::from_header(
+        &values.collect::<Vec<_>>().join(","),
+    ))
+}
+
+/// Delay requested by `Retry-After` header.
+#[must_use]
+pub fn retry_after_from_headers(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        // RFC 9110 defines the numeric Retry-After form as delay-seconds.
+        // This helper handles that form and leaves HTTP-date support out of scope.
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Set `@odata.etag` of the body to the value of `ETag` header.
+pub fn inject_etag(etag: &ODataETag, body: &mut JsonValue) {
+    if let Some(obj) = body.as_object_mut() {
+        let etag_value = JsonValue::String(etag.to_string());
+
+        // Handles both absent and null values
+        obj.entry("@odata.etag")
+            .and_modify(|v| *v = etag_value.clone())
+            .or_insert(etag_value);
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redfish session authentication.
+//!
+//! Session login and logout are implemented on top of [`HttpClient`]
+//! so that all HTTP client implementations share them.
+
+use std::error::Error as StdError;
+
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::BmcCredentials;
+use crate::CacheableError;
+use crate::HttpBmc;
+use crate::HttpClient;
+use crate::RequestError;
+
+/// Session collection URI defined by the Redfish specification.
+const SESSIONS: &str = "/redfish/v1/SessionService/Sessions";
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SessionLogin<'a> {
+    user_name: &'a str,
+    password: &'a str,
+}
+
+impl<C: HttpClient> HttpBmc<C>
+where
+    C::Error: CacheableError + RequestError + StdError + Send + Sync,
+{
+    /// Create a Redfish session and use its token for subsequent
+    /// requests. Returns URI of the created session that is used to
+    /// close it with [`HttpBmc::logout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the session cannot be created.
+    pub async fn login(&self, username: &str, password: &str) -> Result<ODataId, C::Error> {
+        let url = self.redfish_endpoint.with_path(SESSIONS);
+        let response = self
+            .client
+            .post_session::<_, JsonValue>(
+                url,
+                &SessionLogin {
+                    user_name: username,
+                    password,
+                },
+                &self.custom_headers,
+            )
+            .await?;
+        self.set_credentials(BmcCredentials::token(response.auth_token));
+        Ok(response.location)
+    }
+
+    /// Delete the Redfish session created by [`HttpBmc::login`]. The
+    /// session token is not valid after this call, so credentials
+    /// must be replaced before the next request.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the session cannot be deleted.
+    pub async fn logout(&self, session: &ODataId) -> Result<(), C::Error> {
+        let url = self.redfish_endpoint.with_odata_id(session);
+        let credentials = self.read_credentials();
+        self.client
+            .delete::<JsonValue>(
+                url,
+                ODataETag::from(String::from("*")),
+                credentials.as_ref(),
+                &self.custom_headers,
+            )
+            .await
+            .map(|_| ())
+    }
+}
@@ -25,8 +25,9 @@ mod cache_integration_tests {
         reqwest::{BmcError, Client},
         CacheSettings, HttpBmc,
     };
-    use nv_redfish_core::query::{ExpandQuery, FilterQuery};
+    use nv_redfish_core::query::{ExpandQuery, FilterQuery, SelectQuery};
     use nv_redfish_core::Bmc;
+    use nv_redfish_core::ODataETag;
     use url::Url;
     use wiremock::{
         matchers::{header, method, path, query_param},
@@ -167,6 +168,53 @@ mod cache_integration_tests {
         assert!(Arc::ptr_eq(&retrieved1, &retrieved2));
     }
 
+    #[tokio::test]
+    async fn get_if_none_match_reports_not_modified() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::MANAGERS_1;
+        let etag_value = "conditional";
+        let test_resource =
+            create_test_resource(resource_path, Some("changed"), names::TEST_MANAGER, 7);
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .and(header("if-none-match", etag_value))
+            .respond_with(ResponseTemplate::new(304))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&test_resource))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Conditional requests don't depend on the cache of the BMC.
+        let bmc = HttpBmc::new(
+            Client::new()?,
+            Url::parse(&mock_server.uri())?,
+            create_test_credentials(),
+            CacheSettings::with_capacity(0),
+        );
+        let resource_id = create_odata_id(resource_path);
+        let etag = ODataETag::from(etag_value.to_string());
+
+        let not_modified = bmc
+            .get_if_none_match::<TestResource>(&resource_id, &etag)
+            .await?;
+        assert!(not_modified.is_none());
+
+        let modified = bmc
+            .get_if_none_match::<TestResource>(&resource_id, &etag)
+            .await?;
+        assert_eq!(modified.map(|resource| resource.value), Some(7));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_expand_cache_key_includes_query() {
         let mock_server = MockServer::start().await;
@@ -280,6 +328,49 @@ mod cache_integration_tests {
         assert!(Arc::ptr_eq(&larger, &larger_cached));
     }
 
+    #[tokio::test]
+    async fn test_select_is_not_cached() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "selected";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 10);
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .and(query_param("$select", "value"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(&test_resource)
+                    .insert_header("etag", etag_value),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        let first = bmc
+            .select::<TestResource>(&resource_id, SelectQuery::property(&"value"))
+            .await?;
+        let second = bmc
+            .select::<TestResource>(&resource_id, SelectQuery::property(&"value"))
+            .await?;
+        assert_eq!(first.value, second.value);
+        assert!(!Arc::ptr_eq(&first, &second));
+
+        let received_requests = mock_server
+            .received_requests()
+            .await
+            .ok_or("request recording is disabled")?;
+        assert!(received_requests
+            .iter()
+            .all(|request| !request.headers.contains_key("if-none-match")));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_etag_changed_updates_cache() {
         let mock_server = MockServer::start().await;
@@ -452,4 +543,66 @@ mod cache_integration_tests {
         let retrieved = result.unwrap();
         assert_eq!(retrieved.etag.as_ref().unwrap().to_string(), etag_value);
     }
+
+    /// Resource that doesn't keep `@odata.etag` of the body.
+    #[derive(Debug, serde::Deserialize)]
+    struct UntaggedResource {
+        #[serde(rename = "@odata.id")]
+        id: nv_redfish_core::ODataId,
+        #[serde(rename = "Value")]
+        value: i32,
+    }
+
+    impl nv_redfish_core::EntityTypeRef for UntaggedResource {
+        fn odata_id(&self) -> &nv_redfish_core::ODataId {
+            &self.id
+        }
+
+        fn etag(&self) -> Option<&nv_redfish_core::ODataETag> {
+            None
+        }
+    }
+
+    impl nv_redfish_core::Expandable for UntaggedResource {}
+
+    #[tokio::test]
+    async fn test_etag_header_cached_for_entity_without_etag() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::CHASSIS_1;
+        let etag_value = "headeretag";
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .and(header("if-none-match", etag_value))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({
+                        "@odata.id": resource_path,
+                        "Value": 100,
+                    }))
+                    .insert_header("etag", etag_value),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        let first = bmc.get::<UntaggedResource>(&resource_id).await?;
+        let second = bmc.get::<UntaggedResource>(&resource_id).await?;
+
+        assert_eq!(first.value, 100);
+        assert!(Arc::ptr_eq(&first, &second));
+
+        Ok(())
+    }
 }
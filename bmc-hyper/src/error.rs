@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Errors of the hyper client.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use http::StatusCode;
+use nv_redfish_bmc_http::CacheableError;
+use nv_redfish_bmc_http::RedfishError;
+use nv_redfish_bmc_http::RejectedUriReferenceError;
+use nv_redfish_bmc_http::RequestError;
+use nv_redfish_core::BmcErrorInfo;
+use nv_redfish_core::ODataId;
+use url::Url;
+
+/// Type-erased error of tower services and response bodies.
+pub type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// Errors of [`crate::Client`].
+#[derive(Debug)]
+pub enum HyperError {
+    /// Error returned by the service stack, for example, connection
+    /// failure or timeout of a middleware.
+    Service(BoxError),
+    /// Error reading the response body.
+    Body(BoxError),
+    /// HTTP request cannot be built.
+    Http(http::Error),
+    /// Unexpected HTTP response.
+    InvalidResponse {
+        /// URL in request that caused error.
+        url: Url,
+        /// Returned status.
+        status: StatusCode,
+        /// Text in the response.
+        text: String,
+        /// Redfish error parsed from the text of the response.
+        redfish_error: Option<Box<RedfishError>>,
+    },
+    /// Write was rejected because the entity was changed since the
+    /// `ETag` sent in `If-Match` was fetched (`412 Precondition
+    /// Failed`).
+    PreconditionFailed {
+        /// URL in request that caused error.
+        url: Url,
+        /// Text in the response.
+        text: String,
+        /// Redfish error parsed from the text of the response.
+        redfish_error: Option<Box<RedfishError>>,
+    },
+    /// Response body exceeded the configured maximum payload size.
+    PayloadTooLarge {
+        /// URL in request that caused error.
+        url: Url,
+        /// Configured byte limit that was exceeded.
+        limit: usize,
+    },
+    /// JSON to model deserialize error with path tracking.
+    JsonError(serde_path_to_error::Error<serde_json::Error>),
+    /// JSON deserialization error.
+    DecodeError(serde_json::Error),
+    /// JSON serialization error.
+    EncodeError(serde_json::Error),
+    /// A single SSE event exceeded the maximum buffered size.
+    SseEventTooLarge {
+        /// Byte limit that was exceeded.
+        limit: usize,
+    },
+    /// No resource found in cache.
+    CacheMiss,
+    /// HTTP cache error.
+    CacheError(String),
+    /// Request rejected before transport.
+    InvalidRequest(String),
+    /// Operation is not supported by this client.
+    Unsupported(&'static str),
+}
+
+impl HyperError {
+    /// Redfish error from the body of unsuccessful response, if the
+    /// body is a Redfish error.
+    #[must_use]
+    pub fn redfish_error(&self) -> Option<&RedfishError> {
+        match self {
+            Self::InvalidResponse { redfish_error, .. }
+            | Self::PreconditionFailed { redfish_error, .. } => redfish_error.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Error for unsuccessful response with body `text`.
+    pub(crate) fn invalid_response(url: Url, status: StatusCode, text: String) -> Self {
+        let redfish_error = RedfishError::parse(&text).map(Box::new);
+        Self::InvalidResponse {
+            url,
+            status,
+            text,
+            redfish_error,
+        }
+    }
+
+    /// Error for `412 Precondition Failed` response with body `text`.
+    pub(crate) fn precondition_failed(url: Url, text: String) -> Self {
+        let redfish_error = RedfishError::parse(&text).map(Box::new);
+        Self::PreconditionFailed {
+            url,
+            text,
+            redfish_error,
+        }
+    }
+}
+
+impl fmt::Display for HyperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Service(e) => write!(f, "HTTP service error: {e}"),
+            Self::Body(e) => write!(f, "HTTP body error: {e}"),
+            Self::Http(e) => write!(f, "HTTP request error: {e}"),
+            Self::InvalidResponse {
+                url, status, text, ..
+            } => {
+                write!(
+                    f,
+                    "Invalid HTTP response - url: {url} status: {status} text: {text}"
+                )
+            }
+            Self::PreconditionFailed { url, text, .. } => {
+                write!(f, "Precondition failed - url: {url} text: {text}")
+            }
+            Self::PayloadTooLarge { url, limit } => write!(
+                f,
+                "Response payload exceeded maximum size of {limit} bytes - url: {url}"
+            ),
+            Self::JsonError(e) => write!(
+                f,
+                "JSON deserialization error at line {} column {} path {}: {e}",
+                e.inner().line(),
+                e.inner().column(),
+                e.path(),
+            ),
+            Self::DecodeError(e) => write!(f, "JSON Decode error: {e}"),
+            Self::EncodeError(e) => write!(f, "JSON Encode error: {e}"),
+            Self::SseEventTooLarge { limit } => write!(
+                f,
+                "SSE event exceeded maximum buffered size of {limit} bytes"
+            ),
+            Self::CacheMiss => write!(f, "Resource not found in cache"),
+            Self::CacheError(r) => write!(f, "Error occurred in cache {r:?}"),
+            Self::InvalidRequest(e) => write!(f, "Invalid request: {e}"),
+            Self::Unsupported(op) => write!(f, "Not supported: {op}"),
+        }
+    }
+}
+
+impl StdError for HyperError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Service(e) | Self::Body(e) => Some(e.as_ref()),
+            Self::Http(e) => Some(e),
+            Self::JsonError(e) => Some(e.inner()),
+            Self::DecodeError(e) | Self::EncodeError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl CacheableError for HyperError {
+    fn is_cached(&self) -> bool {
+        match self {
+            Self::InvalidResponse { status, .. } => status == &StatusCode::NOT_MODIFIED,
+            _ => false,
+        }
+    }
+
+    fn cache_miss() -> Self {
+        Self::CacheMiss
+    }
+
+    fn cache_error(reason: String) -> Self {
+        Self::CacheError(reason)
+    }
+}
+
+impl RequestError for HyperError {
+    fn rejected_uri_reference(error: RejectedUriReferenceError) -> Self {
+        Self::InvalidRequest(error.reason)
+    }
+}
+
+impl BmcErrorInfo for HyperError {
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::InvalidResponse { status, .. } => Some(status.as_u16()),
+            Self::PreconditionFailed { .. } => Some(412),
+            _ => None,
+        }
+    }
+
+    fn is_transport(&self) -> bool {
+        matches!(self, Self::Service(_) | Self::Body(_))
+    }
+
+    fn odata_id(&self) -> Option<ODataId> {
+        match self {
+            Self::InvalidResponse { url, .. }
+            | Self::PreconditionFailed { url, .. }
+            | Self::PayloadTooLarge { url, .. } => Some(ODataId::from(url.path().to_string())),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Default connection stack: hyper-util pooled client with rustls.
+
+use std::time::Duration;
+
+use hyper_rustls::HttpsConnector;
+use hyper_rustls::HttpsConnectorBuilder;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client as LegacyClient;
+use hyper_util::rt::TokioExecutor;
+
+use crate::RequestBody;
+
+/// Pooled HTTP/1 client connecting over TLS or plain TCP.
+pub type HttpsService = LegacyClient<HttpsConnector<HttpConnector>, RequestBody>;
+
+/// Parameters of the connection pool of [`HttpsService`].
+#[derive(Debug, Clone)]
+pub struct ConnectionParams {
+    /// Timeout of TCP connection establishment.
+    pub connect_timeout: Option<Duration>,
+    /// TCP keepalive interval.
+    pub tcp_keepalive: Option<Duration>,
+    /// Time after which idle pooled connection is closed.
+    pub pool_idle_timeout: Option<Duration>,
+    /// Maximum number of idle pooled connections per host.
+    pub pool_max_idle_per_host: usize,
+}
+
+impl Default for ConnectionParams {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Some(Duration::from_secs(10)),
+            tcp_keepalive: Some(Duration::from_mins(1)),
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            pool_max_idle_per_host: 1,
+        }
+    }
+}
+
+/// Build [`HttpsService`] that verifies servers with Mozilla root
+/// certificates.
+#[must_use]
+pub fn https_service(params: &ConnectionParams) -> HttpsService {
+    let connector = HttpsConnectorBuilder::new()
+        .with_webpki_roots()
+        .https_or_http()
+        .enable_http1()
+        .wrap_connector(http_connector(params));
+    legacy_client(connector, params)
+}
+
+/// Build [`HttpsService`] with custom TLS configuration, for example,
+/// to trust the self-signed certificate of a BMC.
+#[must_use]
+pub fn https_service_with_tls(
+    tls: rustls::ClientConfig,
+    params: &ConnectionParams,
+) -> HttpsService {
+    let connector = HttpsConnectorBuilder::new()
+        .with_tls_config(tls)
+        .https_or_http()
+        .enable_http1()
+        .wrap_connector(http_connector(params));
+    legacy_client(connector, params)
+}
+
+fn http_connector(params: &ConnectionParams) -> HttpConnector {
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+    http.set_connect_timeout(params.connect_timeout);
+    http.set_keepalive(params.tcp_keepalive);
+    http
+}
+
+fn legacy_client(
+    connector: HttpsConnector<HttpConnector>,
+    params: &ConnectionParams,
+) -> HttpsService {
+    LegacyClient::builder(TokioExecutor::new())
+        .pool_idle_timeout(params.pool_idle_timeout)
+        .pool_max_idle_per_host(params.pool_max_idle_per_host)
+        .build(connector)
+}
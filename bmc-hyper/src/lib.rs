@@ -0,0 +1,722 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf
+)]
+#![deny(
+    clippy::absolute_paths,
+    clippy::todo,
+    clippy::unimplemented,
+    clippy::tests_outside_test_module,
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::unwrap_in_result,
+    clippy::unused_trait_names,
+    clippy::print_stdout,
+    clippy::print_stderr
+)]
+#![deny(missing_docs)]
+
+//! [`HttpClient`] implementation built on hyper and tower.
+//!
+//! [`Client`] sends requests through any tower service that accepts
+//! `http::Request<Full<Bytes>>`, so connection management and
+//! middleware (timeouts, rate limiting, metrics, tracing) are
+//! composed by the caller. Redfish semantics (authentication,
+//! `ETag` handling, task monitors, sessions) are shared with other
+//! clients through [`HttpBmc`](nv_redfish_bmc_http::HttpBmc).
+//!
+//! ```rust,no_run
+//! use nv_redfish_bmc_http::BmcCredentials;
+//! use nv_redfish_bmc_http::CacheSettings;
+//! use nv_redfish_bmc_http::HttpBmc;
+//! use nv_redfish_hyper::https_service;
+//! use nv_redfish_hyper::Client;
+//! use nv_redfish_hyper::ConnectionParams;
+//! use std::time::Duration;
+//! use tower::ServiceBuilder;
+//! use url::Url;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let service = ServiceBuilder::new()
+//!     .timeout(Duration::from_secs(30))
+//!     .service(https_service(&ConnectionParams::default()));
+//! let bmc = HttpBmc::new(
+//!     Client::with_service(service),
+//!     Url::parse("https://192.168.1.100")?,
+//!     BmcCredentials::new("admin".into(), "password".into()),
+//!     CacheSettings::default(),
+//! );
+//! let session = bmc.login("admin", "password").await?;
+//! // ...
+//! bmc.logout(&session).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Multipart and raw firmware uploads are not supported yet and
+//! return [`HyperError::Unsupported`].
+
+pub mod error;
+
+#[cfg(feature = "https")]
+mod https;
+
+use std::fmt;
+
+use bytes::Bytes;
+use futures_util::StreamExt as _;
+use http::header;
+use http::request::Builder as RequestBuilder;
+use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Method;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http_body::Body;
+use http_body_util::BodyDataStream;
+use http_body_util::BodyExt as _;
+use http_body_util::Collected;
+use http_body_util::Full;
+use http_body_util::LengthLimitError;
+use http_body_util::Limited;
+use nv_redfish_bmc_http::response;
+use nv_redfish_bmc_http::response::allowed_methods_from_headers;
+use nv_redfish_bmc_http::response::auth_token_from_headers;
+use nv_redfish_bmc_http::response::etag_from_headers;
+use nv_redfish_bmc_http::response::inject_etag;
+use nv_redfish_bmc_http::response::retry_after_from_headers;
+use nv_redfish_bmc_http::response::GetResponse;
+use nv_redfish_bmc_http::BmcCredentials;
+use nv_redfish_bmc_http::ExtendedInfo;
+use nv_redfish_bmc_http::HttpClient;
+use nv_redfish_bmc_http::MultipartRequest;
+use nv_redfish_bmc_http::MultipartUpdateRequest;
+use nv_redfish_bmc_http::RedfishError;
+use nv_redfish_core::AsyncTask;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::SseBody as _;
+use nv_redfish_core::SseError;
+use nv_redfish_core::UploadReader;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use tower::Service;
+use tower::ServiceExt as _;
+use url::Url;
+
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+
+#[doc(inline)]
+pub use error::BoxError;
+#[doc(inline)]
+pub use error::HyperError;
+#[cfg(feature = "https")]
+#[doc(inline)]
+pub use https::https_service;
+#[cfg(feature = "https")]
+#[doc(inline)]
+pub use https::https_service_with_tls;
+#[cfg(feature = "https")]
+#[doc(inline)]
+pub use https::ConnectionParams;
+#[cfg(feature = "https")]
+#[doc(inline)]
+pub use https::HttpsService;
+
+/// Body of requests sent by [`Client`].
+pub type RequestBody = Full<Bytes>;
+
+/// HTTP client that sends requests through tower service `S`.
+#[derive(Clone)]
+pub struct Client<S> {
+    service: S,
+    max_payload_bytes: Option<usize>,
+}
+
+impl<S> Client<S> {
+    /// Create client that sends requests through `service`.
+    pub const fn with_service(service: S) -> Self {
+        Self {
+            service,
+            max_payload_bytes: None,
+        }
+    }
+
+    /// Sets the maximum size of a response body. Larger responses
+    /// fail with [`HyperError::PayloadTooLarge`].
+    #[must_use]
+    pub const fn max_payload_bytes(mut self, bytes: usize) -> Self {
+        self.max_payload_bytes = Some(bytes);
+        self
+    }
+}
+
+#[cfg(feature = "https")]
+impl Client<HttpsService> {
+    /// Create client with default [`https_service`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_service(https_service(&ConnectionParams::default()))
+    }
+}
+
+#[cfg(feature = "https")]
+impl Default for Client<HttpsService> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> fmt::Debug for Client<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("max_payload_bytes", &self.max_payload_bytes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, B> Client<S>
+where
+    S: Service<Request<RequestBody>, Response = Response<B>> + Clone + Send + Sync,
+    S::Future: Send,
+    S::Error: Into<BoxError>,
+    B: Body<Data = Bytes> + Send,
+    B::Error: Into<BoxError>,
+{
+    async fn send(&self, request: Request<RequestBody>) -> Result<Response<B>, HyperError> {
+        self.service
+            .clone()
+            .oneshot(request)
+            .await
+            .map_err(|err| HyperError::Service(err.into()))
+    }
+
+    /// Read the whole response body. If the maximum payload size is
+    /// configured, reading is stopped as soon as the limit is
+    /// exceeded.
+    async fn read_body(&self, url: &Url, body: B) -> Result<Bytes, HyperError> {
+        let Some(limit) = self.max_payload_bytes else {
+            return body
+                .collect()
+                .await
+                .map(Collected::to_bytes)
+                .map_err(|err| HyperError::Body(err.into()));
+        };
+        Limited::new(body, limit)
+            .collect()
+            .await
+            .map(Collected::to_bytes)
+            .map_err(|err| {
+                if err.is::<LengthLimitError>() {
+                    HyperError::PayloadTooLarge {
+                        url: url.clone(),
+                        limit,
+                    }
+                } else {
+                    HyperError::Body(err)
+                }
+            })
+    }
+
+    /// Error for unsuccessful response with the body as text.
+    async fn error_response(&self, url: Url, response: Response<B>) -> HyperError {
+        let status = response.status();
+        let text = self
+            .read_body(&url, response.into_body())
+            .await
+            .map_or_else(
+                |_| "<no data>".into(),
+                |bytes| String::from_utf8_lossy(&bytes).into_owned(),
+            );
+        if status == StatusCode::PRECONDITION_FAILED {
+            HyperError::precondition_failed(url, text)
+        } else {
+            HyperError::invalid_response(url, status, text)
+        }
+    }
+
+    async fn handle_response<T>(
+        &self,
+        url: Url,
+        response: Response<B>,
+    ) -> Result<GetResponse<T>, HyperError>
+    where
+        T: DeserializeOwned,
+    {
+        if !response.status().is_success() {
+            return Err(self.error_response(url, response).await);
+        }
+        let etag = etag_from_headers(response.headers());
+        let allow = allowed_methods_from_headers(response.headers());
+        let bytes = self.read_body(&url, response.into_body()).await?;
+        let mut value: serde_json::Value =
+            serde_json::from_slice(&bytes).map_err(HyperError::DecodeError)?;
+        if let Some(etag) = &etag {
+            inject_etag(etag, &mut value);
+        }
+        serde_path_to_error::deserialize(value)
+            .map(|entity| GetResponse {
+                entity,
+                etag,
+                allow,
+            })
+            .map_err(HyperError::JsonError)
+    }
+
+    async fn handle_modification_response<T>(
+        &self,
+        url: Url,
+        response: Response<B>,
+    ) -> Result<ModificationResponse<T>, HyperError>
+    where
+        T: DeserializeOwned,
+    {
+        let status = response.status();
+        if !status.is_success() {
+            return Err(self.error_response(url, response).await);
+        }
+        let headers = response.headers().clone();
+
+        // Resolve the header once, but defer propagating its error until a
+        // status branch actually uses Location.
+        let location = location_from_headers(&headers, &url, status);
+
+        match status {
+            StatusCode::NO_CONTENT => Ok(ModificationResponse::Empty),
+            StatusCode::ACCEPTED => {
+                let Some(task_location) = location? else {
+                    return Err(HyperError::InvalidResponse {
+                        url,
+                        status,
+                        text: String::from("202 Accepted without Location header"),
+                        redfish_error: None,
+                    });
+                };
+                Ok(ModificationResponse::Task(AsyncTask {
+                    location: task_location.into(),
+                    retry_after: retry_after_from_headers(&headers),
+                }))
+            }
+            StatusCode::OK | StatusCode::CREATED => {
+                let bytes = self.read_body(&url, response.into_body()).await?;
+                if !bytes.is_empty() {
+                    let mut value: serde_json::Value =
+                        serde_json::from_slice(&bytes).map_err(HyperError::DecodeError)?;
+                    if value.get("@odata.id").is_some() {
+                        if let Some(etag) = etag_from_headers(&headers) {
+                            inject_etag(&etag, &mut value);
+                        }
+                    }
+                    return match serde_path_to_error::deserialize(&value) {
+                        Ok(entity) => Ok(ModificationResponse::Entity(entity)),
+                        Err(_) if is_redfish_success_response(&value) => {
+                            Ok(ModificationResponse::Empty)
+                        }
+                        Err(err) => Err(HyperError::JsonError(err)),
+                    };
+                }
+                if let Some(location) = location? {
+                    let value = serde_json::json!({ "@odata.id": location });
+                    return serde_path_to_error::deserialize(value)
+                        .map(ModificationResponse::Entity)
+                        .map_err(HyperError::JsonError);
+                }
+                Ok(ModificationResponse::Empty)
+            }
+            _ => Err(HyperError::InvalidResponse {
+                url,
+                status,
+                text: format!("Unexpected successful status code: {status}"),
+                redfish_error: None,
+            }),
+        }
+    }
+}
+
+impl<S, B> HttpClient for Client<S>
+where
+    S: Service<Request<RequestBody>, Response = Response<B>> + Clone + Send + Sync,
+    S::Future: Send,
+    S::Error: Into<BoxError>,
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    type Error = HyperError;
+
+    async fn get<T>(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        etag: Option<ODataETag>,
+        custom_headers: &HeaderMap,
+    ) -> Result<GetResponse<T>, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut builder = request(Method::GET, &url, Some(credentials), custom_headers)?;
+        if let Some(etag) = etag {
+            builder = builder.header(header::IF_NONE_MATCH, etag.to_string());
+        }
+        let response = self.send(empty_body(builder)?).await?;
+        self.handle_response(url, response).await
+    }
+
+    async fn head(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<(), Self::Error> {
+        let builder = request(Method::HEAD, &url, Some(credentials), custom_headers)?;
+        let response = self.send(empty_body(builder)?).await?;
+        if !response.status().is_success() {
+            return Err(HyperError::InvalidResponse {
+                url,
+                status: response.status(),
+                text: "<no data>".into(),
+                redfish_error: None,
+            });
+        }
+        Ok(())
+    }
+
+    async fn post<T, R>(
+        &self,
+        url: Url,
+        body: &T,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + Sync,
+    {
+        let builder = request(Method::POST, &url, Some(credentials), custom_headers)?;
+        let response = self.send(json_body(builder, body)?).await?;
+        self.handle_modification_response(url, response).await
+    }
+
+    async fn post_session<T, R>(
+        &self,
+        url: Url,
+        body: &T,
+        custom_headers: &HeaderMap,
+    ) -> Result<SessionCreateResponse<R>, Self::Error>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + Sync,
+    {
+        let builder = request(Method::POST, &url, None, custom_headers)?;
+        let response = self.send(json_body(builder, body)?).await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(self.error_response(url, response).await);
+        }
+        let headers = response.headers().clone();
+        let Some(auth_token) = auth_token_from_headers(&headers) else {
+            return Err(HyperError::InvalidResponse {
+                url,
+                status,
+                text: String::from("session creation response missing X-Auth-Token header"),
+                redfish_error: None,
+            });
+        };
+        let Some(location) = location_from_headers(&headers, &url, status)? else {
+            return Err(HyperError::InvalidResponse {
+                url,
+                status,
+                text: String::from("session creation response missing Location header"),
+                redfish_error: None,
+            });
+        };
+        if !matches!(status, StatusCode::OK | StatusCode::CREATED) {
+            return Err(HyperError::InvalidResponse {
+                url,
+                status,
+                text: format!("Unexpected successful status code for session creation: {status}"),
+                redfish_error: None,
+            });
+        }
+        let bytes = self.read_body(&url, response.into_body()).await?;
+        if bytes.is_empty() {
+            return Err(HyperError::InvalidResponse {
+                url,
+                status,
+                text: String::from("session creation response missing entity body"),
+                redfish_error: None,
+            });
+        }
+        let mut value: serde_json::Value =
+            serde_json::from_slice(&bytes).map_err(HyperError::DecodeError)?;
+        if let Some(etag) = etag_from_headers(&headers) {
+            inject_etag(&etag, &mut value);
+        }
+        let entity = serde_path_to_error::deserialize(value).map_err(HyperError::JsonError)?;
+        Ok(SessionCreateResponse {
+            entity,
+            auth_token,
+            location,
+        })
+    }
+
+    async fn post_multipart_update<U, V, T>(
+        &self,
+        _url: Url,
+        _request: MultipartUpdateRequest<'_, U, V>,
+        _credentials: &BmcCredentials,
+        _custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        U: UploadReader,
+        T: DeserializeOwned + Send + Sync,
+        V: Serialize + Send + Sync,
+    {
+        Err(HyperError::Unsupported("multipart update"))
+    }
+
+    async fn post_multipart<T>(
+        &self,
+        _url: Url,
+        _request: MultipartRequest,
+        _credentials: &BmcCredentials,
+        _custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        T: DeserializeOwned + Send + Sync,
+    {
+        Err(HyperError::Unsupported("multipart upload"))
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn post_http_push_uri_update<U, T>(
+        &self,
+        _url: Url,
+        _request: HttpPushUriUpdateRequest<U>,
+        _credentials: &BmcCredentials,
+        _custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        U: UploadReader,
+        T: DeserializeOwned + Send + Sync,
+    {
+        Err(HyperError::Unsupported("HttpPushUri update"))
+    }
+
+    async fn patch<T, R>(
+        &self,
+        url: Url,
+        etag: ODataETag,
+        body: &T,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + Sync,
+    {
+        let builder = request(Method::PATCH, &url, Some(credentials), custom_headers)?
+            .header(header::IF_MATCH, etag.to_string());
+        let response = self.send(json_body(builder, body)?).await?;
+        self.handle_modification_response(url, response).await
+    }
+
+    async fn delete<T>(
+        &self,
+        url: Url,
+        etag: ODataETag,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<ModificationResponse<T>, Self::Error>
+    where
+        T: DeserializeOwned + Send + Sync,
+    {
+        let builder = request(Method::DELETE, &url, Some(credentials), custom_headers)?
+            .header(header::IF_MATCH, etag.to_string());
+        let response = self.send(empty_body(builder)?).await?;
+        self.handle_modification_response(url, response).await
+    }
+
+    async fn sse<T: Sized + for<'a> Deserialize<'a> + Send>(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        let builder = request(Method::GET, &url, Some(credentials), custom_headers)?
+            .header(header::ACCEPT, "text/event-stream");
+        let response = self.send(empty_body(builder)?).await?;
+        if !response.status().is_success() {
+            return Err(self.error_response(url, response).await);
+        }
+        let events = Box::pin(data_stream(response.into_body()))
+            .map(|chunk| chunk.map(|bytes| bytes.to_vec()))
+            .sse_events()
+            .map(|event| match event {
+                Ok(event) => serde_path_to_error::deserialize(
+                    &mut serde_json::Deserializer::from_str(&event.data),
+                )
+                .map_err(HyperError::JsonError),
+                Err(SseError::Body(err)) => Err(HyperError::Body(err)),
+                Err(SseError::EventTooLarge { limit }) => {
+                    Err(HyperError::SseEventTooLarge { limit })
+                }
+            });
+        Ok(Box::pin(events))
+    }
+
+    async fn download(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        let builder = request(Method::GET, &url, Some(credentials), custom_headers)?
+            .header(header::ACCEPT, "*/*");
+        let response = self.send(empty_body(builder)?).await?;
+        if !response.status().is_success() {
+            return Err(self.error_response(url, response).await);
+        }
+        Ok(Box::pin(data_stream(response.into_body()).map(|chunk| {
+            chunk.map(|bytes| bytes.to_vec()).map_err(HyperError::Body)
+        })))
+    }
+}
+
+/// Stream of data frames of the body with type-erased errors.
+fn data_stream<B>(
+    body: B,
+) -> impl futures_util::Stream<Item = Result<Bytes, BoxError>> + Send + 'static
+where
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    BodyDataStream::new(body).map(|chunk| chunk.map_err(Into::into))
+}
+
+/// Request builder with authentication and custom headers. JSON is
+/// requested unless `Accept` is overridden.
+fn request(
+    method: Method,
+    url: &Url,
+    credentials: Option<&BmcCredentials>,
+    custom_headers: &HeaderMap,
+) -> Result<RequestBuilder, HyperError> {
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(url.as_str())
+        .header(header::ACCEPT, "application/json");
+    if let Some(headers) = builder.headers_mut() {
+        for (name, value) in custom_headers {
+            headers.insert(name, value.clone());
+        }
+        if let Some(credentials) = credentials {
+            let (name, value) = auth_header(credentials)?;
+            headers.insert(name, value);
+        }
+    }
+    Ok(builder)
+}
+
+fn auth_header(credentials: &BmcCredentials) -> Result<(HeaderName, HeaderValue), HyperError> {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine as _;
+
+    let (name, value) = match credentials {
+        BmcCredentials::UsernamePassword { username, password } => {
+            let password = password.as_deref().unwrap_or_default();
+            (
+                header::AUTHORIZATION,
+                format!(
+                    "Basic {}",
+                    STANDARD.encode(format!("{username}:{password}"))
+                ),
+            )
+        }
+        BmcCredentials::Token { token } => (HeaderName::from_static("x-auth-token"), token.clone()),
+    };
+    let mut value = HeaderValue::from_str(&value)
+        .map_err(|_| HyperError::InvalidRequest("invalid credentials".into()))?;
+    value.set_sensitive(true);
+    Ok((name, value))
+}
+
+fn empty_body(builder: RequestBuilder) -> Result<Request<RequestBody>, HyperError> {
+    builder
+        .body(Full::new(Bytes::new()))
+        .map_err(HyperError::Http)
+}
+
+fn json_body<T: Serialize>(
+    builder: RequestBuilder,
+    body: &T,
+) -> Result<Request<RequestBody>, HyperError> {
+    let body = serde_json::to_vec(body).map_err(HyperError::EncodeError)?;
+    builder
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(body)))
+        .map_err(HyperError::Http)
+}
+
+fn location_from_headers(
+    headers: &HeaderMap,
+    url: &Url,
+    status: StatusCode,
+) -> Result<Option<ODataId>, HyperError> {
+    response::location_from_headers(headers, url).map_err(|text| HyperError::InvalidResponse {
+        url: url.clone(),
+        status,
+        text: text.to_string(),
+        redfish_error: None,
+    })
+}
+
+/// DSP0266 7.11, Table 10 allows actions without response bodies to return
+/// an error-shaped success body. Only that body should become Empty.
+fn is_redfish_success_response(value: &serde_json::Value) -> bool {
+    #[derive(Deserialize)]
+    struct ExtendedInfoEnvelope {
+        #[serde(rename = "@Message.ExtendedInfo")]
+        _extended_info: Vec<ExtendedInfo>,
+    }
+
+    #[derive(Deserialize)]
+    struct ErrorEnvelope {
+        error: RedfishError,
+    }
+
+    if ExtendedInfoEnvelope::deserialize(value).is_ok() {
+        return true;
+    }
+    let Ok(response) = ErrorEnvelope::deserialize(value) else {
+        return false;
+    };
+    let code = response.error.code();
+    let message = code.rsplit_once('.').map_or(code, |(_, message)| message);
+    matches!(message, "Success" | "Created" | "NoOperation")
+}
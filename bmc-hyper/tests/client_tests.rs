@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use http::HeaderMap;
+use nv_redfish_bmc_http::BmcCredentials;
+use nv_redfish_bmc_http::CacheSettings;
+use nv_redfish_bmc_http::HttpBmc;
+use nv_redfish_bmc_http::HttpClient as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataETag;
+use nv_redfish_hyper::Client;
+use nv_redfish_hyper::HyperError;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use url::Url;
+use wiremock::matchers::body_json;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+const SYSTEM: &str = "/redfish/v1/Systems/1";
+const SESSIONS: &str = "/redfish/v1/SessionService/Sessions";
+const SESSION: &str = "/redfish/v1/SessionService/Sessions/1";
+
+fn credentials() -> BmcCredentials {
+    BmcCredentials::new("admin".into(), "secret".into())
+}
+
+#[tokio::test]
+async fn get_uses_basic_auth_and_injects_etag() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(SYSTEM))
+        .and(header("Authorization", "Basic YWRtaW46c2VjcmV0"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"1\"")
+                .set_body_json(json!({ "@odata.id": SYSTEM, "Id": "1" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new();
+    let url = Url::parse(&format!("{}{SYSTEM}", server.uri()))?;
+    let response = client
+        .get::<Value>(url, &credentials(), None, &HeaderMap::new())
+        .await?;
+    assert_eq!(
+        response.etag.map(|etag| etag.to_string()).as_deref(),
+        Some("\"1\"")
+    );
+    let value = response.entity;
+    assert_eq!(value["@odata.etag"], "\"1\"");
+    assert_eq!(value["Id"], "1");
+    Ok(())
+}
+
+#[tokio::test]
+async fn patch_sends_if_match() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(SYSTEM))
+        .and(header("If-Match", "\"1\""))
+        .and(body_json(json!({ "AssetTag": "tag" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new();
+    let url = Url::parse(&format!("{}{SYSTEM}", server.uri()))?;
+    let response: ModificationResponse<Value> = client
+        .patch(
+            url,
+            ODataETag::from(String::from("\"1\"")),
+            &json!({ "AssetTag": "tag" }),
+            &credentials(),
+            &HeaderMap::new(),
+        )
+        .await?;
+    assert!(matches!(response, ModificationResponse::Empty));
+    Ok(())
+}
+
+#[tokio::test]
+async fn payload_size_limit() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(SYSTEM))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "Description": "x".repeat(1024) })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = Client::new().max_payload_bytes(128);
+    let url = Url::parse(&format!("{}{SYSTEM}", server.uri()))?;
+    let result = client
+        .get::<Value>(url, &credentials(), None, &HeaderMap::new())
+        .await;
+    assert!(matches!(
+        result,
+        Err(HyperError::PayloadTooLarge { limit: 128, .. })
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn session_login_logout() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(SESSIONS))
+        .and(body_json(
+            json!({ "UserName": "admin", "Password": "secret" }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .insert_header("X-Auth-Token", "token")
+                .insert_header("Location", SESSION)
+                .set_body_json(json!({ "@odata.id": SESSION })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(SESSION))
+        .and(header("X-Auth-Token", "token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let bmc = HttpBmc::new(
+        Client::new(),
+        Url::parse(&server.uri())?,
+        credentials(),
+        CacheSettings::default(),
+    );
+    let session = bmc.login("admin", "secret").await?;
+    assert_eq!(session.to_string(), SESSION);
+    bmc.logout(&session).await?;
+    Ok(())
+}
@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Coverage of schema properties by served payloads.
+//!
+//! Bmc records properties of every payload it serves, keyed by the
+//! unversioned type of the resource (`Chassis.Chassis`). Properties of
+//! nested objects are recorded as paths (`Status/Health`); expanded
+//! resources with their own `@odata.type` are recorded under their
+//! type.
+//!
+//! If `NV_REDFISH_COVERAGE_DIR` environment variable is set, each Bmc
+//! writes its coverage as JSON file to this directory when dropped.
+//! Files of the whole test suite are combined with the schema by
+//! `coverage` command of the CSDL compiler into a report of untested
+//! properties.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::env::var_os;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+/// Environment variable with directory where coverage is written.
+pub const COVERAGE_DIR_ENV: &str = "NV_REDFISH_COVERAGE_DIR";
+
+/// Properties of served payloads by type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Coverage {
+    properties: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Coverage {
+    /// Record properties of `payload`.
+    pub fn record(&mut self, payload: &JsonValue) {
+        self.visit(None, "", payload);
+    }
+
+    /// Add properties recorded in `other`.
+    pub fn merge(&mut self, other: Self) {
+        for (rtype, properties) in other.properties {
+            self.properties.entry(rtype).or_default().extend(properties);
+        }
+    }
+
+    /// Recorded properties of the type `rtype` (for example,
+    /// `Chassis.Chassis`).
+    #[must_use]
+    pub fn properties(&self, rtype: &str) -> Option<&BTreeSet<String>> {
+        self.properties.get(rtype)
+    }
+
+    /// Nothing is recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    fn visit(&mut self, rtype: Option<&str>, path: &str, value: &JsonValue) {
+        match value {
+            JsonValue::Object(object) => {
+                let own_type = object
+                    .get("@odata.type")
+                    .and_then(JsonValue::as_str)
+                    .map(unversioned);
+                let (rtype, path) = match (&own_type, rtype) {
+                    (Some(own), _) => (Some(own.as_str()), ""),
+                    (None, rtype) => (rtype, path),
+                };
+                for (name, value) in object {
+                    // Annotations are not schema properties.
+                    if name.contains('@') {
+                        continue;
+                    }
+                    let property = if path.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{path}/{name}")
+                    };
+                    if let Some(rtype) = rtype {
+                        self.properties
+                            .entry(rtype.into())
+                            .or_default()
+                            .insert(property.clone());
+                    }
+                    self.visit(rtype, &property, value);
+                }
+            }
+            JsonValue::Array(items) => {
+                for item in items {
+                    self.visit(rtype, path, item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Type without version: `#Chassis.v1_2_0.Chassis` is `Chassis.Chassis`.
+fn unversioned(odata_type: &str) -> String {
+    let odata_type = odata_type.trim_start_matches('#');
+    let mut ids = odata_type.split('.');
+    match (ids.next(), ids.next_back()) {
+        (Some(namespace), Some(name)) => format!("{namespace}.{name}"),
+        _ => odata_type.into(),
+    }
+}
+
+/// Coverage of one Bmc that is written to `COVERAGE_DIR_ENV` when
+/// dropped.
+#[derive(Debug)]
+pub(crate) struct CoverageRecorder {
+    dir: Option<PathBuf>,
+    coverage: Mutex<Coverage>,
+}
+
+impl Default for CoverageRecorder {
+    fn default() -> Self {
+        Self {
+            dir: var_os(COVERAGE_DIR_ENV).map(PathBuf::from),
+            coverage: Mutex::default(),
+        }
+    }
+}
+
+impl CoverageRecorder {
+    pub(crate) fn record(&self, payload: &JsonValue) {
+        self.coverage.lock().expect("not poisoned").record(payload);
+    }
+
+    pub(crate) fn coverage(&self) -> Coverage {
+        self.coverage.lock().expect("not poisoned").clone()
+    }
+}
+
+impl Drop for CoverageRecorder {
+    fn drop(&mut self) {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let Some(dir) = &self.dir else {
+            return;
+        };
+        let coverage = self.coverage.get_mut().map(|v| &*v);
+        let Ok(coverage) = coverage else {
+            return;
+        };
+        if coverage.is_empty() {
+            return;
+        }
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = dir.join(format!("coverage-{}-{n}.json", process::id()));
+        // Coverage is best effort: failure to write it must not fail
+        // the test.
+        let _ = fs::create_dir_all(dir).and_then(|()| {
+            fs::write(
+                path,
+                serde_json::to_vec(coverage).expect("json serializable"),
+            )
+        });
+    }
+}
@@ -15,25 +15,60 @@
 
 //! Expectations for Bmc Mock.
 
+use std::fmt::Debug;
 use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::iter::repeat_n;
+use std::sync::Arc;
+use std::time::Duration;
 
 use nv_redfish_core::action::ActionTarget;
+use nv_redfish_core::query::ExpandQuery;
 use nv_redfish_core::AsyncTask;
+use nv_redfish_core::FilterQuery;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
+use nv_redfish_core::UpdateMethod;
 
 use serde_json::from_str;
 use serde_json::Value as JsonValue;
 
 pub type Response<E> = Result<JsonValue, E>;
 
+/// Order in which expectations must be met.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExpectOrder {
+    /// Requests are issued exactly in the order of expectations.
+    #[default]
+    Strict,
+    /// Requests to different resources can be issued in any order;
+    /// requests to the same resource are issued in the order of
+    /// expectations.
+    PerResource,
+}
+
 /// Request expected by BMC.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ExpectedRequest {
     /// Expected Get.
     Get { id: ODataId },
 
-    /// Expected Expand.
-    Expand { id: ODataId },
+    /// Expected Head.
+    Head { id: ODataId },
+
+    /// Expected Expand. Without query, any expand query matches.
+    Expand { id: ODataId, query: Option<String> },
+
+    /// Expected page of collection members (`$top` / `$skip`).
+    Page { id: ODataId, query: String },
+
+    /// Expected collection members selected by `$filter`.
+    Filter { id: ODataId, query: String },
+
+    /// Expected properties selected by `$select`.
+    Select { id: ODataId, query: String },
 
     /// Expected Update.
     Update { id: ODataId, request: JsonValue },
@@ -45,9 +80,27 @@ pub enum ExpectedRequest {
         task: AsyncTask,
     },
 
+    /// Expected PATCH with request body that satisfies `body`.
+    Patch { id: ODataId, body: BodyMatcher },
+
     /// Expected update with no response body.
     UpdateEmpty { id: ODataId, request: JsonValue },
 
+    /// Expected update with request other than PATCH.
+    UpdateWithMethod {
+        id: ODataId,
+        request: JsonValue,
+        method: UpdateMethod,
+    },
+
+    /// Expected update with request other than PATCH and no response
+    /// body.
+    UpdateWithMethodEmpty {
+        id: ODataId,
+        request: JsonValue,
+        method: UpdateMethod,
+    },
+
     /// Expected Create.
     Create { id: ODataId, request: JsonValue },
 
@@ -83,27 +136,240 @@ pub enum ExpectedRequest {
         oem_parts: Vec<String>,
     },
 
+    /// Expected multipart upload with parts named `parts`.
+    MultipartUpload { uri: String, parts: Vec<String> },
+
     /// Expected raw HttpPushUri update.
     #[cfg(feature = "update-service-deprecated")]
     HttpPushUriUpdate { uri: String },
 
-    /// Expected Delete.
-    Delete { id: ODataId },
+    /// Expected POST with request body that satisfies `body`,
+    /// answered with `status`. Matches both creation in a collection
+    /// and actions.
+    Post {
+        uri: String,
+        body: BodyMatcher,
+        status: ResponseStatus,
+    },
+
+    /// Expected Delete answered with `status`.
+    Delete { id: ODataId, status: ResponseStatus },
 
     /// Expected asynchronous delete.
     DeleteTask { id: ODataId, task: AsyncTask },
 
     /// Expected Stream.
     Stream { uri: String },
+
+    /// Expected download that returns `body`.
+    Download { uri: String, body: Vec<u8> },
+
+    /// Expected SSE stream that delivers `events`.
+    Sse { uri: String, events: Vec<SseItem> },
+
+    /// Any request to `uri` that is answered with `fault`.
+    Fault { uri: String, fault: Fault },
+}
+
+impl ExpectedRequest {
+    /// URI of the resource the request is sent to.
+    #[must_use]
+    pub fn uri(&self) -> String {
+        match self {
+            Self::Get { id }
+            | Self::Head { id }
+            | Self::Expand { id, .. }
+            | Self::Page { id, .. }
+            | Self::Filter { id, .. }
+            | Self::Select { id, .. }
+            | Self::Update { id, .. }
+            | Self::UpdateTask { id, .. }
+            | Self::Patch { id, .. }
+            | Self::UpdateEmpty { id, .. }
+            | Self::UpdateWithMethod { id, .. }
+            | Self::UpdateWithMethodEmpty { id, .. }
+            | Self::Create { id, .. }
+            | Self::CreateTask { id, .. }
+            | Self::CreateEmpty { id, .. }
+            | Self::CreateSession { id, .. }
+            | Self::Delete { id, .. }
+            | Self::DeleteTask { id, .. } => id.to_string(),
+            Self::Action { target, .. } => target.to_string(),
+            Self::Post { uri, .. }
+            | Self::MultipartUpdate { uri, .. }
+            | Self::MultipartUpload { uri, .. }
+            | Self::Stream { uri }
+            | Self::Download { uri, .. }
+            | Self::Sse { uri, .. }
+            | Self::Fault { uri, .. } => uri.clone(),
+            #[cfg(feature = "update-service-deprecated")]
+            Self::HttpPushUriUpdate { uri } => uri.clone(),
+        }
+    }
+}
+
+/// Matcher of request body.
+#[derive(Clone)]
+pub enum BodyMatcher {
+    /// Body is equal to the value.
+    Exact(JsonValue),
+    /// Body contains all properties of the value. Objects are matched
+    /// recursively, other properties of the body are ignored. Arrays
+    /// must have the same length and match item by item.
+    Subset(JsonValue),
+    /// Body satisfies the predicate.
+    Predicate(Arc<dyn Fn(&JsonValue) -> bool + Send + Sync>),
+}
+
+impl BodyMatcher {
+    pub fn exact(body: impl Display) -> Self {
+        Self::Exact(from_str(&body.to_string()).expect("invalid json"))
+    }
+
+    pub fn subset(body: impl Display) -> Self {
+        Self::Subset(from_str(&body.to_string()).expect("invalid json"))
+    }
+
+    pub fn predicate(f: impl Fn(&JsonValue) -> bool + Send + Sync + 'static) -> Self {
+        Self::Predicate(Arc::new(f))
+    }
+
+    /// Body of the request is matched.
+    #[must_use]
+    pub fn matches(&self, body: &JsonValue) -> bool {
+        match self {
+            Self::Exact(expected) => expected == body,
+            Self::Subset(expected) => is_subset(expected, body),
+            Self::Predicate(f) => f(body),
+        }
+    }
+}
+
+impl Debug for BodyMatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Exact(body) => f.debug_tuple("Exact").field(body).finish(),
+            Self::Subset(body) => f.debug_tuple("Subset").field(body).finish(),
+            Self::Predicate(_) => f.write_str("Predicate"),
+        }
+    }
+}
+
+fn is_subset(expected: &JsonValue, body: &JsonValue) -> bool {
+    match (expected, body) {
+        (JsonValue::Object(expected), JsonValue::Object(body)) => expected
+            .iter()
+            .all(|(k, v)| body.get(k).is_some_and(|b| is_subset(v, b))),
+        (JsonValue::Array(expected), JsonValue::Array(body)) => {
+            expected.len() == body.len() && expected.iter().zip(body).all(|(v, b)| is_subset(v, b))
+        }
+        (expected, body) => expected == body,
+    }
+}
+
+/// Status of response to POST or DELETE.
+#[derive(Debug, Clone)]
+pub enum ResponseStatus {
+    /// `200 OK` with the response of the expectation as body.
+    Ok,
+    /// `201 Created` with `Location` of the created resource and the
+    /// response of the expectation as body.
+    Created { location: ODataId },
+    /// `202 Accepted` with `Location` of the task monitor (see
+    /// `TaskMonitor::task`).
+    Accepted(AsyncTask),
+    /// `204 No Content`.
+    NoContent,
+}
+
+impl ResponseStatus {
+    /// `201 Created` with `Location` of the created resource.
+    pub fn created(location: impl Display) -> Self {
+        Self::Created {
+            location: location.to_string().into(),
+        }
+    }
+}
+
+/// Item of simulated SSE stream.
+#[derive(Debug, Clone)]
+pub enum SseItem {
+    /// Event with JSON payload.
+    Event(JsonValue),
+    /// Connection to the BMC is lost: the stream ends with
+    /// `Error::StreamDisconnected` and items after it are not
+    /// delivered.
+    Disconnect,
+}
+
+impl SseItem {
+    pub fn event(payload: impl Display) -> Self {
+        Self::Event(from_str(&payload.to_string()).expect("invalid json"))
+    }
+}
+
+/// Fault injected before the response to a request.
+#[derive(Debug, Clone)]
+pub enum Fault {
+    /// Response is delayed. Request is then matched against the
+    /// following expectations.
+    Latency(Duration),
+    /// BMC answers `503 Service Unavailable` with optional
+    /// `Retry-After`.
+    ServiceUnavailable { retry_after: Option<Duration> },
+    /// Connection is reset before the response is received.
+    ConnectionReset,
 }
 
 /// Expectation for the tests.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Expect<E> {
     pub request: ExpectedRequest,
     pub response: Response<E>,
 }
 
+impl<E> Expect<E> {
+    /// Attach `etag` to the response (as `@odata.etag` property and,
+    /// by `HttpServer`, as `ETag` header).
+    #[must_use]
+    pub fn with_etag(mut self, etag: impl Display) -> Self {
+        if let Ok(JsonValue::Object(response)) = &mut self.response {
+            response.insert("@odata.etag".into(), etag.to_string().into());
+        }
+        self
+    }
+
+    /// Answer POST or DELETE with `status`. Other expectations are
+    /// not changed.
+    #[must_use]
+    pub fn with_status(mut self, status: ResponseStatus) -> Self {
+        match &mut self.request {
+            ExpectedRequest::Post { status: s, .. } | ExpectedRequest::Delete { status: s, .. } => {
+                *s = status;
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Respond with `response` instead of the default response of
+    /// the expectation.
+    #[must_use]
+    pub fn with_response(self, response: impl Display) -> Self {
+        Self {
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            ..self
+        }
+    }
+}
+
+impl<E: Clone> Expect<E> {
+    /// Expect the same request `n` times.
+    pub fn times(self, n: usize) -> impl Iterator<Item = Self> {
+        repeat_n(self, n)
+    }
+}
+
 impl<E> Expect<E> {
     pub fn get(uri: impl Display, response: impl Display) -> Self {
         Expect {
@@ -113,10 +379,59 @@ impl<E> Expect<E> {
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
     }
+    pub fn head(uri: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Head {
+                id: uri.to_string().into(),
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
     pub fn expand(uri: impl Display, response: impl Display) -> Self {
         Expect {
             request: ExpectedRequest::Expand {
                 id: uri.to_string().into(),
+                query: None,
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+    pub fn expand_with_query(
+        uri: impl Display,
+        query: &ExpandQuery,
+        response: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::Expand {
+                id: uri.to_string().into(),
+                query: Some(query.to_query_string()),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+    pub fn page(uri: impl Display, query: &PageQuery, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Page {
+                id: uri.to_string().into(),
+                query: query.to_query_string(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+    pub fn filter(uri: impl Display, query: &FilterQuery, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Filter {
+                id: uri.to_string().into(),
+                query: query.to_query_string(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+    pub fn select(uri: impl Display, query: &SelectQuery, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Select {
+                id: uri.to_string().into(),
+                query: query.to_query_string(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
         }
@@ -131,6 +446,19 @@ impl<E> Expect<E> {
         }
     }
 
+    /// Expect PATCH of `uri` with body that satisfies `body`. The
+    /// request is answered with no body unless response is set with
+    /// `with_response`.
+    pub fn patch(uri: impl Display, body: BodyMatcher) -> Self {
+        Expect {
+            request: ExpectedRequest::Patch {
+                id: uri.to_string().into(),
+                body,
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+
     pub fn update_task(uri: impl Display, request: impl Display, task: AsyncTask) -> Self {
         Expect {
             request: ExpectedRequest::UpdateTask {
@@ -152,6 +480,37 @@ impl<E> Expect<E> {
         }
     }
 
+    pub fn update_with_method(
+        uri: impl Display,
+        method: UpdateMethod,
+        request: impl Display,
+        response: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::UpdateWithMethod {
+                id: uri.to_string().into(),
+                request: from_str(&request.to_string()).expect("invalid json"),
+                method,
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+
+    pub fn update_with_method_empty(
+        uri: impl Display,
+        method: UpdateMethod,
+        request: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::UpdateWithMethodEmpty {
+                id: uri.to_string().into(),
+                request: from_str(&request.to_string()).expect("invalid json"),
+                method,
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+
     pub fn create(uri: impl Display, request: impl Display, response: impl Display) -> Self {
         Expect {
             request: ExpectedRequest::Create {
@@ -259,10 +618,27 @@ impl<E> Expect<E> {
         }
     }
 
+    /// Expect POST to `uri` (collection or action target) with body
+    /// that satisfies `body`. The request is answered with `204 No
+    /// Content` unless status is set with `with_status`.
+    pub fn post(uri: impl Display, body: BodyMatcher) -> Self {
+        Expect {
+            request: ExpectedRequest::Post {
+                uri: uri.to_string(),
+                body,
+                status: ResponseStatus::NoContent,
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+
+    /// Expect DELETE of `uri`. The request is answered with `204 No
+    /// Content` unless status is set with `with_status`.
     pub fn delete(uri: impl Display) -> Self {
         Expect {
             request: ExpectedRequest::Delete {
                 id: uri.to_string().into(),
+                status: ResponseStatus::NoContent,
             },
             response: Ok(JsonValue::Null),
         }
@@ -278,6 +654,57 @@ impl<E> Expect<E> {
         }
     }
 
+    /// Expect SSE stream at `uri` that delivers `events` in order.
+    pub fn sse(uri: impl Display, events: impl IntoIterator<Item = SseItem>) -> Self {
+        Expect {
+            request: ExpectedRequest::Sse {
+                uri: uri.to_string(),
+                events: events.into_iter().collect(),
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+
+    /// Expect any request to `uri` that is answered with `fault`.
+    /// Faults can be interleaved with other expectations to
+    /// simulate intermittent failures.
+    pub fn fault(uri: impl Display, fault: Fault) -> Self {
+        Expect {
+            request: ExpectedRequest::Fault {
+                uri: uri.to_string(),
+                fault,
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+
+    /// Expect multipart upload to `uri` with parts named `parts`
+    /// answered with `response`.
+    pub fn multipart_upload<S: Display>(
+        uri: impl Display,
+        parts: impl IntoIterator<Item = S>,
+        response: impl Display,
+    ) -> Self {
+        Expect {
+            request: ExpectedRequest::MultipartUpload {
+                uri: uri.to_string(),
+                parts: parts.into_iter().map(|p| p.to_string()).collect(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+        }
+    }
+
+    /// Expect download of `uri` that returns `body`.
+    pub fn download(uri: impl Display, body: impl Into<Vec<u8>>) -> Self {
+        Expect {
+            request: ExpectedRequest::Download {
+                uri: uri.to_string(),
+                body: body.into(),
+            },
+            response: Ok(JsonValue::Null),
+        }
+    }
+
     pub fn stream(uri: impl Display, response: impl Display) -> Self {
         Expect {
             request: ExpectedRequest::Stream {
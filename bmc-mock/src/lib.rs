@@ -13,25 +13,60 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod coverage;
 pub mod expect;
+pub mod mockup;
+pub mod scenario;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod task;
 
+#[doc(inline)]
+pub use coverage::Coverage;
+#[doc(inline)]
+pub use expect::BodyMatcher;
 #[doc(inline)]
 pub use expect::Expect;
+pub use expect::ExpectOrder;
 pub use expect::ExpectedRequest;
+pub use expect::Fault;
+#[doc(inline)]
+pub use expect::ResponseStatus;
+pub use expect::SseItem;
+#[doc(inline)]
+pub use mockup::Mockup;
+pub use mockup::MockupError;
+#[doc(inline)]
+pub use scenario::Scenario;
+#[doc(inline)]
+pub use scenario::ScenarioError;
+#[cfg(feature = "server")]
+#[doc(inline)]
+pub use server::HttpServer;
+#[doc(inline)]
+pub use task::TaskMonitor;
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::error::Error as StdError;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::PoisonError;
+use std::time::Duration;
 
+use coverage::CoverageRecorder;
+use futures_util::StreamExt as _;
 use nv_redfish_core::action::ActionTarget;
 use nv_redfish_core::query::ExpandQuery;
 use nv_redfish_core::ActionError;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc as NvRedfishBmc;
+use nv_redfish_core::BmcErrorInfo;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::Expandable;
 #[cfg(feature = "update-service-deprecated")]
@@ -41,11 +76,15 @@ use nv_redfish_core::MultipartUpdateRequest;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
 use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::SseBody as _;
+use nv_redfish_core::SseError;
+use nv_redfish_core::UpdateMethod;
 use nv_redfish_core::UploadReader;
 use serde::Serialize;
 use serde_json::from_value;
 use serde_json::to_value;
 use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
 
 #[derive(Debug)]
 pub enum Error {
@@ -55,16 +94,29 @@ pub enum Error {
     NothingIsExpected,
     BadResponseJson(JsonError),
     UnexpectedGet(ODataId, ExpectedRequest),
+    UnexpectedHead(ODataId, ExpectedRequest),
     UnexpectedExpand(ODataId, ExpectedRequest),
+    UnexpectedPage(ODataId, String, ExpectedRequest),
+    UnexpectedFilter(ODataId, String, ExpectedRequest),
+    UnexpectedSelect(ODataId, String, ExpectedRequest),
     UnexpectedUpdate(ODataId, String, ExpectedRequest),
+    UnexpectedUpdateWithMethod(ODataId, UpdateMethod, String, ExpectedRequest),
     UnexpectedCreate(ODataId, String, ExpectedRequest),
     UnexpectedCreateSession(ODataId, String, ExpectedRequest),
     UnexpectedDelete(ODataId, ExpectedRequest),
     UnexpectedAction(ActionTarget, String, ExpectedRequest),
     UnexpectedMultipartUpdate(String, String, String, ExpectedRequest),
+    UnexpectedMultipartUpload(String, Vec<String>, ExpectedRequest),
     #[cfg(feature = "update-service-deprecated")]
     UnexpectedHttpPushUriUpdate(String, ExpectedRequest),
     UnexpectedStream(String, ExpectedRequest),
+    UnexpectedDownload(String, ExpectedRequest),
+    StreamDisconnected,
+    SseEventTooLarge(usize),
+    ServiceUnavailable(Option<Duration>),
+    ConnectionReset,
+    MissingIfMatch(ODataId),
+    PreconditionFailed(ODataId, String, String),
 }
 
 impl Display for Error {
@@ -80,15 +132,42 @@ impl Display for Error {
             Self::UnexpectedGet(id, expected) => {
                 write!(f, "unexpected get: {id}; expected: {expected:?}")
             }
+            Self::UnexpectedHead(id, expected) => {
+                write!(f, "unexpected head: {id}; expected: {expected:?}")
+            }
             Self::UnexpectedExpand(id, expected) => {
                 write!(f, "unexpected expand: {id}; expected: {expected:?}")
             }
+            Self::UnexpectedPage(id, query, expected) => {
+                write!(
+                    f,
+                    "unexpected page: {id}; query: {query} expected: {expected:?}"
+                )
+            }
+            Self::UnexpectedFilter(id, query, expected) => {
+                write!(
+                    f,
+                    "unexpected filter: {id}; query: {query} expected: {expected:?}"
+                )
+            }
+            Self::UnexpectedSelect(id, query, expected) => {
+                write!(
+                    f,
+                    "unexpected select: {id}; query: {query} expected: {expected:?}"
+                )
+            }
             Self::UnexpectedUpdate(id, json, expected) => {
                 write!(
                     f,
                     "unexpected update: {id}; json: {json} expected: {expected:?}"
                 )
             }
+            Self::UnexpectedUpdateWithMethod(id, method, json, expected) => {
+                write!(
+                    f,
+                    "unexpected update: {id}; method: {method:?} json: {json} expected: {expected:?}"
+                )
+            }
             Self::UnexpectedCreate(id, json, expected) => {
                 write!(
                     f,
@@ -116,6 +195,12 @@ impl Display for Error {
                     "unexpected multipart update: {uri}; json: {json}; file: {file}; expected: {expected:?}"
                 )
             }
+            Self::UnexpectedMultipartUpload(uri, parts, expected) => {
+                write!(
+                    f,
+                    "unexpected multipart upload: {uri}; parts: {parts:?} expected: {expected:?}"
+                )
+            }
             #[cfg(feature = "update-service-deprecated")]
             Self::UnexpectedHttpPushUriUpdate(uri, expected) => {
                 write!(
@@ -126,6 +211,22 @@ impl Display for Error {
             Self::UnexpectedStream(uri, expected) => {
                 write!(f, "unexpected stream: {uri}; expected: {expected:?}")
             }
+            Self::UnexpectedDownload(uri, expected) => {
+                write!(f, "unexpected download: {uri}; expected: {expected:?}")
+            }
+            Self::StreamDisconnected => write!(f, "stream disconnected"),
+            Self::SseEventTooLarge(limit) => write!(f, "SSE event exceeded {limit} bytes"),
+            Self::ServiceUnavailable(retry_after) => {
+                write!(f, "service unavailable; retry after: {retry_after:?}")
+            }
+            Self::ConnectionReset => write!(f, "connection reset"),
+            Self::MissingIfMatch(id) => write!(f, "missing If-Match: {id}"),
+            Self::PreconditionFailed(id, etag, expected) => {
+                write!(
+                    f,
+                    "precondition failed: {id}; If-Match: {etag} expected: {expected}"
+                )
+            }
         }
     }
 }
@@ -138,24 +239,94 @@ impl Error {
     }
 }
 
+/// Function that sleeps for injected latency.
+type Sleep = dyn Fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
+
 pub struct Bmc<E> {
     expect: Mutex<VecDeque<Expect<E>>>,
+    order: ExpectOrder,
+    mockup: Mockup,
+    sleep: Option<Arc<Sleep>>,
+    if_match: bool,
+    etags: Mutex<HashMap<String, String>>,
+    allowed_methods: Mutex<HashMap<ODataId, AllowedMethods>>,
+    coverage: CoverageRecorder,
 }
 
 impl<E> Default for Bmc<E> {
     fn default() -> Self {
         Self {
             expect: Mutex::default(),
+            order: ExpectOrder::default(),
+            mockup: Mockup::default(),
+            sleep: None,
+            if_match: false,
+            etags: Mutex::default(),
+            allowed_methods: Mutex::default(),
+            coverage: CoverageRecorder::default(),
         }
     }
 }
 
 impl<E> Bmc<E> {
+    /// Create BMC that serves GET, HEAD and expand requests from
+    /// `mockup`. Expectations can be added as usual; an expectation
+    /// that matches the request takes precedence over the mockup.
+    pub fn with_mockup(mockup: Mockup) -> Self {
+        Self {
+            mockup,
+            ..Self::default()
+        }
+    }
+
+    /// Set order in which expectations must be met. Default is
+    /// `ExpectOrder::Strict`.
+    #[must_use]
+    pub fn with_order(mut self, order: ExpectOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Report methods `allow` (for example, `"GET, HEAD, PATCH"`) in
+    /// the `Allow` header of GET responses of resource `id`.
+    pub fn allow(&self, id: impl Display, allow: &str) {
+        self.allowed_methods
+            .lock()
+            .expect("not poisoned")
+            .insert(id.to_string().into(), AllowedMethods::from_header(allow));
+    }
+
+    /// Methods reported in the `Allow` header for resource `id`.
+    pub(crate) fn allowed(&self, id: &ODataId) -> Option<AllowedMethods> {
+        self.allowed_methods
+            .lock()
+            .expect("not poisoned")
+            .get(id)
+            .copied()
+    }
+
     pub fn expect(&self, exp: Expect<E>) {
         let expect: &mut VecDeque<Expect<E>> = &mut self.expect.lock().expect("not poisoned");
         expect.push_back(exp);
     }
 
+    /// Add expectations of `scenario` in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if steps of the scenario refer to unknown
+    /// resources.
+    pub fn expect_scenario(&self, scenario: &Scenario) -> Result<(), ScenarioError> {
+        self.expect_all(scenario.expectations()?);
+        Ok(())
+    }
+
+    /// Add `exps` in order, for example, polls of `TaskMonitor`.
+    pub fn expect_all(&self, exps: impl IntoIterator<Item = Expect<E>>) {
+        let expect: &mut VecDeque<Expect<E>> = &mut self.expect.lock().expect("not poisoned");
+        expect.extend(exps);
+    }
+
     pub fn debug_expect(&self) {
         let expect: &VecDeque<Expect<E>> = &self.expect.lock().expect("not poisoned");
         println!("Expectations (total: {})", expect.len());
@@ -163,6 +334,174 @@ impl<E> Bmc<E> {
             println!("{:#?}", v.request);
         }
     }
+
+    /// Sleep with `sleep` for `Fault::Latency`. Without it latency
+    /// is not simulated.
+    #[must_use]
+    pub fn with_sleep<F>(mut self, sleep: F) -> Self
+    where
+        F: Fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync + 'static,
+    {
+        self.sleep = Some(Arc::new(sleep));
+        self
+    }
+
+    /// Require `If-Match` on updates and deletes of resources that
+    /// were served with ETag (`@odata.etag` of the response, see
+    /// `Expect::with_etag`). Its value must be the last served ETag
+    /// of the resource.
+    #[must_use]
+    pub const fn with_if_match(mut self) -> Self {
+        self.if_match = true;
+        self
+    }
+
+    /// Check that all expected requests were issued.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the list of expected requests that were never
+    /// issued.
+    pub fn verify(&self) {
+        let expect: &VecDeque<Expect<E>> = &self.expect.lock().expect("not poisoned");
+        let missing = expect.iter().map(|v| &v.request).collect::<Vec<_>>();
+        assert!(
+            missing.is_empty(),
+            "expected requests were not issued (total: {}): {missing:#?}",
+            missing.len()
+        );
+    }
+
+    /// Next expectation for request to `uri`.
+    pub(crate) fn next_expect(&self, uri: impl Display) -> Option<Expect<E>> {
+        let mut expect = self.expect.lock().expect("not poisoned");
+        let pos = self.position(&expect, &uri.to_string());
+        let next = expect.remove(pos);
+        if let Some(Ok(response)) = next.as_ref().map(|v| &v.response) {
+            self.served(&uri.to_string(), response);
+        }
+        next
+    }
+
+    /// Properties of payloads served so far (see `coverage` module).
+    pub fn coverage(&self) -> Coverage {
+        self.coverage.coverage()
+    }
+
+    /// Remember ETag and properties of served `response` to request
+    /// to `uri`.
+    fn served(&self, uri: &str, response: &JsonValue) {
+        self.coverage.record(response);
+        if let Some(etag) = response.get("@odata.etag").and_then(JsonValue::as_str) {
+            let id = response
+                .get("@odata.id")
+                .and_then(JsonValue::as_str)
+                .unwrap_or(uri);
+            self.etags
+                .lock()
+                .expect("not poisoned")
+                .insert(id.trim_end_matches('/').into(), etag.into());
+        }
+    }
+
+    /// Error of `If-Match` of update or delete of `uri` if it is
+    /// required (see `with_if_match`) and doesn't match.
+    pub(crate) fn if_match_error(
+        &self,
+        uri: impl Display,
+        if_match: Option<String>,
+    ) -> Option<Error> {
+        if !self.if_match {
+            return None;
+        }
+        let uri = uri.to_string();
+        let etags = self.etags.lock().expect("not poisoned");
+        match (etags.get(uri.trim_end_matches('/')), if_match) {
+            (Some(_), None) => Some(Error::MissingIfMatch(uri.into())),
+            (Some(expected), Some(etag)) if *expected != etag => Some(Error::PreconditionFailed(
+                uri.into(),
+                etag,
+                expected.clone(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Fault injected into the next request to `uri`.
+    pub(crate) fn next_fault(&self, uri: impl Display) -> Option<Fault> {
+        let uri = uri.to_string();
+        let mut expect = self.expect.lock().expect("not poisoned");
+        let pos = self.position(&expect, &uri);
+        match expect.get(pos) {
+            Some(Expect {
+                request: ExpectedRequest::Fault { uri: fault_uri, .. },
+                ..
+            }) if *fault_uri == uri => match expect.remove(pos) {
+                Some(Expect {
+                    request: ExpectedRequest::Fault { fault, .. },
+                    ..
+                }) => Some(fault),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Apply faults injected into the request to `uri`.
+    async fn inject_faults(&self, uri: impl Display) -> Result<(), Error> {
+        let uri = uri.to_string();
+        while let Some(fault) = self.next_fault(&uri) {
+            match fault {
+                Fault::Latency(latency) => {
+                    if let Some(sleep) = &self.sleep {
+                        sleep(latency).await;
+                    }
+                }
+                Fault::ServiceUnavailable { retry_after } => {
+                    return Err(Error::ServiceUnavailable(retry_after))
+                }
+                Fault::ConnectionReset => return Err(Error::ConnectionReset),
+            }
+        }
+        Ok(())
+    }
+
+    /// Position of the expectation that is met by request to `uri`.
+    /// If no expectation is for `uri`, it is the first one so request
+    /// is reported as unexpected.
+    fn position(&self, expect: &VecDeque<Expect<E>>, uri: &str) -> usize {
+        match self.order {
+            ExpectOrder::Strict => 0,
+            ExpectOrder::PerResource => expect
+                .iter()
+                .position(|v| v.request.uri() == uri)
+                .unwrap_or(0),
+        }
+    }
+
+    /// Resource from the mockup that serves the request unless the
+    /// next expectation matches it.
+    pub(crate) fn mockup_response(
+        &self,
+        in_id: &ODataId,
+        expected: impl FnOnce(&ExpectedRequest) -> bool,
+        resource: impl FnOnce(&Mockup, &ODataId) -> Option<JsonValue>,
+    ) -> Option<JsonValue> {
+        if self.mockup.is_empty() {
+            return None;
+        }
+        let expect = self.expect.lock().expect("not poisoned");
+        if expect
+            .get(self.position(&expect, &in_id.to_string()))
+            .is_some_and(|expect| expected(&expect.request))
+        {
+            return None;
+        }
+        drop(expect);
+        let response = resource(&self.mockup, in_id)?;
+        self.served(&in_id.to_string(), &response);
+        Some(response)
+    }
 }
 
 impl<E> NvRedfishBmc for Bmc<E>
@@ -171,21 +510,30 @@ where
 {
     type Error = Error;
 
-    async fn expand<T>(&self, in_id: &ODataId, _query: ExpandQuery) -> Result<Arc<T>, Error>
+    async fn expand<T>(&self, in_id: &ODataId, in_query: ExpandQuery) -> Result<Arc<T>, Error>
     where
         T: Expandable,
     {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_id).await?;
+        let in_query = in_query.to_query_string();
+        let query_matches =
+            |query: &Option<String>| query.as_ref().is_none_or(|query| *query == in_query);
+        if let Some(response) = self.mockup_response(
+            in_id,
+            |request| {
+                matches!(request, ExpectedRequest::Expand { id, query } if id == in_id && query_matches(query))
+            },
+            Mockup::expanded,
+        ) {
+            let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+            return Ok(Arc::new(result));
+        }
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
         match expect {
             Expect {
-                request: ExpectedRequest::Expand { id },
+                request: ExpectedRequest::Expand { id, query },
                 response,
-            } if id == *in_id => {
+            } if id == *in_id && query_matches(&query) => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
                 let result: T = from_value(response).map_err(Error::BadResponseJson)?;
                 Ok(Arc::new(result))
@@ -198,12 +546,16 @@ where
         &self,
         in_id: &ODataId,
     ) -> Result<Arc<T>, Self::Error> {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_id).await?;
+        if let Some(response) = self.mockup_response(
+            in_id,
+            |request| matches!(request, ExpectedRequest::Get { id } if id == in_id),
+            |mockup, id| mockup.get(id).cloned(),
+        ) {
+            let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+            return Ok(Arc::new(result));
+        }
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
         match expect {
             Expect {
                 request: ExpectedRequest::Get { id },
@@ -217,21 +569,59 @@ where
         }
     }
 
+    /// Answered as `304 Not Modified` when the served resource still
+    /// has `etag`.
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> serde::Deserialize<'de> + 'static>(
+        &self,
+        in_id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        let entity = self.get::<T>(in_id).await?;
+        Ok((entity.etag() != Some(etag)).then_some(entity))
+    }
+
+    async fn head(&self, in_id: &ODataId) -> Result<(), Self::Error> {
+        self.inject_faults(in_id).await?;
+        if self
+            .mockup_response(
+                in_id,
+                |request| matches!(request, ExpectedRequest::Head { id } if id == in_id),
+                |mockup, id| mockup.get(id).cloned(),
+            )
+            .is_some()
+        {
+            return Ok(());
+        }
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::Head { id },
+                response,
+            } if id == *in_id => response
+                .map(|_| ())
+                .map_err(|err| Error::ErrorResponse(Box::new(err))),
+            _ => Err(Error::UnexpectedHead(in_id.clone(), expect.request)),
+        }
+    }
+
+    fn allowed_methods(&self, id: &ODataId) -> Option<AllowedMethods> {
+        self.allowed(id)
+    }
+
     async fn update<
         V: Sync + Send + Serialize,
         R: Sync + Send + Sized + for<'de> serde::Deserialize<'de>,
     >(
         &self,
         in_id: &ODataId,
-        _etag: Option<&ODataETag>,
+        etag: Option<&ODataETag>,
         update: &V,
     ) -> Result<ModificationResponse<R>, Self::Error> {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_id).await?;
+        if let Some(err) = self.if_match_error(in_id, etag.map(ToString::to_string)) {
+            return Err(err);
+        }
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
 
         let in_request = to_value(update).expect("json serializable");
 
@@ -252,6 +642,17 @@ where
                 request: ExpectedRequest::UpdateEmpty { id, request },
                 ..
             } if id == *in_id && request == in_request => Ok(ModificationResponse::Empty),
+            Expect {
+                request: ExpectedRequest::Patch { id, body },
+                response,
+            } if id == *in_id && body.matches(&in_request) => {
+                match response.map_err(|err| Error::ErrorResponse(Box::new(err)))? {
+                    JsonValue::Null => Ok(ModificationResponse::Empty),
+                    response => from_value(response)
+                        .map(ModificationResponse::Entity)
+                        .map_err(Error::BadResponseJson),
+                }
+            }
             _ => Err(Error::UnexpectedUpdate(
                 in_id.clone(),
                 in_request.to_string(),
@@ -260,6 +661,61 @@ where
         }
     }
 
+    async fn update_with_method<
+        V: Sync + Send + Serialize,
+        R: Sync + Send + Sized + for<'de> serde::Deserialize<'de>,
+    >(
+        &self,
+        in_id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+        in_method: UpdateMethod,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        if in_method == UpdateMethod::Patch {
+            return self.update(in_id, etag, update).await;
+        }
+        self.inject_faults(in_id).await?;
+        if let Some(err) = self.if_match_error(in_id, etag.map(ToString::to_string)) {
+            return Err(err);
+        }
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
+
+        let in_request = to_value(update).expect("json serializable");
+
+        match expect {
+            Expect {
+                request:
+                    ExpectedRequest::UpdateWithMethod {
+                        id,
+                        request,
+                        method,
+                    },
+                response,
+            } if id == *in_id && request == in_request && method == in_method => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: R = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(ModificationResponse::Entity(result))
+            }
+            Expect {
+                request:
+                    ExpectedRequest::UpdateWithMethodEmpty {
+                        id,
+                        request,
+                        method,
+                    },
+                ..
+            } if id == *in_id && request == in_request && method == in_method => {
+                Ok(ModificationResponse::Empty)
+            }
+            _ => Err(Error::UnexpectedUpdateWithMethod(
+                in_id.clone(),
+                in_method,
+                in_request.to_string(),
+                expect.request,
+            )),
+        }
+    }
+
     async fn create<
         V: Sync + Send + Serialize,
         R: Sync + Send + Sized + for<'de> serde::Deserialize<'de>,
@@ -268,12 +724,8 @@ where
         in_id: &ODataId,
         create: &V,
     ) -> Result<ModificationResponse<R>, Self::Error> {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_id).await?;
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
 
         let in_request = to_value(create).expect("json serializable");
 
@@ -294,6 +746,13 @@ where
                 request: ExpectedRequest::CreateEmpty { id, request },
                 ..
             } if id == *in_id && request == in_request => Ok(ModificationResponse::Empty),
+            Expect {
+                request: ExpectedRequest::Post { uri, body, status },
+                response,
+            } if uri == in_id.to_string() && body.matches(&in_request) => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                status_response(status, response).map_err(Error::BadResponseJson)
+            }
             _ => Err(Error::UnexpectedCreate(
                 in_id.clone(),
                 in_request.to_string(),
@@ -310,12 +769,8 @@ where
         in_id: &ODataId,
         create: &V,
     ) -> Result<SessionCreateResponse<R>, Self::Error> {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_id).await?;
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
         let in_request = to_value(create).expect("json serializable");
         match expect {
             Expect {
@@ -347,18 +802,21 @@ where
     async fn delete<R: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
         &self,
         in_id: &ODataId,
+        etag: Option<&ODataETag>,
     ) -> Result<ModificationResponse<R>, Self::Error> {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_id).await?;
+        if let Some(err) = self.if_match_error(in_id, etag.map(ToString::to_string)) {
+            return Err(err);
+        }
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
         match expect {
             Expect {
-                request: ExpectedRequest::Delete { id },
-                ..
-            } if id == *in_id => Ok(ModificationResponse::Empty),
+                request: ExpectedRequest::Delete { id, status },
+                response,
+            } if id == *in_id => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                status_response(status, response).map_err(Error::BadResponseJson)
+            }
             Expect {
                 request: ExpectedRequest::DeleteTask { id, task },
                 ..
@@ -375,11 +833,9 @@ where
         action: &nv_redfish_core::Action<T, R>,
         params: &T,
     ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.inject_faults(&action.target).await?;
         let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
+            .next_expect(&action.target)
             .ok_or(Error::NothingIsExpected)?;
         let in_request = to_value(params).expect("json serializable");
         match expect {
@@ -391,6 +847,13 @@ where
                 let result: R = from_value(response).map_err(Error::BadResponseJson)?;
                 Ok(ModificationResponse::Entity(result))
             }
+            Expect {
+                request: ExpectedRequest::Post { uri, body, status },
+                response,
+            } if uri == action.target.as_str() && body.matches(&in_request) => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                status_response(status, response).map_err(Error::BadResponseJson)
+            }
             _ => Err(Error::UnexpectedAction(
                 action.target.clone(),
                 in_request.to_string(),
@@ -409,12 +872,8 @@ where
         R: Send + Sync + for<'de> serde::Deserialize<'de>,
         V: Send + Sync + Serialize,
     {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_uri).await?;
+        let expect = self.next_expect(in_uri).ok_or(Error::NothingIsExpected)?;
 
         let MultipartUpdateRequest {
             update_parameters,
@@ -467,12 +926,8 @@ where
         U: UploadReader,
         R: Send + Sync + for<'de> serde::Deserialize<'de>,
     {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_uri).await?;
+        let expect = self.next_expect(in_uri).ok_or(Error::NothingIsExpected)?;
 
         match expect {
             Expect {
@@ -490,24 +945,122 @@ where
         }
     }
 
+    async fn multipart_upload<R>(
+        &self,
+        in_uri: &str,
+        request: nv_redfish_core::MultipartRequest,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        R: Send + Sync + for<'de> serde::Deserialize<'de>,
+    {
+        self.inject_faults(in_uri).await?;
+        let expect = self.next_expect(in_uri).ok_or(Error::NothingIsExpected)?;
+        let in_parts = request
+            .parts
+            .into_iter()
+            .map(|part| part.name)
+            .collect::<Vec<_>>();
+        match expect {
+            Expect {
+                request: ExpectedRequest::MultipartUpload { uri, parts },
+                response,
+            } if uri == *in_uri && parts == in_parts => {
+                match response.map_err(|err| Error::ErrorResponse(Box::new(err)))? {
+                    JsonValue::Null => Ok(ModificationResponse::Empty),
+                    response => from_value(response)
+                        .map(ModificationResponse::Entity)
+                        .map_err(Error::BadResponseJson),
+                }
+            }
+            _ => Err(Error::UnexpectedMultipartUpload(
+                in_uri.to_string(),
+                in_parts,
+                expect.request,
+            )),
+        }
+    }
+
     async fn filter<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
         &self,
-        _id: &ODataId,
-        _query: nv_redfish_core::FilterQuery,
+        in_id: &ODataId,
+        query: nv_redfish_core::FilterQuery,
     ) -> Result<Arc<T>, Self::Error> {
-        todo!("unimplemented")
+        self.inject_faults(in_id).await?;
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
+        let in_query = query.to_query_string();
+        match expect {
+            Expect {
+                request: ExpectedRequest::Filter { id, query },
+                response,
+            } if id == *in_id && query == in_query => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(Arc::new(result))
+            }
+            _ => Err(Error::UnexpectedFilter(
+                in_id.clone(),
+                in_query,
+                expect.request,
+            )),
+        }
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
+        &self,
+        in_id: &ODataId,
+        query: nv_redfish_core::PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.inject_faults(in_id).await?;
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
+        let in_query = query.to_query_string();
+        match expect {
+            Expect {
+                request: ExpectedRequest::Page { id, query },
+                response,
+            } if id == *in_id && query == in_query => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(Arc::new(result))
+            }
+            _ => Err(Error::UnexpectedPage(
+                in_id.clone(),
+                in_query,
+                expect.request,
+            )),
+        }
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
+        &self,
+        in_id: &ODataId,
+        query: nv_redfish_core::SelectQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.inject_faults(in_id).await?;
+        let expect = self.next_expect(in_id).ok_or(Error::NothingIsExpected)?;
+        let in_query = query.to_query_string();
+        match expect {
+            Expect {
+                request: ExpectedRequest::Select { id, query },
+                response,
+            } if id == *in_id && query == in_query => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                Ok(Arc::new(result))
+            }
+            _ => Err(Error::UnexpectedSelect(
+                in_id.clone(),
+                in_query,
+                expect.request,
+            )),
+        }
     }
 
     async fn stream<T: Sized + for<'de> serde::Deserialize<'de> + Send + 'static>(
         &self,
         in_uri: &str,
     ) -> Result<nv_redfish_core::BoxTryStream<T, Self::Error>, Self::Error> {
-        let expect = self
-            .expect
-            .lock()
-            .map_err(Error::mutex_lock)?
-            .pop_front()
-            .ok_or(Error::NothingIsExpected)?;
+        self.inject_faults(in_uri).await?;
+        let expect = self.next_expect(in_uri).ok_or(Error::NothingIsExpected)?;
         match expect {
             Expect {
                 request: ExpectedRequest::Stream { uri },
@@ -519,9 +1072,61 @@ where
                     result.into_iter().map(Ok),
                 )))
             }
+            Expect {
+                request: ExpectedRequest::Sse { uri, events },
+                ..
+            } if uri == *in_uri => {
+                // Events are sent as SSE body and decoded the way
+                // transports without own SSE support do.
+                let mut chunks = Vec::new();
+                for item in events {
+                    match item {
+                        SseItem::Event(payload) => {
+                            chunks.push(Ok(format!("data: {payload}\n\n").into_bytes()));
+                        }
+                        SseItem::Disconnect => {
+                            chunks.push(Err(Error::StreamDisconnected));
+                            break;
+                        }
+                    }
+                }
+                let events = futures_util::stream::iter(chunks)
+                    .sse_events()
+                    .collect::<Vec<_>>()
+                    .await;
+                let mut items = Vec::new();
+                for event in events {
+                    items.push(match event {
+                        Ok(event) => event.json().map_err(Error::BadResponseJson),
+                        Err(SseError::Body(err)) => Err(err),
+                        Err(SseError::EventTooLarge { limit }) => {
+                            Err(Error::SseEventTooLarge(limit))
+                        }
+                    });
+                }
+                Ok(Box::pin(futures_util::stream::iter(items)))
+            }
             _ => Err(Error::UnexpectedStream(in_uri.to_string(), expect.request)),
         }
     }
+
+    async fn download(
+        &self,
+        in_uri: &str,
+    ) -> Result<nv_redfish_core::BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        self.inject_faults(in_uri).await?;
+        let expect = self.next_expect(in_uri).ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::Download { uri, body },
+                ..
+            } if uri == *in_uri => Ok(Box::pin(futures_util::stream::iter([Ok(body)]))),
+            _ => Err(Error::UnexpectedDownload(
+                in_uri.to_string(),
+                expect.request,
+            )),
+        }
+    }
 }
 
 impl ActionError for Error {
@@ -529,3 +1134,62 @@ impl ActionError for Error {
         Error::NotSupported
     }
 }
+
+impl BmcErrorInfo for Error {
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::ServiceUnavailable(_) => Some(503),
+            Self::MissingIfMatch(_) => Some(428),
+            Self::PreconditionFailed(..) => Some(412),
+            _ => None,
+        }
+    }
+
+    fn is_transport(&self) -> bool {
+        matches!(self, Self::ConnectionReset | Self::StreamDisconnected)
+    }
+
+    fn odata_id(&self) -> Option<ODataId> {
+        match self {
+            Self::UnexpectedGet(id, _)
+            | Self::UnexpectedHead(id, _)
+            | Self::UnexpectedExpand(id, _)
+            | Self::UnexpectedPage(id, ..)
+            | Self::UnexpectedFilter(id, ..)
+            | Self::UnexpectedSelect(id, ..)
+            | Self::UnexpectedUpdate(id, ..)
+            | Self::UnexpectedUpdateWithMethod(id, ..)
+            | Self::UnexpectedCreate(id, ..)
+            | Self::UnexpectedCreateSession(id, ..)
+            | Self::UnexpectedDelete(id, _)
+            | Self::MissingIfMatch(id)
+            | Self::PreconditionFailed(id, ..) => Some(id.clone()),
+            _ => None,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::ServiceUnavailable(retry_after) => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+/// Result of POST or DELETE answered with `status` and `response`
+/// body.
+fn status_response<R: for<'de> serde::Deserialize<'de>>(
+    status: ResponseStatus,
+    response: JsonValue,
+) -> Result<ModificationResponse<R>, JsonError> {
+    match (status, response) {
+        (ResponseStatus::Accepted(task), _) => Ok(ModificationResponse::Task(task)),
+        (ResponseStatus::NoContent, _)
+        | (ResponseStatus::Ok | ResponseStatus::Created { .. }, JsonValue::Null) => {
+            Ok(ModificationResponse::Empty)
+        }
+        (ResponseStatus::Ok | ResponseStatus::Created { .. }, response) => {
+            from_value(response).map(ModificationResponse::Entity)
+        }
+    }
+}
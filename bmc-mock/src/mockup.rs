@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resources of DMTF mockup loaded from disk.
+//!
+//! Mockup is a directory tree where each resource is stored in
+//! `index.json` in the directory that matches its path (for example,
+//! `redfish/v1/Systems/1/index.json`). Mockups in short form, that
+//! omit `redfish/v1` directories, are supported as well.
+
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fs;
+use std::io::Error as IoError;
+use std::path::Path;
+use std::path::PathBuf;
+
+use nv_redfish_core::ODataId;
+
+use serde_json::from_slice;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+
+const INDEX_FILE: &str = "index.json";
+const SERVICE_ROOT_DIR: &str = "redfish/v1";
+
+/// Error of mockup loading.
+#[derive(Debug)]
+pub enum MockupError {
+    /// Failed to read directory or file of the mockup.
+    Io(PathBuf, IoError),
+    /// Resource of the mockup is not valid JSON.
+    BadJson(PathBuf, JsonError),
+}
+
+impl Display for MockupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Io(path, err) => write!(f, "failed to read mockup: {}: {err}", path.display()),
+            Self::BadJson(path, err) => {
+                write!(f, "bad json in mockup: {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl StdError for MockupError {}
+
+/// Resources of DMTF mockup indexed by their path.
+#[derive(Debug, Default)]
+pub struct Mockup {
+    resources: HashMap<String, JsonValue>,
+}
+
+impl Mockup {
+    /// Load mockup from `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MockupError::Io` if directory tree cannot be read and
+    /// `MockupError::BadJson` if a resource is not valid JSON.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, MockupError> {
+        let dir = dir.as_ref();
+        let mut mockup = Self::default();
+        if dir.join(SERVICE_ROOT_DIR).join(INDEX_FILE).is_file() {
+            mockup.load_dir(dir, String::new())?;
+        } else {
+            mockup.load_dir(dir, format!("/{SERVICE_ROOT_DIR}"))?;
+        }
+        Ok(mockup)
+    }
+
+    /// Resource with `id`.
+    #[must_use]
+    pub fn get(&self, id: &ODataId) -> Option<&JsonValue> {
+        self.resources.get(id.to_string().trim_end_matches('/'))
+    }
+
+    /// Resource with `id` where members of collection that are
+    /// present in the mockup are expanded.
+    #[must_use]
+    pub fn expanded(&self, id: &ODataId) -> Option<JsonValue> {
+        let mut resource = self.get(id)?.clone();
+        if let Some(JsonValue::Array(members)) = resource.get_mut("Members") {
+            for member in members {
+                let expanded = member
+                    .get("@odata.id")
+                    .and_then(JsonValue::as_str)
+                    .and_then(|id| self.resources.get(id.trim_end_matches('/')));
+                if let Some(expanded) = expanded {
+                    *member = expanded.clone();
+                }
+            }
+        }
+        Some(resource)
+    }
+
+    /// Add resource with `id` or replace existing one.
+    pub fn insert(&mut self, id: impl Display, resource: JsonValue) {
+        let id = id.to_string();
+        self.resources
+            .insert(id.trim_end_matches('/').into(), resource);
+    }
+
+    /// Remove resource with `id`.
+    pub fn remove(&mut self, id: &ODataId) -> Option<JsonValue> {
+        self.resources.remove(id.to_string().trim_end_matches('/'))
+    }
+
+    /// Number of resources in the mockup.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Mockup doesn't contain any resource.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    fn load_dir(&mut self, dir: &Path, path: String) -> Result<(), MockupError> {
+        let entries = fs::read_dir(dir).map_err(|err| MockupError::Io(dir.into(), err))?;
+        for entry in entries {
+            let entry = entry.map_err(|err| MockupError::Io(dir.into(), err))?;
+            let entry_path = entry.path();
+            if entry_path.is_dir() {
+                let name = entry.file_name();
+                self.load_dir(&entry_path, format!("{path}/{}", name.to_string_lossy()))?;
+            } else if entry.file_name() == INDEX_FILE {
+                let content = fs::read(&entry_path)
+                    .map_err(|err| MockupError::Io(entry_path.clone(), err))?;
+                let resource = from_slice(&content)
+                    .map_err(|err| MockupError::BadJson(entry_path.clone(), err))?;
+                self.resources.insert(path.clone(), resource);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,393 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multi-step test scenarios described in JSON.
+//!
+//! Scenario consists of initial `resources` of the BMC and `steps`.
+//! Each step is either an expected request or a change of the state
+//! of resources. Requests that don't specify a response are answered
+//! with the current state of the resource, and writes change the
+//! state, so workflows (for example, firmware update orchestration)
+//! can be described without repeating payloads:
+//!
+//! ```json
+//! {
+//!   "resources": {
+//!     "/redfish/v1/UpdateService/FirmwareInventory/BMC": { "Version": "1.0" }
+//!   },
+//!   "steps": [
+//!     { "get": { "uri": "/redfish/v1/UpdateService/FirmwareInventory/BMC" } },
+//!     { "action": {
+//!         "uri": "/redfish/v1/UpdateService/Actions/UpdateService.SimpleUpdate",
+//!         "body": { "ImageURI": "http://images/bmc-2.0.bin" } } },
+//!     { "task": {
+//!         "uri": "/redfish/v1/TaskService/Tasks/1",
+//!         "states": [{ "running": 50 }, "completed"] } },
+//!     { "set": {
+//!         "uri": "/redfish/v1/UpdateService/FirmwareInventory/BMC",
+//!         "value": { "Version": "2.0" } } },
+//!     { "get": { "uri": "/redfish/v1/UpdateService/FirmwareInventory/BMC" } }
+//!   ]
+//! }
+//! ```
+//!
+//! Changes of the state (`set`, `patch` and `delete` steps) are
+//! applied as JSON merge patch (RFC 7396).
+
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fs;
+use std::io::Error as IoError;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use nv_redfish_core::AsyncTask;
+use nv_redfish_core::ODataId;
+
+use serde::Deserialize;
+use serde_json::from_str;
+use serde_json::Error as JsonError;
+use serde_json::Map;
+use serde_json::Value as JsonValue;
+
+use crate::BodyMatcher;
+use crate::Expect;
+use crate::Fault;
+use crate::Mockup;
+use crate::TaskMonitor;
+
+/// Error of scenario loading.
+#[derive(Debug)]
+pub enum ScenarioError {
+    /// Failed to read scenario file.
+    Io(PathBuf, IoError),
+    /// Scenario is not valid.
+    BadJson(JsonError),
+    /// Step refers to the resource that is not defined (index of the
+    /// step and URI of the resource).
+    UnknownResource(usize, String),
+}
+
+impl Display for ScenarioError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Io(path, err) => {
+                write!(f, "failed to read scenario: {}: {err}", path.display())
+            }
+            Self::BadJson(err) => write!(f, "bad scenario: {err}"),
+            Self::UnknownResource(step, uri) => {
+                write!(f, "unknown resource in step {step}: {uri}")
+            }
+        }
+    }
+}
+
+impl StdError for ScenarioError {}
+
+/// Test scenario: initial resources and steps.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Initial state of resources by their URI.
+    #[serde(default)]
+    pub resources: Map<String, JsonValue>,
+    /// Steps of the scenario in order.
+    pub steps: Vec<Step>,
+}
+
+/// Step of the scenario.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Step {
+    /// Expected GET of `uri`.
+    Get {
+        uri: String,
+        response: Option<JsonValue>,
+    },
+    /// Expected expand of `uri`. Without response, members of the
+    /// collection that are defined in the state are expanded.
+    Expand {
+        uri: String,
+        response: Option<JsonValue>,
+    },
+    /// Expected PATCH of `uri` with `body` (or with body that contains
+    /// `body` if `subset` is set). The body is applied to the state.
+    Patch {
+        uri: String,
+        body: JsonValue,
+        #[serde(default)]
+        subset: bool,
+        response: Option<JsonValue>,
+        task: Option<TaskRef>,
+    },
+    /// Expected create in collection `uri`.
+    Create {
+        uri: String,
+        body: JsonValue,
+        response: Option<JsonValue>,
+        task: Option<TaskRef>,
+    },
+    /// Expected action with target `uri`.
+    Action {
+        uri: String,
+        body: JsonValue,
+        response: Option<JsonValue>,
+    },
+    /// Expected multipart update at `uri`.
+    MultipartUpdate {
+        uri: String,
+        body: JsonValue,
+        file_name: String,
+        response: Option<JsonValue>,
+    },
+    /// Expected DELETE of `uri`. The resource is removed from the
+    /// state.
+    Delete { uri: String, task: Option<TaskRef> },
+    /// Polls of the task at `uri`, one per state.
+    Task { uri: String, states: Vec<TaskStep> },
+    /// Fault of the next request to `uri`.
+    Fault { uri: String, fault: FaultStep },
+    /// Change of the state of the resource at `uri` (for example,
+    /// made by completed task).
+    Set { uri: String, value: JsonValue },
+}
+
+/// Task returned with `202 Accepted`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TaskRef {
+    /// Location of the task monitor.
+    pub uri: String,
+    /// `Retry-After` in seconds.
+    pub retry_after_secs: Option<u64>,
+}
+
+/// State of the task reported on poll.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStep {
+    /// Task is running with percent complete.
+    Running(u32),
+    /// Task is completed.
+    Completed,
+    /// Task is in final failed state (for example, `Exception`).
+    Failed(String),
+}
+
+/// Fault injected into request.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultStep {
+    /// Response is delayed by milliseconds.
+    LatencyMs(u64),
+    /// `503 Service Unavailable` with optional `Retry-After` in
+    /// seconds.
+    Unavailable(Option<u64>),
+    /// Connection is reset.
+    Reset,
+}
+
+impl Scenario {
+    /// Load scenario from JSON file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScenarioError::Io` if file cannot be read and
+    /// `ScenarioError::BadJson` if it is not valid scenario.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError> {
+        let path = path.as_ref();
+        let content =
+            fs::read_to_string(path).map_err(|err| ScenarioError::Io(path.into(), err))?;
+        Self::from_json(&content)
+    }
+
+    /// Parse scenario from JSON `content`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScenarioError::BadJson` if `content` is not valid
+    /// scenario.
+    pub fn from_json(content: &str) -> Result<Self, ScenarioError> {
+        from_str(content).map_err(ScenarioError::BadJson)
+    }
+
+    /// Expectations of the scenario in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScenarioError::UnknownResource` if a request without
+    /// response refers to resource that is not in the state.
+    pub fn expectations<E>(&self) -> Result<Vec<Expect<E>>, ScenarioError> {
+        let mut state = Mockup::default();
+        for (uri, resource) in &self.resources {
+            state.insert(uri, resource.clone());
+        }
+        let mut result = Vec::new();
+        for (index, step) in self.steps.iter().enumerate() {
+            match step {
+                Step::Get { uri, response } => {
+                    let response = match response {
+                        Some(response) => response.clone(),
+                        None => state
+                            .get(&ODataId::from(uri.clone()))
+                            .cloned()
+                            .ok_or_else(|| ScenarioError::UnknownResource(index, uri.clone()))?,
+                    };
+                    result.push(Expect::get(uri, response));
+                }
+                Step::Expand { uri, response } => {
+                    let response = match response {
+                        Some(response) => response.clone(),
+                        None => state
+                            .expanded(&ODataId::from(uri.clone()))
+                            .ok_or_else(|| ScenarioError::UnknownResource(index, uri.clone()))?,
+                    };
+                    result.push(Expect::expand(uri, response));
+                }
+                Step::Patch {
+                    uri,
+                    body,
+                    subset,
+                    response,
+                    task,
+                } => {
+                    if let Some(resource) = state.get(&ODataId::from(uri.clone())) {
+                        state.insert(uri, merge(resource.clone(), body));
+                    }
+                    result.push(match (task, response) {
+                        (Some(task), _) => Expect::update_task(uri, body, task.task()),
+                        (None, response) => {
+                            let matcher = if *subset {
+                                BodyMatcher::Subset(body.clone())
+                            } else {
+                                BodyMatcher::Exact(body.clone())
+                            };
+                            let expect = Expect::patch(uri, matcher);
+                            match response {
+                                Some(response) => expect.with_response(response),
+                                None => expect,
+                            }
+                        }
+                    });
+                }
+                Step::Create {
+                    uri,
+                    body,
+                    response,
+                    task,
+                } => result.push(match (task, response) {
+                    (Some(task), _) => Expect::create_task(uri, body, task.task()),
+                    (None, Some(response)) => Expect::create(uri, body, response),
+                    (None, None) => Expect::create_empty(uri, body),
+                }),
+                Step::Action {
+                    uri,
+                    body,
+                    response,
+                } => result.push(Expect::action(
+                    uri,
+                    body,
+                    response.as_ref().unwrap_or(&JsonValue::Null),
+                )),
+                Step::MultipartUpdate {
+                    uri,
+                    body,
+                    file_name,
+                    response,
+                } => result.push(Expect::multipart_update(
+                    uri,
+                    body,
+                    file_name,
+                    response.as_ref().unwrap_or(&JsonValue::Null),
+                )),
+                Step::Delete { uri, task } => {
+                    state.remove(&ODataId::from(uri.clone()));
+                    result.push(match task {
+                        Some(task) => Expect::delete_task(uri, task.task()),
+                        None => Expect::delete(uri),
+                    });
+                }
+                Step::Task { uri, states } => {
+                    let monitor =
+                        states
+                            .iter()
+                            .fold(TaskMonitor::new(uri), |monitor, state| match state {
+                                TaskStep::Running(percent) => monitor.running(*percent),
+                                TaskStep::Completed => monitor.completed(),
+                                TaskStep::Failed(state) => monitor.failed(state),
+                            });
+                    result.extend(monitor.polls());
+                }
+                Step::Fault { uri, fault } => result.push(Expect::fault(uri, fault.fault())),
+                Step::Set { uri, value } => {
+                    let resource = state
+                        .get(&ODataId::from(uri.clone()))
+                        .cloned()
+                        .unwrap_or(JsonValue::Null);
+                    state.insert(uri, merge(resource, value));
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl TaskRef {
+    fn task(&self) -> AsyncTask {
+        let monitor = TaskMonitor::new(&self.uri);
+        match self.retry_after_secs {
+            Some(secs) => monitor.retry_after(Duration::from_secs(secs)),
+            None => monitor,
+        }
+        .task()
+    }
+}
+
+impl FaultStep {
+    const fn fault(&self) -> Fault {
+        match self {
+            Self::LatencyMs(ms) => Fault::Latency(Duration::from_millis(*ms)),
+            Self::Unavailable(retry_after) => Fault::ServiceUnavailable {
+                retry_after: match retry_after {
+                    Some(secs) => Some(Duration::from_secs(*secs)),
+                    None => None,
+                },
+            },
+            Self::Reset => Fault::ConnectionReset,
+        }
+    }
+}
+
+/// Apply JSON merge `patch` to `target`.
+fn merge(target: JsonValue, patch: &JsonValue) -> JsonValue {
+    match (target, patch) {
+        (JsonValue::Object(mut target), JsonValue::Object(patch)) => {
+            for (k, v) in patch {
+                if v.is_null() {
+                    target.remove(k);
+                } else {
+                    let current = target.remove(k).unwrap_or(JsonValue::Null);
+                    target.insert(k.clone(), merge(current, v));
+                }
+            }
+            JsonValue::Object(target)
+        }
+        (JsonValue::Null, JsonValue::Object(_)) => merge(JsonValue::Object(Map::new()), patch),
+        (_, patch) => patch.clone(),
+    }
+}
@@ -0,0 +1,569 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process Redfish HTTP server for end-to-end tests.
+//!
+//! [`HttpServer`] serves expectations and mockup of a mock [`Bmc`]
+//! over HTTP, so HTTP based BMC implementations can be tested
+//! including headers, authentication and ETags:
+//!
+//! ```ignore
+//! let bmc = Arc::new(Bmc::with_mockup(Mockup::load("mockups/contoso")?));
+//! let server = HttpServer::start_with_required_header(
+//!     bmc.clone(),
+//!     "Authorization",
+//!     "Basic YWRtaW46c2VjcmV0",
+//! )
+//! .await;
+//! bmc.expect(Expect::update_empty("/redfish/v1/Systems/1", json!({ "AssetTag": "A" })));
+//! // Connect HTTP BMC to `server.uri()`...
+//! ```
+//!
+//! Requests are matched against expectations of the BMC in order,
+//! the same way as requests of the in-process mock. Responses with
+//! `@odata.etag` are sent with `ETag` header. Requests that don't
+//! match are answered with `500 Internal Server Error` and the
+//! description of the mismatch. If the BMC requires `If-Match` (see
+//! `Bmc::with_if_match`), writes without it are answered with `428
+//! Precondition Required` and writes with stale ETag with `412
+//! Precondition Failed`.
+//!
+//! Injected faults are served as well: `Fault::Latency` delays the
+//! response and `Fault::ServiceUnavailable` is answered with `503
+//! Service Unavailable`. The server cannot reset the connection, so
+//! `Fault::ConnectionReset` is answered with `500 Internal Server
+//! Error`.
+
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+use std::str::from_utf8;
+use std::sync::Arc;
+use std::time::Duration;
+
+use nv_redfish_core::AsyncTask;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::UpdateMethod;
+
+use serde_json::from_slice;
+use serde_json::Value as JsonValue;
+use wiremock::matchers::any;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::Request;
+use wiremock::Respond;
+use wiremock::ResponseTemplate;
+
+use crate::Bmc;
+use crate::Error;
+use crate::Expect;
+use crate::ExpectedRequest;
+use crate::Fault;
+use crate::ResponseStatus;
+use crate::SseItem;
+
+/// Response or description of the request mismatch.
+type ServeResult = Result<ResponseTemplate, Box<Error>>;
+
+const METHOD_OVERRIDE_HEADER: &str = "x-http-method-override";
+
+/// HTTP server that serves expectations and mockup of a mock BMC.
+pub struct HttpServer {
+    server: MockServer,
+}
+
+impl HttpServer {
+    /// Start server on a random local port that serves requests
+    /// with `bmc`.
+    pub async fn start<E>(bmc: Arc<Bmc<E>>) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        Self::start_with_headers(bmc, BTreeMap::new()).await
+    }
+
+    /// Start server that answers `401 Unauthorized` to requests
+    /// without header `name` equal to `value`, for example,
+    /// `Authorization` or `X-Auth-Token`.
+    pub async fn start_with_required_header<E>(
+        bmc: Arc<Bmc<E>>,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        Self::start_with_headers(bmc, BTreeMap::from([(name.into(), value.into())])).await
+    }
+
+    async fn start_with_headers<E>(bmc: Arc<Bmc<E>>, required: BTreeMap<String, String>) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(Responder { bmc, required })
+            .mount(&server)
+            .await;
+        Self { server }
+    }
+
+    /// Base URI of the server, for example, `http://127.0.0.1:40000`.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Requests received by the server so far, including their
+    /// headers.
+    pub async fn received_requests(&self) -> Vec<Request> {
+        self.server.received_requests().await.unwrap_or_default()
+    }
+}
+
+/// Modification request received by the server.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Modification {
+    /// Update with PATCH or with POST with method override header.
+    Update(UpdateMethod),
+    /// POST without method override header: create, action or
+    /// update with POST.
+    Post,
+    Delete,
+}
+
+impl Modification {
+    fn is_update_with(self, method: UpdateMethod) -> bool {
+        match self {
+            Self::Update(update) => update == method,
+            Self::Post => method == UpdateMethod::Post,
+            Self::Delete => false,
+        }
+    }
+}
+
+struct Responder<E> {
+    bmc: Arc<Bmc<E>>,
+    required: BTreeMap<String, String>,
+}
+
+impl<E> Respond for Responder<E>
+where
+    E: StdError + Send + Sync + 'static,
+{
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let authorized = self.required.iter().all(|(name, value)| {
+            request
+                .headers
+                .get(name.as_str())
+                .is_some_and(|header| header.as_bytes() == value.as_bytes())
+        });
+        if !authorized {
+            return ResponseTemplate::new(401);
+        }
+        self.serve(request)
+            .unwrap_or_else(|err| ResponseTemplate::new(500).set_body_string(err.to_string()))
+    }
+}
+
+impl<E> Responder<E>
+where
+    E: StdError + Send + Sync + 'static,
+{
+    fn serve(&self, request: &Request) -> ServeResult {
+        let in_id = ODataId::from(request.url.path().to_string());
+        let mut latency = Duration::ZERO;
+        while let Some(fault) = self.bmc.next_fault(&in_id) {
+            match fault {
+                Fault::Latency(delay) => latency += delay,
+                Fault::ServiceUnavailable { retry_after } => {
+                    return Ok(unavailable(retry_after).set_delay(latency))
+                }
+                Fault::ConnectionReset => return Err(Error::ConnectionReset.into()),
+            }
+        }
+        self.dispatch(request, in_id)
+            .map(|template| template.set_delay(latency))
+    }
+
+    fn dispatch(&self, request: &Request, in_id: ODataId) -> ServeResult {
+        let body = if request.body.is_empty() {
+            JsonValue::Null
+        } else {
+            from_slice(&request.body).map_err(Error::BadResponseJson)?
+        };
+        let query = request.url.query().map(decode_query);
+        let conditional = matches!(request.method.as_str(), "PATCH" | "DELETE")
+            || request.headers.contains_key(METHOD_OVERRIDE_HEADER);
+        if conditional {
+            let if_match = request
+                .headers
+                .get("If-Match")
+                .and_then(|v| v.to_str().ok())
+                .map(ToString::to_string);
+            match self.bmc.if_match_error(&in_id, if_match) {
+                None => {}
+                Some(err @ Error::MissingIfMatch(_)) => {
+                    return Ok(ResponseTemplate::new(428).set_body_string(err.to_string()))
+                }
+                Some(err) => return Ok(ResponseTemplate::new(412).set_body_string(err.to_string())),
+            }
+        }
+        match request.method.as_str() {
+            "GET" => {
+                let allow = self.bmc.allowed(&in_id);
+                self.get(in_id, query).map(|template| match allow {
+                    Some(allow) => template.insert_header("Allow", allow.to_string().as_str()),
+                    None => template,
+                })
+            }
+            "HEAD" => self.head(in_id),
+            "PATCH" => self.modify(in_id, body, Modification::Update(UpdateMethod::Patch)),
+            "POST" if request.headers.contains_key(METHOD_OVERRIDE_HEADER) => self.modify(
+                in_id,
+                body,
+                Modification::Update(UpdateMethod::PostWithMethodOverride),
+            ),
+            "POST" => self.modify(in_id, body, Modification::Post),
+            "DELETE" => self.modify(in_id, body, Modification::Delete),
+            _ => Err(Error::NotSupported.into()),
+        }
+    }
+
+    fn get(&self, in_id: ODataId, query: Option<String>) -> ServeResult {
+        let is_expand = query.as_ref().is_some_and(|q| q.starts_with("$expand"));
+        let is_page = query
+            .as_ref()
+            .is_some_and(|q| q.starts_with("$top") || q.starts_with("$skip"));
+        let is_select = query.as_ref().is_some_and(|q| q.starts_with("$select"));
+        let is_filter = query.as_ref().is_some_and(|q| q.starts_with("$filter"));
+        if !is_page && !is_select && !is_filter {
+            let mockup = self.bmc.mockup_response(
+                &in_id,
+                |request| match request {
+                    ExpectedRequest::Get { id } if !is_expand => *id == in_id,
+                    ExpectedRequest::Expand {
+                        id,
+                        query: expected,
+                    } if is_expand => *id == in_id && expand_query_matches(expected, &query),
+                    _ => false,
+                },
+                |mockup, id| {
+                    if is_expand {
+                        mockup.expanded(id)
+                    } else {
+                        mockup.get(id).cloned()
+                    }
+                },
+            );
+            if let Some(resource) = mockup {
+                return Ok(entity(200, resource));
+            }
+        }
+        let expect = self
+            .bmc
+            .next_expect(&in_id)
+            .ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::Get { id },
+                response,
+            } if id == in_id && !is_expand && !is_page && !is_select && !is_filter => {
+                self.entity(200, response)
+            }
+            Expect {
+                request:
+                    ExpectedRequest::Expand {
+                        id,
+                        query: expected,
+                    },
+                response,
+            } if id == in_id && is_expand && expand_query_matches(&expected, &query) => {
+                self.entity(200, response)
+            }
+            Expect {
+                request:
+                    ExpectedRequest::Page {
+                        id,
+                        query: expected,
+                    },
+                response,
+            } if id == in_id && query.as_deref() == Some(expected.as_str()) => {
+                self.entity(200, response)
+            }
+            Expect {
+                request:
+                    ExpectedRequest::Select {
+                        id,
+                        query: expected,
+                    }
+                    | ExpectedRequest::Filter {
+                        id,
+                        query: expected,
+                    },
+                response,
+            } if id == in_id && query.as_deref() == Some(expected.as_str()) => {
+                self.entity(200, response)
+            }
+            Expect {
+                request: ExpectedRequest::Stream { uri },
+                response,
+            } if uri == in_id.to_string() => {
+                let events = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                Ok(event_stream(&events))
+            }
+            Expect {
+                request: ExpectedRequest::Sse { uri, events },
+                ..
+            } if uri == in_id.to_string() => {
+                // Connection is closed by the server at disconnect.
+                let events = events
+                    .into_iter()
+                    .map_while(|item| match item {
+                        SseItem::Event(payload) => Some(payload),
+                        SseItem::Disconnect => None,
+                    })
+                    .collect();
+                Ok(event_stream(&JsonValue::Array(events)))
+            }
+            _ if is_page => {
+                Err(Error::UnexpectedPage(in_id, query.unwrap_or_default(), expect.request).into())
+            }
+            _ if is_select => {
+                Err(
+                    Error::UnexpectedSelect(in_id, query.unwrap_or_default(), expect.request)
+                        .into(),
+                )
+            }
+            _ if is_filter => {
+                Err(
+                    Error::UnexpectedFilter(in_id, query.unwrap_or_default(), expect.request)
+                        .into(),
+                )
+            }
+            _ if is_expand => Err(Error::UnexpectedExpand(in_id, expect.request).into()),
+            _ => Err(Error::UnexpectedGet(in_id, expect.request).into()),
+        }
+    }
+
+    fn head(&self, in_id: ODataId) -> ServeResult {
+        let mockup = self.bmc.mockup_response(
+            &in_id,
+            |request| matches!(request, ExpectedRequest::Head { id } if *id == in_id),
+            |mockup, id| mockup.get(id).cloned(),
+        );
+        if mockup.is_some() {
+            return Ok(ResponseTemplate::new(200));
+        }
+        let expect = self
+            .bmc
+            .next_expect(&in_id)
+            .ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::Head { id },
+                response,
+            } if id == in_id => {
+                response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                Ok(ResponseTemplate::new(200))
+            }
+            _ => Err(Error::UnexpectedHead(in_id, expect.request).into()),
+        }
+    }
+
+    /// Serve PATCH, POST and DELETE requests.
+    fn modify(
+        &self,
+        in_id: ODataId,
+        in_request: JsonValue,
+        modification: Modification,
+    ) -> ServeResult {
+        let Expect { request, response } = self
+            .bmc
+            .next_expect(&in_id)
+            .ok_or(Error::NothingIsExpected)?;
+        let matches = |id: &ODataId, request: &JsonValue| *id == in_id && *request == in_request;
+        let patch = modification == Modification::Update(UpdateMethod::Patch);
+        let post = modification == Modification::Post;
+        let delete = modification == Modification::Delete;
+        match request {
+            ExpectedRequest::Update { id, request } if patch && matches(&id, &request) => {
+                self.entity(200, response)
+            }
+            ExpectedRequest::UpdateEmpty { id, request } if patch && matches(&id, &request) => {
+                Ok(ResponseTemplate::new(204))
+            }
+            ExpectedRequest::Patch { id, body }
+                if patch && id == in_id && body.matches(&in_request) =>
+            {
+                match response {
+                    Ok(JsonValue::Null) => Ok(ResponseTemplate::new(204)),
+                    response => self.entity(200, response),
+                }
+            }
+            ExpectedRequest::UpdateTask { id, request, task }
+                if patch && matches(&id, &request) =>
+            {
+                Ok(accepted(&task))
+            }
+            ExpectedRequest::UpdateWithMethod {
+                id,
+                request,
+                method,
+            } if modification.is_update_with(method) && matches(&id, &request) => {
+                self.entity(200, response)
+            }
+            ExpectedRequest::UpdateWithMethodEmpty {
+                id,
+                request,
+                method,
+            } if modification.is_update_with(method) && matches(&id, &request) => {
+                Ok(ResponseTemplate::new(204))
+            }
+            ExpectedRequest::Create { id, request } if post && matches(&id, &request) => {
+                self.entity(201, response)
+            }
+            ExpectedRequest::CreateEmpty { id, request } if post && matches(&id, &request) => {
+                Ok(ResponseTemplate::new(204))
+            }
+            ExpectedRequest::CreateTask { id, request, task } if post && matches(&id, &request) => {
+                Ok(accepted(&task))
+            }
+            ExpectedRequest::CreateSession {
+                id,
+                request,
+                auth_token,
+                location,
+            } if post && matches(&id, &request) => Ok(self
+                .entity(201, response)?
+                .insert_header("X-Auth-Token", auth_token.as_str())
+                .insert_header("Location", location.to_string().as_str())),
+            ExpectedRequest::Action { target, request }
+                if post && target.as_str() == in_id.to_string() && request == in_request =>
+            {
+                self.entity(200, response)
+            }
+            ExpectedRequest::Post { uri, body, status }
+                if post && uri == in_id.to_string() && body.matches(&in_request) =>
+            {
+                self.status(status, response)
+            }
+            ExpectedRequest::Delete { id, status } if delete && id == in_id => {
+                self.status(status, response)
+            }
+            ExpectedRequest::DeleteTask { id, task } if delete && id == in_id => {
+                Ok(accepted(&task))
+            }
+            request if delete => Err(Error::UnexpectedDelete(in_id, request).into()),
+            request => Err(Error::UnexpectedUpdate(in_id, in_request.to_string(), request).into()),
+        }
+    }
+
+    /// Response with `status` and body `response` (no body if it is
+    /// `null`).
+    fn status(&self, status: ResponseStatus, response: Result<JsonValue, E>) -> ServeResult {
+        let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+        Ok(match (status, response) {
+            (ResponseStatus::Accepted(task), _) => accepted(&task),
+            (ResponseStatus::NoContent, _) => ResponseTemplate::new(204),
+            (ResponseStatus::Ok, JsonValue::Null) => ResponseTemplate::new(200),
+            (ResponseStatus::Ok, body) => entity(200, body),
+            (ResponseStatus::Created { location }, body) => {
+                let template = match body {
+                    JsonValue::Null => ResponseTemplate::new(201),
+                    body => entity(201, body),
+                };
+                template.insert_header("Location", location.to_string().as_str())
+            }
+        })
+    }
+
+    fn entity(&self, status: u16, response: Result<JsonValue, E>) -> ServeResult {
+        response
+            .map(|body| entity(status, body))
+            .map_err(|err| Error::ErrorResponse(Box::new(err)).into())
+    }
+}
+
+fn entity(status: u16, body: JsonValue) -> ResponseTemplate {
+    let template = ResponseTemplate::new(status);
+    let template = match body.get("@odata.etag").and_then(JsonValue::as_str) {
+        Some(etag) => template.insert_header("ETag", etag),
+        None => template,
+    };
+    template.set_body_json(body)
+}
+
+fn accepted(task: &AsyncTask) -> ResponseTemplate {
+    let template =
+        ResponseTemplate::new(202).insert_header("Location", task.location.0.to_string().as_str());
+    match task.retry_after {
+        Some(retry_after) => {
+            template.insert_header("Retry-After", retry_after.as_secs().to_string().as_str())
+        }
+        None => template,
+    }
+}
+
+fn unavailable(retry_after: Option<Duration>) -> ResponseTemplate {
+    let template = ResponseTemplate::new(503);
+    match retry_after {
+        Some(retry_after) => {
+            template.insert_header("Retry-After", retry_after.as_secs().to_string().as_str())
+        }
+        None => template,
+    }
+}
+
+/// Server-sent events stream with each item of JSON array `events`
+/// as a separate event.
+fn event_stream(events: &JsonValue) -> ResponseTemplate {
+    let events = match events {
+        JsonValue::Array(events) => events.iter().collect(),
+        event => vec![event],
+    };
+    let body = events
+        .into_iter()
+        .map(|event| format!("data: {event}\n\n"))
+        .collect::<String>();
+    ResponseTemplate::new(200).set_body_raw(body, "text/event-stream")
+}
+
+/// Whether `query` of request matches expand query of expectation.
+/// Expectation without query matches any expand query.
+fn expand_query_matches(expected: &Option<String>, query: &Option<String>) -> bool {
+    expected.is_none() || expected == query
+}
+
+/// Query string with `%XX` escapes decoded, so it can be compared
+/// with queries of expectations.
+fn decode_query(query: &str) -> String {
+    let bytes = query.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
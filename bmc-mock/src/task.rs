@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Simulated lifecycle of asynchronous task.
+//!
+//! Operation that completes asynchronously is answered with `202
+//! Accepted` pointing to the task (see `TaskMonitor::task`). Each
+//! following poll of the task returns its next state (see
+//! `TaskMonitor::polls`).
+
+use std::fmt::Display;
+use std::time::Duration;
+
+use nv_redfish_core::AsyncTask;
+use nv_redfish_core::ODataId;
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::Expect;
+
+const TASK_DATA_TYPE: &str = "#Task.v1_4_3.Task";
+
+/// Task that passes through a sequence of states on successive polls.
+#[derive(Debug, Clone)]
+pub struct TaskMonitor {
+    location: ODataId,
+    retry_after: Option<Duration>,
+    states: Vec<(String, &'static str, u32)>,
+}
+
+impl TaskMonitor {
+    /// Task located at `location` with no states.
+    pub fn new(location: impl Display) -> Self {
+        Self {
+            location: location.to_string().into(),
+            retry_after: None,
+            states: Vec::new(),
+        }
+    }
+
+    /// `Retry-After` reported with `202 Accepted` response.
+    #[must_use]
+    pub const fn retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after = Some(retry_after);
+        self
+    }
+
+    /// Task is reported `Running` with `percent_complete` on the next
+    /// poll.
+    #[must_use]
+    pub fn running(mut self, percent_complete: u32) -> Self {
+        self.states.push(("Running".into(), "OK", percent_complete));
+        self
+    }
+
+    /// Task is reported `Completed` on the next poll.
+    #[must_use]
+    pub fn completed(mut self) -> Self {
+        self.states.push(("Completed".into(), "OK", 100));
+        self
+    }
+
+    /// Task is reported in final `state` (for example, `Exception` or
+    /// `Killed`) on the next poll.
+    #[must_use]
+    pub fn failed(mut self, state: impl Display) -> Self {
+        let percent_complete = self.states.last().map_or(0, |(_, _, percent)| *percent);
+        self.states
+            .push((state.to_string(), "Critical", percent_complete));
+        self
+    }
+
+    /// Task returned in `202 Accepted` response.
+    #[must_use]
+    pub fn task(&self) -> AsyncTask {
+        AsyncTask {
+            location: self.location.clone().into(),
+            retry_after: self.retry_after,
+        }
+    }
+
+    /// Expected polls of the task, one per state.
+    #[must_use]
+    pub fn polls<E>(&self) -> Vec<Expect<E>> {
+        self.states
+            .iter()
+            .map(|(state, status, percent_complete)| {
+                Expect::get(
+                    &self.location,
+                    self.payload(state, status, *percent_complete),
+                )
+            })
+            .collect()
+    }
+
+    fn payload(&self, state: &str, status: &str, percent_complete: u32) -> JsonValue {
+        let id = self.location.to_string();
+        let id = id.rsplit('/').next().unwrap_or_default();
+        json!({
+            "@odata.id": &self.location,
+            "@odata.type": TASK_DATA_TYPE,
+            "Id": id,
+            "Name": format!("Task {id}"),
+            "TaskState": state,
+            "TaskStatus": status,
+            "PercentComplete": percent_complete,
+        })
+    }
+}
@@ -0,0 +1,333 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf
+)]
+#![deny(
+    clippy::absolute_paths,
+    clippy::todo,
+    clippy::unimplemented,
+    clippy::tests_outside_test_module,
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::unwrap_in_result,
+    clippy::unused_trait_names,
+    clippy::print_stdout,
+    clippy::print_stderr
+)]
+#![deny(missing_docs)]
+
+//! Ready to use implementation of [`nv_redfish_core::Bmc`] on top of
+//! reqwest.
+//!
+//! It combines [`HttpBmc`] with reqwest [`Client`] and takes care of
+//! authentication: HTTP Basic, Redfish session or an existing session
+//! token.
+//!
+//! ```rust,no_run
+//! use nv_redfish_reqwest::Auth;
+//! use nv_redfish_reqwest::Builder;
+//! use std::time::Duration;
+//! use url::Url;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let connection = Builder::new(
+//!     Url::parse("https://192.168.1.100")?,
+//!     Auth::session("admin", "password"),
+//! )
+//! .timeout(Duration::from_secs(30))
+//! .connect()
+//! .await?;
+//!
+//! let bmc = connection.bmc();
+//! // Use `bmc` with `nv_redfish::ServiceRoot::new(bmc)`.
+//!
+//! connection.close().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::HeaderValue;
+use nv_redfish_core::ODataId;
+use url::Url;
+
+#[doc(inline)]
+pub use nv_redfish_bmc_http::reqwest::BmcError;
+#[doc(inline)]
+pub use nv_redfish_bmc_http::reqwest::Client;
+#[doc(inline)]
+pub use nv_redfish_bmc_http::reqwest::ClientParams;
+#[doc(inline)]
+pub use nv_redfish_bmc_http::BmcCredentials;
+#[doc(inline)]
+pub use nv_redfish_bmc_http::CacheSettings;
+#[doc(inline)]
+pub use nv_redfish_bmc_http::HttpBmc;
+
+/// BMC accessed with reqwest HTTP client.
+pub type ReqwestBmc = HttpBmc<Client>;
+
+/// Authentication used to access the BMC.
+///
+/// `Debug` redacts secrets.
+#[derive(Clone)]
+pub enum Auth {
+    /// HTTP Basic authentication for every request.
+    Basic {
+        /// Username to access BMC.
+        username: String,
+        /// Password to access BMC.
+        password: String,
+    },
+    /// Redfish session created on connect. Token of the session is
+    /// used for all requests.
+    Session {
+        /// Username to access BMC.
+        username: String,
+        /// Password to access BMC.
+        password: String,
+    },
+    /// Token of an already existing Redfish session.
+    Token(String),
+}
+
+impl Auth {
+    /// HTTP Basic authentication.
+    pub fn basic(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::Basic {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Redfish session authentication.
+    pub fn session(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::Session {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Existing Redfish session token.
+    pub fn token(token: impl Into<String>) -> Self {
+        Self::Token(token.into())
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Basic { username, .. } => f
+                .debug_struct("Auth::Basic")
+                .field("username", username)
+                .field("password", &"[REDACTED]")
+                .finish(),
+            Self::Session { username, .. } => f
+                .debug_struct("Auth::Session")
+                .field("username", username)
+                .field("password", &"[REDACTED]")
+                .finish(),
+            Self::Token(_) => f.debug_tuple("Auth::Token").field(&"[REDACTED]").finish(),
+        }
+    }
+}
+
+/// Errors of connecting to the BMC.
+#[derive(Debug)]
+pub enum Error {
+    /// HTTP client cannot be built.
+    Client(reqwest::Error),
+    /// Request to the BMC failed.
+    Bmc(BmcError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Client(err) => write!(f, "HTTP client build error: {err}"),
+            Self::Bmc(err) => write!(f, "BMC error: {err}"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Client(err) => Some(err),
+            Self::Bmc(err) => Some(err),
+        }
+    }
+}
+
+/// Builder of the connection to the BMC.
+pub struct Builder {
+    endpoint: Url,
+    auth: Auth,
+    params: ClientParams,
+    cache_settings: CacheSettings,
+    accept_language: Option<HeaderValue>,
+}
+
+impl Builder {
+    /// Create builder for BMC at `endpoint` with default
+    /// [`ClientParams`] and [`CacheSettings`].
+    #[must_use]
+    pub fn new(endpoint: Url, auth: Auth) -> Self {
+        Self {
+            endpoint,
+            auth,
+            params: ClientParams::default(),
+            cache_settings: CacheSettings::default(),
+            accept_language: None,
+        }
+    }
+
+    /// Replace all HTTP client parameters.
+    #[must_use]
+    pub fn params(self, params: ClientParams) -> Self {
+        Self { params, ..self }
+    }
+
+    /// Accept invalid TLS certificates. BMCs often use self-signed
+    /// certificates.
+    #[must_use]
+    pub fn accept_invalid_certs(self, accept: bool) -> Self {
+        self.map_params(|params| params.accept_invalid_certs(accept))
+    }
+
+    /// Timeout of a single HTTP request.
+    #[must_use]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.map_params(|params| params.timeout(timeout))
+    }
+
+    /// Timeout of TCP connection establishment.
+    #[must_use]
+    pub fn connect_timeout(self, timeout: Duration) -> Self {
+        self.map_params(|params| params.connect_timeout(timeout))
+    }
+
+    /// Maximum number of idle pooled connections to the BMC.
+    #[must_use]
+    pub fn pool_max_idle(self, max: usize) -> Self {
+        self.map_params(|params| params.pool_max_idle_per_host(max))
+    }
+
+    /// Time after which idle pooled connection is closed.
+    #[must_use]
+    pub fn pool_idle_timeout(self, timeout: Duration) -> Self {
+        self.map_params(|params| params.idle_timeout(timeout))
+    }
+
+    /// Settings of the response cache.
+    #[must_use]
+    pub fn cache_settings(self, cache_settings: CacheSettings) -> Self {
+        Self {
+            cache_settings,
+            ..self
+        }
+    }
+
+    /// Language of human-readable strings returned by the BMC, such
+    /// as messages of Redfish errors. See
+    /// [`HttpBmc::with_accept_language`].
+    #[must_use]
+    pub fn accept_language(self, language: HeaderValue) -> Self {
+        Self {
+            accept_language: Some(language),
+            ..self
+        }
+    }
+
+    /// Build the HTTP client and authenticate. Session is created
+    /// only for [`Auth::Session`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Client`] if the HTTP client cannot be built
+    /// and [`Error::Bmc`] if the session cannot be created.
+    pub async fn connect(self) -> Result<Connection, Error> {
+        let client = Client::with_params(self.params).map_err(Error::Client)?;
+        let credentials = match &self.auth {
+            Auth::Basic { username, password } | Auth::Session { username, password } => {
+                BmcCredentials::new(username.clone(), password.clone())
+            }
+            Auth::Token(token) => BmcCredentials::token(token.clone()),
+        };
+        let bmc = HttpBmc::new(client, self.endpoint, credentials, self.cache_settings);
+        let bmc = Arc::new(match self.accept_language {
+            Some(language) => bmc.with_accept_language(language),
+            None => bmc,
+        });
+        let session = match &self.auth {
+            Auth::Session { username, password } => {
+                Some(bmc.login(username, password).await.map_err(Error::Bmc)?)
+            }
+            Auth::Basic { .. } | Auth::Token(_) => None,
+        };
+        Ok(Connection { bmc, session })
+    }
+
+    fn map_params(self, f: impl FnOnce(ClientParams) -> ClientParams) -> Self {
+        Self {
+            params: f(self.params),
+            ..self
+        }
+    }
+}
+
+/// Authenticated connection to the BMC.
+pub struct Connection {
+    bmc: Arc<ReqwestBmc>,
+    session: Option<ODataId>,
+}
+
+impl Connection {
+    /// BMC to be used with `nv-redfish`.
+    #[must_use]
+    pub fn bmc(&self) -> Arc<ReqwestBmc> {
+        Arc::clone(&self.bmc)
+    }
+
+    /// URI of the session created on connect.
+    #[must_use]
+    pub const fn session(&self) -> Option<&ODataId> {
+        self.session.as_ref()
+    }
+
+    /// Delete the session created on connect, if any. The BMC must
+    /// not be used after this call.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the session cannot be deleted.
+    pub async fn close(self) -> Result<(), BmcError> {
+        match &self.session {
+            Some(session) => self.bmc.logout(session).await,
+            None => Ok(()),
+        }
+    }
+}
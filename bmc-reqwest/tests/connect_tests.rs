@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nv_redfish_reqwest::Auth;
+use nv_redfish_reqwest::Builder;
+use serde_json::json;
+use std::error::Error as StdError;
+use url::Url;
+use wiremock::matchers::body_json;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+const SESSIONS: &str = "/redfish/v1/SessionService/Sessions";
+const SESSION: &str = "/redfish/v1/SessionService/Sessions/1";
+
+#[tokio::test]
+async fn session_auth_creates_and_closes_session() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(SESSIONS))
+        .and(body_json(
+            json!({ "UserName": "admin", "Password": "secret" }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .insert_header("X-Auth-Token", "token")
+                .insert_header("Location", SESSION)
+                .set_body_json(json!({ "@odata.id": SESSION })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(SESSION))
+        .and(header("X-Auth-Token", "token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let connection = Builder::new(Url::parse(&server.uri())?, Auth::session("admin", "secret"))
+        .connect()
+        .await?;
+    assert_eq!(
+        connection.session().map(ToString::to_string).as_deref(),
+        Some(SESSION)
+    );
+    connection.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn basic_auth_does_not_create_session() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let connection = Builder::new(Url::parse(&server.uri())?, Auth::basic("admin", "secret"))
+        .connect()
+        .await?;
+    assert!(connection.session().is_none());
+    connection.close().await?;
+
+    Ok(())
+}
+
+#[test]
+fn auth_debug_redacts_secrets() {
+    let debug = format!("{:?}", Auth::session("admin", "secret"));
+    assert!(debug.contains("admin"));
+    assert!(!debug.contains("secret"));
+}
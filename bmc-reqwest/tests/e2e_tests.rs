@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nv_redfish_bmc_mock::Bmc as MockBmc;
+use nv_redfish_bmc_mock::Expect;
+use nv_redfish_bmc_mock::Fault;
+use nv_redfish_bmc_mock::HttpServer;
+use nv_redfish_core::Bmc as _;
+use nv_redfish_core::BmcErrorInfo as _;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_reqwest::Auth;
+use nv_redfish_reqwest::Builder;
+use serde::Deserialize;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+const SYSTEM: &str = "/redfish/v1/Systems/1";
+
+#[derive(Debug)]
+struct TestError;
+
+impl fmt::Display for TestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "test error")
+    }
+}
+
+impl StdError for TestError {}
+
+#[derive(Deserialize)]
+struct System {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+    #[serde(rename = "@odata.etag")]
+    etag: Option<ODataETag>,
+    #[serde(rename = "AssetTag")]
+    asset_tag: String,
+}
+
+impl EntityTypeRef for System {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        self.etag.as_ref()
+    }
+}
+
+#[tokio::test]
+async fn requests_are_served_over_http() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(MockBmc::<TestError>::default());
+    let server = HttpServer::start_with_required_header(
+        bmc.clone(),
+        "Authorization",
+        "Basic YWRtaW46c2VjcmV0",
+    )
+    .await;
+    let connection = Builder::new(Url::parse(&server.uri())?, Auth::basic("admin", "secret"))
+        .connect()
+        .await?;
+    let http = connection.bmc();
+
+    let id = ODataId::from(SYSTEM.to_string());
+    bmc.expect(Expect::get(
+        SYSTEM,
+        json!({ "@odata.id": SYSTEM, "@odata.etag": "\"1\"", "AssetTag": "A" }),
+    ));
+    let system = http.get::<System>(&id).await?;
+    assert_eq!(system.asset_tag, "A");
+
+    let update = json!({ "AssetTag": "B" });
+    bmc.expect(Expect::update_empty(SYSTEM, &update));
+    let response = http
+        .update::<Value, Value>(&id, system.etag(), &update)
+        .await?;
+    assert!(matches!(response, ModificationResponse::Empty));
+
+    let requests = server.received_requests().await;
+    let patch = requests.last().expect("PATCH request");
+    assert_eq!(patch.method.as_str(), "PATCH");
+    assert_eq!(
+        patch.headers.get("If-Match").map(|v| v.as_bytes()),
+        Some(&b"\"1\""[..])
+    );
+    connection.close().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_credentials_are_rejected() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(MockBmc::<TestError>::default());
+    let server = HttpServer::start_with_required_header(
+        bmc.clone(),
+        "Authorization",
+        "Basic YWRtaW46c2VjcmV0",
+    )
+    .await;
+    let connection = Builder::new(Url::parse(&server.uri())?, Auth::basic("admin", "wrong"))
+        .connect()
+        .await?;
+
+    bmc.expect(Expect::get(SYSTEM, json!({ "@odata.id": SYSTEM })));
+    let result = connection
+        .bmc()
+        .get::<System>(&ODataId::from(SYSTEM.to_string()))
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unavailability_reports_retry_after() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(MockBmc::<TestError>::default());
+    let server = HttpServer::start(bmc.clone()).await;
+    let connection = Builder::new(Url::parse(&server.uri())?, Auth::basic("admin", "secret"))
+        .connect()
+        .await?;
+
+    bmc.expect(Expect::fault(
+        SYSTEM,
+        Fault::ServiceUnavailable {
+            retry_after: Some(Duration::from_secs(2)),
+        },
+    ));
+    let Err(err) = connection
+        .bmc()
+        .get::<System>(&ODataId::from(SYSTEM.to_string()))
+        .await
+    else {
+        return Err("503 response must fail the request".into());
+    };
+    assert_eq!(err.status_code(), Some(503));
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(2)));
+    bmc.verify();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn slow_response_times_out() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(MockBmc::<TestError>::default());
+    let server = HttpServer::start(bmc.clone()).await;
+    let connection = Builder::new(Url::parse(&server.uri())?, Auth::basic("admin", "secret"))
+        .timeout(Duration::from_millis(100))
+        .connect()
+        .await?;
+
+    bmc.expect(Expect::fault(
+        SYSTEM,
+        Fault::Latency(Duration::from_secs(5)),
+    ));
+    bmc.expect(Expect::get(SYSTEM, json!({ "@odata.id": SYSTEM })));
+    let result = connection
+        .bmc()
+        .get::<System>(&ODataId::from(SYSTEM.to_string()))
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -22,9 +22,10 @@
 //! - `T`: request parameters payload type (sent as the POST body when running the action)
 //! - `R`: response type returned by the BMC for that action
 //!
-//! Only the `target` field is deserialized. Any additional metadata
-//! (such as `...@Redfish.AllowableValues`) is ignored by this type
-//! and may be used by higher layers.
+//! Only the `target` field and the `@Redfish.ActionInfo` annotation
+//! are deserialized. Any additional metadata (such as
+//! `...@Redfish.AllowableValues`) is ignored by this type and may be
+//! used by higher layers.
 //!
 //! Example: how an action appears in a Redfish resource and which part maps to [`Action`]
 //!
@@ -33,6 +34,7 @@
 //!   "Actions": {
 //!     "#ComputerSystem.Reset": {
 //!       "target": "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset",
+//!       "@Redfish.ActionInfo": "/redfish/v1/Systems/1/ResetActionInfo",
 //!       "ResetType@Redfish.AllowableValues": [
 //!         "On",
 //!         "GracefulRestart",
@@ -44,11 +46,15 @@
 //! ```
 //!
 //! The [`Action<T, R>`] value corresponds to the inner object of
-//! `"#ComputerSystem.Reset"` and deserializes the `target` field only.
+//! `"#ComputerSystem.Reset"` and deserializes the `target` field and
+//! the reference to the `ActionInfo` resource.
 //!
 
 use crate::Bmc;
+use crate::MaybeSend;
+use crate::MaybeSync;
 use crate::ModificationResponse;
+use crate::ODataId;
 use core::fmt::Debug;
 use core::fmt::Display;
 use core::fmt::Formatter;
@@ -95,7 +101,10 @@ pub struct Action<T, R> {
     /// URI reference used to trigger the action.
     #[serde(rename = "target")]
     pub target: ActionTarget,
-    // TODO: we can retrieve constraints on attributes here.
+    /// Reference to the `ActionInfo` resource that describes
+    /// parameters of the action (`@Redfish.ActionInfo`).
+    #[serde(rename = "@Redfish.ActionInfo", default)]
+    pub action_info: Option<ODataId>,
     /// Establishes a dependency on the `T` (parameters) type.
     #[serde(skip_deserializing)]
     _marker: PhantomData<T>,
@@ -108,6 +117,7 @@ impl<T, R> Debug for Action<T, R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Action")
             .field("target", &self.target)
+            .field("action_info", &self.action_info)
             .finish()
     }
 }
@@ -119,7 +129,11 @@ pub trait ActionError {
     fn not_supported() -> Self;
 }
 
-impl<T: Send + Sync + Serialize, R: Send + Sync + Sized + for<'de> Deserialize<'de>> Action<T, R> {
+impl<
+        T: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    > Action<T, R>
+{
     /// Run specific action with parameters passed as argument.
     ///
     /// URI-reference resolution and outbound request policy are handled by
@@ -150,13 +164,14 @@ mod tests {
     fn debug_does_not_require_parameter_or_result_debug() {
         let action: Action<NotDebug, NotDebug> = Action {
             target: ActionTarget::new("/redfish/v1/Actions/Test".into()),
+            action_info: None,
             _marker: PhantomData,
             _marker_retval: PhantomData,
         };
 
         assert_eq!(
             format!("{action:?}"),
-            "Action { target: ActionTarget(\"/redfish/v1/Actions/Test\") }"
+            "Action { target: ActionTarget(\"/redfish/v1/Actions/Test\"), action_info: None }"
         );
     }
 }
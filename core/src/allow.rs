@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP methods allowed for a resource
+//!
+//! Redfish services report methods supported by a resource in the
+//! `Allow` header of GET and HEAD responses (for example, `"GET,
+//! HEAD, PATCH"`). [`AllowedMethods`] is the parsed form of this
+//! header. It lets clients find out whether a resource can be
+//! updated, deleted or posted to before sending the request.
+
+use crate::Flags;
+use std::fmt;
+use std::ops::BitOr;
+
+/// Set of HTTP methods reported in the `Allow` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AllowedMethods(u64);
+
+impl AllowedMethods {
+    /// `GET` method.
+    pub const GET: Self = Self(1);
+    /// `HEAD` method.
+    pub const HEAD: Self = Self(1 << 1);
+    /// `POST` method.
+    pub const POST: Self = Self(1 << 2);
+    /// `PUT` method.
+    pub const PUT: Self = Self(1 << 3);
+    /// `PATCH` method.
+    pub const PATCH: Self = Self(1 << 4);
+    /// `DELETE` method.
+    pub const DELETE: Self = Self(1 << 5);
+
+    /// Parse value of the `Allow` header. Unknown methods are
+    /// ignored.
+    #[must_use]
+    pub fn from_header(value: &str) -> Self {
+        Self::from_member_names(&value.to_ascii_uppercase())
+    }
+
+    /// Returns `true` if all methods of `other` are allowed.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no method is allowed.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Flags for AllowedMethods {
+    const MEMBERS: &'static [(&'static str, u64)] = &[
+        ("GET", Self::GET.0),
+        ("HEAD", Self::HEAD.0),
+        ("POST", Self::POST.0),
+        ("PUT", Self::PUT.0),
+        ("PATCH", Self::PATCH.0),
+        ("DELETE", Self::DELETE.0),
+    ];
+
+    fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    fn bits(self) -> u64 {
+        self.0
+    }
+}
+
+impl BitOr for AllowedMethods {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl fmt::Display for AllowedMethods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.member_names().join(", ").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_allow_header() {
+        let allowed = AllowedMethods::from_header("GET, HEAD, Patch, OPTIONS");
+        assert!(allowed.contains(AllowedMethods::GET | AllowedMethods::PATCH));
+        assert!(!allowed.contains(AllowedMethods::DELETE));
+        assert_eq!(allowed.to_string(), "GET, HEAD, PATCH");
+        assert!(AllowedMethods::from_header("").is_empty());
+    }
+}
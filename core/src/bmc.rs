@@ -33,49 +33,94 @@
 //!
 //! Operation semantics:
 //! - `get` fetches the entity at the given `@odata.id`.
+//! - `get_if_none_match` fetches the entity unless it still matches the
+//!   given `ETag` (conditional GET with `If-None-Match`).
+//! - `head` checks that the entity at the given `@odata.id` exists and
+//!   is accessible without transferring its body.
+//! - `allowed_methods` returns methods reported in the `Allow` header
+//!   when the entity was retrieved. It doesn't send a request.
 //! - `expand` fetches the entity with the provided `$expand` query.
+//! - `page` fetches members of a collection selected by `$top` / `$skip`.
+//! - `select` fetches only properties listed in the `$select` query.
 //! - `create` typically performs a POST to a collection identified by `id` and
 //!   returns the server-provided representation (`R`).
 //! - `update` typically performs a PATCH on an entity identified by `id` and
 //!   returns the updated representation (`R`).
+//! - `update_with_method` updates an entity with the request selected by
+//!   [`UpdateMethod`] for services that don't accept PATCH.
 //! - `delete` removes the entity at `id`.
 //! - `action` posts to an action endpoint (`Action.target`).
+//! - `multipart_upload` posts a `multipart/form-data` request built from
+//!   streamed parts.
+//! - `download` streams raw bytes of a binary resource (for example, log
+//!   entry `AdditionalDataURI`) without buffering it in memory.
 //!
 //! Notes for implementors:
 //! - The trait is `Send + Sync` and returns `Send` futures to support use in
-//!   async runtimes and multithreaded contexts.
+//!   async runtimes and multithreaded contexts. With the `unsend` feature
+//!   these bounds are dropped (see [`crate::MaybeSend`]), so the trait can be
+//!   implemented on single-threaded targets like `wasm32-unknown-unknown`.
 //! - Implementations may include client-side caching or conditional requests;
 //!   these details are intentionally abstracted behind the trait.
+//! - `get_if_none_match`, `head`, `allowed_methods`, `page`, `select` and
+//!   `update_with_method` have default implementations built on `get` and
+//!   `update`. Override them when the service supports the corresponding
+//!   requests.
 //! - Errors should implement `std::error::Error` and be safely transferable
-//!   across threads.
+//!   across threads. Implementing [`BmcErrorInfo`] lets callers
+//!   classify errors (status code, transport failure) without string
+//!   matching.
 
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::query::ExpandQuery;
 use crate::Action;
+use crate::AllowedMethods;
 use crate::BoxTryStream;
 use crate::EntityTypeRef;
 use crate::Expandable;
 use crate::FilterQuery;
 #[cfg(feature = "update-service-deprecated")]
 use crate::HttpPushUriUpdateRequest;
+use crate::MaybeSend;
+use crate::MaybeSync;
 use crate::ModificationResponse;
 use crate::ODataETag;
 use crate::ODataId;
+use crate::PageQuery;
+use crate::SelectQuery;
 use crate::SessionCreateResponse;
 use std::error::Error as StdError;
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::MultipartRequest;
 use crate::MultipartUpdateRequest;
 use crate::UploadReader;
 
+/// Request used to update an entity.
+///
+/// Redfish updates entities with PATCH. Some services accept only
+/// POST for certain resources or expect the PATCH to be tunneled
+/// through POST with `X-HTTP-Method-Override` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+pub enum UpdateMethod {
+    /// `PATCH` request.
+    #[default]
+    Patch,
+    /// `POST` request with the update as body.
+    Post,
+    /// `POST` request with `X-HTTP-Method-Override: PATCH` header.
+    PostWithMethodOverride,
+}
+
 /// BMC trait defines access to a Baseboard Management Controller using
 /// the Redfish protocol.
-pub trait Bmc: Send + Sync {
+pub trait Bmc: MaybeSend + MaybeSync {
     /// BMC Error.
-    type Error: StdError + Send + Sync;
+    type Error: StdError + MaybeSend + MaybeSync;
 
     /// Expand any expandable object (navigation property or entity).
     ///
@@ -84,7 +129,7 @@ pub trait Bmc: Send + Sync {
         &self,
         id: &ODataId,
         query: ExpandQuery,
-    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + MaybeSend;
 
     /// Get data of the object (navigation property or entity).
     ///
@@ -92,7 +137,47 @@ pub trait Bmc: Send + Sync {
     fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
         &self,
         id: &ODataId,
-    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + MaybeSend;
+
+    /// Get data of the object unless its current `ETag` matches
+    /// `etag` (HTTP `If-None-Match`). Returns `None` if the object is
+    /// not modified (`304 Not Modified`).
+    ///
+    /// The default implementation doesn't send conditional requests
+    /// and always returns the object retrieved with [`Self::get`].
+    ///
+    /// `T` is structure that is used for return type.
+    fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> impl Future<Output = Result<Option<Arc<T>>, Self::Error>> + MaybeSend {
+        let _ = etag;
+        let get = self.get::<T>(id);
+        async move { get.await.map(Some) }
+    }
+
+    /// Check that the object exists and is accessible with current
+    /// credentials without retrieving its body (HTTP `HEAD`).
+    ///
+    /// The default implementation retrieves the object with
+    /// [`Self::get`] and discards it.
+    fn head(&self, id: &ODataId) -> impl Future<Output = Result<(), Self::Error>> + MaybeSend {
+        let get = self.get::<ExistingEntity>(id);
+        async move { get.await.map(|_| ()) }
+    }
+
+    /// Methods allowed for the object according to the `Allow` header
+    /// of the response the object was retrieved with. Returns `None`
+    /// if the object was not retrieved yet or the service doesn't
+    /// report allowed methods.
+    ///
+    /// The default implementation doesn't remember allowed methods
+    /// and always returns `None`.
+    fn allowed_methods(&self, id: &ODataId) -> Option<AllowedMethods> {
+        let _ = id;
+        None
+    }
 
     /// Get and filters data of the object (navigation property or entity).
     ///
@@ -101,45 +186,112 @@ pub trait Bmc: Send + Sync {
         &self,
         id: &ODataId,
         query: FilterQuery,
-    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + MaybeSend;
+
+    /// Get one page of collection members selected by the query.
+    /// Unlike `get`, `Members@odata.nextLink` is not followed.
+    ///
+    /// The default implementation ignores the query and returns the
+    /// collection retrieved with [`Self::get`].
+    ///
+    /// `T` is structure that is used for return type.
+    fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: PageQuery,
+    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + MaybeSend {
+        let _ = query;
+        self.get::<T>(id)
+    }
+
+    /// Get only selected properties of the object.
+    ///
+    /// The default implementation ignores the query and returns the
+    /// whole object retrieved with [`Self::get`].
+    ///
+    /// `T` is structure that is used for return type.
+    fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: SelectQuery,
+    ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + MaybeSend {
+        let _ = query;
+        self.get::<T>(id)
+    }
 
     /// Creates element of the collection.
     ///
     /// `V` is structure that is used for create.
     /// `R` is structure that is used for return type.
-    fn create<V: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>(
+    fn create<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
         &self,
         id: &ODataId,
         query: &V,
-    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend;
 
     /// Creates a Redfish session.
     ///
     /// Session creation is special in Redfish: the response body contains the
     /// session entity, `X-Auth-Token` contains the token used for subsequent
     /// requests, and `Location` contains the URI to delete when logging out.
-    fn create_session<V: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>(
+    fn create_session<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
         &self,
         id: &ODataId,
         query: &V,
-    ) -> impl Future<Output = Result<SessionCreateResponse<R>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<SessionCreateResponse<R>, Self::Error>> + MaybeSend;
 
     /// Update entity.
     ///
     /// `V` is structure that is used for update.
     /// `R` is structure that is used for return type (updated entity).
-    fn update<V: Sync + Send + Serialize, R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
+    fn update<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend;
+
+    /// Update entity with the request selected by `method`.
+    /// `UpdateMethod::Patch` is equivalent to `update`.
+    ///
+    /// The default implementation supports only PATCH: it ignores
+    /// `method` and sends the update with [`Self::update`].
+    ///
+    /// `V` is structure that is used for update.
+    /// `R` is structure that is used for return type (updated entity).
+    fn update_with_method<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
         &self,
         id: &ODataId,
         etag: Option<&ODataETag>,
         update: &V,
-    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send;
+        method: UpdateMethod,
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend {
+        let _ = method;
+        self.update(id, etag, update)
+    }
 
     /// Delete entity.
+    ///
+    /// `etag` is the last known `@odata.etag` of the entity. It is
+    /// sent as a precondition of the delete so that changes made by
+    /// other clients are not silently discarded.
     fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
         &self,
         id: &ODataId,
-    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send;
+        etag: Option<&ODataETag>,
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend;
 
     /// Run action.
     ///
@@ -152,11 +304,14 @@ pub trait Bmc: Send + Sync {
     ///
     /// `T` is structure that contains action parameters.
     /// `R` is structure with return type.
-    fn action<T: Send + Sync + Serialize, R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
+    fn action<
+        T: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
         &self,
         action: &Action<T, R>,
         params: &T,
-    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend;
 
     /// POST a Redfish `UpdateService` multipart upload using a named stream.
     ///
@@ -169,11 +324,28 @@ pub trait Bmc: Send + Sync {
         &self,
         uri: &str,
         request: MultipartUpdateRequest<'_, U, V>,
-    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend
     where
         U: UploadReader,
-        R: Send + Sync + for<'de> Deserialize<'de>,
-        V: Send + Sync + Serialize;
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        V: MaybeSend + MaybeSync + Serialize;
+
+    /// POST a `multipart/form-data` request with the provided parts.
+    ///
+    /// This is the generic form of `multipart_update` for endpoints
+    /// that expect a different set of parts.
+    ///
+    /// `uri` should be resolved as a Redfish URI reference.
+    ///
+    /// Implementations may reject URI references that violate their outbound
+    /// request policy before transport.
+    fn multipart_upload<R>(
+        &self,
+        uri: &str,
+        request: MultipartRequest,
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend
+    where
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>;
 
     /// POST a raw binary stream to a Redfish `UpdateService` `HttpPushUri`.
     ///
@@ -187,10 +359,10 @@ pub trait Bmc: Send + Sync {
         &self,
         uri: &str,
         request: HttpPushUriUpdateRequest<U>,
-    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send
+    ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + MaybeSend
     where
         U: UploadReader,
-        R: Send + Sync + for<'de> Deserialize<'de>;
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>;
 
     /// Stream data for the URI.
     ///
@@ -200,8 +372,76 @@ pub trait Bmc: Send + Sync {
     /// request policy before transport.
     ///
     /// `T` is structure that is used for the stream return type.
-    fn stream<T: Sized + for<'de> Deserialize<'de> + Send + 'static>(
+    ///
+    /// Transports without their own SSE support can decode the body
+    /// returned by `download` with [`crate::SseBody`].
+    fn stream<T: Sized + for<'de> Deserialize<'de> + MaybeSend + 'static>(
+        &self,
+        uri: &str,
+    ) -> impl Future<Output = Result<BoxTryStream<T, Self::Error>, Self::Error>> + MaybeSend;
+
+    /// Download binary data for the URI as a stream of chunks.
+    ///
+    /// This is intended for attachments that are referenced by URI
+    /// rather than being Redfish resources, such as log entry
+    /// `AdditionalDataURI`, certificate exports or diagnostic dumps.
+    /// Data is not buffered in memory and is not cached.
+    ///
+    /// `uri` should be resolved as a Redfish URI reference.
+    ///
+    /// Implementations may reject URI references that violate their outbound
+    /// request policy before transport.
+    fn download(
         &self,
         uri: &str,
-    ) -> impl Future<Output = Result<BoxTryStream<T, Self::Error>, Self::Error>> + Send;
+    ) -> impl Future<Output = Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error>> + MaybeSend;
+}
+
+/// Entity of any type retrieved by the default implementation of
+/// [`Bmc::head`] only to check that it exists.
+#[derive(Deserialize)]
+struct ExistingEntity {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+}
+
+impl EntityTypeRef for ExistingEntity {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
+/// Details of errors of a [`Bmc`] implementation used to classify
+/// them.
+///
+/// All methods have defaults, so implementations provide only
+/// details that their errors carry.
+pub trait BmcErrorInfo {
+    /// HTTP status code of the response that caused the error, if
+    /// the error was caused by an unsuccessful response.
+    fn status_code(&self) -> Option<u16> {
+        None
+    }
+
+    /// Returns `true` if no response was received: connection
+    /// failure, reset or timeout.
+    fn is_transport(&self) -> bool {
+        false
+    }
+
+    /// Identifier of the resource the failed request was sent to.
+    fn odata_id(&self) -> Option<ODataId> {
+        None
+    }
+
+    /// Delay requested by the BMC in the `Retry-After` header of the
+    /// response that caused the error, for example, of `503 Service
+    /// Unavailable`.
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
 }
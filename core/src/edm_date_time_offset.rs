@@ -83,7 +83,7 @@ impl Display for Error {
 impl StdError for Error {}
 
 /// Type corresponding to `Edm.DateTimeOffset`.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct EdmDateTimeOffset(#[serde(with = "time::serde::rfc3339")] OffsetDateTime);
 
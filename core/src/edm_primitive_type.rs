@@ -28,4 +28,6 @@ pub enum EdmPrimitiveType {
     Integer(i64),
     /// Floating point primitive type.
     Decimal(f64),
+    /// Collection of primitive values.
+    Collection(Vec<Self>),
 }
@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support of enum types with `IsFlags="true"`
+//!
+//! Values of such types are combinations of enum members. In JSON
+//! payloads they are represented as a string with comma-separated
+//! member names (for example, `"Read,Write"`). Generated types are
+//! bit sets that implement [`Flags`]; serialization is done by
+//! [`serialize`] and [`deserialize`].
+//!
+//! Member names that are not known by the schema are ignored during
+//! deserialization, the same way as unsupported values of plain
+//! enums are mapped to the fallback variant.
+
+use serde::Deserialize as _;
+use serde::Deserializer;
+use serde::Serializer;
+
+/// Enum type that can hold combination of members.
+pub trait Flags: Sized + Copy {
+    /// Names of members and their values.
+    const MEMBERS: &'static [(&'static str, u64)];
+
+    /// Create value from raw bits.
+    fn from_bits(bits: u64) -> Self;
+
+    /// Raw bits of value.
+    fn bits(self) -> u64;
+
+    /// Names of members contained in the value. Member with zero
+    /// value is only reported when no other bits are set.
+    fn member_names(self) -> Vec<&'static str> {
+        let bits = self.bits();
+        if bits == 0 {
+            Self::MEMBERS
+                .iter()
+                .filter(|(_, v)| *v == 0)
+                .map(|(name, _)| *name)
+                .take(1)
+                .collect()
+        } else {
+            Self::MEMBERS
+                .iter()
+                .filter(|(_, v)| *v != 0 && bits & v == *v)
+                .map(|(name, _)| *name)
+                .collect()
+        }
+    }
+
+    /// Parse comma-separated list of member names. Unknown names are
+    /// ignored.
+    fn from_member_names(s: &str) -> Self {
+        Self::from_bits(
+            s.split(',')
+                .map(str::trim)
+                .filter_map(|name| Self::MEMBERS.iter().find(|(n, _)| *n == name))
+                .fold(0, |bits, (_, v)| bits | v),
+        )
+    }
+}
+
+/// Serialize value as comma-separated list of member names.
+///
+/// # Errors
+///
+/// Returns error of the serializer.
+pub fn serialize<T: Flags, S: Serializer>(v: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&v.member_names().join(","))
+}
+
+/// Deserialize value from comma-separated list of member names.
+///
+/// # Errors
+///
+/// Returns error if value is not a string.
+pub fn deserialize<'de, T: Flags, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    Ok(T::from_member_names(&s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Access(u64);
+
+    impl Flags for Access {
+        const MEMBERS: &'static [(&'static str, u64)] = &[("None", 0), ("Read", 1), ("Write", 2)];
+        fn from_bits(bits: u64) -> Self {
+            Self(bits)
+        }
+        fn bits(self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn member_names_round_trip() {
+        assert_eq!(Access(3).member_names(), vec!["Read", "Write"]);
+        assert_eq!(Access(0).member_names(), vec!["None"]);
+        assert_eq!(Access::from_member_names("Read, Write"), Access(3));
+        assert_eq!(Access::from_member_names("Write,Execute"), Access(2));
+        assert_eq!(Access::from_member_names("None"), Access(0));
+    }
+}
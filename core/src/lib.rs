@@ -27,7 +27,9 @@
 //! - Generic operation traits: [`Creatable`], [`Updatable`], [`Deletable`]
 //! - Entity contracts: [`EntityTypeRef`], [`Expandable`]
 //! - Action envelope: [`Action<T, R>`]
+//! - Request validation: [`Validate`]
 //! - Client abstraction: [`Bmc`] (transport-agnostic interface used by generated code)
+//! - Thread-safety bounds: [`MaybeSend`], [`MaybeSync`] (`Send`/`Sync` unless the `unsend` feature is enabled)
 //!
 //! Non-goals
 //! - No service- or schema-specific models are defined here.
@@ -62,9 +64,13 @@
     clippy::print_stderr
 )]
 #![deny(missing_docs)]
+// Futures are intentionally not `Send` with the `unsend` feature.
+#![cfg_attr(feature = "unsend", allow(clippy::future_not_send))]
 
 /// Action-related types.
 pub mod action;
+/// HTTP methods allowed for a resource.
+pub mod allow;
 /// BMC trait and credentials.
 pub mod bmc;
 /// Custom deserialization helpers.
@@ -77,14 +83,28 @@ pub mod edm_date_time_offset;
 pub mod edm_duration;
 /// `Edm.PrimitiveType` type.
 pub mod edm_primitive_type;
+/// Enum types with `IsFlags` attribute.
+pub mod flags;
+/// Thread-safety bounds controlled by the `unsend` feature.
+pub mod maybe_send;
+/// JSON merge patch helpers.
+pub mod merge_patch;
 /// Navigation property wrapper.
 pub mod nav_property;
 /// Type for `@odata.id` identifier.
 pub mod odata;
 /// Support of redfish queries
 pub mod query;
+/// Required properties support.
+pub mod required;
+/// Server-sent events decoding.
+pub mod sse;
+/// Validation of `@odata.type`.
+pub mod type_check;
 /// Upload data types.
 pub mod upload;
+/// Client-side validation of request payloads.
+pub mod validation;
 
 use crate::query::ExpandQuery;
 use futures_core::TryStream;
@@ -99,8 +119,14 @@ pub use action::Action;
 #[doc(inline)]
 pub use action::ActionError;
 #[doc(inline)]
+pub use allow::AllowedMethods;
+#[doc(inline)]
 pub use bmc::Bmc;
 #[doc(inline)]
+pub use bmc::BmcErrorInfo;
+#[doc(inline)]
+pub use bmc::UpdateMethod;
+#[doc(inline)]
 pub use deserialize::de_optional_nullable;
 #[doc(inline)]
 pub use deserialize::de_required_nullable;
@@ -113,6 +139,12 @@ pub use edm_duration::EdmDuration;
 #[doc(inline)]
 pub use edm_primitive_type::EdmPrimitiveType;
 #[doc(inline)]
+pub use flags::Flags;
+#[doc(inline)]
+pub use maybe_send::MaybeSend;
+#[doc(inline)]
+pub use maybe_send::MaybeSync;
+#[doc(inline)]
 pub use nav_property::NavProperty;
 #[doc(inline)]
 pub use nav_property::Reference;
@@ -125,15 +157,35 @@ pub use odata::ODataId;
 #[doc(inline)]
 pub use query::FilterQuery;
 #[doc(inline)]
+pub use query::PageQuery;
+#[doc(inline)]
+pub use query::SelectQuery;
+#[doc(inline)]
 pub use query::ToFilterLiteral;
 #[doc(inline)]
+pub use required::MissingRequiredProperty;
+#[doc(inline)]
 pub use serde_json::Value as AdditionalProperties;
 #[doc(inline)]
+pub use sse::SseBody;
+#[doc(inline)]
+pub use sse::SseError;
+#[doc(inline)]
+pub use sse::SseEvent;
+#[doc(inline)]
+pub use type_check::ODataTypeMismatch;
+#[doc(inline)]
 pub use upload::DataStream;
 #[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
 pub use upload::HttpPushUriUpdateRequest;
 #[doc(inline)]
+pub use upload::MultipartPart;
+#[doc(inline)]
+pub use upload::MultipartPartReader;
+#[doc(inline)]
+pub use upload::MultipartRequest;
+#[doc(inline)]
 pub use upload::MultipartUpdateRequest;
 #[doc(inline)]
 pub use upload::OemMultipartPart;
@@ -148,19 +200,62 @@ pub use upload::UploadReader;
 pub use upload::UploadStream;
 #[doc(inline)]
 pub use uuid::Uuid as EdmGuid;
+#[doc(inline)]
+pub use validation::Validate;
+#[doc(inline)]
+pub use validation::ValidationError;
 
 /// Entity type reference trait implemented by the CSDL compiler
 /// for all generated entity types and for all [`NavProperty<T>`] where
 /// `T` is a struct for an entity type.
-pub trait EntityTypeRef: Send + Sync + Sized {
+pub trait EntityTypeRef: MaybeSend + MaybeSync + Sized {
     /// Value of `@odata.id` field of the Entity.
     fn odata_id(&self) -> &ODataId;
 
     /// Value of `@odata.etag` field of the Entity.
     fn etag(&self) -> Option<&ODataETag>;
 
+    /// Names of properties of the Entity annotated with
+    /// `Redfish.Required`. Properties of base types are not
+    /// included.
+    #[must_use]
+    fn required_properties() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// First required property (including required properties of
+    /// base types) that is missing in JSON payload of the Entity.
+    #[must_use]
+    fn missing_required_property(payload: &serde_json::Value) -> Option<&'static str> {
+        required::missing_property(Self::required_properties(), payload)
+    }
+
+    /// Names of `@odata.type` accepted for the Entity: name of its
+    /// type and names of known derived types (for example,
+    /// `Chassis`). Empty if any type is accepted.
+    #[must_use]
+    fn odata_type_names() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Link to the next page of collection members
+    /// (`Members@odata.nextLink`) when the service returned only a
+    /// part of the members.
+    #[must_use]
+    fn next_page_link(&self) -> Option<&ODataId> {
+        None
+    }
+
+    /// Append members of the next page of the collection. BMC
+    /// implementations call it until [`Self::next_page_link`] returns
+    /// `None`.
+    fn append_page(&mut self, _page: Self) {}
+
     /// Refresh the entity by fetching it again from the BMC.
-    fn refresh<B: Bmc>(&self, bmc: &B) -> impl Future<Output = Result<Arc<Self>, B::Error>> + Send
+    fn refresh<B: Bmc>(
+        &self,
+        bmc: &B,
+    ) -> impl Future<Output = Result<Arc<Self>, B::Error>> + MaybeSend
     where
         Self: for<'de> Deserialize<'de> + 'static,
     {
@@ -175,15 +270,20 @@ pub trait Expandable: EntityTypeRef + for<'de> Deserialize<'de> + 'static {
         &self,
         bmc: &B,
         query: ExpandQuery,
-    ) -> impl Future<Output = Result<Arc<Self>, B::Error>> + Send {
+    ) -> impl Future<Output = Result<Arc<Self>, B::Error>> + MaybeSend {
         bmc.expand::<Self>(self.odata_id(), query)
     }
 }
 
 /// Boxed fallible stream used by BMC streaming APIs.
+#[cfg(not(feature = "unsend"))]
 pub type BoxTryStream<T, E> =
     Pin<Box<dyn TryStream<Ok = T, Error = E, Item = Result<T, E>> + Send>>;
 
+/// Boxed fallible stream used by BMC streaming APIs.
+#[cfg(feature = "unsend")]
+pub type BoxTryStream<T, E> = Pin<Box<dyn TryStream<Ok = T, Error = E, Item = Result<T, E>>>>;
+
 /// Location of an asynchronous task monitor.
 ///
 /// Wraps the `Location` returned for an operation that is completing
@@ -202,7 +302,7 @@ impl From<ODataId> for AsyncTaskLocation {
 }
 
 /// Outcome of a mutating Redfish operation that can complete asynchronously.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AsyncTask {
     /// Location to use for polling completion.
     pub location: AsyncTaskLocation,
@@ -301,28 +401,32 @@ impl<T: fmt::Debug> fmt::Debug for SessionCreateResponse<T> {
 
 /// This trait is assigned to the collections that are marked as
 /// creatable in the CSDL specification.
-pub trait Creatable<V: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>:
-    EntityTypeRef
+pub trait Creatable<
+    V: MaybeSend + MaybeSync + Serialize,
+    R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+>: EntityTypeRef
 {
     /// Create an entity using `create` as payload.
     fn create<B: Bmc>(
         &self,
         bmc: &B,
         create: &V,
-    ) -> impl Future<Output = Result<ModificationResponse<R>, B::Error>> + Send {
+    ) -> impl Future<Output = Result<ModificationResponse<R>, B::Error>> + MaybeSend {
         bmc.create::<V, R>(self.odata_id(), create)
     }
 }
 
 /// This trait is assigned to entity types that are marked as
 /// updatable in the CSDL specification.
-pub trait Updatable<V: Sync + Send + Serialize>: EntityTypeRef + for<'de> Deserialize<'de> {
+pub trait Updatable<V: MaybeSync + MaybeSend + Serialize>:
+    EntityTypeRef + for<'de> Deserialize<'de>
+{
     /// Update an entity using `update` as payload.
     fn update<B: Bmc>(
         &self,
         bmc: &B,
         update: &V,
-    ) -> impl Future<Output = Result<ModificationResponse<Self>, B::Error>> + Send {
+    ) -> impl Future<Output = Result<ModificationResponse<Self>, B::Error>> + MaybeSend {
         bmc.update::<V, Self>(self.odata_id(), self.etag(), update)
     }
 }
@@ -334,8 +438,8 @@ pub trait Deletable: EntityTypeRef + for<'de> Deserialize<'de> {
     fn delete<B: Bmc>(
         &self,
         bmc: &B,
-    ) -> impl Future<Output = Result<ModificationResponse<Self>, B::Error>> + Send {
-        bmc.delete::<Self>(self.odata_id())
+    ) -> impl Future<Output = Result<ModificationResponse<Self>, B::Error>> + MaybeSend {
+        bmc.delete::<Self>(self.odata_id(), self.etag())
     }
 }
 
@@ -419,7 +523,8 @@ mod tests {
     #[test]
     fn try_map_entity_maps_entity_and_propagates_error() -> Result<(), &'static str> {
         assert_entity(
-            ModificationResponse::Entity(21_u32).try_map_entity(|value| Ok(value * 2))?,
+            ModificationResponse::Entity(21_u32)
+                .try_map_entity(|value| Ok::<u32, &'static str>(value * 2))?,
             42,
         )?;
 
@@ -447,7 +552,7 @@ mod tests {
     ) -> Result<(), &'static str> {
         assert_entity(
             ModificationResponse::Entity(21_u32)
-                .try_map_entity_async(|value| async move { Ok(value * 2) })
+                .try_map_entity_async(|value| async move { Ok::<u32, &'static str>(value * 2) })
                 .await?,
             42,
         )?;
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Thread-safety bounds controlled by the `unsend` feature.
+//!
+//! By default [`MaybeSend`] and [`MaybeSync`] are equivalent to
+//! [`Send`] and [`Sync`], so futures returned by [`crate::Bmc`] can be
+//! spawned on multithreaded runtimes. With `unsend` enabled they are
+//! implemented for every type. This allows [`crate::Bmc`]
+//! implementations on single-threaded targets like
+//! `wasm32-unknown-unknown`, where futures of the browser `fetch` API
+//! are not `Send`.
+
+/// [`Send`] unless `unsend` feature is enabled, any type otherwise.
+#[cfg(not(feature = "unsend"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(feature = "unsend"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// [`Send`] unless `unsend` feature is enabled, any type otherwise.
+#[cfg(feature = "unsend")]
+pub trait MaybeSend {}
+
+#[cfg(feature = "unsend")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// [`Sync`] unless `unsend` feature is enabled, any type otherwise.
+#[cfg(not(feature = "unsend"))]
+pub trait MaybeSync: Sync {}
+
+#[cfg(not(feature = "unsend"))]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+/// [`Sync`] unless `unsend` feature is enabled, any type otherwise.
+#[cfg(feature = "unsend")]
+pub trait MaybeSync {}
+
+#[cfg(feature = "unsend")]
+impl<T: ?Sized> MaybeSync for T {}
@@ -0,0 +1,163 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON merge patch (RFC 7386) helpers
+//!
+//! [`diff`] computes a minimal merge patch between two JSON values.
+//! [`writable`] and [`update_diff`] allow to "edit and save" an
+//! entity without hand-crafting the update payload: writable
+//! properties of the entity payload are read into the generated
+//! update type, the caller modifies a copy of it, and
+//! [`update_diff`] returns an update that contains only changed
+//! properties. Update types contain only properties that are
+//! writable according to the schema, so read-only properties are
+//! never sent.
+//!
+//! Arrays are compared as a whole: if any element is changed, the
+//! patch contains the complete new array.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+/// Compute merge patch that turns `current` into `modified`.
+/// Properties that are missing in `modified` are set to `null`.
+/// Returns `None` if the values are equal.
+#[must_use]
+pub fn diff(current: &Value, modified: &Value) -> Option<Value> {
+    diff_values(current, modified, true)
+}
+
+/// Read writable properties of entity `payload` into update type
+/// `V`. Properties that are not part of `V` are ignored.
+///
+/// # Errors
+///
+/// Returns an error if a writable property of `payload` has an
+/// unexpected type.
+pub fn writable<V: DeserializeOwned>(payload: &Value) -> Result<V, serde_json::Error> {
+    V::deserialize(payload)
+}
+
+/// Compute update that turns `current` into `modified`. Properties
+/// that are unset in `modified` are not changed. Returns `None` if
+/// nothing is changed.
+///
+/// # Errors
+///
+/// Returns an error if update values cannot be converted to JSON or
+/// back.
+pub fn update_diff<V>(current: &V, modified: &V) -> Result<Option<V>, serde_json::Error>
+where
+    V: Serialize + DeserializeOwned,
+{
+    let current = serde_json::to_value(current)?;
+    let modified = serde_json::to_value(modified)?;
+    diff_values(&current, &modified, false)
+        .map(|patch| V::deserialize(&patch))
+        .transpose()
+}
+
+fn diff_values(current: &Value, modified: &Value, remove_missing: bool) -> Option<Value> {
+    match (current, modified) {
+        (Value::Object(current), Value::Object(modified)) => {
+            let mut patch = modified
+                .iter()
+                .filter_map(|(k, v)| {
+                    current
+                        .get(k)
+                        .map_or_else(|| Some(v.clone()), |c| diff_values(c, v, remove_missing))
+                        .map(|v| (k.clone(), v))
+                })
+                .collect::<Map<_, _>>();
+            if remove_missing {
+                patch.extend(
+                    current
+                        .keys()
+                        .filter(|k| !modified.contains_key(*k))
+                        .map(|k| (k.clone(), Value::Null)),
+                );
+            }
+            (!patch.is_empty()).then_some(Value::Object(patch))
+        }
+        _ => (current != modified).then(|| modified.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[test]
+    fn minimal_merge_patch() {
+        let current = json!({
+            "AssetTag": "old",
+            "Boot": { "BootSourceOverrideTarget": "Pxe", "BootOrder": ["A", "B"] },
+            "Removed": 1,
+        });
+        let modified = json!({
+            "AssetTag": "old",
+            "Boot": { "BootSourceOverrideTarget": "Hdd", "BootOrder": ["A", "B"] },
+            "Added": true,
+        });
+        assert_eq!(
+            diff(&current, &modified),
+            Some(json!({
+                "Boot": { "BootSourceOverrideTarget": "Hdd" },
+                "Added": true,
+                "Removed": null,
+            }))
+        );
+        assert_eq!(diff(&current, &current), None);
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+    struct TestUpdate {
+        #[serde(rename = "AssetTag", skip_serializing_if = "Option::is_none")]
+        asset_tag: Option<String>,
+        #[serde(rename = "Enabled", skip_serializing_if = "Option::is_none")]
+        enabled: Option<bool>,
+    }
+
+    #[test]
+    fn update_contains_changed_writable_properties() -> Result<(), serde_json::Error> {
+        let payload = json!({
+            "@odata.id": "/redfish/v1/Systems/1",
+            "Id": "1",
+            "AssetTag": "old",
+            "Enabled": true,
+        });
+        let current = writable::<TestUpdate>(&payload)?;
+        assert_eq!(current.asset_tag.as_deref(), Some("old"));
+
+        let mut modified = current.clone();
+        modified.asset_tag = Some("new".into());
+        assert_eq!(
+            update_diff(&current, &modified)?,
+            Some(TestUpdate {
+                asset_tag: Some("new".into()),
+                enabled: None,
+            })
+        );
+
+        modified = current.clone();
+        modified.enabled = None;
+        assert_eq!(update_diff(&current, &modified)?, None);
+        Ok(())
+    }
+}
@@ -39,13 +39,17 @@ use crate::Deletable;
 use crate::EntityTypeRef;
 use crate::Expandable;
 use crate::FilterQuery;
+use crate::MaybeSend;
+use crate::MaybeSync;
 use crate::ODataETag;
 use crate::ODataId;
+use crate::PageQuery;
 use crate::Updatable;
 use serde::de;
 use serde::de::Deserializer;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
 /// Reference variant of the navigation property (only `@odata.id`
@@ -160,15 +164,30 @@ impl<T: EntityTypeRef> EntityTypeRef for NavProperty<T> {
             Self::Reference(_) => None,
         }
     }
+
+    fn required_properties() -> &'static [&'static str] {
+        T::required_properties()
+    }
+
+    fn missing_required_property(payload: &JsonValue) -> Option<&'static str> {
+        T::missing_required_property(payload)
+    }
+
+    fn odata_type_names() -> &'static [&'static str] {
+        T::odata_type_names()
+    }
 }
 
 impl<C, R, T: Creatable<C, R>> Creatable<C, R> for NavProperty<T>
 where
-    C: Send + Sync + Serialize,
-    R: Send + Sync + for<'de> Deserialize<'de>,
+    C: MaybeSend + MaybeSync + Serialize,
+    R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+{
+}
+impl<U, T: Updatable<U>> Updatable<U> for NavProperty<T> where
+    U: MaybeSync + MaybeSend + Sized + Serialize
 {
 }
-impl<U, T: Updatable<U>> Updatable<U> for NavProperty<T> where U: Sync + Send + Sized + Serialize {}
 impl<T: Deletable> Deletable for NavProperty<T> {}
 impl<T: Expandable> Expandable for NavProperty<T> {}
 
@@ -232,6 +251,15 @@ impl<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static> NavProperty<T> {
     pub async fn filter<B: Bmc>(&self, bmc: &B, query: FilterQuery) -> Result<Arc<T>, B::Error> {
         bmc.filter::<T>(self.id(), query).await
     }
+
+    /// Get one page of the collection members selected by the query.
+    ///
+    /// # Errors
+    ///
+    /// Returns a BMC error if retrieval of the page fails.
+    pub async fn page<B: Bmc>(&self, bmc: &B, query: PageQuery) -> Result<Arc<T>, B::Error> {
+        bmc.page::<T>(self.id(), query).await
+    }
 }
 
 #[cfg(test)]
@@ -53,6 +53,31 @@
 //! assert_eq!(query.to_query_string(), "$filter=Temperature gt 50 and Temperature lt 80");
 //! ```
 //!
+//! ## Paging Query (`$top` / `$skip`)
+//!
+//! The [`PageQuery`] builder constructs `$top` and `$skip` parameters to request
+//! only a part of collection members.
+//!
+//! ```rust
+//! use nv_redfish_core::query::PageQuery;
+//!
+//! // Second page of 50 members
+//! let query = PageQuery::top(50).skip(50);
+//! assert_eq!(query.to_query_string(), "$top=50&$skip=50");
+//! ```
+//!
+//! ## Select Query (`$select`)
+//!
+//! The [`SelectQuery`] builder constructs `$select` parameters to request only
+//! specific properties of a resource.
+//!
+//! ```rust
+//! use nv_redfish_core::query::SelectQuery;
+//!
+//! let query = SelectQuery::property(&"PowerState").property_then(&"Status/Health");
+//! assert_eq!(query.to_query_string(), "$select=PowerState,Status/Health");
+//! ```
+//!
 //! # Type Safety
 //!
 //! Both builders use traits to ensure type safety:
@@ -320,6 +345,22 @@ impl ExpandQuery {
         self
     }
 
+    /// Limit number of levels of the query to `max_levels`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nv_redfish_core::query::ExpandQuery;
+    ///
+    /// let query = ExpandQuery::all().levels(3).max_levels(2);
+    /// assert_eq!(query.to_query_string(), "$expand=*($levels=2)");
+    /// ```
+    #[must_use]
+    pub fn max_levels(mut self, max_levels: u32) -> Self {
+        self.levels = self.levels.map(|levels| levels.min(max_levels));
+        self
+    }
+
     /// Convert to the `OData` query string according to Redfish specification.
     ///
     /// This generates the actual query parameter string that will be appended to
@@ -724,6 +765,108 @@ impl FilterQuery {
     }
 }
 
+/// Builder for Redfish `$top` and `$skip` query parameters.
+///
+/// `$top` limits number of collection members included in the
+/// response and `$skip` sets number of members to skip from the
+/// beginning of the collection.
+///
+/// # Examples
+///
+/// ```rust
+/// use nv_redfish_core::query::PageQuery;
+///
+/// let first = PageQuery::top(100);
+/// assert_eq!(first.to_query_string(), "$top=100");
+///
+/// let next = first.next();
+/// assert_eq!(next.to_query_string(), "$top=100&$skip=100");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageQuery {
+    top: usize,
+    skip: usize,
+}
+
+impl PageQuery {
+    /// Create query for the first `top` members.
+    #[must_use]
+    pub const fn top(top: usize) -> Self {
+        Self { top, skip: 0 }
+    }
+
+    /// Skip `skip` members from the beginning of the collection.
+    #[must_use]
+    pub const fn skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    /// Query for the page that follows this one.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self {
+            top: self.top,
+            skip: self.skip.saturating_add(self.top),
+        }
+    }
+
+    /// Convert to the `OData` query string
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        if self.skip == 0 {
+            format!("$top={}", self.top)
+        } else {
+            format!("$top={}&$skip={}", self.top, self.skip)
+        }
+    }
+}
+
+/// Builder for Redfish `$select` query parameters.
+///
+/// `$select` requests that the service returns only listed properties
+/// of the resource (and `@odata` annotations). Nested properties are
+/// selected with `/` separated paths. Property paths use the same
+/// [`crate::FilterProperty`] trait as [`FilterQuery`].
+///
+/// Types used to deserialize the response must accept absence of all
+/// properties that are not selected.
+///
+/// # Examples
+///
+/// ```rust
+/// use nv_redfish_core::query::SelectQuery;
+///
+/// let query = SelectQuery::property(&"PowerState");
+/// assert_eq!(query.to_query_string(), "$select=PowerState");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SelectQuery {
+    properties: Vec<String>,
+}
+
+impl SelectQuery {
+    /// Create a new select query with one property.
+    pub fn property<P: crate::FilterProperty>(property: &P) -> Self {
+        Self {
+            properties: vec![property.property_path().to_string()],
+        }
+    }
+
+    /// Add property to the selection.
+    #[must_use]
+    pub fn property_then<P: crate::FilterProperty>(mut self, property: &P) -> Self {
+        self.properties.push(property.property_path().to_string());
+        self
+    }
+
+    /// Convert to the `OData` query string
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        format!("$select={}", self.properties.join(","))
+    }
+}
+
 /// Implement `FilterProperty` for `&str`
 impl crate::FilterProperty for &str {
     fn property_path(&self) -> &str {
@@ -784,6 +927,14 @@ mod tests {
         assert_eq!(query.to_query_string(), "$expand=*($levels=3)");
     }
 
+    #[test]
+    fn test_expand_with_max_levels() {
+        let query = ExpandQuery::all().levels(3).max_levels(1);
+        assert_eq!(query.to_query_string(), "$expand=*($levels=1)");
+        let query = ExpandQuery::all().levels(2).max_levels(5);
+        assert_eq!(query.to_query_string(), "$expand=*($levels=2)");
+    }
+
     #[test]
     fn test_simple_eq() {
         let filter = FilterQuery::eq(&"Count", 2);
@@ -870,4 +1021,19 @@ mod tests {
             "$filter=ProcessorSummary/Count eq 2 and MemorySummary/TotalSystemMemoryGiB gt 64"
         );
     }
+
+    #[test]
+    fn test_select_query() {
+        let query =
+            SelectQuery::property(&"PowerState").property_then(&"Status/Health".to_string());
+        assert_eq!(query.to_query_string(), "$select=PowerState,Status/Health");
+    }
+
+    #[test]
+    fn test_page_query() {
+        let query = PageQuery::top(10);
+        assert_eq!(query.to_query_string(), "$top=10");
+        assert_eq!(query.skip(30).to_query_string(), "$top=10&$skip=30");
+        assert_eq!(query.next().next(), PageQuery::top(10).skip(20));
+    }
 }
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support of properties annotated with `Redfish.Required`
+//!
+//! Generated entity types list their required properties (see
+//! [`EntityTypeRef::required_properties`]). When payload cannot be
+//! deserialized, this metadata allows to report which required
+//! property the service has not provided instead of a generic JSON
+//! error.
+//!
+//! Required properties are enforced by the generated deserializers
+//! regardless of this metadata. The structured error is available
+//! only where the caller holds the payload; `Bmc` implementations
+//! that deserialize responses themselves report serde's "missing
+//! field" error.
+//!
+//! [`EntityTypeRef::required_properties`]: crate::EntityTypeRef::required_properties
+
+use crate::ODataId;
+use serde_json::Value as JsonValue;
+use std::error::Error as StdError;
+use std::fmt;
+
+/// Required property is missing in the payload of the resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingRequiredProperty {
+    /// Identifier of the resource.
+    pub resource: ODataId,
+    /// Name of the missing property.
+    pub property: &'static str,
+}
+
+impl fmt::Display for MissingRequiredProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "missing required property {} of resource {}",
+            self.property, self.resource
+        )
+    }
+}
+
+impl StdError for MissingRequiredProperty {}
+
+/// First of `properties` that is not present in JSON object
+/// `payload`. Returns `None` if `payload` is not an object.
+#[must_use]
+pub fn missing_property(
+    properties: &'static [&'static str],
+    payload: &JsonValue,
+) -> Option<&'static str> {
+    let obj = payload.as_object()?;
+    properties.iter().copied().find(|p| !obj.contains_key(*p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn missing_property_found() {
+        let payload = json!({ "Name": "name", "Id": null });
+        assert_eq!(missing_property(&["Name", "Id"], &payload), None);
+        assert_eq!(
+            missing_property(&["Name", "ChassisType"], &payload),
+            Some("ChassisType")
+        );
+        assert_eq!(missing_property(&["Name"], &json!([])), None);
+    }
+}
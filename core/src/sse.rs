@@ -0,0 +1,286 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Server-sent events (SSE) decoding
+//!
+//! [`SseBody`] decodes a stream of raw body chunks (for example, one
+//! returned by [`crate::Bmc::download`]) into [`SseEvent`]s. It is the
+//! default way to implement [`crate::Bmc::stream`] for transports that
+//! don't have their own SSE support, and allows test BMCs to drive
+//! event streams from recorded payloads.
+//!
+//! Lines may be terminated by `\n` or `\r\n`. Comment lines and the
+//! `retry` field are ignored. An event that is not terminated by an
+//! empty line when the body ends is discarded. An event that grows
+//! beyond the limit of buffered bytes ends the stream with
+//! [`SseError::EventTooLarge`].
+
+use futures_core::Stream;
+use futures_core::TryStream;
+use serde::de::DeserializeOwned;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::mem::take;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+/// Event decoded from SSE stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SseEvent {
+    /// Value of the `event` field.
+    pub event: Option<String>,
+    /// Value of the `id` field.
+    pub id: Option<String>,
+    /// Values of all `data` fields joined by `\n`.
+    pub data: String,
+}
+
+impl SseEvent {
+    /// Deserialize data of the event as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if data is not valid JSON of type `T`.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.data)
+    }
+}
+
+/// Default limit of bytes buffered for a single event.
+pub const DEFAULT_MAX_EVENT_BYTES: usize = 1024 * 1024;
+
+/// Error of [`SseEvents`] stream.
+#[derive(Debug)]
+pub enum SseError<E> {
+    /// Error of the underlying body stream.
+    Body(E),
+    /// Event exceeded the limit of buffered bytes before it was
+    /// terminated. The stream ends after this error.
+    EventTooLarge {
+        /// Limit that was exceeded.
+        limit: usize,
+    },
+}
+
+impl<E: Display> Display for SseError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Body(err) => write!(f, "SSE body error: {err}"),
+            Self::EventTooLarge { limit } => {
+                write!(f, "SSE event exceeded {limit} bytes without terminating")
+            }
+        }
+    }
+}
+
+impl<E: StdError + 'static> StdError for SseError<E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Body(err) => Some(err),
+            Self::EventTooLarge { .. } => None,
+        }
+    }
+}
+
+/// Body of server-sent events: stream of raw chunks that can be
+/// decoded into events.
+pub trait SseBody: TryStream<Ok = Vec<u8>> + Sized {
+    /// Decode chunks of the stream into events. Events are limited
+    /// to [`DEFAULT_MAX_EVENT_BYTES`].
+    fn sse_events(self) -> SseEvents<Self> {
+        self.sse_events_with_limit(DEFAULT_MAX_EVENT_BYTES)
+    }
+
+    /// Decode chunks of the stream into events of at most
+    /// `max_event_bytes` bytes.
+    fn sse_events_with_limit(self, max_event_bytes: usize) -> SseEvents<Self> {
+        SseEvents {
+            inner: self,
+            buf: Vec::new(),
+            event: SseEvent::default(),
+            has_data: false,
+            max_event_bytes,
+            done: false,
+        }
+    }
+}
+
+impl<S: TryStream<Ok = Vec<u8>>> SseBody for S {}
+
+/// Stream of events returned by [`SseBody::sse_events`].
+pub struct SseEvents<S> {
+    inner: S,
+    buf: Vec<u8>,
+    event: SseEvent,
+    has_data: bool,
+    max_event_bytes: usize,
+    done: bool,
+}
+
+impl<S> SseEvents<S> {
+    /// Process all complete lines in the buffer until an event is
+    /// dispatched.
+    fn next_event(&mut self) -> Option<SseEvent> {
+        while let Some(pos) = self.buf.iter().position(|b| *b == b'\n') {
+            let mut line = self.buf.drain(..=pos).collect::<Vec<_>>();
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            if let Some(event) = self.process_line(&String::from_utf8_lossy(&line)) {
+                return Some(event);
+            }
+        }
+        None
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            let event = take(&mut self.event);
+            return take(&mut self.has_data).then_some(event);
+        }
+        let (field, value) = line.split_once(':').map_or((line, ""), |(field, value)| {
+            (field, value.strip_prefix(' ').unwrap_or(value))
+        });
+        match field {
+            "data" => {
+                if self.has_data {
+                    self.event.data.push('\n');
+                }
+                self.event.data.push_str(value);
+                self.has_data = true;
+            }
+            "event" => self.event.event = Some(value.into()),
+            "id" => self.event.id = Some(value.into()),
+            // Comments, `retry` and unknown fields.
+            _ => {}
+        }
+        None
+    }
+}
+
+impl<S> Stream for SseEvents<S>
+where
+    S: TryStream<Ok = Vec<u8>> + Unpin,
+{
+    type Item = Result<SseEvent, SseError<S::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.done {
+                return Poll::Ready(None);
+            }
+            if let Some(event) = this.next_event() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+            // Only the incomplete tail of the current event is left
+            // in the buffer.
+            if this.buf.len() + this.event.data.len() > this.max_event_bytes {
+                this.done = true;
+                return Poll::Ready(Some(Err(SseError::EventTooLarge {
+                    limit: this.max_event_bytes,
+                })));
+            }
+            match Pin::new(&mut this.inner).try_poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => this.buf.extend(chunk),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(SseError::Body(err)))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::convert::Infallible;
+    use std::future::poll_fn;
+
+    struct Chunks(VecDeque<Vec<u8>>);
+
+    impl Stream for Chunks {
+        type Item = Result<Vec<u8>, Infallible>;
+
+        fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.get_mut().0.pop_front().map(Ok))
+        }
+    }
+
+    fn decode_with_limit(
+        chunks: &[&str],
+        limit: usize,
+    ) -> Vec<Result<SseEvent, SseError<Infallible>>> {
+        let chunks = Chunks(chunks.iter().map(|c| c.as_bytes().to_vec()).collect());
+        let mut events = chunks.sse_events_with_limit(limit);
+        let mut result = Vec::new();
+        tokio_test::block_on(async {
+            while let Some(event) = poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await {
+                result.push(event);
+            }
+        });
+        result
+    }
+
+    fn decode(chunks: &[&str]) -> Vec<SseEvent> {
+        decode_with_limit(chunks, DEFAULT_MAX_EVENT_BYTES)
+            .into_iter()
+            .map(|event| event.expect("infallible"))
+            .collect()
+    }
+
+    #[test]
+    fn events_split_across_chunks() {
+        let events = decode(&[
+            ": heartbeat\n\nevent: Al",
+            "ert\r\nid: 1\r\ndata: {\"Id\":\n",
+            "data: \"1\"}\n\ndata: 2\n\ndata: incomplete\n",
+        ]);
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: Some("Alert".into()),
+                    id: Some("1".into()),
+                    data: "{\"Id\":\n\"1\"}".into(),
+                },
+                SseEvent {
+                    event: None,
+                    id: None,
+                    data: "2".into(),
+                },
+            ]
+        );
+        let value: serde_json::Value = events[0].json().expect("valid json");
+        assert_eq!(value, serde_json::json!({ "Id": "1" }));
+    }
+
+    #[test]
+    fn event_too_large_ends_stream() {
+        let events =
+            decode_with_limit(&["data: 1\n\n", "data: 0123", "456789\n", "data: 2\n\n"], 8);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], Ok(event) if event.data == "1"));
+        assert!(matches!(
+            events[1],
+            Err(SseError::EventTooLarge { limit: 8 })
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of `@odata.type` of resources
+//!
+//! Generated entity types list names of `@odata.type` they accept:
+//! their own name and names of known derived types (see
+//! [`EntityTypeRef::odata_type_names`]). A BMC that answers a wrong
+//! URI with an error page or with a different resource produces a
+//! payload of another type. Checking `@odata.type` reports the
+//! received type instead of a generic JSON error or silently
+//! deserialized wrong data.
+//!
+//! [`EntityTypeRef::odata_type_names`]: crate::EntityTypeRef::odata_type_names
+
+use crate::odata::ODataType;
+use crate::ODataId;
+use serde_json::Value as JsonValue;
+use std::error::Error as StdError;
+use std::fmt;
+
+/// `@odata.type` of the resource doesn't match the expected entity
+/// type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ODataTypeMismatch {
+    /// Identifier of the resource.
+    pub resource: ODataId,
+    /// Accepted type names.
+    pub expected: &'static [&'static str],
+    /// Received `@odata.type`. `None` if the payload has no
+    /// `@odata.type`.
+    pub received: Option<String>,
+}
+
+impl ODataTypeMismatch {
+    /// Check that `@odata.type` of `payload` of resource `resource`
+    /// names one of `expected` types. Any payload is accepted if
+    /// `expected` is empty.
+    #[must_use]
+    pub fn check(
+        resource: &ODataId,
+        expected: &'static [&'static str],
+        payload: &JsonValue,
+    ) -> Option<Self> {
+        if expected.is_empty() {
+            return None;
+        }
+        match ODataType::parse_from(payload) {
+            Some(odata_type) if expected.contains(&odata_type.type_name) => None,
+            _ => Some(Self {
+                resource: resource.clone(),
+                expected,
+                received: payload
+                    .get("@odata.type")
+                    .and_then(JsonValue::as_str)
+                    .map(ToString::to_string),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ODataTypeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unexpected @odata.type {} of resource {}, expected {}",
+            self.received.as_deref().unwrap_or("(missing)"),
+            self.resource,
+            self.expected.join(" or ")
+        )
+    }
+}
+
+impl StdError for ODataTypeMismatch {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn type_mismatch_found() {
+        let id = ODataId::from("/redfish/v1/Chassis/1".to_string());
+        let expected = &["Chassis", "NvidiaChassis"];
+        let chassis = json!({ "@odata.type": "#Chassis.v1_22_0.Chassis" });
+        assert_eq!(ODataTypeMismatch::check(&id, expected, &chassis), None);
+        assert_eq!(ODataTypeMismatch::check(&id, &[], &json!({})), None);
+
+        let error = json!({ "error": { "code": "Base.1.8.ResourceMissingAtURI" } });
+        let mismatch = ODataTypeMismatch::check(&id, expected, &error).expect("mismatch");
+        assert_eq!(mismatch.received, None);
+
+        let system = json!({ "@odata.type": "#ComputerSystem.v1_20_0.ComputerSystem" });
+        let mismatch = ODataTypeMismatch::check(&id, expected, &system).expect("mismatch");
+        assert_eq!(
+            mismatch.to_string(),
+            "unexpected @odata.type #ComputerSystem.v1_20_0.ComputerSystem of resource /redfish/v1/Chassis/1, expected Chassis or NvidiaChassis"
+        );
+    }
+}
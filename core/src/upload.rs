@@ -20,12 +20,14 @@ use std::time::Duration;
 
 use futures_io::AsyncRead;
 
+use crate::MaybeSend;
+
 const OEM_PREFIX: &str = "Oem";
 
 /// Async reader accepted by upload methods.
-pub trait UploadReader: AsyncRead + Send + 'static {}
+pub trait UploadReader: AsyncRead + MaybeSend + 'static {}
 
-impl<T> UploadReader for T where T: AsyncRead + Send + 'static {}
+impl<T> UploadReader for T where T: AsyncRead + MaybeSend + 'static {}
 
 /// Named data stream accepted by multipart upload methods.
 pub struct DataStream<R> {
@@ -107,8 +109,13 @@ impl fmt::Display for OemMultipartPartNameError {
 impl StdError for OemMultipartPartNameError {}
 
 /// Reader type used for OEM multipart form parts.
+#[cfg(not(feature = "unsend"))]
 pub type OemMultipartPartReader = Pin<Box<dyn AsyncRead + Send + 'static>>;
 
+/// Reader type used for OEM multipart form parts.
+#[cfg(feature = "unsend")]
+pub type OemMultipartPartReader = Pin<Box<dyn AsyncRead + 'static>>;
+
 /// OEM multipart form part.
 pub struct OemMultipartPart {
     /// Multipart part name.
@@ -171,6 +178,80 @@ impl OemMultipartPart {
     }
 }
 
+/// Reader type used for multipart form parts.
+#[cfg(not(feature = "unsend"))]
+pub type MultipartPartReader = Pin<Box<dyn AsyncRead + Send + 'static>>;
+
+/// Reader type used for multipart form parts.
+#[cfg(feature = "unsend")]
+pub type MultipartPartReader = Pin<Box<dyn AsyncRead + 'static>>;
+
+/// Multipart form part.
+pub struct MultipartPart {
+    /// Multipart part name.
+    pub name: String,
+
+    /// Streamed part data.
+    pub reader: MultipartPartReader,
+
+    /// Optional part filename.
+    pub file_name: Option<String>,
+
+    /// Optional part content type.
+    pub content_type: Option<String>,
+
+    /// Known part length, when available.
+    pub content_length: Option<u64>,
+}
+
+impl MultipartPart {
+    /// Create a multipart part.
+    #[must_use]
+    pub fn new(name: impl Into<String>, reader: impl UploadReader) -> Self {
+        Self {
+            name: name.into(),
+            reader: Box::pin(reader),
+            file_name: None,
+            content_type: None,
+            content_length: None,
+        }
+    }
+
+    /// Attach a filename.
+    #[must_use]
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Attach a content type.
+    #[must_use]
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Attach a known content length.
+    #[must_use]
+    pub const fn with_content_length(mut self, content_length: u64) -> Self {
+        self.content_length = Some(content_length);
+        self
+    }
+}
+
+/// Multipart upload request data.
+///
+/// Unlike [`MultipartUpdateRequest`], parts are sent exactly as
+/// provided, so this can be used for any `multipart/form-data`
+/// endpoint (for example, certificate import).
+pub struct MultipartRequest {
+    /// Parts of the form in the order they are sent.
+    pub parts: Vec<MultipartPart>,
+
+    /// Timeout used only for this upload request.
+    pub upload_timeout: Duration,
+}
+
 /// Multipart `UpdateService` upload request data.
 pub struct MultipartUpdateRequest<'a, U, V> {
     /// Redfish `UpdateParameters` JSON part.
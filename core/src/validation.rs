@@ -0,0 +1,294 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side validation of request payloads
+//!
+//! Generated create and update structures implement [`Validate`]
+//! using `Validation.Minimum`, `Validation.Maximum` and
+//! `Validation.Pattern` annotations and `MaxLength` of writable
+//! properties. This allows to reject invalid values before a request
+//! is sent to BMC.
+
+use regex::Regex;
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Validation of request payload against schema constraints.
+pub trait Validate {
+    /// Check that all values satisfy constraints of the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first violated constraint.
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// Violated schema constraint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Value is less than `Validation.Minimum`.
+    Minimum {
+        /// Name of the property.
+        property: &'static str,
+        /// Minimum allowed value.
+        minimum: i64,
+    },
+    /// Value is greater than `Validation.Maximum`.
+    Maximum {
+        /// Name of the property.
+        property: &'static str,
+        /// Maximum allowed value.
+        maximum: i64,
+    },
+    /// Value doesn't match `Validation.Pattern`.
+    Pattern {
+        /// Name of the property.
+        property: &'static str,
+        /// Pattern that value must match.
+        pattern: &'static str,
+    },
+    /// Value is longer than `MaxLength`.
+    MaxLength {
+        /// Name of the property.
+        property: &'static str,
+        /// Maximum number of characters.
+        max_length: usize,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Minimum { property, minimum } => {
+                write!(f, "{property}: value is less than minimum {minimum}")
+            }
+            Self::Maximum { property, maximum } => {
+                write!(f, "{property}: value is greater than maximum {maximum}")
+            }
+            Self::Pattern { property, pattern } => {
+                write!(f, "{property}: value doesn't match pattern {pattern}")
+            }
+            Self::MaxLength {
+                property,
+                max_length,
+            } => {
+                write!(
+                    f,
+                    "{property}: value is longer than {max_length} characters"
+                )
+            }
+        }
+    }
+}
+
+impl StdError for ValidationError {}
+
+/// Numeric value that can be checked against
+/// `Validation.Minimum` / `Validation.Maximum`.
+pub trait ValidationNumber {
+    /// Value is less than `v`.
+    fn is_less_than(&self, v: i64) -> bool;
+    /// Value is greater than `v`.
+    fn is_greater_than(&self, v: i64) -> bool;
+}
+
+impl ValidationNumber for i64 {
+    fn is_less_than(&self, v: i64) -> bool {
+        *self < v
+    }
+    fn is_greater_than(&self, v: i64) -> bool {
+        *self > v
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl ValidationNumber for f64 {
+    fn is_less_than(&self, v: i64) -> bool {
+        *self < v as Self
+    }
+    fn is_greater_than(&self, v: i64) -> bool {
+        *self > v as Self
+    }
+}
+
+/// Check `Validation.Minimum` constraint.
+///
+/// # Errors
+///
+/// Returns an error if value is less than minimum.
+pub fn validate_minimum<T: ValidationNumber>(
+    property: &'static str,
+    value: &T,
+    minimum: i64,
+) -> Result<(), ValidationError> {
+    if value.is_less_than(minimum) {
+        Err(ValidationError::Minimum { property, minimum })
+    } else {
+        Ok(())
+    }
+}
+
+/// Check `Validation.Maximum` constraint.
+///
+/// # Errors
+///
+/// Returns an error if value is greater than maximum.
+pub fn validate_maximum<T: ValidationNumber>(
+    property: &'static str,
+    value: &T,
+    maximum: i64,
+) -> Result<(), ValidationError> {
+    if value.is_greater_than(maximum) {
+        Err(ValidationError::Maximum { property, maximum })
+    } else {
+        Ok(())
+    }
+}
+
+/// `Validation.Pattern` of a property. The regular expression is
+/// compiled on first use, so generated code keeps patterns in
+/// statics and compiles each of them once.
+pub struct Pattern {
+    pattern: &'static str,
+    regex: OnceLock<Option<Regex>>,
+}
+
+impl Pattern {
+    /// Create pattern from the schema annotation.
+    #[must_use]
+    pub const fn new(pattern: &'static str) -> Self {
+        Self {
+            pattern,
+            regex: OnceLock::new(),
+        }
+    }
+
+    /// Pattern as written in the schema.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        self.pattern
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        // The CSDL compiler doesn't generate checks of patterns that
+        // cannot be compiled. Pattern that still cannot be compiled
+        // accepts any value, so that valid values are never rejected.
+        self.regex
+            .get_or_init(|| Regex::new(self.pattern).ok())
+            .as_ref()
+            .is_none_or(|re| re.is_match(value))
+    }
+}
+
+impl fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pattern").field(&self.pattern).finish()
+    }
+}
+
+/// Check `Validation.Pattern` constraint.
+///
+/// # Errors
+///
+/// Returns an error if value doesn't match the pattern.
+pub fn validate_pattern<T: fmt::Display + ?Sized>(
+    property: &'static str,
+    value: &T,
+    pattern: &Pattern,
+) -> Result<(), ValidationError> {
+    if pattern.is_match(&value.to_string()) {
+        Ok(())
+    } else {
+        Err(ValidationError::Pattern {
+            property,
+            pattern: pattern.as_str(),
+        })
+    }
+}
+
+/// Check `MaxLength` constraint.
+///
+/// # Errors
+///
+/// Returns an error if value has more than `max_length` characters.
+pub fn validate_max_length<T: fmt::Display + ?Sized>(
+    property: &'static str,
+    value: &T,
+    max_length: usize,
+) -> Result<(), ValidationError> {
+    if value.to_string().chars().count() > max_length {
+        Err(ValidationError::MaxLength {
+            property,
+            max_length,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_validation() {
+        assert!(validate_minimum("P", &0_i64, 0).is_ok());
+        assert_eq!(
+            validate_minimum("P", &-1_i64, 0),
+            Err(ValidationError::Minimum {
+                property: "P",
+                minimum: 0
+            })
+        );
+        assert!(validate_maximum("P", &100.0_f64, 100).is_ok());
+        assert_eq!(
+            validate_maximum("P", &100.5_f64, 100),
+            Err(ValidationError::Maximum {
+                property: "P",
+                maximum: 100
+            })
+        );
+    }
+
+    #[test]
+    fn pattern_validation() {
+        static PATTERN: Pattern = Pattern::new(r"^\d+\.\d+\.\d+$");
+        assert!(validate_pattern("P", "1.2.3", &PATTERN).is_ok());
+        assert_eq!(
+            validate_pattern("P", "1.2", &PATTERN),
+            Err(ValidationError::Pattern {
+                property: "P",
+                pattern: PATTERN.as_str(),
+            })
+        );
+        // Look-ahead is not supported by the engine.
+        assert!(validate_pattern("P", "x", &Pattern::new("^(?=y)")).is_ok());
+    }
+
+    #[test]
+    fn max_length_validation() {
+        assert!(validate_max_length("P", "ab", 2).is_ok());
+        // Length is counted in characters, not bytes.
+        assert!(validate_max_length("P", "\u{e9}\u{e9}", 2).is_ok());
+        assert_eq!(
+            validate_max_length("P", "abc", 2),
+            Err(ValidationError::MaxLength {
+                property: "P",
+                max_length: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Entry point for build scripts of downstream crates
+//!
+//! Vendors can ship OEM support in separate crates. The build script
+//! of such a crate compiles OEM CSDL documents and references
+//! standard Redfish types generated by `nv-redfish` instead of
+//! generating them again:
+//!
+//! ```no_run
+//! use nv_redfish_csdl_compiler::build::OemCompiler;
+//!
+//! OemCompiler::new()
+//!     .oem_csdls(["oem/Contoso_v1.xml"])
+//!     .resolve_csdls(["csdl/Resource_v1.xml", "csdl/Chassis_v1.xml"])
+//!     .external_module("nv_redfish::schema")
+//!     .compile("contoso.rs")
+//!     .expect("OEM schemas must be compiled");
+//! ```
+//!
+//! Standard types that are referenced by OEM schemas must be
+//! generated by the external crate, so features of `nv-redfish` that
+//! compile them must be enabled. OEM actions bound to standard types
+//! are not generated because structures of another crate cannot be
+//! extended.
+
+use crate::commands::process_command;
+use crate::commands::Commands;
+use crate::compiler::EntityTypeFilterPattern;
+use crate::compiler::PropertyPattern;
+use crate::Error;
+use std::path::PathBuf;
+
+/// Builder of OEM schema compilation.
+#[derive(Debug, Default)]
+pub struct OemCompiler {
+    oem_csdls: Vec<String>,
+    resolve_csdls: Vec<String>,
+    entity_type_patterns: Vec<EntityTypeFilterPattern>,
+    rigid_array_patterns: Vec<PropertyPattern>,
+    strict_references: bool,
+    reference_cache: Option<PathBuf>,
+    download_references: bool,
+    external_module: Option<String>,
+    stats: Option<PathBuf>,
+}
+
+impl OemCompiler {
+    /// Create compilation without documents.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add OEM documents. All types of these documents are compiled.
+    #[must_use]
+    pub fn oem_csdls<I, S>(mut self, csdls: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.oem_csdls.extend(csdls.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add documents used for type resolution in OEM documents.
+    #[must_use]
+    pub fn resolve_csdls<I, S>(mut self, csdls: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.resolve_csdls.extend(csdls.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add patterns of entity types to compile when referenced via a
+    /// navigation property. If no patterns are added, all entity
+    /// types are compiled.
+    #[must_use]
+    pub fn entity_type_patterns<I>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = EntityTypeFilterPattern>,
+    {
+        self.entity_type_patterns.extend(patterns);
+        self
+    }
+
+    /// Add patterns of properties that must be compiled with rigid
+    /// array support.
+    #[must_use]
+    pub fn rigid_array_patterns<I>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = PropertyPattern>,
+    {
+        self.rigid_array_patterns.extend(patterns);
+        self
+    }
+
+    /// Fail if any property references a type that is not found in
+    /// the loaded documents.
+    #[must_use]
+    pub const fn strict_references(mut self, v: bool) -> Self {
+        self.strict_references = v;
+        self
+    }
+
+    /// Load documents referenced by `edmx:Reference` from the cache
+    /// directory. If `download` is set, missing documents are
+    /// downloaded into the cache (requires the `remote-references`
+    /// feature).
+    #[must_use]
+    pub fn reference_cache(mut self, dir: impl Into<PathBuf>, download: bool) -> Self {
+        self.reference_cache = Some(dir.into());
+        self.download_references = download;
+        self
+    }
+
+    /// Reference types defined only by resolve documents through the
+    /// module of another crate (for example, `nv_redfish::schema`)
+    /// instead of generating them.
+    #[must_use]
+    pub fn external_module(mut self, module: impl Into<String>) -> Self {
+        self.external_module = Some(module.into());
+        self
+    }
+
+    /// Write statistics of generated code to the JSON file.
+    #[must_use]
+    pub fn stats(mut self, path: impl Into<PathBuf>) -> Self {
+        self.stats = Some(path.into());
+        self
+    }
+
+    /// Compile documents and write generated code to `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if documents cannot be read or compiled, or if
+    /// output cannot be written.
+    pub fn compile(self, output: impl Into<PathBuf>) -> Result<(), Error> {
+        process_command(&Commands::CompileOem {
+            root_csdls: self.oem_csdls,
+            resolve_csdls: self.resolve_csdls,
+            output: output.into(),
+            entity_type_patterns: self.entity_type_patterns,
+            rigid_array_patterns: self.rigid_array_patterns,
+            strict_references: self.strict_references,
+            reference_cache: self.reference_cache,
+            download_references: self.download_references,
+            external_module: self.external_module,
+            stats: self.stats,
+        })
+        .map(|_| ())
+    }
+}
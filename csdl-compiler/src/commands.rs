@@ -15,32 +15,58 @@
 
 //! Command-line entry points for the compiler
 //!
-//! Provides two subcommands used by build scripts or users:
+//! Provides subcommands used by build scripts or users:
 //! - `Compile`: parse and compile one or more CSDL/EDMX files starting
 //!   from a root singleton, then generate Rust to an output file.
 //! - `CompileOem`: compile OEM schemas into the root set (all types in
 //!   the OEM input) while resolving references from additional files.
+//! - `Payloads`: compile schemas like `Compile` and write randomized
+//!   schema-shaped JSON payloads of selected entity types for fuzz
+//!   tests (see `payload` module).
+//! - `Coverage`: compile schemas like `Payloads` and report schema
+//!   properties that were not recorded by the test Bmc (see `coverage`
+//!   module).
 //!
-//! Both commands:
+//! Both compile commands:
 //! - Read EDMX, build a `SchemaBundle`, and compile with optional
 //!   `EntityTypeFilter` patterns to limit navigation targets.
+//! - Optionally load documents for unresolved `edmx:Reference` entries
+//!   from a `ReferenceCache` (downloading them on request).
 //! - Optimize the compiled set and run the Rust generator.
+//! - `CompileOem` can reference types of resolved schemas generated
+//!   by another crate instead of generating them again.
 //! - Pretty-print the resulting syntax and write it to the `output` path.
 
+use crate::compiler::Compiled;
 use crate::compiler::Config as CompilerConfig;
 use crate::compiler::EntityTypeFilter;
 use crate::compiler::EntityTypeFilterPattern;
 use crate::compiler::PropertyFilter;
 use crate::compiler::PropertyPattern;
 use crate::compiler::SchemaBundle;
+use crate::coverage::CoverageReport;
+use crate::coverage::Recorded;
+use crate::diagnostics::SourceFile;
+use crate::edmx::apply_external_annotations;
 use crate::edmx::Edmx;
 use crate::generator::rust::Config as GeneratorConfig;
+use crate::generator::rust::ExternalTypes;
 use crate::generator::rust::RustGenerator;
 use crate::optimizer::optimize;
 use crate::optimizer::Config as OptimizerConfig;
+use crate::payload::Config as PayloadConfig;
+use crate::payload::PayloadGenerator;
+use crate::redfish::enumeration::apply_enumerations;
+use crate::reference_cache::missing_references;
+use crate::reference_cache::ReferenceCache;
+use crate::stats::Report;
 use crate::Error;
 use clap::Subcommand;
+use quote::ToTokens as _;
 use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::fs::read_dir;
+use std::fs::read_to_string;
 use std::fs::write;
 use std::fs::File;
 use std::io::Read as _;
@@ -93,6 +119,34 @@ pub enum Commands {
         /// `EthernetInterface.*.EthernetInterface/StaticNameServers` - matches `StaticNameServers` property of `EthernetInterface`
         #[arg(short = 'a', long = "rigid-arrays")]
         rigid_array_patterns: Vec<PropertyPattern>,
+        /// Patterns of properties of lean types. Types matched by any
+        /// pattern are generated only with matching properties; other
+        /// properties are kept in `additional_properties`.
+        ///
+        /// Pattern is a wildcard over the qualified name.
+        /// Examples:
+        /// `Chassis.*.Chassis/Status` - only `Status` property of `Chassis`
+        #[arg(short = 'l', long = "lean-properties")]
+        lean_property_patterns: Vec<PropertyPattern>,
+        /// Fail if any property references a type that is not found in
+        /// the loaded CSDL documents. All unresolved types are listed.
+        #[arg(long = "strict-references")]
+        strict_references: bool,
+        /// Directory with cached documents referenced via
+        /// `edmx:Reference` to DMTF schema URLs. Documents from this
+        /// directory are loaded when referenced namespaces are not
+        /// defined by the specified CSDL documents.
+        #[arg(long = "reference-cache")]
+        reference_cache: Option<PathBuf>,
+        /// Download referenced documents that are missing in the
+        /// reference cache and store them in the cache. Requires the
+        /// `remote-references` feature.
+        #[arg(long = "download-references", requires = "reference_cache")]
+        download_references: bool,
+        /// Write statistics of generated code (types, lines per
+        /// module, largest structs) to this JSON file.
+        #[arg(long = "stats")]
+        stats: Option<PathBuf>,
     },
     /// Compile OEM CSDL schemas.
     CompileOem {
@@ -123,6 +177,83 @@ pub enum Commands {
         /// `EthernetInterface.*.EthernetInterface/StaticNameServers` - matches `StaticNameServers` property of `EthernetInterface`
         #[arg(short = 'a', long = "rigid-arrays")]
         rigid_array_patterns: Vec<PropertyPattern>,
+        /// Fail if any property references a type that is not found in
+        /// the loaded CSDL documents. All unresolved types are listed.
+        #[arg(long = "strict-references")]
+        strict_references: bool,
+        /// Directory with cached documents referenced via
+        /// `edmx:Reference` to DMTF schema URLs. Documents from this
+        /// directory are loaded when referenced namespaces are not
+        /// defined by the specified CSDL documents.
+        #[arg(long = "reference-cache")]
+        reference_cache: Option<PathBuf>,
+        /// Download referenced documents that are missing in the
+        /// reference cache and store them in the cache. Requires the
+        /// `remote-references` feature.
+        #[arg(long = "download-references", requires = "reference_cache")]
+        download_references: bool,
+        /// Rust path of the module where types of `resolve_csdls`
+        /// are already generated (for example, `nv_redfish::schema`).
+        /// If set, types from namespaces that are defined only in
+        /// `resolve_csdls` are not generated and are referenced by
+        /// this path.
+        #[arg(long = "external-module")]
+        external_module: Option<String>,
+        /// Write statistics of generated code (types, lines per
+        /// module, largest structs) to this JSON file.
+        #[arg(long = "stats")]
+        stats: Option<PathBuf>,
+    },
+    /// Generate randomized payloads of entity types for fuzz tests.
+    Payloads {
+        /// Root service to compile (one of the root singletons in
+        /// the EDM document).
+        #[arg(short, long, default_value = DEFAULT_ROOT)]
+        root: String,
+        /// Patterns of entity types to be included to root set even
+        /// if they are not referenced from root.
+        #[arg(short = 'i', long = "include-root-pattern")]
+        include_root_patterns: Vec<EntityTypeFilterPattern>,
+        /// CSDL documents to compile.
+        #[arg(required = true)]
+        csdls: Vec<String>,
+        /// Output JSON file with payloads grouped by entity type.
+        #[arg(short, long, default_value = "payloads.json")]
+        output: PathBuf,
+        /// Patterns of entity types to generate payloads for.
+        ///
+        /// Pattern is a wildcard over the qualified name.
+        /// Examples:
+        /// `ServiceRoot.*.*` - any entity type in any version of the service root
+        #[arg(short = 't', long = "type", required = true)]
+        type_patterns: Vec<EntityTypeFilterPattern>,
+        /// Number of payloads per entity type.
+        #[arg(short = 'n', long, default_value_t = 32)]
+        count: usize,
+        /// Seed of the payload generator.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Report schema properties not exercised by tests.
+    Coverage {
+        /// Root service to compile (one of the root singletons in
+        /// the EDM document).
+        #[arg(short, long, default_value = DEFAULT_ROOT)]
+        root: String,
+        /// Patterns of entity types to be included to root set even
+        /// if they are not referenced from root.
+        #[arg(short = 'i', long = "include-root-pattern")]
+        include_root_patterns: Vec<EntityTypeFilterPattern>,
+        /// CSDL documents to compile.
+        #[arg(required = true)]
+        csdls: Vec<String>,
+        /// Directory with JSON files of properties recorded by the
+        /// test Bmc (`NV_REDFISH_COVERAGE_DIR` of the test run).
+        #[arg(long)]
+        records: PathBuf,
+        /// Output JSON file with the coverage report.
+        #[arg(short, long, default_value = "coverage.json")]
+        output: PathBuf,
     },
 }
 
@@ -131,8 +262,8 @@ pub enum Commands {
 /// # Errors
 ///
 /// Returns an error if command processing fails.
+#[allow(clippy::too_many_lines)]
 pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
-    let mut display_output = Vec::new();
     match command {
         Commands::Compile {
             root,
@@ -141,12 +272,20 @@ pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
             output,
             entity_type_patterns,
             rigid_array_patterns,
+            lean_property_patterns,
+            strict_references,
+            reference_cache,
+            download_references,
+            stats,
         } => {
             let root_service = root.parse().map_err(Error::WrongRootService)?;
             if csdls.is_empty() {
                 return Err(Error::AtLeastOneCSDLFileNeeded);
             }
-            let schema_bundle = read_csdls(&[], csdls)?;
+            let reference_cache = reference_cache
+                .as_ref()
+                .map(|dir| ReferenceCache::new(dir.clone(), *download_references));
+            let (schema_bundle, sources) = read_csdls(&[], csdls, reference_cache.as_ref())?;
             let compiled = schema_bundle
                 .compile(
                     &[root_service],
@@ -156,19 +295,26 @@ pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
                             entity_type_patterns.clone(),
                         ),
                         rigid_array_filter: PropertyFilter::new(rigid_array_patterns.clone()),
+                        lean_property_filter: PropertyFilter::new(lean_property_patterns.clone()),
+                        strict_references: *strict_references,
                     },
                 )
-                .map_err(Error::compile_error)?;
-            let compiled = optimize(compiled, &OptimizerConfig::default());
+                .map_err(|e| {
+                    Error::located_compile_error(SourceFile::locate_compile_error(&sources, &e), e)
+                })?;
+            let compiled = optimize(
+                compiled,
+                &OptimizerConfig {
+                    reachable_from: Some(EntityTypeFilter::new_restrictive(
+                        include_root_patterns.clone(),
+                    )),
+                    ..OptimizerConfig::default()
+                },
+            );
+            let warnings = unsupported_patterns(&compiled);
             let generator = RustGenerator::new(compiled, GeneratorConfig::default())
                 .map_err(Error::generate_error)?;
-
-            let result = generator.generate().to_string();
-            let syntax_tree = syn::parse_file(&result).map_err(Error::ParseGenerated)?;
-            write(output, prettyplease::unparse(&syntax_tree))
-                .map_err(|e| Error::WriteOutput(output.clone(), e))?;
-            display_output.push(format!("{} file has been written", output.display()));
-            Ok(display_output)
+            write_generated(generator, output, stats.as_ref()).map(|v| [warnings, v].concat())
         }
         Commands::CompileOem {
             root_csdls,
@@ -176,61 +322,292 @@ pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
             output,
             entity_type_patterns,
             rigid_array_patterns,
+            strict_references,
+            reference_cache,
+            download_references,
+            external_module,
+            stats,
         } => {
             if root_csdls.is_empty() {
                 return Err(Error::AtLeastOneCSDLFileNeeded);
             }
-            let schema_bundle = read_csdls(root_csdls, resolve_csdls)?;
+            let reference_cache = reference_cache
+                .as_ref()
+                .map(|dir| ReferenceCache::new(dir.clone(), *download_references));
+            let (schema_bundle, sources) =
+                read_csdls(root_csdls, resolve_csdls, reference_cache.as_ref())?;
             let compiled = schema_bundle
                 .compile_all(CompilerConfig {
                     entity_type_filter: EntityTypeFilter::new_permissive(
                         entity_type_patterns.clone(),
                     ),
                     rigid_array_filter: PropertyFilter::new(rigid_array_patterns.clone()),
+                    lean_property_filter: PropertyFilter::default(),
+                    strict_references: *strict_references,
                 })
-                .map_err(Error::compile_error)?;
+                .map_err(|e| {
+                    Error::located_compile_error(SourceFile::locate_compile_error(&sources, &e), e)
+                })?;
             let compiled = optimize(compiled, &OptimizerConfig::default());
-            let generator = RustGenerator::new(compiled, GeneratorConfig::default())
-                .map_err(Error::generate_error)?;
-            let result = generator.generate().to_string();
-            let syntax_tree = syn::parse_file(&result).map_err(Error::ParseGenerated)?;
-            write(output, prettyplease::unparse(&syntax_tree))
-                .map_err(|e| Error::WriteOutput(output.clone(), e))?;
-            display_output.push(format!("{} file has been written", output.display()));
-            Ok(display_output)
+            let warnings = unsupported_patterns(&compiled);
+            let external_types = external_module
+                .as_ref()
+                .map(|module| external_types(module, &schema_bundle.edmx_docs, root_csdls.len()))
+                .transpose()?;
+            let generator = RustGenerator::new(
+                compiled,
+                GeneratorConfig {
+                    external_types,
+                    ..GeneratorConfig::default()
+                },
+            )
+            .map_err(Error::generate_error)?;
+            write_generated(generator, output, stats.as_ref()).map(|v| [warnings, v].concat())
+        }
+        Commands::Payloads {
+            root,
+            include_root_patterns,
+            csdls,
+            output,
+            type_patterns,
+            count,
+            seed,
+        } => write_payloads(
+            root,
+            include_root_patterns,
+            csdls,
+            type_patterns,
+            *count,
+            *seed,
+            output,
+        ),
+        Commands::Coverage {
+            root,
+            include_root_patterns,
+            csdls,
+            records,
+            output,
+        } => write_coverage(root, include_root_patterns, csdls, records, output),
+    }
+}
+
+// Warnings about patterns of writable properties that cannot be
+// checked by generated code.
+fn unsupported_patterns(compiled: &Compiled<'_>) -> Vec<String> {
+    let mut warnings = compiled
+        .entity_types
+        .values()
+        .map(|t| (t.name, &t.properties))
+        .chain(
+            compiled
+                .complex_types
+                .values()
+                .map(|t| (t.name, &t.properties)),
+        )
+        .flat_map(|(qtype, properties)| {
+            properties
+                .properties
+                .iter()
+                .filter(|p| {
+                    p.odata.permissions_is_write() || p.redfish.is_required_on_create.into_inner()
+                })
+                .filter_map(move |p| {
+                    p.validation.unsupported_pattern.map(|pattern| {
+                        format!(
+                            "warning: {qtype}/{}: pattern {pattern} is not supported and is not checked",
+                            p.name
+                        )
+                    })
+                })
+        })
+        .collect::<Vec<_>>();
+    warnings.sort();
+    warnings
+}
+
+fn write_generated(
+    generator: RustGenerator<'_>,
+    output: &PathBuf,
+    stats: Option<&PathBuf>,
+) -> Result<Vec<String>, Error> {
+    let result = generator.generate().to_string();
+    let syntax_tree = syn::parse_file(&result).map_err(Error::ParseGenerated)?;
+    write(output, prettyplease::unparse(&syntax_tree))
+        .map_err(|e| Error::WriteOutput(output.clone(), e))?;
+    let mut display_output = vec![format!("{} file has been written", output.display())];
+    if let Some(stats) = stats {
+        write(stats, Report::new(&syntax_tree).to_json())
+            .map_err(|e| Error::WriteOutput(stats.clone(), e))?;
+        display_output.push(format!("{} file has been written", stats.display()));
+    }
+    Ok(display_output)
+}
+
+fn write_payloads(
+    root: &str,
+    include_root_patterns: &[EntityTypeFilterPattern],
+    csdls: &[String],
+    type_patterns: &[EntityTypeFilterPattern],
+    count: usize,
+    seed: u64,
+    output: &PathBuf,
+) -> Result<Vec<String>, Error> {
+    let payloads = with_reachable(root, include_root_patterns, csdls, |compiled| {
+        let types = EntityTypeFilter::new_restrictive(type_patterns.to_vec());
+        let mut generator = PayloadGenerator::new(
+            compiled,
+            PayloadConfig {
+                seed,
+                ..PayloadConfig::default()
+            },
+        );
+        compiled
+            .entity_types
+            .iter()
+            .filter(|(qtype, et)| types.matches(qtype) && !et.is_abstract.into_inner())
+            .map(|(qtype, _)| {
+                let payloads = (0..count)
+                    .filter_map(|_| generator.entity_type(*qtype))
+                    .collect::<Vec<_>>();
+                (qtype.to_string(), payloads)
+            })
+            .collect::<BTreeMap<_, _>>()
+    })?;
+    let content = serde_json::to_string_pretty(&payloads)
+        .map_err(|e| Error::WriteOutput(output.clone(), e.into()))?;
+    write(output, content).map_err(|e| Error::WriteOutput(output.clone(), e))?;
+    Ok(vec![format!("{} file has been written", output.display())])
+}
+
+fn write_coverage(
+    root: &str,
+    include_root_patterns: &[EntityTypeFilterPattern],
+    csdls: &[String],
+    records: &PathBuf,
+    output: &PathBuf,
+) -> Result<Vec<String>, Error> {
+    let records_name = || records.display().to_string();
+    let mut recorded = Recorded::new();
+    for entry in read_dir(records).map_err(|e| Error::Io(records_name(), e))? {
+        let path = entry.map_err(|e| Error::Io(records_name(), e))?.path();
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        let fname = path.display().to_string();
+        let content = read_to_string(&path).map_err(|e| Error::Io(fname.clone(), e))?;
+        let record: Recorded =
+            serde_json::from_str(&content).map_err(|e| Error::Io(fname, e.into()))?;
+        for (rtype, properties) in record {
+            recorded.entry(rtype).or_default().extend(properties);
         }
     }
+    let report = with_reachable(root, include_root_patterns, csdls, |compiled| {
+        CoverageReport::new(compiled, &recorded)
+    })?;
+    write(output, report.to_json()).map_err(|e| Error::WriteOutput(output.clone(), e))?;
+    Ok(vec![
+        format!(
+            "{} of {} schema properties are covered",
+            report.covered, report.properties
+        ),
+        format!("{} file has been written", output.display()),
+    ])
 }
 
-fn read_csdls(root_csdls: &[String], resolve_csdls: &[String]) -> Result<SchemaBundle, Error> {
+/// Compile `csdls` restricted to types reachable from the root set
+/// and apply `f` to the result.
+fn with_reachable<T>(
+    root: &str,
+    include_root_patterns: &[EntityTypeFilterPattern],
+    csdls: &[String],
+    f: impl FnOnce(&Compiled<'_>) -> T,
+) -> Result<T, Error> {
+    let root_service = root.parse().map_err(Error::WrongRootService)?;
+    if csdls.is_empty() {
+        return Err(Error::AtLeastOneCSDLFileNeeded);
+    }
+    let (schema_bundle, sources) = read_csdls(&[], csdls, None)?;
+    let compiled = schema_bundle
+        .compile(
+            &[root_service],
+            &EntityTypeFilter::new_restrictive(include_root_patterns.to_vec()),
+            CompilerConfig::default(),
+        )
+        .map_err(|e| {
+            Error::located_compile_error(SourceFile::locate_compile_error(&sources, &e), e)
+        })?;
+    let compiled = optimize(
+        compiled,
+        &OptimizerConfig {
+            reachable_from: Some(EntityTypeFilter::new_restrictive(
+                include_root_patterns.to_vec(),
+            )),
+            ..OptimizerConfig::default()
+        },
+    );
+    Ok(f(&compiled))
+}
+
+fn read_csdls(
+    root_csdls: &[String],
+    resolve_csdls: &[String],
+    reference_cache: Option<&ReferenceCache>,
+) -> Result<(SchemaBundle, Vec<SourceFile>), Error> {
     let csdls = root_csdls
         .iter()
         .chain(resolve_csdls.iter())
         .collect::<Vec<_>>();
-    let edmx_docs = csdls
+    let mut sources = csdls
         .iter()
         .map(|fname| {
             let mut file = File::open(fname).map_err(|err| Error::Io((*fname).clone(), err))?;
             let mut content = String::new();
             file.read_to_string(&mut content)
                 .map_err(|err| Error::Io((*fname).clone(), err))?;
-            Edmx::parse(&content).map_err(|e| Error::Edmx((*fname).clone(), e))
+            Ok(SourceFile::new((*fname).clone(), content))
         })
         .collect::<Result<Vec<_>, _>>()?;
+    let mut edmx_docs = sources
+        .iter()
+        .map(parse_source)
+        .collect::<Result<Vec<_>, _>>()?;
 
-    csdls
+    if let Some(cache) = reference_cache {
+        // Referenced documents can have references too, so repeat
+        // until all reachable references are visited.
+        let mut visited = HashSet::new();
+        loop {
+            let uris = missing_references(&edmx_docs)
+                .into_iter()
+                .filter(|uri| !visited.contains(*uri))
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            if uris.is_empty() {
+                break;
+            }
+            for uri in uris {
+                if let Some(source) = cache.fetch(&uri)? {
+                    edmx_docs.push(parse_source(&source)?);
+                    sources.push(source);
+                }
+                visited.insert(uri);
+            }
+        }
+    }
+
+    sources
         .iter()
         .zip(&edmx_docs)
-        .flat_map(|(fname, edmx)| {
+        .flat_map(|(source, edmx)| {
             edmx.data_services
                 .schemas
                 .iter()
-                .map(move |schema| (schema.namespace.to_string(), fname))
+                .map(move |schema| (schema.namespace.to_string(), &source.path))
         })
         .fold(
             BTreeMap::<String, Vec<String>>::new(),
             |mut map, (namespace, fname)| {
-                map.entry(namespace).or_default().push((*fname).clone());
+                map.entry(namespace).or_default().push(fname.clone());
                 map
             },
         )
@@ -240,12 +617,43 @@ fn read_csdls(root_csdls: &[String], resolve_csdls: &[String]) -> Result<SchemaB
             Err(Error::DuplicateNamespace(namespace, files))
         })?;
 
-    Ok(SchemaBundle {
-        edmx_docs,
-        root_set_threshold: if root_csdls.is_empty() {
-            None
-        } else {
-            Some(root_csdls.len())
+    apply_external_annotations(&mut edmx_docs);
+    apply_enumerations(&mut edmx_docs);
+
+    Ok((
+        SchemaBundle {
+            edmx_docs,
+            root_set_threshold: if root_csdls.is_empty() {
+                None
+            } else {
+                Some(root_csdls.len())
+            },
         },
-    })
+        sources,
+    ))
+}
+
+// Root namespaces that are defined only by documents used for
+// resolution (documents after first `root_count` ones).
+fn external_types(module: &str, docs: &[Edmx], root_count: usize) -> Result<ExternalTypes, Error> {
+    let module = syn::parse_str::<syn::Path>(module)
+        .map_err(|err| Error::InvalidExternalModule(module.into(), err))?
+        .into_token_stream();
+    let root_namespaces = |docs: &[Edmx]| {
+        docs.iter()
+            .flat_map(|doc| doc.data_services.schemas.iter())
+            .filter_map(|schema| schema.namespace.ids.first().map(ToString::to_string))
+            .collect::<HashSet<_>>()
+    };
+    let (root_docs, resolve_docs) = docs.split_at(root_count.min(docs.len()));
+    let local = root_namespaces(root_docs);
+    let namespaces = root_namespaces(resolve_docs)
+        .into_iter()
+        .filter(|ns| !local.contains(ns))
+        .collect();
+    Ok(ExternalTypes { module, namespaces })
+}
+
+fn parse_source(source: &SourceFile) -> Result<Edmx, Error> {
+    Edmx::parse(&source.content).map_err(|e| Error::Edmx(source.locate_validate_error(&e), e))
 }
@@ -97,15 +97,21 @@ pub(crate) fn compile_action<'a>(
         return Ok(Compiled::default());
     }
     let stack = stack.new_frame();
-    // Compile ReturnType if present.
+    // Compile ReturnType if present. Like parameters, return value can
+    // be an entity (for example, a resource created by the action).
     let (compiled_rt, return_type) = action
         .return_type
         .as_ref()
         .map_or_else(
             || Ok((Compiled::default(), None)),
             |rt| {
-                ensure_type(rt.rtype.qualified_type_name().into(), ctx, &stack)
-                    .map(|(compiled, _)| (compiled, Some(rt.rtype.as_ref().map(Into::into))))
+                let qtype_name = rt.rtype.qualified_type_name().into();
+                if is_simple_type(qtype_name) || ctx.schema_index.find_type(qtype_name).is_some() {
+                    ensure_type(qtype_name, ctx, &stack).map(|(compiled, _)| compiled)
+                } else {
+                    EntityType::ensure(qtype_name, ctx, &stack)
+                }
+                .map(|compiled| (compiled, Some(rt.rtype.as_ref().map(Into::into))))
             },
         )
         .map_err(Box::new)
@@ -230,6 +230,33 @@ impl<'a> Compiled<'a> {
         );
         forced
     }
+
+    /// Names of `@odata.type` accepted for each entity type: its own
+    /// name and names of all entity types derived from it. Cycles in
+    /// the inheritance chain are cut by the set of visited names.
+    #[must_use]
+    pub fn odata_type_names(&self) -> HashMap<QualifiedName<'a>, Vec<&'a str>> {
+        let mut result = HashMap::<_, Vec<_>>::new();
+        for t in self.entity_types.values() {
+            let mut visited = HashSet::new();
+            let mut next = Some(t.name);
+            while let Some(name) = next {
+                if !visited.insert(name) {
+                    break;
+                }
+                result
+                    .entry(name)
+                    .or_default()
+                    .push(t.name.name.inner().as_str());
+                next = self.entity_types.get(&name).and_then(|v| v.base);
+            }
+        }
+        for names in result.values_mut() {
+            names.sort_unstable();
+            names.dedup();
+        }
+        result
+    }
 }
 
 /// Walk each updating type's base chain and record its ancestors in
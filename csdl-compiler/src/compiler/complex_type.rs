@@ -109,12 +109,20 @@ pub(crate) fn compile<'a>(
     let (compiled, properties) =
         Properties::compile(qtype, &ct.properties, ctx, stack.new_frame())?;
 
+    let odata = OData::new(MustHaveId::new(false), ct);
+    // Properties of lean types that are not compiled are kept as
+    // additional properties.
+    let odata = if ctx.config.lean_property_filter.matches_type(qtype) {
+        odata.with_additional_properties()
+    } else {
+        odata
+    };
     let complex_type = ComplexType {
         name,
         base,
         properties,
-        odata: OData::new(MustHaveId::new(false), ct),
-        redfish: Redfish::new(ct),
+        odata,
+        redfish: Redfish::new(ct, ct.is_open_type),
         is_abstract: ct.is_abstract,
     };
     let typeinfo = TypeInfo::complex_type(&complex_type);
@@ -55,6 +55,12 @@ pub struct Config {
     pub entity_type_filter: EntityTypeFilter,
     /// Array properties that should be generated as rigid.
     pub rigid_array_filter: PropertyFilter,
+    /// Properties of lean types. Types matched by any pattern are
+    /// compiled only with matching properties.
+    pub lean_property_filter: PropertyFilter,
+    /// Fail compilation if any property references a type that is not
+    /// found in the loaded schemas.
+    pub strict_references: bool,
 }
 
 /// Entity type filter specified by wildcard patterns.
@@ -109,9 +115,11 @@ impl EntityTypeFilter {
 /// `ServiceRoot.*.*` - any `EntityType` in any version of the service root
 /// `SomeNamespace.*.Entity1|Entity2` - `EntityType1` or `EntityType2` from any version of namespace `SomeNamespace`
 /// `*.*.Entity1|Entity2` - `EntityType1` or `EntityType2` from any version of any namespace
+/// `Sensor.>=v1_2_0.*` - any `EntityType` in version `v1_2_0` or later of `Sensor`
+/// `Sensor.v1_2_0-v1_5_0.*` - any `EntityType` in versions `v1_2_0` to `v1_5_0` (inclusive) of `Sensor`
 #[derive(Clone, Debug)]
 pub struct EntityTypeFilterPattern {
-    ns_ids: Vec<Option<SimpleIdentifier>>,
+    ns_ids: Vec<NamespaceIdPattern>,
     names: HashSet<SimpleIdentifier>,
 }
 
@@ -125,16 +133,12 @@ impl EntityTypeFilterPattern {
         if typename.namespace.len() != self.ns_ids.len() {
             return false;
         }
-        for depth in 0..typename.namespace.len() {
-            if let Some(pattern_id) = &self.ns_ids[depth] {
-                if let Some(ns) = typename.namespace.get_id(depth) {
-                    if pattern_id != ns {
-                        return false;
-                    }
-                }
-            }
-        }
-        true
+        (0..typename.namespace.len()).all(|depth| {
+            typename
+                .namespace
+                .get_id(depth)
+                .is_none_or(|ns| self.ns_ids[depth].matches(ns))
+        })
     }
 }
 
@@ -156,15 +160,7 @@ impl FromStr for EntityTypeFilterPattern {
             };
             let ns_ids = ids
                 .into_iter()
-                .map(|id| {
-                    if id == "*" {
-                        Ok(None)
-                    } else {
-                        id.parse()
-                            .map(Some)
-                            .map_err(|_| FilterPatternError::InvalidIdentifier(id.into()))
-                    }
-                })
+                .map(str::parse)
                 .collect::<Result<Vec<_>, _>>()?;
             Ok(Self { ns_ids, names })
         } else {
@@ -173,6 +169,122 @@ impl FromStr for EntityTypeFilterPattern {
     }
 }
 
+/// Pattern for one identifier of the namespace.
+#[derive(Clone, Debug)]
+enum NamespaceIdPattern {
+    /// `*` - any identifier.
+    Any,
+    /// Exact identifier.
+    Exact(SimpleIdentifier),
+    /// Range of schema versions (`v1_2_0-v1_5_0`, `>=v1_2_0`, `<v1_5_0` etc).
+    Versions(VersionRange),
+}
+
+impl NamespaceIdPattern {
+    fn matches(&self, id: &SimpleIdentifier) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Exact(v) => v == id,
+            Self::Versions(range) => id
+                .inner()
+                .parse::<SchemaVersion>()
+                .is_ok_and(|v| range.contains(v)),
+        }
+    }
+}
+
+impl FromStr for NamespaceIdPattern {
+    type Err = FilterPatternError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "*" {
+            Ok(Self::Any)
+        } else if s.contains(['-', '<', '>', '=']) {
+            s.parse().map(Self::Versions)
+        } else {
+            s.parse()
+                .map(Self::Exact)
+                .map_err(|_| FilterPatternError::InvalidIdentifier(s.into()))
+        }
+    }
+}
+
+/// Redfish schema version encoded in namespace as `vMAJOR_MINOR_ERRATA`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct SchemaVersion(u32, u32, u32);
+
+impl FromStr for SchemaVersion {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s
+            .strip_prefix('v')
+            .ok_or(())?
+            .split('_')
+            .map(|v| v.parse::<u32>().map_err(|_| ()));
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(major), Some(minor), Some(errata), None) => Ok(Self(major?, minor?, errata?)),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Version range bound.
+#[derive(Clone, Copy, Debug)]
+enum VersionBound {
+    Unbounded,
+    Included(SchemaVersion),
+    Excluded(SchemaVersion),
+}
+
+/// Range of schema versions.
+#[derive(Clone, Copy, Debug)]
+struct VersionRange {
+    from: VersionBound,
+    to: VersionBound,
+}
+
+impl VersionRange {
+    fn contains(&self, v: SchemaVersion) -> bool {
+        let after_from = match self.from {
+            VersionBound::Unbounded => true,
+            VersionBound::Included(from) => v >= from,
+            VersionBound::Excluded(from) => v > from,
+        };
+        let before_to = match self.to {
+            VersionBound::Unbounded => true,
+            VersionBound::Included(to) => v <= to,
+            VersionBound::Excluded(to) => v < to,
+        };
+        after_from && before_to
+    }
+}
+
+impl FromStr for VersionRange {
+    type Err = FilterPatternError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let version = |v: &str| {
+            v.parse::<SchemaVersion>()
+                .map_err(|()| FilterPatternError::InvalidVersionRange(s.into()))
+        };
+        let (from, to) = if let Some(v) = s.strip_prefix(">=") {
+            (VersionBound::Included(version(v)?), VersionBound::Unbounded)
+        } else if let Some(v) = s.strip_prefix("<=") {
+            (VersionBound::Unbounded, VersionBound::Included(version(v)?))
+        } else if let Some(v) = s.strip_prefix('>') {
+            (VersionBound::Excluded(version(v)?), VersionBound::Unbounded)
+        } else if let Some(v) = s.strip_prefix('<') {
+            (VersionBound::Unbounded, VersionBound::Excluded(version(v)?))
+        } else if let Some((from, to)) = s.split_once('-') {
+            (
+                VersionBound::Included(version(from)?),
+                VersionBound::Included(version(to)?),
+            )
+        } else {
+            return Err(FilterPatternError::InvalidVersionRange(s.into()));
+        };
+        Ok(Self { from, to })
+    }
+}
+
 impl<'de> Deserialize<'de> for EntityTypeFilterPattern {
     fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
         struct ValVisitor {}
@@ -197,6 +309,8 @@ pub enum FilterPatternError {
     EmptyPattern,
     /// The pattern contains an invalid identifier.
     InvalidIdentifier(String),
+    /// The pattern contains an invalid version range.
+    InvalidVersionRange(String),
 }
 
 impl StdError for FilterPatternError {}
@@ -206,6 +320,7 @@ impl Display for FilterPatternError {
         match self {
             Self::EmptyPattern => write!(f, "empty pattern is forbidden"),
             Self::InvalidIdentifier(v) => write!(f, "invalid pattern: {v}"),
+            Self::InvalidVersionRange(v) => write!(f, "invalid version range: {v}"),
         }
     }
 }
@@ -215,23 +330,36 @@ impl Display for FilterPatternError {
 #[derive(Default)]
 pub struct PropertyFilter {
     search_index: HashMap<PropertyName, EntityTypeFilter>,
+    wildcards: Vec<(PropertyNameWildcard, EntityTypeFilterPattern)>,
+    type_filters: Vec<EntityTypeFilterPattern>,
 }
 
 impl PropertyFilter {
     /// Create a new filter from a list of patterns.
     #[must_use]
     pub fn new(patterns: Vec<PropertyPattern>) -> Self {
-        let search_index = patterns
-            .into_iter()
-            .map(|p| (p.property_name, p.type_filter))
-            .fold(HashMap::<_, Vec<_>>::new(), |mut m, (k, v)| {
-                m.entry(k).or_default().push(v);
-                m
-            })
+        let type_filters = patterns.iter().map(|p| p.type_filter.clone()).collect();
+        let (exact, wildcards) = patterns.into_iter().fold(
+            (HashMap::<_, Vec<_>>::new(), Vec::new()),
+            |(mut exact, mut wildcards), p| {
+                match p.property_name {
+                    PropertyNamePattern::Exact(name) => {
+                        exact.entry(name).or_default().push(p.type_filter);
+                    }
+                    PropertyNamePattern::Wildcard(w) => wildcards.push((w, p.type_filter)),
+                }
+                (exact, wildcards)
+            },
+        );
+        let search_index = exact
             .into_iter()
             .map(|(name, vec)| (name, EntityTypeFilter::new_restrictive(vec)))
             .collect();
-        Self { search_index }
+        Self {
+            search_index,
+            wildcards,
+            type_filters,
+        }
     }
     /// Check if propety with pname of type with qualified name qtype
     /// matches filter.
@@ -240,17 +368,31 @@ impl PropertyFilter {
         self.search_index
             .get(pname)
             .is_some_and(|f| f.matches(&qtype))
+            || self
+                .wildcards
+                .iter()
+                .any(|(w, f)| w.matches(pname) && f.matches(&qtype))
+    }
+
+    /// Check if any pattern of the filter matches type with
+    /// qualified name qtype.
+    #[must_use]
+    pub fn matches_type(&self, qtype: QualifiedName) -> bool {
+        self.type_filters.iter().any(|f| f.matches(&qtype))
     }
 }
 
 /// Property pattern is
-/// `QualifiedTypePattern/PropertyName`
+/// `QualifiedTypePattern/PropertyNamePattern`
+///
 /// Where
 ///   `QualifiedTypePattern` is `EntityTypeFilterPattern`.
+///   `PropertyNamePattern` is property name where `*` matches any
+///   sequence of characters (`Related*`, `*Servers`, `*`).
 #[derive(Clone, Debug)]
 pub struct PropertyPattern {
     type_filter: EntityTypeFilterPattern,
-    property_name: PropertyName,
+    property_name: PropertyNamePattern,
 }
 
 impl FromStr for PropertyPattern {
@@ -258,7 +400,14 @@ impl FromStr for PropertyPattern {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some((type_filter, property_name)) = s.rsplit_once('/') {
             let type_filter = type_filter.parse().map_err(Self::Err::TypeFilterPattern)?;
-            let property_name = property_name.parse().map_err(Self::Err::PropertyName)?;
+            let property_name = if property_name.contains('*') {
+                PropertyNameWildcard::new(property_name).map(PropertyNamePattern::Wildcard)?
+            } else {
+                property_name
+                    .parse()
+                    .map(PropertyNamePattern::Exact)
+                    .map_err(Self::Err::PropertyName)?
+            };
             Ok(Self {
                 type_filter,
                 property_name,
@@ -269,6 +418,50 @@ impl FromStr for PropertyPattern {
     }
 }
 
+/// Property name part of the `PropertyPattern`.
+#[derive(Clone, Debug)]
+enum PropertyNamePattern {
+    Exact(PropertyName),
+    Wildcard(PropertyNameWildcard),
+}
+
+/// Property name with `*` wildcards.
+#[derive(Clone, Debug)]
+struct PropertyNameWildcard(String);
+
+impl PropertyNameWildcard {
+    fn new(s: &str) -> Result<Self, PropetyPatternError> {
+        if s.chars()
+            .all(|c| c == '*' || c == '_' || c.is_alphanumeric())
+        {
+            Ok(Self(s.into()))
+        } else {
+            Err(PropetyPatternError::InvalidPropertyNameWildcard(s.into()))
+        }
+    }
+
+    fn matches(&self, pname: &PropertyName) -> bool {
+        let mut parts = self.0.split('*');
+        let name = pname.inner().inner().as_str();
+        // Pattern always contains at least one `*` so the first part
+        // is prefix and the last one is suffix.
+        let prefix = parts.next().unwrap_or_default();
+        let Some(mut rest) = name.strip_prefix(prefix) else {
+            return false;
+        };
+        let mut parts = parts.collect::<Vec<_>>();
+        let suffix = parts.pop().unwrap_or_default();
+        for part in parts {
+            if let Some(pos) = rest.find(part) {
+                rest = &rest[pos + part.len()..];
+            } else {
+                return false;
+            }
+        }
+        rest.len() >= suffix.len() && rest.ends_with(suffix)
+    }
+}
+
 impl<'de> Deserialize<'de> for PropertyPattern {
     fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
         struct ValVisitor {}
@@ -295,6 +488,8 @@ pub enum PropetyPatternError {
     TypeFilterPattern(FilterPatternError),
     /// Property name error.
     PropertyName(attribute_values::Error),
+    /// Property name wildcard contains invalid characters.
+    InvalidPropertyNameWildcard(String),
 }
 
 impl StdError for PropetyPatternError {}
@@ -305,6 +500,68 @@ impl Display for PropetyPatternError {
             Self::NoPropertyNameDefined => write!(f, "no property name defined"),
             Self::TypeFilterPattern(v) => write!(f, "type filter error: {v}"),
             Self::PropertyName(v) => write!(f, "property name error: {v}"),
+            Self::InvalidPropertyNameWildcard(v) => {
+                write!(f, "invalid property name wildcard: {v}")
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edmx::Namespace as EdmxNamespace;
+
+    fn matches(pattern: &str, ns: &str, name: &str, pname: &str) -> bool {
+        let ns: EdmxNamespace = ns.parse().expect("valid namespace");
+        let name: SimpleIdentifier = name.parse().expect("valid identifier");
+        let pname: PropertyName = pname.parse().expect("valid property name");
+        let filter = PropertyFilter::new(vec![pattern.parse().expect("valid pattern")]);
+        filter.matches(QualifiedName::new(&ns, &name), &pname)
+    }
+
+    #[test]
+    fn property_name_wildcards() {
+        let pattern = "Sensor.*.*/Related*";
+        assert!(matches(pattern, "Sensor.v1_2_0", "Sensor", "RelatedItem"));
+        assert!(matches(pattern, "Sensor.v1_2_0", "Sensor", "Related"));
+        assert!(!matches(pattern, "Sensor.v1_2_0", "Sensor", "Reading"));
+        assert!(!matches(
+            pattern,
+            "Chassis.v1_2_0",
+            "Chassis",
+            "RelatedItem"
+        ));
+        assert!(matches("*.*.*/*Servers", "A.v1_0_0", "B", "NameServers"));
+        assert!(matches("*.*.*/A*B*C", "A.v1_0_0", "B", "AxxBxxC"));
+        assert!(!matches("*.*.*/A*B*C", "A.v1_0_0", "B", "AxxCxxB"));
+        assert!(matches("*.*.*/*", "A.v1_0_0", "B", "Anything"));
+        assert!("*.*.*/Bad-Name*".parse::<PropertyPattern>().is_err());
+    }
+
+    #[test]
+    fn version_ranges() {
+        let pattern = "Sensor.v1_2_0-v1_5_0.Sensor/Related*";
+        assert!(!matches(pattern, "Sensor.v1_1_9", "Sensor", "RelatedItem"));
+        assert!(matches(pattern, "Sensor.v1_2_0", "Sensor", "RelatedItem"));
+        assert!(matches(pattern, "Sensor.v1_5_0", "Sensor", "RelatedItem"));
+        assert!(!matches(pattern, "Sensor.v1_5_1", "Sensor", "RelatedItem"));
+
+        let pattern = "Sensor.>=v1_2_0.Sensor/Reading";
+        assert!(!matches(pattern, "Sensor.v1_1_0", "Sensor", "Reading"));
+        assert!(matches(pattern, "Sensor.v1_10_0", "Sensor", "Reading"));
+
+        let pattern = "Sensor.<v1_2_0.Sensor/Reading";
+        assert!(matches(pattern, "Sensor.v1_1_0", "Sensor", "Reading"));
+        assert!(!matches(pattern, "Sensor.v1_2_0", "Sensor", "Reading"));
+        // Non-version identifier never matches version range.
+        assert!(!matches(pattern, "Sensor.Extra", "Sensor", "Reading"));
+
+        assert!("Sensor.>=1_2_0.Sensor/Reading"
+            .parse::<PropertyPattern>()
+            .is_err());
+        assert!("Sensor.v1_2-v1_3_0.*"
+            .parse::<EntityTypeFilterPattern>()
+            .is_err());
+    }
+}
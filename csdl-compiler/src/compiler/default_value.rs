@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Default values captured from the `DefaultValue` attribute of
+//! structural properties.
+
+use crate::compiler::QualifiedName;
+use crate::compiler::SchemaIndex;
+use crate::compiler::TypeClass;
+use crate::edmx::SimpleIdentifier;
+use crate::edmx::Type;
+
+/// Default value of the compiled property.
+///
+/// Only values of primitive types that have direct Rust mapping and
+/// enum members are supported. Other default values are ignored.
+#[derive(Debug, Clone)]
+pub enum DefaultValue<'a> {
+    /// `Edm.Boolean` value.
+    Boolean(bool),
+    /// `Edm.Int64` value.
+    Integer(i64),
+    /// `Edm.Decimal` or `Edm.Double` value.
+    Number(f64),
+    /// `Edm.String` value.
+    String(&'a str),
+    /// Member of an enum type.
+    EnumMember(SimpleIdentifier),
+}
+
+impl<'a> DefaultValue<'a> {
+    /// Parse default value of the property of type `ptype`. Returns
+    /// `None` if the type is not supported, if the value cannot be
+    /// parsed or if it is not a member of the enum type.
+    #[must_use]
+    pub fn new(
+        ptype: QualifiedName<'_>,
+        class: TypeClass,
+        value: &'a str,
+        schema_index: &SchemaIndex<'_>,
+    ) -> Option<Self> {
+        match class {
+            TypeClass::SimpleType if ptype.namespace.is_edm() => {
+                match ptype.name.inner().as_str() {
+                    "Boolean" => value.parse().ok().map(Self::Boolean),
+                    "Int64" => value.parse().ok().map(Self::Integer),
+                    "Decimal" | "Double" => value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|v| v.is_finite())
+                        .map(Self::Number),
+                    "String" => Some(Self::String(value)),
+                    _ => None,
+                }
+            }
+            TypeClass::EnumType => {
+                let member = value.parse::<SimpleIdentifier>().ok()?;
+                match schema_index.find_type(ptype)? {
+                    Type::EnumType(et) if et.members.iter().any(|m| m.name.inner() == &member) => {
+                        Some(Self::EnumMember(member))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
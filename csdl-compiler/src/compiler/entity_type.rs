@@ -24,6 +24,7 @@ use crate::compiler::Properties;
 use crate::compiler::PropertiesManipulation;
 use crate::compiler::Property;
 use crate::compiler::QualifiedName;
+use crate::compiler::Redfish;
 use crate::compiler::Stack;
 use crate::edmx::entity_type::Key;
 use crate::edmx::EntityType as EdmxEntityType;
@@ -42,6 +43,8 @@ pub struct EntityType<'a> {
     pub properties: Properties<'a>,
     /// Attached `OData` annotations.
     pub odata: OData<'a>,
+    /// Attached Redfish annotations.
+    pub redfish: Redfish<'a>,
     /// Whether the type is abstract.
     pub is_abstract: IsAbstract,
 }
@@ -80,12 +83,21 @@ impl<'a> EntityType<'a> {
         let (compiled, properties) =
             Properties::compile(name, &schema_entity_type.properties, ctx, stack.new_frame())?;
 
+        let odata = OData::new(MustHaveId::new(true), schema_entity_type);
+        // Properties of lean types that are not compiled are kept as
+        // additional properties.
+        let odata = if ctx.config.lean_property_filter.matches_type(name) {
+            odata.with_additional_properties()
+        } else {
+            odata
+        };
         let entity_type = EntityType {
             name,
             base,
             key: schema_entity_type.key.as_ref(),
             properties,
-            odata: OData::new(MustHaveId::new(true), schema_entity_type),
+            odata,
+            redfish: Redfish::new(schema_entity_type, schema_entity_type.is_open_type),
             is_abstract: schema_entity_type.is_abstract,
         };
         Ok(stack
@@ -30,6 +30,12 @@ pub struct EnumType<'a> {
     pub name: QualifiedName<'a>,
     /// Underlying integral type.
     pub underlying_type: EnumUnderlyingType,
+    /// Values of the type are combinations of members
+    /// (`IsFlags="true"`).
+    pub is_flags: bool,
+    /// Unknown values are kept as strings (type is built from
+    /// `Redfish.Enumeration` annotation).
+    pub other_fallback: bool,
     /// Members of the enum.
     pub members: Vec<EnumMember<'a>>,
     /// `OData` annotations associated with the enum type.
@@ -40,6 +46,8 @@ pub struct EnumType<'a> {
 pub struct EnumMember<'a> {
     /// Name of the member.
     pub name: &'a EnumMemberName,
+    /// Value of the member if specified and valid.
+    pub value: Option<u64>,
     /// Attached `OData` annotations.
     pub odata: OData<'a>,
 }
@@ -48,6 +56,7 @@ impl<'a> From<&'a EdmxEnumMember> for EnumMember<'a> {
     fn from(v: &'a EdmxEnumMember) -> Self {
         Self {
             name: &v.name,
+            value: v.value.as_ref().and_then(|v| v.parse().ok()),
             odata: OData::new(MustHaveId::new(false), v),
         }
     }
@@ -62,6 +71,8 @@ pub(crate) fn compile<'a>(
         Compiled::new_enum_type(EnumType {
             name: qtype,
             underlying_type,
+            is_flags: et.is_flags.unwrap_or_default(),
+            other_fallback: et.is_string_enumeration,
             members: et.members.iter().map(Into::into).collect(),
             odata: OData::new(MustHaveId::new(false), et),
         }),
@@ -50,6 +50,9 @@ pub enum Error<'a> {
     EntityType(QualifiedName<'a>, Box<Self>),
     /// Type was not found.
     TypeNotFound(QualifiedName<'a>),
+    /// Properties reference types that are not found in any loaded
+    /// schema (strict references mode).
+    UnresolvedReferences(Vec<QualifiedName<'a>>),
     /// Type definition is not a primitive type.
     TypeDefinitionOfNotPrimitiveType(QualifiedName<'a>),
     /// Error while compiling a type definition.
@@ -105,6 +108,10 @@ impl Display for Error<'_> {
                 write!(f, "while compiling entity type: {name}\n{err}")
             }
             Self::TypeNotFound(v) => write!(f, "type not found: {v}"),
+            Self::UnresolvedReferences(types) => {
+                write!(f, "unresolved type references:")?;
+                types.iter().try_for_each(|qtype| write!(f, "\n - {qtype}"))
+            }
             Self::TypeDefinitionOfNotPrimitiveType(v) => {
                 write!(f, "type definition is not a primitive type: {v}")
             }
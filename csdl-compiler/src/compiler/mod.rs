@@ -59,6 +59,8 @@ pub mod compiled;
 pub mod complex_type;
 /// Compilation context.
 pub mod context;
+/// Compiled default values of properties.
+pub mod default_value;
 /// Compiled entity type.
 pub mod entity_type;
 /// Compiled enum type.
@@ -85,6 +87,8 @@ pub mod stack;
 pub mod traits;
 /// Compiled type definition.
 pub mod type_definition;
+/// Compiled value constraints.
+pub mod validation;
 
 // Type re-exports
 #[doc(inline)]
@@ -114,6 +118,8 @@ pub use context::PropertyFilter;
 #[doc(inline)]
 pub use context::PropertyPattern;
 #[doc(inline)]
+pub use default_value::DefaultValue;
+#[doc(inline)]
 pub use entity_type::EntityType;
 #[doc(inline)]
 pub use enum_type::EnumType;
@@ -147,6 +153,8 @@ pub use qualified_name::QualifiedName;
 pub use redfish::Redfish;
 #[doc(inline)]
 pub use type_definition::TypeDefinition;
+#[doc(inline)]
+pub use validation::Validation;
 
 // Trait re-exports
 #[doc(inline)]
@@ -226,6 +234,7 @@ impl SchemaBundle {
         config: Config,
     ) -> Result<Compiled<'_>, Error<'_>> {
         let schema_index = SchemaIndex::build(&self.edmx_docs)?;
+        Self::check_references(&schema_index, &config)?;
         let root_set = self.root_set_from_singletons(&schema_index, singletons, root_patterns)?;
         let ctx = Context {
             schema_index,
@@ -243,15 +252,30 @@ impl SchemaBundle {
     ///
     /// Returns a compile error if any type cannot be resolved.
     pub fn compile_all(&self, config: Config) -> Result<Compiled<'_>, Error<'_>> {
+        let schema_index = SchemaIndex::build(&self.edmx_docs)?;
+        Self::check_references(&schema_index, &config)?;
         let root_set = self.root_set_all();
         let ctx = Context {
-            schema_index: SchemaIndex::build(&self.edmx_docs)?,
+            schema_index,
             config,
             root_set_entities: root_set.entity_types.iter().copied().collect(),
         };
         self.compile_root_set(&root_set, &ctx)
     }
 
+    fn check_references<'a>(
+        schema_index: &SchemaIndex<'a>,
+        config: &Config,
+    ) -> Result<(), Error<'a>> {
+        if config.strict_references {
+            let unresolved = schema_index.unresolved_property_types();
+            if !unresolved.is_empty() {
+                return Err(Error::UnresolvedReferences(unresolved));
+            }
+        }
+        Ok(())
+    }
+
     fn root_set_from_singletons<'a>(
         &'a self,
         schema_index: &SchemaIndex<'a>,
@@ -534,6 +558,83 @@ mod test {
         );
     }
 
+    #[test]
+    fn strict_references_lists_unresolved_types() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Oem">
+                 <ComplexType Name="Status">
+                   <Property Name="Health" Type="Missing.Health"/>
+                 </ComplexType>
+                 <EntityType Name="Oem">
+                   <Property Name="Status" Type="Oem.Status"/>
+                   <Property Name="Items" Type="Collection(Missing.Item)"/>
+                   <NavigationProperty Name="Other" Type="Missing.Health"/>
+                 </EntityType>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).expect("schema must be valid")],
+            root_set_threshold: None,
+        };
+        let result = bundle.compile_all(Config {
+            strict_references: true,
+            ..Config::default()
+        });
+        let unresolved = match result {
+            Err(Error::UnresolvedReferences(types)) => {
+                types.iter().map(ToString::to_string).collect::<Vec<_>>()
+            }
+            _ => Vec::new(),
+        };
+        assert_eq!(unresolved, vec!["Missing.Health", "Missing.Item"]);
+    }
+
+    #[test]
+    fn unsupported_validation_pattern_is_not_checked() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+                 <EntityType Name="Resource" Abstract="true"/>
+                 <EntityType Name="ResourceCollection" Abstract="true"/>
+               </Schema>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Oem">
+                 <EntityType Name="Oem">
+                   <Property Name="Version" Type="Edm.String">
+                     <Annotation Term="Validation.Pattern" String="^(?!0)\d+$"/>
+                   </Property>
+                 </EntityType>
+               </Schema>
+               <Schema Namespace="Settings">
+                 <ComplexType Name="Settings"/>
+                 <ComplexType Name="PreferredApplyTime"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).expect("schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile_all(Config::default())
+            .expect("look-around pattern must not fail compilation");
+        let validation = compiled
+            .entity_types
+            .values()
+            .flat_map(|t| &t.properties.properties)
+            .map(|p| p.validation)
+            .next()
+            .expect("property must be compiled");
+        assert!(validation.pattern.is_none());
+        assert_eq!(
+            validation
+                .unsupported_pattern
+                .map(|v| v.into_inner().as_str()),
+            Some(r"^(?!0)\d+$")
+        );
+    }
+
     #[test]
     fn schema_test() {
         let schema = r#"<edmx:Edmx Version="4.0">
@@ -599,4 +700,127 @@ mod test {
             &"The version of the Redfish service."
         );
     }
+
+    #[test]
+    fn lean_types_compile_only_listed_properties() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+                 <EntityType Name="Resource" Abstract="true"/>
+                 <EntityType Name="ResourceCollection" Abstract="true"/>
+               </Schema>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Oem">
+                 <EntityContainer Name="ServiceContainer">
+                   <Singleton Name="Service" Type="Oem.Oem"/>
+                 </EntityContainer>
+                 <ComplexType Name="Status">
+                   <Property Name="Health" Type="Edm.String"/>
+                 </ComplexType>
+                 <EntityType Name="Oem">
+                   <Property Name="Name" Type="Edm.String"/>
+                   <Property Name="Status" Type="Oem.Status"/>
+                 </EntityType>
+               </Schema>
+               <Schema Namespace="Settings">
+                 <ComplexType Name="Settings"/>
+                 <ComplexType Name="PreferredApplyTime"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).expect("schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile(
+                &["Service".parse().expect("must be correct name")],
+                &EntityTypeFilter::new_restrictive(vec![]),
+                Config {
+                    lean_property_filter: PropertyFilter::new(vec!["Oem.Oem/Name"
+                        .parse()
+                        .expect("must be correct pattern")]),
+                    ..Config::default()
+                },
+            )
+            .expect("schema must be compiled");
+        let qtype: QualifiedTypeName = "Oem.Oem".parse().expect("must be correct name");
+        let et = compiled
+            .entity_types
+            .get(&(&qtype).into())
+            .expect("entity type must be compiled");
+        let names = et
+            .properties
+            .properties
+            .iter()
+            .map(|p| p.name.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Name"]);
+        assert!(et.odata.additional_properties.is_some_and(|v| *v.inner()));
+        // Types of skipped properties are not compiled.
+        let status: QualifiedTypeName = "Oem.Status".parse().expect("must be correct name");
+        assert!(!compiled.complex_types.contains_key(&(&status).into()));
+    }
+
+    #[test]
+    fn open_types_have_dynamic_properties() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+                 <EntityType Name="Resource" Abstract="true"/>
+                 <EntityType Name="ResourceCollection" Abstract="true"/>
+               </Schema>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Oem">
+                 <EntityContainer Name="ServiceContainer">
+                   <Singleton Name="Service" Type="Oem.Oem"/>
+                 </EntityContainer>
+                 <ComplexType Name="Lists" OpenType="true">
+                   <Annotation Term="Redfish.DynamicPropertyPatterns">
+                     <Collection>
+                       <Record>
+                         <PropertyValue Property="Pattern" String="^[A-Z]+$"/>
+                         <PropertyValue Property="Type" String="Collection(Edm.PrimitiveType)"/>
+                       </Record>
+                     </Collection>
+                   </Annotation>
+                 </ComplexType>
+                 <EntityType Name="Oem" OpenType="true">
+                   <Property Name="Lists" Type="Oem.Lists"/>
+                 </EntityType>
+               </Schema>
+               <Schema Namespace="Settings">
+                 <ComplexType Name="Settings"/>
+                 <ComplexType Name="PreferredApplyTime"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).expect("schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile(
+                &["Service".parse().expect("must be correct name")],
+                &EntityTypeFilter::new_restrictive(vec![]),
+                Config::default(),
+            )
+            .expect("schema must be compiled");
+        let qtype: QualifiedTypeName = "Oem.Oem".parse().expect("must be correct name");
+        let et = compiled
+            .entity_types
+            .get(&(&qtype).into())
+            .expect("entity type must be compiled");
+        assert_eq!(
+            et.redfish.dynamic_properties.map(|v| v.ptype),
+            Some("Edm.Untyped")
+        );
+        let qtype: QualifiedTypeName = "Oem.Lists".parse().expect("must be correct name");
+        let ct = compiled
+            .complex_types
+            .get(&(&qtype).into())
+            .expect("complex type must be compiled");
+        assert_eq!(
+            ct.redfish.dynamic_properties.map(|v| v.ptype),
+            Some("Collection(Edm.PrimitiveType)")
+        );
+    }
 }
@@ -16,6 +16,7 @@
 //! `OData` attributes captured from annotations, used by code generation.
 
 use crate::odata::annotations::AdditionalProperties;
+use crate::odata::annotations::AutoExpand;
 use crate::odata::annotations::Deletable;
 use crate::odata::annotations::DescriptionRef;
 use crate::odata::annotations::Insertable;
@@ -58,6 +59,8 @@ pub struct OData<'a> {
     pub permissions: Option<Permissions>,
     /// Additional properties can be added.
     pub additional_properties: Option<AdditionalProperties>,
+    /// Navigation property value is expanded in the payload.
+    pub auto_expand: Option<AutoExpand>,
     /// Insertability (Capabilities.InsertRestrictions).
     pub insertable: Option<Insertable<'a>>,
     /// Updatability (Capabilities.UpdateRestrictions).
@@ -76,6 +79,7 @@ impl<'a> OData<'a> {
             long_description: src.odata_long_description(),
             permissions: src.odata_permissions(),
             additional_properties: src.odata_additional_properties(),
+            auto_expand: src.odata_auto_expand(),
             insertable: src.capabilities_insertable(),
             updatable: src.capabilities_updatable(),
             deletable: src.capabilities_deletable(),
@@ -93,12 +97,25 @@ impl<'a> OData<'a> {
             && self.deletable.is_none()
     }
 
+    /// Mark that additional properties can be added.
+    #[must_use]
+    pub const fn with_additional_properties(mut self) -> Self {
+        self.additional_properties = Some(AdditionalProperties::new(true));
+        self
+    }
+
     /// Property is explicitly `Write` only.
     #[must_use]
     pub fn permissions_is_write_only(&self) -> bool {
         self.permissions.is_some_and(|v| v == Permissions::Write)
     }
 
+    /// Navigation property is explicitly auto-expanded.
+    #[must_use]
+    pub fn is_auto_expand(&self) -> bool {
+        self.auto_expand.is_some_and(AutoExpand::into_inner)
+    }
+
     /// Property is writable (not strictly `Read`).
     #[must_use]
     pub fn permissions_is_write(&self) -> bool {
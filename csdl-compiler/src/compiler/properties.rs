@@ -18,6 +18,7 @@ use crate::compiler::redfish::RedfishProperty;
 use crate::compiler::Compiled;
 use crate::compiler::ComplexType;
 use crate::compiler::Context;
+use crate::compiler::DefaultValue;
 use crate::compiler::EntityType;
 use crate::compiler::Error;
 use crate::compiler::MapType;
@@ -27,6 +28,7 @@ use crate::compiler::QualifiedName;
 use crate::compiler::RigidArraySupport;
 use crate::compiler::Stack;
 use crate::compiler::TypeClass;
+use crate::compiler::Validation;
 use crate::edmx::property::Property as EdmxProperty;
 use crate::edmx::property::PropertyAttrs;
 use crate::edmx::NavigationProperty as EdmxNavigationProperty;
@@ -57,8 +59,10 @@ impl<'a> Properties<'a> {
         ctx: &Context<'a>,
         stack: Stack<'a, '_>,
     ) -> Result<(Compiled<'a>, Self), Error<'a>> {
+        let lean = ctx.config.lean_property_filter.matches_type(qtype);
         props
             .iter()
+            .filter(|sp| !lean || ctx.config.lean_property_filter.matches(qtype, &sp.name))
             .try_fold((stack, Properties::default()), |(stack, mut p), sp| {
                 let stack = match &sp.attrs {
                     PropertyAttrs::StructuralProperty(v) => {
@@ -75,6 +79,15 @@ impl<'a> Properties<'a> {
                             ptype: v.ptype.as_ref().map(|t| (typeinfo, t.into())),
                             odata: OData::new(MustHaveId::new(false), v),
                             redfish: RedfishProperty::new(v),
+                            validation: Validation::new(v),
+                            default_value: v.default_value.as_ref().and_then(|value| {
+                                DefaultValue::new(
+                                    v.ptype.qualified_type_name().into(),
+                                    typeinfo.class,
+                                    value,
+                                    &ctx.schema_index,
+                                )
+                            }),
                             nullable: v.nullable.unwrap_or(IsNullable::new(true)),
                             rigid_array_support: RigidArraySupport::new(
                                 ctx.config.rigid_array_filter.matches(qtype, &v.name),
@@ -285,6 +298,10 @@ pub struct Property<'a> {
     pub odata: OData<'a>,
     /// Redfish-specific property annotations.
     pub redfish: RedfishProperty,
+    /// Constraints of the property value.
+    pub validation: Validation<'a>,
+    /// Value of the property if it is missing in payload.
+    pub default_value: Option<DefaultValue<'a>>,
     /// Whether the property is nullable.
     pub nullable: IsNullable,
     /// Redfish specification is not very specific about which
@@ -53,7 +53,7 @@ impl RedfishProperty {
 }
 
 /// Redfish attributes attached to types.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Redfish<'a> {
     /// Dynamic properties defined for the type.
     pub dynamic_properties: Option<DynamicProperties<'a>>,
@@ -61,10 +61,22 @@ pub struct Redfish<'a> {
 
 impl<'a> Redfish<'a> {
     /// Create a new instance from an object that provides Redfish
-    /// property annotations.
-    pub fn new(src: &'a impl RedfishAnnotations) -> Self {
+    /// property annotations. Open types without dynamic property
+    /// patterns accept any primitive values.
+    pub fn new(src: &'a impl RedfishAnnotations, is_open_type: bool) -> Self {
+        Self {
+            dynamic_properties: src
+                .dynamic_properties()
+                .or_else(|| is_open_type.then_some(DynamicProperties::OPEN_TYPE)),
+        }
+    }
+
+    /// Take attributes of the parent type that are not defined by
+    /// the type itself.
+    #[must_use]
+    pub fn or_parent(self, parent: Self) -> Self {
         Self {
-            dynamic_properties: src.dynamic_properties(),
+            dynamic_properties: self.dynamic_properties.or(parent.dynamic_properties),
         }
     }
 }
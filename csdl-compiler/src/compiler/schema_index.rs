@@ -16,6 +16,7 @@
 use crate::compiler::Error;
 use crate::compiler::Namespace;
 use crate::compiler::QualifiedName;
+use crate::edmx::property::PropertyAttrs;
 use crate::edmx::ComplexType;
 use crate::edmx::Edmx;
 use crate::edmx::EntityType;
@@ -149,6 +150,44 @@ impl<'a> SchemaIndex<'a> {
             .and_then(|ns| ns.types.get(qtype.name))
     }
 
+    /// Types referenced by properties of all indexed schemas that
+    /// cannot be found in any of them. Result is sorted and does not
+    /// contain duplicates.
+    #[must_use]
+    pub fn unresolved_property_types(&self) -> Vec<QualifiedName<'a>> {
+        let mut unresolved = self
+            .index
+            .values()
+            .flat_map(|s| {
+                let complex_types = s.types.values().flat_map(|t| {
+                    if let Type::ComplexType(ct) = t {
+                        ct.properties.as_slice()
+                    } else {
+                        &[]
+                    }
+                });
+                s.entity_types
+                    .values()
+                    .flat_map(|et| et.properties.iter())
+                    .chain(complex_types)
+            })
+            .map(|p| -> QualifiedName<'a> {
+                match &p.attrs {
+                    PropertyAttrs::StructuralProperty(v) => v.ptype.qualified_type_name().into(),
+                    PropertyAttrs::NavigationProperty(v) => v.ptype.qualified_type_name().into(),
+                }
+            })
+            .filter(|qtype| {
+                !qtype.namespace.is_edm()
+                    && self.find_type(*qtype).is_none()
+                    && self.find_entity_type(*qtype).is_none()
+            })
+            .collect::<Vec<_>>();
+        unresolved.sort();
+        unresolved.dedup();
+        unresolved
+    }
+
     /// Find a child type by qualified name. For complex/entity types,
     /// returns the most distant unique descendant; otherwise returns
     /// the input type unchanged.
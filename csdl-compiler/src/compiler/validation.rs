@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Value constraints captured from `Validation.*` annotations and
+//! the `MaxLength` facet.
+
+use crate::edmx::property::StructuralProperty;
+use crate::odata::annotations::ODataAnnotations as _;
+use crate::odata::annotations::ValidationMaximum;
+use crate::odata::annotations::ValidationMinimum;
+use crate::odata::annotations::ValidationPatternRef;
+use regex::Regex;
+use tagged_types::TaggedType;
+
+/// Maximum length of string value (`MaxLength` facet).
+pub type ValidationMaxLength = TaggedType<usize, ValidationMaxLengthTag>;
+#[doc(hidden)]
+#[derive(tagged_types::Tag)]
+#[implement(Clone, Copy)]
+#[transparent(Display, Debug)]
+#[capability(inner_access, cloned)]
+pub enum ValidationMaxLengthTag {}
+
+/// Constraints of value of the compiled property.
+#[derive(Debug, Clone, Copy)]
+pub struct Validation<'a> {
+    /// Minimum value (`Validation.Minimum`).
+    pub minimum: Option<ValidationMinimum>,
+    /// Maximum value (`Validation.Maximum`).
+    pub maximum: Option<ValidationMaximum>,
+    /// Pattern of the value (`Validation.Pattern`).
+    pub pattern: Option<ValidationPatternRef<'a>>,
+    /// Pattern that the `regex` crate cannot compile. CSDL patterns
+    /// are ECMA-262 regular expressions, so they may use features
+    /// like look-around. Such patterns are not checked.
+    pub unsupported_pattern: Option<ValidationPatternRef<'a>>,
+    /// Maximum length of `Edm.String` value (`MaxLength`).
+    pub max_length: Option<ValidationMaxLength>,
+}
+
+impl<'a> Validation<'a> {
+    /// Create a new instance from the structural property.
+    #[must_use]
+    pub fn new(src: &'a StructuralProperty) -> Self {
+        let (pattern, unsupported_pattern) = match src.validation_pattern() {
+            Some(pattern) if Regex::new(pattern.into_inner()).is_err() => (None, Some(pattern)),
+            pattern => (pattern, None),
+        };
+        let ptype = src.ptype.qualified_type_name().inner();
+        let max_length = src
+            .max_length
+            .as_ref()
+            .filter(|_| ptype.namespace.is_edm() && ptype.name.inner() == "String")
+            // `max` means that length is not limited.
+            .and_then(|v| v.parse().ok())
+            .map(ValidationMaxLength::new);
+        Self {
+            minimum: src.validation_minimum(),
+            maximum: src.validation_maximum(),
+            pattern,
+            unsupported_pattern,
+            max_length,
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Coverage of schema properties by tests
+//!
+//! Test Bmc records properties of payloads it serves by unversioned
+//! type (`Chassis.Chassis`); properties of complex values are recorded
+//! as paths (`Status/Health`). The report compares the recorded
+//! properties with properties of compiled entity types and lists
+//! properties that were never exercised by tests.
+
+use crate::compiler::Compiled;
+use crate::compiler::Properties;
+use crate::compiler::QualifiedName;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+/// Maximum depth of complex properties reported as paths.
+pub const MAX_DEPTH: usize = 4;
+
+/// Recorded properties by unversioned type.
+pub type Recorded = BTreeMap<String, BTreeSet<String>>;
+
+/// Coverage of schema properties.
+#[derive(Debug, Default, Serialize)]
+pub struct CoverageReport {
+    /// Total number of schema properties.
+    pub properties: usize,
+    /// Number of schema properties recorded by tests.
+    pub covered: usize,
+    /// Coverage of entity types, ordered by type name.
+    pub types: Vec<TypeCoverage>,
+}
+
+/// Coverage of one entity type.
+#[derive(Debug, Serialize)]
+pub struct TypeCoverage {
+    /// Unversioned name of the type (`Chassis.Chassis`).
+    pub name: String,
+    /// Number of properties of the type.
+    pub properties: usize,
+    /// Number of properties recorded by tests.
+    pub covered: usize,
+    /// Properties that were not recorded.
+    pub uncovered: Vec<String>,
+}
+
+impl CoverageReport {
+    /// Compare properties of entity types of `compiled` with
+    /// `recorded` properties.
+    #[must_use]
+    pub fn new(compiled: &Compiled<'_>, recorded: &Recorded) -> Self {
+        let mut schema = Recorded::new();
+        for qtype in compiled.entity_types.keys() {
+            let mut paths = BTreeSet::new();
+            entity_paths(compiled, *qtype, &mut paths);
+            if !paths.is_empty() {
+                schema.entry(unversioned(*qtype)).or_default().extend(paths);
+            }
+        }
+        let mut report = Self::default();
+        for (name, paths) in schema {
+            let seen = recorded.get(&name);
+            let (covered, uncovered): (Vec<_>, Vec<_>) = paths
+                .into_iter()
+                .partition(|path| seen.is_some_and(|seen| seen.contains(path)));
+            let stats = TypeCoverage {
+                name,
+                properties: covered.len() + uncovered.len(),
+                covered: covered.len(),
+                uncovered,
+            };
+            report.properties += stats.properties;
+            report.covered += stats.covered;
+            report.types.push(stats);
+        }
+        report
+    }
+
+    /// Report as pretty-printed JSON.
+    ///
+    /// # Panics
+    ///
+    /// Never panics: the report contains only strings and numbers.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report must be serializable")
+    }
+}
+
+/// Type name in the form recorded by the test Bmc.
+fn unversioned(qtype: QualifiedName<'_>) -> String {
+    format!("{}.{}", qtype.namespace.root(), qtype.name)
+}
+
+fn entity_paths<'a>(
+    compiled: &Compiled<'a>,
+    qtype: QualifiedName<'a>,
+    paths: &mut BTreeSet<String>,
+) {
+    let mut next = Some(qtype);
+    while let Some(et) = next.and_then(|qtype| compiled.entity_types.get(&qtype)) {
+        properties_paths(compiled, &et.properties, "", &mut Vec::new(), paths);
+        next = et.base;
+    }
+}
+
+fn properties_paths<'a>(
+    compiled: &Compiled<'a>,
+    properties: &Properties<'a>,
+    prefix: &str,
+    visiting: &mut Vec<QualifiedName<'a>>,
+    paths: &mut BTreeSet<String>,
+) {
+    for p in &properties.properties {
+        // Write-only properties are never served.
+        if p.odata.permissions_is_write_only() {
+            continue;
+        }
+        let path = format!("{prefix}{}", p.name);
+        let (_, ptype) = p.ptype.inner();
+        // Recursive complex types are reported down to the first
+        // repetition.
+        if visiting.len() < MAX_DEPTH && !visiting.contains(ptype) {
+            visiting.push(*ptype);
+            let mut next = Some(*ptype);
+            while let Some(ct) = next.and_then(|qtype| compiled.complex_types.get(&qtype)) {
+                properties_paths(
+                    compiled,
+                    &ct.properties,
+                    &format!("{path}/"),
+                    visiting,
+                    paths,
+                );
+                next = ct.base;
+            }
+            visiting.pop();
+        }
+        paths.insert(path);
+    }
+    for p in &properties.nav_properties {
+        paths.insert(format!("{prefix}{}", p.name()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Config as CompilerConfig;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use crate::optimizer::optimize;
+    use crate::optimizer::Config as OptimizerConfig;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn report_lists_uncovered_properties() {
+        let fname = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/schemas/base/schema.xml");
+        let data = fs::read_to_string(fname).expect("test schema must be readable");
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(&data).expect("test schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("test schema must be compiled");
+        let compiled = optimize(compiled, &OptimizerConfig::default());
+
+        let empty = CoverageReport::new(&compiled, &Recorded::new());
+        assert_eq!(empty.covered, 0);
+        assert!(empty.properties > 0);
+        let root = empty
+            .types
+            .iter()
+            .find(|t| t.name == "ServiceRoot.ServiceRoot")
+            .expect("service root must be reported");
+        for property in [
+            "RedfishVersion",
+            "TestRequiredService",
+            "ReadOnlyComplex/Required",
+        ] {
+            assert!(root.uncovered.iter().any(|p| p == property));
+        }
+
+        let recorded = Recorded::from([(
+            "ServiceRoot.ServiceRoot".into(),
+            ["RedfishVersion", "ReadOnlyComplex/Required", "NotInSchema"]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        )]);
+        let report = CoverageReport::new(&compiled, &recorded);
+        assert_eq!(report.covered, 2);
+        assert_eq!(report.properties, empty.properties);
+        let root = report
+            .types
+            .iter()
+            .find(|t| t.name == "ServiceRoot.ServiceRoot")
+            .expect("service root must be reported");
+        assert_eq!(root.covered, 2);
+        assert!(!root
+            .uncovered
+            .iter()
+            .any(|p| p == "RedfishVersion" || p == "ReadOnlyComplex/Required"));
+    }
+}
@@ -0,0 +1,543 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Source locations for compiler diagnostics
+//!
+//! Parsed EDMX documents do not keep track of where elements were
+//! defined. When validation or compilation fails, the source is read
+//! with `quick-xml` recording byte offsets of elements and attribute
+//! values, and the error context chain (schema → type → property →
+//! referenced type) is followed through the recorded elements to
+//! find the file, line and column that caused the error.
+
+use crate::compiler::Error as CompileError;
+use crate::edmx::Namespace;
+use crate::edmx::QualifiedName;
+use crate::edmx::SimpleIdentifier;
+use crate::edmx::TypeName;
+use crate::edmx::ValidateError;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::borrow::Cow;
+use std::convert::TryFrom as _;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::ops::Range;
+use std::slice::from_ref;
+
+const TYPE_ELEMENTS: &[&str] = &["EntityType", "ComplexType", "EnumType", "TypeDefinition"];
+const PROPERTY_ELEMENTS: &[&str] = &["Property", "NavigationProperty"];
+
+/// Content of a CSDL document together with the path it was read
+/// from.
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    /// Path of the file (as specified by the user).
+    pub path: String,
+    /// Text of the file.
+    pub content: String,
+}
+
+/// Line and column in a source file (both start from 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Location of a diagnostic: the file and, if known, position inside
+/// it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: String,
+    pub position: Option<Position>,
+}
+
+impl SourceFile {
+    /// Create source file from its path and content.
+    #[must_use]
+    pub const fn new(path: String, content: String) -> Self {
+        Self { path, content }
+    }
+
+    /// Convert byte offset in the content to line and column.
+    #[must_use]
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.content.len());
+        let before = self.content.get(..offset).unwrap_or_default();
+        let line_start = before.rfind('\n').map_or(0, |v| v + 1);
+        Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Location of the EDMX validation error in this file.
+    #[must_use]
+    pub fn locate_validate_error(&self, err: &ValidateError) -> SourceLocation {
+        let mut anchors = Vec::new();
+        validate_error_anchors(err, &mut anchors);
+        let offset = match err_leaf(err) {
+            ValidateError::XmlDeserialize(_) => self.xml_error_offset(),
+            _ => None,
+        }
+        .or_else(|| Self::locate(from_ref(self), &anchors).map(|(_, v)| v));
+        SourceLocation {
+            file: self.path.clone(),
+            position: offset.map(|v| self.position(v)),
+        }
+    }
+
+    /// Locate the compilation error in one of the source files. Returns
+    /// `None` if the error cannot be associated with any file.
+    #[must_use]
+    pub fn locate_compile_error(
+        sources: &[Self],
+        err: &CompileError<'_>,
+    ) -> Option<SourceLocation> {
+        let mut anchors = Vec::new();
+        compile_error_anchors(err, &mut anchors);
+        Self::locate(sources, &anchors).map(|(index, offset)| SourceLocation {
+            file: sources[index].path.clone(),
+            position: Some(sources[index].position(offset)),
+        })
+    }
+
+    // Follow anchors as deep as possible. Returns index of the file
+    // and offset of the deepest anchor found.
+    fn locate(sources: &[Self], anchors: &[Anchor]) -> Option<(usize, usize)> {
+        let elements = sources.iter().map(Self::elements).collect::<Vec<_>>();
+        // File, element and offset of the deepest anchor found.
+        let mut current: Option<(usize, usize, usize)> = None;
+        for anchor in anchors {
+            let found = match anchor {
+                Anchor::Schema(ns) => elements.iter().enumerate().find_map(|(file, elements)| {
+                    find_element(elements, 0..elements.len(), &["Schema"], "Namespace", ns)
+                        .map(|index| (file, index, elements[index].start))
+                }),
+                Anchor::Element(names, name) => current.and_then(|(file, parent, _)| {
+                    let elements = &elements[file];
+                    find_element(elements, subtree(elements, parent), names, "Name", name)
+                        .map(|index| (file, index, elements[index].start))
+                }),
+                Anchor::Reference(qname) => current.and_then(|(file, parent, _)| {
+                    let elements = &elements[file];
+                    find_reference(elements, subtree(elements, parent), qname)
+                        .map(|offset| (file, parent, offset))
+                }),
+            };
+            match found {
+                Some(v) => current = Some(v),
+                None => break,
+            }
+        }
+        current.map(|(file, _, offset)| (file, offset))
+    }
+
+    // Elements of the document in document order. Reading stops at
+    // the first XML error.
+    fn elements(&self) -> Vec<Element> {
+        let mut reader = Reader::from_str(&self.content);
+        let mut elements = Vec::new();
+        let mut open = Vec::new();
+        while let Ok(start) = usize::try_from(reader.buffer_position()) {
+            let event = reader.read_event();
+            let Ok(end) = usize::try_from(reader.buffer_position()) else {
+                break;
+            };
+            match event {
+                Ok(Event::Start(e)) => {
+                    open.push(elements.len());
+                    elements.push(self.element(start, self.content.len(), &e));
+                }
+                Ok(Event::Empty(e)) => elements.push(self.element(start, end, &e)),
+                Ok(Event::End(_)) => {
+                    if let Some(index) = open.pop() {
+                        elements[index].end = end;
+                    }
+                }
+                Ok(Event::Eof) | Err(_) => break,
+                Ok(_) => (),
+            }
+        }
+        elements
+    }
+
+    fn element(&self, start: usize, end: usize, e: &BytesStart<'_>) -> Element {
+        let attributes = e
+            .attributes()
+            .flatten()
+            .filter_map(|attr| {
+                Some(ElementAttribute {
+                    name: String::from_utf8_lossy(attr.key.local_name().as_ref()).into_owned(),
+                    value: attr.unescape_value().ok()?.into_owned(),
+                    offset: self.value_offset(&attr).unwrap_or(start),
+                })
+            })
+            .collect();
+        Element {
+            name: String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+            start,
+            end,
+            attributes,
+        }
+    }
+
+    // Offset of the attribute value in the content. Values read from
+    // the content borrow it.
+    fn value_offset(&self, attr: &Attribute<'_>) -> Option<usize> {
+        match &attr.value {
+            Cow::Borrowed(value) => {
+                let content = self.content.as_bytes().as_ptr_range();
+                content
+                    .contains(&value.as_ptr())
+                    .then(|| value.as_ptr() as usize - content.start as usize)
+            }
+            Cow::Owned(_) => None,
+        }
+    }
+
+    // Position of XML-level error: either malformed XML or attribute
+    // value that is not a valid identifier.
+    fn xml_error_offset(&self) -> Option<usize> {
+        let mut reader = Reader::from_str(&self.content);
+        loop {
+            let start = usize::try_from(reader.buffer_position()).ok()?;
+            match reader.read_event() {
+                Ok(Event::Eof) => return None,
+                Ok(Event::Start(e) | Event::Empty(e)) => {
+                    if let Some(offset) = self.invalid_attribute_offset(start, &e) {
+                        return Some(offset);
+                    }
+                }
+                Ok(_) => (),
+                Err(_) => return usize::try_from(reader.error_position()).ok(),
+            }
+        }
+    }
+
+    fn invalid_attribute_offset(&self, start: usize, e: &BytesStart<'_>) -> Option<usize> {
+        e.attributes().flatten().find_map(|attr| {
+            let value = attr.unescape_value().ok()?;
+            let valid = match attr.key.local_name().as_ref() {
+                b"Name" => value.parse::<SimpleIdentifier>().is_ok(),
+                b"Namespace" => value.parse::<Namespace>().is_ok(),
+                b"BaseType" | b"Term" => value.parse::<QualifiedName>().is_ok(),
+                b"Type" => value.parse::<TypeName>().is_ok(),
+                _ => true,
+            };
+            if valid {
+                None
+            } else {
+                Some(self.value_offset(&attr).unwrap_or(start))
+            }
+        })
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.position {
+            Some(position) => write!(f, "{}:{position}", self.file),
+            None => self.file.fmt(f),
+        }
+    }
+}
+
+impl From<&str> for SourceLocation {
+    fn from(file: &str) -> Self {
+        Self {
+            file: file.into(),
+            position: None,
+        }
+    }
+}
+
+impl From<String> for SourceLocation {
+    fn from(file: String) -> Self {
+        Self {
+            file,
+            position: None,
+        }
+    }
+}
+
+/// Element read from the source with its position.
+struct Element {
+    /// Local name of the element.
+    name: String,
+    /// Offset of the start tag.
+    start: usize,
+    /// Offset right after the end tag.
+    end: usize,
+    attributes: Vec<ElementAttribute>,
+}
+
+struct ElementAttribute {
+    /// Local name of the attribute.
+    name: String,
+    /// Unescaped value of the attribute.
+    value: String,
+    /// Offset of the value (without quotes).
+    offset: usize,
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&ElementAttribute> {
+        self.attributes.iter().find(|attr| attr.name == name)
+    }
+}
+
+// Indexes of the element at `parent` and its descendants.
+fn subtree(elements: &[Element], parent: usize) -> Range<usize> {
+    let end = elements[parent].end;
+    let len = elements[parent..]
+        .iter()
+        .take_while(|e| e.start < end)
+        .count();
+    parent..parent + len
+}
+
+// Find element with one of `names` local names that has attribute
+// `attr` equal to `value`.
+fn find_element(
+    elements: &[Element],
+    range: Range<usize>,
+    names: &[&str],
+    attr: &str,
+    value: &str,
+) -> Option<usize> {
+    range.into_iter().find(|index| {
+        let element = &elements[*index];
+        names.contains(&element.name.as_str())
+            && element.attribute(attr).is_some_and(|v| v.value == value)
+    })
+}
+
+// Find offset of attribute value that refers to `qname` (either
+// directly or as `Collection(qname)`).
+fn find_reference(elements: &[Element], range: Range<usize>, qname: &str) -> Option<usize> {
+    elements[range].iter().find_map(|element| {
+        element.attributes.iter().find_map(|attr| {
+            if attr.value == qname {
+                Some(attr.offset)
+            } else {
+                let inner = attr
+                    .value
+                    .strip_prefix("Collection(")
+                    .and_then(|v| v.strip_suffix(')'))?;
+                (inner == qname).then_some(attr.offset + "Collection(".len())
+            }
+        })
+    })
+}
+
+enum Anchor {
+    /// `Schema` element with the namespace.
+    Schema(String),
+    /// Element with one of the names and specified `Name` attribute.
+    Element(&'static [&'static str], String),
+    /// Attribute that references the qualified name.
+    Reference(String),
+}
+
+fn err_leaf(err: &ValidateError) -> &ValidateError {
+    match err {
+        ValidateError::Schema(_, err)
+        | ValidateError::ComplexType(_, err)
+        | ValidateError::EntityType(_, err)
+        | ValidateError::NavigationProperty(_, err)
+        | ValidateError::Action(_, err) => err_leaf(err),
+        _ => err,
+    }
+}
+
+fn validate_error_anchors(err: &ValidateError, anchors: &mut Vec<Anchor>) {
+    let next = match err {
+        ValidateError::Schema(ns, err) => {
+            anchors.push(Anchor::Schema(ns.to_string()));
+            err
+        }
+        ValidateError::ComplexType(name, err) => {
+            anchors.push(Anchor::Element(&["ComplexType"], name.to_string()));
+            err
+        }
+        ValidateError::EntityType(name, err) => {
+            anchors.push(Anchor::Element(&["EntityType"], name.to_string()));
+            err
+        }
+        ValidateError::NavigationProperty(name, err) => {
+            anchors.push(Anchor::Element(&["NavigationProperty"], name.to_string()));
+            err
+        }
+        ValidateError::Action(name, err) => {
+            anchors.push(Anchor::Element(&["Action"], name.to_string()));
+            err
+        }
+        _ => return,
+    };
+    validate_error_anchors(next, anchors);
+}
+
+fn compile_error_anchors(err: &CompileError<'_>, anchors: &mut Vec<Anchor>) {
+    let next = match err {
+        CompileError::Schema(ns, err) => {
+            anchors.push(Anchor::Schema(ns.to_string()));
+            err
+        }
+        CompileError::EntityType(qname, err)
+        | CompileError::Type(qname, err)
+        | CompileError::TypeDefinition(qname, err) => {
+            anchors.push(Anchor::Schema(qname.namespace.to_string()));
+            anchors.push(Anchor::Element(TYPE_ELEMENTS, qname.name.to_string()));
+            err
+        }
+        CompileError::Property(name, err) => {
+            anchors.push(Anchor::Element(PROPERTY_ELEMENTS, name.to_string()));
+            err
+        }
+        CompileError::Action(name, err) => {
+            anchors.push(Anchor::Element(&["Action"], name.to_string()));
+            err
+        }
+        CompileError::ActionParameter(name, err) => {
+            anchors.push(Anchor::Element(&["Parameter"], name.to_string()));
+            err
+        }
+        CompileError::Singleton(name, err) => {
+            anchors.push(Anchor::Element(&["Singleton"], name.to_string()));
+            err
+        }
+        CompileError::ActionReturnType(err) => err,
+        CompileError::TypeNotFound(qname)
+        | CompileError::EntityTypeNotFound(qname)
+        | CompileError::ComplexTypeNotFound(qname)
+        | CompileError::TypeDefinitionOfNotPrimitiveType(qname) => {
+            anchors.push(Anchor::Reference(qname.to_string()));
+            return;
+        }
+        _ => return,
+    };
+    compile_error_anchors(next, anchors);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::QualifiedName as CompilerQualifiedName;
+    use crate::edmx::Edmx;
+    use crate::edmx::PropertyName;
+
+    const SCHEMA: &str = r#"<edmx:Edmx Version="4.0">
+  <edmx:Reference Uri="http://example.com/Resource_v1.xml">
+    <edmx:Include Namespace="Test.v1_0_0"/>
+  </edmx:Reference>
+  <edmx:DataServices>
+    <Schema Namespace="Test.v1_0_0">
+      <ComplexType Name="Status">
+        <Property Name="State" Type="Edm.String"/>
+      </ComplexType>
+      <EntityType Name="Test">
+        <Property Name="Status" Type="Test.v1_0_0.Status"/>
+        <Property Name="Other" Type="Test.v1_0_0.Missing"/>
+      </EntityType>
+    </Schema>
+  </edmx:DataServices>
+</edmx:Edmx>
+"#;
+
+    fn source(content: &str) -> SourceFile {
+        SourceFile::new("Test_v1.xml".into(), content.into())
+    }
+
+    #[test]
+    fn locate_unresolved_type() {
+        let ns: Namespace = "Test.v1_0_0".parse().expect("valid namespace");
+        let ename: SimpleIdentifier = "Test".parse().expect("valid identifier");
+        let missing: SimpleIdentifier = "Missing".parse().expect("valid identifier");
+        let pname: PropertyName = "Other".parse().expect("valid identifier");
+        let err = CompileError::Schema(
+            &ns,
+            Box::new(CompileError::EntityType(
+                CompilerQualifiedName::new(&ns, &ename),
+                Box::new(CompileError::Property(
+                    &pname,
+                    Box::new(CompileError::TypeNotFound(CompilerQualifiedName::new(
+                        &ns, &missing,
+                    ))),
+                )),
+            )),
+        );
+        let location = SourceFile::locate_compile_error(&[source(SCHEMA)], &err)
+            .expect("location must be found");
+        assert_eq!(location.to_string(), "Test_v1.xml:12:38");
+    }
+
+    #[test]
+    fn locate_unresolved_type_with_any_attribute_syntax() {
+        let content = SCHEMA
+            .replace(
+                r#"<EntityType Name="Test">"#,
+                "<EntityType Name=\"Other\">\n        <Property Name=\"Other\" Type=\"Test.v1_0_0.Missing\"/>\n      </EntityType>\n      <EntityType Name = 'Test'>",
+            )
+            .replace(
+                r#"<Property Name="Other" Type="Test.v1_0_0.Missing"/>
+      </EntityType>
+    </Schema>"#,
+                "<Property Name='Other' Type = 'Collection(Test.v1_0_0.Missing)'/>\n      </EntityType>\n    </Schema>",
+            );
+        let ns: Namespace = "Test.v1_0_0".parse().expect("valid namespace");
+        let ename: SimpleIdentifier = "Test".parse().expect("valid identifier");
+        let missing: SimpleIdentifier = "Missing".parse().expect("valid identifier");
+        let pname: PropertyName = "Other".parse().expect("valid identifier");
+        let err = CompileError::EntityType(
+            CompilerQualifiedName::new(&ns, &ename),
+            Box::new(CompileError::Property(
+                &pname,
+                Box::new(CompileError::TypeNotFound(CompilerQualifiedName::new(
+                    &ns, &missing,
+                ))),
+            )),
+        );
+        let location = SourceFile::locate_compile_error(&[source(&content)], &err)
+            .expect("location must be found");
+        assert_eq!(location.to_string(), "Test_v1.xml:15:51");
+    }
+
+    #[test]
+    fn locate_invalid_identifier() {
+        let content = SCHEMA.replace(r#"Name="State""#, r#"Name="St-ate""#);
+        let err = Edmx::parse(&content).expect_err("schema must be invalid");
+        let location = source(&content).locate_validate_error(&err);
+        assert_eq!(location.to_string(), "Test_v1.xml:8:25");
+    }
+
+    #[test]
+    fn locate_malformed_xml() {
+        let content = SCHEMA.replace("</ComplexType>", "</CompleType>");
+        let err = Edmx::parse(&content).expect_err("schema must be invalid");
+        let location = source(&content).locate_validate_error(&err);
+        assert_eq!(location.position.map(|p| p.line), Some(9));
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Out-of-band annotations (`edm:Annotations` element)
+
+use crate::edmx::property::Property;
+use crate::edmx::property::PropertyAttrs;
+use crate::edmx::Annotation;
+use crate::edmx::Edmx;
+use crate::edmx::QualifiedName;
+use crate::edmx::SimpleIdentifier;
+use crate::edmx::Type;
+use serde::Deserialize;
+use std::mem::take;
+
+/// 14.2 Element edm:Annotations
+#[derive(Debug, Deserialize)]
+pub struct Annotations {
+    /// 14.2.1 Attribute Target
+    #[serde(rename = "@Target")]
+    pub target: String,
+    /// Annotations applied to the target.
+    #[serde(rename = "Annotation", default)]
+    pub annotations: Vec<Annotation>,
+}
+
+/// Move annotations from `edm:Annotations` elements of all documents
+/// to their targets, so they are processed the same way as inline
+/// annotations.
+///
+/// Supported targets are types (`Namespace.Type`) and their
+/// properties or enum members (`Namespace.Type/Name`). Annotations
+/// with other targets, or targets that are not loaded, are kept in
+/// `Schema::external_annotations`.
+pub fn apply_external_annotations(docs: &mut [Edmx]) {
+    for doc_idx in 0..docs.len() {
+        for schema_idx in 0..docs[doc_idx].data_services.schemas.len() {
+            let pending =
+                take(&mut docs[doc_idx].data_services.schemas[schema_idx].external_annotations);
+            let unresolved = pending
+                .into_iter()
+                .filter_map(|v| match target_annotations(docs, &v.target) {
+                    Some(target) => {
+                        target.extend(v.annotations);
+                        None
+                    }
+                    None => Some(v),
+                })
+                .collect();
+            docs[doc_idx].data_services.schemas[schema_idx].external_annotations = unresolved;
+        }
+    }
+}
+
+fn target_annotations<'a>(docs: &'a mut [Edmx], target: &str) -> Option<&'a mut Vec<Annotation>> {
+    let (path, member) = match target.split_once('/') {
+        Some((path, member)) => (path, Some(member.parse::<SimpleIdentifier>().ok()?)),
+        None => (target, None),
+    };
+    let qname = path.parse::<QualifiedName>().ok()?;
+    let schema = docs
+        .iter_mut()
+        .flat_map(|doc| doc.data_services.schemas.iter_mut())
+        .find(|s| s.namespace == qname.namespace)?;
+    if schema.entity_types.contains_key(&qname.name) {
+        let et = schema.entity_types.get_mut(&qname.name)?;
+        return match member {
+            None => Some(&mut et.annotations),
+            Some(name) => property_annotations(&mut et.properties, &name),
+        };
+    }
+    match (schema.types.get_mut(&qname.name)?, member) {
+        (Type::ComplexType(ct), None) => Some(&mut ct.annotations),
+        (Type::ComplexType(ct), Some(name)) => property_annotations(&mut ct.properties, &name),
+        (Type::EnumType(et), None) => Some(&mut et.annotations),
+        (Type::EnumType(et), Some(name)) => et
+            .members
+            .iter_mut()
+            .find(|m| m.name.inner() == &name)
+            .map(|m| &mut m.annotations),
+        (Type::TypeDefinition(td), None) => Some(&mut td.annotations),
+        (Type::TypeDefinition(_), Some(_)) => None,
+    }
+}
+
+fn property_annotations<'a>(
+    properties: &'a mut [Property],
+    name: &SimpleIdentifier,
+) -> Option<&'a mut Vec<Annotation>> {
+    properties
+        .iter_mut()
+        .find(|p| p.name.inner() == name)
+        .map(|p| match &mut p.attrs {
+            PropertyAttrs::StructuralProperty(v) => &mut v.annotations,
+            PropertyAttrs::NavigationProperty(v) => &mut v.annotations,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn annotations_applied_to_targets() {
+        let types = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema Namespace="Test.v1_0_0">
+                 <EntityType Name="Test">
+                   <Property Name="Prop" Type="Edm.String"/>
+                 </EntityType>
+                 <EnumType Name="State">
+                   <Member Name="Enabled"/>
+                 </EnumType>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let annotations = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema Namespace="Test.Annotations">
+                 <Annotations Target="Test.v1_0_0.Test/Prop">
+                   <Annotation Term="Redfish.Required"/>
+                   <Annotation Term="OData.Description" String="Property."/>
+                 </Annotations>
+                 <Annotations Target="Test.v1_0_0.State/Enabled">
+                   <Annotation Term="OData.Description" String="Enabled."/>
+                 </Annotations>
+                 <Annotations Target="Test.v1_0_0.Missing">
+                   <Annotation Term="OData.Description" String="Missing."/>
+                 </Annotations>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let mut docs = [types, annotations]
+            .iter()
+            .map(|s| Edmx::parse(s).expect("valid document"))
+            .collect::<Vec<_>>();
+        apply_external_annotations(&mut docs);
+
+        let schema = &docs[0].data_services.schemas[0];
+        let et = schema
+            .entity_types
+            .values()
+            .next()
+            .expect("entity type must be defined");
+        let PropertyAttrs::StructuralProperty(prop) = &et.properties[0].attrs else {
+            unreachable!("structural property is defined");
+        };
+        assert_eq!(prop.annotations.len(), 2);
+        let Some(Type::EnumType(state)) = schema.types.values().next() else {
+            unreachable!("enum type is defined");
+        };
+        assert_eq!(state.members[0].annotations.len(), 1);
+        let unresolved = &docs[1].data_services.schemas[0].external_annotations;
+        assert_eq!(unresolved.len(), 1);
+        assert_eq!(unresolved[0].target, "Test.v1_0_0.Missing");
+    }
+}
@@ -60,6 +60,8 @@ pub struct ComplexType {
     pub properties: Vec<Property>,
     pub annotations: Vec<Annotation>,
     pub is_abstract: IsAbstract,
+    /// Type allows properties that are not declared (`OpenType`).
+    pub is_open_type: bool,
 }
 
 impl DeComplexType {
@@ -89,6 +91,7 @@ impl DeComplexType {
             properties,
             annotations,
             is_abstract: self.r#abstract.unwrap_or(IsAbstract::new(false)),
+            is_open_type: self.open_type.unwrap_or_default(),
         })
     }
 }
@@ -85,6 +85,8 @@ pub struct EntityType {
     pub properties: Vec<Property>,
     pub annotations: Vec<Annotation>,
     pub is_abstract: IsAbstract,
+    /// Type allows properties that are not declared (`OpenType`).
+    pub is_open_type: bool,
 }
 
 impl DeEntityType {
@@ -125,6 +127,7 @@ impl DeEntityType {
             properties,
             annotations,
             is_abstract: self.r#abstract.unwrap_or(IsAbstract::new(false)),
+            is_open_type: self.open_type.unwrap_or_default(),
         })
     }
 }
@@ -81,6 +81,9 @@ pub struct EnumType {
     pub name: LocalTypeName,
     pub underlying_type: Option<EnumUnderlyingType>,
     pub is_flags: Option<bool>,
+    /// Type is not defined in schema but built from
+    /// `Redfish.Enumeration` annotation of a string property.
+    pub is_string_enumeration: bool,
     pub members: Vec<EnumMember>,
     pub annotations: Vec<Annotation>,
 }
@@ -104,6 +107,7 @@ impl DeEnumType {
             name: self.name.clone(),
             underlying_type: self.underlying_type,
             is_flags: self.is_flags,
+            is_string_enumeration: false,
             members,
             annotations,
         })
@@ -48,6 +48,9 @@ pub mod enum_type;
 /// 12.1 Element edm:Action
 pub mod action;
 
+/// 14.2 Element edm:Annotations
+pub mod annotations;
+
 /// 14.3 Element edm:Annotation
 pub mod annotation;
 
@@ -67,6 +70,10 @@ pub use annotation::Annotation;
 #[doc(inline)]
 pub use annotation::AnnotationRecord;
 #[doc(inline)]
+pub use annotations::apply_external_annotations;
+#[doc(inline)]
+pub use annotations::Annotations;
+#[doc(inline)]
 pub use attribute_values::Namespace;
 #[doc(inline)]
 pub use attribute_values::QualifiedName;
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::compiler::Error as CompileError;
+use crate::diagnostics::SourceLocation;
 use crate::edmx::attribute_values::Error as AttributeValuesError;
 use crate::edmx::ValidateError;
 use crate::generator::rust::Error as GenerateError;
@@ -29,21 +30,30 @@ use std::path::PathBuf;
 pub enum Error {
     AtLeastOneCSDLFileNeeded,
     Io(String, IoError),
-    Edmx(String, ValidateError),
+    Edmx(SourceLocation, ValidateError),
     DuplicateNamespace(String, Vec<String>),
-    Compile(Vec<String>),
+    Compile(Option<SourceLocation>, Vec<String>),
     WrongRootService(AttributeValuesError),
     Generate(Vec<String>),
     ParseGenerated(syn::Error),
     WriteOutput(PathBuf, IoError),
+    InvalidReferenceUri(String),
+    DownloadReference(String, String),
+    DownloadNotSupported(String),
+    InvalidExternalModule(String, syn::Error),
 }
 
 // Passing by reference would break possibility to use it as
 // `map_err(Error::compile_error)` etc.
 #[allow(clippy::needless_pass_by_value)]
 impl Error {
+    #[must_use]
     pub fn compile_error(e: CompileError<'_>) -> Self {
+        Self::located_compile_error(None, e)
+    }
+    pub fn located_compile_error(location: Option<SourceLocation>, e: CompileError<'_>) -> Self {
         Self::Compile(
+            location,
             format!("{e}")
                 .split('\n')
                 .map(ToString::to_string)
@@ -67,15 +77,18 @@ impl Display for Error {
                 "at least one CSDL file is needed for compilation".fmt(f)
             }
             Self::Io(fname, error) => write!(f, "input/output error: file: {fname}: {error}"),
-            Self::Edmx(fname, error) => {
-                write!(f, "EDMX format validation error: file: {fname}: {error}")
+            Self::Edmx(location, error) => {
+                write!(f, "EDMX format validation error: file: {location}: {error}")
             }
             Self::DuplicateNamespace(namespace, files) => {
                 write!(f, "duplicate CSDL namespace detected: {namespace}")?;
                 files.iter().try_for_each(|file| write!(f, "\n - {file}"))
             }
-            Self::Compile(lines) => {
+            Self::Compile(location, lines) => {
                 write!(f, "compilation error:")?;
+                if let Some(location) = location {
+                    write!(f, " at {location}")?;
+                }
                 lines
                     .iter()
                     .enumerate()
@@ -95,6 +108,19 @@ impl Display for Error {
             Self::WriteOutput(fname, error) => {
                 write!(f, "failed write output file: {}: {error}", fname.display())
             }
+            Self::InvalidReferenceUri(uri) => {
+                write!(f, "cannot derive cache file name from reference uri: {uri}")
+            }
+            Self::DownloadReference(uri, error) => {
+                write!(f, "failed to download referenced document: {uri}: {error}")
+            }
+            Self::DownloadNotSupported(uri) => write!(
+                f,
+                "cannot download referenced document (compiler built without remote-references feature): {uri}"
+            ),
+            Self::InvalidExternalModule(module, error) => {
+                write!(f, "invalid path of external module: {module}: {error}")
+            }
         }
     }
 }
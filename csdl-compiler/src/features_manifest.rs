@@ -47,6 +47,7 @@ pub struct Collected<'a> {
     pub patterns: Vec<&'a EntityTypeFilterPattern>,
     pub root_patterns: Vec<&'a EntityTypeFilterPattern>,
     pub rigid_array_patterns: Vec<&'a PropertyPattern>,
+    pub lean_property_patterns: Vec<&'a PropertyPattern>,
 }
 
 impl FeaturesManifest {
@@ -82,6 +83,7 @@ impl FeaturesManifest {
                     acc.patterns.extend(f.patterns.iter());
                     acc.root_patterns.extend(f.root_patterns.iter());
                     acc.rigid_array_patterns.extend(f.rigid_arrays.iter());
+                    acc.lean_property_patterns.extend(f.lean_properties.iter());
                 }
                 acc
             })
@@ -145,6 +147,10 @@ pub struct Feature {
     pub root_patterns: Vec<EntityTypeFilterPattern>,
     #[serde(default)]
     pub rigid_arrays: Vec<PropertyPattern>,
+    /// Properties of lean types. Types matched by these patterns
+    /// are generated only with matching properties.
+    #[serde(default)]
+    pub lean_properties: Vec<PropertyPattern>,
 }
 
 /// OEM-specific feature.
@@ -96,8 +96,10 @@ impl<'a, 'config> ActionFullTypeName<'a, 'config> {
 
 impl ToTokens for ActionFullTypeName<'_, '_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let top = &self.config.top_module_alias;
-        tokens.extend(quote! { #top });
+        tokens.extend(
+            self.config
+                .top_module_for(&self.defining_ns.root().to_string()),
+        );
         for depth in 0..self.defining_ns.len() {
             if let Some(id) = self.defining_ns.get_id(depth) {
                 let name = ModName::new(id);
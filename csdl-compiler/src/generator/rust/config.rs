@@ -13,9 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::compiler::Namespace;
 use crate::edmx::PropertyName;
 use proc_macro2::Ident;
 use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
 
 /// Configuration of Generation
 pub struct Config {
@@ -29,6 +33,53 @@ pub struct Config {
     /// Maximum number of parameters that are passed as function
     /// parameter before switching to action struct.
     pub action_fn_max_param_number_threshold: usize,
+
+    /// Types that are generated by another crate. Code for these
+    /// types is not generated, references to them use path of the
+    /// external module.
+    pub external_types: Option<ExternalTypes>,
+}
+
+impl Config {
+    /// Whether types of the namespace are generated by another
+    /// crate.
+    #[must_use]
+    pub fn is_external(&self, namespace: &Namespace<'_>) -> bool {
+        self.external_types
+            .as_ref()
+            .is_some_and(|ext| ext.contains(&namespace.root().to_string()))
+    }
+
+    /// Path to the top module that contains types of the root
+    /// namespace (for example, `Settings`).
+    #[must_use]
+    pub fn top_module_for(&self, root_namespace: &str) -> TokenStream {
+        match &self.external_types {
+            Some(ext) if ext.contains(root_namespace) => ext.module.clone(),
+            _ => {
+                let top = &self.top_module_alias;
+                quote! { #top }
+            }
+        }
+    }
+}
+
+/// Types generated by another crate (for example, standard Redfish
+/// types of `nv-redfish` referenced by OEM schemas).
+pub struct ExternalTypes {
+    /// Path to the module with generated types (for example,
+    /// `nv_redfish::schema`).
+    pub module: TokenStream,
+    /// Root namespaces (for example, `Resource`) of the types.
+    pub namespaces: HashSet<String>,
+}
+
+impl ExternalTypes {
+    /// Whether the root namespace is external.
+    #[must_use]
+    pub fn contains(&self, root_namespace: &str) -> bool {
+        self.namespaces.contains(root_namespace)
+    }
 }
 
 impl Default for Config {
@@ -39,6 +90,7 @@ impl Default for Config {
                 "Base".parse().expect("should always be parsed"),
             ),
             action_fn_max_param_number_threshold: 3,
+            external_types: None,
         }
     }
 }
@@ -38,6 +38,10 @@ pub struct EnumDef<'a> {
 impl EnumDef<'_> {
     /// Generate rust code for types derived from enums.
     pub fn generate(self, tokens: &mut TokenStream, config: &Config) {
+        if self.compiled.is_flags {
+            self.generate_flags(tokens, config);
+            return;
+        }
         let name = self.name;
         let top = &config.top_module_alias;
         let mut members_content = TokenStream::new();
@@ -62,18 +66,31 @@ impl EnumDef<'_> {
                 Self::#member_name => #snake_case_literal,
             });
         }
-        members_content.extend(quote! {
-            #[doc = " Fallback value for values that are not supported by current version of Redfish schema."]
-            #[serde(other)]
-            UnsupportedValue,
-        });
-        snake_case_match_arms.extend(quote! {
-            Self::UnsupportedValue => "unsupported_value",
-        });
+        let derive_copy = if self.compiled.other_fallback {
+            members_content.extend(quote! {
+                #[doc = " Value that is not listed in the schema."]
+                #[serde(untagged)]
+                Other(String),
+            });
+            snake_case_match_arms.extend(quote! {
+                Self::Other(_) => "other",
+            });
+            TokenStream::new()
+        } else {
+            members_content.extend(quote! {
+                #[doc = " Fallback value for values that are not supported by current version of Redfish schema."]
+                #[serde(other)]
+                UnsupportedValue,
+            });
+            snake_case_match_arms.extend(quote! {
+                Self::UnsupportedValue => "unsupported_value",
+            });
+            quote! { , Copy }
+        };
         tokens.extend([
             doc_format_and_generate(self.name, &self.compiled.odata),
             quote! {
-                #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+                #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone #derive_copy)]
                 #[allow(clippy::enum_variant_names)]
                 pub enum #name
             },
@@ -92,12 +109,102 @@ impl EnumDef<'_> {
     }
 }
 
+impl EnumDef<'_> {
+    /// Generate bit set for enums with `IsFlags="true"`. Each member
+    /// becomes an associated constant. Members without valid value
+    /// get value by their position.
+    fn generate_flags(self, tokens: &mut TokenStream, config: &Config) {
+        let name = self.name;
+        let top = &config.top_module_alias;
+        let mut consts = TokenStream::new();
+        let mut members = TokenStream::new();
+        for (idx, m) in self.compiled.members.iter().enumerate() {
+            let value = m.value.unwrap_or_else(|| 1 << idx.min(63));
+            let value_literal = Literal::u64_suffixed(value);
+            let member_name = Literal::string(m.name.inner().inner());
+            let const_name =
+                ident::escaped(&casemungler::to_snake(m.name.inner().inner()).to_uppercase());
+            consts.extend([
+                doc_format_and_generate(m.name, &m.odata),
+                quote! { pub const #const_name: Self = Self(#value_literal); },
+            ]);
+            members.extend(quote! { (#member_name, #value_literal), });
+        }
+        tokens.extend([
+            doc_format_and_generate(self.name, &self.compiled.odata),
+            quote! {
+                #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+                pub struct #name(u64);
+
+                impl #name {
+                    #consts
+
+                    /// Value without any members.
+                    #[must_use]
+                    pub const fn empty() -> Self {
+                        Self(0)
+                    }
+
+                    /// Check that all members of `other` are set.
+                    #[must_use]
+                    pub const fn contains(self, other: Self) -> bool {
+                        self.0 & other.0 == other.0
+                    }
+
+                    /// Check that no members are set.
+                    #[must_use]
+                    pub const fn is_empty(self) -> bool {
+                        self.0 == 0
+                    }
+                }
+
+                impl std::ops::BitOr for #name {
+                    type Output = Self;
+                    fn bitor(self, rhs: Self) -> Self {
+                        Self(self.0 | rhs.0)
+                    }
+                }
+
+                impl std::ops::BitOrAssign for #name {
+                    fn bitor_assign(&mut self, rhs: Self) {
+                        self.0 |= rhs.0;
+                    }
+                }
+
+                impl #top::Flags for #name {
+                    const MEMBERS: &'static [(&'static str, u64)] = &[#members];
+                    fn from_bits(bits: u64) -> Self {
+                        Self(bits)
+                    }
+                    fn bits(self) -> u64 {
+                        self.0
+                    }
+                }
+
+                impl Serialize for #name {
+                    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        nv_redfish_core::flags::serialize(self, serializer)
+                    }
+                }
+
+                impl<'de> Deserialize<'de> for #name {
+                    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        nv_redfish_core::flags::deserialize(deserializer)
+                    }
+                }
+            },
+        ]);
+    }
+}
+
+/// Name of the enum variant built from the enum member identifier.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-struct EnumMemberName<'a>(&'a SimpleIdentifier);
+pub struct EnumMemberName<'a>(&'a SimpleIdentifier);
 
 impl<'a> EnumMemberName<'a> {
+    /// Create new by member identifier.
     #[must_use]
-    const fn new(v: &'a SimpleIdentifier) -> Self {
+    pub const fn new(v: &'a SimpleIdentifier) -> Self {
         Self(v)
     }
 }
@@ -66,10 +66,10 @@ impl<'a, 'config> FullTypeName<'a, 'config> {
     }
 
     fn namespace_to_tokens(&self, tokens: &mut TokenStream) {
-        let top = &self.config.top_module_alias;
-        tokens.extend(quote! { #top });
-        for depth in 0..self.type_name.namespace.len() {
-            if let Some(id) = self.type_name.namespace.get_id(depth) {
+        let namespace = self.type_name.namespace;
+        tokens.extend(self.config.top_module_for(&namespace.root().to_string()));
+        for depth in 0..namespace.len() {
+            if let Some(id) = namespace.get_id(depth) {
                 let name = ModName::new(id);
                 tokens.append(Punct::new(':', Spacing::Joint));
                 tokens.append(Punct::new(':', Spacing::Joint));
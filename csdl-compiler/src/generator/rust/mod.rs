@@ -22,6 +22,12 @@
 //!  - Camel case identifieds in namespaces are converted to snake case.
 //!  - If namespace more than one ids then submodules are generated in according to namespace.
 //!
+//! Ordering:
+//!  - Compiled types, actions and excerpt copies are processed in
+//!    order of their names, so generated code doesn't depend on map
+//!    iteration order and is reproducible for the same schemas.
+//!  - Properties and enum members keep order of the schema.
+//!
 
 /// Escaped identifier construction
 pub mod ident;
@@ -68,6 +74,7 @@ use crate::compiler::IsCreatable;
 use crate::compiler::QualifiedName;
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -79,8 +86,12 @@ pub use action_name::ActionName;
 #[doc(inline)]
 pub use config::Config;
 #[doc(inline)]
+pub use config::ExternalTypes;
+#[doc(inline)]
 pub use enum_def::EnumDef;
 #[doc(inline)]
+pub use enum_def::EnumMemberName;
+#[doc(inline)]
 pub use full_type_name::FullTypeName;
 #[doc(inline)]
 pub use mod_def::ModDef;
@@ -131,42 +142,61 @@ impl<'a> RustGenerator<'a> {
     /// data structure.
     pub fn new(compiled: Compiled<'a>, config: Config) -> Result<Self, Error<'a>> {
         let forced_updates = compiled.forced_updates();
+        let mut odata_type_names = compiled.odata_type_names();
 
         let root = ModDef::default();
         let mut cactions = compiled.actions;
         let creatable = compiled.creatable_entity_types;
         let mut excerpt_copies = compiled.excerpt_copies;
-        let root = cactions.iter().try_fold(root, |m, (_, ma)| {
-            ma.iter()
-                .try_fold(m, |m, (_, a)| m.add_action_type(a, &config))
-        })?;
-        let root = compiled
-            .complex_types
+        // Types generated by another crate are only referenced.
+        let is_local = |name: &QualifiedName<'_>| !config.is_external(&name.namespace);
+        let mut actions = cactions.iter().collect::<Vec<_>>();
+        actions.sort_by_key(|(name, _)| **name);
+        let root = actions
             .into_iter()
+            .filter(|(name, _)| is_local(name))
+            .try_fold(root, |m, (_, ma)| {
+                let mut ma = ma.values().collect::<Vec<_>>();
+                ma.sort_by_key(|a| a.name);
+                ma.into_iter()
+                    .try_fold(m, |m, a| m.add_action_type(a, &config))
+            })?;
+        let root = sorted(compiled.complex_types)
+            .into_iter()
+            .filter(|(name, _)| is_local(name))
             .try_fold(root, |m, (name, t)| {
                 let actions = cactions.remove(&name).unwrap_or_default();
                 let forced = ForcedUpdate::new(forced_updates.contains(&name));
                 m.add_complex_type(t, actions, forced, &config)
             })?;
-        let root = compiled
-            .entity_types
+        let root = sorted(compiled.entity_types)
             .into_iter()
+            .filter(|(name, _)| is_local(name))
             .try_fold(root, |m, (_, t)| {
                 let is_creatable = IsCreatable::new(creatable.contains(&t.name));
                 let forced = ForcedUpdate::new(forced_updates.contains(&t.name));
-                let type_excerpt_copies = excerpt_copies
+                let mut type_excerpt_copies = excerpt_copies
                     .remove(&t.name)
                     .map(|v| v.into_iter().collect::<Vec<_>>())
                     .unwrap_or_default();
-                m.add_entity_type(t, is_creatable, type_excerpt_copies, forced, &config)
+                type_excerpt_copies.sort();
+                let type_names = odata_type_names.remove(&t.name).unwrap_or_default();
+                m.add_entity_type(
+                    t,
+                    is_creatable,
+                    type_excerpt_copies,
+                    forced,
+                    type_names,
+                    &config,
+                )
             })?;
-        let root = compiled
-            .type_definitions
+        let root = sorted(compiled.type_definitions)
             .into_iter()
+            .filter(|(name, _)| is_local(name))
             .try_fold(root, |m, (_, t)| m.add_type_definition(t))?;
-        let root = compiled
-            .enum_types
+        let root = sorted(compiled.enum_types)
             .into_iter()
+            .filter(|(name, _)| is_local(name))
             .try_fold(root, |m, (_, t)| m.add_enum_type(t))?;
         Ok(Self { root, config })
     }
@@ -197,7 +227,10 @@ impl<'a> RustGenerator<'a> {
                 ReferenceLeaf,
                 AdditionalProperties,
                 DynamicProperties,
+                Flags,
                 ToSnakeCase,
+                Validate,
+                ValidationError,
                 de_optional_nullable,
                 de_required_nullable,
             };
@@ -231,3 +264,10 @@ impl<'a> RustGenerator<'a> {
         tokens
     }
 }
+
+/// Entries of the map ordered by key.
+fn sorted<K: Ord, V>(map: HashMap<K, V>) -> Vec<(K, V)> {
+    let mut entries = map.into_iter().collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+}
@@ -231,17 +231,28 @@ impl<'a> ModDef<'a> {
         creatable: IsCreatable,
         excerpt_copies: Vec<ExcerptCopy>,
         forced_update: ForcedUpdate,
+        odata_type_names: Vec<&'a str>,
         config: &Config,
     ) -> Result<Self, Error<'a>> {
-        self.inner_add_entity_type(t, creatable, excerpt_copies, forced_update, 0, config)
+        self.inner_add_entity_type(
+            t,
+            creatable,
+            excerpt_copies,
+            forced_update,
+            odata_type_names,
+            0,
+            config,
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn inner_add_entity_type(
         mut self,
         t: EntityType<'a>,
         creatable: IsCreatable,
         excerpt_copies: Vec<ExcerptCopy>,
         forced_update: ForcedUpdate,
+        odata_type_names: Vec<&'a str>,
         depth: usize,
         config: &Config,
     ) -> Result<Self, Error<'a>> {
@@ -255,6 +266,7 @@ impl<'a> ModDef<'a> {
                     creatable,
                     excerpt_copies,
                     forced_update,
+                    odata_type_names,
                     depth + 1,
                     config,
                 )
@@ -293,8 +305,14 @@ impl<'a> ModDef<'a> {
             } else {
                 builder
             };
+            let builder = if let Some(dynamic_properties) = t.redfish.dynamic_properties {
+                builder.with_dynamic_properties(dynamic_properties)
+            } else {
+                builder
+            };
             let builder = builder
                 .with_properties(t.properties)
+                .with_odata_type_names(odata_type_names)
                 .with_generate_type(gen_types);
             self.add_struct_def(builder.build(config)?)
                 .map_err(Box::new)
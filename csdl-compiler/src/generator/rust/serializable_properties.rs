@@ -14,6 +14,8 @@
 // limitations under the License.
 
 use crate::compiler::Properties;
+use crate::compiler::Property;
+use crate::compiler::TypeClass;
 use crate::generator::rust::Config;
 use crate::generator::rust::FullTypeName;
 use crate::generator::rust::StructFieldName;
@@ -37,6 +39,10 @@ struct SerializableProperty<'a> {
     required_on_create: bool,
     /// Whether the property may be written but not read.
     write_only: bool,
+    /// Statements that check value `v` of the property (a reference
+    /// to the generated Rust type). Empty when there is nothing to
+    /// check.
+    validation: TokenStream,
 }
 
 /// Properties selected for serialization in generated create and update request structures.
@@ -84,12 +90,38 @@ impl<'a> SerializableProperties<'a> {
                             }
                         }
                     };
+                    let rename = Literal::string(p.name.inner().inner());
+                    let checks = match typeinfo.class {
+                        TypeClass::ComplexType => {
+                            let top = &config.top_module_alias;
+                            quote! { #top::Validate::validate(v)?; }
+                        }
+                        TypeClass::SimpleType | TypeClass::TypeDefinition => {
+                            Self::value_checks(&rename, p)
+                        }
+                        TypeClass::EnumType => TokenStream::new(),
+                    };
+                    let validation = if checks.is_empty() {
+                        checks
+                    } else {
+                        match p.ptype {
+                            OneOrCollection::One(_) => checks,
+                            OneOrCollection::Collection(_) => {
+                                if p.rigid_array_support.into_inner() {
+                                    quote! { for v in v.iter().flatten() { #checks } }
+                                } else {
+                                    quote! { for v in v { #checks } }
+                                }
+                            }
+                        }
+                    };
                     Some(SerializableProperty {
-                        rename: Literal::string(p.name.inner().inner()),
+                        rename,
                         name: StructFieldName::new_property(p.name),
                         prop_type,
                         required_on_create: p.redfish.is_required_on_create.into_inner(),
                         write_only: p.odata.permissions_is_write_only(),
+                        validation,
                     })
                 })
                 .collect(),
@@ -254,6 +286,78 @@ impl<'a> SerializableProperties<'a> {
             .into_token_stream()
     }
 
+    /// Generates checks of every update-request property that has
+    /// validation constraints.
+    #[must_use]
+    pub fn validate_fields_for_update(&self) -> TokenStream {
+        self.0
+            .iter()
+            .filter(|p| !p.validation.is_empty())
+            .map(|p| {
+                let name = p.name;
+                let validation = &p.validation;
+                quote! {
+                    if let Some(v) = &self.#name { #validation }
+                }
+            })
+            .into_token_stream()
+    }
+
+    /// Generates checks of every create-request property that has
+    /// validation constraints.
+    #[must_use]
+    pub fn validate_fields_for_create(&self) -> TokenStream {
+        self.0
+            .iter()
+            .filter(|p| !p.validation.is_empty())
+            .map(|p| {
+                let name = p.name;
+                let validation = &p.validation;
+                // Each check has its own block, so that items declared
+                // by checks of different properties don't collide.
+                if p.required_on_create {
+                    quote! {
+                        {
+                            let v = &self.#name;
+                            #validation
+                        }
+                    }
+                } else {
+                    quote! {
+                        if let Some(v) = &self.#name { #validation }
+                    }
+                }
+            })
+            .into_token_stream()
+    }
+
+    // Checks of `Validation.*` annotations and `MaxLength` of the
+    // property.
+    fn value_checks(rename: &Literal, p: &Property<'_>) -> TokenStream {
+        let minimum = p.validation.minimum.map(|v| {
+            let v = Literal::i64_unsuffixed(v.into_inner());
+            quote! { nv_redfish_core::validation::validate_minimum(#rename, v, #v)?; }
+        });
+        let maximum = p.validation.maximum.map(|v| {
+            let v = Literal::i64_unsuffixed(v.into_inner());
+            quote! { nv_redfish_core::validation::validate_maximum(#rename, v, #v)?; }
+        });
+        let pattern = p.validation.pattern.map(|v| {
+            let v = Literal::string(v.into_inner());
+            // Regular expression is compiled on first use and shared
+            // by all subsequent checks.
+            quote! {
+                static PATTERN: nv_redfish_core::validation::Pattern = nv_redfish_core::validation::Pattern::new(#v);
+                nv_redfish_core::validation::validate_pattern(#rename, v, &PATTERN)?;
+            }
+        });
+        let max_length = p.validation.max_length.map(|v| {
+            let v = Literal::usize_unsuffixed(v.into_inner());
+            quote! { nv_redfish_core::validation::validate_max_length(#rename, v, #v)?; }
+        });
+        quote! { #minimum #maximum #pattern #max_length }
+    }
+
     fn generate_optional_property_setter(p: &SerializableProperty<'a>) -> TokenStream {
         let name = p.name;
         let prop_type = &p.prop_type;
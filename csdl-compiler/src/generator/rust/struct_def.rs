@@ -15,6 +15,7 @@
 
 use crate::compiler::Action;
 use crate::compiler::ActionsMap;
+use crate::compiler::DefaultValue;
 use crate::compiler::NavProperty;
 use crate::compiler::OData;
 use crate::compiler::Parameter;
@@ -28,6 +29,7 @@ use crate::generator::rust::doc::format_and_generate as doc_format_and_generate;
 use crate::generator::rust::ActionFullTypeName;
 use crate::generator::rust::ActionName;
 use crate::generator::rust::Config;
+use crate::generator::rust::EnumMemberName;
 use crate::generator::rust::Error;
 use crate::generator::rust::FullTypeName;
 use crate::generator::rust::SerializableProperties;
@@ -75,6 +77,8 @@ pub struct StructDef<'a> {
     // it in active resource itself.
     need_redfish_settings: bool,
     dynamic_properties: Option<DynamicProperties<'a>>,
+    // Names of `@odata.type` accepted for the entity type.
+    odata_type_names: Vec<&'a str>,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -126,13 +130,17 @@ impl<'a> StructDef<'a> {
         let (base_props, impl_type) = self.base_type(&odata_id, &odata_etag, config);
 
         // Properties token streams:
-        let properties_iter = self.properties.properties.iter().filter_map(|p| {
-            if p.odata.permissions_is_write_only() || p.redfish.is_excerpt_only.into_inner() {
-                None
-            } else {
-                Some(Self::generate_property(p, config))
-            }
-        });
+        let properties = self
+            .properties
+            .properties
+            .iter()
+            .filter(|p| {
+                !p.odata.permissions_is_write_only() && !p.redfish.is_excerpt_only.into_inner()
+            })
+            .collect::<Vec<_>>();
+        let properties_iter = properties
+            .iter()
+            .map(|p| Self::generate_property(p, &self.name, config));
 
         // Navigation properties token streams:
         let nav_properties_iter = self
@@ -148,40 +156,15 @@ impl<'a> StructDef<'a> {
             .iter()
             .map(|a| Self::generate_action_property(a, config));
 
-        let additional_properties = if self.odata.additional_properties.is_some_and(|v| *v.inner())
-        {
-            // If additional_properties are explicitly set then we add
-            // placeholder with serde_json::Value to
-            // deserializer. Actually, it is almost always Oem /
-            // OemAction.
-            quote! {
-                #[serde(flatten)]
-                pub additional_properties: #top::AdditionalProperties,
-            }
-        } else {
-            // Add dynamic properties if no additional properties
-            // defined.
-            self.dynamic_properties
-                .map_or_else(
-                    TokenStream::new,
-                    |dynamic_properties| match dynamic_properties.ptype.as_str() {
-                        "Edm.PrimitiveType" => quote! {
-                            #[serde(flatten)]
-                            pub dynamic_properties: #top::DynamicProperties<#top::edm::PrimitiveType>,
-                        },
-                        "Edm.String" => quote! {
-                            #[serde(flatten)]
-                            pub dynamic_properties: #top::DynamicProperties<#top::edm::String>,
-                        },
-                        v => quote! { not_supported_type: compile_error!(#v) },
-                    },
-                )
-        };
+        let additional_properties = self.generate_additional_properties(config);
+
+        let (next_link_property, paging_impl) = self.generate_paging(impl_type);
 
         // Combine all together in content
         let all_properties = iter::once(base_props)
             .chain(properties_iter)
             .chain(nav_properties_iter)
+            .chain(iter::once(next_link_property))
             .chain(action_iter)
             .chain(iter::once(additional_properties));
 
@@ -210,7 +193,13 @@ impl<'a> StructDef<'a> {
                 #[doc = "SAFETY: All generated data types are Sync"]
                 unsafe impl Sync for #name {}
             },
+            Self::generate_default_value_fns(&name, &properties, config),
         ]);
+        if impl_type == ImplType::None {
+            self.generate_default_impl(tokens, &properties, &actions);
+        }
+
+        let required_impl = self.generate_required_impl(impl_type, config);
 
         // Additional function that are implemented for type:
         let entity_type_impl = |fn_id_impl, fn_etag_impl| {
@@ -218,6 +207,8 @@ impl<'a> StructDef<'a> {
                 impl #top::EntityTypeRef for #name {
                     #[inline] fn odata_id(&self) -> &ODataId { #fn_id_impl }
                     #[inline] fn etag(&self) -> Option<&ODataETag> { #fn_etag_impl }
+                    #required_impl
+                    #paging_impl
                 }
             }
         };
@@ -248,6 +239,157 @@ impl<'a> StructDef<'a> {
         }
     }
 
+    // Property with link to the next page and `EntityTypeRef`
+    // functions that merge pages of collection members.
+    fn generate_paging(&self, impl_type: ImplType) -> (TokenStream, TokenStream) {
+        if impl_type == ImplType::None {
+            return (TokenStream::new(), TokenStream::new());
+        }
+        self.paged_members()
+            .map_or_else(Default::default, |members| {
+                (
+                    quote! {
+                        #[serde(rename="Members@odata.nextLink", default)]
+                        pub members_next_link: Option<ODataId>,
+                    },
+                    quote! {
+                        #[inline] fn next_page_link(&self) -> Option<&ODataId> {
+                            self.members_next_link.as_ref()
+                        }
+                        fn append_page(&mut self, page: Self) {
+                            self.#members.extend(page.#members);
+                            self.members_next_link = page.members_next_link;
+                        }
+                    },
+                )
+            })
+    }
+
+    // `Members` of collections that the service may split into pages
+    // linked by `Members@odata.nextLink`.
+    fn paged_members(&self) -> Option<StructFieldName<'a>> {
+        self.properties.nav_properties.iter().find_map(|p| match p {
+            NavProperty::Expandable(p)
+                if p.name.inner().inner() == "Members"
+                    && matches!(p.ptype, OneOrCollection::Collection(_))
+                    && p.redfish.is_required.into_inner()
+                    && !p.nullable.into_inner()
+                    && p.redfish.excerpt_copy.is_none()
+                    && !p.odata.permissions_is_write_only() =>
+            {
+                Some(StructFieldName::new_property(p.name))
+            }
+            _ => None,
+        })
+    }
+
+    // Placeholder for properties that are not defined by schema.
+    fn generate_additional_properties(&self, config: &Config) -> TokenStream {
+        let top = &config.top_module_alias;
+        if self.odata.additional_properties.is_some_and(|v| *v.inner()) {
+            // If additional_properties are explicitly set then we add
+            // placeholder with serde_json::Value to
+            // deserializer. Actually, it is almost always Oem /
+            // OemAction.
+            quote! {
+                #[serde(flatten)]
+                pub additional_properties: #top::AdditionalProperties,
+            }
+        } else {
+            // Add dynamic properties if no additional properties
+            // defined.
+            self.dynamic_properties
+                .map_or_else(
+                    TokenStream::new,
+                    |dynamic_properties| match dynamic_properties.ptype {
+                        "Edm.PrimitiveType" => quote! {
+                            #[serde(flatten)]
+                            pub dynamic_properties: #top::DynamicProperties<#top::edm::PrimitiveType>,
+                        },
+                        "Edm.String" => quote! {
+                            #[serde(flatten)]
+                            pub dynamic_properties: #top::DynamicProperties<#top::edm::String>,
+                        },
+                        "Collection(Edm.PrimitiveType)" => quote! {
+                            #[serde(flatten)]
+                            pub dynamic_properties: #top::DynamicProperties<Vec<#top::edm::PrimitiveType>>,
+                        },
+                        "Collection(Edm.String)" => quote! {
+                            #[serde(flatten)]
+                            pub dynamic_properties: #top::DynamicProperties<Vec<#top::edm::String>>,
+                        },
+                        // Untyped members of open types and types
+                        // that have no mapping are kept as JSON.
+                        _ => quote! {
+                            #[serde(flatten)]
+                            pub dynamic_properties: #top::DynamicProperties<#top::AdditionalProperties>,
+                        },
+                    },
+                )
+        }
+    }
+
+    // Required properties are reported by name when they are missing
+    // in payload.
+    fn generate_required_impl(&self, impl_type: ImplType, config: &Config) -> TokenStream {
+        let top = &config.top_module_alias;
+        let required = self.required_properties();
+        let fn_required_impl = if required.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! {
+                #[inline] fn required_properties() -> &'static [&'static str] { &[#(#required),*] }
+            }
+        };
+        let fn_missing_required_impl = match (impl_type, self.base) {
+            (ImplType::Child, Some(base)) => {
+                let typename = FullTypeName::new(base, config);
+                quote! {
+                    fn missing_required_property(payload: &#top::AdditionalProperties) -> Option<&'static str> {
+                        <#typename as #top::EntityTypeRef>::missing_required_property(payload).or_else(|| {
+                            nv_redfish_core::required::missing_property(Self::required_properties(), payload)
+                        })
+                    }
+                }
+            }
+            _ => TokenStream::new(),
+        };
+        let fn_odata_type_names_impl = if self.odata_type_names.is_empty() {
+            TokenStream::new()
+        } else {
+            let names = self.odata_type_names.iter().map(|v| Literal::string(v));
+            quote! {
+                #[inline] fn odata_type_names() -> &'static [&'static str] { &[#(#names),*] }
+            }
+        };
+        quote! { #fn_required_impl #fn_missing_required_impl #fn_odata_type_names_impl }
+    }
+
+    // Names of properties that are required in the payload.
+    fn required_properties(&self) -> Vec<Literal> {
+        let properties = self.properties.properties.iter().filter_map(|p| {
+            (p.redfish.is_required.into_inner() && !p.odata.permissions_is_write_only())
+                .then_some(p.name)
+        });
+        let nav_properties = self
+            .properties
+            .nav_properties
+            .iter()
+            .filter_map(|p| match p {
+                NavProperty::Expandable(v)
+                    if v.redfish.is_required.into_inner()
+                        && !v.odata.permissions_is_write_only() =>
+                {
+                    Some(v.name)
+                }
+                _ => None,
+            });
+        properties
+            .chain(nav_properties)
+            .map(|name| Literal::string(name.inner().inner()))
+            .collect()
+    }
+
     fn generate_excerpt(
         &self,
         tokens: &mut TokenStream,
@@ -255,26 +397,59 @@ impl<'a> StructDef<'a> {
         excerpt_copy: &ExcerptCopy,
     ) {
         let mut content = TokenStream::new();
-        let all_properties = self.properties.properties.iter().filter_map(|p| {
-            if !p.odata.permissions_is_write_only()
-                && p.redfish
-                    .excerpt
-                    .as_ref()
-                    .is_some_and(|excerpt| excerpt.matches(excerpt_copy))
-            {
-                Some(Self::generate_property(p, config))
-            } else {
-                None
-            }
-        });
+        let name = self.name.for_excerpt_copy(excerpt_copy);
+        let properties = self
+            .properties
+            .properties
+            .iter()
+            .filter(|p| {
+                !p.odata.permissions_is_write_only()
+                    && p.redfish
+                        .excerpt
+                        .as_ref()
+                        .is_some_and(|excerpt| excerpt.matches(excerpt_copy))
+            })
+            .collect::<Vec<_>>();
+        let all_properties = properties
+            .iter()
+            .map(|p| Self::generate_property(p, &name, config));
+
+        // Navigation properties can be part of excerpt too. Only
+        // expandable properties keep Redfish annotations, references
+        // are never included.
+        let nav_properties = self
+            .properties
+            .nav_properties
+            .iter()
+            .filter_map(|p| match p {
+                NavProperty::Expandable(v)
+                    if v.redfish
+                        .excerpt
+                        .as_ref()
+                        .is_some_and(|excerpt| excerpt.matches(excerpt_copy)) =>
+                {
+                    Some(Self::generate_nav_property(p, config))
+                }
+                _ => None,
+            });
 
         content.extend(all_properties);
+        content.extend(nav_properties);
 
-        let name = self.name.for_excerpt_copy(excerpt_copy);
-        tokens.extend([quote! {
-            #[derive(Deserialize, Debug)]
-            pub struct #name { #content }
-        }]);
+        // See `generate_read` for the reason of Send and Sync
+        // implementation.
+        tokens.extend([
+            doc_format_and_generate(&name, &self.odata),
+            quote! {
+                #[derive(Deserialize, Debug)]
+                pub struct #name { #content }
+                #[doc = "SAFETY: All generated data types are Send"]
+                unsafe impl Send for #name {}
+                #[doc = "SAFETY: All generated data types are Sync"]
+                unsafe impl Sync for #name {}
+            },
+            Self::generate_default_value_fns(&name, &properties, config),
+        ]);
     }
 
     fn base_type(
@@ -295,7 +470,7 @@ impl<'a> StructDef<'a> {
         self.base.map_or_else(
             || {
                 if *self.odata.must_have_id.inner() {
-                    let top = &config.top_module_alias;
+                    let settings = config.top_module_for("Settings");
                     // MustHaveId only for the root elements in type hierarchy. This requirements by code
                     // generation. Generator needs to add @odata.id field to the struct.
                     // If we will add odata.id on each level it may break deserialization.
@@ -307,9 +482,9 @@ impl<'a> StructDef<'a> {
                             pub #odata_etag: Option<ODataETag>,
                             #maybe_odata_type
                             #[serde(rename = "@Redfish.Settings")]
-                            pub redfish_settings: Option<#top::settings::Settings>,
+                            pub redfish_settings: Option<#settings::settings::Settings>,
                             #[serde(rename = "@Redfish.SettingsApplyTime")]
-                            pub redfish_settings_apply_type: Option<#top::settings::PreferredApplyTime>,
+                            pub redfish_settings_apply_type: Option<#settings::settings::PreferredApplyTime>,
                         },
                         ImplType::Root,
                     )
@@ -387,12 +562,19 @@ impl<'a> StructDef<'a> {
         );
         tokens.extend(quote! {
             #[doc = #comment]
-            #[derive(Serialize, Default)]
+            #[derive(Serialize, Deserialize, Clone, Default)]
             #debug_derive
             pub struct #name { #base #content #additional_properties }
         });
 
         let content = properties.optional_property_setter_for_update();
+        let top = &config.top_module_alias;
+        let validate_base = self.base.map(|_| {
+            quote! {
+                if let Some(v) = &self.base { #top::Validate::validate(v)?; }
+            }
+        });
+        let validate_fields = properties.validate_fields_for_update();
 
         // Generate builder for struct.
         tokens.extend(quote! {
@@ -408,6 +590,13 @@ impl<'a> StructDef<'a> {
                 #base_impl
                 #content
             }
+            impl #top::Validate for #name {
+                fn validate(&self) -> Result<(), #top::ValidationError> {
+                    #validate_base
+                    #validate_fields
+                    Ok(())
+                }
+            }
             #debug_impl
         });
     }
@@ -436,6 +625,8 @@ impl<'a> StructDef<'a> {
         // Implement builder for create struct:
         let builder_fn_arglist = properties.builder_fn_arg_list_for_create();
         let builder_fn_content = properties.builder_fn_content_for_create();
+        let validate_fields = properties.validate_fields_for_create();
+        let top = &config.top_module_alias;
 
         tokens.extend([quote! {
             impl #name {
@@ -451,6 +642,12 @@ impl<'a> StructDef<'a> {
                 }
                 #prop_fn_content
             }
+            impl #top::Validate for #name {
+                fn validate(&self) -> Result<(), #top::ValidationError> {
+                    #validate_fields
+                    Ok(())
+                }
+            }
             #debug_impl
         }]);
     }
@@ -524,16 +721,36 @@ impl<'a> StructDef<'a> {
         ]);
     }
 
-    fn generate_property(p: &Property<'_>, config: &Config) -> TokenStream {
+    fn generate_property(p: &Property<'_>, owner: &impl ToTokens, config: &Config) -> TokenStream {
         let doc = doc_format_and_generate(p.name, &p.odata);
-        let (serde, field_type) = Self::gen_de_struct_field(
-            &p.ptype,
-            FullTypeName::new(p.ptype.name(), config),
-            Literal::string(p.name.inner().inner()),
-            p.nullable,
-            p.redfish.is_required,
-            p.rigid_array_support,
-        );
+        let rename = Literal::string(p.name.inner().inner());
+        let ftype = FullTypeName::new(p.ptype.name(), config);
+        let (serde, field_type) = if let Some(default_fn) = Self::default_value_fn(p) {
+            // Properties with default value are not wrapped into
+            // `Option`. Nullable properties are still `Option`
+            // where `None` means null.
+            let default_fn = Literal::string(&format!("{}::{default_fn}", owner.to_token_stream()));
+            if p.nullable.into_inner() {
+                (
+                    quote! { #[serde(rename=#rename, default=#default_fn, deserialize_with="de_required_nullable")] },
+                    quote! { Option<#ftype> },
+                )
+            } else {
+                (
+                    quote! { #[serde(rename=#rename, default=#default_fn)] },
+                    quote! { #ftype },
+                )
+            }
+        } else {
+            Self::gen_de_struct_field(
+                &p.ptype,
+                ftype,
+                rename,
+                p.nullable,
+                p.redfish.is_required,
+                p.rigid_array_support,
+            )
+        };
         let name = StructFieldName::new_property(p.name);
         quote! {
             #doc #serde
@@ -541,6 +758,123 @@ impl<'a> StructDef<'a> {
         }
     }
 
+    // Name of the function that provides default value of the
+    // property. Only optional single-valued properties with
+    // `DefaultValue` have it.
+    fn default_value_fn(p: &Property<'_>) -> Option<Ident> {
+        (p.default_value.is_some()
+            && !p.redfish.is_required.into_inner()
+            && matches!(p.ptype, OneOrCollection::One(_)))
+        .then(|| {
+            Ident::new(
+                &format!("default_{}", StructFieldName::new_property(p.name)),
+                Span::call_site(),
+            )
+        })
+    }
+
+    // Value of the property if it is missing in payload.
+    fn default_value(p: &Property<'_>, config: &Config) -> Option<TokenStream> {
+        let value = match p.default_value.as_ref()? {
+            DefaultValue::Boolean(v) => quote! { #v },
+            DefaultValue::Integer(v) => Literal::i64_unsuffixed(*v).into_token_stream(),
+            DefaultValue::Number(v) => Literal::f64_unsuffixed(*v).into_token_stream(),
+            DefaultValue::String(v) => quote! { #v.into() },
+            DefaultValue::EnumMember(v) => {
+                let ftype = FullTypeName::new(p.ptype.name(), config);
+                let member = EnumMemberName::new(v);
+                quote! { #ftype::#member }
+            }
+        };
+        Some(if p.nullable.into_inner() {
+            quote! { Some(#value) }
+        } else {
+            value
+        })
+    }
+
+    // Functions used by serde to fill properties that have default
+    // value.
+    fn generate_default_value_fns(
+        owner: &impl ToTokens,
+        properties: &[&Property<'_>],
+        config: &Config,
+    ) -> TokenStream {
+        let fns = properties
+            .iter()
+            .filter_map(|p| {
+                let default_fn = Self::default_value_fn(p)?;
+                let value = Self::default_value(p, config)?;
+                let ftype = FullTypeName::new(p.ptype.name(), config);
+                Some(if p.nullable.into_inner() {
+                    quote! {
+                        #[allow(clippy::unnecessary_wraps)]
+                        fn #default_fn() -> Option<#ftype> { #value }
+                    }
+                } else {
+                    quote! { fn #default_fn() -> #ftype { #value } }
+                })
+            })
+            .collect::<Vec<_>>();
+        if fns.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! { impl #owner { #(#fns)* } }
+        }
+    }
+
+    // Complex types where all properties are optional or have
+    // default values implement `Default`. It is implemented only if
+    // at least one property has default value.
+    fn generate_default_impl(
+        &self,
+        tokens: &mut TokenStream,
+        properties: &[&Property<'_>],
+        actions: &[&Action<'_>],
+    ) {
+        let has_defaults = properties
+            .iter()
+            .any(|p| Self::default_value_fn(p).is_some());
+        let all_optional = properties
+            .iter()
+            .all(|p| !p.redfish.is_required.into_inner())
+            && self.properties.nav_properties.iter().all(|p| match p {
+                NavProperty::Expandable(v) => !v.redfish.is_required.into_inner(),
+                NavProperty::Reference(_) => true,
+            });
+        if !has_defaults
+            || !all_optional
+            || self.base.is_some()
+            || !actions.is_empty()
+            || *self.odata.must_have_type.inner()
+            || self.odata.additional_properties.is_some_and(|v| *v.inner())
+            || self.dynamic_properties.is_some()
+        {
+            return;
+        }
+        let fields = properties
+            .iter()
+            .map(|p| {
+                let name = StructFieldName::new_property(p.name);
+                Self::default_value_fn(p).map_or_else(
+                    || quote! { #name: None, },
+                    |default_fn| quote! { #name: Self::#default_fn(), },
+                )
+            })
+            .chain(self.properties.nav_properties.iter().map(|p| {
+                let name = StructFieldName::new_property(p.name());
+                quote! { #name: None, }
+            }));
+        let name = self.name;
+        tokens.extend(quote! {
+            impl Default for #name {
+                fn default() -> Self {
+                    Self { #(#fields)* }
+                }
+            }
+        });
+    }
+
     // Returns serde annotation and field type token streams.
     fn gen_de_struct_field<T>(
         cardinality: &OneOrCollection<T>,
@@ -630,7 +964,13 @@ impl<'a> StructDef<'a> {
                 let ptype = p.redfish.excerpt_copy.as_ref().map_or_else(
                     || {
                         let full_type = FullTypeName::new(p.ptype.name(), config);
-                        quote! { NavProperty<#full_type> }
+                        if p.odata.is_auto_expand() {
+                            // Auto-expanded values are always included
+                            // in the payload.
+                            full_type.to_token_stream()
+                        } else {
+                            quote! { NavProperty<#full_type> }
+                        }
                     },
                     |excerpt| {
                         FullTypeName::new(p.ptype.name(), config)
@@ -769,17 +1109,22 @@ impl<'a> StructDef<'a> {
         let name = ActionName::new(a.name);
         let typename =
             ActionFullTypeName::new(a.defining_namespace, a.binding_name, a.name, config);
-        let ret_type = match a.return_type {
+        let ret_type = Self::action_return_type(a, config);
+        quote! {
+            #[serde(rename=#rename)]
+            pub #name: Option<#top::Action<#typename, #ret_type>>,
+        }
+    }
+
+    // Type of the value returned by BMC on successful action.
+    fn action_return_type(a: &Action, config: &Config) -> TokenStream {
+        match a.return_type {
             Some(OneOrCollection::One(v)) => FullTypeName::new(v, config).to_token_stream(),
             Some(OneOrCollection::Collection(v)) => {
                 let typename = FullTypeName::new(v, config);
                 quote! { Vec<#typename> }
             }
             None => quote! { () },
-        };
-        quote! {
-            #[serde(rename=#rename)]
-            pub #name: Option<#top::Action<#typename, #ret_type>>,
         }
     }
 
@@ -843,14 +1188,7 @@ impl<'a> StructDef<'a> {
         let name = ActionName::new(a.name);
         let typename =
             ActionFullTypeName::new(a.defining_namespace, a.binding_name, a.name, config);
-        let ret_type = match a.return_type {
-            Some(OneOrCollection::One(v)) => FullTypeName::new(v, config).to_token_stream(),
-            Some(OneOrCollection::Collection(v)) => {
-                let typename = FullTypeName::new(v, config);
-                quote! { Vec<#typename> }
-            }
-            None => quote! { () },
-        };
+        let ret_type = Self::action_return_type(a, config);
         let doc_action_errors = quote! {
             #[doc = ""]
             #[doc = "# Errors"]
@@ -950,6 +1288,7 @@ impl<'a> StructDefBuilder<'a> {
             create_type: None,
             need_redfish_settings: false,
             dynamic_properties: None,
+            odata_type_names: Vec::new(),
         })
     }
 
@@ -1009,6 +1348,13 @@ impl<'a> StructDefBuilder<'a> {
         self
     }
 
+    /// Setup names of `@odata.type` accepted for the entity type.
+    #[must_use]
+    pub fn with_odata_type_names(mut self, names: Vec<&'a str>) -> Self {
+        self.0.odata_type_names = names;
+        self
+    }
+
     /// # Errors
     ///
     /// Returns error if struct definition cannot be generated by the
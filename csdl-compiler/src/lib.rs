@@ -24,6 +24,8 @@
 //! - Compile: resolve types, properties, actions, and annotations into
 //!   `Compiled` (`compiler`); optionally optimize the set (`optimizer`)
 //! - Generate: produce Rust modules and types (`generator`)
+//! - Build: compile OEM schemas from build scripts of downstream
+//!   crates (`build`)
 //!
 //! Key features
 //! - Understands `OData` annotations (permissions, insert/update/delete)
@@ -54,10 +56,16 @@
 
 //#![deny(missing_docs)]
 
+/// Build script API of OEM schema compilation.
+pub mod build;
 /// High-level compiler commands.
 pub mod commands;
 /// Redfish schema compiler.
 pub mod compiler;
+/// Coverage of schema properties by tests.
+pub mod coverage;
+/// Source locations for diagnostics.
+pub mod diagnostics;
 /// Entity Data Model XML definitions.
 pub mod edmx;
 /// Compiler errors.
@@ -72,8 +80,14 @@ pub mod odata;
 pub mod one_or_collection;
 /// Optimizer for compiled data structures.
 pub mod optimizer;
+/// Schema-shaped payloads for fuzz tests.
+pub mod payload;
 /// Redfish-specific utilities.
 pub mod redfish;
+/// Cache of documents referenced by `edmx:Reference`.
+pub mod reference_cache;
+/// Statistics of generated code.
+pub mod stats;
 
 use tagged_types::TaggedType;
 
@@ -131,9 +145,19 @@ pub enum IsAbstractTag {}
 mod test {
     use super::edmx::attribute_values::SimpleIdentifier;
     use super::edmx::Edmx;
+    use crate::commands::process_command;
+    use crate::commands::Commands;
+    use crate::commands::DEFAULT_ROOT;
     use crate::Error;
+    use quote::ToTokens as _;
+    use std::env::temp_dir;
     use std::fs;
     use std::path::Path;
+    use std::process;
+    use syn::Expr;
+    use syn::ImplItem;
+    use syn::Item;
+    use syn::Stmt;
 
     fn crate_root() -> &'static Path {
         Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -182,7 +206,8 @@ mod test {
         let fname = crate_root().join("test-data/edmx/odata-4.0.xml");
         let fname_string = fname.display().to_string();
         let data = fs::read_to_string(fname).map_err(|err| Error::Io(fname_string.clone(), err))?;
-        let _edmx: Edmx = Edmx::parse(&data).map_err(|err| Error::Edmx(fname_string, err))?;
+        let _edmx: Edmx =
+            Edmx::parse(&data).map_err(|err| Error::Edmx(fname_string.into(), err))?;
         Ok(())
     }
 
@@ -192,7 +217,7 @@ mod test {
         let fname = crate_root().join("test-data/redfish-schema/CoolantConnector_v1.xml");
         let fname_string = fname.display().to_string();
         let data = fs::read_to_string(fname).map_err(|err| Error::Io(fname_string.clone(), err))?;
-        let edmx: Edmx = Edmx::parse(&data).map_err(|err| Error::Edmx(fname_string, err))?;
+        let edmx: Edmx = Edmx::parse(&data).map_err(|err| Error::Edmx(fname_string.into(), err))?;
         assert_eq!(edmx.data_services.schemas.len(), 6);
         assert_eq!(edmx.data_services.schemas.get(1).unwrap().types.len(), 4);
         assert_eq!(
@@ -210,4 +235,148 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_generated_code_is_reproducible() -> Result<(), Error> {
+        let schema = crate_root().join("../tests/schemas/base/schema.xml");
+        let output =
+            |n: usize| temp_dir().join(format!("csdl-compiler-repro-{}-{n}.rs", process::id()));
+        let generated = (0..3)
+            .map(|n| {
+                process_command(&Commands::Compile {
+                    root: DEFAULT_ROOT.into(),
+                    include_root_patterns: vec![],
+                    csdls: vec![schema.display().to_string()],
+                    output: output(n),
+                    entity_type_patterns: vec![],
+                    rigid_array_patterns: vec![],
+                    lean_property_patterns: vec![],
+                    strict_references: false,
+                    reference_cache: None,
+                    download_references: false,
+                    stats: None,
+                })?;
+                let content = fs::read_to_string(output(n))
+                    .map_err(|err| Error::Io(output(n).display().to_string(), err))?;
+                let _ = fs::remove_file(output(n));
+                Ok(content)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        assert!(generated.iter().all(|v| v == &generated[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn create_validation_checks_are_scoped() -> Result<(), Error> {
+        let schema = r#"<edmx:Edmx xmlns:edmx="http://docs.oasis-open.org/odata/ns/edmx" Version="4.0">
+  <edmx:Reference Uri="http://redfish.dmtf.org/schemas/v1/RedfishExtensions_v1.xml">
+    <edmx:Include Namespace="Validation.v1_0_0" Alias="Validation"/>
+    <edmx:Include Namespace="RedfishExtensions.v1_0_0" Alias="Redfish"/>
+  </edmx:Reference>
+  <edmx:DataServices>
+    <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+      <EntityType Name="Resource" Abstract="true"/>
+      <EntityType Name="ResourceCollection" Abstract="true"/>
+    </Schema>
+    <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Test.v1_0_0">
+      <EntityType Name="TestCollection">
+        <Annotation Term="Capabilities.InsertRestrictions">
+          <Record><PropertyValue Property="Insertable" Bool="true"/></Record>
+        </Annotation>
+        <NavigationProperty Name="Members" Type="Collection(Test.v1_0_0.TestMember)"/>
+      </EntityType>
+      <EntityType Name="TestMember">
+        <Property Name="First" Type="Edm.String" Nullable="false" MaxLength="8">
+          <Annotation Term="Redfish.RequiredOnCreate"/>
+          <Annotation Term="Validation.Pattern" String="^[a-z]+$"/>
+        </Property>
+        <Property Name="Second" Type="Edm.String" Nullable="false">
+          <Annotation Term="Redfish.RequiredOnCreate"/>
+          <Annotation Term="Validation.Pattern" String="^[0-9]+$"/>
+        </Property>
+        <Property Name="Third" Type="Edm.String" Nullable="false">
+          <Annotation Term="Redfish.RequiredOnCreate"/>
+          <Annotation Term="Validation.Pattern" String="^(?!root$).*$"/>
+        </Property>
+      </EntityType>
+    </Schema>
+    <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Settings">
+      <ComplexType Name="Settings"/>
+      <ComplexType Name="PreferredApplyTime"/>
+    </Schema>
+  </edmx:DataServices>
+</edmx:Edmx>
+"#;
+        let path = |ext: &str| {
+            temp_dir().join(format!("csdl-compiler-validation-{}.{ext}", process::id()))
+        };
+        fs::write(path("xml"), schema)
+            .map_err(|err| Error::Io(path("xml").display().to_string(), err))?;
+        let messages = process_command(&Commands::CompileOem {
+            root_csdls: vec![path("xml").display().to_string()],
+            resolve_csdls: vec![],
+            output: path("rs"),
+            entity_type_patterns: vec![],
+            rigid_array_patterns: vec![],
+            strict_references: false,
+            reference_cache: None,
+            download_references: false,
+            external_module: None,
+            stats: None,
+        });
+        let content = fs::read_to_string(path("rs"));
+        let _ = fs::remove_file(path("xml"));
+        let _ = fs::remove_file(path("rs"));
+        let messages = messages?;
+        let content = content.map_err(|err| Error::Io(path("rs").display().to_string(), err))?;
+        assert!(messages.iter().any(|msg| msg.starts_with(
+            "warning: Test.TestMember/Third: pattern ^(?!root$).*$ is not supported"
+        )));
+        assert!(content.contains(r#"validate_max_length("First", v, 8)"#));
+
+        // Patterns of both properties are declared in separate blocks
+        // of `validate`.
+        let file = syn::parse_file(&content).map_err(Error::ParseGenerated)?;
+        let validate =
+            validate_stmts(&file, "TestMemberCreate").expect("validate must be generated");
+        let scoped_patterns = validate
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Stmt::Expr(Expr::Block(b), _) => Some(&b.block.stmts),
+                _ => None,
+            })
+            .filter(|stmts| {
+                stmts
+                    .iter()
+                    .any(|stmt| matches!(stmt, Stmt::Item(Item::Static(_))))
+            })
+            .count();
+        assert!(!validate
+            .iter()
+            .any(|stmt| matches!(stmt, Stmt::Item(Item::Static(_)))));
+        assert_eq!(scoped_patterns, 2);
+        Ok(())
+    }
+
+    // Statements of generated `validate` of the type.
+    fn validate_stmts<'a>(file: &'a syn::File, type_name: &str) -> Option<&'a Vec<Stmt>> {
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Mod(m) => m.content.as_ref(),
+                _ => None,
+            })
+            .flat_map(|(_, items)| items)
+            .filter_map(|item| match item {
+                Item::Impl(v) if v.self_ty.to_token_stream().to_string() == type_name => {
+                    Some(&v.items)
+                }
+                _ => None,
+            })
+            .flatten()
+            .find_map(|item| match item {
+                ImplItem::Fn(f) if f.sig.ident == "validate" => Some(&f.block.stmts),
+                _ => None,
+            })
+    }
 }
@@ -30,9 +30,9 @@ struct Cli {
 fn main() -> Result<(), Error> {
     let cli = Cli::parse();
 
-    let _ = process_command(&cli.command)?
-        .into_iter()
-        .map(|msg| println!("{msg}"));
+    for msg in process_command(&cli.command)? {
+        println!("{msg}");
+    }
     Ok(())
 }
 
@@ -56,6 +56,44 @@ pub type AdditionalProperties = TaggedType<bool, AdditionalPropertiesTag>;
 #[capability(inner_access, cloned)]
 pub enum AdditionalPropertiesTag {}
 
+/// Value of the navigation property is included in the payload
+/// instead of a reference.
+pub type AutoExpand = TaggedType<bool, AutoExpandTag>;
+#[doc(hidden)]
+#[derive(tagged_types::Tag)]
+#[implement(Clone, Copy)]
+#[transparent(Display, Debug)]
+#[capability(inner_access, cloned)]
+pub enum AutoExpandTag {}
+
+/// Minimum value of the property (`Validation.Minimum`).
+pub type ValidationMinimum = TaggedType<i64, ValidationMinimumTag>;
+#[doc(hidden)]
+#[derive(tagged_types::Tag)]
+#[implement(Clone, Copy)]
+#[transparent(Display, Debug)]
+#[capability(inner_access, cloned)]
+pub enum ValidationMinimumTag {}
+
+/// Maximum value of the property (`Validation.Maximum`).
+pub type ValidationMaximum = TaggedType<i64, ValidationMaximumTag>;
+#[doc(hidden)]
+#[derive(tagged_types::Tag)]
+#[implement(Clone, Copy)]
+#[transparent(Display, Debug)]
+#[capability(inner_access, cloned)]
+pub enum ValidationMaximumTag {}
+
+/// Regular expression that value of the property must match
+/// (`Validation.Pattern`).
+pub type ValidationPatternRef<'a> = TaggedType<&'a String, ValidationPatternTag>;
+#[doc(hidden)]
+#[derive(tagged_types::Tag)]
+#[implement(Clone, Copy)]
+#[transparent(Display, Debug)]
+#[capability(inner_access, cloned)]
+pub enum ValidationPatternTag {}
+
 /// Capabilities of Enity type
 #[derive(Debug, Clone, Copy)]
 pub struct Capability<'a> {
@@ -102,6 +140,7 @@ pub enum Permissions {
 trait IsODataNamespace {
     fn is_odata_namespace(&self) -> bool;
     fn is_capabilities_namespace(&self) -> bool;
+    fn is_validation_namespace(&self) -> bool;
 }
 
 impl IsODataNamespace for Namespace {
@@ -111,11 +150,15 @@ impl IsODataNamespace for Namespace {
     fn is_capabilities_namespace(&self) -> bool {
         self.ids.len() == 1 && self.ids[0].inner() == "Capabilities"
     }
+    fn is_validation_namespace(&self) -> bool {
+        self.ids.len() == 1 && self.ids[0].inner() == "Validation"
+    }
 }
 
 pub trait ODataAnnotation {
     fn is_odata_annotation(&self, name: &str) -> bool;
     fn is_capabilities_annotation(&self, name: &str) -> bool;
+    fn is_validation_annotation(&self, name: &str) -> bool;
 }
 
 impl ODataAnnotation for Annotation {
@@ -126,6 +169,10 @@ impl ODataAnnotation for Annotation {
         self.term.inner().namespace.is_capabilities_namespace()
             && self.term.inner().name.inner() == name
     }
+    fn is_validation_annotation(&self, name: &str) -> bool {
+        self.term.inner().namespace.is_validation_namespace()
+            && self.term.inner().name.inner() == name
+    }
 }
 
 pub trait ODataAnnotations {
@@ -155,6 +202,13 @@ pub trait ODataAnnotations {
             .map(AdditionalProperties::new)
     }
 
+    fn odata_auto_expand(&self) -> Option<AutoExpand> {
+        self.annotations()
+            .iter()
+            .find(|a| a.is_odata_annotation("AutoExpand"))
+            .map(|a| AutoExpand::new(a.bool_value.unwrap_or(true)))
+    }
+
     fn odata_permissions(&self) -> Option<Permissions> {
         self.annotations()
             .iter()
@@ -215,6 +269,30 @@ pub trait ODataAnnotations {
             })
             .map(Deletable::new)
     }
+
+    fn validation_minimum(&self) -> Option<ValidationMinimum> {
+        self.annotations()
+            .iter()
+            .find(|a| a.is_validation_annotation("Minimum"))
+            .and_then(|a| a.int_value)
+            .map(ValidationMinimum::new)
+    }
+
+    fn validation_maximum(&self) -> Option<ValidationMaximum> {
+        self.annotations()
+            .iter()
+            .find(|a| a.is_validation_annotation("Maximum"))
+            .and_then(|a| a.int_value)
+            .map(ValidationMaximum::new)
+    }
+
+    fn validation_pattern(&self) -> Option<ValidationPatternRef<'_>> {
+        self.annotations()
+            .iter()
+            .find(|a| a.is_validation_annotation("Pattern"))
+            .and_then(|a| a.string.as_ref())
+            .map(ValidationPatternRef::new)
+    }
 }
 
 impl ODataAnnotations for EnumType {
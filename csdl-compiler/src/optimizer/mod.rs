@@ -25,6 +25,7 @@ mod prune_entity_type_inheritance;
 mod prune_namespaces;
 mod remove_empty_complex_types;
 mod remove_empty_entity_types;
+mod remove_unreachable_types;
 
 use crate::compiler::Compiled;
 use crate::compiler::EntityTypeFilter;
@@ -36,10 +37,14 @@ use prune_entity_type_inheritance::prune_entity_type_inheritance;
 use prune_namespaces::prune_namespaces;
 use remove_empty_complex_types::remove_empty_complex_types;
 use remove_empty_entity_types::remove_empty_entity_types;
+use remove_unreachable_types::remove_unreachable_types;
 use std::collections::HashMap;
 
 pub struct Config {
     pub never_prune: EntityTypeFilter,
+    /// Complex types that are reachable in addition to compiled
+    /// entity types. If `None`, unreachable types are not removed.
+    pub reachable_from: Option<EntityTypeFilter>,
 }
 
 impl Default for Config {
@@ -51,6 +56,7 @@ impl Default for Config {
                     .map(|f| f.parse().expect("must be correct filter"))
                     .collect(),
             ),
+            reachable_from: None,
         }
     }
 }
@@ -59,6 +65,7 @@ impl Default for Config {
 #[must_use]
 pub fn optimize<'a>(input: Compiled<'a>, config: &Config) -> Compiled<'a> {
     [
+        remove_unreachable_types,
         remove_empty_complex_types,
         remove_empty_entity_types,
         prune_complex_type_inheritance,
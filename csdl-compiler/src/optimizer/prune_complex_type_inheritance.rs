@@ -85,10 +85,12 @@ pub fn prune_complex_type_inheritance<'a>(input: Compiled<'a>, _config: &Config)
         .map(|(name, v)| {
             let mut base = v.base;
             let mut properties = vec![v.properties];
+            let mut redfish = v.redfish;
             while let Some(next_base) = base {
                 if let Some(parent) = remove.remove(&next_base) {
                     properties.push(parent.properties);
                     base = parent.base;
+                    redfish = redfish.or_parent(parent.redfish);
                 } else {
                     break;
                 }
@@ -100,7 +102,7 @@ pub fn prune_complex_type_inheritance<'a>(input: Compiled<'a>, _config: &Config)
                     base,
                     properties: Properties::rev_join(properties),
                     odata: v.odata,
-                    redfish: v.redfish,
+                    redfish,
                     is_abstract: v.is_abstract,
                 },
             )
@@ -93,11 +93,13 @@ pub fn prune_entity_type_inheritance<'a>(input: Compiled<'a>, config: &Config) -
                 let mut base = v.base;
                 let mut properties = vec![v.properties];
                 let mut odata = v.odata;
+                let mut redfish = v.redfish;
                 while let Some(next_base) = base {
                     if let Some(parent) = remove.remove(&next_base) {
                         properties.push(parent.properties);
                         base = parent.base;
                         merge_odata(&mut odata, parent.odata);
+                        redfish = redfish.or_parent(parent.redfish);
                     } else {
                         break;
                     }
@@ -110,6 +112,7 @@ pub fn prune_entity_type_inheritance<'a>(input: Compiled<'a>, config: &Config) -
                         key: v.key,
                         properties: Properties::rev_join(properties),
                         odata,
+                        redfish,
                         is_abstract: v.is_abstract,
                     },
                 )
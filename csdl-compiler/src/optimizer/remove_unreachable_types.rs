@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Remove unreachable types optimization
+//!
+//! Complex and enum types are needed for code generation only if
+//! they can be reached from compiled entity types: through base
+//! types, properties and actions of reachable types. Complex types
+//! that match root patterns of the configuration are reachable too,
+//! as well as `@Redfish.Settings` types if any entity type has
+//! settings (generator references them directly).
+//! When only few features are enabled most of Redfish types are
+//! unreachable after other optimizations, so removing them notably
+//! reduces size of generated code.
+
+use crate::compiler::Action;
+use crate::compiler::Compiled;
+use crate::compiler::EntityType;
+use crate::compiler::EntityTypeFilter;
+use crate::compiler::NavProperty;
+use crate::compiler::ParameterType;
+use crate::compiler::Properties;
+use crate::compiler::QualifiedName;
+use crate::optimizer::Config;
+use std::collections::HashSet;
+
+/// Types that generator references for entity types with settings.
+const SETTINGS_TYPES: &[&str] = &[
+    "Settings.Settings|PreferredApplyTime",
+    "Settings.*.Settings|PreferredApplyTime",
+];
+
+pub fn remove_unreachable_types<'a>(input: Compiled<'a>, config: &Config) -> Compiled<'a> {
+    let Some(roots) = &config.reachable_from else {
+        return input;
+    };
+    let reachable = collect_reachable(&input, roots);
+    Compiled {
+        complex_types: input
+            .complex_types
+            .into_iter()
+            .filter(|(name, _)| reachable.contains(name))
+            .collect(),
+        enum_types: input
+            .enum_types
+            .into_iter()
+            .filter(|(name, _)| reachable.contains(name))
+            .collect(),
+        actions: input
+            .actions
+            .into_iter()
+            .filter(|(name, _)| reachable.contains(name))
+            .collect(),
+        ..input
+    }
+}
+
+fn collect_reachable<'a>(
+    input: &Compiled<'a>,
+    roots: &EntityTypeFilter,
+) -> HashSet<QualifiedName<'a>> {
+    let settings_types = EntityTypeFilter::new_restrictive(
+        SETTINGS_TYPES
+            .iter()
+            .map(|f| f.parse().expect("must be correct filter"))
+            .collect(),
+    );
+    let has_settings = input
+        .entity_types
+        .values()
+        .any(EntityType::generates_update);
+    let mut queue = input
+        .entity_types
+        .keys()
+        .chain(
+            input
+                .complex_types
+                .keys()
+                .filter(|name| roots.matches(name) || has_settings && settings_types.matches(name)),
+        )
+        .copied()
+        .collect::<Vec<_>>();
+    let mut reachable = HashSet::new();
+    while let Some(name) = queue.pop() {
+        if !reachable.insert(name) {
+            continue;
+        }
+        if let Some(et) = input.entity_types.get(&name) {
+            queue.extend(et.base);
+            queue.extend(properties_types(&et.properties));
+        }
+        if let Some(ct) = input.complex_types.get(&name) {
+            queue.extend(ct.base);
+            queue.extend(properties_types(&ct.properties));
+        }
+        if let Some(actions) = input.actions.get(&name) {
+            queue.extend(actions.values().flat_map(action_types));
+        }
+    }
+    reachable
+}
+
+fn properties_types<'a, 'b>(
+    properties: &'b Properties<'a>,
+) -> impl Iterator<Item = QualifiedName<'a>> + 'b {
+    properties.properties.iter().map(|p| p.ptype.name()).chain(
+        properties.nav_properties.iter().filter_map(|p| match p {
+            NavProperty::Expandable(v) => Some(v.ptype.name()),
+            NavProperty::Reference(_) => None,
+        }),
+    )
+}
+
+fn action_types<'a, 'b>(action: &'b Action<'a>) -> impl Iterator<Item = QualifiedName<'a>> + 'b {
+    action
+        .parameters
+        .iter()
+        .map(|p| match &p.ptype {
+            ParameterType::Entity(v) => v.name(),
+            ParameterType::Type(v) => v.name(),
+        })
+        .chain(action.return_type.as_ref().map(|v| *v.inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Config as CompilerConfig;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn unreachable_types_removed() {
+        let fname = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/schemas/base/schema.xml");
+        let data = fs::read_to_string(fname).expect("test schema must be readable");
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(&data).expect("test schema must be valid")],
+            root_set_threshold: None,
+        };
+        let root_only = "RootSetOnlyComplexType";
+        let has_type = |compiled: &Compiled<'_>, name: &str| {
+            compiled
+                .complex_types
+                .keys()
+                .any(|qname| qname.name.inner().as_str() == name)
+        };
+        // All complex types are compiled, including ones that are not
+        // referenced by any entity type.
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("test schema must be compiled");
+        assert!(has_type(&compiled, root_only));
+
+        let disabled = Config::default();
+        let compiled = remove_unreachable_types(compiled, &disabled);
+        assert!(has_type(&compiled, root_only));
+
+        let with_root = Config {
+            reachable_from: Some(EntityTypeFilter::new_restrictive(vec![
+                "ServiceRoot.*.RootSetOnlyComplexType"
+                    .parse()
+                    .expect("must be correct filter"),
+            ])),
+            ..Config::default()
+        };
+        let compiled = remove_unreachable_types(compiled, &with_root);
+        assert!(has_type(&compiled, root_only));
+        assert!(has_type(&compiled, "Settings"));
+
+        let without_root = Config {
+            reachable_from: Some(EntityTypeFilter::new_restrictive(vec![])),
+            ..Config::default()
+        };
+        let entity_types = compiled.entity_types.len();
+        let compiled = remove_unreachable_types(compiled, &without_root);
+        assert!(!has_type(&compiled, root_only));
+        assert_eq!(compiled.entity_types.len(), entity_types);
+        // Types of all remaining properties are still compiled.
+        for ct in compiled.complex_types.values() {
+            for p in &ct.properties.properties {
+                let name = p.ptype.name();
+                assert!(
+                    name.namespace.is_edm()
+                        || compiled.complex_types.contains_key(&name)
+                        || compiled.enum_types.contains_key(&name)
+                        || compiled.type_definitions.contains_key(&name),
+                    "type of property {} must be compiled",
+                    p.name
+                );
+            }
+        }
+    }
+}
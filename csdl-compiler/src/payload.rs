@@ -0,0 +1,403 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schema-shaped payloads for fuzz tests
+//!
+//! `PayloadGenerator` walks compiled types and produces randomized
+//! JSON payloads that a conforming BMC could send for them:
+//! - Optional properties are present or absent.
+//! - Nullable properties and rigid array items can be `null`.
+//! - Enums get known members and, sometimes, values unknown to the
+//!   schema. Flags get comma-separated combinations of members.
+//! - Collections have a random number of items.
+//!
+//! Each string value is unique and reported as a marker of the
+//! payload. Generated types must keep all markers after
+//! deserialization; a lost marker means silent data loss.
+//!
+//! Generation is deterministic for the same seed, so a failing
+//! payload can be reproduced.
+
+use crate::compiler::Compiled;
+use crate::compiler::NavProperty;
+use crate::compiler::Properties;
+use crate::compiler::Property;
+use crate::compiler::QualifiedName;
+use crate::OneOrCollection;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use std::mem::take;
+
+/// Configuration of the payload generator.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Seed of the pseudo-random generator.
+    pub seed: u64,
+    /// Nesting depth of complex types after which only required
+    /// properties are generated.
+    pub max_depth: usize,
+    /// Maximum number of items in collections.
+    pub max_items: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            max_depth: 4,
+            max_items: 3,
+        }
+    }
+}
+
+/// Generated payload.
+#[derive(Debug, Serialize)]
+pub struct Payload {
+    /// JSON payload.
+    pub payload: Value,
+    /// String values of the payload that must be preserved by
+    /// deserialization.
+    pub markers: Vec<String>,
+}
+
+/// Generator of schema-shaped payloads.
+pub struct PayloadGenerator<'a, 'c> {
+    compiled: &'c Compiled<'a>,
+    config: Config,
+    rng: SplitMix64,
+    next_marker: u64,
+    markers: Vec<String>,
+}
+
+impl<'a, 'c> PayloadGenerator<'a, 'c> {
+    /// Create generator of payloads for types of `compiled`.
+    #[must_use]
+    pub const fn new(compiled: &'c Compiled<'a>, config: Config) -> Self {
+        Self {
+            compiled,
+            config,
+            rng: SplitMix64(config.seed),
+            next_marker: 0,
+            markers: Vec::new(),
+        }
+    }
+
+    /// Generate payload of the entity type `qtype`. Returns `None`
+    /// if the type is not compiled.
+    pub fn entity_type(&mut self, qtype: QualifiedName<'a>) -> Option<Payload> {
+        self.compiled.entity_types.get(&qtype)?;
+        let mut object = Map::new();
+        object.insert("@odata.id".into(), self.odata_id().into());
+        object.insert("@odata.type".into(), format!("#{qtype}").into());
+        self.entity_properties(qtype, &mut object, 0);
+        Some(self.finish(Value::Object(object)))
+    }
+
+    /// Generate payload of the complex type `qtype`. Returns `None`
+    /// if the type is not compiled.
+    pub fn complex_type(&mut self, qtype: QualifiedName<'a>) -> Option<Payload> {
+        self.compiled.complex_types.get(&qtype)?;
+        let value = self.complex_value(qtype, 0);
+        Some(self.finish(value))
+    }
+
+    fn finish(&mut self, payload: Value) -> Payload {
+        Payload {
+            payload,
+            markers: take(&mut self.markers),
+        }
+    }
+
+    fn entity_properties(
+        &mut self,
+        qtype: QualifiedName<'a>,
+        object: &mut Map<String, Value>,
+        depth: usize,
+    ) {
+        let compiled = self.compiled;
+        let mut chain = Vec::new();
+        let mut next = Some(qtype);
+        while let Some(et) = next.and_then(|qtype| compiled.entity_types.get(&qtype)) {
+            chain.push(&et.properties);
+            next = et.base;
+        }
+        for properties in chain.into_iter().rev() {
+            self.properties(properties, object, depth);
+        }
+    }
+
+    fn complex_value(&mut self, qtype: QualifiedName<'a>, depth: usize) -> Value {
+        let compiled = self.compiled;
+        let mut chain = Vec::new();
+        let mut next = Some(qtype);
+        while let Some(ct) = next.and_then(|qtype| compiled.complex_types.get(&qtype)) {
+            chain.push(&ct.properties);
+            next = ct.base;
+        }
+        let mut object = Map::new();
+        for properties in chain.into_iter().rev() {
+            self.properties(properties, &mut object, depth);
+        }
+        Value::Object(object)
+    }
+
+    fn properties(
+        &mut self,
+        properties: &Properties<'a>,
+        object: &mut Map<String, Value>,
+        depth: usize,
+    ) {
+        let optional_allowed = depth < self.config.max_depth;
+        for p in &properties.properties {
+            if p.odata.permissions_is_write_only() || p.redfish.is_excerpt_only.into_inner() {
+                continue;
+            }
+            let required = p.redfish.is_required.into_inner();
+            if !required && (!optional_allowed || self.chance(3)) {
+                continue;
+            }
+            let value = if p.nullable.into_inner() && self.chance(4) {
+                Value::Null
+            } else {
+                self.property_value(p, depth)
+            };
+            object.insert(p.name.to_string(), value);
+        }
+        for p in &properties.nav_properties {
+            let (name, is_collection, nullable, required) = match p {
+                NavProperty::Expandable(v) => (
+                    v.name,
+                    matches!(v.ptype, OneOrCollection::Collection(_)),
+                    v.nullable.into_inner(),
+                    v.redfish.is_required.into_inner(),
+                ),
+                NavProperty::Reference(v) => (
+                    *v.inner(),
+                    matches!(v, OneOrCollection::Collection(_)),
+                    false,
+                    false,
+                ),
+            };
+            if !required && (!optional_allowed || self.chance(3)) {
+                continue;
+            }
+            let value = if nullable && self.chance(4) {
+                Value::Null
+            } else if is_collection {
+                let count = self.below(self.config.max_items + 1);
+                Value::Array((0..count).map(|_| self.reference()).collect())
+            } else {
+                self.reference()
+            };
+            object.insert(name.to_string(), value);
+        }
+    }
+
+    fn property_value(&mut self, p: &Property<'a>, depth: usize) -> Value {
+        match p.ptype {
+            OneOrCollection::One((_, qtype)) => self.value(qtype, depth),
+            OneOrCollection::Collection((_, qtype)) => {
+                let rigid = p.rigid_array_support.into_inner();
+                let count = self.below(self.config.max_items + 1);
+                Value::Array(
+                    (0..count)
+                        .map(|_| {
+                            if rigid && self.chance(4) {
+                                Value::Null
+                            } else {
+                                self.value(qtype, depth)
+                            }
+                        })
+                        .collect(),
+                )
+            }
+        }
+    }
+
+    fn value(&mut self, qtype: QualifiedName<'a>, depth: usize) -> Value {
+        let compiled = self.compiled;
+        if qtype.namespace.is_edm() {
+            self.primitive(qtype.name.inner().as_str())
+        } else if let Some(td) = compiled.type_definitions.get(&qtype) {
+            self.primitive(td.underlying_type.name.inner().as_str())
+        } else if let Some(et) = compiled.enum_types.get(&qtype) {
+            if et.is_flags {
+                // Unknown names of flags are ignored by
+                // deserialization, so they are not markers.
+                let mut names = et
+                    .members
+                    .iter()
+                    .filter(|_| self.chance(2))
+                    .map(|m| m.name.to_string())
+                    .collect::<Vec<_>>();
+                if self.chance(5) {
+                    names.push(format!("FuzzUnknown{}", self.next_id()));
+                }
+                return names.join(",").into();
+            }
+            let member = et
+                .members
+                .get(self.below(et.members.len()))
+                .map(|m| m.name.to_string());
+            match member {
+                Some(member) if !self.chance(5) => member.into(),
+                _ => {
+                    let unknown = format!("FuzzUnknown{}", self.next_id());
+                    if et.other_fallback {
+                        // Unknown values of string enumerations are
+                        // kept.
+                        self.markers.push(unknown.clone());
+                    }
+                    unknown.into()
+                }
+            }
+        } else if compiled.complex_types.contains_key(&qtype) {
+            self.complex_value(qtype, depth + 1)
+        } else {
+            Value::Null
+        }
+    }
+
+    fn primitive(&mut self, name: &str) -> Value {
+        match name {
+            "Boolean" => self.chance(2).into(),
+            "Byte" | "SByte" | "Int16" | "Int32" | "Int64" => self.below(1000).into(),
+            "Decimal" | "Double" | "Single" => {
+                #[allow(clippy::cast_precision_loss)]
+                let value = self.below(100_000) as f64 / 100.0;
+                value.into()
+            }
+            "DateTimeOffset" => format!("2026-01-{:02}T12:00:00Z", self.below(28) + 1).into(),
+            "Duration" => format!("PT{}S", self.below(3600)).into(),
+            "Guid" => format!("00000000-0000-4000-8000-{:012x}", self.next_id()).into(),
+            _ => {
+                let marker = format!("fuzz-{}", self.next_id());
+                self.markers.push(marker.clone());
+                marker.into()
+            }
+        }
+    }
+
+    fn reference(&mut self) -> Value {
+        let mut object = Map::new();
+        object.insert("@odata.id".into(), self.odata_id().into());
+        Value::Object(object)
+    }
+
+    fn odata_id(&mut self) -> String {
+        format!("/redfish/v1/Fuzz/{}", self.next_id())
+    }
+
+    const fn next_id(&mut self) -> u64 {
+        self.next_marker += 1;
+        self.next_marker
+    }
+
+    /// `true` with probability `1 / n`.
+    const fn chance(&mut self, n: u64) -> bool {
+        self.rng.next().is_multiple_of(n)
+    }
+
+    /// Random number below `n` (zero if `n` is zero).
+    #[allow(clippy::cast_possible_truncation)]
+    const fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            0
+        } else {
+            (self.rng.next() % n as u64) as usize
+        }
+    }
+}
+
+/// `SplitMix64` pseudo-random generator: small, fast and good enough
+/// for payload shapes.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Config as CompilerConfig;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn payloads_are_schema_shaped_and_reproducible() {
+        let fname = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/schemas/base/schema.xml");
+        let data = fs::read_to_string(fname).expect("test schema must be readable");
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(&data).expect("test schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("test schema must be compiled");
+        let qtype = *compiled
+            .entity_types
+            .iter()
+            .find(|(qname, et)| {
+                qname.name.inner().as_str() == "ServiceRoot" && !et.is_abstract.into_inner()
+            })
+            .map(|(qname, _)| qname)
+            .expect("service root must be compiled");
+
+        let generate = |seed| {
+            let mut generator = PayloadGenerator::new(
+                &compiled,
+                Config {
+                    seed,
+                    ..Config::default()
+                },
+            );
+            (0..16)
+                .map(|_| generator.entity_type(qtype).expect("payload"))
+                .collect::<Vec<_>>()
+        };
+        let payloads = generate(1);
+        assert_eq!(
+            serde_json::to_value(&payloads).expect("serializable"),
+            serde_json::to_value(generate(1)).expect("serializable")
+        );
+        assert_ne!(
+            serde_json::to_value(&payloads).expect("serializable"),
+            serde_json::to_value(generate(2)).expect("serializable")
+        );
+        for Payload { payload, markers } in &payloads {
+            assert!(payload["@odata.id"].is_string());
+            let text = payload.to_string();
+            for marker in markers {
+                assert!(text.contains(marker.as_str()));
+            }
+        }
+        // Optional properties are sometimes absent.
+        let sizes = payloads
+            .iter()
+            .filter_map(|p| p.payload.as_object().map(Map::len))
+            .collect::<Vec<_>>();
+        assert!(sizes.iter().any(|size| *size != sizes[0]));
+    }
+}
@@ -15,6 +15,7 @@
 
 use crate::edmx::Annotation;
 use crate::edmx::ComplexType;
+use crate::edmx::EntityType;
 use crate::edmx::NavigationProperty;
 use crate::edmx::Parameter;
 use crate::edmx::StructuralProperty;
@@ -82,7 +83,12 @@ pub trait RedfishAnnotations {
                     || Some(Excerpt::All),
                     |s| {
                         Some(Excerpt::Keys(
-                            s.split(',').map(Into::into).map(ExcerptKey::new).collect(),
+                            s.split(',')
+                                .map(str::trim)
+                                .filter(|key| !key.is_empty())
+                                .map(Into::into)
+                                .map(ExcerptKey::new)
+                                .collect(),
                         ))
                     },
                 )
@@ -118,8 +124,8 @@ pub trait RedfishAnnotations {
                                 .property_value("Pattern")
                                 .and_then(|p| p.string_value.as_ref())
                                 .map(|p| DynamicProperties {
-                                    pattern: p,
-                                    ptype: t,
+                                    pattern: p.as_str(),
+                                    ptype: t.as_str(),
                                 })
                         })
                 })
@@ -150,3 +156,9 @@ impl RedfishAnnotations for ComplexType {
         &self.annotations
     }
 }
+
+impl RedfishAnnotations for EntityType {
+    fn annotations(&self) -> &Vec<Annotation> {
+        &self.annotations
+    }
+}
@@ -0,0 +1,208 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enumerations of string properties (`Redfish.Enumeration`)
+//!
+//! Some string properties list their known values with the
+//! `Redfish.Enumeration` annotation instead of referencing an enum
+//! type, because the set of values may be extended by registries.
+//! For each such property an enum type named `{Type}{Property}` is
+//! added to the schema of the owning type and the property is
+//! switched to this type. Generated enums keep values that are not
+//! listed in the `Other` variant.
+//!
+//! Property is kept as a string if any of the members is not a valid
+//! identifier, if a member is named `Other`, if the property has
+//! `Validation.Pattern`, or if the name of the enum type is already
+//! defined in the schema.
+
+use crate::edmx::annotation::AnnotationCollection;
+use crate::edmx::property::PropertyAttrs;
+use crate::edmx::Edmx;
+use crate::edmx::EnumMember;
+use crate::edmx::EnumMemberName;
+use crate::edmx::EnumType;
+use crate::edmx::LocalTypeName;
+use crate::edmx::Namespace;
+use crate::edmx::Property;
+use crate::edmx::QualifiedName;
+use crate::edmx::QualifiedTypeName;
+use crate::edmx::SimpleIdentifier;
+use crate::edmx::Type;
+use crate::odata::annotations::ODataAnnotation as _;
+use crate::redfish::annotations::RedfishAnnotation as _;
+use std::collections::HashSet;
+use std::mem::take;
+
+/// Replace string properties annotated with `Redfish.Enumeration`
+/// by generated enum types.
+pub fn apply_enumerations(docs: &mut [Edmx]) {
+    for schema in docs
+        .iter_mut()
+        .flat_map(|doc| doc.data_services.schemas.iter_mut())
+    {
+        let namespace = schema.namespace.clone();
+        let mut defined = schema
+            .types
+            .keys()
+            .chain(schema.entity_types.keys())
+            .cloned()
+            .collect::<HashSet<_>>();
+        let owners = schema
+            .entity_types
+            .values_mut()
+            .map(|et| (et.name.inner().clone(), &mut et.properties))
+            .chain(schema.types.values_mut().filter_map(|t| match t {
+                Type::ComplexType(ct) => Some((ct.name.inner().clone(), &mut ct.properties)),
+                Type::EnumType(_) | Type::TypeDefinition(_) => None,
+            }));
+        let mut enums = Vec::new();
+        for (owner, properties) in owners {
+            for p in properties.iter_mut() {
+                if let Some(et) = property_enumeration(&namespace, &owner, p, &mut defined) {
+                    enums.push(et);
+                }
+            }
+        }
+        for et in enums {
+            schema
+                .types
+                .insert(et.name.inner().clone(), Type::EnumType(et));
+        }
+    }
+}
+
+fn property_enumeration(
+    namespace: &Namespace,
+    owner: &SimpleIdentifier,
+    p: &mut Property,
+    defined: &mut HashSet<SimpleIdentifier>,
+) -> Option<EnumType> {
+    let PropertyAttrs::StructuralProperty(v) = &mut p.attrs else {
+        return None;
+    };
+    let ptype = v.ptype.qualified_type_name().inner();
+    if !ptype.namespace.is_edm()
+        || ptype.name.inner() != "String"
+        || v.annotations
+            .iter()
+            .any(|a| a.is_validation_annotation("Pattern"))
+    {
+        return None;
+    }
+    let collection = v
+        .annotations
+        .iter_mut()
+        .find(|a| a.is_redfish_annotation("Enumeration"))
+        .and_then(|a| a.collection.as_mut())?;
+    let name = format!("{owner}{}", v.name)
+        .parse::<SimpleIdentifier>()
+        .ok()
+        .filter(|name| !defined.contains(name))?;
+    let members = members(collection)?;
+    defined.insert(name.clone());
+    let qtype = QualifiedTypeName::new(QualifiedName {
+        namespace: namespace.clone(),
+        name: name.clone(),
+    });
+    v.ptype = v.ptype.as_ref().map(|_| qtype);
+    Some(EnumType {
+        name: LocalTypeName::new(name),
+        underlying_type: None,
+        is_flags: None,
+        is_string_enumeration: true,
+        members,
+        annotations: Vec::new(),
+    })
+}
+
+fn members(collection: &mut AnnotationCollection) -> Option<Vec<EnumMember>> {
+    let names = collection
+        .record
+        .iter()
+        .map(|r| {
+            r.property_value("Member")
+                .and_then(|v| v.string_value.as_ref())
+                .and_then(|v| v.parse::<SimpleIdentifier>().ok())
+                .filter(|name| name.inner() != "Other")
+        })
+        .collect::<Option<Vec<_>>>()
+        .filter(|names| !names.is_empty())?;
+    Some(
+        names
+            .into_iter()
+            .zip(collection.record.iter_mut())
+            .map(|(name, record)| EnumMember {
+                name: EnumMemberName::new(name),
+                value: None,
+                annotations: take(&mut record.annotations),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_properties_become_enums() {
+        let doc = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema Namespace="Test.v1_0_0">
+                 <EntityType Name="Test">
+                   <Property Name="Kind" Type="Edm.String">
+                     <Annotation Term="Redfish.Enumeration">
+                       <Collection>
+                         <Record><PropertyValue Property="Member" String="A"/></Record>
+                         <Record><PropertyValue Property="Member" String="B"/></Record>
+                       </Collection>
+                     </Annotation>
+                   </Property>
+                   <Property Name="Version" Type="Edm.String">
+                     <Annotation Term="Redfish.Enumeration">
+                       <Collection>
+                         <Record><PropertyValue Property="Member" String="PCIe 3.0"/></Record>
+                       </Collection>
+                     </Annotation>
+                   </Property>
+                 </EntityType>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let mut docs = [Edmx::parse(doc).expect("valid document")];
+        apply_enumerations(&mut docs);
+
+        let schema = &docs[0].data_services.schemas[0];
+        assert_eq!(schema.types.len(), 1);
+        let Some(Type::EnumType(kind)) = schema.types.values().next() else {
+            unreachable!("enum type is added");
+        };
+        assert_eq!(kind.name.inner().inner(), "TestKind");
+        assert_eq!(kind.members.len(), 2);
+        let ptypes = schema
+            .entity_types
+            .values()
+            .flat_map(|et| et.properties.iter())
+            .filter_map(|p| match &p.attrs {
+                PropertyAttrs::StructuralProperty(v) => {
+                    Some(v.ptype.qualified_type_name().inner().name.inner().clone())
+                }
+                PropertyAttrs::NavigationProperty(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ptypes, vec!["TestKind", "String"]);
+    }
+}
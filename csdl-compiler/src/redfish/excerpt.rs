@@ -20,7 +20,7 @@ use tagged_types::TaggedType;
 pub type ExcerptKey = TaggedType<String, ExcerptKeyTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
-#[implement(Clone, Copy, Hash, PartialEq, Eq)]
+#[implement(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[transparent(Display, Debug)]
 #[capability(inner_access)]
 pub enum ExcerptKeyTag {}
@@ -53,7 +53,7 @@ impl Excerpt {
 /// contains. `AllKeys` defines that all attribures marked as Excerpt
 /// shall be included. If specific key is defined then only attributes
 /// marked with `ExcerptKey` must be included.
-#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub enum ExcerptCopy {
     AllKeys,
     Key(ExcerptKey),
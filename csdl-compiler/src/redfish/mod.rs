@@ -21,6 +21,9 @@ pub mod annotations;
 /// Defines excerpt copy of the resource.
 pub mod excerpt;
 
+/// Enum types for string properties with `Redfish.Enumeration`.
+pub mod enumeration;
+
 #[doc(inline)]
 pub use excerpt::Excerpt;
 
@@ -33,6 +36,16 @@ pub use excerpt::ExcerptCopy;
 /// Dynamic properties defined by the type.
 #[derive(Debug, Clone, Copy)]
 pub struct DynamicProperties<'a> {
-    pub pattern: &'a String,
-    pub ptype: &'a String,
+    pub pattern: &'a str,
+    pub ptype: &'a str,
+}
+
+impl DynamicProperties<'_> {
+    /// Dynamic properties of `OpenType` types without
+    /// `Redfish.DynamicPropertyPatterns`: any name, any JSON value
+    /// including nested objects.
+    pub const OPEN_TYPE: Self = Self {
+        pattern: ".*",
+        ptype: "Edm.Untyped",
+    };
 }
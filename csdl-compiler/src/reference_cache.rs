@@ -0,0 +1,182 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolution of `edmx:Reference` documents through a local cache
+//!
+//! OEM schemas usually reference standard DMTF namespaces by URI
+//! (for example, `http://redfish.dmtf.org/schemas/v1/Resource_v1.xml`).
+//! When such a namespace is not defined by any of the loaded
+//! documents, the referenced document is looked up in the cache
+//! directory by the last segment of the URI. If download is enabled
+//! (requires the `remote-references` feature), missing documents are
+//! fetched and stored in the cache so that they can be vendored.
+
+use crate::diagnostics::SourceFile;
+use crate::edmx::Edmx;
+use crate::Error;
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+/// Prefixes of URIs that are resolved through the cache.
+pub const DMTF_SCHEMA_URL_PREFIXES: &[&str] = &[
+    "http://redfish.dmtf.org/schemas/",
+    "https://redfish.dmtf.org/schemas/",
+];
+
+/// Cache of documents referenced by `edmx:Reference`.
+#[derive(Debug, Clone)]
+pub struct ReferenceCache {
+    dir: PathBuf,
+    download: bool,
+}
+
+impl ReferenceCache {
+    /// Create cache in the directory. If `download` is set, missing
+    /// documents are downloaded into the directory.
+    #[must_use]
+    pub const fn new(dir: PathBuf, download: bool) -> Self {
+        Self { dir, download }
+    }
+
+    /// Get the document referenced by the URI.
+    ///
+    /// Returns `None` if the URI does not point at DMTF schemas or if
+    /// the document is not cached and download is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URI doesn't contain a file name, if
+    /// the cache cannot be read or written, or if download fails.
+    pub fn fetch(&self, uri: &str) -> Result<Option<SourceFile>, Error> {
+        if !DMTF_SCHEMA_URL_PREFIXES
+            .iter()
+            .any(|prefix| uri.starts_with(prefix))
+        {
+            return Ok(None);
+        }
+        let path = self.dir.join(cache_file_name(uri)?);
+        let fname = path.display().to_string();
+        if path.exists() {
+            let content = fs::read_to_string(&path).map_err(|err| Error::Io(fname.clone(), err))?;
+            Ok(Some(SourceFile::new(fname, content)))
+        } else if self.download {
+            let content = download(uri)?;
+            fs::create_dir_all(&self.dir)
+                .and_then(|()| fs::write(&path, &content))
+                .map_err(|err| Error::WriteOutput(path, err))?;
+            Ok(Some(SourceFile::new(fname, content)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// URIs of references that include namespaces not defined in any of
+/// the documents. Result is sorted and does not contain duplicates.
+#[must_use]
+pub fn missing_references(docs: &[Edmx]) -> Vec<&str> {
+    let defined = docs
+        .iter()
+        .flat_map(|doc| doc.data_services.schemas.iter().map(|s| &s.namespace))
+        .collect::<HashSet<_>>();
+    let mut uris = docs
+        .iter()
+        .flat_map(|doc| doc.references.iter())
+        .filter(|r| r.includes.iter().any(|i| !defined.contains(&i.namespace)))
+        .map(|r| r.uri.as_str())
+        .collect::<Vec<_>>();
+    uris.sort_unstable();
+    uris.dedup();
+    uris
+}
+
+fn cache_file_name(uri: &str) -> Result<&str, Error> {
+    let path = uri.split(['?', '#']).next().unwrap_or_default();
+    let name = path.rsplit('/').next().unwrap_or_default();
+    if name.is_empty()
+        || name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        Err(Error::InvalidReferenceUri(uri.into()))
+    } else {
+        Ok(name)
+    }
+}
+
+#[cfg(feature = "remote-references")]
+fn download(uri: &str) -> Result<String, Error> {
+    use reqwest::blocking::get as http_get;
+    use reqwest::blocking::Response;
+    http_get(uri)
+        .and_then(Response::error_for_status)
+        .and_then(Response::text)
+        .map_err(|err| Error::DownloadReference(uri.into(), err.to_string()))
+}
+
+#[cfg(not(feature = "remote-references"))]
+fn download(uri: &str) -> Result<String, Error> {
+    Err(Error::DownloadNotSupported(uri.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_name_from_uri() {
+        assert_eq!(
+            cache_file_name("http://redfish.dmtf.org/schemas/v1/Resource_v1.xml").ok(),
+            Some("Resource_v1.xml")
+        );
+        assert_eq!(
+            cache_file_name("http://redfish.dmtf.org/schemas/v1/Resource_v1.xml?x=1#y").ok(),
+            Some("Resource_v1.xml")
+        );
+        assert!(cache_file_name("http://redfish.dmtf.org/schemas/v1/").is_err());
+        assert!(cache_file_name("http://redfish.dmtf.org/schemas/v1/..").is_err());
+    }
+
+    #[test]
+    fn references_to_undefined_namespaces() {
+        let doc = |namespace: &str| {
+            format!(
+                r#"<edmx:Edmx Version="4.0">
+                 <edmx:Reference Uri="http://redfish.dmtf.org/schemas/v1/Resource_v1.xml">
+                   <edmx:Include Namespace="Resource"/>
+                   <edmx:Include Namespace="Resource.v1_0_0"/>
+                 </edmx:Reference>
+                 <edmx:Reference Uri="http://redfish.dmtf.org/schemas/v1/Other_v1.xml">
+                   <edmx:Include Namespace="Other"/>
+                 </edmx:Reference>
+                 <edmx:DataServices>
+                   <Schema Namespace="{namespace}"/>
+                 </edmx:DataServices>
+               </edmx:Edmx>"#
+            )
+        };
+        let docs = ["Oem", "Resource", "Other"]
+            .iter()
+            .map(|ns| Edmx::parse(&doc(ns)).expect("valid document"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            missing_references(&docs),
+            vec!["http://redfish.dmtf.org/schemas/v1/Resource_v1.xml"]
+        );
+        assert!(missing_references(&docs[1..]).len() == 1);
+    }
+}
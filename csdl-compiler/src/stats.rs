@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Statistics of generated code
+//!
+//! The report is produced from the formatted generated code and is
+//! written as JSON, so growth of generated code can be tracked across
+//! schema updates and feature changes. Lines are counted in the
+//! output of the formatter; each module is accounted only for its own
+//! items (submodules are reported separately).
+
+use serde::Serialize;
+use syn::File;
+use syn::Item;
+use syn::ItemMod;
+
+/// Number of structs reported in `largest_structs`.
+pub const LARGEST_STRUCTS: usize = 20;
+
+/// Statistics of generated code.
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    /// Total number of lines.
+    pub lines: usize,
+    /// Total number of generated structs.
+    pub structs: usize,
+    /// Total number of generated enums.
+    pub enums: usize,
+    /// Total number of generated type aliases.
+    pub type_aliases: usize,
+    /// Statistics of modules, ordered by module path.
+    pub modules: Vec<ModuleStats>,
+    /// Largest structs by number of lines.
+    pub largest_structs: Vec<StructStats>,
+}
+
+/// Statistics of one generated module (namespace).
+#[derive(Debug, Default, Serialize)]
+pub struct ModuleStats {
+    /// Path of the module (`service_root::v1_0_0`). Empty for the root.
+    pub path: String,
+    /// Lines of module items excluding submodules.
+    pub lines: usize,
+    /// Number of structs in the module.
+    pub structs: usize,
+    /// Number of enums in the module.
+    pub enums: usize,
+    /// Number of type aliases in the module.
+    pub type_aliases: usize,
+}
+
+/// Statistics of one generated struct.
+#[derive(Debug, Serialize)]
+pub struct StructStats {
+    /// Path of the struct (`service_root::v1_0_0::ServiceRoot`).
+    pub path: String,
+    /// Number of fields.
+    pub fields: usize,
+    /// Number of lines of the struct definition.
+    pub lines: usize,
+}
+
+impl Report {
+    /// Collect statistics of the generated file.
+    #[must_use]
+    pub fn new(file: &File) -> Self {
+        let mut report = Self {
+            lines: prettyplease::unparse(file).lines().count(),
+            ..Self::default()
+        };
+        let mut structs = Vec::new();
+        report.visit(&mut Vec::new(), &file.items, &mut structs);
+        report.modules.sort_by(|a, b| a.path.cmp(&b.path));
+        structs.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.path.cmp(&b.path)));
+        structs.truncate(LARGEST_STRUCTS);
+        report.largest_structs = structs;
+        report
+    }
+
+    /// Report as pretty-printed JSON.
+    ///
+    /// # Panics
+    ///
+    /// Never panics: the report contains only strings and numbers.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report must be serializable")
+    }
+
+    fn visit(&mut self, path: &mut Vec<String>, items: &[Item], structs: &mut Vec<StructStats>) {
+        let mut module = ModuleStats {
+            path: path.join("::"),
+            ..ModuleStats::default()
+        };
+        for item in items {
+            match item {
+                Item::Mod(ItemMod {
+                    ident,
+                    content: Some((_, items)),
+                    ..
+                }) => {
+                    path.push(ident.to_string());
+                    self.visit(path, items, structs);
+                    path.pop();
+                    continue;
+                }
+                Item::Struct(v) => {
+                    module.structs += 1;
+                    structs.push(StructStats {
+                        path: path
+                            .iter()
+                            .cloned()
+                            .chain([v.ident.to_string()])
+                            .collect::<Vec<_>>()
+                            .join("::"),
+                        fields: v.fields.len(),
+                        lines: item_lines(item),
+                    });
+                }
+                Item::Enum(_) => module.enums += 1,
+                Item::Type(_) => module.type_aliases += 1,
+                _ => (),
+            }
+            module.lines += item_lines(item);
+        }
+        self.structs += module.structs;
+        self.enums += module.enums;
+        self.type_aliases += module.type_aliases;
+        self.modules.push(module);
+    }
+}
+
+fn item_lines(item: &Item) -> usize {
+    prettyplease::unparse(&File {
+        shebang: None,
+        attrs: Vec::new(),
+        items: vec![item.clone()],
+    })
+    .lines()
+    .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_of_nested_modules() {
+        let file = syn::parse_file(
+            "pub type Top = bool;
+             pub mod a {
+                 pub struct Small { pub v: bool }
+                 pub mod b {
+                     pub enum E { X }
+                     pub struct Big { pub x: bool, pub y: bool, pub z: bool }
+                 }
+             }",
+        )
+        .expect("valid code");
+        let report = Report::new(&file);
+        assert_eq!(
+            (report.structs, report.enums, report.type_aliases),
+            (2, 1, 1)
+        );
+        let paths = report
+            .modules
+            .iter()
+            .map(|m| m.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["", "a", "a::b"]);
+        assert_eq!(report.largest_structs[0].path, "a::b::Big");
+        assert_eq!(report.largest_structs[0].fields, 3);
+        assert_eq!(report.modules[1].structs, 1);
+    }
+}
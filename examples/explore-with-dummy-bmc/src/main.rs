@@ -22,9 +22,9 @@ use nv_redfish_core::query::ExpandQuery;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::HttpPushUriUpdateRequest;
 use nv_redfish_core::{
-    Action, ActionError, Bmc, EntityTypeRef, Expandable, ModificationResponse,
+    Action, ActionError, AllowedMethods, Bmc, EntityTypeRef, Expandable, ModificationResponse,
     MultipartUpdateRequest, NavProperty, ODataETag, ODataId, SessionCreateResponse, Updatable,
-    UploadReader,
+    UpdateMethod, UploadReader,
 };
 use redfish_oem_contoso::redfish::contoso_turboencabulator_service::{
     ContosoTurboencabulatorServiceUpdate, TurboencabulatorMode,
@@ -495,7 +495,7 @@ impl Bmc for MockBmc {
     where
         T: Expandable,
     {
-        todo!("unimplimented")
+        Err(Error::NotSupported)
     }
 
     async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de>>(
@@ -503,7 +503,23 @@ impl Bmc for MockBmc {
         _id: &ODataId,
         _query: nv_redfish_core::FilterQuery,
     ) -> Result<Arc<T>, Error> {
-        todo!("unimplimented")
+        Err(Error::NotSupported)
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+        _query: nv_redfish_core::PageQuery,
+    ) -> Result<Arc<T>, Error> {
+        Err(Error::NotSupported)
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+        _query: nv_redfish_core::SelectQuery,
+    ) -> Result<Arc<T>, Error> {
+        Err(Error::NotSupported)
     }
 
     async fn get<T: EntityTypeRef + for<'de> Deserialize<'de>>(
@@ -517,6 +533,14 @@ impl Bmc for MockBmc {
         Ok(Arc::new(result))
     }
 
+    async fn head(&self, _id: &ODataId) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn allowed_methods(&self, _id: &ODataId) -> Option<AllowedMethods> {
+        None
+    }
+
     async fn update<
         V: Sync + Send + Serialize,
         R: Sync + Send + Sized + for<'de> Deserialize<'de>,
@@ -536,6 +560,20 @@ impl Bmc for MockBmc {
         Ok(ModificationResponse::Entity(result))
     }
 
+    async fn update_with_method<
+        V: Sync + Send + Serialize,
+        R: Sync + Send + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+        method: UpdateMethod,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        println!("BMC Update method: {method:?}");
+        self.update(id, etag, update).await
+    }
+
     async fn create<
         V: Sync + Send + Serialize,
         R: Sync + Send + Sized + for<'de> Deserialize<'de>,
@@ -580,6 +618,7 @@ impl Bmc for MockBmc {
     async fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
         &self,
         _id: &ODataId,
+        _etag: Option<&ODataETag>,
     ) -> Result<ModificationResponse<R>, Self::Error> {
         Ok(ModificationResponse::Empty)
     }
@@ -609,6 +648,17 @@ impl Bmc for MockBmc {
         Err(Error::NotSupported)
     }
 
+    async fn multipart_upload<R>(
+        &self,
+        _uri: &str,
+        _request: nv_redfish_core::MultipartRequest,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        Err(Error::NotSupported)
+    }
+
     #[cfg(feature = "update-service-deprecated")]
     async fn http_push_uri_update<U, R>(
         &self,
@@ -694,6 +744,13 @@ impl Bmc for MockBmc {
             events.into_iter().map(Ok),
         )))
     }
+
+    async fn download(
+        &self,
+        _uri: &str,
+    ) -> Result<nv_redfish_core::BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        Err(Error::NotSupported)
+    }
 }
 
 impl ActionError for Error {
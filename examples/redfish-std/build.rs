@@ -89,6 +89,11 @@ fn run() -> Result<(), Error> {
         .collect::<Result<Vec<_>, _>>()
         .expect("must be successfuly parsed"),
         rigid_array_patterns: vec![],
+        lean_property_patterns: vec![],
+        strict_references: false,
+        reference_cache: None,
+        download_references: false,
+        stats: None,
     })?;
     Ok(())
 }
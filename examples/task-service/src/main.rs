@@ -27,7 +27,9 @@ use nv_redfish::bmc_http::CacheSettings;
 use nv_redfish::bmc_http::HttpBmc;
 use nv_redfish::core::AsyncTask;
 use nv_redfish::core::ODataId;
+use nv_redfish::retry::RetryPolicy;
 use nv_redfish::ServiceRoot;
+use nv_redfish::Sleep;
 use url::Url;
 
 #[derive(Debug, Parser)]
@@ -68,7 +70,12 @@ async fn main() -> Result<(), Box<dyn StdError>> {
         CacheSettings::default(),
     ));
 
-    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    // Busy BMCs answer task polls with 503 and `Retry-After`; the
+    // transient policy waits as requested instead of failing the poll.
+    let root = ServiceRoot::builder(Arc::clone(&bmc))
+        .retry(RetryPolicy::transient(), Sleep::new(tokio::time::sleep))
+        .build()
+        .await?;
     let task_service = root
         .task_service()
         .await?
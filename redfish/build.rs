@@ -92,6 +92,15 @@ fn run() -> Result<(), Box<dyn StdError>> {
             .cloned()
             .collect(),
         rigid_array_patterns: features.rigid_array_patterns.into_iter().cloned().collect(),
+        lean_property_patterns: features
+            .lean_property_patterns
+            .into_iter()
+            .cloned()
+            .collect(),
+        strict_references: false,
+        reference_cache: None,
+        download_references: false,
+        stats: None,
     })?;
 
     // ================================================================================
@@ -140,6 +149,11 @@ fn run() -> Result<(), Box<dyn StdError>> {
             resolve_csdls,
             entity_type_patterns: patterns.into_iter().cloned().collect(),
             rigid_array_patterns: vec![],
+            strict_references: false,
+            reference_cache: None,
+            download_references: false,
+            external_module: None,
+            stats: None,
         })?;
     }
     Ok(())
@@ -26,7 +26,7 @@
 //!     honoring `min_slot` when configured.
 //!
 //! Configuration:
-//! - `account`: controls read patching via `read_patch_fn`.
+//! - `account`: configuration of `Account` objects.
 //! - `slot_defined_user_accounts`:
 //!   - `min_slot`: minimum numeric slot id considered.
 //!   - `hide_disabled`: omit disabled accounts from `all_accounts_data`.
@@ -37,14 +37,23 @@
 //!   `/redfish/v1/AccountService/Accounts`).
 //! - Collection reads use `$expand` with depth 1 to materialize
 //!   members when available.
+//! - Read and write patches of accounts are resolved when the
+//!   collection is read.
 
 use crate::account::Account;
 use crate::account::AccountConfig;
+use crate::account::AccountCreateBuilder;
+use crate::account::AccountPatches;
+use crate::account::AccountPolicy;
 use crate::account::ManagerAccountCreate;
 use crate::account::ManagerAccountUpdate;
+use crate::collection::PartialMembers;
+use crate::collection::RedfishCollection;
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::CreateWithPatch;
 use crate::patch_support::ReadPatchFn;
+use crate::patch_support::WritePatchFn;
+use crate::resource::ResourceIdRef;
 use crate::schema::manager_account::ManagerAccount;
 use crate::schema::manager_account_collection::ManagerAccountCollection;
 use crate::schema::resource::ResourceCollection;
@@ -83,6 +92,8 @@ pub struct Config {
     pub account: AccountConfig,
     /// Configuration for slot-defined user accounts.
     pub slot_defined_user_accounts: Option<SlotDefinedConfig>,
+    /// Account policy of the account service.
+    pub policy: AccountPolicy,
 }
 
 /// Account collection.
@@ -90,6 +101,7 @@ pub struct Config {
 /// Provides functions to access collection members.
 pub struct AccountCollection<B: Bmc> {
     config: Config,
+    patches: AccountPatches,
     bmc: NvBmc<B>,
     collection: Arc<ManagerAccountCollection>,
 }
@@ -112,7 +124,10 @@ impl<B: Bmc> CreateWithPatch<ManagerAccountCollection, ManagerAccount, ManagerAc
         self.collection.as_ref()
     }
     fn patch(&self) -> Option<&ReadPatchFn> {
-        self.config.account.read_patch_fn.as_ref()
+        self.patches.read_patch_fn.as_ref()
+    }
+    fn write_patch(&self) -> Option<&WritePatchFn> {
+        self.patches.write_patch_fn.as_ref()
     }
     fn bmc(&self) -> &B {
         self.bmc.as_ref()
@@ -125,15 +140,13 @@ impl<B: Bmc> AccountCollection<B> {
         collection_ref: &NavProperty<ManagerAccountCollection>,
         config: Config,
     ) -> Result<Self, Error<B>> {
-        let collection = Self::expand_collection(
-            &bmc,
-            collection_ref,
-            config.account.read_patch_fn.as_ref(),
-            None,
-        )
-        .await?;
+        let patches = AccountPatches::resolve(&bmc, collection_ref.id());
+        let collection =
+            Self::expand_collection(&bmc, collection_ref, patches.read_patch_fn.as_ref(), None)
+                .await?;
         Ok(Self {
             config,
+            patches,
             bmc,
             collection,
         })
@@ -147,6 +160,22 @@ impl<B: Bmc> AccountCollection<B> {
         self.collection.as_ref().odata_id()
     }
 
+    /// Start building a new account. The builder validates the
+    /// account against the policy of the account service before
+    /// creating it with `create_account`.
+    #[must_use]
+    pub const fn create_builder(&self) -> AccountCreateBuilder<'_, B> {
+        AccountCreateBuilder::new(self)
+    }
+
+    pub(crate) const fn policy(&self) -> &AccountPolicy {
+        &self.config.policy
+    }
+
+    pub(crate) const fn slot_defined(&self) -> bool {
+        self.config.slot_defined_user_accounts.is_some()
+    }
+
     /// Create a new account.
     ///
     /// Returns one of the following modification outcomes:
@@ -212,12 +241,22 @@ impl<B: Bmc> AccountCollection<B> {
             // No available slot found
             Err(Error::AccountSlotNotAvailable)
         } else {
-            Ok(self
+            let response = self
                 .create_with_patch(&create)
                 .await?
                 .map_entity(|account| {
                     Account::from_data(self.bmc.clone(), account, self.config.account.clone())
-                }))
+                });
+            self.bmc
+                .follow_create_task(response, |created| async move {
+                    Account::new(
+                        &self.bmc,
+                        &NavProperty::new_reference(created),
+                        &self.config.account,
+                    )
+                    .await
+                })
+                .await
         }
     }
 
@@ -249,4 +288,75 @@ impl<B: Bmc> AccountCollection<B> {
         }
         Ok(result)
     }
+
+    /// Retrieve account data like [`Self::all_accounts_data`], but
+    /// don't stop on accounts that cannot be retrieved: their errors
+    /// are returned along with the retrieved accounts.
+    pub async fn try_all_accounts_data(&self) -> PartialMembers<Account<B>, B> {
+        let hide_disabled = self
+            .config
+            .slot_defined_user_accounts
+            .as_ref()
+            .is_some_and(|cfg| cfg.hide_disabled);
+        let mut result = PartialMembers {
+            members: Vec::with_capacity(self.collection.members.len()),
+            errors: Vec::new(),
+        };
+        for m in &self.collection.members {
+            match Account::new(&self.bmc, m, &self.config.account).await {
+                Ok(account) => {
+                    if !hide_disabled || account.is_enabled() {
+                        result.members.push(account);
+                    }
+                }
+                Err(err) => result.errors.push((m.id().clone(), err)),
+            }
+        }
+        result
+    }
+}
+
+impl<B: Bmc> RedfishCollection<B> for AccountCollection<B> {
+    type MemberSchema = ManagerAccount;
+    type Member = Account<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<ManagerAccount>] {
+        &self.collection.members
+    }
+
+    async fn member(&self, nav: &NavProperty<ManagerAccount>) -> Result<Account<B>, Error<B>> {
+        Account::new(&self.bmc, nav, &self.config.account).await
+    }
+
+    async fn members(&self) -> Result<Vec<Account<B>>, Error<B>> {
+        self.all_accounts_data().await
+    }
+
+    async fn try_members(&self) -> PartialMembers<Account<B>, B> {
+        self.try_all_accounts_data().await
+    }
+
+    async fn get(&self, id: ResourceIdRef<'_>) -> Result<Option<Account<B>>, Error<B>> {
+        let Some(nav) = self
+            .collection
+            .members
+            .iter()
+            .find(|nav| nav.id().last_segment() == Some(*id.inner()))
+        else {
+            return Ok(None);
+        };
+        let account = self.member(nav).await?;
+        // Hidden disabled slots are reported as missing accounts, the
+        // same way as `all_accounts_data` skips them.
+        let hidden = self
+            .config
+            .slot_defined_user_accounts
+            .as_ref()
+            .is_some_and(|cfg| cfg.hide_disabled && !account.is_enabled());
+        Ok((!hidden).then_some(account))
+    }
 }
@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builder of account creation requests.
+//!
+//! `AccountCreateBuilder` collects properties of a new account and
+//! validates them before any request is sent:
+//! - user name, password and role are required;
+//! - password length must respect `MinPasswordLength` and
+//!   `MaxPasswordLength` of the account service;
+//! - in slot-defined mode a slot is always enabled when it is taken,
+//!   so disabled accounts cannot be created.
+
+use crate::account::Account;
+use crate::account::AccountCollection;
+use crate::account::AccountTypes;
+use crate::account::ManagerAccountCreate;
+use crate::account::RoleId;
+use crate::Error;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::ModificationResponse;
+
+/// Account policy of the account service.
+#[derive(Clone, Default)]
+pub(crate) struct AccountPolicy {
+    /// Minimum length of the password.
+    pub min_password_length: Option<usize>,
+    /// Maximum length of the password.
+    pub max_password_length: Option<usize>,
+}
+
+/// Builder of a new account. Created by
+/// [`AccountCollection::create_builder`].
+///
+/// # Example
+///
+/// ```ignore
+/// let account = accounts
+///     .create_builder()
+///     .user_name("operator")
+///     .password("secret-password")
+///     .role(RoleId::Operator)
+///     .create()
+///     .await?;
+/// ```
+pub struct AccountCreateBuilder<'a, B: Bmc> {
+    collection: &'a AccountCollection<B>,
+    user_name: Option<String>,
+    password: Option<String>,
+    role_id: Option<RoleId>,
+    enabled: Option<bool>,
+    account_types: Option<Vec<AccountTypes>>,
+}
+
+impl<'a, B: Bmc> AccountCreateBuilder<'a, B> {
+    pub(crate) const fn new(collection: &'a AccountCollection<B>) -> Self {
+        Self {
+            collection,
+            user_name: None,
+            password: None,
+            role_id: None,
+            enabled: None,
+            account_types: None,
+        }
+    }
+
+    /// User name of the account. Required.
+    #[must_use]
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.user_name = Some(user_name.into());
+        self
+    }
+
+    /// Password of the account. Required.
+    #[must_use]
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Role of the account. Required.
+    #[must_use]
+    pub fn role(mut self, role_id: impl Into<RoleId>) -> Self {
+        self.role_id = Some(role_id.into());
+        self
+    }
+
+    /// Whether the account is enabled. Service default is used if
+    /// not set.
+    #[must_use]
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Account types of the account. Service default (`Redfish`) is
+    /// used if not set.
+    #[must_use]
+    pub fn account_types(mut self, account_types: Vec<AccountTypes>) -> Self {
+        self.account_types = Some(account_types);
+        self
+    }
+
+    /// Validate the account and build the creation request without
+    /// sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidAccount` if a required property is not
+    /// set or if the account violates the policy of the account
+    /// service or the slot-defined account mode.
+    pub fn build(self) -> Result<ManagerAccountCreate, Error<B>> {
+        let user_name = required(self.user_name, "user name")?;
+        let password = required(self.password, "password")?;
+        let role_id = required(self.role_id, "role")?;
+        if user_name.is_empty() {
+            return Err(Error::InvalidAccount("user name is empty".into()));
+        }
+        let policy = self.collection.policy();
+        let length = password.chars().count();
+        if let Some(min) = policy.min_password_length.filter(|min| length < *min) {
+            return Err(Error::InvalidAccount(format!(
+                "password is shorter than {min} characters"
+            )));
+        }
+        if let Some(max) = policy.max_password_length.filter(|max| length > *max) {
+            return Err(Error::InvalidAccount(format!(
+                "password is longer than {max} characters"
+            )));
+        }
+        if self.collection.slot_defined() && self.enabled == Some(false) {
+            return Err(Error::InvalidAccount(
+                "disabled accounts cannot be created in slot-defined mode".into(),
+            ));
+        }
+        let mut create = ManagerAccountCreate::builder(password, user_name, role_id.into());
+        if let Some(enabled) = self.enabled {
+            create = create.with_enabled(enabled);
+        }
+        if let Some(account_types) = self.account_types {
+            create = create.with_account_types(account_types);
+        }
+        Ok(create.build())
+    }
+
+    /// Validate the account and create it.
+    ///
+    /// Returns the same outcomes as
+    /// [`AccountCollection::create_account`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidAccount` if validation fails or an
+    /// error if creating the account fails.
+    pub async fn create(self) -> Result<ModificationResponse<Account<B>>, Error<B>> {
+        let collection = self.collection;
+        let create = self.build()?;
+        collection.create_account(create).await
+    }
+}
+
+fn required<T, B: Bmc>(value: Option<T>, name: &str) -> Result<T, Error<B>> {
+    value.ok_or_else(|| Error::InvalidAccount(format!("{name} is required")))
+}
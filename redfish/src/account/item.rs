@@ -23,42 +23,76 @@
 //!   when configured
 //!
 //! Configuration:
-//! - `Config::read_patch_fn`: apply read-time JSON patches for vendor
-//!   compatibility
 //! - `Config::disable_account_on_delete`: make `delete()` disable the account
 //!   rather than remove it
 //!
+//! Patches:
+//! - `Patches::read_patch_fn`: apply read-time JSON patches for vendor
+//!   compatibility
+//! - `Patches::write_patch_fn`: apply JSON patches to update request bodies
+//!   for vendor compatibility
+//!
+//! Patches are resolved for the account when it is read, so quirks
+//! scoped to a subtree apply.
+//!
 //! Note: `Account` objects are created by higher-level APIs (e.g.
 //! `AccountCollection`) and do not create accounts on the BMC by themselves.
 //! Use the collection to create new accounts.
 
 use crate::account::ManagerAccountUpdate;
+use crate::account::RoleId;
 use crate::patch_support::Payload;
 use crate::patch_support::ReadPatchFn;
 use crate::patch_support::UpdateWithPatch;
+use crate::patch_support::WritePatchFn;
 use crate::schema::manager_account::ManagerAccount;
+use crate::DeletableResource;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
 use crate::ResourceSchema;
+use crate::SupportedMethods;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
 use std::convert::identity;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Config {
-    /// Function to patch input JSON when reading account structures.
-    pub read_patch_fn: Option<ReadPatchFn>,
     /// If true, deletion disables the account instead of removing it.
     pub disable_account_on_delete: bool,
 }
 
+/// Patches of `ManagerAccount` payloads required by quirks.
+#[derive(Clone)]
+pub struct Patches {
+    /// Function to patch input JSON when reading account structures.
+    pub read_patch_fn: Option<ReadPatchFn>,
+    /// Function to patch output JSON when creating or updating
+    /// accounts.
+    pub write_patch_fn: Option<WritePatchFn>,
+}
+
+impl Patches {
+    /// Patches that currently apply to resource `id`.
+    pub fn resolve<B: Bmc>(bmc: &NvBmc<B>, id: &ODataId) -> Self {
+        let patches = bmc.patches_for(id);
+        Self {
+            read_patch_fn: patches.read::<ManagerAccount>(),
+            write_patch_fn: patches.write::<ManagerAccount>(),
+        }
+    }
+}
+
 /// Represents a Redfish `ManagerAccount`.
 pub struct Account<B: Bmc> {
     config: Config,
+    patches: Patches,
     bmc: NvBmc<B>,
     data: Arc<ManagerAccount>,
 }
@@ -68,10 +102,13 @@ impl<B: Bmc> UpdateWithPatch<ManagerAccount, ManagerAccountUpdate, B> for Accoun
         self.data.as_ref()
     }
     fn patch(&self) -> Option<&ReadPatchFn> {
-        self.config.read_patch_fn.as_ref()
+        self.patches.read_patch_fn.as_ref()
+    }
+    fn write_patch(&self) -> Option<&WritePatchFn> {
+        self.patches.write_patch_fn.as_ref()
     }
-    fn bmc(&self) -> &B {
-        self.bmc.as_ref()
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
     }
 }
 
@@ -83,7 +120,8 @@ impl<B: Bmc> Account<B> {
         nav: &NavProperty<ManagerAccount>,
         config: &Config,
     ) -> Result<Self, Error<B>> {
-        if let Some(read_patch_fn) = &config.read_patch_fn {
+        let patches = Patches::resolve(bmc, nav.id());
+        if let Some(read_patch_fn) = &patches.read_patch_fn {
             Payload::get(bmc.as_ref(), nav, read_patch_fn.as_ref()).await
         } else {
             nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
@@ -92,15 +130,18 @@ impl<B: Bmc> Account<B> {
             bmc: bmc.clone(),
             data,
             config: config.clone(),
+            patches,
         })
     }
 
     /// Create from existing data.
     pub(crate) fn from_data(bmc: NvBmc<B>, data: ManagerAccount, config: Config) -> Self {
+        let patches = Patches::resolve(&bmc, data.odata_id());
         Self {
             bmc,
             data: Arc::new(data),
             config,
+            patches,
         }
     }
 
@@ -116,6 +157,12 @@ impl<B: Bmc> Account<B> {
         self.data.enabled.is_none_or(identity)
     }
 
+    /// Role of the account.
+    #[must_use]
+    pub fn role(&self) -> Option<RoleId> {
+        self.data.role_id.as_deref().map(RoleId::from)
+    }
+
     /// Update the account.
     ///
     /// Returns one of the following modification outcomes:
@@ -133,10 +180,16 @@ impl<B: Bmc> Account<B> {
         &self,
         update: &ManagerAccountUpdate,
     ) -> Result<ModificationResponse<Self>, Error<B>> {
-        Ok(self
+        let response = self
             .update_with_patch(update)
             .await?
-            .map_entity(|ma| Self::from_data(self.bmc.clone(), ma, self.config.clone())))
+            .map_entity(|ma| Self::from_data(self.bmc.clone(), ma, self.config.clone()));
+        self.bmc
+            .follow_task(response, || async {
+                let nav = NavProperty::new_reference(self.data.odata_id().clone());
+                Self::new(&self.bmc, &nav, &self.config).await
+            })
+            .await
     }
 
     /// Update the account's password.
@@ -164,6 +217,28 @@ impl<B: Bmc> Account<B> {
         .await
     }
 
+    /// Update the account's role.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the updated account.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server responds with an error or if the
+    /// response cannot be parsed.
+    pub async fn update_role(&self, role: RoleId) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.update(
+            &ManagerAccountUpdate::builder()
+                .with_role_id(role.into())
+                .build(),
+        )
+        .await
+    }
+
     /// Update the account's user name.
     ///
     /// Returns one of the following modification outcomes:
@@ -202,18 +277,15 @@ impl<B: Bmc> Account<B> {
     ///
     /// # Errors
     ///
-    /// Returns an error if deletion fails.
+    /// Returns an error if deletion fails or if the service doesn't allow
+    /// deletion of the account.
     pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
         if self.config.disable_account_on_delete {
             self.update(&ManagerAccountUpdate::builder().with_enabled(false).build())
                 .await
         } else {
             self.bmc
-                .as_ref()
-                .delete::<NavProperty<ManagerAccount>>(self.data.odata_id())
-                .await
-                .map_err(Error::Bmc)?
-                .try_map_entity_async(|nav| async move {
+                .delete_entity(self.data.as_ref(), self.data.odata_id(), |nav| async move {
                     Self::new(&self.bmc, &nav, &self.config).await
                 })
                 .await
@@ -221,8 +293,31 @@ impl<B: Bmc> Account<B> {
     }
 }
 
+impl<B: Bmc> DeletableResource<B> for Account<B> {
+    async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        Self::delete(self).await
+    }
+}
+
+impl<B: Bmc> SupportedMethods<B> for Account<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.data.odata_id())
+    }
+}
+
 impl<B: Bmc> Resource for Account<B> {
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> RefreshableResource<B> for Account<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+            &self.config,
+        )
+        .await
+    }
+}
@@ -22,24 +22,36 @@
 //! - `AccountService`: entry point to manage accounts
 //! - `AccountCollection`: access and create `ManagerAccount` members
 //! - `Account`: operate on an individual `ManagerAccount`
+//! - `Role`: operate on an individual `Role`
 //!
 //! Vendor compatibility
 //! - Some implementations omit fields marked as `Redfish.Required`.
 //! - This crate can apply read/response patches (see `patch_support`) to keep
-//!   behavior compatible across vendors (for example, defaulting `AccountTypes`).
+//!   behavior compatible across vendors (for example, defaulting `AccountTypes`
+//!   or `Name` of the account service).
 //!
 
 /// Collection of accounts.
 mod collection;
+/// Builder of new accounts.
+mod create;
 /// Account inside account service.
 mod item;
+/// Standard roles and privileges.
+mod role;
+/// Role inside account service.
+mod role_item;
 
-use crate::patch_support::JsonValue;
-use crate::patch_support::ReadPatchFn;
+use crate::bmc_quirks::BmcQuirks;
+use crate::patch_support::PatchRegistry;
+use crate::patch_support::Payload;
+use crate::patch_support::PropertyDefaults;
 use crate::schema::account_service::AccountService as SchemaAccountService;
+use crate::schema::manager_account::ManagerAccount;
 use crate::Error;
 use crate::NvBmc;
 use crate::ServiceRoot;
+use futures_util::TryStreamExt as _;
 use nv_redfish_core::Bmc;
 use std::sync::Arc;
 
@@ -51,13 +63,50 @@ pub use crate::schema::manager_account::ManagerAccountCreate;
 pub use crate::schema::manager_account::ManagerAccountUpdate;
 #[doc(inline)]
 pub use item::Account;
+#[doc(inline)]
+pub use role::Privilege;
+#[doc(inline)]
+pub use role::RoleId;
+#[doc(inline)]
+pub use role_item::Role;
 
 #[doc(inline)]
 pub use collection::AccountCollection;
 #[doc(inline)]
 pub(crate) use collection::SlotDefinedConfig;
 #[doc(inline)]
+pub use create::AccountCreateBuilder;
+#[doc(inline)]
+pub(crate) use create::AccountPolicy;
+#[doc(inline)]
 pub(crate) use item::Config as AccountConfig;
+#[doc(inline)]
+pub(crate) use item::Patches as AccountPatches;
+
+/// Register patches of account resources required by platform
+/// quirks.
+pub(crate) fn register_patches(quirks: &BmcQuirks, patches: &mut PatchRegistry) {
+    let mut defaults = PropertyDefaults::new();
+    if quirks.bug_no_account_type_in_accounts() {
+        // `AccountTypes` is marked as `Redfish.Required`, but some
+        // systems ignore this requirement.
+        //
+        // Note quote from schema: "if this property is not provided by
+        // the client, the default value shall be an array that contains
+        // the value `Redfish`".
+        defaults = defaults.with("AccountTypes", vec!["Redfish"]);
+    }
+    if let Some(patch_fn) = defaults.into_patch_fn() {
+        patches.add_read::<ManagerAccount>("account_type_defaults", patch_fn);
+    }
+    let mut service_defaults = PropertyDefaults::new();
+    if quirks.bug_missing_account_service_name_field() {
+        service_defaults = service_defaults.with("Name", "Unnamed account service");
+    }
+    if let Some(patch_fn) = service_defaults.into_patch_fn() {
+        patches.add_read::<SchemaAccountService>("account_service_defaults", patch_fn);
+    }
+}
 
 /// Account service. Provides the ability to manage accounts via Redfish.
 pub struct AccountService<B: Bmc> {
@@ -76,29 +125,31 @@ impl<B: Bmc> AccountService<B> {
         let Some(service_nav) = root.root.account_service.as_ref() else {
             return Ok(None);
         };
-        let service = service_nav.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
-
-        let mut patches = Vec::new();
-        if bmc.quirks.bug_no_account_type_in_accounts() {
-            patches.push(append_default_account_type);
-        }
-        let account_read_patch_fn = if patches.is_empty() {
-            None
+        let service_patch_fn = bmc
+            .patches_for(service_nav.id())
+            .read::<SchemaAccountService>();
+        let service = if let Some(read_patch_fn) = service_patch_fn {
+            Payload::get(bmc.as_ref(), service_nav, read_patch_fn.as_ref()).await
         } else {
-            let account_read_patch_fn: ReadPatchFn =
-                Arc::new(move |v| patches.iter().fold(v, |acc, f| f(acc)));
-            Some(account_read_patch_fn)
-        };
-        let slot_defined_user_accounts = bmc.quirks.slot_defined_user_accounts();
+            service_nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
+        }?;
+        let slot_defined_user_accounts = bmc.quirks().slot_defined_user_accounts();
         Ok(Some(Self {
             collection_config: collection::Config {
                 account: AccountConfig {
-                    read_patch_fn: account_read_patch_fn,
                     disable_account_on_delete: slot_defined_user_accounts
                         .as_ref()
                         .is_some_and(|cfg| cfg.disable_account_on_delete),
                 },
                 slot_defined_user_accounts,
+                policy: AccountPolicy {
+                    min_password_length: service
+                        .min_password_length
+                        .and_then(|v| usize::try_from(v).ok()),
+                    max_password_length: service
+                        .max_password_length
+                        .and_then(|v| usize::try_from(v).ok()),
+                },
             },
             service,
             bmc: bmc.clone(),
@@ -134,20 +185,27 @@ impl<B: Bmc> AccountService<B> {
             Ok(None)
         }
     }
-}
 
-// `AccountTypes` is marked as `Redfish.Required`, but some systems
-// ignore this requirement. The account service replaces its value with
-// a reasonable default (see below).
-//
-// Note quote from schema: "if this property is not provided by the client, the default value
-// shall be an array that contains the value `Redfish`".
-fn append_default_account_type(v: JsonValue) -> JsonValue {
-    if let JsonValue::Object(mut obj) = v {
-        obj.entry("AccountTypes")
-            .or_insert(JsonValue::Array(vec![JsonValue::String("Redfish".into())]));
-        JsonValue::Object(obj)
-    } else {
-        v
+    /// Get `Vec<Role>` of roles defined by this account service.
+    ///
+    /// Fetches the role collection and returns a list of [`Role`]
+    /// handles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving the collection or its members
+    /// fails.
+    pub async fn roles(&self) -> Result<Option<Vec<Role<B>>>, Error<B>> {
+        if let Some(collection_ref) = &self.service.roles {
+            let collection = self.bmc.expand_property(collection_ref).await?;
+
+            self.bmc
+                .members_stream(&collection.members, |m| Role::new(&self.bmc, m))
+                .try_collect()
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
     }
 }
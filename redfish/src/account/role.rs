@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Standard roles and privileges.
+//!
+//! Redfish identifies roles and privileges by strings. `RoleId` and
+//! `Privilege` provide the values defined by the Redfish
+//! specification and keep any other (custom or OEM) value in the
+//! `Other` variant, so values returned by the service round-trip
+//! unchanged.
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// Identifier of a role (`RoleId` of a `ManagerAccount`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum RoleId {
+    /// Administrator: all standard privileges.
+    Administrator,
+    /// Operator: configures components and own account.
+    Operator,
+    /// Read-only: logs in and changes own password.
+    ReadOnly,
+    /// Custom or OEM role.
+    Other(String),
+}
+
+impl RoleId {
+    /// String value of the role as used by Redfish.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Administrator => "Administrator",
+            Self::Operator => "Operator",
+            Self::ReadOnly => "ReadOnly",
+            Self::Other(v) => v,
+        }
+    }
+
+    /// Privileges assigned to the standard role by the Redfish
+    /// specification. Returns `None` for custom roles whose
+    /// privileges are defined by the service.
+    #[must_use]
+    pub const fn standard_privileges(&self) -> Option<&'static [Privilege]> {
+        match self {
+            Self::Administrator => Some(&[
+                Privilege::Login,
+                Privilege::ConfigureManager,
+                Privilege::ConfigureUsers,
+                Privilege::ConfigureSelf,
+                Privilege::ConfigureComponents,
+            ]),
+            Self::Operator => Some(&[
+                Privilege::Login,
+                Privilege::ConfigureSelf,
+                Privilege::ConfigureComponents,
+            ]),
+            Self::ReadOnly => Some(&[Privilege::Login, Privilege::ConfigureSelf]),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<&str> for RoleId {
+    fn from(v: &str) -> Self {
+        match v {
+            "Administrator" => Self::Administrator,
+            "Operator" => Self::Operator,
+            "ReadOnly" => Self::ReadOnly,
+            _ => Self::Other(v.into()),
+        }
+    }
+}
+
+impl From<String> for RoleId {
+    fn from(v: String) -> Self {
+        match Self::from(v.as_str()) {
+            Self::Other(_) => Self::Other(v),
+            role => role,
+        }
+    }
+}
+
+impl From<RoleId> for String {
+    fn from(v: RoleId) -> Self {
+        match v {
+            RoleId::Other(v) => v,
+            role => role.as_str().into(),
+        }
+    }
+}
+
+impl fmt::Display for RoleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Privilege of a role.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Privilege {
+    /// Can log in to the service and read resources.
+    Login,
+    /// Can configure managers.
+    ConfigureManager,
+    /// Can configure users and their accounts.
+    ConfigureUsers,
+    /// Can change the password of own account.
+    ConfigureSelf,
+    /// Can configure components managed by this service.
+    ConfigureComponents,
+    /// Authentication is not required.
+    NoAuth,
+    /// Custom or OEM privilege.
+    Other(String),
+}
+
+impl Privilege {
+    /// String value of the privilege as used by Redfish.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Login => "Login",
+            Self::ConfigureManager => "ConfigureManager",
+            Self::ConfigureUsers => "ConfigureUsers",
+            Self::ConfigureSelf => "ConfigureSelf",
+            Self::ConfigureComponents => "ConfigureComponents",
+            Self::NoAuth => "NoAuth",
+            Self::Other(v) => v,
+        }
+    }
+}
+
+impl From<&str> for Privilege {
+    fn from(v: &str) -> Self {
+        match v {
+            "Login" => Self::Login,
+            "ConfigureManager" => Self::ConfigureManager,
+            "ConfigureUsers" => Self::ConfigureUsers,
+            "ConfigureSelf" => Self::ConfigureSelf,
+            "ConfigureComponents" => Self::ConfigureComponents,
+            "NoAuth" => Self::NoAuth,
+            _ => Self::Other(v.into()),
+        }
+    }
+}
+
+impl From<String> for Privilege {
+    fn from(v: String) -> Self {
+        match Self::from(v.as_str()) {
+            Self::Other(_) => Self::Other(v),
+            privilege => privilege,
+        }
+    }
+}
+
+impl From<Privilege> for String {
+    fn from(v: Privilege) -> Self {
+        match v {
+            Privilege::Other(v) => v,
+            privilege => privilege.as_str().into(),
+        }
+    }
+}
+
+impl fmt::Display for Privilege {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
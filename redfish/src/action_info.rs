@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Action parameter descriptors
+//!
+//! Services may annotate an action with `@Redfish.ActionInfo` that
+//! references an `ActionInfo` resource. This resource describes
+//! parameters supported by the service for the action: whether a
+//! parameter is required, its type and allowable values.
+
+use crate::core::EntityTypeRef;
+use crate::core::ODataETag;
+use crate::core::ODataId;
+use serde::Deserialize;
+
+/// Data type of the action parameter.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterType {
+    /// A boolean.
+    Boolean,
+    /// A number.
+    Number,
+    /// An array of numbers.
+    NumberArray,
+    /// A string.
+    String,
+    /// An array of strings.
+    StringArray,
+    /// An embedded JSON object.
+    Object,
+    /// An array of JSON objects.
+    ObjectArray,
+    /// Type that is not known by this crate.
+    #[serde(other)]
+    Other,
+}
+
+/// Descriptor of the action parameter supported by the service.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ActionParameter {
+    /// Name of the parameter.
+    pub name: String,
+    /// Indicates whether the parameter is required.
+    pub required: Option<bool>,
+    /// Data type of the parameter.
+    pub data_type: Option<ParameterType>,
+    /// Data type of the object if the parameter is an object.
+    pub object_data_type: Option<String>,
+    /// Allowable values for the parameter.
+    pub allowable_values: Option<Vec<String>>,
+    /// Allowable numeric values or ranges for the parameter.
+    pub allowable_numbers: Option<Vec<String>>,
+    /// Regular expression that describes allowable values for the
+    /// parameter.
+    pub allowable_pattern: Option<String>,
+    /// Minimum value of the numeric parameter.
+    pub minimum_value: Option<f64>,
+    /// Maximum value of the numeric parameter.
+    pub maximum_value: Option<f64>,
+    /// Minimum number of array items.
+    pub array_size_minimum: Option<u64>,
+    /// Maximum number of array items.
+    pub array_size_maximum: Option<u64>,
+}
+
+impl ActionParameter {
+    /// Returns `true` if the parameter must be provided.
+    #[must_use]
+    pub fn is_required(&self) -> bool {
+        self.required.unwrap_or(false)
+    }
+
+    /// Returns `true` if `value` is allowed for the parameter. Any
+    /// value is allowed if the service doesn't list allowable
+    /// values.
+    #[must_use]
+    pub fn allows_value(&self, value: &str) -> bool {
+        self.allowable_values
+            .as_ref()
+            .is_none_or(|values| values.iter().any(|v| v == value))
+    }
+}
+
+/// `ActionInfo` resource. Only parameters are parsed.
+#[derive(Deserialize)]
+pub(crate) struct ActionInfo {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+    #[serde(rename = "@odata.etag")]
+    etag: Option<ODataETag>,
+    #[serde(rename = "Parameters")]
+    parameters: Option<Vec<ActionParameter>>,
+}
+
+impl ActionInfo {
+    pub(crate) fn parameters(&self) -> Vec<ActionParameter> {
+        self.parameters.clone().unwrap_or_default()
+    }
+}
+
+impl EntityTypeRef for ActionInfo {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        self.etag.as_ref()
+    }
+}
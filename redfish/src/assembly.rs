@@ -25,6 +25,7 @@ use crate::schema::assembly::Assembly as AssemblySchema;
 use crate::schema::assembly::AssemblyData as AssemblyDataSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
@@ -99,6 +100,16 @@ impl<B: Bmc> Resource for Assembly<B> {
     }
 }
 
+impl<B: Bmc> RefreshableResource<B> for Assembly<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
+
 /// Assembly data.
 pub struct AssemblyData<B: Bmc> {
     data: Arc<AssemblyDataSchema>,
@@ -16,33 +16,145 @@
 //! BMC implementaion that takes in account protocol features.  That
 //! is built on top of core BMC.
 
+use crate::action_info::ActionInfo;
+use crate::action_info::ActionParameter;
 use crate::bmc_quirks::BmcQuirks;
+#[cfg(feature = "computer-systems")]
+use crate::bmc_quirks::SettingsApplyStrategy;
+use crate::collection::PartialMembers;
+#[cfg(feature = "patch-collection")]
+use crate::patch_support::FilterFn;
+#[cfg(any(feature = "accounts", feature = "patch-collection"))]
+use crate::patch_support::PatchRegistry;
 use crate::protocol_features::ExpandQueryFeatures;
+#[cfg(feature = "task-service")]
+use crate::task_service::TaskFollowing;
+use crate::Error;
 use crate::ProtocolFeatures;
-use nv_redfish_core::Bmc;
-use std::sync::Arc;
-
+use futures_core::Stream;
+use futures_util::stream;
+use futures_util::FutureExt as _;
+use futures_util::StreamExt as _;
 #[cfg(feature = "impl-nv-bmc-expand")]
-use crate::Error;
+use futures_util::TryStreamExt as _;
 #[cfg(feature = "impl-nv-bmc-expand")]
 use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::Bmc;
+#[cfg(any(
+    feature = "accounts",
+    feature = "certificates",
+    feature = "event-service",
+    feature = "session-service",
+    feature = "telemetry-service",
+    all(feature = "computer-systems", feature = "storages")
+))]
+use nv_redfish_core::Deletable;
+use nv_redfish_core::EntityTypeRef;
 #[cfg(feature = "impl-nv-bmc-expand")]
 use nv_redfish_core::Expandable;
-#[cfg(feature = "impl-nv-bmc-expand")]
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+#[cfg(feature = "computer-systems")]
+use nv_redfish_core::RedfishSettings;
+use nv_redfish_core::SelectQuery;
+#[cfg(feature = "tracing")]
+use nv_redfish_core::UpdateMethod;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+use std::sync::RwLockReadGuard;
+use std::sync::RwLockWriteGuard;
+#[cfg(feature = "tracing")]
+use tracing::Instrument as _;
 
+/// BMC with protocol features and quirks detected from the service
+/// root.
+///
+/// Conditional GET caching (`If-None-Match` / `304 Not Modified`) is
+/// done by the wrapped BMC, either by the transport (`HttpBmc`
+/// configured with `CacheSettings`) or by wrapping any BMC with
+/// [`CacheBmc`](crate::cache::CacheBmc), so that all requests issued
+/// through `Bmc` share one cache.
+/// Request and response hooks are added as a layer of the wrapped BMC
+/// (see [`Interceptors`](crate::intercept::Interceptors)).
 pub struct NvBmc<B: Bmc> {
     bmc: Arc<B>,
     protocol_features: Arc<ProtocolFeatures>,
-    pub(crate) quirks: Arc<BmcQuirks>,
+    quirk_state: Arc<RwLock<QuirkState>>,
+    member_concurrency: usize,
+    client_side_expand: bool,
+    #[cfg(feature = "task-service")]
+    task_following: Option<Arc<TaskFollowing>>,
+}
+
+/// Quirks of the platform and quirks of resource subtrees that are
+/// served by other platforms (for example, downstream BMCs behind an
+/// aggregator). The state is shared by all clones of `NvBmc`, so
+/// replacing it applies new quirks to all resource handles.
+struct QuirkState {
+    global: QuirkSet,
+    scoped: Vec<(ODataId, QuirkSet)>,
+}
+
+impl QuirkState {
+    /// Quirks of the resource `id`: quirks of the innermost subtree
+    /// that contains the resource or global quirks.
+    fn find(&self, id: &ODataId) -> &QuirkSet {
+        self.scoped
+            .iter()
+            .filter(|(subtree, _)| subtree.is_path_prefix(id))
+            .max_by_key(|(subtree, _)| subtree.to_string().len())
+            .map_or(&self.global, |(_, set)| set)
+    }
+}
+
+/// Quirks and patches required by them.
+struct QuirkSet {
+    quirks: Arc<BmcQuirks>,
+    #[cfg(any(feature = "accounts", feature = "patch-collection"))]
+    patches: Arc<PatchRegistry>,
+}
+
+impl QuirkSet {
+    fn new(quirks: BmcQuirks) -> Self {
+        Self {
+            #[cfg(any(feature = "accounts", feature = "patch-collection"))]
+            patches: quirks.patches().into(),
+            quirks: quirks.into(),
+        }
+    }
 }
 
+/// Default number of collection members that are fetched
+/// concurrently. Members are fetched one by one unless the caller
+/// opts in to concurrent fetching: many BMCs handle parallel requests
+/// poorly.
+const DEFAULT_MEMBER_CONCURRENCY: usize = 1;
+
 impl<B: Bmc> NvBmc<B> {
     pub(crate) fn new(bmc: Arc<B>, protocol_features: ProtocolFeatures, quirks: BmcQuirks) -> Self {
         Self {
             bmc,
             protocol_features: protocol_features.into(),
-            quirks: quirks.into(),
+            quirk_state: Arc::new(RwLock::new(QuirkState {
+                global: QuirkSet::new(quirks),
+                scoped: Vec::new(),
+            })),
+            member_concurrency: DEFAULT_MEMBER_CONCURRENCY,
+            client_side_expand: true,
+            #[cfg(feature = "task-service")]
+            task_following: None,
         }
     }
 
@@ -50,7 +162,107 @@ impl<B: Bmc> NvBmc<B> {
         Self {
             bmc,
             protocol_features: self.protocol_features,
-            quirks: self.quirks,
+            quirk_state: self.quirk_state,
+            member_concurrency: self.member_concurrency,
+            client_side_expand: self.client_side_expand,
+            #[cfg(feature = "task-service")]
+            task_following: self.task_following,
+        }
+    }
+
+    pub(crate) fn member_concurrency(self, member_concurrency: usize) -> Self {
+        Self {
+            member_concurrency: member_concurrency.max(1),
+            ..self
+        }
+    }
+
+    /// Maximum number of collection members that are fetched
+    /// concurrently.
+    #[cfg(feature = "inventory")]
+    pub(crate) const fn member_concurrency_limit(&self) -> usize {
+        self.member_concurrency
+    }
+
+    pub(crate) fn disable_client_side_expand(self) -> Self {
+        Self {
+            client_side_expand: false,
+            ..self
+        }
+    }
+
+    /// Expand query features of the BMC for resource `id`. Expand is
+    /// reported as unsupported if it is disabled by a quirk.
+    pub(crate) fn expand_features(&self, id: &ODataId) -> ExpandQueryFeatures {
+        let mut features = self.protocol_features.expand;
+        if self.quirks_for(id).expand_is_not_working_properly() {
+            features.expand_all = false;
+            features.no_links = false;
+        }
+        features
+    }
+
+    /// Current quirks of the platform.
+    pub(crate) fn quirks(&self) -> Arc<BmcQuirks> {
+        self.read_quirk_state().global.quirks.clone()
+    }
+
+    /// Current quirks that apply to resource `id`.
+    pub(crate) fn quirks_for(&self, id: &ODataId) -> Arc<BmcQuirks> {
+        self.read_quirk_state().find(id).quirks.clone()
+    }
+
+    /// Patches required by current quirks that apply to resource
+    /// `id`.
+    #[cfg(any(feature = "accounts", feature = "patch-collection"))]
+    pub(crate) fn patches_for(&self, id: &ODataId) -> Arc<PatchRegistry> {
+        self.read_quirk_state().find(id).patches.clone()
+    }
+
+    /// Replace quirks of the platform. New quirks are used by all
+    /// clones of this `NvBmc` for subsequent requests. Quirks of
+    /// subtrees are preserved.
+    pub(crate) fn reload_quirks(&self, quirks: BmcQuirks) {
+        self.write_quirk_state().global = QuirkSet::new(quirks);
+    }
+
+    /// Use `quirks` for resources in `subtree` instead of quirks of
+    /// the platform.
+    pub(crate) fn scope_quirks(&self, subtree: ODataId, quirks: BmcQuirks) {
+        let set = QuirkSet::new(quirks);
+        let mut state = self.write_quirk_state();
+        if let Some((_, scoped)) = state.scoped.iter_mut().find(|(id, _)| *id == subtree) {
+            *scoped = set;
+        } else {
+            state.scoped.push((subtree, set));
+        }
+    }
+
+    fn write_quirk_state(&self) -> RwLockWriteGuard<'_, QuirkState> {
+        self.quirk_state
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn read_quirk_state(&self) -> RwLockReadGuard<'_, QuirkState> {
+        self.quirk_state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns `true` if `$expand` of resource `id` is emulated on the
+    /// client side when it cannot be used: client-side expansion is
+    /// not disabled by the caller or expand of the resource is
+    /// disabled by a quirk.
+    pub(crate) fn expands_on_client_side(&self, id: &ODataId) -> bool {
+        self.client_side_expand || self.quirks_for(id).expand_is_not_working_properly()
+    }
+
+    #[cfg(feature = "task-service")]
+    pub(crate) fn follow_tasks(self, following: TaskFollowing) -> Self {
+        Self {
+            task_following: Some(following.into()),
+            ..self
         }
     }
 
@@ -61,10 +273,16 @@ impl<B: Bmc> NvBmc<B> {
                 expand: ExpandQueryFeatures {
                     expand_all: false,
                     no_links: false,
+                    levels: false,
+                    max_levels: None,
                 },
             }
             .into(),
-            quirks: self.quirks,
+            quirk_state: self.quirk_state,
+            member_concurrency: self.member_concurrency,
+            client_side_expand: self.client_side_expand,
+            #[cfg(feature = "task-service")]
+            task_following: self.task_following,
         }
     }
 
@@ -73,6 +291,475 @@ impl<B: Bmc> NvBmc<B> {
         self.bmc.as_ref()
     }
 
+    /// Fetch collection members with `f`, running at most configured
+    /// number of fetches concurrently, without stopping on the first
+    /// error: errors are collected with `OData` identifiers of members
+    /// that failed. Order of members is preserved.
+    pub fn try_fetch_members<'a, T, R, F, Fut>(
+        &self,
+        members: &'a [NavProperty<T>],
+        f: F,
+    ) -> impl Future<Output = PartialMembers<R, B>> + use<'a, B, T, R, F, Fut>
+    where
+        T: EntityTypeRef,
+        F: FnMut(&'a NavProperty<T>) -> Fut,
+        Fut: Future<Output = Result<R, Error<B>>>,
+    {
+        let fetch = self.members_stream(members, f).collect::<Vec<_>>();
+        #[cfg(feature = "tracing")]
+        let fetch = fetch.instrument(tracing::debug_span!(
+            "try_fetch_members",
+            count = members.len(),
+            concurrency = self.member_concurrency
+        ));
+        fetch.map(move |fetched| {
+            let mut result = PartialMembers {
+                members: Vec::with_capacity(members.len()),
+                errors: Vec::new(),
+            };
+            // Fetched members are in the order of the collection.
+            for (member, fetched) in members.iter().zip(fetched) {
+                match fetched {
+                    Ok(v) => result.members.push(v),
+                    Err(err) => result.errors.push((member.id().clone(), err)),
+                }
+            }
+            result
+        })
+    }
+
+    /// Stream of collection members fetched with `f` as the stream
+    /// is polled, running at most configured number of fetches
+    /// concurrently. Order of members is preserved. `members` are
+    /// references of all pages of the collection.
+    pub fn members_stream<'a, N, R, F, Fut>(
+        &self,
+        members: &'a [N],
+        f: F,
+    ) -> impl Stream<Item = Result<R, Error<B>>> + use<'a, B, N, R, F, Fut>
+    where
+        F: FnMut(&'a N) -> Fut,
+        Fut: Future<Output = Result<R, Error<B>>>,
+    {
+        stream::iter(members)
+            .map(f)
+            .buffered(self.member_concurrency)
+    }
+
+    /// Wait for the task of `response` if task following is enabled
+    /// and fetch the final entity with `fetch` once it is completed.
+    /// Other responses are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task is not completed successfully in
+    /// time or if `fetch` fails.
+    #[cfg(any(
+        feature = "accounts",
+        feature = "computer-systems",
+        feature = "controls",
+        feature = "telemetry-service",
+        feature = "update-service-deprecated"
+    ))]
+    pub async fn follow_task<T, F, Fut>(
+        &self,
+        response: ModificationResponse<T>,
+        fetch: F,
+    ) -> Result<ModificationResponse<T>, Error<B>>
+    where
+        T: MaybeSend + MaybeSync,
+        F: FnOnce() -> Fut + MaybeSend,
+        Fut: Future<Output = Result<T, Error<B>>> + MaybeSend,
+    {
+        match self.wait_task(&response).await? {
+            TaskWait::Completed(_) => fetch().await.map(ModificationResponse::Entity),
+            TaskWait::NotFollowed => Ok(response),
+        }
+    }
+
+    /// Wait for the task of create `response` if task following is
+    /// enabled and fetch the created entity with `fetch` once the
+    /// task is completed. Completed task that doesn't report the
+    /// created resource is returned as `ModificationResponse::Empty`.
+    /// Other responses are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task is not completed successfully in
+    /// time or if `fetch` fails.
+    pub async fn follow_create_task<T, F, Fut>(
+        &self,
+        response: ModificationResponse<T>,
+        fetch: F,
+    ) -> Result<ModificationResponse<T>, Error<B>>
+    where
+        T: MaybeSend + MaybeSync,
+        F: FnOnce(ODataId) -> Fut + MaybeSend,
+        Fut: Future<Output = Result<T, Error<B>>> + MaybeSend,
+    {
+        match self.wait_task(&response).await? {
+            TaskWait::Completed(Some(created)) => {
+                fetch(created).await.map(ModificationResponse::Entity)
+            }
+            TaskWait::Completed(None) => Ok(ModificationResponse::Empty),
+            TaskWait::NotFollowed => Ok(response),
+        }
+    }
+
+    /// Wait for the task of `response` if task following is enabled.
+    /// Completed task is reported as `ModificationResponse::Empty`.
+    /// It is used for operations that don't produce an entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task is not completed successfully in
+    /// time.
+    pub async fn complete_task<T: MaybeSend + MaybeSync>(
+        &self,
+        response: ModificationResponse<T>,
+    ) -> Result<ModificationResponse<T>, Error<B>> {
+        match self.wait_task(&response).await? {
+            TaskWait::Completed(_) => Ok(ModificationResponse::Empty),
+            TaskWait::NotFollowed => Ok(response),
+        }
+    }
+
+    /// Delete `entity` at `location` and wait for the returned task
+    /// if task following is enabled. Entity returned by the server is
+    /// converted with `f`. Only entities that are deletable according
+    /// to the CSDL capabilities can be deleted. DELETE is not sent if
+    /// the `Allow` header of the entity doesn't list it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::DeleteNotAllowed` if the service doesn't allow
+    /// deletion of the entity. Returns an error if deletion fails, if
+    /// `f` fails or if the task is not completed successfully in time.
+    #[cfg(any(
+        feature = "accounts",
+        feature = "certificates",
+        feature = "event-service",
+        feature = "session-service",
+        feature = "telemetry-service",
+        all(feature = "computer-systems", feature = "storages")
+    ))]
+    pub async fn delete_entity<S, T, F, Fut>(
+        &self,
+        entity: &S,
+        location: &ODataId,
+        f: F,
+    ) -> Result<ModificationResponse<T>, Error<B>>
+    where
+        S: Deletable + MaybeSend + MaybeSync,
+        T: MaybeSend + MaybeSync,
+        F: FnOnce(NavProperty<S>) -> Fut + MaybeSend,
+        Fut: Future<Output = Result<T, Error<B>>> + MaybeSend,
+    {
+        let id = entity.odata_id();
+        if self
+            .supported_methods(id)
+            .is_some_and(|allowed| !allowed.contains(AllowedMethods::DELETE))
+        {
+            return Err(Error::DeleteNotAllowed(id.clone()));
+        }
+        let response = self
+            .bmc
+            .delete::<NavProperty<S>>(location, entity.etag())
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(f)
+            .await?;
+        self.complete_task(response).await
+    }
+
+    /// Follow the task of `response` to completion if task following
+    /// is enabled.
+    // Nothing is awaited when tasks can't be followed.
+    #[cfg_attr(not(feature = "task-service"), allow(clippy::unused_async))]
+    async fn wait_task<T: MaybeSync>(
+        &self,
+        response: &ModificationResponse<T>,
+    ) -> Result<TaskWait, Error<B>> {
+        #[cfg(feature = "task-service")]
+        if let (ModificationResponse::Task(task), Some(following)) =
+            (response, &self.task_following)
+        {
+            return following.wait(self, task).await.map(TaskWait::Completed);
+        }
+        #[cfg(not(feature = "task-service"))]
+        let _ = response;
+        Ok(TaskWait::NotFollowed)
+    }
+
+    /// Check that the resource exists and is accessible with current
+    /// credentials without transferring its body. HEAD is used unless
+    /// the BMC is known not to support it; then the resource is
+    /// retrieved with GET.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if the resource is not available (for
+    /// example, it doesn't exist or access is denied) or if failed to
+    /// send request to the BMC.
+    pub async fn probe(&self, id: &ODataId) -> Result<(), Error<B>> {
+        let probe = async {
+            if self.quirks_for(id).head_is_not_supported() {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    quirk = "HeadIsNotSupported",
+                    "probing with GET instead of HEAD"
+                );
+                self.bmc.get::<ProbedEntity>(id).await.map(|_| ())
+            } else {
+                self.bmc.head(id).await
+            }
+        };
+        #[cfg(feature = "tracing")]
+        let probe = probe.instrument(tracing::debug_span!("probe", odata_id = %id));
+        probe.await.map_err(Error::Bmc)
+    }
+
+    /// Methods allowed for the resource according to the `Allow`
+    /// header of the response the resource was retrieved with.
+    /// Returns `None` if the BMC didn't report allowed methods.
+    pub fn supported_methods(&self, id: &ODataId) -> Option<AllowedMethods> {
+        self.bmc.allowed_methods(id)
+    }
+
+    /// Parameters of the action described by its `ActionInfo`
+    /// resource. Returns `None` if the action isn't annotated with
+    /// `@Redfish.ActionInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to retrieve `ActionInfo` from
+    /// the BMC.
+    pub async fn allowed_action_parameters<T: MaybeSync, R: MaybeSync>(
+        &self,
+        action: &Action<T, R>,
+    ) -> Result<Option<Vec<ActionParameter>>, Error<B>> {
+        let Some(id) = &action.action_info else {
+            return Ok(None);
+        };
+        let get = self.bmc.get::<ActionInfo>(id);
+        #[cfg(feature = "tracing")]
+        let get = get.instrument(tracing::debug_span!("action_info", odata_id = %id));
+        get.await
+            .map(|info| Some(info.parameters()))
+            .map_err(Error::Bmc)
+    }
+
+    /// Update resource at `id` with `update`. PATCH is used unless
+    /// the platform requires POST for updates (see
+    /// `Quirk::PatchIsNotSupported` and
+    /// `Quirk::PatchRequiresMethodOverride`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if the update fails.
+    pub async fn update<V, R>(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+    ) -> Result<ModificationResponse<R>, Error<B>>
+    where
+        V: Serialize + MaybeSend + MaybeSync,
+        R: for<'de> Deserialize<'de> + MaybeSend + MaybeSync,
+    {
+        let method = self.quirks_for(id).update_method();
+        #[cfg(feature = "tracing")]
+        if method != UpdateMethod::Patch {
+            tracing::debug!(
+                odata_id = %id,
+                method = ?method,
+                "update is sent with POST"
+            );
+        }
+        self.bmc
+            .update_with_method(id, etag, update, method)
+            .await
+            .map_err(Error::Bmc)
+    }
+
+    /// Update `entity` that may advertise settings object
+    /// (`@Redfish.Settings`). Target of PATCH request and
+    /// `@Redfish.SettingsApplyTime` annotation are selected according
+    /// to quirks of the platform:
+    ///
+    /// - the entity itself if settings object is not advertised or is
+    ///   ignored by the BMC;
+    /// - the settings object otherwise, with `OnReset` apply time if
+    ///   the BMC requires it.
+    ///
+    /// The entity `ETag` is sent as precondition when the entity
+    /// itself is updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Json` if `update` cannot be serialized and
+    /// `Error::Bmc` if the update fails.
+    #[cfg(feature = "computer-systems")]
+    pub async fn update_settings<T, V, R>(
+        &self,
+        entity: &T,
+        update: &V,
+    ) -> Result<ModificationResponse<R>, Error<B>>
+    where
+        T: EntityTypeRef + RedfishSettings<T>,
+        V: Serialize + MaybeSend + MaybeSync,
+        R: for<'de> Deserialize<'de> + MaybeSend + MaybeSync,
+    {
+        let strategy = self.quirks_for(entity.odata_id()).settings_apply_strategy();
+        let settings = match strategy {
+            SettingsApplyStrategy::Direct => None,
+            SettingsApplyStrategy::SettingsObject
+            | SettingsApplyStrategy::SettingsObjectWithApplyTime => entity.settings_object(),
+        };
+        let id = settings
+            .as_ref()
+            .map_or_else(|| entity.odata_id(), NavProperty::id);
+        // ETag of the settings object is not known without fetching it.
+        let etag = if settings.is_some() {
+            None
+        } else {
+            entity.etag()
+        };
+        let update = async {
+            if settings.is_some() && strategy == SettingsApplyStrategy::SettingsObjectWithApplyTime
+            {
+                let mut value = serde_json::to_value(update).map_err(Error::Json)?;
+                if let JsonValue::Object(obj) = &mut value {
+                    obj.insert(
+                        "@Redfish.SettingsApplyTime".into(),
+                        serde_json::json!({ "ApplyTime": "OnReset" }),
+                    );
+                }
+                self.update::<JsonValue, R>(id, etag, &value).await
+            } else {
+                self.update::<V, R>(id, etag, update).await
+            }
+        };
+        #[cfg(feature = "tracing")]
+        let update = update.instrument(tracing::debug_span!(
+            "update_settings",
+            odata_id = %id,
+            strategy = ?strategy
+        ));
+        update.await
+    }
+
+    /// Get resource at `id` and deserialize it to `T`. It is escape
+    /// hatch for resources and properties that are not modeled by
+    /// the crate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to send request to the BMC and
+    /// `Error::Json` if the payload cannot be deserialized to `T`.
+    pub async fn get_as<T>(&self, id: &ODataId) -> Result<T, Error<B>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let get = self.bmc.get::<RawEntity>(id);
+        #[cfg(feature = "tracing")]
+        let get = get.instrument(tracing::debug_span!("get_as", odata_id = %id));
+        let raw = get.await.map_err(Error::Bmc)?;
+        T::deserialize(&raw.payload).map_err(Error::Json)
+    }
+
+    /// Update resource at `id` with JSON `value` (PATCH) and wait for
+    /// the returned task if task following is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if the update fails or error if the task
+    /// is not completed successfully in time.
+    pub async fn patch_json(
+        &self,
+        id: &ODataId,
+        value: &JsonValue,
+    ) -> Result<ModificationResponse<JsonValue>, Error<B>> {
+        let update = self.update::<JsonValue, JsonValue>(id, None, value);
+        #[cfg(feature = "tracing")]
+        let update = update.instrument(tracing::debug_span!("patch_json", odata_id = %id));
+        let response = update.await?;
+        self.complete_task(response).await
+    }
+
+    /// Send JSON `value` to resource at `id` (POST), for example, to
+    /// create a collection member or to invoke an action. Waits for
+    /// the returned task if task following is enabled; the resource
+    /// created by the task is returned if the service reports it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if the request fails or error if the task
+    /// is not completed successfully in time.
+    pub async fn post_json(
+        &self,
+        id: &ODataId,
+        value: &JsonValue,
+    ) -> Result<ModificationResponse<JsonValue>, Error<B>> {
+        let create = self.bmc.create::<JsonValue, JsonValue>(id, value);
+        #[cfg(feature = "tracing")]
+        let create = create.instrument(tracing::debug_span!("post_json", odata_id = %id));
+        let response = create.await.map_err(Error::Bmc)?;
+        self.follow_create_task(response, |created| async move {
+            self.get_as::<JsonValue>(&created).await
+        })
+        .await
+    }
+
+    /// Get only properties of the entity listed in the query and
+    /// deserialize them to `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to send request to the BMC and
+    /// `Error::Json` if the payload cannot be deserialized to `T`.
+    pub async fn get_with_select<T>(&self, id: &ODataId, query: SelectQuery) -> Result<T, Error<B>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let select = self.bmc.select::<RawEntity>(id, query);
+        #[cfg(feature = "tracing")]
+        let select = select.instrument(tracing::debug_span!("select", odata_id = %id));
+        let raw = select.await.map_err(Error::Bmc)?;
+        T::deserialize(&raw.payload).map_err(Error::Json)
+    }
+
+    /// Expand navigation property using the query provided by caller,
+    /// for example, only specific navigation properties. Levels of
+    /// the query are limited by `MaxLevels` of the BMC (one level if
+    /// the BMC doesn't support `$levels`). The property is retrieved
+    /// without expansion if the BMC doesn't support `$expand`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to send request to the BMC.
+    #[cfg(feature = "impl-nv-bmc-expand")]
+    pub async fn expand_property_with_query<T>(
+        &self,
+        nav: &NavProperty<T>,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Error<B>>
+    where
+        T: Expandable,
+    {
+        let features = self.expand_features(nav.id());
+        let max_levels = features.supported_levels(u32::MAX).unwrap_or(1);
+        let query =
+            (features.no_links || features.expand_all).then(|| query.max_levels(max_levels));
+        let read = self.read_property(nav, query);
+        #[cfg(feature = "tracing")]
+        let read = read.instrument(tracing::debug_span!(
+            "expand_property_with_query",
+            odata_id = %nav.id()
+        ));
+        read.await
+    }
+
     /// Expand navigation property with optimal available method.
     ///
     /// # Errors
@@ -84,28 +771,292 @@ impl<B: Bmc> NvBmc<B> {
     where
         T: Expandable,
     {
-        let optimal_query = if self.protocol_features.expand.no_links {
+        self.expand_property_with_levels(nav, 1).await
+    }
+
+    /// Expand navigation property `levels` deep with optimal
+    /// available method. Number of levels is limited by `MaxLevels`
+    /// of the BMC; only one level is expanded if the BMC doesn't
+    /// support `$levels`. If `$expand` cannot be used, it is emulated
+    /// on the client side unless disabled (see
+    /// [`ServiceRoot::disable_client_side_expand`](crate::ServiceRoot::disable_client_side_expand)):
+    /// members of the collection are fetched concurrently and
+    /// embedded into it (one level).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to send request to the BMC.
+    ///
+    #[cfg(feature = "impl-nv-bmc-expand")]
+    pub async fn expand_property_with_levels<T>(
+        &self,
+        nav: &NavProperty<T>,
+        levels: u32,
+    ) -> Result<Arc<T>, Error<B>>
+    where
+        T: Expandable,
+    {
+        self.expand_with(nav, levels, |_| true, |_, member| member)
+            .await
+    }
+
+    /// Expand navigation property to collection with members of type
+    /// `M`. Members fetched by client-side expansion are patched with
+    /// read patches of `M` that apply to them before they are
+    /// embedded into the collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to send request to the BMC.
+    #[cfg(feature = "patch-collection")]
+    pub(crate) async fn expand_members<T, M>(
+        &self,
+        nav: &NavProperty<T>,
+    ) -> Result<Arc<T>, Error<B>>
+    where
+        T: Expandable,
+        M: 'static,
+    {
+        self.expand_with(
+            nav,
+            1,
+            |_| true,
+            |id, member| match self.patches_for(id).read::<M>() {
+                Some(patch) => patch(member),
+                None => member,
+            },
+        )
+        .await
+    }
+
+    /// Expand navigation property to collection whose members are
+    /// patched by the caller. Client-side expansion doesn't fetch
+    /// members rejected by `filter_fn`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Bmc` if failed to send request to the BMC.
+    #[cfg(feature = "patch-collection")]
+    pub(crate) async fn expand_filtered<T>(
+        &self,
+        nav: &NavProperty<T>,
+        filter_fn: Option<&FilterFn>,
+    ) -> Result<Arc<T>, Error<B>>
+    where
+        T: Expandable,
+    {
+        self.expand_with(
+            nav,
+            1,
+            |member| filter_fn.is_none_or(|filter| filter(member)),
+            |_, member| member,
+        )
+        .await
+    }
+
+    #[cfg(feature = "impl-nv-bmc-expand")]
+    async fn expand_with<T, K, P>(
+        &self,
+        nav: &NavProperty<T>,
+        levels: u32,
+        keep_member: K,
+        patch_member: P,
+    ) -> Result<Arc<T>, Error<B>>
+    where
+        T: Expandable,
+        K: Fn(&JsonValue) -> bool + MaybeSync,
+        P: Fn(&ODataId, JsonValue) -> JsonValue + MaybeSync,
+    {
+        let features = self.expand_features(nav.id());
+        let optimal_query = if features.no_links {
             // Prefer no links expand.
             Some(ExpandQuery::no_links())
-        } else if self.protocol_features.expand.expand_all {
+        } else if features.expand_all {
             Some(ExpandQuery::all())
         } else {
             None
         };
-        if let Some(optimal_query) = optimal_query {
-            nav.expand(self.bmc.as_ref(), optimal_query)
+        let optimal_query = match features.supported_levels(levels) {
+            Some(levels) => optimal_query.map(|query| query.levels(levels)),
+            None => optimal_query,
+        };
+        let read = async {
+            if optimal_query.is_none() && self.expands_on_client_side(nav.id()) {
+                self.expand_client_side(nav, &keep_member, &patch_member)
+                    .await
+            } else {
+                self.read_property(nav, optimal_query).await
+            }
+        };
+        #[cfg(feature = "tracing")]
+        let read = read.instrument(tracing::debug_span!(
+            "expand_property",
+            odata_id = %nav.id(),
+            levels
+        ));
+        read.await
+    }
+
+    /// Emulate expansion of navigation property: retrieve the entity
+    /// and replace references in its `Members` accepted by
+    /// `keep_member` with member payloads fetched concurrently and
+    /// patched with `patch_member`. If the expanded entity cannot be
+    /// parsed, it is returned without expansion, so members are
+    /// fetched by callers as if `$expand` was not emulated.
+    #[cfg(feature = "impl-nv-bmc-expand")]
+    async fn expand_client_side<T, K, P>(
+        &self,
+        nav: &NavProperty<T>,
+        keep_member: &K,
+        patch_member: &P,
+    ) -> Result<Arc<T>, Error<B>>
+    where
+        T: Expandable,
+        K: Fn(&JsonValue) -> bool + MaybeSync,
+        P: Fn(&ODataId, JsonValue) -> JsonValue + MaybeSync,
+    {
+        if let NavProperty::Expanded(_) = nav {
+            return nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!("expanding on client side");
+        let raw = self
+            .bmc
+            .get::<RawEntity>(nav.id())
+            .await
+            .map_err(Error::Bmc)?;
+        let mut payload = raw.payload.clone();
+        if let Some(JsonValue::Array(members)) = payload.get_mut("Members") {
+            // Only references are fetched. Members embedded by the BMC
+            // and `null` entries (see `NullEntriesInArrays` quirk) are
+            // kept as is.
+            let expanded = self
+                .members_stream(members.as_slice(), |member| async move {
+                    let Some(id) = reference_id(member).filter(|_| keep_member(member)) else {
+                        return Ok(member.clone());
+                    };
+                    let fetched = self.bmc.get::<RawEntity>(&id).await.map_err(Error::Bmc)?;
+                    Ok(patch_member(&id, fetched.payload.clone()))
+                })
+                .try_collect::<Vec<_>>()
+                .await?;
+            *members = expanded;
+        }
+        match T::deserialize(&payload) {
+            Ok(entity) => Ok(Arc::new(entity)),
+            Err(err) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    odata_id = %nav.id(),
+                    error = %err,
+                    "client-side expanded entity cannot be parsed, members are not expanded"
+                );
+                #[cfg(not(feature = "tracing"))]
+                drop(err);
+                T::deserialize(&raw.payload)
+                    .map(Arc::new)
+                    .map_err(Error::Json)
+            }
+        }
+    }
+
+    /// Read navigation property with expand query, or without
+    /// expansion if query is not supported.
+    #[cfg(feature = "impl-nv-bmc-expand")]
+    async fn read_property<T>(
+        &self,
+        nav: &NavProperty<T>,
+        query: Option<ExpandQuery>,
+    ) -> Result<Arc<T>, Error<B>>
+    where
+        T: Expandable,
+    {
+        if let Some(query) = query {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(query = %query.to_query_string(), "expanding");
+            nav.expand(self.bmc.as_ref(), query)
                 .await
                 .map_err(Error::Bmc)?
                 .get(self.bmc.as_ref())
                 .await
                 .map_err(Error::Bmc)
         } else {
-            // if query is not suported.
             nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)
         }
     }
 }
 
+/// `@odata.id` of collection member `member` if it is a reference.
+#[cfg(feature = "impl-nv-bmc-expand")]
+fn reference_id(member: &JsonValue) -> Option<ODataId> {
+    let member = member.as_object().filter(|member| member.len() == 1)?;
+    member
+        .get("@odata.id")
+        .and_then(JsonValue::as_str)
+        .map(|id| ODataId::from(id.to_string()))
+}
+
+/// Any entity retrieved by GET fallback of probing. Only `@odata.id`
+/// is parsed.
+#[derive(Deserialize)]
+struct ProbedEntity {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+}
+
+impl EntityTypeRef for ProbedEntity {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
+/// Result of waiting for the task of a modification response.
+enum TaskWait {
+    /// Response is not a task or task following is disabled.
+    NotFollowed,
+    /// Task is completed. Contains the resource created by the
+    /// operation if the service reported it.
+    #[cfg_attr(not(feature = "task-service"), allow(dead_code))]
+    Completed(Option<ODataId>),
+}
+
+/// Any entity retrieved by `get_as`. Payload is kept as is and
+/// deserialized to the caller type afterwards.
+struct RawEntity {
+    odata_id: ODataId,
+    payload: JsonValue,
+}
+
+impl EntityTypeRef for RawEntity {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
+impl<'de> Deserialize<'de> for RawEntity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let payload = JsonValue::deserialize(deserializer)?;
+        let odata_id = payload
+            .get("@odata.id")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+            .to_string()
+            .into();
+        Ok(Self { odata_id, payload })
+    }
+}
+
 // Implementing Clone because derive requires B to be Clone but NvBmc
 // doesn't require it.
 impl<B: Bmc> Clone for NvBmc<B> {
@@ -113,7 +1064,11 @@ impl<B: Bmc> Clone for NvBmc<B> {
         Self {
             bmc: self.bmc.clone(),
             protocol_features: self.protocol_features.clone(),
-            quirks: self.quirks.clone(),
+            quirk_state: self.quirk_state.clone(),
+            member_concurrency: self.member_concurrency,
+            client_side_expand: self.client_side_expand,
+            #[cfg(feature = "task-service")]
+            task_following: self.task_following.clone(),
         }
     }
 }
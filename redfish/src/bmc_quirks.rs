@@ -13,60 +13,90 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::quirks::PlatformClass;
+use crate::quirks::PlatformInfo;
+use crate::quirks::Quirk;
+use crate::quirks::QuirkRegistry;
 use crate::schema::service_root::ServiceRoot;
+use nv_redfish_core::UpdateMethod;
+use std::collections::BTreeSet;
 
+#[cfg(feature = "accounts")]
+use crate::account::register_patches as register_account_patches;
 #[cfg(feature = "accounts")]
 use crate::account::SlotDefinedConfig as SlotDefinedUserAccountsConfig;
+#[cfg(feature = "computer-systems")]
+use crate::computer_system::register_patches as register_computer_system_patches;
+#[cfg(any(feature = "accounts", feature = "patch-collection"))]
+use crate::patch_support::PatchRegistry;
+#[cfg(feature = "update-service")]
+use crate::update_service::register_patches as register_update_service_patches;
 
 /// Object that provides quirks of individual platforms. On first root
-/// retrieval we classify platform and then apply specific workarounds
-/// for each individual platform class.
+/// retrieval we classify platform using [`QuirkRegistry`] and then
+/// apply specific workarounds for each individual platform class.
 pub struct BmcQuirks {
-    platform: Option<Platform>,
-}
-
-// Platform shouldn't be considered as vendor. Actually it is class of
-// devices that have the same set of quirks.
-#[derive(PartialEq, Eq)]
-enum Platform {
-    Hpe,
-    Dell,
-    AmiViking,
-    AmiGb300,
-    Nvidia,
-    NvidiaDpu,
-    Anonymous1_9_0,
-    NvSwitch,
+    quirks: BTreeSet<Quirk>,
 }
 
 impl BmcQuirks {
-    pub fn new(root: &ServiceRoot) -> Self {
-        let vendor_str = root.vendor.as_ref().and_then(Option::as_deref);
-        let redfish_version_str = root.redfish_version.as_deref();
-        let product_str = root.product.as_ref().and_then(Option::as_deref);
-        // The GB300 host BMC exposes an AMI OEM `RtpVersion` in the service
-        // root; use it to distinguish GB300 from other AMI BMCs so the
-        // expand workaround is not applied to every AMI platform.
-        let rtp_version = root
-            .base
-            .base
-            .oem
-            .as_ref()
-            .and_then(|oem| oem.additional_properties.get("Ami"))
-            .and_then(|ami| ami.get("RtpVersion"))
-            .and_then(|v| v.as_str());
-        let platform = match vendor_str {
-            Some("HPE") => Some(Platform::Hpe),
-            Some("Dell") => Some(Platform::Dell),
-            Some("AMI") if redfish_version_str == Some("1.11.0") => Some(Platform::AmiViking),
-            Some("AMI") if rtp_version == Some("13.09.1") => Some(Platform::AmiGb300),
-            Some("NVIDIA") if product_str == Some("P3809") => Some(Platform::NvSwitch),
-            Some("NVIDIA") => Some(Platform::Nvidia),
-            Some("Nvidia") if product_str == Some("Nvidia-BMCMezz") => Some(Platform::NvidiaDpu),
-            None if redfish_version_str == Some("1.9.0") => Some(Platform::Anonymous1_9_0),
-            _ => None,
-        };
-        Self { platform }
+    pub fn new(root: &ServiceRoot, registry: &QuirkRegistry) -> Self {
+        Self::from_class(registry.classify(&Self::platform_info(root)))
+    }
+
+    /// Quirks of the platform class `class`.
+    pub fn from_class(class: Option<&dyn PlatformClass>) -> Self {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            platform = class.map(PlatformClass::name),
+            "BMC platform classified"
+        );
+        let quirks = class
+            .map(|class| class.quirks().iter().copied().collect())
+            .unwrap_or_default();
+        Self { quirks }
+    }
+
+    /// Enable `quirk` regardless of the platform class.
+    pub fn enable(&mut self, quirk: Quirk) {
+        self.quirks.insert(quirk);
+    }
+
+    /// Disable `quirk` regardless of the platform class.
+    pub fn disable(&mut self, quirk: Quirk) {
+        self.quirks.remove(&quirk);
+    }
+
+    fn platform_info(root: &ServiceRoot) -> PlatformInfo<'_> {
+        PlatformInfo {
+            vendor: root.vendor.as_ref().and_then(Option::as_deref),
+            product: root.product.as_ref().and_then(Option::as_deref),
+            redfish_version: root.redfish_version.as_deref(),
+            oem: root
+                .base
+                .base
+                .oem
+                .as_ref()
+                .map(|oem| &oem.additional_properties),
+        }
+    }
+
+    fn has(&self, quirk: Quirk) -> bool {
+        self.quirks.contains(&quirk)
+    }
+
+    /// Patches of resources required by quirks of the platform.
+    #[cfg(any(feature = "accounts", feature = "patch-collection"))]
+    pub(crate) fn patches(&self) -> PatchRegistry {
+        #[allow(unused_mut)] // modified if any resource feature enabled.
+        let mut patches = PatchRegistry::new();
+        #[cfg(feature = "accounts")]
+        register_account_patches(self, &mut patches);
+        #[cfg(feature = "computer-systems")]
+        register_computer_system_patches(self, &mut patches);
+        #[cfg(feature = "update-service")]
+        register_update_service_patches(self, &mut patches);
+        patches
     }
 
     // Account type is required according to schema specification
@@ -75,7 +105,7 @@ impl BmcQuirks {
     // `nv-redfish`.
     #[cfg(feature = "accounts")]
     pub(crate) fn bug_no_account_type_in_accounts(&self) -> bool {
-        self.platform == Some(Platform::Hpe)
+        self.has(Quirk::NoAccountTypeInAccounts)
     }
 
     // In some implementations BMC cannot create / delete Redfish
@@ -85,13 +115,12 @@ impl BmcQuirks {
     // account user should just disable it.
     #[cfg(feature = "accounts")]
     pub(crate) fn slot_defined_user_accounts(&self) -> Option<SlotDefinedUserAccountsConfig> {
-        self.platform.as_ref().and_then(|platform| {
-            (platform == &Platform::Dell).then_some(SlotDefinedUserAccountsConfig {
+        self.has(Quirk::SlotDefinedUserAccounts)
+            .then_some(SlotDefinedUserAccountsConfig {
                 min_slot: Some(3),
                 hide_disabled: true,
                 disable_account_on_delete: true,
             })
-        })
     }
 
     // In some implementations BMC ReleaseDate is incorrectly set to
@@ -99,14 +128,14 @@ impl BmcQuirks {
     // SoftwareInventoryCollection).
     #[cfg(feature = "update-service")]
     pub(crate) fn fw_inventory_wrong_release_date(&self) -> bool {
-        self.platform == Some(Platform::Dell)
+        self.has(Quirk::FwInventoryWrongReleaseDate)
     }
 
     /// In some cases there is addtional fields in Links.ContainedBy in
     /// Chassis resource, this flag aims to patch this invalid links
     #[cfg(feature = "chassis")]
     pub(crate) fn bug_invalid_contained_by_fields(&self) -> bool {
-        self.platform == Some(Platform::AmiViking)
+        self.has(Quirk::InvalidContainedByFields)
     }
 
     /// Missing navigation properties in root object.
@@ -116,14 +145,8 @@ impl BmcQuirks {
         feature = "managers",
         feature = "update-service",
     ))]
-    pub(crate) const fn bug_missing_root_nav_properties(&self) -> bool {
-        match self.platform {
-            // 1. There are situations when Viking doesn't provide any
-            //    navigation properties in root before BMC reset.
-            // 2. LiteonPowershelf doesn't provide Systems
-            Some(Platform::AmiViking | Platform::Anonymous1_9_0) => true,
-            _ => false,
-        }
+    pub(crate) fn bug_missing_root_nav_properties(&self) -> bool {
+        self.has(Quirk::MissingRootNavProperties)
     }
 
     /// Missing chassis type property in Chassis resource. This
@@ -131,28 +154,70 @@ impl BmcQuirks {
     /// systems doesn't provide it.
     #[cfg(feature = "chassis")]
     pub(crate) fn bug_missing_chassis_type_field(&self) -> bool {
-        self.platform == Some(Platform::AmiViking)
+        self.has(Quirk::MissingChassisTypeField)
     }
 
     /// Missing Name property in Chassis resource. This property is
     /// required in any resource.
     #[cfg(feature = "chassis")]
     pub(crate) fn bug_missing_chassis_name_field(&self) -> bool {
-        self.platform == Some(Platform::AmiViking)
+        self.has(Quirk::MissingChassisNameField)
     }
 
     /// NVIDIA DPU sometimes returns empty string UUID in
     /// chassis/computer system payloads when DPU is in NIC mode.
     #[cfg(any(feature = "chassis", feature = "computer-systems"))]
     pub(crate) fn bug_empty_uuid_field(&self) -> bool {
-        self.platform == Some(Platform::NvidiaDpu)
+        self.has(Quirk::EmptyUuidField)
+    }
+
+    /// Missing Name property in AccountService resource. This
+    /// property is required in any resource.
+    #[cfg(feature = "accounts")]
+    pub(crate) fn bug_missing_account_service_name_field(&self) -> bool {
+        self.has(Quirk::MissingAccountServiceNameField)
+    }
+
+    /// Some implementations (Supermicro) use different letter case in
+    /// `@odata.id` of OEM resources and in links pointing to them.
+    #[cfg(feature = "oem-supermicro")]
+    pub(crate) fn odata_id_case_mismatch(&self) -> bool {
+        self.has(Quirk::ODataIdCaseMismatch)
+    }
+
+    /// Some implementations emit `null` entries in arrays of
+    /// navigation properties.
+    #[cfg(feature = "patch-collection")]
+    pub(crate) fn null_entries_in_arrays(&self) -> bool {
+        self.has(Quirk::NullEntriesInArrays)
+    }
+
+    /// Some implementations return only a part of collection members
+    /// without link to the next page while `Members@odata.count`
+    /// reports all of them.
+    #[cfg(feature = "patch-collection")]
+    pub(crate) fn members_count_mismatch(&self) -> bool {
+        self.has(Quirk::MembersCountMismatch)
+    }
+
+    /// Strategy of updating resources that advertise settings object
+    /// (`@Redfish.Settings`).
+    #[cfg(feature = "computer-systems")]
+    pub(crate) fn settings_apply_strategy(&self) -> SettingsApplyStrategy {
+        if self.has(Quirk::SettingsObjectIgnored) {
+            SettingsApplyStrategy::Direct
+        } else if self.has(Quirk::SettingsApplyTimeRequired) {
+            SettingsApplyStrategy::SettingsObjectWithApplyTime
+        } else {
+            SettingsApplyStrategy::SettingsObject
+        }
     }
 
     /// Missing Name property in Chassis resource. This property is
     /// required in any resource.
     #[cfg(feature = "update-service")]
     pub(crate) fn bug_missing_update_service_name_field(&self) -> bool {
-        self.platform == Some(Platform::AmiViking)
+        self.has(Quirk::MissingUpdateServiceNameField)
     }
 
     /// In some implementations BMC ReleaseDate is incorrectly set to
@@ -161,27 +226,27 @@ impl BmcQuirks {
     /// this is invalid Edm.DateTimeOffset.
     #[cfg(feature = "computer-systems")]
     pub(crate) fn computer_systems_wrong_last_reset_time(&self) -> bool {
-        self.platform == Some(Platform::Dell)
+        self.has(Quirk::ComputerSystemsWrongLastResetTime)
     }
 
     /// In some implementations, Event records in SSE payload do not include
     /// `MemberId`.
     #[cfg(feature = "event-service")]
     pub(crate) fn event_service_sse_no_member_id(&self) -> bool {
-        self.platform == Some(Platform::Nvidia)
+        self.has(Quirk::EventServiceSseNoMemberId)
     }
 
     /// In some implementations, Event records in SSE payload use compact
     /// timezone offsets in `EventTimestamp` (for example, `-0600`).
     #[cfg(feature = "event-service")]
     pub(crate) fn event_service_sse_wrong_timestamp_offset(&self) -> bool {
-        self.platform == Some(Platform::Dell)
+        self.has(Quirk::EventServiceSseWrongTimestampOffset)
     }
 
     /// In some implementations, Event records in SSE payload omit `EventType`.
     #[cfg(feature = "event-service")]
     pub(crate) fn event_service_sse_missing_event_type(&self) -> bool {
-        self.platform == Some(Platform::Nvidia)
+        self.has(Quirk::EventServiceSseMissingEventType)
     }
 
     /// SSE payload does not include `@odata.id`.
@@ -196,9 +261,10 @@ impl BmcQuirks {
     /// these collections.
     #[cfg(feature = "computer-systems")]
     pub(crate) fn filter_computer_system_odata_ids(&self) -> Option<fn(&str) -> bool> {
-        (self.platform == Some(Platform::AmiViking)).then_some(|odata_id| {
-            odata_id.ends_with("/DGX") || odata_id.ends_with("/HGX_Baseboard_0")
-        })
+        self.has(Quirk::FilterComputerSystems)
+            .then_some(|odata_id| {
+                odata_id.ends_with("/DGX") || odata_id.ends_with("/HGX_Baseboard_0")
+            })
     }
 
     /// Vikings provide wrong elements in manager
@@ -206,25 +272,57 @@ impl BmcQuirks {
     /// these collections.
     #[cfg(feature = "managers")]
     pub(crate) fn filter_manager_odata_ids(&self) -> Option<fn(&str) -> bool> {
-        (self.platform == Some(Platform::AmiViking)).then_some(|odata_id| {
+        self.has(Quirk::FilterManagers).then_some(|odata_id| {
             odata_id.ends_with("/BMC")
                 || odata_id.ends_with("/HGX_BMC_0")
                 || odata_id.ends_with("/HGX_FabricManager_0")
         })
     }
 
+    /// Some implementations reject PATCH requests and accept updates
+    /// only with POST (optionally tunneled with
+    /// `X-HTTP-Method-Override` header). This function returns
+    /// request used to update resources.
+    pub(crate) fn update_method(&self) -> UpdateMethod {
+        if self.has(Quirk::PatchRequiresMethodOverride) {
+            UpdateMethod::PostWithMethodOverride
+        } else if self.has(Quirk::PatchIsNotSupported) {
+            UpdateMethod::Post
+        } else {
+            UpdateMethod::Patch
+        }
+    }
+
+    /// Some implementations answer HEAD requests with `405 Method Not
+    /// Allowed` for resources that are available with GET. Probing of
+    /// resources falls back to GET for these platforms.
+    pub(crate) fn head_is_not_supported(&self) -> bool {
+        self.has(Quirk::HeadIsNotSupported)
+    }
+
     /// In some cases we expand is not working according to spec,
     /// if it is the case for specific chassis, we would disable
     /// expand api.
     ///
     /// AMI host BMCs (Viking and the GB300-class `Ami`) return `$expand`
     /// responses that drop Required fields (Id/Name/ChassisType) from embedded
-    /// members; the standalone resource GETs are complete, so disabling expand
-    /// makes nv-redfish fetch each member individually and parse correctly.
-    pub(crate) const fn expand_is_not_working_properly(&self) -> bool {
-        matches!(
-            self.platform,
-            Some(Platform::AmiViking | Platform::AmiGb300)
-        )
+    /// members; the standalone resource GETs are complete, so expand is
+    /// emulated on the client side by fetching each member individually.
+    pub(crate) fn expand_is_not_working_properly(&self) -> bool {
+        self.has(Quirk::ExpandIsNotWorkingProperly)
     }
 }
+
+/// How updates of resources with settings object
+/// (`@Redfish.Settings`) are sent to the BMC.
+#[cfg(feature = "computer-systems")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SettingsApplyStrategy {
+    /// PATCH the resource itself.
+    Direct,
+    /// PATCH the settings object if it is advertised.
+    SettingsObject,
+    /// PATCH the settings object with `@Redfish.SettingsApplyTime`
+    /// annotation.
+    SettingsObjectWithApplyTime,
+}
@@ -0,0 +1,249 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conditional GET caching keyed by `ETag`.
+//!
+//! [`CacheBmc`] wraps any [`Bmc`] and keeps resources it reads with
+//! `get` together with their `@odata.etag`. Subsequent reads of a
+//! cached resource are sent with `If-None-Match` (see
+//! [`Bmc::get_if_none_match`]) and the cached `Arc` is returned when
+//! the BMC answers `304 Not Modified`, so large inventories don't
+//! transfer and deserialize identical payloads again:
+//!
+//! ```ignore
+//! let root = ServiceRoot::new(Arc::new(CacheBmc::new(bmc, 1000))).await?;
+//! ```
+//!
+//! The BMC decides whether a cached resource is fresh, so writes
+//! don't invalidate the cache. Resources without `ETag` and results of
+//! queries (`$expand`, `$filter`, `$select`, paging) are not cached.
+//! BMCs that cache responses themselves, such as `HttpBmc` with
+//! non-zero `CacheSettings` capacity, don't need this layer.
+
+use crate::layer::forward_bmc;
+use futures_util::TryStreamExt as _;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::FilterQuery;
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartRequest;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UpdateMethod;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::convert::identity;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+
+/// `Arc` of a cached resource of any type.
+#[cfg(not(feature = "unsend"))]
+type AnyEntity = Box<dyn Any + Send + Sync>;
+/// `Arc` of a cached resource of any type.
+#[cfg(feature = "unsend")]
+type AnyEntity = Box<dyn Any>;
+
+/// The same resource can be read as different types, for example, as
+/// a typed entity and as raw JSON.
+type CacheKey = (ODataId, TypeId);
+
+struct CacheEntry {
+    etag: ODataETag,
+    entity: AnyEntity,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct Entries {
+    clock: u64,
+    map: HashMap<CacheKey, CacheEntry>,
+}
+
+/// BMC that caches resources read from the wrapped BMC and revalidates
+/// them with conditional requests.
+pub struct CacheBmc<B: Bmc> {
+    bmc: Arc<B>,
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl<B: Bmc> CacheBmc<B> {
+    /// Wrap `bmc` keeping at most `capacity` resources. The least
+    /// recently used resource is evicted when the cache is full;
+    /// capacity `0` disables caching.
+    pub fn new(bmc: Arc<B>, capacity: usize) -> Self {
+        Self {
+            bmc,
+            capacity,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Wrapped BMC.
+    #[must_use]
+    pub fn inner(&self) -> &B {
+        self.bmc.as_ref()
+    }
+
+    /// Maximum number of cached resources.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached resources.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries().map.len()
+    }
+
+    /// Returns `true` if no resources are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries().map.is_empty()
+    }
+
+    /// Drop all cached resources.
+    pub fn clear(&self) {
+        self.entries().map.clear();
+    }
+
+    fn entries(&self) -> MutexGuard<'_, Entries> {
+        // Entries stay consistent even if a thread panicked.
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Cached resource with its `ETag`.
+    fn lookup<T: MaybeSend + MaybeSync + 'static>(
+        &self,
+        key: &CacheKey,
+    ) -> Option<(ODataETag, Arc<T>)> {
+        let mut entries = self.entries();
+        entries.clock += 1;
+        let clock = entries.clock;
+        let entry = entries.map.get_mut(key)?;
+        entry.last_used = clock;
+        let entity = entry.entity.downcast_ref::<Arc<T>>().map(Arc::clone)?;
+        let etag = entry.etag.clone();
+        drop(entries);
+        Some((etag, entity))
+    }
+
+    /// Cache `entity` if it has `ETag`. Resources that lost their
+    /// `ETag` are dropped from the cache.
+    fn store<T: EntityTypeRef + 'static>(&self, key: CacheKey, entity: &Arc<T>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries();
+        let Some(etag) = entity.etag().cloned() else {
+            entries.map.remove(&key);
+            return;
+        };
+        if entries.map.len() >= self.capacity && !entries.map.contains_key(&key) {
+            let evicted = entries
+                .map
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(evicted) = evicted {
+                entries.map.remove(&evicted);
+            }
+        }
+        entries.clock += 1;
+        let last_used = entries.clock;
+        entries.map.insert(
+            key,
+            CacheEntry {
+                etag,
+                entity: Box::new(Arc::clone(entity)),
+                last_used,
+            },
+        );
+    }
+}
+
+impl<B: Bmc> fmt::Debug for CacheBmc<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CacheBmc")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: Bmc> Bmc for CacheBmc<B>
+where
+    B::Error: 'static,
+{
+    type Error = B::Error;
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        let key = (id.clone(), TypeId::of::<T>());
+        let entity = match self.lookup::<T>(&key) {
+            Some((etag, cached)) => match self.bmc.get_if_none_match::<T>(id, &etag).await? {
+                Some(entity) => entity,
+                None => return Ok(cached),
+            },
+            None => self.bmc.get::<T>(id).await?,
+        };
+        self.store(key, &entity);
+        Ok(entity)
+    }
+
+    forward_bmc!(identity;
+        expand,
+        get_if_none_match,
+        head,
+        allowed_methods,
+        filter,
+        page,
+        select,
+        create,
+        create_session,
+        update,
+        update_with_method,
+        delete,
+        action,
+        multipart_update,
+        multipart_upload,
+        http_push_uri_update,
+        stream,
+        download,
+    );
+}
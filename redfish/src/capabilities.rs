@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capabilities of the BMC
+//!
+//! [`Capabilities`] combines protocol features reported in the
+//! service root, quirks of the platform and services discovered on
+//! the BMC. It is obtained with
+//! [`ServiceRoot::capabilities`](crate::ServiceRoot::capabilities)
+//! and lets callers choose workflow up front instead of trying
+//! requests and handling failures.
+
+use nv_redfish_core::UpdateMethod;
+use serde::Serialize;
+
+/// Capabilities of the BMC as seen by `nv-redfish`.
+///
+/// Capabilities of services that are not compiled in (for example,
+/// `multipart_update` without `update-service` feature) are reported
+/// as not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Capabilities {
+    /// `$expand` is supported and is not disabled by a quirk.
+    pub expand: bool,
+    /// `$levels` of `$expand` is supported.
+    pub expand_levels: bool,
+    /// Maximum number of levels that can be expanded.
+    pub max_expand_levels: Option<u32>,
+    /// `$expand` is emulated on the client side when the BMC doesn't
+    /// support it or expand is disabled by a quirk.
+    pub client_side_expand: bool,
+    /// `$select` is supported.
+    pub select: bool,
+    /// `$filter` is supported.
+    pub filter: bool,
+    /// `$top` and `$skip` are supported.
+    pub top_skip: bool,
+    /// HEAD requests are supported.
+    pub head: bool,
+    /// Request used to update resources.
+    pub update_method: UpdateMethod,
+    /// Session service is available, so session authentication can
+    /// be used.
+    pub sessions: bool,
+    /// Update service provides `MultipartHttpPushUri`.
+    pub multipart_update: bool,
+    /// Event service provides `ServerSentEventUri`.
+    pub sse: bool,
+}
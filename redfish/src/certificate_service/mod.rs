@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Certificate Service entities and helpers.
+//!
+//! This module provides typed access to Redfish `CertificateService`,
+//! including listing and deleting certificates installed on the
+//! service.
+
+mod certificate;
+
+use crate::schema::certificate_service::CertificateService as CertificateServiceSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use crate::ServiceRoot;
+use futures_util::TryStreamExt as _;
+use nv_redfish_core::Bmc;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use certificate::Certificate;
+
+/// Certificate service.
+///
+/// Provides access to certificates listed by `CertificateLocations`.
+pub struct CertificateService<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<CertificateServiceSchema>,
+}
+
+impl<B: Bmc> CertificateService<B> {
+    /// Create a new certificate service handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        root: &ServiceRoot<B>,
+    ) -> Result<Option<Self>, Error<B>> {
+        if let Some(service_ref) = &root.root.certificate_service {
+            let data = service_ref.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+            Ok(Some(Self {
+                bmc: bmc.clone(),
+                data,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the raw schema data for this certificate service.
+    #[must_use]
+    pub fn raw(&self) -> Arc<CertificateServiceSchema> {
+        self.data.clone()
+    }
+
+    /// Get `Vec<Certificate>` of all certificates installed on the
+    /// service (`Links.Certificates` of `CertificateLocations`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving certificate locations or
+    /// certificates fails.
+    pub async fn certificates(&self) -> Result<Option<Vec<Certificate<B>>>, Error<B>> {
+        if let Some(locations_ref) = &self.data.certificate_locations {
+            let locations = locations_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)?;
+            let members = locations
+                .links
+                .as_ref()
+                .and_then(|links| links.certificates.as_deref())
+                .unwrap_or_default();
+
+            self.bmc
+                .members_stream(members, |m| Certificate::new(&self.bmc, m))
+                .try_collect()
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<B: Bmc> Resource for CertificateService<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
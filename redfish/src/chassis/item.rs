@@ -13,7 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::action_info::ActionParameter;
 use crate::bmc_quirks::BmcQuirks;
+use crate::chassis::ChassisLink;
 use crate::entity_link::FromLink;
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
@@ -22,16 +24,23 @@ use crate::hardware_id::PartNumber as HardwareIdPartNumber;
 use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::patch_support::JsonValue;
 use crate::patch_support::Payload;
+use crate::patch_support::PropertyDefaults;
 use crate::patch_support::ReadPatchFn;
+use crate::resource::fmt_summary;
 use crate::resource::ResetType;
 use crate::schema::chassis::Chassis as ChassisSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::bmc::Bmc;
+use nv_redfish_core::MaybeSend;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use std::fmt;
 use std::future::Future;
 use std::sync::Arc;
 
@@ -47,6 +56,8 @@ use crate::chassis::Power;
 use crate::chassis::PowerSupply;
 #[cfg(feature = "thermal")]
 use crate::chassis::Thermal;
+#[cfg(any(feature = "controls", feature = "network-adapters"))]
+use crate::collection::RedfishCollection as _;
 #[cfg(feature = "controls")]
 use crate::control::extract_environment_power_limit_control;
 #[cfg(feature = "controls")]
@@ -67,6 +78,8 @@ use crate::schema::sensor::Sensor as SchemaSensor;
 use crate::sensor::extract_environment_sensors;
 #[cfg(feature = "sensors")]
 use crate::sensor::SensorLink;
+#[cfg(feature = "log-services")]
+use futures_util::TryStreamExt as _;
 #[cfg(feature = "oem-nvidia-baseboard")]
 use std::convert::identity;
 
@@ -91,21 +104,24 @@ pub struct Config {
 
 impl Config {
     pub fn new(quirks: &BmcQuirks) -> Self {
-        let mut patches = Vec::new();
-        if quirks.bug_invalid_contained_by_fields() {
-            patches.push(remove_invalid_contained_by_fields as fn(JsonValue) -> JsonValue);
-        }
+        let mut defaults = PropertyDefaults::new();
         if quirks.bug_missing_chassis_type_field() {
-            patches.push(add_default_chassis_type);
+            defaults = defaults.with("ChassisType", "Other");
         }
         if quirks.bug_missing_chassis_name_field() {
-            patches.push(add_default_chassis_name);
+            defaults = defaults.with("Name", "Unnamed chassis");
+        }
+        let mut patches = Vec::new();
+        if quirks.bug_invalid_contained_by_fields() {
+            patches.push(remove_invalid_contained_by_fields as fn(JsonValue) -> JsonValue);
         }
         if quirks.bug_empty_uuid_field() {
             patches.push(normalize_empty_uuid_field);
         }
-        let read_patch_fn = (!patches.is_empty())
-            .then(|| Arc::new(move |v| patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn);
+        let read_patch_fn = (!patches.is_empty() || !defaults.is_empty()).then(|| {
+            Arc::new(move |v| patches.iter().fold(defaults.apply(v), |acc, f| f(acc)))
+                as ReadPatchFn
+        });
         Self { read_patch_fn }
     }
 }
@@ -127,7 +143,7 @@ impl<B: Bmc> Chassis<B> {
         bmc: &NvBmc<B>,
         nav: &NavProperty<ChassisSchema>,
     ) -> Result<Self, Error<B>> {
-        let config = Config::new(&bmc.quirks);
+        let config = Config::new(&bmc.quirks_for(nav.id()));
         if let Some(read_patch_fn) = &config.read_patch_fn {
             Payload::get(bmc.as_ref(), nav, read_patch_fn.as_ref()).await
         } else {
@@ -149,6 +165,14 @@ impl<B: Bmc> Chassis<B> {
         self.data.clone()
     }
 
+    /// One-line summary of the chassis for logs: identifier, model,
+    /// serial number and state. Same as
+    /// the `Display` output.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+
     /// Reset this chassis.
     ///
     /// # Errors
@@ -172,10 +196,29 @@ impl<B: Bmc> Chassis<B> {
             return Err(Error::ActionNotAvailable);
         }
 
-        actions
+        let response = actions
             .reset(self.bmc.as_ref(), reset_type)
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
+    }
+
+    /// Parameters of the `Reset` action supported by this chassis
+    /// according to `@Redfish.ActionInfo` of the action. Returns
+    /// `None` if the action doesn't reference `ActionInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this chassis does not support the `Reset`
+    /// action or if retrieving `ActionInfo` fails.
+    pub async fn reset_parameters(&self) -> Result<Option<Vec<ActionParameter>>, Error<B>> {
+        let action = self
+            .data
+            .actions
+            .as_ref()
+            .and_then(|actions| actions.reset.as_ref())
+            .ok_or(Error::ActionNotAvailable)?;
+        self.bmc.allowed_action_parameters(action).await
     }
 
     /// Get hardware identifier of the network adpater.
@@ -209,6 +252,35 @@ impl<B: Bmc> Chassis<B> {
         }
     }
 
+    /// Links to chassis contained by this chassis (`Links.Contains`).
+    /// Contained chassis are not fetched.
+    #[must_use]
+    pub fn contains(&self) -> Vec<ChassisLink<B>> {
+        self.data
+            .links
+            .as_ref()
+            .and_then(|links| links.contains.as_ref())
+            .map_or_else(Vec::new, |contains| {
+                contains
+                    .iter()
+                    .map(|c| {
+                        ChassisLink::new(&self.bmc, NavProperty::new_reference(c.id().clone()))
+                    })
+                    .collect()
+            })
+    }
+
+    /// Link to the chassis that contains this chassis
+    /// (`Links.ContainedBy`). Returns `None` for top-level chassis.
+    #[must_use]
+    pub fn contained_by(&self) -> Option<ChassisLink<B>> {
+        self.data
+            .links
+            .as_ref()
+            .and_then(|links| links.contained_by.as_ref())
+            .map(|c| ChassisLink::new(&self.bmc, NavProperty::new_reference(c.id().clone())))
+    }
+
     /// Get assembly of this chassis
     ///
     /// Returns `Ok(None)` when the assembly link is absent.
@@ -354,12 +426,13 @@ impl<B: Bmc> Chassis<B> {
                 .await
                 .map_err(Error::Bmc)?;
 
-            let mut log_services = Vec::new();
-            for m in &log_services_collection.members {
-                log_services.push(LogService::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(log_services))
+            self.bmc
+                .members_stream(&log_services_collection.members, |m| {
+                    LogService::new(&self.bmc, m)
+                })
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -472,13 +545,46 @@ impl<B: Bmc> Resource for Chassis<B> {
     }
 }
 
+impl<B: Bmc> ResourceProvidesStatus for Chassis<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for Chassis<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
+
+impl<B: Bmc> fmt::Display for Chassis<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_summary(
+            f,
+            "Chassis",
+            self,
+            &[
+                ("model", self.data.model.as_ref().and_then(Option::as_deref)),
+                (
+                    "serial",
+                    self.data.serial_number.as_ref().and_then(Option::as_deref),
+                ),
+            ],
+        )
+    }
+}
+
 impl<B: Bmc> FromLink<B> for Chassis<B> {
     type Schema = ChassisSchema;
 
     fn from_link(
         bmc: &NvBmc<B>,
         nav: &NavProperty<Self::Schema>,
-    ) -> impl Future<Output = Result<Self, Error<B>>> + Send {
+    ) -> impl Future<Output = Result<Self, Error<B>>> + MaybeSend {
         Self::new(bmc, nav)
     }
 }
@@ -496,26 +602,6 @@ fn remove_invalid_contained_by_fields(mut v: JsonValue) -> JsonValue {
     v
 }
 
-fn add_default_chassis_type(v: JsonValue) -> JsonValue {
-    if let JsonValue::Object(mut obj) = v {
-        obj.entry("ChassisType")
-            .or_insert(JsonValue::String("Other".into()));
-        JsonValue::Object(obj)
-    } else {
-        v
-    }
-}
-
-fn add_default_chassis_name(v: JsonValue) -> JsonValue {
-    if let JsonValue::Object(mut obj) = v {
-        obj.entry("Name")
-            .or_insert(JsonValue::String("Unnamed chassis".into()));
-        JsonValue::Object(obj)
-    } else {
-        v
-    }
-}
-
 fn normalize_empty_uuid_field(mut v: JsonValue) -> JsonValue {
     if let JsonValue::Object(ref mut obj) = v {
         if let Some(uuid) = obj.get_mut("UUID") {
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 mod item;
+mod topology;
 
 #[cfg(feature = "network-adapters")]
 mod network_adapter;
@@ -37,6 +38,8 @@ pub use item::Model;
 pub use item::PartNumber;
 #[doc(inline)]
 pub use item::SerialNumber;
+#[doc(inline)]
+pub use topology::ChassisNode;
 
 #[doc(inline)]
 #[cfg(feature = "network-adapters")]
@@ -65,6 +68,7 @@ pub use power_supply::PowerSupply;
 #[cfg(feature = "thermal")]
 pub use thermal::Thermal;
 
+use crate::collection::RedfishCollection;
 use crate::core::NavProperty;
 use crate::entity_link::EntityLink;
 use crate::patch_support::CollectionWithPatch;
@@ -92,45 +96,47 @@ impl<B: Bmc> ChassisCollection<B> {
         bmc: &NvBmc<B>,
         root: &ServiceRoot<B>,
     ) -> Result<Option<Self>, Error<B>> {
-        let item_config = item::Config::new(&bmc.quirks);
-        if let Some(collection_ref) = &root.root.chassis {
-            Self::expand_collection(
-                bmc,
-                collection_ref,
-                item_config.read_patch_fn.as_ref(),
-                None,
-            )
-            .await
-            .map(Some)
-        } else if bmc.quirks.bug_missing_root_nav_properties() {
-            bmc.expand_property(&NavProperty::new_reference(
-                format!("{}/Chassis", root.odata_id()).into(),
-            ))
-            .await
-            .map(Some)
-        } else {
-            Ok(None)
-        }
-        .map(|c| {
-            c.map(|collection| Self {
+        let missing_root_nav;
+        let collection_ref = match &root.root.chassis {
+            Some(collection_ref) => collection_ref,
+            None if bmc.quirks().bug_missing_root_nav_properties() => {
+                missing_root_nav =
+                    NavProperty::new_reference(format!("{}/Chassis", root.odata_id()).into());
+                &missing_root_nav
+            }
+            None => return Ok(None),
+        };
+        let item_config = item::Config::new(&bmc.quirks_for(collection_ref.id()));
+        Self::expand_collection(
+            bmc,
+            collection_ref,
+            item_config.read_patch_fn.as_ref(),
+            None,
+        )
+        .await
+        .map(|collection| {
+            Some(Self {
                 bmc: bmc.clone(),
                 collection,
             })
         })
     }
+}
+
+impl<B: Bmc> RedfishCollection<B> for ChassisCollection<B> {
+    type MemberSchema = ChassisSchema;
+    type Member = Chassis<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
 
-    /// List all chassis avaiable in this BMC
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching collection data fails.
-    pub async fn members(&self) -> Result<Vec<Chassis<B>>, Error<B>> {
-        let mut chassis_members = Vec::new();
-        for chassis in &self.collection.members {
-            chassis_members.push(Chassis::new(&self.bmc, chassis).await?);
-        }
+    fn members_nav(&self) -> &[NavProperty<ChassisSchema>] {
+        &self.collection.members
+    }
 
-        Ok(chassis_members)
+    async fn member(&self, nav: &NavProperty<ChassisSchema>) -> Result<Chassis<B>, Error<B>> {
+        Chassis::new(&self.bmc, nav).await
     }
 }
 
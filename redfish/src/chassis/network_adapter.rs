@@ -15,6 +15,7 @@
 
 //! Network adapters
 
+use crate::collection::RedfishCollection;
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
@@ -24,8 +25,11 @@ use crate::schema::network_adapter::NetworkAdapter as NetworkAdapterSchema;
 use crate::schema::network_adapter_collection::NetworkAdapterCollection as NetworkAdapterCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
@@ -53,18 +57,25 @@ impl<B: Bmc> NetworkAdapterCollection<B> {
             collection,
         })
     }
+}
 
-    /// List all managers available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching manager data fails.
-    pub async fn members(&self) -> Result<Vec<NetworkAdapter<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(NetworkAdapter::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+impl<B: Bmc> RedfishCollection<B> for NetworkAdapterCollection<B> {
+    type MemberSchema = NetworkAdapterSchema;
+    type Member = NetworkAdapter<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<NetworkAdapterSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(
+        &self,
+        nav: &NavProperty<NetworkAdapterSchema>,
+    ) -> Result<NetworkAdapter<B>, Error<B>> {
+        NetworkAdapter::new(&self.bmc, nav).await
     }
 }
 
@@ -170,3 +181,19 @@ impl<B: Bmc> Resource for NetworkAdapter<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for NetworkAdapter<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for NetworkAdapter<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
@@ -16,11 +16,11 @@
 use crate::schema::power::Power as PowerSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 /// Legacy Power resource wrapper.
@@ -32,8 +32,8 @@ use std::sync::Arc;
 /// Note: This type intentionally does NOT implement `crate::metrics::HasMetrics`
 /// to encourage explicit handling of legacy vs modern approaches.
 pub struct Power<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<PowerSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> Power<B> {
@@ -46,8 +46,8 @@ impl<B: Bmc> Power<B> {
             .await
             .map_err(Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -67,3 +67,13 @@ impl<B: Bmc> Resource for Power<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> RefreshableResource<B> for Power<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
@@ -18,8 +18,11 @@ use crate::schema::power_supply::PowerSupply as PowerSupplySchema;
 use crate::schema::power_supply_metrics::PowerSupplyMetrics;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
@@ -89,10 +92,11 @@ impl<B: Bmc> PowerSupply<B> {
             return Err(Error::ActionNotAvailable);
         }
 
-        actions
+        let response = actions
             .reset(self.bmc.as_ref(), reset_type)
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
     }
 
     /// Get power supply metrics.
@@ -184,3 +188,19 @@ impl<B: Bmc> Resource for PowerSupply<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for PowerSupply<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for PowerSupply<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
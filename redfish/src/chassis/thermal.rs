@@ -16,11 +16,11 @@
 use crate::schema::thermal::Thermal as ThermalSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 /// Legacy Thermal resource wrapper.
@@ -32,8 +32,8 @@ use std::sync::Arc;
 /// Note: This type intentionally does NOT implement `crate::metrics::HasMetrics`
 /// to encourage explicit handling of legacy vs modern approaches.
 pub struct Thermal<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<ThermalSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> Thermal<B> {
@@ -47,8 +47,8 @@ impl<B: Bmc> Thermal<B> {
             .await
             .map_err(Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -68,3 +68,13 @@ impl<B: Bmc> Resource for Thermal<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> RefreshableResource<B> for Thermal<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
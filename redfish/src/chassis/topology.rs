@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Containment topology of chassis.
+//!
+//! [`ChassisCollection::topology`] builds the enclosure tree (for
+//! example rack → chassis → sleds) from `Links.ContainedBy` and
+//! `Links.Contains` of chassis of the collection:
+//! - `ContainedBy` of a chassis takes precedence over `Contains` of
+//!   other chassis;
+//! - links to chassis that are not members of the collection are
+//!   ignored, so such chassis become roots of the tree;
+//! - a chassis appears in the tree only once, even if links form a
+//!   cycle.
+
+use crate::chassis::Chassis;
+use crate::chassis::ChassisCollection;
+use crate::collection::RedfishCollection as _;
+use crate::core::Bmc;
+use crate::core::ODataId;
+use crate::Error;
+use crate::Resource as _;
+use std::collections::HashMap;
+
+/// Node of the chassis containment tree.
+pub struct ChassisNode<B: Bmc> {
+    chassis: Chassis<B>,
+    children: Vec<ChassisNode<B>>,
+}
+
+impl<B: Bmc> ChassisNode<B> {
+    /// Chassis of this node.
+    #[must_use]
+    pub const fn chassis(&self) -> &Chassis<B> {
+        &self.chassis
+    }
+
+    /// Chassis contained by the chassis of this node.
+    #[must_use]
+    pub fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    /// Take the chassis and contained nodes out of this node.
+    #[must_use]
+    pub fn into_parts(self) -> (Chassis<B>, Vec<Self>) {
+        (self.chassis, self.children)
+    }
+}
+
+impl<B: Bmc> ChassisCollection<B> {
+    /// Fetch all chassis and build the containment tree. Returns
+    /// top-level chassis in the order of the collection members.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching chassis fails.
+    pub async fn topology(&self) -> Result<Vec<ChassisNode<B>>, Error<B>> {
+        Ok(build_topology(self.members().await?))
+    }
+}
+
+fn build_topology<B: Bmc>(members: Vec<Chassis<B>>) -> Vec<ChassisNode<B>> {
+    let index = members
+        .iter()
+        .enumerate()
+        .map(|(i, chassis)| (chassis.odata_id().clone(), i))
+        .collect::<HashMap<ODataId, usize>>();
+    let lookup = |id: &ODataId| index.get(id).copied();
+
+    let mut parents = members
+        .iter()
+        .enumerate()
+        .map(|(i, chassis)| {
+            chassis
+                .contained_by()
+                .and_then(|link| lookup(link.odata_id()))
+                .filter(|parent| *parent != i)
+        })
+        .collect::<Vec<_>>();
+    for (i, chassis) in members.iter().enumerate() {
+        for child in chassis
+            .contains()
+            .iter()
+            .filter_map(|l| lookup(l.odata_id()))
+        {
+            if child != i && parents[child].is_none() {
+                parents[child] = Some(i);
+            }
+        }
+    }
+
+    let mut children = vec![Vec::new(); members.len()];
+    for (child, parent) in parents.iter().enumerate() {
+        if let Some(parent) = parent {
+            children[*parent].push(child);
+        }
+    }
+
+    let mut members = members.into_iter().map(Some).collect::<Vec<_>>();
+    // Chassis without parent are roots. Chassis that are left after
+    // that are in a containment cycle; the first of each cycle
+    // becomes a root.
+    let mut roots = Vec::new();
+    for root in (0..members.len()).filter(|i| parents[*i].is_none()) {
+        roots.extend(take_node(root, &children, &mut members));
+    }
+    for root in 0..members.len() {
+        roots.extend(take_node(root, &children, &mut members));
+    }
+    roots
+}
+
+fn take_node<B: Bmc>(
+    i: usize,
+    children: &[Vec<usize>],
+    members: &mut [Option<Chassis<B>>],
+) -> Option<ChassisNode<B>> {
+    let chassis = members[i].take()?;
+    let children = children[i]
+        .iter()
+        .filter_map(|child| take_node(*child, children, members))
+        .collect();
+    Some(ChassisNode { chassis, children })
+}
@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uniform access to Redfish collections
+//!
+//! All collection wrappers (chassis, systems, managers, accounts,
+//! network adapters, ...) implement [`RedfishCollection`], so tools
+//! can be written once for any collection.
+
+use crate::core::Bmc;
+use crate::core::EntityTypeRef;
+use crate::core::MaybeSend;
+use crate::core::MaybeSync;
+use crate::core::NavProperty;
+use crate::core::ODataId;
+use crate::resource::ResourceIdRef;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use futures_core::Stream;
+use futures_util::TryStreamExt as _;
+use std::future::Future;
+
+/// Represents Redfish collection of resources.
+///
+/// Collections provide the BMC, navigation properties of members and
+/// fetching of a single member; fetching of many members is provided
+/// by the trait.
+pub trait RedfishCollection<B: Bmc>: MaybeSync {
+    /// Schema type of members of the collection.
+    type MemberSchema: EntityTypeRef + 'static;
+
+    /// Wrapper of members of the collection.
+    type Member: Resource + MaybeSend;
+
+    /// Required function. BMC the collection was retrieved from.
+    fn bmc(&self) -> &NvBmc<B>;
+
+    /// Required function. Navigation properties of members of the
+    /// collection.
+    fn members_nav(&self) -> &[NavProperty<Self::MemberSchema>];
+
+    /// Required function. Fetch the member referenced by `nav`.
+    fn member(
+        &self,
+        nav: &NavProperty<Self::MemberSchema>,
+    ) -> impl Future<Output = Result<Self::Member, Error<B>>> + MaybeSend;
+
+    /// `OData` identifiers of all members of the collection. Members
+    /// are not fetched.
+    fn odata_ids(&self) -> Vec<&ODataId> {
+        self.members_nav().iter().map(NavProperty::id).collect()
+    }
+
+    /// Number of members of the collection.
+    fn len(&self) -> usize {
+        self.members_nav().len()
+    }
+
+    /// Collection has no members.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Fetch all members of the collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching of any member fails.
+    fn members(&self) -> impl Future<Output = Result<Vec<Self::Member>, Error<B>>> + MaybeSend {
+        self.members_stream().try_collect()
+    }
+
+    /// Fetch all members of the collection without stopping on
+    /// members that cannot be fetched. Errors of such members are
+    /// returned along with the fetched members.
+    fn try_members(&self) -> impl Future<Output = PartialMembers<Self::Member, B>> + MaybeSend {
+        self.bmc()
+            .try_fetch_members(self.members_nav(), move |nav| self.member(nav))
+    }
+
+    /// Stream of members that are fetched as the stream is polled, so
+    /// large collections can be processed without collecting all
+    /// members first.
+    ///
+    /// Pagination is not followed by the stream: references to
+    /// members of all pages (`Members@odata.nextLink`) are retrieved
+    /// with the collection by the BMC, and only fetching of the
+    /// members themselves is deferred.
+    fn members_stream(&self) -> impl Stream<Item = Result<Self::Member, Error<B>>> + MaybeSend {
+        self.bmc()
+            .members_stream(self.members_nav(), move |nav| self.member(nav))
+    }
+
+    /// Fetch the member with identifier `id`. Returns `None` if the
+    /// collection has no such member.
+    ///
+    /// Only the requested member is fetched: members are looked up
+    /// by the last segment of their `OData` identifier, which is the
+    /// resource identifier according to the Redfish specification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching of the member fails.
+    fn get(
+        &self,
+        id: ResourceIdRef<'_>,
+    ) -> impl Future<Output = Result<Option<Self::Member>, Error<B>>> + MaybeSend {
+        let nav = self
+            .members_nav()
+            .iter()
+            .find(|nav| nav.id().last_segment() == Some(*id.inner()));
+        async move {
+            match nav {
+                Some(nav) => self.member(nav).await.map(Some),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+/// Members of a collection fetched by `try_members`.
+pub struct PartialMembers<T, B: Bmc> {
+    /// Fetched members in the order of the collection.
+    pub members: Vec<T>,
+    /// `OData` identifiers of members that cannot be fetched with
+    /// their errors.
+    pub errors: Vec<(ODataId, Error<B>)>,
+}
+
+impl<T, B: Bmc> Default for PartialMembers<T, B> {
+    fn default() -> Self {
+        Self {
+            members: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<T, B: Bmc> PartialMembers<T, B> {
+    /// All members of the collection are fetched.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Fetched members if all members are fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first member that cannot be fetched.
+    pub fn into_result(self) -> Result<Vec<T>, Error<B>> {
+        self.errors
+            .into_iter()
+            .next()
+            .map_or(Ok(self.members), |(_, err)| Err(err))
+    }
+}
@@ -19,16 +19,19 @@ use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EdmPrimitiveType;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 /// BIOS.
 ///
 /// Provides functions to access BIOS functions.
 pub struct Bios<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<BiosSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> Bios<B> {
@@ -41,8 +44,8 @@ impl<B: Bmc> Bios<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -61,6 +64,67 @@ impl<B: Bmc> Bios<B> {
             .and_then(|attributes| attributes.dynamic_properties.get(name))
             .map(|v| BiosAttributeRef::new(v.as_ref()))
     }
+
+    /// Update BIOS attributes.
+    ///
+    /// The update is sent either to the BIOS resource or to its
+    /// settings object (`@Redfish.Settings`) depending on the
+    /// platform. Most platforms apply new values on the next reset
+    /// of the system.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the updated BIOS.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if updating the BIOS fails.
+    pub async fn set_attributes<K, I>(
+        &self,
+        attributes: I,
+    ) -> Result<ModificationResponse<Self>, Error<B>>
+    where
+        K: Into<String>,
+        I: IntoIterator<Item = (K, EdmPrimitiveType)>,
+    {
+        let update = BiosAttributesUpdate {
+            attributes: attributes
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        };
+        let response = self
+            .bmc
+            .update_settings::<_, _, NavProperty<BiosSchema>>(self.data.as_ref(), &update)
+            .await?
+            .try_map_entity_async(|nav| async move {
+                let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+                Ok(Self {
+                    bmc: self.bmc.clone(),
+                    data,
+                })
+            })
+            .await?;
+        self.bmc
+            .follow_task(response, || async {
+                let nav = NavProperty::<BiosSchema>::new_reference(self.data.odata_id().clone());
+                let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+                Ok(Self {
+                    bmc: self.bmc.clone(),
+                    data,
+                })
+            })
+            .await
+    }
+}
+
+#[derive(Serialize)]
+struct BiosAttributesUpdate {
+    #[serde(rename = "Attributes")]
+    attributes: BTreeMap<String, EdmPrimitiveType>,
 }
 
 /// Reference to a BIOS attribute.
@@ -15,17 +15,18 @@
 //! Boot options
 //!
 
+use crate::collection::RedfishCollection;
 use crate::computer_system::BootOptionReference;
 use crate::schema::boot_option::BootOption as BootOptionSchema;
 use crate::schema::boot_option_collection::BootOptionCollection as BootOptionCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::convert::identity;
-use std::marker::PhantomData;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -49,18 +50,22 @@ impl<B: Bmc> BootOptionCollection<B> {
             collection,
         })
     }
+}
 
-    /// List all managers available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching manager data fails.
-    pub async fn members(&self) -> Result<Vec<BootOption<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(BootOption::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+impl<B: Bmc> RedfishCollection<B> for BootOptionCollection<B> {
+    type MemberSchema = BootOptionSchema;
+    type Member = BootOption<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<BootOptionSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(&self, nav: &NavProperty<BootOptionSchema>) -> Result<BootOption<B>, Error<B>> {
+        BootOption::new(&self.bmc, nav).await
     }
 }
 
@@ -90,8 +95,8 @@ pub enum DisplayNameTag {}
 ///
 /// Provides functions to access boot option.
 pub struct BootOption<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<BootOptionSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> BootOption<B> {
@@ -104,8 +109,8 @@ impl<B: Bmc> BootOption<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -160,3 +165,13 @@ impl<B: Bmc> Resource for BootOption<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> RefreshableResource<B> for BootOption<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
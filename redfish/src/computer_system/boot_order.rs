@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Boot order manipulation helpers.
+//!
+//! Helpers read the current persistent boot order, validate
+//! references against boot options of the system and write the new
+//! order with [`ComputerSystem::set_boot_order`].
+
+use crate::collection::RedfishCollection as _;
+use crate::computer_system::BootOption;
+use crate::computer_system::BootOptionReference;
+use crate::computer_system::ComputerSystem;
+use crate::core::Bmc;
+use crate::core::MaybeSend;
+use crate::core::ModificationResponse;
+use crate::Error;
+
+impl<B: Bmc> ComputerSystem<B> {
+    /// Move boot option `reference` to the first position of the
+    /// persistent boot order. The option is added to the order if it
+    /// is not there yet.
+    ///
+    /// The reference is validated against `BootOptions` of the
+    /// system. If the system doesn't expose boot options, it is
+    /// validated against the current boot order.
+    ///
+    /// Returns the same outcomes as [`Self::set_boot_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::BootOptionNotFound` if the reference is not
+    /// known, or an error if fetching boot options or updating the
+    /// system fails.
+    pub async fn move_boot_option_first(
+        &self,
+        reference: BootOptionReference<&str>,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        let reference = reference.to_string();
+        let mut order = self.boot_order_strings();
+        let known = match self.boot_options().await? {
+            Some(options) => options
+                .members()
+                .await?
+                .iter()
+                .any(|option| option.boot_reference().to_string() == reference),
+            None => order.contains(&reference),
+        };
+        if !known {
+            return Err(Error::BootOptionNotFound(reference));
+        }
+        order.retain(|v| *v != reference);
+        order.insert(0, reference);
+        self.set_boot_order(order.into_iter().map(BootOptionReference::new).collect())
+            .await
+    }
+
+    /// Move boot options for which `predicate` returns `true` to the
+    /// beginning of the persistent boot order. Relative order of
+    /// moved options and of the rest of the order is preserved.
+    /// References of the order that don't match any boot option of
+    /// the system stay with the rest of the order.
+    ///
+    /// Returns the same outcomes as [`Self::set_boot_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching boot options or updating the
+    /// system fails.
+    pub async fn reorder_boot<F>(
+        &self,
+        mut predicate: F,
+    ) -> Result<ModificationResponse<Self>, Error<B>>
+    where
+        F: FnMut(&BootOption<B>) -> bool + MaybeSend,
+    {
+        let options = match self.boot_options().await? {
+            Some(options) => options.members().await?,
+            None => Vec::new(),
+        };
+        let selected = options
+            .iter()
+            .filter(|option| predicate(option))
+            .map(|option| option.boot_reference().to_string())
+            .collect::<Vec<_>>();
+        let (mut order, rest): (Vec<_>, Vec<_>) = self
+            .boot_order_strings()
+            .into_iter()
+            .partition(|v| selected.contains(v));
+        order.extend(rest);
+        self.set_boot_order(order.into_iter().map(BootOptionReference::new).collect())
+            .await
+    }
+
+    fn boot_order_strings(&self) -> Vec<String> {
+        self.boot_order()
+            .unwrap_or_default()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+}
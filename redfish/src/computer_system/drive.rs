@@ -19,8 +19,11 @@ use crate::schema::drive::Drive as DriveSchema;
 use crate::schema::drive_metrics::DriveMetrics;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
@@ -130,3 +133,19 @@ impl<B: Bmc> Resource for Drive<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for Drive<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for Drive<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
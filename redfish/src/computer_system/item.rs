@@ -13,28 +13,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::action_info::ActionParameter;
 use crate::core::Bmc;
 use crate::core::EntityTypeRef as _;
 use crate::core::ModificationResponse;
 use crate::core::NavProperty;
-use crate::core::RedfishSettings as _;
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
 use crate::hardware_id::PartNumber as HardwareIdPartNumber;
 use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::patch_support::Payload;
-use crate::patch_support::ReadPatchFn;
+use crate::resource::fmt_summary;
 use crate::resource::PowerState;
 use crate::resource::ResetType;
 use crate::schema::computer_system::ComputerSystem as ComputerSystemSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
-
+use crate::ResourceStatusSchema;
+
+#[cfg(any(
+    feature = "processors",
+    feature = "storages",
+    feature = "memory",
+    feature = "log-services"
+))]
+use futures_util::TryStreamExt as _;
 use serde::Serialize;
 use std::convert::identity;
+use std::fmt;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -118,9 +129,8 @@ impl<B: Bmc> ComputerSystem<B> {
     pub(crate) async fn new(
         bmc: &NvBmc<B>,
         nav: &NavProperty<ComputerSystemSchema>,
-        read_patch_fn: Option<&ReadPatchFn>,
     ) -> Result<Self, Error<B>> {
-        if let Some(read_patch_fn) = read_patch_fn {
+        if let Some(read_patch_fn) = bmc.patches_for(nav.id()).read::<ComputerSystemSchema>() {
             Payload::get(bmc.as_ref(), nav, read_patch_fn.as_ref()).await
         } else {
             nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
@@ -140,6 +150,14 @@ impl<B: Bmc> ComputerSystem<B> {
         self.data.clone()
     }
 
+    /// One-line summary of the system for logs: identifier, model,
+    /// serial number and state. Same as
+    /// the `Display` output.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+
     /// Get hardware identifier of the network adpater.
     #[must_use]
     pub fn hardware_id(&self) -> HardwareIdRef<'_, ComputerSystemTag> {
@@ -211,10 +229,29 @@ impl<B: Bmc> ComputerSystem<B> {
             return Err(Error::ActionNotAvailable);
         }
 
-        actions
+        let response = actions
             .reset(self.bmc.as_ref(), reset_type)
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
+    }
+
+    /// Parameters of the `Reset` action supported by this system
+    /// according to `@Redfish.ActionInfo` of the action. Returns
+    /// `None` if the action doesn't reference `ActionInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this system does not support the `Reset`
+    /// action or if retrieving `ActionInfo` fails.
+    pub async fn reset_parameters(&self) -> Result<Option<Vec<ActionParameter>>, Error<B>> {
+        let action = self
+            .data
+            .actions
+            .as_ref()
+            .and_then(|actions| actions.reset.as_ref())
+            .ok_or(Error::ActionNotAvailable)?;
+        self.bmc.allowed_action_parameters(action).await
     }
 
     /// An array of `BootOptionReference` strings that represent the persistent boot order for with this
@@ -236,6 +273,9 @@ impl<B: Bmc> ComputerSystem<B> {
 
     /// Update the persistent boot order for this computer system.
     ///
+    /// The update is sent either to the system or to its settings
+    /// object (`@Redfish.Settings`) depending on the platform.
+    ///
     /// Returns one of the following modification outcomes:
     ///
     /// - `ModificationResponse::Entity` contains the updated computer system.
@@ -254,17 +294,10 @@ impl<B: Bmc> ComputerSystem<B> {
             boot: BootPatch { boot_order },
         };
 
-        let settings = self.data.settings_object();
-
-        let update_odata = settings
-            .as_ref()
-            .map_or_else(|| self.data.odata_id(), |settings| settings.odata_id());
-
-        self.bmc
-            .as_ref()
-            .update::<_, NavProperty<ComputerSystemSchema>>(update_odata, None, &update)
-            .await
-            .map_err(Error::Bmc)?
+        let response = self
+            .bmc
+            .update_settings::<_, _, NavProperty<ComputerSystemSchema>>(self.data.as_ref(), &update)
+            .await?
             .try_map_entity_async(|nav| async move {
                 let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
 
@@ -273,6 +306,18 @@ impl<B: Bmc> ComputerSystem<B> {
                     data,
                 })
             })
+            .await?;
+        self.bmc
+            .follow_task(response, || async {
+                let nav = NavProperty::<ComputerSystemSchema>::new_reference(
+                    self.data.odata_id().clone(),
+                );
+                let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+                Ok(Self {
+                    bmc: self.bmc.clone(),
+                    data,
+                })
+            })
             .await
     }
 
@@ -305,12 +350,13 @@ impl<B: Bmc> ComputerSystem<B> {
         if let Some(processors_ref) = &self.data.processors {
             let processors_collection = self.bmc.expand_property(processors_ref).await?;
 
-            let mut processors = Vec::new();
-            for m in &processors_collection.members {
-                processors.push(Processor::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(processors))
+            self.bmc
+                .members_stream(&processors_collection.members, |m| {
+                    Processor::new(&self.bmc, m)
+                })
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -345,12 +391,11 @@ impl<B: Bmc> ComputerSystem<B> {
         if let Some(storage_ref) = &self.data.storage {
             let storage_collection = self.bmc.expand_property(storage_ref).await?;
 
-            let mut storage_controllers = Vec::new();
-            for m in &storage_collection.members {
-                storage_controllers.push(Storage::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(storage_controllers))
+            self.bmc
+                .members_stream(&storage_collection.members, |m| Storage::new(&self.bmc, m))
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -369,12 +414,11 @@ impl<B: Bmc> ComputerSystem<B> {
         if let Some(memory_ref) = &self.data.memory {
             let memory_collection = self.bmc.expand_property(memory_ref).await?;
 
-            let mut memory_modules = Vec::new();
-            for m in &memory_collection.members {
-                memory_modules.push(Memory::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(memory_modules))
+            self.bmc
+                .members_stream(&memory_collection.members, |m| Memory::new(&self.bmc, m))
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -395,12 +439,13 @@ impl<B: Bmc> ComputerSystem<B> {
                 .await
                 .map_err(Error::Bmc)?;
 
-            let mut log_services = Vec::new();
-            for m in &log_services_collection.members {
-                log_services.push(LogService::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(log_services))
+            self.bmc
+                .members_stream(&log_services_collection.members, |m| {
+                    LogService::new(&self.bmc, m)
+                })
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -481,3 +526,36 @@ impl<B: Bmc> Resource for ComputerSystem<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for ComputerSystem<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for ComputerSystem<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
+
+impl<B: Bmc> fmt::Display for ComputerSystem<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_summary(
+            f,
+            "ComputerSystem",
+            self,
+            &[
+                ("model", self.data.model.as_ref().and_then(Option::as_deref)),
+                (
+                    "serial",
+                    self.data.serial_number.as_ref().and_then(Option::as_deref),
+                ),
+            ],
+        )
+    }
+}
@@ -19,8 +19,11 @@ use crate::schema::memory::Memory as MemorySchema;
 use crate::schema::memory_metrics::MemoryMetrics;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
@@ -130,3 +133,19 @@ impl<B: Bmc> Resource for Memory<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for Memory<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for Memory<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
@@ -19,11 +19,14 @@
 //! and their sub-resources like processors, storage, memory, and drives.
 
 mod item;
+mod power;
 
 #[cfg(feature = "bios")]
 pub mod bios;
 #[cfg(feature = "boot-options")]
 pub mod boot_option;
+#[cfg(feature = "boot-options")]
+mod boot_order;
 #[cfg(feature = "storages")]
 pub mod drive;
 #[cfg(feature = "memory")]
@@ -34,11 +37,16 @@ pub mod processor;
 pub mod secure_boot;
 #[cfg(feature = "storages")]
 pub mod storage;
+#[cfg(feature = "storages")]
+pub mod volume;
 
+use crate::bmc_quirks::BmcQuirks;
+use crate::collection::RedfishCollection;
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::FilterFn;
 use crate::patch_support::JsonValue;
-use crate::patch_support::ReadPatchFn;
+use crate::patch_support::NullEntries;
+use crate::patch_support::PatchRegistry;
 use crate::resource::Resource as _;
 use crate::schema::computer_system::ComputerSystem as ComputerSystemSchema;
 use crate::schema::computer_system_collection::ComputerSystemCollection as ComputerSystemCollectionSchema;
@@ -55,6 +63,8 @@ use std::sync::Arc;
 pub use item::BootOptionReference;
 #[doc(inline)]
 pub use item::ComputerSystem;
+#[doc(inline)]
+pub use power::PowerWaiting;
 
 #[doc(inline)]
 #[cfg(feature = "bios")]
@@ -83,6 +93,9 @@ pub use secure_boot::SecureBootCurrentBootType;
 #[doc(inline)]
 #[cfg(feature = "storages")]
 pub use storage::Storage;
+#[doc(inline)]
+#[cfg(feature = "storages")]
+pub use volume::Volume;
 
 /// Computer system collection.
 ///
@@ -90,7 +103,6 @@ pub use storage::Storage;
 pub struct SystemCollection<B: Bmc> {
     bmc: NvBmc<B>,
     collection: Arc<ComputerSystemCollectionSchema>,
-    read_patch_fn: Option<ReadPatchFn>,
 }
 
 impl<B: Bmc> SystemCollection<B> {
@@ -98,9 +110,8 @@ impl<B: Bmc> SystemCollection<B> {
         bmc: &NvBmc<B>,
         root: &ServiceRoot<B>,
     ) -> Result<Option<Self>, Error<B>> {
-        let mut patches = Vec::new();
         let mut filters = Vec::new();
-        if let Some(odata_id_filter) = bmc.quirks.filter_computer_system_odata_ids() {
+        if let Some(odata_id_filter) = bmc.quirks().filter_computer_system_odata_ids() {
             filters.push(Box::new(move |js: &JsonValue| {
                 js.get("@odata.id")
                     .and_then(|v| v.as_str())
@@ -108,55 +119,55 @@ impl<B: Bmc> SystemCollection<B> {
                     .is_some_and(identity)
             }));
         }
-        if bmc.quirks.computer_systems_wrong_last_reset_time() {
-            patches.push(computer_systems_wrong_last_reset_time as fn(JsonValue) -> JsonValue);
-        }
-        if bmc.quirks.bug_empty_uuid_field() {
-            patches.push(normalize_empty_uuid_field);
-        }
-        let read_patch_fn = (!patches.is_empty())
-            .then(|| Arc::new(move |v| patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn);
         let filters_fn = (!filters.is_empty())
             .then(move || Arc::new(move |v: &JsonValue| filters.iter().any(|f| f(v))) as FilterFn);
 
-        if let Some(collection_ref) = &root.root.systems {
-            Self::expand_collection(
-                bmc,
-                collection_ref,
-                read_patch_fn.as_ref(),
-                filters_fn.as_ref(),
-            )
-            .await
-            .map(Some)
-        } else if bmc.quirks.bug_missing_root_nav_properties() {
-            bmc.expand_property(&NavProperty::new_reference(
-                format!("{}/Systems", root.odata_id()).into(),
-            ))
-            .await
-            .map(Some)
-        } else {
-            Ok(None)
-        }
-        .map(|c| {
-            c.map(|collection| Self {
+        let missing_root_nav;
+        let collection_ref = match &root.root.systems {
+            Some(collection_ref) => collection_ref,
+            None if bmc.quirks().bug_missing_root_nav_properties() => {
+                missing_root_nav =
+                    NavProperty::new_reference(format!("{}/Systems", root.odata_id()).into());
+                &missing_root_nav
+            }
+            None => return Ok(None),
+        };
+        let read_patch_fn = bmc
+            .patches_for(collection_ref.id())
+            .read::<ComputerSystemSchema>();
+        Self::expand_collection(
+            bmc,
+            collection_ref,
+            read_patch_fn.as_ref(),
+            filters_fn.as_ref(),
+        )
+        .await
+        .map(|collection| {
+            Some(Self {
                 bmc: bmc.clone(),
                 collection,
-                read_patch_fn,
             })
         })
     }
+}
 
-    /// List all computer systems available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching system data fails.
-    pub async fn members(&self) -> Result<Vec<ComputerSystem<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(ComputerSystem::new(&self.bmc, m, self.read_patch_fn.as_ref()).await?);
-        }
-        Ok(members)
+impl<B: Bmc> RedfishCollection<B> for SystemCollection<B> {
+    type MemberSchema = ComputerSystemSchema;
+    type Member = ComputerSystem<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<ComputerSystemSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(
+        &self,
+        nav: &NavProperty<ComputerSystemSchema>,
+    ) -> Result<ComputerSystem<B>, Error<B>> {
+        ComputerSystem::new(&self.bmc, nav).await
     }
 }
 
@@ -171,6 +182,31 @@ impl<B: Bmc> CollectionWithPatch<ComputerSystemCollectionSchema, ComputerSystemS
     }
 }
 
+/// Register patches of computer system resources required by
+/// platform quirks.
+pub(crate) fn register_patches(quirks: &BmcQuirks, patches: &mut PatchRegistry) {
+    if quirks.computer_systems_wrong_last_reset_time() {
+        patches.add_read::<ComputerSystemSchema>(
+            "computer_systems_wrong_last_reset_time",
+            Arc::new(computer_systems_wrong_last_reset_time),
+        );
+    }
+    if quirks.bug_empty_uuid_field() {
+        patches.add_read::<ComputerSystemSchema>(
+            "normalize_empty_uuid_field",
+            Arc::new(normalize_empty_uuid_field),
+        );
+    }
+    if quirks.null_entries_in_arrays() {
+        let nulls = NullEntries::new()
+            .with("/Links/Chassis")
+            .with("/Links/ManagedBy");
+        if let Some(patch_fn) = nulls.into_patch_fn() {
+            patches.add_read::<ComputerSystemSchema>("remove_null_entries", patch_fn);
+        }
+    }
+}
+
 // `LastResetTime` is marked as `edm.DateTimeOffset`, but some systems
 // puts "0000-00-00T00:00:00+00:00" as LastResetTime that is not
 // conform to ABNF of the DateTimeOffset. We delete such fields...
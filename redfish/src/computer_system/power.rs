@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Power control of computer systems.
+//!
+//! [`ComputerSystem::power_on`], [`ComputerSystem::power_off`] and
+//! [`ComputerSystem::restart`] invoke the `Reset` action and wait
+//! until the system reports the target `PowerState`. Waiting is
+//! configured with [`PowerWaiting`].
+
+use crate::computer_system::ComputerSystem;
+use crate::core::ActionError;
+use crate::core::Bmc;
+use crate::resource::PowerState;
+use crate::resource::ResetType;
+use crate::sleep::Sleep;
+use crate::Error;
+use crate::RefreshableResource as _;
+use crate::Resource as _;
+use std::time::Duration;
+
+/// Settings of waiting for the power state of a computer system.
+///
+/// The [`Sleep`] function is provided by the caller so that waiting
+/// doesn't depend on a particular async runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// let waiting = PowerWaiting::new(Sleep::new(tokio::time::sleep))
+///     .timeout(Duration::from_secs(120));
+/// let system = system.power_off(true, &waiting).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct PowerWaiting {
+    timeout: Duration,
+    poll_interval: Duration,
+    sleep: Sleep,
+}
+
+impl PowerWaiting {
+    /// Create settings that use `sleep` to wait between polls. The
+    /// system is polled every 5 seconds for up to 5 minutes by
+    /// default.
+    #[must_use]
+    pub const fn new(sleep: Sleep) -> Self {
+        Self {
+            timeout: Duration::from_secs(300),
+            poll_interval: Duration::from_secs(5),
+            sleep,
+        }
+    }
+
+    /// Maximum time to wait for the target power state. The time is
+    /// counted as the number of polls times the poll interval, so
+    /// waiting doesn't depend on a system clock; time of requests is
+    /// not counted.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Interval between polls of the system.
+    #[must_use]
+    pub const fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Poll `system` until it reports `power_state`. Returns the
+    /// last fetched system.
+    async fn wait<B: Bmc>(
+        &self,
+        system: &ComputerSystem<B>,
+        power_state: PowerState,
+    ) -> Result<ComputerSystem<B>, Error<B>> {
+        self.wait_until(system, power_state, |system| {
+            system.power_state() == Some(power_state)
+        })
+        .await
+    }
+
+    /// Poll `system` until `reached` returns true for it.
+    /// `power_state` is reported in the timeout error.
+    async fn wait_until<B: Bmc>(
+        &self,
+        system: &ComputerSystem<B>,
+        power_state: PowerState,
+        mut reached: impl FnMut(&ComputerSystem<B>) -> bool,
+    ) -> Result<ComputerSystem<B>, Error<B>> {
+        let mut waited = Duration::ZERO;
+        let mut system = system.refresh().await?;
+        while !reached(&system) {
+            waited += self.poll_interval;
+            if waited > self.timeout {
+                return Err(Error::PowerStateTimeout {
+                    system: system.odata_id().clone(),
+                    power_state,
+                    timeout: self.timeout,
+                });
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                system = %system.odata_id(),
+                state = ?system.power_state(),
+                "system is not in target power state"
+            );
+            self.sleep.sleep(self.poll_interval).await;
+            system = system.refresh().await?;
+        }
+        Ok(system)
+    }
+}
+
+impl<B: Bmc> ComputerSystem<B>
+where
+    B::Error: ActionError,
+{
+    /// Power on the system and wait until it reports `On` power
+    /// state. Returns the system fetched in the target state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system does not support the `Reset`
+    /// action, if invoking the action or fetching the system fails,
+    /// or if the system doesn't reach the target state within the
+    /// timeout of `waiting`.
+    pub async fn power_on(&self, waiting: &PowerWaiting) -> Result<Self, Error<B>> {
+        // The power state is polled, so a task of the action is not
+        // followed.
+        let _ = self.reset(Some(ResetType::On)).await?;
+        waiting.wait(self, PowerState::On).await
+    }
+
+    /// Power off the system and wait until it reports `Off` power
+    /// state. Graceful power off asks the operating system to shut
+    /// down (`GracefulShutdown`), otherwise the power is removed
+    /// immediately (`ForceOff`). Returns the system fetched in the
+    /// target state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system does not support the `Reset`
+    /// action, if invoking the action or fetching the system fails,
+    /// or if the system doesn't reach the target state within the
+    /// timeout of `waiting`.
+    pub async fn power_off(
+        &self,
+        graceful: bool,
+        waiting: &PowerWaiting,
+    ) -> Result<Self, Error<B>> {
+        let reset_type = if graceful {
+            ResetType::GracefulShutdown
+        } else {
+            ResetType::ForceOff
+        };
+        let _ = self.reset(Some(reset_type)).await?;
+        waiting.wait(self, PowerState::Off).await
+    }
+
+    /// Restart the system and wait until it reports `On` power state
+    /// after the restart. Graceful restart asks the operating system
+    /// to shut down before the restart (`GracefulRestart`), otherwise
+    /// the system is restarted immediately (`ForceRestart`). Returns
+    /// the system fetched in the target state.
+    ///
+    /// Many services keep reporting `On` during a warm restart. The
+    /// restart is finished when the system reports `On` after it left
+    /// `On` power state or after its `LastResetTime` changed. If the
+    /// system doesn't report `LastResetTime`, the restart is finished
+    /// as soon as the system reports `On` after the `Reset` action
+    /// completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system does not support the `Reset`
+    /// action, if invoking the action or fetching the system fails,
+    /// or if the system doesn't reach the target state within the
+    /// timeout of `waiting`.
+    pub async fn restart(&self, graceful: bool, waiting: &PowerWaiting) -> Result<Self, Error<B>> {
+        let reset_type = if graceful {
+            ResetType::GracefulRestart
+        } else {
+            ResetType::ForceRestart
+        };
+        let last_reset_time = self.raw().last_reset_time;
+        let mut left_on = false;
+        let _ = self.reset(Some(reset_type)).await?;
+        waiting
+            .wait_until(self, PowerState::On, |system| {
+                if system.power_state() != Some(PowerState::On) {
+                    left_on = true;
+                    return false;
+                }
+                left_on
+                    || last_reset_time.is_none()
+                    || system.raw().last_reset_time != last_reset_time
+            })
+            .await
+    }
+}
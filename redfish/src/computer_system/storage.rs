@@ -16,11 +16,16 @@
 //! Storage subsystem and its respective properties.
 
 use crate::computer_system::Drive;
+use crate::computer_system::Volume;
 use crate::schema::storage::Storage as StorageSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
+use futures_util::TryStreamExt as _;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
@@ -78,6 +83,29 @@ impl<B: Bmc> Storage<B> {
             Ok(None)
         }
     }
+
+    /// Get volumes of this storage controller.
+    ///
+    /// Fetches the volume collection and returns a list of [`Volume`]
+    /// handles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the volume collection or volume
+    /// data fails.
+    pub async fn volumes(&self) -> Result<Option<Vec<Volume<B>>>, Error<B>> {
+        if let Some(volumes_ref) = &self.data.volumes {
+            let volumes_collection = self.bmc.expand_property(volumes_ref).await?;
+
+            self.bmc
+                .members_stream(&volumes_collection.members, |m| Volume::new(&self.bmc, m))
+                .try_collect()
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<B: Bmc> Resource for Storage<B> {
@@ -85,3 +113,19 @@ impl<B: Bmc> Resource for Storage<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for Storage<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for Storage<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Volume (logical storage) of a storage subsystem.
+
+use crate::schema::volume::Volume as VolumeSchema;
+use crate::DeletableResource;
+use crate::Error;
+use crate::NvBmc;
+use crate::RefreshableResource;
+use crate::Resource;
+use crate::ResourceProvidesStatus;
+use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
+use crate::SupportedMethods;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+/// Represents a volume of a storage subsystem.
+///
+/// Provides access to volume information. Volumes can be deleted
+/// through [`DeletableResource`].
+pub struct Volume<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<VolumeSchema>,
+}
+
+impl<B: Bmc> Volume<B> {
+    /// Create a new volume handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<VolumeSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this volume.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<VolumeSchema> {
+        self.data.clone()
+    }
+
+    /// Delete this volume.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the volume returned by the
+    ///   server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deleting the volume fails or if the service
+    /// doesn't allow deletion of the volume.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.bmc
+            .delete_entity(self.data.as_ref(), self.data.odata_id(), |nav| async move {
+                Self::new(&self.bmc, &nav).await
+            })
+            .await
+    }
+}
+
+impl<B: Bmc> DeletableResource<B> for Volume<B> {
+    async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        Self::delete(self).await
+    }
+}
+
+impl<B: Bmc> SupportedMethods<B> for Volume<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.data.odata_id())
+    }
+}
+
+impl<B: Bmc> Resource for Volume<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
+
+impl<B: Bmc> ResourceProvidesStatus for Volume<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for Volume<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
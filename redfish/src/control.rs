@@ -38,16 +38,28 @@
 //! }
 //! ```
 
+#[cfg(feature = "chassis")]
 use std::sync::Arc;
 
+#[cfg(feature = "chassis")]
+#[cfg(feature = "chassis")]
+#[cfg(feature = "chassis")]
+#[cfg(feature = "chassis")]
+use crate::collection::RedfishCollection;
+#[cfg(feature = "chassis")]
 use crate::schema::control::Control as ControlSchema;
 #[cfg(feature = "chassis")]
 use crate::schema::control_collection::ControlCollection as ControlCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
+use crate::SupportedMethods;
 
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
@@ -79,7 +91,7 @@ pub use crate::schema::control::SetPointType;
 ///
 /// This wraps the collection resource and its member links. Per-control
 /// properties such as set point and allowable range are stored on each
-/// [`Control`] returned by [`Self::members`].
+/// [`Control`] returned by [`RedfishCollection::members`].
 ///
 /// # Example
 ///
@@ -117,20 +129,23 @@ impl<B: Bmc> ControlCollection<B> {
     pub fn raw(&self) -> Arc<ControlCollectionSchema> {
         self.collection.clone()
     }
+}
 
-    /// List all controls in this collection.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching a control fails.
-    pub async fn members(&self) -> Result<Vec<Control<B>>, Error<B>> {
-        let mut controls = Vec::with_capacity(self.collection.members.len());
+#[cfg(feature = "chassis")]
+impl<B: Bmc> RedfishCollection<B> for ControlCollection<B> {
+    type MemberSchema = ControlSchema;
+    type Member = Control<B>;
 
-        for control in &self.collection.members {
-            controls.push(Control::new(&self.bmc, control).await?);
-        }
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<ControlSchema>] {
+        &self.collection.members
+    }
 
-        Ok(controls)
+    async fn member(&self, nav: &NavProperty<ControlSchema>) -> Result<Control<B>, Error<B>> {
+        Control::new(&self.bmc, nav).await
     }
 }
 
@@ -192,22 +207,52 @@ impl<B: Bmc> Control<B> {
         &self,
         update: &ControlUpdate,
     ) -> Result<ModificationResponse<Self>, Error<B>> {
-        self.bmc
-            .as_ref()
+        let response = self
+            .bmc
             .update::<_, NavProperty<ControlSchema>>(self.data.odata_id(), self.data.etag(), update)
-            .await
-            .map_err(Error::Bmc)?
+            .await?
             .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await?;
+        self.bmc
+            .follow_task(response, || async {
+                Self::new(
+                    &self.bmc,
+                    &NavProperty::new_reference(self.data.odata_id().clone()),
+                )
+                .await
+            })
             .await
     }
 }
 
+impl<B: Bmc> SupportedMethods<B> for Control<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.data.odata_id())
+    }
+}
+
 impl<B: Bmc> Resource for Control<B> {
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
 }
 
+impl<B: Bmc> ResourceProvidesStatus for Control<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for Control<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
+
 #[cfg(any(
     feature = "chassis",
     feature = "memory",
@@ -0,0 +1,439 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-request deadlines and cooperative cancellation.
+//!
+//! [`DeadlineBmc`] wraps any [`Bmc`] and limits the time of every
+//! request it sends, so a hung BMC endpoint can't stall a whole
+//! inventory sweep. All requests can also be aborted at once with
+//! [`Cancellation`]:
+//!
+//! ```ignore
+//! let cancellation = Cancellation::new();
+//! let deadlines = Deadlines::new(Sleep::new(tokio::time::sleep))
+//!     .read_timeout(Some(Duration::from_secs(30)))
+//!     .cancellation(cancellation.clone());
+//! let root = ServiceRoot::new(Arc::new(DeadlineBmc::new(bmc, deadlines))).await?;
+//! // From another task:
+//! cancellation.cancel();
+//! ```
+//!
+//! The [`Sleep`] function is provided by the caller so that deadlines
+//! don't depend on a particular async runtime.
+
+use crate::layer::BmcLayer;
+use crate::layer::Layered;
+use crate::layer::Request;
+use crate::layer::RequestKind;
+use crate::sleep::Sleep;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BmcErrorInfo;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use nv_redfish_core::ODataId;
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::poll_fn;
+use std::future::Future;
+use std::mem;
+use std::pin::pin;
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+use std::time::Duration;
+
+/// Errors of [`DeadlineBmc`].
+#[derive(Debug)]
+pub enum DeadlineError<E> {
+    /// Error of the wrapped BMC.
+    Bmc(E),
+    /// Request didn't finish within the configured timeout.
+    Timeout {
+        /// Name of the BMC operation, for example, `get`.
+        operation: &'static str,
+        /// Configured timeout.
+        timeout: Duration,
+    },
+    /// Request was aborted with [`Cancellation::cancel`].
+    Cancelled,
+}
+
+impl<E: fmt::Display> fmt::Display for DeadlineError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bmc(err) => write!(f, "BMC error: {err}"),
+            Self::Timeout { operation, timeout } => {
+                write!(f, "BMC {operation} request timed out after {timeout:?}")
+            }
+            Self::Cancelled => write!(f, "BMC request cancelled"),
+        }
+    }
+}
+
+impl<E: StdError> StdError for DeadlineError<E> {}
+
+impl<E: BmcErrorInfo> BmcErrorInfo for DeadlineError<E> {
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Bmc(err) => err.status_code(),
+            _ => None,
+        }
+    }
+
+    fn is_transport(&self) -> bool {
+        match self {
+            Self::Bmc(err) => err.is_transport(),
+            Self::Timeout { .. } => true,
+            Self::Cancelled => false,
+        }
+    }
+
+    fn odata_id(&self) -> Option<ODataId> {
+        match self {
+            Self::Bmc(err) => err.odata_id(),
+            _ => None,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Bmc(err) => err.retry_after(),
+            _ => None,
+        }
+    }
+}
+
+/// Handle to cancel all requests of [`DeadlineBmc`] it is attached
+/// to. Clones share the state: cancelling one cancels all of them.
+///
+/// Requests that are in flight are dropped at the next poll; new
+/// requests fail immediately with [`DeadlineError::Cancelled`].
+/// Cancellation is permanent.
+#[derive(Clone, Default)]
+pub struct Cancellation {
+    state: Arc<CancellationState>,
+}
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    waiters: Mutex<Waiters>,
+}
+
+#[derive(Default)]
+struct Waiters {
+    next_id: u64,
+    wakers: HashMap<u64, Waker>,
+}
+
+impl CancellationState {
+    fn waiters(&self) -> MutexGuard<'_, Waiters> {
+        // Waiters stay consistent even if a waker panicked.
+        self.waiters.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Cancellation {
+    /// Create a new, not cancelled handle.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all current and future requests.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Release);
+        let wakers = mem::take(&mut self.state.waiters().wakers);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if [`Self::cancel`] was called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Future that is completed when [`Self::cancel`] is called.
+    pub fn cancelled(&self) -> impl Future<Output = ()> + MaybeSend + '_ {
+        Cancelled {
+            state: &self.state,
+            id: None,
+        }
+    }
+}
+
+impl fmt::Debug for Cancellation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cancellation")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Waiter for cancellation. Its waker is unregistered on drop so
+/// that finished requests don't accumulate.
+struct Cancelled<'a> {
+    state: &'a CancellationState,
+    id: Option<u64>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let state = self.state;
+        if state.cancelled.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        let mut waiters = state.waiters();
+        // `cancel` may have drained wakers between the check above
+        // and taking the lock.
+        if state.cancelled.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        let id = self.id.unwrap_or_else(|| {
+            let id = waiters.next_id;
+            waiters.next_id += 1;
+            id
+        });
+        waiters.wakers.insert(id, cx.waker().clone());
+        drop(waiters);
+        self.id = Some(id);
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            self.state.waiters().wakers.remove(&id);
+        }
+    }
+}
+
+/// Deadline settings of [`DeadlineBmc`].
+///
+/// Reads (`get`, `head`, `expand`, `filter`, `page`, `select`) and
+/// opening of streams are limited by the read timeout. Writes
+/// (`create`, `update`, `delete`, actions and session creation) are
+/// limited by the write timeout. Firmware and multipart uploads are
+/// not limited by default because their duration depends on the
+/// image size.
+#[derive(Clone, Debug)]
+pub struct Deadlines {
+    read_timeout: Option<Duration>,
+    write_timeout: Option<Duration>,
+    upload_timeout: Option<Duration>,
+    sleep: Sleep,
+    cancellation: Cancellation,
+}
+
+impl Deadlines {
+    /// Create settings that use `sleep` to wait for deadlines. Reads
+    /// and writes are limited to 60 seconds by default.
+    #[must_use]
+    pub fn new(sleep: Sleep) -> Self {
+        Self {
+            read_timeout: Some(Duration::from_mins(1)),
+            write_timeout: Some(Duration::from_mins(1)),
+            upload_timeout: None,
+            sleep,
+            cancellation: Cancellation::new(),
+        }
+    }
+
+    /// Limit reads and writes with the same timeout.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self.write_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum time of a read request. `None` disables the limit.
+    #[must_use]
+    pub const fn read_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Maximum time of a write request. `None` disables the limit.
+    #[must_use]
+    pub const fn write_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.write_timeout = timeout;
+        self
+    }
+
+    /// Maximum time of an upload request. `None` (default) disables
+    /// the limit.
+    #[must_use]
+    pub const fn upload_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.upload_timeout = timeout;
+        self
+    }
+
+    /// Abort requests when `cancellation` is cancelled.
+    #[must_use]
+    pub fn cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Run `request` until it is finished, `timeout` expires or
+    /// requests are cancelled, whatever happens first.
+    async fn run<T, E, F>(
+        &self,
+        operation: &'static str,
+        timeout: Option<Duration>,
+        request: F,
+    ) -> Result<T, DeadlineError<E>>
+    where
+        F: Future<Output = Result<T, E>> + MaybeSend,
+    {
+        if self.cancellation.is_cancelled() {
+            return Err(DeadlineError::Cancelled);
+        }
+        let mut request = pin!(request);
+        let mut timer = timeout.map(|timeout| (timeout, self.sleep.sleep(timeout)));
+        let mut cancelled = pin!(self.cancellation.cancelled());
+        poll_fn(move |cx| {
+            if let Poll::Ready(result) = request.as_mut().poll(cx) {
+                return Poll::Ready(result.map_err(DeadlineError::Bmc));
+            }
+            if let Some((timeout, sleep)) = &mut timer {
+                if sleep.as_mut().poll(cx).is_ready() {
+                    return Poll::Ready(Err(DeadlineError::Timeout {
+                        operation,
+                        timeout: *timeout,
+                    }));
+                }
+            }
+            cancelled
+                .as_mut()
+                .poll(cx)
+                .map(|()| Err(DeadlineError::Cancelled))
+        })
+        .await
+    }
+}
+
+impl<E: StdError + MaybeSend + MaybeSync> BmcLayer<E> for Deadlines {
+    type Error = DeadlineError<E>;
+
+    fn bmc_error(err: E) -> Self::Error {
+        DeadlineError::Bmc(err)
+    }
+
+    async fn call<T, F>(&self, request: &Request<'_>, send: F) -> Result<T, Self::Error>
+    where
+        T: MaybeSend,
+        F: Future<Output = Result<T, E>> + MaybeSend,
+    {
+        let timeout = match request.kind {
+            RequestKind::Read => self.read_timeout,
+            RequestKind::Write => self.write_timeout,
+            RequestKind::Upload => self.upload_timeout,
+        };
+        self.run(request.operation, timeout, send).await
+    }
+}
+
+/// BMC that limits time of every request of the wrapped BMC
+/// according to [`Deadlines`].
+///
+/// Only opening of `stream` and `download` is limited; items are
+/// delivered without deadline.
+pub type DeadlineBmc<B> = Layered<Deadlines, B>;
+
+impl<B: Bmc> Layered<Deadlines, B> {
+    /// Deadline settings.
+    #[must_use]
+    pub const fn deadlines(&self) -> &Deadlines {
+        self.layer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cancellation;
+    use super::DeadlineError;
+    use super::Deadlines;
+    use crate::sleep::Sleep;
+    use futures_util::future::pending;
+    use futures_util::FutureExt as _;
+    use std::fmt::Error as FmtError;
+    use std::time::Duration;
+
+    type TestResult = Result<u32, DeadlineError<FmtError>>;
+
+    fn instant_sleep() -> Deadlines {
+        Deadlines::new(Sleep::new(|_| async {}))
+    }
+
+    #[test]
+    fn finished_request_wins() {
+        let deadlines = instant_sleep();
+        let result: Option<TestResult> = deadlines
+            .run("get", Some(Duration::ZERO), async { Ok(42) })
+            .now_or_never();
+        assert!(matches!(result, Some(Ok(42))));
+    }
+
+    #[test]
+    fn hung_request_times_out() {
+        let deadlines = instant_sleep();
+        let result: Option<TestResult> = deadlines
+            .run("get", Some(Duration::from_secs(1)), pending())
+            .now_or_never();
+        assert!(matches!(
+            result,
+            Some(Err(DeadlineError::Timeout {
+                operation: "get",
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn no_timeout_waits() {
+        let deadlines = instant_sleep();
+        let result: Option<TestResult> = deadlines.run("get", None, pending()).now_or_never();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn cancelled_request_is_aborted() {
+        let cancellation = Cancellation::new();
+        let deadlines = instant_sleep().cancellation(cancellation.clone());
+        let mut request = Box::pin(deadlines.run("get", None, pending::<Result<u32, FmtError>>()));
+        assert!((&mut request).now_or_never().is_none());
+        cancellation.cancel();
+        assert!(matches!(
+            request.now_or_never(),
+            Some(Err(DeadlineError::Cancelled))
+        ));
+        assert!(cancellation.state.waiters().wakers.is_empty());
+    }
+}
@@ -20,7 +20,7 @@
 //! without eagerly fetching it.
 //!
 //! Capabilities are determined by trait bounds on the schema type `T`:
-//! - [`fetch`](crate::entity_link::EntityLink::fetch) — always available (requires `T: EntityTypeRef + Deserialize + Send + Sync`)
+//! - [`fetch`](crate::entity_link::EntityLink::fetch) — always available (requires `T: EntityTypeRef + Deserialize + MaybeSend + MaybeSync`)
 //! - [`delete`](crate::entity_link::EntityLink::delete) — available when `T: Deletable`
 //!
 //! Concrete link types are defined as type aliases:
@@ -34,6 +34,7 @@ use crate::NvBmc;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::Deletable;
 use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::MaybeSend;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataId;
@@ -124,11 +125,13 @@ where
     ///
     /// Returns an error if deleting the entity fails.
     pub async fn delete(&self) -> Result<ModificationResponse<T>, Error<B>> {
-        self.bmc
+        let response = self
+            .bmc
             .as_ref()
-            .delete(self.odata_id())
+            .delete(self.odata_id(), self.nav.etag())
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
     }
 }
 
@@ -144,5 +147,5 @@ pub trait FromLink<B: Bmc>: Sized {
     fn from_link(
         bmc: &NvBmc<B>,
         nav: &NavProperty<Self::Schema>,
-    ) -> impl Future<Output = Result<Self, Error<B>>> + Send;
+    ) -> impl Future<Output = Result<Self, Error<B>>> + MaybeSend;
 }
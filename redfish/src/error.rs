@@ -13,13 +13,54 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "computer-systems")]
+use crate::resource::PowerState;
+#[cfg(feature = "task-service")]
+use crate::schema::task::TaskState;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::BmcErrorInfo;
+use nv_redfish_core::MissingRequiredProperty;
+use nv_redfish_core::ODataId;
 use serde_json::Error as JsonError;
 use std::error::Error as StdError;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+#[cfg(any(feature = "computer-systems", feature = "task-service"))]
+use std::time::Duration;
+
+/// Category of an [`Error`]. Returned by [`Error::kind`] so callers
+/// can handle errors without matching on every variant or on error
+/// messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// No response was received from the BMC: connection failure,
+    /// reset or timeout.
+    Transport,
+    /// Request was rejected because of missing or insufficient
+    /// credentials (`401`, `403`).
+    Auth,
+    /// Resource or referenced entity doesn't exist (`404`).
+    NotFound,
+    /// Request conflicts with the current state of the resource
+    /// (`409`, `412`, `428`) or the service has no room for it.
+    Precondition,
+    /// Service doesn't behave as required by the Redfish
+    /// specification, for example, a required property is missing.
+    VendorNonconformance,
+    /// Payload cannot be deserialized to the schema type.
+    Schema,
+    /// Feature or action is not provided by the service.
+    Unsupported,
+    /// Arguments provided by the caller are invalid.
+    InvalidArgument,
+    /// Waiting for an operation to finish timed out.
+    Timeout,
+    /// Service reported an error not covered by other categories
+    /// (other `4xx` and `5xx` statuses, failed tasks).
+    Service,
+}
 
 /// Redfish Errors.
 pub enum Error<B: Bmc> {
@@ -29,8 +70,18 @@ pub enum Error<B: Bmc> {
     /// `slot_defined_user_accounts` feature.
     #[cfg(feature = "accounts")]
     AccountSlotNotAvailable,
+    /// Account doesn't satisfy requirements of the account service.
+    #[cfg(feature = "accounts")]
+    InvalidAccount(String),
     /// Action not available for this resource
     ActionNotAvailable,
+    /// Service doesn't allow DELETE of the resource according to the
+    /// `Allow` header of its response.
+    DeleteNotAllowed(ODataId),
+    /// Boot option reference doesn't match any boot option of the
+    /// computer system.
+    #[cfg(feature = "boot-options")]
+    BootOptionNotFound(String),
     /// Event service does not provide `ServerSentEventUri`
     #[cfg(feature = "event-service")]
     EventServiceServerSentEventUriNotAvailable,
@@ -51,6 +102,33 @@ pub enum Error<B: Bmc> {
         /// Expected TaskService Tasks collection path.
         task_collection: nv_redfish_core::ODataId,
     },
+    /// Followed task finished without completing successfully.
+    #[cfg(feature = "task-service")]
+    TaskNotCompleted {
+        /// Task location.
+        task_location: nv_redfish_core::ODataId,
+        /// Final state of the task.
+        task_state: TaskState,
+    },
+    /// Followed task did not finish within the configured timeout.
+    #[cfg(feature = "task-service")]
+    TaskTimeout {
+        /// Task location.
+        task_location: nv_redfish_core::ODataId,
+        /// Configured timeout.
+        timeout: Duration,
+    },
+    /// Computer system did not reach the power state within the
+    /// configured timeout.
+    #[cfg(feature = "computer-systems")]
+    PowerStateTimeout {
+        /// `@odata.id` of the computer system.
+        system: nv_redfish_core::ODataId,
+        /// Target power state.
+        power_state: PowerState,
+        /// Configured timeout.
+        timeout: Duration,
+    },
     /// Metric definitions are not available for telemetry service
     #[cfg(feature = "telemetry-service")]
     MetricDefinitionsNotAvailable,
@@ -59,6 +137,18 @@ pub enum Error<B: Bmc> {
     MetricReportDefinitionsNotAvailable,
     /// JSON parse error.
     Json(JsonError),
+    /// Required property is missing in the resource payload.
+    ///
+    /// Generated types reject payloads without `Redfish.Required`
+    /// properties on every read, but only payloads patched for
+    /// platform quirks are checked by the crate itself and report
+    /// this error. Other reads fail in the [`Bmc`] implementation,
+    /// which reports the deserialization error ("missing field") as
+    /// [`Error::Bmc`].
+    MissingRequiredProperty(MissingRequiredProperty),
+    /// Platform class forced with `ServiceRootBuilder::force_platform`
+    /// is not registered in the quirk registry.
+    UnknownPlatform(String),
 }
 
 impl<B: Bmc> Display for Error<B> {
@@ -67,13 +157,22 @@ impl<B: Bmc> Display for Error<B> {
         match self {
             Self::Bmc(err) => write!(f, "BMC error: {err}"),
             Self::Json(err) => write!(f, "JSON error: {err}"),
+            Self::MissingRequiredProperty(err) => write!(f, "Invalid payload: {err}"),
+            Self::UnknownPlatform(name) => write!(f, "Unknown platform class: {name}"),
             #[cfg(feature = "accounts")]
             Self::AccountSlotNotAvailable => {
                 write!(f, "Free account slot is not found")
             }
+            #[cfg(feature = "accounts")]
+            Self::InvalidAccount(reason) => write!(f, "Invalid account: {reason}"),
             Self::ActionNotAvailable => {
                 write!(f, "Action is not available for this resource")
             }
+            Self::DeleteNotAllowed(id) => write!(f, "DELETE is not allowed for resource {id}"),
+            #[cfg(feature = "boot-options")]
+            Self::BootOptionNotFound(reference) => {
+                write!(f, "Boot option is not found: {reference}")
+            }
             #[cfg(feature = "event-service")]
             Self::EventServiceServerSentEventUriNotAvailable => {
                 write!(f, "Event service does not provide ServerSentEventUri")
@@ -98,6 +197,25 @@ impl<B: Bmc> Display for Error<B> {
                 f,
                 "Task location {task_location} is not in TaskService Tasks collection {task_collection}"
             ),
+            #[cfg(feature = "task-service")]
+            Self::TaskNotCompleted {
+                task_location,
+                task_state,
+            } => write!(f, "Task {task_location} finished in state {task_state:?}"),
+            #[cfg(feature = "task-service")]
+            Self::TaskTimeout {
+                task_location,
+                timeout,
+            } => write!(f, "Task {task_location} did not finish within {timeout:?}"),
+            #[cfg(feature = "computer-systems")]
+            Self::PowerStateTimeout {
+                system,
+                power_state,
+                timeout,
+            } => write!(
+                f,
+                "System {system} did not reach power state {power_state:?} within {timeout:?}"
+            ),
             #[cfg(feature = "telemetry-service")]
             Self::MetricDefinitionsNotAvailable => {
                 write!(f, "Metric definitions are not available")
@@ -110,6 +228,110 @@ impl<B: Bmc> Display for Error<B> {
     }
 }
 
+impl<B: Bmc> Error<B> {
+    /// Identifier of the resource the error relates to, if it is
+    /// known.
+    #[must_use]
+    pub fn odata_id(&self) -> Option<ODataId>
+    where
+        B::Error: BmcErrorInfo,
+    {
+        match self {
+            Self::Bmc(err) => err.odata_id(),
+            Self::MissingRequiredProperty(err) => Some(err.resource.clone()),
+            Self::DeleteNotAllowed(id) => Some(id.clone()),
+            #[cfg(feature = "task-service")]
+            Self::TaskLocationNotInTaskService { task_location, .. }
+            | Self::TaskNotCompleted { task_location, .. }
+            | Self::TaskTimeout { task_location, .. } => Some(task_location.clone()),
+            #[cfg(feature = "computer-systems")]
+            Self::PowerStateTimeout { system, .. } => Some(system.clone()),
+            _ => None,
+        }
+    }
+
+    /// HTTP status code of the response that caused the error.
+    #[must_use]
+    pub fn status_code(&self) -> Option<u16>
+    where
+        B::Error: BmcErrorInfo,
+    {
+        match self {
+            Self::Bmc(err) => err.status_code(),
+            _ => None,
+        }
+    }
+
+    /// Category of the error.
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind
+    where
+        B::Error: BmcErrorInfo,
+    {
+        match self {
+            Self::Bmc(err) => bmc_error_kind(err),
+            Self::Json(_) => ErrorKind::Schema,
+            Self::MissingRequiredProperty(_) => ErrorKind::VendorNonconformance,
+            Self::UnknownPlatform(_) => ErrorKind::InvalidArgument,
+            #[cfg(feature = "accounts")]
+            Self::AccountSlotNotAvailable => ErrorKind::Precondition,
+            #[cfg(feature = "accounts")]
+            Self::InvalidAccount(_) => ErrorKind::InvalidArgument,
+            Self::ActionNotAvailable | Self::DeleteNotAllowed(_) => ErrorKind::Unsupported,
+            #[cfg(feature = "boot-options")]
+            Self::BootOptionNotFound(_) => ErrorKind::NotFound,
+            #[cfg(feature = "event-service")]
+            Self::EventServiceServerSentEventUriNotAvailable => ErrorKind::Unsupported,
+            #[cfg(feature = "update-service")]
+            Self::UpdateServiceMultipartHttpPushUriNotAvailable => ErrorKind::Unsupported,
+            #[cfg(feature = "update-service-deprecated")]
+            Self::UpdateServiceHttpPushUriNotAvailable => ErrorKind::Unsupported,
+            #[cfg(feature = "task-service")]
+            Self::TaskServiceTasksUnavailable => ErrorKind::Unsupported,
+            #[cfg(feature = "task-service")]
+            Self::TaskLocationNotInTaskService { .. } => ErrorKind::VendorNonconformance,
+            #[cfg(feature = "task-service")]
+            Self::TaskNotCompleted { .. } => ErrorKind::Service,
+            #[cfg(feature = "task-service")]
+            Self::TaskTimeout { .. } => ErrorKind::Timeout,
+            #[cfg(feature = "computer-systems")]
+            Self::PowerStateTimeout { .. } => ErrorKind::Timeout,
+            #[cfg(feature = "telemetry-service")]
+            Self::MetricDefinitionsNotAvailable | Self::MetricReportDefinitionsNotAvailable => {
+                ErrorKind::Unsupported
+            }
+        }
+    }
+
+    /// Returns `true` if the same request may succeed when it is
+    /// repeated later: transport failures, timeouts and statuses
+    /// `408`, `429`, `502`, `503` and `504`.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool
+    where
+        B::Error: BmcErrorInfo,
+    {
+        matches!(self.kind(), ErrorKind::Transport | ErrorKind::Timeout)
+            || matches!(self.status_code(), Some(408 | 429 | 502 | 503 | 504))
+    }
+}
+
+/// Category of an error of the BMC implementation.
+fn bmc_error_kind<E: BmcErrorInfo>(err: &E) -> ErrorKind {
+    if err.is_transport() {
+        return ErrorKind::Transport;
+    }
+    match err.status_code() {
+        Some(401 | 403) => ErrorKind::Auth,
+        Some(404 | 410) => ErrorKind::NotFound,
+        Some(409 | 412 | 428) => ErrorKind::Precondition,
+        Some(405 | 501) => ErrorKind::Unsupported,
+        Some(400 | 422) => ErrorKind::InvalidArgument,
+        Some(408 | 504) => ErrorKind::Timeout,
+        _ => ErrorKind::Service,
+    }
+}
+
 impl<B: Bmc> Debug for Error<B> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         Display::fmt(self, f)
@@ -16,16 +16,19 @@
 //! Ethernet interfaces
 //!
 
+use crate::collection::RedfishCollection;
 use crate::mac_address::MacAddress;
 use crate::schema::ethernet_interface::EthernetInterface as EthernetInterfaceSchema;
 use crate::schema::ethernet_interface_collection::EthernetInterfaceCollection as EthernetInterfaceCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -52,18 +55,25 @@ impl<B: Bmc> EthernetInterfaceCollection<B> {
             collection,
         })
     }
+}
+
+impl<B: Bmc> RedfishCollection<B> for EthernetInterfaceCollection<B> {
+    type MemberSchema = EthernetInterfaceSchema;
+    type Member = EthernetInterface<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
 
-    /// List all managers available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching manager data fails.
-    pub async fn members(&self) -> Result<Vec<EthernetInterface<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(EthernetInterface::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+    fn members_nav(&self) -> &[NavProperty<EthernetInterfaceSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(
+        &self,
+        nav: &NavProperty<EthernetInterfaceSchema>,
+    ) -> Result<EthernetInterface<B>, Error<B>> {
+        EthernetInterface::new(&self.bmc, nav).await
     }
 }
 
@@ -83,8 +93,8 @@ pub enum UefiDevicePathTag {}
 ///
 /// Provides functions to access ethernet interface.
 pub struct EthernetInterface<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<EthernetInterfaceSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> EthernetInterface<B> {
@@ -97,8 +107,8 @@ impl<B: Bmc> EthernetInterface<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -168,3 +178,19 @@ impl<B: Bmc> Resource for EthernetInterface<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for EthernetInterface<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for EthernetInterface<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
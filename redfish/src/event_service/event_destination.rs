@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Event subscription (`EventDestination`) of the event service.
+
+use crate::schema::event_destination::EventDestination as EventDestinationSchema;
+use crate::DeletableResource;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use crate::SupportedMethods;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+/// Event subscription entity wrapper.
+pub struct EventDestination<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<EventDestinationSchema>,
+}
+
+impl<B: Bmc> EventDestination<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<EventDestinationSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get raw event destination schema data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<EventDestinationSchema> {
+        self.data.clone()
+    }
+
+    /// Delete this subscription.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the subscription returned
+    ///   by the server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deleting the entity fails or if the service
+    /// doesn't allow deletion of the entity.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.bmc
+            .delete_entity(self.data.as_ref(), self.data.odata_id(), |nav| async move {
+                Self::new(&self.bmc, &nav).await
+            })
+            .await
+    }
+}
+
+impl<B: Bmc> DeletableResource<B> for EventDestination<B> {
+    async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        Self::delete(self).await
+    }
+}
+
+impl<B: Bmc> SupportedMethods<B> for EventDestination<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.data.odata_id())
+    }
+}
+
+impl<B: Bmc> Resource for EventDestination<B> {
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+}
@@ -17,6 +17,7 @@
 //!
 //! This module provides typed access to Redfish `EventService`.
 
+mod event_destination;
 mod patch;
 
 use crate::patch_support::ReadPatchFn;
@@ -37,6 +38,9 @@ use serde::Deserializer;
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
+#[doc(inline)]
+pub use event_destination::EventDestination;
+
 #[doc(inline)]
 pub use crate::schema::metric_report::MetricReport;
 
@@ -98,19 +102,19 @@ impl<B: Bmc> EventService<B> {
             let mut sse_read_patches = Vec::new();
             let mut sse_event_record_patches: Vec<patch::EventRecordPatchFn> = Vec::new();
 
-            if bmc.quirks.event_service_sse_no_member_id() {
+            if bmc.quirks().event_service_sse_no_member_id() {
                 sse_event_record_patches.push(patch::patch_missing_event_record_member_id);
             }
-            if bmc.quirks.event_service_sse_missing_event_type() {
+            if bmc.quirks().event_service_sse_missing_event_type() {
                 sse_event_record_patches.push(patch::patch_missing_event_type_to_unsupported);
             }
-            if bmc.quirks.event_service_sse_no_odata_id() {
+            if bmc.quirks().event_service_sse_no_odata_id() {
                 let patch_event_id: ReadPatchFn =
                     Arc::new(patch::patch_missing_event_odata_id as fn(JsonValue) -> JsonValue);
                 sse_read_patches.push(patch_event_id);
                 sse_event_record_patches.push(patch::patch_missing_event_record_odata_id);
             }
-            if bmc.quirks.event_service_sse_wrong_timestamp_offset() {
+            if bmc.quirks().event_service_sse_wrong_timestamp_offset() {
                 sse_event_record_patches.push(patch::patch_compact_event_timestamp_offset);
             }
 
@@ -180,6 +184,30 @@ impl<B: Bmc> EventService<B> {
 
         Ok(Box::pin(stream))
     }
+
+    /// Get `Vec<EventDestination>` of event subscriptions of this
+    /// service.
+    ///
+    /// Fetches the subscription collection and returns a list of
+    /// [`EventDestination`] handles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving the collection or its members
+    /// fails.
+    pub async fn subscriptions(&self) -> Result<Option<Vec<EventDestination<B>>>, Error<B>> {
+        if let Some(collection_ref) = &self.data.subscriptions {
+            let collection = self.bmc.expand_property(collection_ref).await?;
+
+            self.bmc
+                .members_stream(&collection.members, |m| EventDestination::new(&self.bmc, m))
+                .try_collect()
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<B: Bmc> Resource for EventService<B> {
@@ -15,6 +15,7 @@
 
 use std::marker::PhantomData;
 
+use serde::Serialize;
 use tagged_types::TaggedType;
 
 /// Type for hardware manufacturers.
@@ -104,7 +105,8 @@ impl<T> tagged_types::ValueMap for SerialNumberTag<T> {}
 
 /// Hardware ID is Manufacturer + Model + Part Number + Serial Number.
 /// It is tagged by the type of related redfish module.
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
+#[serde(bound = "")]
 pub struct HardwareId<Tag> {
     /// Manufacturer of the hardware.
     pub manufacturer: Option<Manufacturer<String, Tag>>,
@@ -117,7 +119,8 @@ pub struct HardwareId<Tag> {
 }
 
 /// Reference to hardware IDs.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize)]
+#[serde(bound = "")]
 pub struct HardwareIdRef<'a, Tag> {
     /// Manufacturer of the hardware.
     pub manufacturer: Option<Manufacturer<&'a str, Tag>>,
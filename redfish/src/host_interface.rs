@@ -16,15 +16,18 @@
 //! Host interfaces
 //!
 
+use crate::collection::RedfishCollection;
 use crate::schema::host_interface::HostInterface as HostInterfaceSchema;
 use crate::schema::host_interface_collection::HostInterfaceCollection as HostInterfaceCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 /// Host interfaces collection.
@@ -47,18 +50,25 @@ impl<B: Bmc> HostInterfaceCollection<B> {
             collection,
         })
     }
+}
+
+impl<B: Bmc> RedfishCollection<B> for HostInterfaceCollection<B> {
+    type MemberSchema = HostInterfaceSchema;
+    type Member = HostInterface<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
 
-    /// List all managers available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching manager data fails.
-    pub async fn members(&self) -> Result<Vec<HostInterface<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(HostInterface::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+    fn members_nav(&self) -> &[NavProperty<HostInterfaceSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(
+        &self,
+        nav: &NavProperty<HostInterfaceSchema>,
+    ) -> Result<HostInterface<B>, Error<B>> {
+        HostInterface::new(&self.bmc, nav).await
     }
 }
 
@@ -66,8 +76,8 @@ impl<B: Bmc> HostInterfaceCollection<B> {
 ///
 /// Provides functions to access host interface.
 pub struct HostInterface<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<HostInterfaceSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> HostInterface<B> {
@@ -80,8 +90,8 @@ impl<B: Bmc> HostInterface<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -108,3 +118,19 @@ impl<B: Bmc> Resource for HostInterface<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for HostInterface<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for HostInterface<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,263 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request and response hooks.
+//!
+//! [`InterceptedBmc`] wraps any [`Bmc`] and calls [`Interceptors`]
+//! before and after every request it sends, for example, for logging
+//! or auditing. Hooks see the operation, `@odata.id` of the resource,
+//! the error of a failed request and the time spent on it:
+//!
+//! ```ignore
+//! let interceptors = Interceptors::new().after_response(|info| {
+//!     eprintln!(
+//!         "{} {} {:?} in {:?}",
+//!         info.request.operation,
+//!         info.request.odata_id,
+//!         info.error.and_then(|err| err.status_code()),
+//!         info.elapsed,
+//!     );
+//! });
+//! let root = ServiceRoot::builder(bmc).layer(interceptors).build().await?;
+//! ```
+//!
+//! Headers of HTTP requests are transport specific and are set by the
+//! [`Bmc`] implementation (see `ClientParams::before_request` of the
+//! reqwest client).
+//!
+//! [`Bmc`]: nv_redfish_core::Bmc
+
+use crate::layer::BmcLayer;
+use crate::layer::Layered;
+use crate::layer::Request;
+use nv_redfish_core::BmcErrorInfo;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Hook that is called before every request is sent.
+type BeforeRequestHook = dyn Fn(&Request<'_>) + Send + Sync;
+
+/// Hook that is called after every request completes.
+type AfterResponseHook = dyn Fn(&ResponseInfo<'_>) + Send + Sync;
+
+/// Summary of a completed request passed to
+/// [`Interceptors::after_response`] hooks.
+#[derive(Clone, Copy)]
+pub struct ResponseInfo<'a> {
+    /// Completed request.
+    pub request: &'a Request<'a>,
+    /// Error of the request, `None` if it succeeded.
+    pub error: Option<&'a dyn BmcErrorInfo>,
+    /// Time spent on the request.
+    pub elapsed: Duration,
+}
+
+impl fmt::Debug for ResponseInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseInfo")
+            .field("request", &self.request)
+            .field(
+                "status_code",
+                &self.error.and_then(BmcErrorInfo::status_code),
+            )
+            .field("elapsed", &self.elapsed)
+            .finish()
+    }
+}
+
+/// Request and response hooks.
+///
+/// Hooks are called for every attempt of a request, in the order they
+/// were added. Opening of a stream is a request; its items are not.
+///
+/// # Examples
+///
+/// ```rust
+/// use nv_redfish::intercept::Interceptors;
+///
+/// let interceptors = Interceptors::new()
+///     .before_request(|request| eprintln!("{} {}", request.operation, request.odata_id))
+///     .after_response(|info| {
+///         if let Some(status) = info.error.and_then(|err| err.status_code()) {
+///             eprintln!("{} failed with {status}", info.request.odata_id);
+///         }
+///     });
+/// ```
+#[derive(Clone, Default)]
+pub struct Interceptors {
+    before_request: Vec<Arc<BeforeRequestHook>>,
+    after_response: Vec<Arc<AfterResponseHook>>,
+}
+
+impl Interceptors {
+    /// Creates an empty set of hooks.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a hook that is called before a request is sent.
+    #[must_use]
+    pub fn before_request<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Request<'_>) + Send + Sync + 'static,
+    {
+        self.before_request.push(Arc::new(hook));
+        self
+    }
+
+    /// Adds a hook that is called after a request succeeded or
+    /// failed.
+    #[must_use]
+    pub fn after_response<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&ResponseInfo<'_>) + Send + Sync + 'static,
+    {
+        self.after_response.push(Arc::new(hook));
+        self
+    }
+}
+
+impl fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interceptors")
+            .field("before_request", &self.before_request.len())
+            .field("after_response", &self.after_response.len())
+            .finish()
+    }
+}
+
+impl<E> BmcLayer<E> for Interceptors
+where
+    E: StdError + BmcErrorInfo + MaybeSend + MaybeSync,
+{
+    type Error = E;
+
+    fn bmc_error(err: E) -> Self::Error {
+        err
+    }
+
+    async fn call<T, F>(&self, request: &Request<'_>, send: F) -> Result<T, Self::Error>
+    where
+        T: MaybeSend,
+        F: Future<Output = Result<T, E>> + MaybeSend,
+    {
+        for hook in &self.before_request {
+            hook(request);
+        }
+        if self.after_response.is_empty() {
+            return send.await;
+        }
+        let started = Instant::now();
+        let result = send.await;
+        let info = ResponseInfo {
+            request,
+            error: result.as_ref().err().map(|err| err as &dyn BmcErrorInfo),
+            elapsed: started.elapsed(),
+        };
+        for hook in &self.after_response {
+            hook(&info);
+        }
+        result
+    }
+}
+
+/// BMC that calls [`Interceptors`] for every request of the wrapped
+/// BMC.
+pub type InterceptedBmc<B> = Layered<Interceptors, B>;
+
+#[cfg(test)]
+mod tests {
+    use super::Interceptors;
+    use crate::layer::BmcLayer as _;
+    use crate::layer::Request;
+    use crate::layer::RequestKind;
+    use futures_util::FutureExt as _;
+    use nv_redfish_core::BmcErrorInfo;
+    use nv_redfish_core::ODataId;
+    use std::error::Error as StdError;
+    use std::fmt;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct Unavailable;
+
+    impl fmt::Display for Unavailable {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("service unavailable")
+        }
+    }
+
+    impl StdError for Unavailable {}
+
+    impl BmcErrorInfo for Unavailable {
+        fn status_code(&self) -> Option<u16> {
+            Some(503)
+        }
+    }
+
+    #[test]
+    fn hooks_see_every_request() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let interceptors = Interceptors::new()
+            .before_request({
+                let seen = seen.clone();
+                move |request| {
+                    seen.lock()
+                        .expect("not poisoned")
+                        .push((request.odata_id.to_string(), None));
+                }
+            })
+            .after_response({
+                let seen = seen.clone();
+                move |info| {
+                    let status = info.error.and_then(BmcErrorInfo::status_code);
+                    seen.lock()
+                        .expect("not poisoned")
+                        .push((info.request.odata_id.to_string(), status));
+                }
+            });
+        let odata_id = ODataId::from("/redfish/v1/Systems/1".to_string());
+        let request = Request {
+            operation: "get",
+            kind: RequestKind::Read,
+            odata_id: &odata_id,
+            resource: "ComputerSystem",
+        };
+
+        let ok = interceptors.call(&request, async { Ok::<_, Unavailable>(()) });
+        assert!(matches!(ok.now_or_never(), Some(Ok(()))));
+        let failed = interceptors.call(&request, async { Err::<(), _>(Unavailable) });
+        assert!(matches!(failed.now_or_never(), Some(Err(Unavailable))));
+
+        let id = odata_id.to_string();
+        assert_eq!(
+            *seen.lock().expect("not poisoned"),
+            [
+                (id.clone(), None),
+                (id.clone(), None),
+                (id.clone(), None),
+                (id, Some(503)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,464 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inventory snapshot of the whole service tree
+//!
+//! [`Inventory::collect`] walks enabled subsystems (systems with
+//! their processors and memory, chassis, managers, firmware and
+//! network adapters / interfaces) concurrently and produces a flat
+//! list of items. Each item is identified by its `@odata.id` and
+//! refers to its parent the same way, so snapshots of the same BMC
+//! can be compared and stored.
+//!
+//! [`Inventory::try_collect`] doesn't stop on resources that cannot
+//! be fetched, so one broken resource doesn't abort the whole sweep.
+//!
+//! ```ignore
+//! use nv_redfish::inventory::Inventory;
+//! use nv_redfish::inventory::Options;
+//!
+//! let inventory = Inventory::collect(&root, &Options::default()).await?;
+//! println!("{}", serde_json::to_string_pretty(&inventory)?);
+//! ```
+
+use crate::chassis::Chassis;
+use crate::collection::PartialMembers;
+use crate::collection::RedfishCollection as _;
+use crate::computer_system::ComputerSystem;
+use crate::computer_system::Memory;
+use crate::computer_system::Processor;
+use crate::core::Bmc;
+use crate::core::ODataId;
+use crate::ethernet_interface::EthernetInterface;
+use crate::hardware_id::HardwareIdRef;
+use crate::manager::Manager;
+use crate::resource::Status;
+use crate::update_service::SoftwareInventory;
+use crate::Error;
+use crate::Resource;
+use crate::ResourceProvidesStatus;
+use crate::ServiceRoot;
+use futures_util::future;
+use futures_util::stream;
+use futures_util::StreamExt as _;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Subsystems included into the inventory.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Options {
+    /// Computer systems with their processors and memory modules.
+    pub systems: bool,
+    /// Chassis.
+    pub chassis: bool,
+    /// Managers (BMCs).
+    pub managers: bool,
+    /// Firmware inventory of the update service.
+    pub firmware: bool,
+    /// Network adapters of chassis and ethernet interfaces of
+    /// systems and managers.
+    pub network: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            systems: true,
+            chassis: true,
+            managers: true,
+            firmware: true,
+            network: true,
+        }
+    }
+}
+
+/// Kind of inventory item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum Kind {
+    /// Computer system.
+    System,
+    /// Processor of a computer system.
+    Processor,
+    /// Memory module of a computer system.
+    Memory,
+    /// Chassis.
+    Chassis,
+    /// Manager.
+    Manager,
+    /// Firmware inventory item.
+    Firmware,
+    /// Network adapter of a chassis.
+    NetworkAdapter,
+    /// Ethernet interface of a system or a manager.
+    EthernetInterface,
+}
+
+/// One item of the inventory.
+#[derive(Clone, Debug, Serialize)]
+pub struct Item {
+    /// `@odata.id` of the resource. Stable identifier of the item.
+    pub odata_id: ODataId,
+    /// `@odata.id` of the resource that contains this item.
+    pub parent: Option<ODataId>,
+    /// Kind of the item.
+    pub kind: Kind,
+    /// Identifier of the resource.
+    pub id: String,
+    /// Name of the resource.
+    pub name: String,
+    /// Manufacturer of the hardware.
+    pub manufacturer: Option<String>,
+    /// Model of the hardware.
+    pub model: Option<String>,
+    /// Part number of the hardware.
+    pub part_number: Option<String>,
+    /// Serial number of the hardware.
+    pub serial_number: Option<String>,
+    /// Firmware or software version.
+    pub version: Option<String>,
+    /// MAC address of the interface.
+    pub mac_address: Option<String>,
+    /// Status of the resource.
+    pub status: Option<Status>,
+}
+
+impl Item {
+    fn new<R>(kind: Kind, resource: &R, parent: Option<&ODataId>) -> Self
+    where
+        R: Resource + ResourceProvidesStatus,
+    {
+        Self {
+            odata_id: resource.odata_id().clone(),
+            parent: parent.cloned(),
+            kind,
+            id: resource.id().to_string(),
+            name: resource.name().to_string(),
+            manufacturer: None,
+            model: None,
+            part_number: None,
+            serial_number: None,
+            version: None,
+            mac_address: None,
+            status: resource.status(),
+        }
+    }
+
+    fn with_hardware_id<Tag>(self, hw: HardwareIdRef<'_, Tag>) -> Self {
+        Self {
+            manufacturer: hw.manufacturer.map(|v| v.to_string()),
+            model: hw.model.map(|v| v.to_string()),
+            part_number: hw.part_number.map(|v| v.to_string()),
+            serial_number: hw.serial_number.map(|v| v.to_string()),
+            ..self
+        }
+    }
+}
+
+/// Flat snapshot of the inventory of the BMC.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Inventory {
+    /// Items ordered by `@odata.id`.
+    pub items: Vec<Item>,
+}
+
+impl Inventory {
+    /// Collect inventory of subsystems enabled in `options`.
+    /// Subsystems that are not provided by the BMC are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching of any resource fails.
+    pub async fn collect<B: Bmc>(
+        root: &ServiceRoot<B>,
+        options: &Options,
+    ) -> Result<Self, Error<B>> {
+        let (inventory, errors) = Self::try_collect(root, options).await?;
+        errors
+            .into_iter()
+            .next()
+            .map_or(Ok(inventory), |(_, err)| Err(err))
+    }
+
+    /// Collect inventory like [`Self::collect`], but don't stop on
+    /// resources that cannot be fetched. Items of such resources are
+    /// skipped and errors are returned along with the inventory,
+    /// identified by `@odata.id` of the failed resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching of a collection of a subsystem
+    /// fails.
+    pub async fn try_collect<B: Bmc>(
+        root: &ServiceRoot<B>,
+        options: &Options,
+    ) -> Result<(Self, Vec<(ODataId, Error<B>)>), Error<B>> {
+        let (systems, chassis, managers, firmware) = future::try_join4(
+            systems(root, options),
+            chassis(root, options),
+            managers(root, options),
+            firmware(root, options),
+        )
+        .await?;
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+        for partial in [systems, chassis, managers, firmware] {
+            items.extend(partial.members);
+            errors.extend(partial.errors);
+        }
+        items.sort_by(|a, b| a.odata_id.cmp(&b.odata_id));
+        Ok((Self { items }, errors))
+    }
+
+    /// Items of kind `kind`.
+    pub fn items_of(&self, kind: Kind) -> impl Iterator<Item = &Item> {
+        self.items.iter().filter(move |item| item.kind == kind)
+    }
+}
+
+/// Items of `members` from `results` of collecting items of each
+/// member. Failed members are reported with errors of `members`.
+fn member_items<R: Resource, B: Bmc>(
+    members: PartialMembers<R, B>,
+    results: Vec<Result<Vec<Item>, Error<B>>>,
+) -> PartialMembers<Item, B> {
+    let mut items = PartialMembers {
+        members: Vec::new(),
+        errors: members.errors,
+    };
+    for (member, result) in members.members.iter().zip(results) {
+        match result {
+            Ok(v) => items.members.extend(v),
+            Err(err) => items.errors.push((member.odata_id().clone(), err)),
+        }
+    }
+    items
+}
+
+async fn systems<B: Bmc>(
+    root: &ServiceRoot<B>,
+    options: &Options,
+) -> Result<PartialMembers<Item, B>, Error<B>> {
+    if !options.systems && !options.network {
+        return Ok(PartialMembers::default());
+    }
+    let Some(collection) = root.systems().await? else {
+        return Ok(PartialMembers::default());
+    };
+    let systems = collection.try_members().await;
+    let results = stream::iter(&systems.members)
+        .map(|system| system_items(system, options))
+        .buffered(root.member_concurrency_limit())
+        .collect::<Vec<_>>()
+        .await;
+    Ok(member_items(systems, results))
+}
+
+async fn system_items<B: Bmc>(
+    system: &ComputerSystem<B>,
+    options: &Options,
+) -> Result<Vec<Item>, Error<B>> {
+    let parent = Some(system.odata_id());
+    let mut items = Vec::new();
+    if options.systems {
+        items.push(Item::new(Kind::System, system, None).with_hardware_id(system.hardware_id()));
+        let (processors, memory) =
+            future::try_join(system.processors(), system.memory_modules()).await?;
+        items.extend(
+            processors
+                .into_iter()
+                .flatten()
+                .map(|processor| processor_item(&processor, parent)),
+        );
+        items.extend(
+            memory
+                .into_iter()
+                .flatten()
+                .map(|memory| memory_item(&memory, parent)),
+        );
+    }
+    if options.network {
+        if let Some(interfaces) = system.ethernet_interfaces().await? {
+            items.extend(
+                interfaces
+                    .members()
+                    .await?
+                    .iter()
+                    .map(|interface| ethernet_interface_item(interface, parent)),
+            );
+        }
+    }
+    Ok(items)
+}
+
+async fn chassis<B: Bmc>(
+    root: &ServiceRoot<B>,
+    options: &Options,
+) -> Result<PartialMembers<Item, B>, Error<B>> {
+    if !options.chassis && !options.network {
+        return Ok(PartialMembers::default());
+    }
+    let Some(collection) = root.chassis().await? else {
+        return Ok(PartialMembers::default());
+    };
+    let chassis = collection.try_members().await;
+    let results = stream::iter(&chassis.members)
+        .map(|chassis| chassis_items(chassis, options))
+        .buffered(root.member_concurrency_limit())
+        .collect::<Vec<_>>()
+        .await;
+    Ok(member_items(chassis, results))
+}
+
+async fn chassis_items<B: Bmc>(
+    chassis: &Chassis<B>,
+    options: &Options,
+) -> Result<Vec<Item>, Error<B>> {
+    let mut items = Vec::new();
+    if options.chassis {
+        items.push(Item::new(Kind::Chassis, chassis, None).with_hardware_id(chassis.hardware_id()));
+    }
+    if options.network {
+        let parent = Some(chassis.odata_id());
+        items.extend(
+            chassis
+                .network_adapters()
+                .await?
+                .into_iter()
+                .flatten()
+                .map(|adapter| {
+                    Item::new(Kind::NetworkAdapter, &adapter, parent)
+                        .with_hardware_id(adapter.hardware_id())
+                }),
+        );
+    }
+    Ok(items)
+}
+
+async fn managers<B: Bmc>(
+    root: &ServiceRoot<B>,
+    options: &Options,
+) -> Result<PartialMembers<Item, B>, Error<B>> {
+    if !options.managers && !options.network {
+        return Ok(PartialMembers::default());
+    }
+    let Some(collection) = root.managers().await? else {
+        return Ok(PartialMembers::default());
+    };
+    let managers = collection.try_members().await;
+    let results = stream::iter(&managers.members)
+        .map(|manager| manager_items(manager, options))
+        .buffered(root.member_concurrency_limit())
+        .collect::<Vec<_>>()
+        .await;
+    Ok(member_items(managers, results))
+}
+
+async fn manager_items<B: Bmc>(
+    manager: &Manager<B>,
+    options: &Options,
+) -> Result<Vec<Item>, Error<B>> {
+    let mut items = Vec::new();
+    if options.managers {
+        let data = manager.raw();
+        items.push(Item {
+            model: text(data.model.as_ref()),
+            version: text(data.firmware_version.as_ref()),
+            ..Item::new(Kind::Manager, manager, None)
+        });
+    }
+    if options.network {
+        if let Some(interfaces) = manager.ethernet_interfaces().await? {
+            let parent = Some(manager.odata_id());
+            items.extend(
+                interfaces
+                    .members()
+                    .await?
+                    .iter()
+                    .map(|interface| ethernet_interface_item(interface, parent)),
+            );
+        }
+    }
+    Ok(items)
+}
+
+async fn firmware<B: Bmc>(
+    root: &ServiceRoot<B>,
+    options: &Options,
+) -> Result<PartialMembers<Item, B>, Error<B>> {
+    if !options.firmware {
+        return Ok(PartialMembers::default());
+    }
+    let Some(update_service) = root.update_service().await? else {
+        return Ok(PartialMembers::default());
+    };
+    let Some(firmware) = update_service.try_firmware_inventories().await? else {
+        return Ok(PartialMembers::default());
+    };
+    let parent = Some(update_service.odata_id());
+    Ok(PartialMembers {
+        members: firmware
+            .members
+            .iter()
+            .map(|firmware| firmware_item(firmware, parent))
+            .collect(),
+        errors: firmware.errors,
+    })
+}
+
+fn processor_item<B: Bmc>(processor: &Processor<B>, parent: Option<&ODataId>) -> Item {
+    let data = processor.raw();
+    Item {
+        manufacturer: text(data.manufacturer.as_ref()),
+        model: text(data.model.as_ref()),
+        part_number: text(data.part_number.as_ref()),
+        serial_number: text(data.serial_number.as_ref()),
+        ..Item::new(Kind::Processor, processor, parent)
+    }
+}
+
+fn memory_item<B: Bmc>(memory: &Memory<B>, parent: Option<&ODataId>) -> Item {
+    let data = memory.raw();
+    Item {
+        manufacturer: text(data.manufacturer.as_ref()),
+        model: text(data.model.as_ref()),
+        part_number: text(data.part_number.as_ref()),
+        serial_number: text(data.serial_number.as_ref()),
+        ..Item::new(Kind::Memory, memory, parent)
+    }
+}
+
+fn ethernet_interface_item<B: Bmc>(
+    interface: &EthernetInterface<B>,
+    parent: Option<&ODataId>,
+) -> Item {
+    Item {
+        mac_address: interface.mac_address().map(|v| v.to_string()),
+        ..Item::new(Kind::EthernetInterface, interface, parent)
+    }
+}
+
+fn firmware_item<B: Bmc>(firmware: &SoftwareInventory<B>, parent: Option<&ODataId>) -> Item {
+    Item {
+        version: firmware.version().map(|v| v.to_string()),
+        ..Item::new(Kind::Firmware, firmware, parent)
+    }
+}
+
+/// Text of nullable string property.
+fn text(v: Option<&Option<String>>) -> Option<String> {
+    v.and_then(Option::as_deref).map(ToString::to_string)
+}
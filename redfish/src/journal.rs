@@ -0,0 +1,702 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Journal of BMC exchanges for debugging.
+//!
+//! [`JournalBmc`] wraps any [`Bmc`] and records every request it sends
+//! together with its outcome as a [`JournalEntry`] to a
+//! [`JournalSink`]. Entries are serializable, so exchanges with a
+//! misbehaving BMC can be captured in production, stored as JSON and
+//! turned into mock expectations for tests:
+//!
+//! ```ignore
+//! let journal = MemoryJournal::new();
+//! let bmc = JournalBmc::new(bmc, journal.clone());
+//! let root = ServiceRoot::new(Arc::new(bmc)).await?;
+//! // ...
+//! let exchanges = serde_json::to_string(&journal.take())?;
+//! ```
+//!
+//! Values of `Password` properties are redacted by default, see
+//! [`Redaction`].
+//!
+//! [`FileJournal`] writes entries to a file as JSON lines, so that
+//! traffic captured against real hardware can be served back by
+//! [`ReplayBmc`](crate::replay::ReplayBmc) in regression tests.
+
+use crate::json_entity::JsonEntity;
+use crate::layer::forward_bmc;
+use futures_util::TryStreamExt as _;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::AsyncTask;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BmcErrorInfo;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::FilterQuery;
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartRequest;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UpdateMethod;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeSet;
+use std::error::Error as StdError;
+use std::fmt;
+use std::fs::File;
+use std::io::Error as IoError;
+use std::io::Write as _;
+use std::mem;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Duration;
+
+/// Replacement of redacted property values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Errors of [`JournalBmc`].
+#[derive(Debug)]
+pub enum JournalError<E> {
+    /// Error of the wrapped BMC.
+    Bmc(E),
+    /// Recorded response doesn't match the requested type.
+    Json(JsonError),
+}
+
+impl<E: fmt::Display> fmt::Display for JournalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bmc(err) => write!(f, "BMC error: {err}"),
+            Self::Json(err) => write!(f, "JSON error: {err}"),
+        }
+    }
+}
+
+impl<E: StdError> StdError for JournalError<E> {}
+
+impl<E: BmcErrorInfo> BmcErrorInfo for JournalError<E> {
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Bmc(err) => err.status_code(),
+            Self::Json(_) => None,
+        }
+    }
+
+    fn is_transport(&self) -> bool {
+        match self {
+            Self::Bmc(err) => err.is_transport(),
+            Self::Json(_) => false,
+        }
+    }
+
+    fn odata_id(&self) -> Option<ODataId> {
+        match self {
+            Self::Bmc(err) => err.odata_id(),
+            Self::Json(_) => None,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Bmc(err) => err.retry_after(),
+            Self::Json(_) => None,
+        }
+    }
+}
+
+/// Outcome of a journaled request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JournalOutcome {
+    /// BMC returned an entity. The body is `None` when bodies are
+    /// redacted.
+    Entity(Option<JsonValue>),
+    /// BMC started a task at the location.
+    Task(ODataId),
+    /// BMC returned no body.
+    Empty,
+    /// BMC opened a stream. Streamed items are not journaled.
+    Stream,
+    /// Request failed with the error.
+    Error(String),
+}
+
+/// Single request of the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// HTTP method of the request, for example, `GET`.
+    pub method: String,
+    /// BMC operation, for example, `expand` or `action`.
+    pub operation: String,
+    /// Resource or URI the request was sent to.
+    pub odata_id: ODataId,
+    /// Request body, if any and not redacted.
+    pub request: Option<JsonValue>,
+    /// Outcome of the request.
+    pub outcome: JournalOutcome,
+}
+
+/// Destination of [`JournalEntry`] records.
+pub trait JournalSink: MaybeSend + MaybeSync {
+    /// Record the entry. Called after every request is completed.
+    fn record(&self, entry: JournalEntry);
+}
+
+impl<F: Fn(JournalEntry) + MaybeSend + MaybeSync> JournalSink for F {
+    fn record(&self, entry: JournalEntry) {
+        self(entry);
+    }
+}
+
+/// Sink that keeps entries in memory. Clones share the entries.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryJournal {
+    entries: Arc<Mutex<Vec<JournalEntry>>>,
+}
+
+impl MemoryJournal {
+    /// Create empty journal.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Entries recorded so far.
+    #[must_use]
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        self.lock().clone()
+    }
+
+    /// Return entries recorded so far and clear the journal.
+    #[must_use]
+    pub fn take(&self) -> Vec<JournalEntry> {
+        mem::take(&mut *self.lock())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<JournalEntry>> {
+        // Journal stays usable even if a recording thread panicked.
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl JournalSink for MemoryJournal {
+    fn record(&self, entry: JournalEntry) {
+        self.lock().push(entry);
+    }
+}
+
+/// Sink that appends entries to a file, one JSON document per line.
+#[derive(Debug)]
+pub struct FileJournal {
+    file: Mutex<File>,
+}
+
+impl FileJournal {
+    /// Create (or truncate) the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be created.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, IoError> {
+        File::create(path).map(|file| Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl JournalSink for FileJournal {
+    fn record(&self, entry: JournalEntry) {
+        let Ok(mut line) = serde_json::to_vec(&entry) else {
+            return;
+        };
+        line.push(b'\n');
+        // Journal is best effort: failure to write an entry must not
+        // fail the request.
+        let result = self
+            .file
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .write_all(&line);
+        #[cfg(feature = "tracing")]
+        if let Err(err) = result {
+            tracing::warn!(error = %err, "failed to write journal entry");
+        }
+        #[cfg(not(feature = "tracing"))]
+        drop(result);
+    }
+}
+
+/// What is removed from journaled bodies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redaction {
+    /// Bodies are journaled as is.
+    None,
+    /// Values of properties with these names are replaced with
+    /// [`REDACTED`] at any depth.
+    Properties(BTreeSet<String>),
+    /// Bodies are not journaled at all.
+    Bodies,
+}
+
+impl Default for Redaction {
+    fn default() -> Self {
+        Self::Properties(BTreeSet::from(["Password".to_string()]))
+    }
+}
+
+impl Redaction {
+    fn apply(&self, mut body: JsonValue) -> Option<JsonValue> {
+        match self {
+            Self::None => Some(body),
+            Self::Properties(names) => {
+                redact_properties(&mut body, names);
+                Some(body)
+            }
+            Self::Bodies => None,
+        }
+    }
+}
+
+fn redact_properties(value: &mut JsonValue, names: &BTreeSet<String>) {
+    match value {
+        JsonValue::Object(map) => {
+            for (name, value) in map.iter_mut() {
+                if names.contains(name) {
+                    *value = JsonValue::String(REDACTED.into());
+                } else {
+                    redact_properties(value, names);
+                }
+            }
+        }
+        JsonValue::Array(items) => {
+            for item in items {
+                redact_properties(item, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// BMC that records every request of the wrapped BMC to a
+/// [`JournalSink`].
+pub struct JournalBmc<B: Bmc> {
+    bmc: Arc<B>,
+    sink: Arc<dyn JournalSink>,
+    redaction: Redaction,
+}
+
+impl<B: Bmc> JournalBmc<B> {
+    /// Wrap `bmc` recording its requests to `sink` with default
+    /// [`Redaction`].
+    pub fn new(bmc: Arc<B>, sink: impl JournalSink + 'static) -> Self {
+        Self {
+            bmc,
+            sink: Arc::new(sink),
+            redaction: Redaction::default(),
+        }
+    }
+
+    /// Use `redaction` for request and response bodies.
+    #[must_use]
+    pub fn with_redaction(self, redaction: Redaction) -> Self {
+        Self { redaction, ..self }
+    }
+
+    /// Wrapped BMC.
+    #[must_use]
+    pub fn inner(&self) -> &B {
+        self.bmc.as_ref()
+    }
+
+    fn record<T>(
+        &self,
+        method: &str,
+        operation: &str,
+        odata_id: &ODataId,
+        request: Option<JsonValue>,
+        result: &Result<T, B::Error>,
+        outcome: impl FnOnce(&T) -> JournalOutcome,
+    ) {
+        self.sink.record(JournalEntry {
+            method: method.into(),
+            operation: operation.into(),
+            odata_id: odata_id.clone(),
+            request: request.and_then(|body| self.redaction.apply(body)),
+            outcome: result
+                .as_ref()
+                .map_or_else(|err| JournalOutcome::Error(err.to_string()), outcome),
+        });
+    }
+
+    fn entity_outcome(&self, entity: &JsonEntity) -> JournalOutcome {
+        JournalOutcome::Entity(self.redaction.apply(entity.payload.clone()))
+    }
+
+    fn modification_outcome(&self, response: &ModificationResponse<JsonValue>) -> JournalOutcome {
+        match response {
+            ModificationResponse::Entity(body) => {
+                JournalOutcome::Entity(self.redaction.apply(body.clone()))
+            }
+            ModificationResponse::Task(AsyncTask { location, .. }) => {
+                JournalOutcome::Task(location.0.clone())
+            }
+            ModificationResponse::Empty => JournalOutcome::Empty,
+        }
+    }
+
+    fn read<T: for<'de> Deserialize<'de>>(
+        &self,
+        operation: &str,
+        id: &ODataId,
+        result: Result<Arc<JsonEntity>, B::Error>,
+    ) -> Result<Arc<T>, JournalError<B::Error>> {
+        self.record("GET", operation, id, None, &result, |entity| {
+            self.entity_outcome(entity)
+        });
+        let entity = result.map_err(JournalError::Bmc)?;
+        T::deserialize(&entity.payload)
+            .map(Arc::new)
+            .map_err(JournalError::Json)
+    }
+
+    fn write<V: Serialize, R: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        operation: &str,
+        id: &ODataId,
+        request: Option<&V>,
+        result: Result<ModificationResponse<JsonValue>, B::Error>,
+    ) -> Result<ModificationResponse<R>, JournalError<B::Error>> {
+        let request = request.and_then(|v| serde_json::to_value(v).ok());
+        self.record(method, operation, id, request, &result, |response| {
+            self.modification_outcome(response)
+        });
+        result
+            .map_err(JournalError::Bmc)?
+            .try_map_entity(|body| R::deserialize(body).map_err(JournalError::Json))
+    }
+}
+
+impl<B: Bmc> fmt::Debug for JournalBmc<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JournalBmc")
+            .field("redaction", &self.redaction)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: Bmc> Bmc for JournalBmc<B>
+where
+    B::Error: 'static,
+{
+    type Error = JournalError<B::Error>;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let result = self.bmc.expand::<JsonEntity>(id, query).await;
+        self.read("expand", id, result)
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        let result = self.bmc.get::<JsonEntity>(id).await;
+        self.read("get", id, result)
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        let result = self.bmc.get_if_none_match::<JsonEntity>(id, etag).await;
+        self.record("GET", "get_if_none_match", id, None, &result, |entity| {
+            entity
+                .as_deref()
+                .map_or(JournalOutcome::Empty, |entity| self.entity_outcome(entity))
+        });
+        result
+            .map_err(JournalError::Bmc)?
+            .map(|entity| {
+                T::deserialize(&entity.payload)
+                    .map(Arc::new)
+                    .map_err(JournalError::Json)
+            })
+            .transpose()
+    }
+
+    async fn head(&self, id: &ODataId) -> Result<(), Self::Error> {
+        let result = self.bmc.head(id).await;
+        self.record("HEAD", "head", id, None, &result, |()| {
+            JournalOutcome::Empty
+        });
+        result.map_err(JournalError::Bmc)
+    }
+
+    forward_bmc!(JournalError::Bmc; allowed_methods);
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let result = self.bmc.filter::<JsonEntity>(id, query).await;
+        self.read("filter", id, result)
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let result = self.bmc.page::<JsonEntity>(id, query).await;
+        self.read("page", id, result)
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: SelectQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let result = self.bmc.select::<JsonEntity>(id, query).await;
+        self.read("select", id, result)
+    }
+
+    async fn create<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let result = self.bmc.create::<V, JsonValue>(id, query).await;
+        self.write("POST", "create", id, Some(query), result)
+    }
+
+    async fn create_session<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<SessionCreateResponse<R>, Self::Error> {
+        let result = self.bmc.create_session::<V, JsonValue>(id, query).await;
+        // Credentials are always redacted: the journal must not be
+        // usable to log in to the BMC. The session token is never
+        // recorded.
+        let request = serde_json::to_value(query).ok().map(|mut body| {
+            redact_properties(&mut body, &BTreeSet::from(["Password".to_string()]));
+            body
+        });
+        self.record("POST", "create_session", id, request, &result, |response| {
+            JournalOutcome::Entity(self.redaction.apply(response.entity.clone()))
+        });
+        let response = result.map_err(JournalError::Bmc)?;
+        Ok(SessionCreateResponse {
+            entity: R::deserialize(response.entity).map_err(JournalError::Json)?,
+            auth_token: response.auth_token,
+            location: response.location,
+        })
+    }
+
+    async fn update<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let result = self.bmc.update::<V, JsonValue>(id, etag, update).await;
+        self.write("PATCH", "update", id, Some(update), result)
+    }
+
+    async fn update_with_method<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+        method: UpdateMethod,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let result = self
+            .bmc
+            .update_with_method::<V, JsonValue>(id, etag, update, method)
+            .await;
+        let http_method = match method {
+            UpdateMethod::Patch => "PATCH",
+            UpdateMethod::Post | UpdateMethod::PostWithMethodOverride => "POST",
+        };
+        self.write(http_method, "update", id, Some(update), result)
+    }
+
+    async fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let result = self
+            .bmc
+            .delete::<JsonEntity>(id, etag)
+            .await
+            .map(|response| response.map_entity(|entity| entity.payload));
+        self.write::<(), R>("DELETE", "delete", id, None, result)
+    }
+
+    async fn action<
+        T: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        action: &Action<T, R>,
+        params: &T,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        // The same action returning untyped JSON. Action can only be
+        // built by deserialization.
+        let untyped = Action::<T, JsonValue>::deserialize(serde_json::json!({
+            "target": action.target.as_str(),
+            "@Redfish.ActionInfo": action.action_info,
+        }))
+        .map_err(JournalError::Json)?;
+        let result = self.bmc.action(&untyped, params).await;
+        let target = ODataId::from(action.target.to_string());
+        self.write("POST", "action", &target, Some(params), result)
+    }
+
+    async fn multipart_update<U, V, R>(
+        &self,
+        uri: &str,
+        request: MultipartUpdateRequest<'_, U, V>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        V: MaybeSend + MaybeSync + Serialize,
+    {
+        let result = self.bmc.multipart_update(uri, request).await;
+        let uri = ODataId::from(uri.to_string());
+        self.write::<(), R>("POST", "multipart_update", &uri, None, result)
+    }
+
+    async fn multipart_upload<R>(
+        &self,
+        uri: &str,
+        request: MultipartRequest,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    {
+        let result = self.bmc.multipart_upload(uri, request).await;
+        let uri = ODataId::from(uri.to_string());
+        self.write::<(), R>("POST", "multipart_upload", &uri, None, result)
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn http_push_uri_update<U, R>(
+        &self,
+        uri: &str,
+        request: HttpPushUriUpdateRequest<U>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    {
+        let result = self.bmc.http_push_uri_update(uri, request).await;
+        let uri = ODataId::from(uri.to_string());
+        self.write::<(), R>("POST", "http_push_uri_update", &uri, None, result)
+    }
+
+    async fn stream<T: Sized + for<'de> Deserialize<'de> + MaybeSend + 'static>(
+        &self,
+        uri: &str,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        let result = self.bmc.stream::<T>(uri).await;
+        let uri = ODataId::from(uri.to_string());
+        self.record("GET", "stream", &uri, None, &result, |_| {
+            JournalOutcome::Stream
+        });
+        let stream = result.map_err(JournalError::Bmc)?;
+        Ok(Box::pin(stream.map_err(JournalError::Bmc)))
+    }
+
+    async fn download(&self, uri: &str) -> Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        let result = self.bmc.download(uri).await;
+        let id = ODataId::from(uri.to_string());
+        self.record("GET", "download", &id, None, &result, |_| {
+            JournalOutcome::Stream
+        });
+        let stream = result.map_err(JournalError::Bmc)?;
+        Ok(Box::pin(stream.map_err(JournalError::Bmc)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Redaction;
+    use super::REDACTED;
+    use serde_json::json;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn redacts_nested_properties() {
+        let body = json!({
+            "UserName": "admin",
+            "Password": "secret",
+            "Members": [{ "Password": "other" }],
+        });
+        let redacted = Redaction::default().apply(body.clone());
+        assert_eq!(
+            redacted,
+            Some(json!({
+                "UserName": "admin",
+                "Password": REDACTED,
+                "Members": [{ "Password": REDACTED }],
+            }))
+        );
+        assert_eq!(Redaction::None.apply(body.clone()), Some(body.clone()));
+        assert_eq!(Redaction::Bodies.apply(body), None);
+        let names = BTreeSet::from(["UserName".to_string()]);
+        assert_eq!(
+            Redaction::Properties(names).apply(json!({ "UserName": "admin" })),
+            Some(json!({ "UserName": REDACTED }))
+        );
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Untyped entity for BMC decorators that inspect response bodies
+//! before they are converted to the requested type.
+
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde_json::Value as JsonValue;
+
+const NEXT_LINK: &str = "Members@odata.nextLink";
+
+/// Entity with JSON payload. Pages of collections are appended like
+/// pages of generated collection types, so the payload contains all
+/// members.
+pub struct JsonEntity {
+    odata_id: ODataId,
+    etag: Option<ODataETag>,
+    next_link: Option<ODataId>,
+    pub payload: JsonValue,
+}
+
+impl EntityTypeRef for JsonEntity {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        self.etag.as_ref()
+    }
+
+    fn next_page_link(&self) -> Option<&ODataId> {
+        self.next_link.as_ref()
+    }
+
+    fn append_page(&mut self, mut page: Self) {
+        if let (Some(JsonValue::Array(members)), Some(JsonValue::Array(more))) = (
+            self.payload.get_mut("Members"),
+            page.payload.get_mut("Members"),
+        ) {
+            members.append(more);
+        }
+        if let JsonValue::Object(map) = &mut self.payload {
+            map.remove(NEXT_LINK);
+        }
+        self.next_link = page.next_link;
+    }
+}
+
+impl Expandable for JsonEntity {}
+
+impl<'de> Deserialize<'de> for JsonEntity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let payload = JsonValue::deserialize(deserializer)?;
+        let string = |name| {
+            payload
+                .get(name)
+                .and_then(JsonValue::as_str)
+                .map(ToString::to_string)
+        };
+        Ok(Self {
+            odata_id: string("@odata.id").unwrap_or_default().into(),
+            etag: string("@odata.etag").map(Into::into),
+            next_link: string(NEXT_LINK).map(Into::into),
+            payload,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityTypeRef as _;
+    use super::JsonEntity;
+    use serde::Deserialize as _;
+    use serde_json::json;
+    use serde_json::Error as JsonError;
+
+    #[test]
+    fn appends_collection_pages() -> Result<(), JsonError> {
+        let mut first = JsonEntity::deserialize(json!({
+            "@odata.id": "/redfish/v1/Chassis",
+            "Members": [{ "@odata.id": "/redfish/v1/Chassis/1" }],
+            "Members@odata.nextLink": "/redfish/v1/Chassis?$skip=1",
+        }))?;
+        assert!(first.next_page_link().is_some());
+        let last = JsonEntity::deserialize(json!({
+            "@odata.id": "/redfish/v1/Chassis",
+            "Members": [{ "@odata.id": "/redfish/v1/Chassis/2" }],
+        }))?;
+        first.append_page(last);
+        assert!(first.next_page_link().is_none());
+        assert_eq!(
+            first.payload,
+            json!({
+                "@odata.id": "/redfish/v1/Chassis",
+                "Members": [
+                    { "@odata.id": "/redfish/v1/Chassis/1" },
+                    { "@odata.id": "/redfish/v1/Chassis/2" },
+                ],
+            })
+        );
+        Ok(())
+    }
+}
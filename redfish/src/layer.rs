@@ -0,0 +1,672 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Middleware of BMC requests.
+//!
+//! A [`BmcLayer`] sees every request sent to a [`Bmc`] and decides
+//! how it is sent: it can limit its time, retry it or record it.
+//! [`Layered`] applies a layer to any [`Bmc`], so a layer implements
+//! [`BmcLayer::call`] instead of every method of the trait:
+//!
+//! ```ignore
+//! let bmc = Layered::new(bmc, Deadlines::new(Sleep::new(tokio::time::sleep)));
+//! let root = ServiceRoot::new(Arc::new(bmc)).await?;
+//! ```
+
+use futures_util::TryStreamExt as _;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::FilterQuery;
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartRequest;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UpdateMethod;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use std::any::type_name;
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+use std::future::Ready;
+use std::sync::Arc;
+
+/// Kind of a BMC request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestKind {
+    /// Request that doesn't modify the service: GET, HEAD or opening
+    /// of a stream.
+    Read,
+    /// Request that modifies the service: create, update, delete or
+    /// action.
+    Write,
+    /// Upload of a streamed body. Uploads are sent once.
+    Upload,
+}
+
+/// Request sent through a [`BmcLayer`].
+#[derive(Debug, Clone, Copy)]
+pub struct Request<'a> {
+    /// Name of the BMC operation, for example, `get` or `update`.
+    pub operation: &'static str,
+    /// Kind of the request.
+    pub kind: RequestKind,
+    /// Resource or URI the request is sent to.
+    pub odata_id: &'a ODataId,
+    /// Name of the requested resource type without module path, for
+    /// example, `Chassis`. Requests without entity type (HEAD,
+    /// downloads and uploads) use the operation name.
+    pub resource: &'static str,
+}
+
+/// Middleware of requests sent to a BMC with error type `E`.
+///
+/// Requests of every [`Bmc`] method are passed to the layer by
+/// [`Layered`].
+pub trait BmcLayer<E>: MaybeSend + MaybeSync {
+    /// Error of requests sent through the layer.
+    type Error: StdError + MaybeSend + MaybeSync;
+
+    /// Convert error of the wrapped BMC, for example, error of an
+    /// item of a stream.
+    fn bmc_error(err: E) -> Self::Error;
+
+    /// Send `request`. `send` is the request to the wrapped BMC.
+    fn call<T, F>(
+        &self,
+        request: &Request<'_>,
+        send: F,
+    ) -> impl Future<Output = Result<T, Self::Error>> + MaybeSend
+    where
+        T: MaybeSend,
+        F: Future<Output = Result<T, E>> + MaybeSend;
+
+    /// Decide whether `request` that failed with `err` is sent again.
+    /// `attempt` is the number of the failed attempt starting from one.
+    /// The request is sent again after the returned future is
+    /// completed. Uploads are never sent again. By default requests
+    /// are not repeated.
+    fn retry(
+        &self,
+        request: &Request<'_>,
+        attempt: u32,
+        err: &Self::Error,
+    ) -> Option<impl Future<Output = ()> + MaybeSend> {
+        let _ = (request, attempt, err);
+        None::<Ready<()>>
+    }
+}
+
+/// BMC that sends requests of the wrapped BMC through a
+/// [`BmcLayer`].
+pub struct Layered<L, B: Bmc> {
+    bmc: Arc<B>,
+    layer: L,
+}
+
+impl<L, B: Bmc> Layered<L, B> {
+    /// Wrap `bmc` with `layer`.
+    pub fn new(bmc: Arc<B>, layer: impl Into<L>) -> Self {
+        Self {
+            bmc,
+            layer: layer.into(),
+        }
+    }
+
+    /// Wrapped BMC.
+    #[must_use]
+    pub fn inner(&self) -> &B {
+        self.bmc.as_ref()
+    }
+
+    /// Layer requests are sent through.
+    #[must_use]
+    pub const fn layer(&self) -> &L {
+        &self.layer
+    }
+
+    /// Wrapped BMC and the layer.
+    #[must_use]
+    pub fn into_parts(self) -> (Arc<B>, L) {
+        (self.bmc, self.layer)
+    }
+}
+
+impl<L: fmt::Debug, B: Bmc> fmt::Debug for Layered<L, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Layered")
+            .field("layer", &self.layer)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Request<'a> {
+    const fn new(
+        operation: &'static str,
+        kind: RequestKind,
+        odata_id: &'a ODataId,
+        resource: &'static str,
+    ) -> Self {
+        Self {
+            operation,
+            kind,
+            odata_id,
+            resource,
+        }
+    }
+}
+
+/// Name of the resource type `T` without module path, for example,
+/// `Chassis`.
+pub(crate) fn resource_type<T: ?Sized>() -> &'static str {
+    let name = type_name::<T>();
+    let name = name.split_once('<').map_or(name, |(name, _)| name);
+    name.rsplit_once("::").map_or(name, |(_, name)| name)
+}
+
+/// Send `$send` through the layer of `$self` and send it again while
+/// the layer asks to retry.
+macro_rules! repeatable {
+    ($self:ident, $request:expr, $send:expr) => {{
+        let request = $request;
+        let mut attempt = 1;
+        loop {
+            match $self.layer.call(&request, $send).await {
+                Err(err) => {
+                    let retried = match $self.layer.retry(&request, attempt, &err) {
+                        Some(delay) => {
+                            delay.await;
+                            true
+                        }
+                        None => false,
+                    };
+                    if !retried {
+                        break Err(err);
+                    }
+                    attempt += 1;
+                }
+                result => break result,
+            }
+        }
+    }};
+}
+
+// Streams of the wrapped BMC are boxed with converted errors.
+impl<L, B> Bmc for Layered<L, B>
+where
+    B: Bmc,
+    B::Error: 'static,
+    L: BmcLayer<B::Error> + 'static,
+    L::Error: 'static,
+{
+    type Error = L::Error;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let request = Request::new("expand", RequestKind::Read, id, resource_type::<T>());
+        repeatable!(self, request, self.bmc.expand(id, query.clone()))
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        let request = Request::new("get", RequestKind::Read, id, resource_type::<T>());
+        repeatable!(self, request, self.bmc.get(id))
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        let request = Request::new(
+            "get_if_none_match",
+            RequestKind::Read,
+            id,
+            resource_type::<T>(),
+        );
+        repeatable!(self, request, self.bmc.get_if_none_match(id, etag))
+    }
+
+    async fn head(&self, id: &ODataId) -> Result<(), Self::Error> {
+        let request = Request::new("head", RequestKind::Read, id, "head");
+        repeatable!(self, request, self.bmc.head(id))
+    }
+
+    fn allowed_methods(&self, id: &ODataId) -> Option<AllowedMethods> {
+        self.bmc.allowed_methods(id)
+    }
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let request = Request::new("filter", RequestKind::Read, id, resource_type::<T>());
+        repeatable!(self, request, self.bmc.filter(id, query.clone()))
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let request = Request::new("page", RequestKind::Read, id, resource_type::<T>());
+        repeatable!(self, request, self.bmc.page(id, query))
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: SelectQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let request = Request::new("select", RequestKind::Read, id, resource_type::<T>());
+        repeatable!(self, request, self.bmc.select(id, query.clone()))
+    }
+
+    async fn create<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let request = Request::new("create", RequestKind::Write, id, resource_type::<R>());
+        repeatable!(self, request, self.bmc.create(id, query))
+    }
+
+    async fn create_session<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<SessionCreateResponse<R>, Self::Error> {
+        let request = Request::new(
+            "create_session",
+            RequestKind::Write,
+            id,
+            resource_type::<R>(),
+        );
+        repeatable!(self, request, self.bmc.create_session(id, query))
+    }
+
+    async fn update<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let request = Request::new("update", RequestKind::Write, id, resource_type::<R>());
+        repeatable!(self, request, self.bmc.update(id, etag, update))
+    }
+
+    async fn update_with_method<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+        method: UpdateMethod,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let request = Request::new("update", RequestKind::Write, id, resource_type::<R>());
+        repeatable!(
+            self,
+            request,
+            self.bmc.update_with_method(id, etag, update, method)
+        )
+    }
+
+    async fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let request = Request::new("delete", RequestKind::Write, id, resource_type::<R>());
+        repeatable!(self, request, self.bmc.delete(id, etag))
+    }
+
+    async fn action<
+        T: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        action: &Action<T, R>,
+        params: &T,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let target = ODataId::from(action.target.to_string());
+        let request = Request::new("action", RequestKind::Write, &target, resource_type::<R>());
+        repeatable!(self, request, self.bmc.action(action, params))
+    }
+
+    async fn multipart_update<U, V, R>(
+        &self,
+        uri: &str,
+        request: MultipartUpdateRequest<'_, U, V>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        V: MaybeSend + MaybeSync + Serialize,
+    {
+        let id = ODataId::from(uri.to_string());
+        let upload = Request::new(
+            "multipart_update",
+            RequestKind::Upload,
+            &id,
+            "multipart_update",
+        );
+        self.layer
+            .call(&upload, self.bmc.multipart_update(uri, request))
+            .await
+    }
+
+    async fn multipart_upload<R>(
+        &self,
+        uri: &str,
+        request: MultipartRequest,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    {
+        let id = ODataId::from(uri.to_string());
+        let upload = Request::new(
+            "multipart_upload",
+            RequestKind::Upload,
+            &id,
+            "multipart_upload",
+        );
+        self.layer
+            .call(&upload, self.bmc.multipart_upload(uri, request))
+            .await
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn http_push_uri_update<U, R>(
+        &self,
+        uri: &str,
+        request: HttpPushUriUpdateRequest<U>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    {
+        let id = ODataId::from(uri.to_string());
+        let upload = Request::new(
+            "http_push_uri_update",
+            RequestKind::Upload,
+            &id,
+            "http_push_uri_update",
+        );
+        self.layer
+            .call(&upload, self.bmc.http_push_uri_update(uri, request))
+            .await
+    }
+
+    async fn stream<T: Sized + for<'de> Deserialize<'de> + MaybeSend + 'static>(
+        &self,
+        uri: &str,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        let id = ODataId::from(uri.to_string());
+        let request = Request::new("stream", RequestKind::Read, &id, resource_type::<T>());
+        let stream = repeatable!(self, request, self.bmc.stream::<T>(uri))?;
+        Ok(Box::pin(stream.map_err(L::bmc_error)))
+    }
+
+    async fn download(&self, uri: &str) -> Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        let id = ODataId::from(uri.to_string());
+        let request = Request::new("download", RequestKind::Read, &id, "download");
+        let stream = repeatable!(self, request, self.bmc.download(uri))?;
+        Ok(Box::pin(stream.map_err(L::bmc_error)))
+    }
+}
+
+/// Implements methods of [`Bmc`] that send requests to the wrapped
+/// BMC (`self.bmc`) as is, converting its errors with `$map`. Used by
+/// decorators that handle only some of the requests, for example,
+/// only reads.
+macro_rules! forward_bmc {
+    ($map:path; $($method:ident),+ $(,)?) => {
+        $(forward_bmc!(@method $method $map);)+
+    };
+    (@method expand $map:path) => {
+        async fn expand<T: Expandable>(
+            &self,
+            id: &ODataId,
+            query: ExpandQuery,
+        ) -> Result<Arc<T>, Self::Error> {
+            self.bmc.expand(id, query).await.map_err($map)
+        }
+    };
+    (@method filter $map:path) => {
+        async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+            &self,
+            id: &ODataId,
+            query: FilterQuery,
+        ) -> Result<Arc<T>, Self::Error> {
+            self.bmc.filter(id, query).await.map_err($map)
+        }
+    };
+    (@method page $map:path) => {
+        async fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+            &self,
+            id: &ODataId,
+            query: PageQuery,
+        ) -> Result<Arc<T>, Self::Error> {
+            self.bmc.page(id, query).await.map_err($map)
+        }
+    };
+    (@method select $map:path) => {
+        async fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+            &self,
+            id: &ODataId,
+            query: SelectQuery,
+        ) -> Result<Arc<T>, Self::Error> {
+            self.bmc.select(id, query).await.map_err($map)
+        }
+    };
+    (@method get_if_none_match $map:path) => {
+        async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+            &self,
+            id: &ODataId,
+            etag: &ODataETag,
+        ) -> Result<Option<Arc<T>>, Self::Error> {
+            self.bmc.get_if_none_match(id, etag).await.map_err($map)
+        }
+    };
+    (@method head $map:path) => {
+        async fn head(&self, id: &ODataId) -> Result<(), Self::Error> {
+            self.bmc.head(id).await.map_err($map)
+        }
+    };
+    (@method allowed_methods $map:path) => {
+        fn allowed_methods(&self, id: &ODataId) -> Option<AllowedMethods> {
+            self.bmc.allowed_methods(id)
+        }
+    };
+    (@method create $map:path) => {
+        async fn create<
+            V: MaybeSend + MaybeSync + Serialize,
+            R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        >(
+            &self,
+            id: &ODataId,
+            query: &V,
+        ) -> Result<ModificationResponse<R>, Self::Error> {
+            self.bmc.create(id, query).await.map_err($map)
+        }
+    };
+    (@method create_session $map:path) => {
+        async fn create_session<
+            V: MaybeSend + MaybeSync + Serialize,
+            R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        >(
+            &self,
+            id: &ODataId,
+            query: &V,
+        ) -> Result<SessionCreateResponse<R>, Self::Error> {
+            self.bmc.create_session(id, query).await.map_err($map)
+        }
+    };
+    (@method update $map:path) => {
+        async fn update<
+            V: MaybeSync + MaybeSend + Serialize,
+            R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+        >(
+            &self,
+            id: &ODataId,
+            etag: Option<&ODataETag>,
+            update: &V,
+        ) -> Result<ModificationResponse<R>, Self::Error> {
+            self.bmc.update(id, etag, update).await.map_err($map)
+        }
+    };
+    (@method update_with_method $map:path) => {
+        async fn update_with_method<
+            V: MaybeSync + MaybeSend + Serialize,
+            R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+        >(
+            &self,
+            id: &ODataId,
+            etag: Option<&ODataETag>,
+            update: &V,
+            method: UpdateMethod,
+        ) -> Result<ModificationResponse<R>, Self::Error> {
+            self.bmc
+                .update_with_method(id, etag, update, method)
+                .await
+                .map_err($map)
+        }
+    };
+    (@method delete $map:path) => {
+        async fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
+            &self,
+            id: &ODataId,
+            etag: Option<&ODataETag>,
+        ) -> Result<ModificationResponse<R>, Self::Error> {
+            self.bmc.delete(id, etag).await.map_err($map)
+        }
+    };
+    (@method action $map:path) => {
+        async fn action<
+            T: MaybeSend + MaybeSync + Serialize,
+            R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+        >(
+            &self,
+            action: &Action<T, R>,
+            params: &T,
+        ) -> Result<ModificationResponse<R>, Self::Error> {
+            self.bmc.action(action, params).await.map_err($map)
+        }
+    };
+    (@method multipart_update $map:path) => {
+        async fn multipart_update<U, V, R>(
+            &self,
+            uri: &str,
+            request: MultipartUpdateRequest<'_, U, V>,
+        ) -> Result<ModificationResponse<R>, Self::Error>
+        where
+            U: UploadReader,
+            R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+            V: MaybeSend + MaybeSync + Serialize,
+        {
+            self.bmc.multipart_update(uri, request).await.map_err($map)
+        }
+    };
+    (@method multipart_upload $map:path) => {
+        async fn multipart_upload<R>(
+            &self,
+            uri: &str,
+            request: MultipartRequest,
+        ) -> Result<ModificationResponse<R>, Self::Error>
+        where
+            R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        {
+            self.bmc.multipart_upload(uri, request).await.map_err($map)
+        }
+    };
+    (@method http_push_uri_update $map:path) => {
+        #[cfg(feature = "update-service-deprecated")]
+        async fn http_push_uri_update<U, R>(
+            &self,
+            uri: &str,
+            request: HttpPushUriUpdateRequest<U>,
+        ) -> Result<ModificationResponse<R>, Self::Error>
+        where
+            U: UploadReader,
+            R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        {
+            self.bmc.http_push_uri_update(uri, request).await.map_err($map)
+        }
+    };
+    (@method stream $map:path) => {
+        async fn stream<T: Sized + for<'de> Deserialize<'de> + MaybeSend + 'static>(
+            &self,
+            uri: &str,
+        ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+            let stream = self.bmc.stream::<T>(uri).await.map_err($map)?;
+            Ok(Box::pin(stream.map_err($map)))
+        }
+    };
+    (@method download $map:path) => {
+        async fn download(
+            &self,
+            uri: &str,
+        ) -> Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+            let stream = self.bmc.download(uri).await.map_err($map)?;
+            Ok(Box::pin(stream.map_err($map)))
+        }
+    };
+}
+
+pub(crate) use forward_bmc;
+
+#[cfg(test)]
+mod tests {
+    use super::resource_type;
+    use std::time::Duration;
+
+    #[test]
+    fn resource_type_is_short_name() {
+        assert_eq!(resource_type::<Duration>(), "Duration");
+        assert_eq!(resource_type::<Vec<Duration>>(), "Vec");
+        assert_eq!(resource_type::<u32>(), "u32");
+    }
+}
@@ -68,6 +68,8 @@
 )]
 #![deny(missing_docs)]
 #![allow(clippy::doc_markdown)]
+// Futures are intentionally not `Send` with the `unsend` feature.
+#![cfg_attr(feature = "unsend", allow(clippy::future_not_send))]
 
 /// Errors defined by the crate.
 pub mod error;
@@ -78,6 +80,51 @@ pub mod service_root;
 /// Redfish resource common functions.
 pub mod resource;
 
+/// Uniform access to Redfish collections.
+pub mod collection;
+
+/// Action parameter descriptors (`ActionInfo`).
+pub mod action_info;
+
+/// Middleware of BMC requests.
+pub mod layer;
+
+/// Per-request deadlines and cooperative cancellation.
+pub mod deadline;
+
+/// Retries of failed requests.
+pub mod retry;
+
+/// Request and response hooks.
+pub mod intercept;
+
+/// Limit of concurrent requests.
+pub mod limit;
+
+/// Conditional GET caching keyed by `ETag`.
+pub mod cache;
+
+/// Runtime-agnostic sleep.
+pub mod sleep;
+
+/// Client-side request metrics.
+pub mod metrics;
+
+/// Journal of BMC exchanges for debugging.
+pub mod journal;
+
+/// Replay of recorded BMC exchanges.
+pub mod replay;
+
+/// Validation of `@odata.type` of responses.
+pub mod type_check;
+
+/// Capability matrix of the BMC.
+pub mod capabilities;
+
+/// Registry of BMC platform classes and their quirks.
+pub mod quirks;
+
 /// Hardware identifier (Manufacturer + Model + Part Number + Serial
 /// Number).
 pub mod hardware_id;
@@ -85,9 +132,15 @@ pub mod hardware_id;
 /// MAC addresses returned by the crate.
 pub mod mac_address;
 
+/// Commonly used types and traits.
+pub mod prelude;
+
 /// Accounts Service.
 #[cfg(feature = "accounts")]
 pub mod account;
+/// Certificate Service.
+#[cfg(feature = "certificates")]
+pub mod certificate_service;
 /// Chassis.
 #[cfg(feature = "chassis")]
 pub mod chassis;
@@ -139,6 +192,10 @@ pub mod task_service;
 #[cfg(feature = "telemetry-service")]
 pub mod telemetry_service;
 
+/// Inventory snapshot of the whole service tree.
+#[cfg(feature = "inventory")]
+pub mod inventory;
+
 /// Individual OEM extensions support.
 #[cfg(feature = "oem")]
 pub mod oem;
@@ -161,6 +218,9 @@ pub(crate) mod bmc;
 /// BMC quirks support.
 pub(crate) mod bmc_quirks;
 
+/// Untyped JSON entity used by BMC decorators.
+pub(crate) mod json_entity;
+
 #[doc(inline)]
 pub use nv_redfish_core as core;
 
@@ -171,16 +231,32 @@ pub use nv_redfish_bmc_http as bmc_http;
 #[doc(inline)]
 pub use compiled_schema::redfish as schema;
 
+#[doc(inline)]
+pub use capabilities::Capabilities;
+#[doc(inline)]
+pub use collection::PartialMembers;
+#[doc(inline)]
+pub use collection::RedfishCollection;
 #[doc(inline)]
 pub use error::Error;
 #[doc(inline)]
+pub use error::ErrorKind;
+#[doc(inline)]
 pub use nv_redfish_core::Bmc;
 #[doc(inline)]
 pub use protocol_features::ProtocolFeatures;
 #[doc(inline)]
+pub use resource::DeletableResource;
+#[doc(inline)]
+pub use resource::RefreshableResource;
+#[doc(inline)]
 pub use resource::Resource;
 #[doc(inline)]
+pub use resource::SupportedMethods;
+#[doc(inline)]
 pub use service_root::ServiceRoot;
+#[doc(inline)]
+pub use sleep::Sleep;
 
 #[doc(inline)]
 #[cfg(feature = "resource-status")]
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Limit of concurrent requests.
+//!
+//! Some BMCs misbehave when they receive more than a few concurrent
+//! requests. [`LimitedBmc`] wraps any [`Bmc`] and keeps the number
+//! of its requests in flight within [`ConcurrencyLimit`], however
+//! many requests are issued by parallel fetches of the client:
+//!
+//! ```ignore
+//! let root = ServiceRoot::builder(bmc)
+//!     .layer(ConcurrencyLimit::new(4))
+//!     .build()
+//!     .await?;
+//! ```
+//!
+//! A request holds its slot until the whole response is received and
+//! decoded. Requests waiting for a slot are served in order.
+//!
+//! [`Bmc`]: nv_redfish_core::Bmc
+
+use crate::layer::BmcLayer;
+use crate::layer::Layered;
+use crate::layer::Request;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+/// Maximum number of requests in flight. Clones share the limit, so
+/// one limit can be applied to several BMCs.
+#[derive(Clone)]
+pub struct ConcurrencyLimit {
+    state: Arc<LimitState>,
+}
+
+struct LimitState {
+    limit: usize,
+    slots: Mutex<Slots>,
+}
+
+#[derive(Default)]
+struct Slots {
+    in_flight: usize,
+    next_id: u64,
+    /// Requests waiting for a slot in order of arrival.
+    waiters: VecDeque<(u64, Waker)>,
+    /// Waiters the slot of a finished request was handed over to.
+    granted: HashSet<u64>,
+}
+
+impl LimitState {
+    fn slots(&self) -> MutexGuard<'_, Slots> {
+        // Slots stay consistent even if a waker panicked.
+        self.slots.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Hand the slot of a finished request over to the first waiter or
+    /// free it if nobody waits.
+    fn release(&self) {
+        let mut slots = self.slots();
+        if let Some((id, waker)) = slots.waiters.pop_front() {
+            slots.granted.insert(id);
+            drop(slots);
+            waker.wake();
+        } else {
+            slots.in_flight -= 1;
+        }
+    }
+}
+
+impl ConcurrencyLimit {
+    /// Allow at most `limit` requests in flight. Limit `0` is treated
+    /// as `1`, so requests are never blocked forever.
+    #[must_use]
+    pub fn new(limit: usize) -> Self {
+        Self {
+            state: Arc::new(LimitState {
+                limit: limit.max(1),
+                slots: Mutex::new(Slots::default()),
+            }),
+        }
+    }
+
+    /// Maximum number of requests in flight.
+    #[must_use]
+    pub fn limit(&self) -> usize {
+        self.state.limit
+    }
+
+    /// Number of requests in flight.
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.state.slots().in_flight
+    }
+
+    /// Wait for a free slot. The slot is released when the returned
+    /// permit is dropped.
+    fn acquire(&self) -> Acquire<'_> {
+        Acquire {
+            state: &self.state,
+            id: None,
+        }
+    }
+}
+
+impl fmt::Debug for ConcurrencyLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrencyLimit")
+            .field("limit", &self.limit())
+            .field("in_flight", &self.in_flight())
+            .finish()
+    }
+}
+
+/// Slot of a request in flight.
+struct Permit<'a> {
+    state: &'a LimitState,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        self.state.release();
+    }
+}
+
+/// Waiter for a slot. It leaves the queue on drop; a slot that was
+/// already handed over to it is passed on.
+struct Acquire<'a> {
+    state: &'a LimitState,
+    id: Option<u64>,
+}
+
+impl<'a> Future for Acquire<'a> {
+    type Output = Permit<'a>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Permit<'a>> {
+        let state = self.state;
+        let mut slots = state.slots();
+        if let Some(id) = self.id {
+            if slots.granted.remove(&id) {
+                self.id = None;
+                return Poll::Ready(Permit { state });
+            }
+            if let Some((_, waker)) = slots.waiters.iter_mut().find(|(waiter, _)| *waiter == id) {
+                waker.clone_from(cx.waker());
+            }
+            return Poll::Pending;
+        }
+        // Slots are handed over while requests wait, so a free slot
+        // means that the queue is empty.
+        if slots.in_flight < state.limit {
+            slots.in_flight += 1;
+            return Poll::Ready(Permit { state });
+        }
+        let id = slots.next_id;
+        slots.next_id += 1;
+        slots.waiters.push_back((id, cx.waker().clone()));
+        drop(slots);
+        self.id = Some(id);
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            let mut slots = self.state.slots();
+            if slots.granted.remove(&id) {
+                drop(slots);
+                self.state.release();
+            } else {
+                slots.waiters.retain(|(waiter, _)| *waiter != id);
+            }
+        }
+    }
+}
+
+impl<E: StdError + MaybeSend + MaybeSync> BmcLayer<E> for ConcurrencyLimit {
+    type Error = E;
+
+    fn bmc_error(err: E) -> Self::Error {
+        err
+    }
+
+    async fn call<T, F>(&self, _: &Request<'_>, send: F) -> Result<T, Self::Error>
+    where
+        T: MaybeSend,
+        F: Future<Output = Result<T, E>> + MaybeSend,
+    {
+        let _permit = self.acquire().await;
+        send.await
+    }
+}
+
+/// BMC that limits the number of requests in flight of the wrapped
+/// BMC according to [`ConcurrencyLimit`].
+///
+/// Only opening of `stream` and `download` is limited; items are
+/// delivered without holding a slot.
+pub type LimitedBmc<B> = Layered<ConcurrencyLimit, B>;
+
+#[cfg(test)]
+mod tests {
+    use super::ConcurrencyLimit;
+    use futures_util::FutureExt as _;
+
+    #[test]
+    fn zero_limit_allows_one_request() {
+        let limit = ConcurrencyLimit::new(0);
+        assert_eq!(limit.limit(), 1);
+        let permit = limit.acquire().now_or_never();
+        assert!(permit.is_some());
+        assert!(limit.acquire().now_or_never().is_none());
+        drop(permit);
+        assert_eq!(limit.in_flight(), 0);
+    }
+
+    #[test]
+    fn slots_are_handed_over_in_order() {
+        let limit = ConcurrencyLimit::new(1);
+        let first = limit.acquire().now_or_never();
+        assert!(first.is_some());
+        let mut second = Box::pin(limit.acquire());
+        let mut third = Box::pin(limit.acquire());
+        assert!((&mut second).now_or_never().is_none());
+        assert!((&mut third).now_or_never().is_none());
+
+        drop(first);
+        assert!((&mut third).now_or_never().is_none());
+        let second = second.now_or_never();
+        assert!(second.is_some());
+        assert_eq!(limit.in_flight(), 1);
+
+        // A waiter that gave up passes the slot on.
+        drop(second);
+        drop(third);
+        assert_eq!(limit.in_flight(), 0);
+        assert!(limit.acquire().now_or_never().is_some());
+    }
+}
@@ -22,8 +22,11 @@ use crate::schema::log_entry::LogEntry;
 use crate::schema::log_service::LogService as LogServiceSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
@@ -104,6 +107,32 @@ impl<B: Bmc> LogService<B> {
         }
     }
 
+    /// List log entries of one page selected by `$top` / `$skip`
+    /// query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The log service does not have a log entries collection
+    /// - Fetching log entries data fails
+    pub async fn entries_page(
+        &self,
+        query: nv_redfish_core::PageQuery,
+    ) -> Result<Option<Vec<Arc<LogEntry>>>, Error<B>> {
+        if let Some(entries_ref) = &self.data.entries {
+            let entries_collection = entries_ref
+                .page(self.bmc.as_ref(), query)
+                .await
+                .map_err(Error::Bmc)?;
+
+            self.expand_entries(&entries_collection.members)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Clear all log entries.
     ///
     /// # Arguments
@@ -128,10 +157,11 @@ impl<B: Bmc> LogService<B> {
             .as_ref()
             .ok_or(Error::ActionNotAvailable)?;
 
-        actions
+        let response = actions
             .clear_log(self.bmc.as_ref(), log_entry_codes)
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
     }
 
     /// This unwraps `NavProperty`, usually all BMC already have them expanded, so we do not expect network IO here
@@ -153,3 +183,19 @@ impl<B: Bmc> Resource for LogService<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for LogService<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for LogService<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use serde::Serialize;
 use std::fmt;
 
 /// MAC address returned by the crate.
@@ -20,7 +21,10 @@ use std::fmt;
 /// nv-redfish is not opionated about format of the MAC addresses. So,
 /// it returns whatever server returns. This type is only introduced
 /// to reduce number of untyped &str returned by functions.
-#[derive(Clone, Copy, Debug)]
+///
+/// Serialized as a plain string.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(transparent)]
 #[repr(transparent)]
 pub struct MacAddress<'a>(&'a str);
 
@@ -13,16 +13,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::action_info::ActionParameter;
+use crate::resource::fmt_summary;
 use crate::resource::ResetType;
 use crate::schema::manager::Manager as ManagerSchema;
 use crate::schema::manager::ResetToDefaultsType as ManagerResetToDefaultsType;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use std::fmt;
 use std::sync::Arc;
 
 #[cfg(feature = "manager-network-protocol")]
@@ -43,6 +49,8 @@ use crate::oem::hpe::manager::HpeManager;
 use crate::oem::lenovo::manager::LenovoManager;
 #[cfg(feature = "oem-supermicro")]
 use crate::oem::supermicro::manager::SupermicroManager;
+#[cfg(feature = "log-services")]
+use futures_util::TryStreamExt as _;
 
 /// Represents a manager (BMC) in the system.
 ///
@@ -77,6 +85,14 @@ impl<B: Bmc> Manager<B> {
         self.data.clone()
     }
 
+    /// One-line summary of the manager for logs: identifier, model,
+    /// serial number and state. Same as
+    /// the `Display` output.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+
     /// Get the network protocol resource associated with this manager.
     ///
     /// Returns `Ok(None)` when the network protocol link is absent.
@@ -118,10 +134,29 @@ impl<B: Bmc> Manager<B> {
             return Err(Error::ActionNotAvailable);
         }
 
-        actions
+        let response = actions
             .reset(self.bmc.as_ref(), reset_type)
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
+    }
+
+    /// Parameters of the `Reset` action supported by this manager
+    /// according to `@Redfish.ActionInfo` of the action. Returns
+    /// `None` if the action doesn't reference `ActionInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this manager does not support the `Reset`
+    /// action or if retrieving `ActionInfo` fails.
+    pub async fn reset_parameters(&self) -> Result<Option<Vec<ActionParameter>>, Error<B>> {
+        let action = self
+            .data
+            .actions
+            .as_ref()
+            .and_then(|actions| actions.reset.as_ref())
+            .ok_or(Error::ActionNotAvailable)?;
+        self.bmc.allowed_action_parameters(action).await
     }
 
     /// Reset this manager's settings to defaults.
@@ -147,10 +182,11 @@ impl<B: Bmc> Manager<B> {
             return Err(Error::ActionNotAvailable);
         }
 
-        actions
+        let response = actions
             .reset_to_defaults(self.bmc.as_ref(), Some(reset_type))
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
     }
 
     /// Get ethernet interfaces for this manager.
@@ -206,12 +242,13 @@ impl<B: Bmc> Manager<B> {
                 .await
                 .map_err(crate::Error::Bmc)?;
 
-            let mut log_services = Vec::new();
-            for m in &log_services_collection.members {
-                log_services.push(LogService::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(log_services))
+            self.bmc
+                .members_stream(&log_services_collection.members, |m| {
+                    LogService::new(&self.bmc, m)
+                })
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -283,3 +320,36 @@ impl<B: Bmc> Resource for Manager<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for Manager<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for Manager<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
+
+impl<B: Bmc> fmt::Display for Manager<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_summary(
+            f,
+            "Manager",
+            self,
+            &[
+                ("model", self.data.model.as_ref().and_then(Option::as_deref)),
+                (
+                    "serial",
+                    self.data.serial_number.as_ref().and_then(Option::as_deref),
+                ),
+            ],
+        )
+    }
+}
@@ -21,6 +21,7 @@ mod item;
 #[cfg(feature = "manager-network-protocol")]
 mod network_protocol;
 
+use crate::collection::RedfishCollection;
 use crate::core::NavProperty;
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::FilterFn;
@@ -58,7 +59,7 @@ impl<B: Bmc> ManagerCollection<B> {
         root: &ServiceRoot<B>,
     ) -> Result<Option<Self>, Error<B>> {
         let mut filters = Vec::new();
-        if let Some(odata_id_filter) = bmc.quirks.filter_manager_odata_ids() {
+        if let Some(odata_id_filter) = bmc.quirks().filter_manager_odata_ids() {
             filters.push(Box::new(move |js: &JsonValue| {
                 js.get("@odata.id")
                     .and_then(|v| v.as_str())
@@ -69,38 +70,41 @@ impl<B: Bmc> ManagerCollection<B> {
         let filters_fn = (!filters.is_empty())
             .then(move || Arc::new(move |v: &JsonValue| filters.iter().any(|f| f(v))) as FilterFn);
 
-        if let Some(collection_ref) = &root.root.managers {
-            Self::expand_collection(bmc, collection_ref, None, filters_fn.as_ref())
-                .await
-                .map(Some)
-        } else if bmc.quirks.bug_missing_root_nav_properties() {
-            bmc.expand_property(&NavProperty::new_reference(
-                format!("{}/Managers", root.odata_id()).into(),
-            ))
+        let missing_root_nav;
+        let collection_ref = match &root.root.managers {
+            Some(collection_ref) => collection_ref,
+            None if bmc.quirks().bug_missing_root_nav_properties() => {
+                missing_root_nav =
+                    NavProperty::new_reference(format!("{}/Managers", root.odata_id()).into());
+                &missing_root_nav
+            }
+            None => return Ok(None),
+        };
+        Self::expand_collection(bmc, collection_ref, None, filters_fn.as_ref())
             .await
-            .map(Some)
-        } else {
-            Ok(None)
-        }
-        .map(|c| {
-            c.map(|collection| Self {
-                bmc: bmc.clone(),
-                collection,
+            .map(|collection| {
+                Some(Self {
+                    bmc: bmc.clone(),
+                    collection,
+                })
             })
-        })
     }
+}
 
-    /// List all managers available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching manager data fails.
-    pub async fn members(&self) -> Result<Vec<Manager<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(Manager::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+impl<B: Bmc> RedfishCollection<B> for ManagerCollection<B> {
+    type MemberSchema = ManagerSchema;
+    type Member = Manager<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<ManagerSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(&self, nav: &NavProperty<ManagerSchema>) -> Result<Manager<B>, Error<B>> {
+        Manager::new(&self.bmc, nav).await
     }
 }
 
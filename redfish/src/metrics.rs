@@ -0,0 +1,345 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side request metrics.
+//!
+//! [`MetricsBmc`] wraps any [`Bmc`] and records every request it
+//! sends into [`RequestMetrics`]: request counts per operation, error
+//! counts per class and latency histograms per resource type. The
+//! collected data is read as a [`MetricsSnapshot`], for example, to
+//! export it to Prometheus:
+//!
+//! ```ignore
+//! let metrics = RequestMetrics::new();
+//! let bmc = MetricsBmc::new(bmc, metrics.clone())
+//!     .with_error_classifier(|err: &BmcError| match err {
+//!         BmcError::ReqwestError(_) => "transport",
+//!         _ => "response",
+//!     });
+//! let root = ServiceRoot::new(Arc::new(bmc)).await?;
+//! // ...
+//! let snapshot = metrics.snapshot();
+//! ```
+//!
+//! Latencies are measured with `std::time::Instant` by default. It
+//! is not available on `wasm32-unknown-unknown`, so a clock of the
+//! platform has to be provided there with
+//! [`MetricsLayer::with_clock`].
+
+use crate::layer::BmcLayer;
+use crate::layer::Layered;
+use crate::layer::Request;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+use std::mem;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Upper bounds of latency histogram buckets.
+pub const LATENCY_BUCKETS: [Duration; 11] = [
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(25),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_millis(2500),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+];
+
+/// Function that returns class of BMC error.
+type ClassifyFn<E> = dyn Fn(&E) -> &'static str + Send + Sync;
+
+/// Function that returns monotonic time since an arbitrary origin.
+type ClockFn = dyn Fn() -> Duration + Send + Sync;
+
+/// Default clock: time since the first call.
+fn instant_clock() -> Duration {
+    static ORIGIN: OnceLock<Instant> = OnceLock::new();
+    ORIGIN.get_or_init(Instant::now).elapsed()
+}
+
+/// Histogram of request latencies.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LatencyHistogram {
+    /// Number of requests for every bucket of [`LATENCY_BUCKETS`].
+    /// Counts are cumulative: a request is counted in every bucket
+    /// with upper bound not less than its latency.
+    pub buckets: [u64; LATENCY_BUCKETS.len()],
+    /// Total number of requests, including requests slower than the
+    /// last bucket.
+    pub count: u64,
+    /// Sum of latencies of all requests.
+    pub sum: Duration,
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, latency: Duration) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if latency <= bound {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum += latency;
+    }
+}
+
+/// Metrics collected at the moment of [`RequestMetrics::snapshot`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MetricsSnapshot {
+    /// Number of requests per BMC operation, for example, `get` or
+    /// `update`.
+    pub requests: BTreeMap<&'static str, u64>,
+    /// Number of failed requests per error class.
+    pub errors: BTreeMap<&'static str, u64>,
+    /// Latencies per resource type, for example, `Chassis`. Requests
+    /// without entity type (HEAD, downloads and uploads) are keyed
+    /// by operation name.
+    pub latencies: BTreeMap<&'static str, LatencyHistogram>,
+}
+
+impl MetricsSnapshot {
+    /// Total number of requests.
+    #[must_use]
+    pub fn total_requests(&self) -> u64 {
+        self.requests.values().sum()
+    }
+
+    /// Total number of failed requests.
+    #[must_use]
+    pub fn total_errors(&self) -> u64 {
+        self.errors.values().sum()
+    }
+}
+
+/// Shared collector of request metrics. Clones share the collected
+/// data.
+#[derive(Debug, Clone, Default)]
+pub struct RequestMetrics {
+    state: Arc<Mutex<MetricsSnapshot>>,
+}
+
+impl RequestMetrics {
+    /// Create empty collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Metrics collected so far.
+    #[must_use]
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.state().clone()
+    }
+
+    /// Return metrics collected so far and start collecting from
+    /// scratch.
+    #[must_use]
+    pub fn take(&self) -> MetricsSnapshot {
+        mem::take(&mut *self.state())
+    }
+
+    fn record(
+        &self,
+        operation: &'static str,
+        resource: &'static str,
+        latency: Duration,
+        error: Option<&'static str>,
+    ) {
+        let mut state = self.state();
+        *state.requests.entry(operation).or_default() += 1;
+        if let Some(class) = error {
+            *state.errors.entry(class).or_default() += 1;
+        }
+        state
+            .latencies
+            .entry(resource)
+            .or_default()
+            .observe(latency);
+    }
+
+    fn state(&self) -> MutexGuard<'_, MetricsSnapshot> {
+        // Metrics stay usable even if a recording thread panicked.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Layer that records metrics of every request to
+/// [`RequestMetrics`].
+pub struct MetricsLayer<E> {
+    metrics: RequestMetrics,
+    classify: Arc<ClassifyFn<E>>,
+    clock: Arc<ClockFn>,
+}
+
+impl<E> MetricsLayer<E> {
+    /// Record requests to `metrics`. All errors are counted in the
+    /// `bmc` class.
+    #[must_use]
+    pub fn new(metrics: RequestMetrics) -> Self {
+        Self {
+            metrics,
+            classify: Arc::new(|_| "bmc"),
+            clock: Arc::new(instant_clock),
+        }
+    }
+
+    /// Count errors in classes returned by `classify`, for example,
+    /// `transport`, `client` or `server`.
+    #[must_use]
+    pub fn with_error_classifier<F>(self, classify: F) -> Self
+    where
+        F: Fn(&E) -> &'static str + Send + Sync + 'static,
+    {
+        Self {
+            classify: Arc::new(classify),
+            ..self
+        }
+    }
+
+    /// Measure latencies with `clock` that returns monotonic time
+    /// since an arbitrary origin, for example, time since
+    /// `performance.now()` origin on `wasm32-unknown-unknown`.
+    #[must_use]
+    pub fn with_clock<F>(self, clock: F) -> Self
+    where
+        F: Fn() -> Duration + Send + Sync + 'static,
+    {
+        Self {
+            clock: Arc::new(clock),
+            ..self
+        }
+    }
+
+    /// Collector of the metrics.
+    #[must_use]
+    pub const fn metrics(&self) -> &RequestMetrics {
+        &self.metrics
+    }
+}
+
+impl<E> From<RequestMetrics> for MetricsLayer<E> {
+    fn from(metrics: RequestMetrics) -> Self {
+        Self::new(metrics)
+    }
+}
+
+impl<E> fmt::Debug for MetricsLayer<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MetricsLayer")
+            .field("metrics", &self.metrics)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E: StdError + MaybeSend + MaybeSync> BmcLayer<E> for MetricsLayer<E> {
+    type Error = E;
+
+    fn bmc_error(err: E) -> Self::Error {
+        err
+    }
+
+    async fn call<T, F>(&self, request: &Request<'_>, send: F) -> Result<T, Self::Error>
+    where
+        T: MaybeSend,
+        F: Future<Output = Result<T, E>> + MaybeSend,
+    {
+        let started = (self.clock)();
+        let result = send.await;
+        let error = result.as_ref().err().map(|err| (self.classify)(err));
+        self.metrics.record(
+            request.operation,
+            request.resource,
+            (self.clock)().saturating_sub(started),
+            error,
+        );
+        result
+    }
+}
+
+/// BMC that records metrics of every request of the wrapped BMC to
+/// [`RequestMetrics`].
+pub type MetricsBmc<B> = Layered<MetricsLayer<<B as Bmc>::Error>, B>;
+
+impl<B: Bmc> Layered<MetricsLayer<B::Error>, B> {
+    /// Count errors in classes returned by `classify`, for example,
+    /// `transport`, `client` or `server`.
+    #[must_use]
+    pub fn with_error_classifier<F>(self, classify: F) -> Self
+    where
+        F: Fn(&B::Error) -> &'static str + Send + Sync + 'static,
+    {
+        let (bmc, layer) = self.into_parts();
+        Self::new(bmc, layer.with_error_classifier(classify))
+    }
+
+    /// Measure latencies with `clock`. See
+    /// [`MetricsLayer::with_clock`].
+    #[must_use]
+    pub fn with_clock<F>(self, clock: F) -> Self
+    where
+        F: Fn() -> Duration + Send + Sync + 'static,
+    {
+        let (bmc, layer) = self.into_parts();
+        Self::new(bmc, layer.with_clock(clock))
+    }
+
+    /// Collector of the metrics.
+    #[must_use]
+    pub const fn metrics(&self) -> &RequestMetrics {
+        self.layer().metrics()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestMetrics;
+    use super::LATENCY_BUCKETS;
+    use std::time::Duration;
+
+    #[test]
+    fn records_requests_errors_and_latencies() {
+        let metrics = RequestMetrics::new();
+        metrics.record("get", "Chassis", Duration::from_millis(7), None);
+        metrics.record("get", "Chassis", Duration::from_mins(1), Some("server"));
+        metrics.record("update", "Chassis", Duration::from_millis(1), None);
+
+        let snapshot = metrics.take();
+        assert_eq!(snapshot.total_requests(), 3);
+        assert_eq!(snapshot.requests["get"], 2);
+        assert_eq!(snapshot.errors["server"], 1);
+        let chassis = &snapshot.latencies["Chassis"];
+        assert_eq!(chassis.count, 3);
+        assert_eq!(chassis.buckets[0], 1);
+        assert_eq!(chassis.buckets[1], 2);
+        assert_eq!(chassis.buckets[LATENCY_BUCKETS.len() - 1], 2);
+        assert_eq!(metrics.snapshot().total_requests(), 0);
+    }
+}
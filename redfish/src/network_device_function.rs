@@ -15,16 +15,19 @@
 
 //! Network device functions.
 
+use crate::collection::RedfishCollection;
 use crate::mac_address::MacAddress;
 use crate::schema::network_device_function::NetworkDeviceFunction as NetworkDeviceFunctionSchema;
 use crate::schema::network_device_function_collection::NetworkDeviceFunctionCollection as NetworkDeviceFunctionCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 /// Network device functions collection.
@@ -47,18 +50,25 @@ impl<B: Bmc> NetworkDeviceFunctionCollection<B> {
             collection,
         })
     }
+}
+
+impl<B: Bmc> RedfishCollection<B> for NetworkDeviceFunctionCollection<B> {
+    type MemberSchema = NetworkDeviceFunctionSchema;
+    type Member = NetworkDeviceFunction<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
 
-    /// List all managers available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching manager data fails.
-    pub async fn members(&self) -> Result<Vec<NetworkDeviceFunction<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(NetworkDeviceFunction::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+    fn members_nav(&self) -> &[NavProperty<NetworkDeviceFunctionSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(
+        &self,
+        nav: &NavProperty<NetworkDeviceFunctionSchema>,
+    ) -> Result<NetworkDeviceFunction<B>, Error<B>> {
+        NetworkDeviceFunction::new(&self.bmc, nav).await
     }
 }
 
@@ -66,8 +76,8 @@ impl<B: Bmc> NetworkDeviceFunctionCollection<B> {
 ///
 /// Provides functions to access network device function.
 pub struct NetworkDeviceFunction<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<NetworkDeviceFunctionSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> NetworkDeviceFunction<B> {
@@ -80,8 +90,8 @@ impl<B: Bmc> NetworkDeviceFunction<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -107,3 +117,19 @@ impl<B: Bmc> Resource for NetworkDeviceFunction<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for NetworkDeviceFunction<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for NetworkDeviceFunction<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
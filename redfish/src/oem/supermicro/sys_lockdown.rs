@@ -18,6 +18,8 @@
 use crate::core::Bmc;
 use crate::core::NavProperty;
 use crate::oem::supermicro::schema::sys_lockdown::SysLockdown as SysLockdownSchema;
+use crate::patch_support::restore_odata_id_case;
+use crate::patch_support::Payload;
 use crate::Error;
 use crate::NvBmc;
 use std::marker::PhantomData;
@@ -39,13 +41,15 @@ impl<B: Bmc> SysLockdown<B> {
         bmc: &NvBmc<B>,
         nav: &NavProperty<SysLockdownSchema>,
     ) -> Result<Self, Error<B>> {
-        nav.get(bmc.as_ref())
-            .await
-            .map_err(Error::Bmc)
-            .map(|data| Self {
-                data,
-                _marker: PhantomData,
-            })
+        if bmc.quirks_for(nav.id()).odata_id_case_mismatch() {
+            Payload::get(bmc.as_ref(), nav, restore_odata_id_case(nav.id())).await
+        } else {
+            nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
+        }
+        .map(|data| Self {
+            data,
+            _marker: PhantomData,
+        })
     }
 
     /// Get the raw schema data for this Supermicro system lockdown resource.
@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::patch_support::JsonValue;
+use crate::patch_support::ReadPatchFn;
+use serde_json::Number;
+use std::sync::Arc;
+
+/// Coercion of string-encoded numbers (`"1500"`) and booleans
+/// (`"true"`) into proper JSON types at configured property paths.
+///
+/// Path is a list of property names separated by `/`. Segment `*`
+/// matches all elements of an array, for example,
+/// `PowerControl/*/PowerConsumedWatts`. Strings that are neither
+/// numbers nor booleans are left as is.
+#[derive(Debug, Clone, Default)]
+#[allow(dead_code)] // used if quirk requires it.
+pub struct StringCoercion(Vec<Vec<String>>);
+
+#[allow(dead_code)] // used if quirk requires it.
+impl StringCoercion {
+    /// Create empty set of paths.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Register `path` of the property to coerce.
+    #[must_use]
+    pub fn with(mut self, path: &str) -> Self {
+        self.0.push(path.split('/').map(Into::into).collect());
+        self
+    }
+
+    /// Whether no paths are registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Coerce string values at registered paths of the payload.
+    #[must_use]
+    pub fn apply(&self, mut v: JsonValue) -> JsonValue {
+        for path in &self.0 {
+            coerce_path(&mut v, path);
+        }
+        v
+    }
+
+    /// Convert to read patch function. Returns `None` if no paths
+    /// are registered.
+    #[must_use]
+    pub fn into_patch_fn(self) -> Option<ReadPatchFn> {
+        (!self.is_empty()).then(|| Arc::new(move |v| self.apply(v)) as ReadPatchFn)
+    }
+}
+
+fn coerce_path(v: &mut JsonValue, path: &[String]) {
+    match (path.split_first(), v) {
+        (None, v) => coerce(v),
+        (Some((segment, rest)), JsonValue::Array(items)) if segment == "*" => {
+            for item in items {
+                coerce_path(item, rest);
+            }
+        }
+        (Some((segment, rest)), JsonValue::Object(obj)) => {
+            if let Some(v) = obj.get_mut(segment) {
+                coerce_path(v, rest);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn coerce(v: &mut JsonValue) {
+    let JsonValue::String(s) = v else {
+        return;
+    };
+    let s = s.trim();
+    let coerced = if s.eq_ignore_ascii_case("true") {
+        Some(JsonValue::Bool(true))
+    } else if s.eq_ignore_ascii_case("false") {
+        Some(JsonValue::Bool(false))
+    } else if let Ok(n) = s.parse::<i64>() {
+        Some(n.into())
+    } else if let Ok(n) = s.parse::<u64>() {
+        Some(n.into())
+    } else {
+        s.parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(JsonValue::Number)
+    };
+    if let Some(coerced) = coerced {
+        *v = coerced;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringCoercion;
+    use serde_json::json;
+
+    #[test]
+    fn coerce_configured_paths() {
+        let coercion = StringCoercion::new()
+            .with("PowerControl/*/PowerConsumedWatts")
+            .with("Enabled")
+            .with("Ratio");
+        assert_eq!(
+            coercion.apply(json!({
+                "PowerControl": [
+                    { "PowerConsumedWatts": "1500" },
+                    { "PowerConsumedWatts": 1200 },
+                    { "PowerConsumedWatts": "n/a" },
+                ],
+                "Enabled": "True",
+                "Ratio": "0.5",
+                "Version": "1",
+            })),
+            json!({
+                "PowerControl": [
+                    { "PowerConsumedWatts": 1500 },
+                    { "PowerConsumedWatts": 1200 },
+                    { "PowerConsumedWatts": "n/a" },
+                ],
+                "Enabled": true,
+                "Ratio": 0.5,
+                "Version": "1",
+            })
+        );
+        assert_eq!(coercion.apply(json!(null)), json!(null));
+        assert!(StringCoercion::new().into_patch_fn().is_none());
+    }
+}
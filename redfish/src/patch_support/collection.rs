@@ -28,12 +28,20 @@ use nv_redfish_core::Expandable;
 use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
 use serde::Deserialize;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+#[cfg(feature = "patch-collection-create")]
+use crate::patch_support::WritePatchFn;
 #[cfg(feature = "patch-collection-create")]
 use nv_redfish_core::Creatable;
 #[cfg(feature = "patch-collection-create")]
+use nv_redfish_core::MaybeSend;
+#[cfg(feature = "patch-collection-create")]
+use nv_redfish_core::MaybeSync;
+#[cfg(feature = "patch-collection-create")]
 use nv_redfish_core::ModificationResponse;
 #[cfg(feature = "patch-collection-create")]
 use serde::Serialize;
@@ -47,7 +55,7 @@ use serde::Serialize;
 pub trait CollectionWithPatch<T, M, B>
 where
     T: Expandable + 'static,
-    M: EntityTypeRef + for<'de> Deserialize<'de>,
+    M: EntityTypeRef + for<'de> Deserialize<'de> + 'static,
     B: Bmc,
 {
     fn convert_patched(base: ResourceCollection, members: Vec<NavProperty<M>>) -> T;
@@ -58,18 +66,30 @@ where
         patch_fn: Option<&ReadPatchFn>,
         filter_fn: Option<&FilterFn>,
     ) -> Result<Arc<T>, Error<B>> {
-        if patch_fn.is_some() || filter_fn.is_some() {
+        let quirks = bmc.quirks();
+        if patch_fn.is_some()
+            || filter_fn.is_some()
+            || quirks.null_entries_in_arrays()
+            || quirks.members_count_mismatch()
+        {
             // Patches are not free so we keep separate branch for
             // patched collections only having this cost on systems
             // that requires to pay the price.
             let patched_collection_ref = NavProperty::<Collection>::new_reference(nav.id().clone());
-            let collection = bmc.expand_property(&patched_collection_ref).await?;
+            let collection = bmc
+                .expand_filtered(&patched_collection_ref, filter_fn)
+                .await?;
+            let missing = if quirks.members_count_mismatch() {
+                collection.missing_members(bmc).await?
+            } else {
+                Vec::new()
+            };
             let patch_fn = patch_fn.map(AsRef::as_ref);
             let filter_fn = filter_fn.map(AsRef::as_ref);
-            let members = collection.members(patch_fn, filter_fn)?;
+            let members = collection.members(&missing, patch_fn, filter_fn)?;
             Ok(Arc::new(Self::convert_patched(collection.base(), members)))
         } else {
-            bmc.expand_property(nav).await
+            bmc.expand_members::<T, M>(nav).await
         }
     }
 }
@@ -82,16 +102,25 @@ where
 pub trait CreateWithPatch<T, M, C, B>
 where
     T: Creatable<C, M>,
-    C: Serialize + Sync + Send,
-    M: for<'de> Deserialize<'de> + Sync + Send,
+    C: Serialize + MaybeSync + MaybeSend,
+    M: for<'de> Deserialize<'de> + MaybeSync + MaybeSend,
     B: Bmc,
 {
     fn entity_ref(&self) -> &T;
     fn patch(&self) -> Option<&ReadPatchFn>;
+    fn write_patch(&self) -> Option<&WritePatchFn>;
     fn bmc(&self) -> &B;
 
     async fn create_with_patch(&self, create: &C) -> Result<ModificationResponse<M>, Error<B>> {
-        if let Some(patch_fn) = &self.patch() {
+        if let Some(write_patch_fn) = self.write_patch() {
+            let create = Payload::to_write(create, write_patch_fn.as_ref())?;
+            let patch_fn = self.patch();
+            Collection::create(self.entity_ref(), self.bmc(), &create, |v| match patch_fn {
+                Some(f) => f(v),
+                None => v,
+            })
+            .await
+        } else if let Some(patch_fn) = &self.patch() {
             Collection::create(self.entity_ref(), self.bmc(), create, patch_fn.as_ref()).await
         } else {
             self.entity_ref()
@@ -114,6 +143,10 @@ struct Collection {
     base: ResourceCollection,
     #[serde(rename = "Members")]
     members: Vec<Payload>,
+    #[serde(rename = "Members@odata.count", default)]
+    members_count: Option<usize>,
+    #[serde(rename = "Members@odata.nextLink", default)]
+    members_next_link: Option<ODataId>,
 }
 
 impl Collection {
@@ -128,7 +161,7 @@ impl Collection {
         T: EntityTypeRef,
         V: for<'de> Deserialize<'de>,
         B: Bmc,
-        C: Serialize + Sync + Send,
+        C: Serialize + MaybeSync + MaybeSend,
         F: Fn(JsonValue) -> JsonValue + Sync + Send,
     {
         Creator {
@@ -160,8 +193,54 @@ impl Collection {
         }
     }
 
+    /// Members that are counted in `Members@odata.count` but are
+    /// missing in `Members` (even after following
+    /// `Members@odata.nextLink`). They are requested with `$skip`
+    /// until the count is reached or the BMC stops returning new
+    /// members.
+    async fn missing_members<B: Bmc>(&self, bmc: &NvBmc<B>) -> Result<Vec<Payload>, Error<B>> {
+        let expected = self.members_count.unwrap_or_default();
+        let mut seen = self
+            .members
+            .iter()
+            .map(Payload::odata_id)
+            .collect::<HashSet<_>>();
+        let mut missing = Vec::new();
+        while seen.len() < expected {
+            let query = PageQuery::top(expected - seen.len()).skip(seen.len());
+            let page = bmc
+                .as_ref()
+                .page::<Self>(self.odata_id(), query)
+                .await
+                .map_err(Error::Bmc)?;
+            let before = missing.len();
+            missing.extend(
+                page.members
+                    .iter()
+                    .filter(|member| seen.insert(member.odata_id()))
+                    .cloned(),
+            );
+            if missing.len() == before {
+                break;
+            }
+        }
+        #[cfg(feature = "tracing")]
+        if self.members.len() != expected {
+            tracing::debug!(
+                quirk = "MembersCountMismatch",
+                odata_id = %self.odata_id(),
+                count = expected,
+                members = self.members.len(),
+                fetched = missing.len(),
+                "collection members reconciled"
+            );
+        }
+        Ok(missing)
+    }
+
     fn members<T, FP, FF, B>(
         &self,
+        missing: &[Payload],
         patch_fn: Option<&FP>,
         filter_fn: Option<&FF>,
     ) -> Result<Vec<NavProperty<T>>, Error<B>>
@@ -173,8 +252,18 @@ impl Collection {
     {
         self.members
             .iter()
-            .filter(|v| filter_fn.is_none_or(|ff| v.filter(ff)))
-            .map(|v| patch_fn.map_or_else(|| v.parse(), |fp| v.to_target(fp)))
+            .chain(missing)
+            // `null` members cannot be parsed and are skipped.
+            .filter(|v| !v.is_null())
+            .filter(|v| {
+                let keep = filter_fn.is_none_or(|ff| v.filter(ff));
+                #[cfg(feature = "tracing")]
+                if !keep {
+                    tracing::debug!(odata_id = %v.odata_id(), "collection member filtered out by BMC quirk");
+                }
+                keep
+            })
+            .map(|v| patch_fn.map_or_else(|| v.parse(), |fp| v.to_entity(&v.odata_id(), fp)))
             .collect::<Result<Vec<_>, _>>()
     }
 }
@@ -186,6 +275,13 @@ impl EntityTypeRef for Collection {
     fn etag(&self) -> Option<&ODataETag> {
         self.base.etag()
     }
+    fn next_page_link(&self) -> Option<&ODataId> {
+        self.members_next_link.as_ref()
+    }
+    fn append_page(&mut self, page: Self) {
+        self.members.extend(page.members);
+        self.members_next_link = page.members_next_link;
+    }
 }
 
 impl Expandable for Collection {}
@@ -208,4 +304,4 @@ impl EntityTypeRef for Creator<'_> {
 }
 
 #[cfg(feature = "patch-collection-create")]
-impl<V: Serialize + Send + Sync> Creatable<V, Payload> for Creator<'_> {}
+impl<V: Serialize + MaybeSend + MaybeSync> Creatable<V, Payload> for Creator<'_> {}
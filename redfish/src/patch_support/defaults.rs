@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::patch_support::JsonValue;
+use crate::patch_support::ReadPatchFn;
+use std::sync::Arc;
+
+/// Default values of required properties that some Redfish
+/// implementations omit.
+///
+/// Defaults are registered per property and are applied only if the
+/// payload is a JSON object that doesn't contain the property. Values
+/// provided by the BMC are never overwritten.
+#[derive(Debug, Clone, Default)]
+pub struct PropertyDefaults(Vec<(&'static str, JsonValue)>);
+
+impl PropertyDefaults {
+    /// Create empty set of defaults.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Register default `value` of the `property`.
+    #[must_use]
+    pub fn with(mut self, property: &'static str, value: impl Into<JsonValue>) -> Self {
+        self.0.push((property, value.into()));
+        self
+    }
+
+    /// Whether no defaults are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add default values of missing properties to the payload.
+    #[must_use]
+    pub fn apply(&self, v: JsonValue) -> JsonValue {
+        if let JsonValue::Object(mut obj) = v {
+            for (property, value) in &self.0 {
+                obj.entry(*property).or_insert_with(|| value.clone());
+            }
+            JsonValue::Object(obj)
+        } else {
+            v
+        }
+    }
+
+    /// Convert to read patch function. Returns `None` if no defaults
+    /// are registered.
+    #[must_use]
+    pub fn into_patch_fn(self) -> Option<ReadPatchFn> {
+        (!self.is_empty()).then(|| Arc::new(move |v| self.apply(v)) as ReadPatchFn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PropertyDefaults;
+    use serde_json::json;
+
+    #[test]
+    fn defaults_do_not_override_payload() {
+        let defaults = PropertyDefaults::new()
+            .with("Name", "Unnamed")
+            .with("ChassisType", "Other");
+        assert_eq!(
+            defaults.apply(json!({ "Name": "Chassis" })),
+            json!({ "Name": "Chassis", "ChassisType": "Other" })
+        );
+        assert_eq!(defaults.apply(json!(null)), json!(null));
+        assert!(PropertyDefaults::new().into_patch_fn().is_none());
+    }
+}
@@ -16,28 +16,55 @@
 //! Sometimes Redfish implementations do not perfectly match the CSDL
 //! specification. This module provides helpers to deal with that.
 
+/// Coercion of string-encoded numbers and booleans.
+#[cfg(feature = "patch-coercion")]
+mod coercion;
 /// Redfish collection related patches.
 #[cfg(feature = "patch-collection")]
 mod collection;
+/// Default values of missing properties.
+#[cfg(feature = "patch-defaults")]
+mod defaults;
+/// Removal of `null` entries from arrays.
+#[cfg(feature = "patch-null-entries")]
+mod nulls;
 /// Redfish payload patches.
 #[cfg(feature = "patch-payload")]
 mod payload;
+/// Patches of resources keyed by resource type.
+#[cfg(any(feature = "accounts", feature = "patch-collection"))]
+mod registry;
 
 #[doc(inline)]
 pub use serde_json::Value as JsonValue;
 
+#[cfg(feature = "patch-coercion")]
+#[doc(inline)]
+pub use coercion::StringCoercion;
 #[cfg(feature = "patch-collection")]
 #[doc(inline)]
 pub use collection::CollectionWithPatch;
 #[cfg(feature = "patch-collection-create")]
 #[doc(inline)]
 pub use collection::CreateWithPatch;
+#[cfg(feature = "patch-defaults")]
+#[doc(inline)]
+pub use defaults::PropertyDefaults;
+#[cfg(feature = "patch-null-entries")]
+#[doc(inline)]
+pub use nulls::NullEntries;
+#[cfg(feature = "patch-payload-get")]
+#[doc(inline)]
+pub use payload::restore_odata_id_case;
 #[cfg(feature = "patch-payload")]
 #[doc(inline)]
 pub use payload::Payload;
 #[cfg(feature = "patch-payload-update")]
 #[doc(inline)]
 pub use payload::UpdateWithPatch;
+#[cfg(any(feature = "accounts", feature = "patch-collection"))]
+#[doc(inline)]
+pub use registry::PatchRegistry;
 
 use std::sync::Arc;
 
@@ -45,6 +72,11 @@ use std::sync::Arc;
 /// structure to a Redfish-compatible structure.
 pub type ReadPatchFn = Arc<dyn Fn(JsonValue) -> JsonValue + Sync + Send>;
 
+/// Reference to a write patch function. This function should transform
+/// a JSON body of PATCH or POST request built from Redfish structures to
+/// the structure expected by the BMC implementation.
+pub type WritePatchFn = Arc<dyn Fn(JsonValue) -> JsonValue + Sync + Send>;
+
 /// Reference to a filter function. This function should filters a JSON
 /// structure.
 #[cfg(feature = "patch-collection")]
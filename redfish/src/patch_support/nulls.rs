@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::patch_support::JsonValue;
+use crate::patch_support::ReadPatchFn;
+use std::sync::Arc;
+
+/// Removal of `null` entries from arrays at configured JSON pointer
+/// paths, for example, `/Members` or `/Links/Chassis`.
+///
+/// Some implementations emit `null` in arrays of navigation
+/// properties that cannot be deserialized. Arrays that are missing
+/// and values that are not arrays are left as is.
+#[derive(Debug, Clone, Default)]
+#[allow(dead_code)] // used if quirk requires it.
+pub struct NullEntries(Vec<String>);
+
+#[allow(dead_code)] // used if quirk requires it.
+impl NullEntries {
+    /// Create empty set of paths.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Register JSON `pointer` of the array to clean up.
+    #[must_use]
+    pub fn with(mut self, pointer: impl Into<String>) -> Self {
+        self.0.push(pointer.into());
+        self
+    }
+
+    /// Whether no paths are registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Remove `null` entries from arrays at registered paths of the
+    /// payload.
+    #[must_use]
+    pub fn apply(&self, mut v: JsonValue) -> JsonValue {
+        for pointer in &self.0 {
+            if let Some(JsonValue::Array(items)) = v.pointer_mut(pointer) {
+                items.retain(|item| !item.is_null());
+            }
+        }
+        v
+    }
+
+    /// Convert to read patch function. Returns `None` if no paths
+    /// are registered.
+    #[must_use]
+    pub fn into_patch_fn(self) -> Option<ReadPatchFn> {
+        (!self.is_empty()).then(|| Arc::new(move |v| self.apply(v)) as ReadPatchFn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NullEntries;
+    use serde_json::json;
+
+    #[test]
+    fn remove_null_entries_at_configured_paths() {
+        let nulls = NullEntries::new()
+            .with("/Links/Chassis")
+            .with("/RelatedItem")
+            .with("/Name");
+        assert_eq!(
+            nulls.apply(json!({
+                "Links": {
+                    "Chassis": [null, { "@odata.id": "/redfish/v1/Chassis/1" }, null],
+                    "ManagedBy": [null],
+                },
+                "Name": null,
+            })),
+            json!({
+                "Links": {
+                    "Chassis": [{ "@odata.id": "/redfish/v1/Chassis/1" }],
+                    "ManagedBy": [null],
+                },
+                "Name": null,
+            })
+        );
+        assert_eq!(nulls.apply(json!(null)), json!(null));
+        assert!(NullEntries::new().into_patch_fn().is_none());
+    }
+}
@@ -18,13 +18,27 @@ use crate::Error;
 use nv_redfish_core::Bmc;
 use serde::Deserialize;
 
-#[cfg(any(feature = "patch-payload-get", feature = "patch-payload-update"))]
+#[cfg(any(
+    feature = "patch-payload-get",
+    feature = "patch-payload-update",
+    feature = "patch-collection"
+))]
 use nv_redfish_core::EntityTypeRef;
 #[cfg(any(feature = "patch-payload-get", feature = "patch-payload-update"))]
 use nv_redfish_core::Expandable;
+#[cfg(any(
+    feature = "patch-payload-get",
+    feature = "patch-payload-update",
+    feature = "patch-collection"
+))]
+use nv_redfish_core::MissingRequiredProperty;
 #[cfg(any(feature = "patch-payload-get", feature = "patch-payload-update"))]
 use nv_redfish_core::ODataETag;
-#[cfg(any(feature = "patch-payload-get", feature = "patch-payload-update"))]
+#[cfg(any(
+    feature = "patch-payload-get",
+    feature = "patch-payload-update",
+    feature = "patch-collection"
+))]
 use nv_redfish_core::ODataId;
 #[cfg(any(feature = "patch-payload-get", feature = "patch-payload-update"))]
 use serde::Deserializer;
@@ -37,50 +51,114 @@ use std::sync::Arc;
 #[cfg(feature = "patch-payload-update")]
 use crate::patch_support::ReadPatchFn;
 #[cfg(feature = "patch-payload-update")]
+use crate::patch_support::WritePatchFn;
+#[cfg(feature = "patch-payload-update")]
+use crate::NvBmc;
+#[cfg(feature = "patch-payload-update")]
+use nv_redfish_core::MaybeSend;
+#[cfg(feature = "patch-payload-update")]
+use nv_redfish_core::MaybeSync;
+#[cfg(feature = "patch-payload-update")]
 use nv_redfish_core::ModificationResponse;
 #[cfg(feature = "patch-payload-update")]
 use nv_redfish_core::Updatable;
-#[cfg(feature = "patch-payload-update")]
+#[cfg(any(feature = "patch-payload-update", feature = "patch-collection-create"))]
 use serde::Serialize;
 
 #[cfg(feature = "patch-payload-update")]
 pub trait UpdateWithPatch<T, V, B>
 where
-    V: Serialize + Send + Sync,
-    T: Updatable<V>,
+    V: Serialize + MaybeSend + MaybeSync,
+    T: Updatable<V> + MaybeSend + MaybeSync,
     B: Bmc,
 {
     fn entity_ref(&self) -> &T;
     fn patch(&self) -> Option<&ReadPatchFn>;
-    fn bmc(&self) -> &B;
+    fn write_patch(&self) -> Option<&WritePatchFn>;
+    fn bmc(&self) -> &NvBmc<B>;
 
     async fn update_with_patch(&self, update: &V) -> Result<ModificationResponse<T>, Error<B>> {
-        if let Some(patch_fn) = self.patch() {
-            Updator {
-                id: self.entity_ref().odata_id(),
-                etag: self.entity_ref().etag(),
-            }
-            .update(self.bmc(), update, patch_fn.as_ref())
-            .await
+        let updator = Updator {
+            id: self.entity_ref().odata_id(),
+            etag: self.entity_ref().etag(),
+        };
+        if let Some(write_patch_fn) = self.write_patch() {
+            let update = Payload::to_write(update, write_patch_fn.as_ref())?;
+            let patch_fn = self.patch();
+            updator
+                .update(self.bmc(), &update, |v| match patch_fn {
+                    Some(f) => f(v),
+                    None => v,
+                })
+                .await
+        } else if let Some(patch_fn) = self.patch() {
+            updator.update(self.bmc(), update, patch_fn.as_ref()).await
         } else {
-            self.entity_ref()
-                .update(self.bmc(), update)
+            self.bmc()
+                .update::<V, T>(
+                    self.entity_ref().odata_id(),
+                    self.entity_ref().etag(),
+                    update,
+                )
                 .await
-                .map_err(Error::Bmc)
         }
     }
 }
 
+/// Patch that replaces `@odata.id` of the payload with `id` if they
+/// differ only in letter case.
+#[cfg(feature = "patch-payload-get")]
+pub fn restore_odata_id_case(id: &ODataId) -> impl Fn(JsonValue) -> JsonValue + '_ {
+    move |mut v| {
+        let id = id.to_string();
+        if let Some(odata_id) = v.get_mut("@odata.id") {
+            if odata_id
+                .as_str()
+                .is_some_and(|s| s != id && s.eq_ignore_ascii_case(&id))
+            {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    patch = "restore_odata_id_case",
+                    odata_id = %id,
+                    "patch modified payload"
+                );
+                *odata_id = JsonValue::String(id);
+            }
+        }
+        v
+    }
+}
+
 /// Support payload patching.
 ///
 /// This struct supports deserialization from any JSON payload and
 /// provides a method to apply a patch and then deserialize to the
 /// target type.
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
+#[cfg_attr(
+    any(feature = "patch-payload-update", feature = "patch-collection-create"),
+    derive(Serialize)
+)]
 #[serde(transparent)]
 pub struct Payload(JsonValue);
 
 impl Payload {
+    /// Serialize request body `body` and apply write patch function
+    /// `f` to it.
+    #[cfg(any(feature = "patch-payload-update", feature = "patch-collection-create"))]
+    pub(crate) fn to_write<U, B, F>(body: &U, f: F) -> Result<Self, Error<B>>
+    where
+        U: Serialize,
+        B: Bmc,
+        F: FnOnce(JsonValue) -> JsonValue,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("applying write patch");
+        serde_json::to_value(body)
+            .map(|v| Self(f(v)))
+            .map_err(Error::Json)
+    }
+
     #[cfg(feature = "patch-payload-get")]
     pub(crate) async fn get<T, B, F>(
         bmc: &B,
@@ -97,24 +175,21 @@ impl Payload {
             NavProperty::Reference(_) => {
                 let getter = NavProperty::<Getter>::new_reference(nav.id().clone());
                 let v = getter.get(bmc).await.map_err(Error::Bmc)?;
-                v.payload.to_target(f).map(Arc::new)
+                v.payload.to_entity(nav.id(), f).map(Arc::new)
             }
         }
     }
 
     /// Apply function `f` to the payload and then try to deserialize to the
     /// target type.
+    #[cfg(feature = "patch-collection-create")]
     pub(crate) fn to_target<T, B, F>(&self, f: F) -> Result<T, Error<B>>
     where
         T: for<'de> Deserialize<'de>,
         B: Bmc,
         F: FnOnce(JsonValue) -> JsonValue,
     {
-        let is_reference = self
-            .0
-            .as_object()
-            .is_some_and(|obj| obj.len() == 1 && obj.contains_key("@odata.id"));
-        if is_reference {
+        if self.is_reference() {
             // Do not apply patches to the references.
             serde_json::from_value(self.0.clone()).map_err(Error::Json)
         } else {
@@ -122,6 +197,66 @@ impl Payload {
         }
     }
 
+    /// Apply function `f` to the payload and then try to deserialize to the
+    /// target entity type. If a required property is missing in the
+    /// patched payload, the error names the property and the resource
+    /// `id`. Unpatched payloads are deserialized by the `Bmc`, so the
+    /// same payload read without a patch fails with the deserialization
+    /// error of the `Bmc` instead.
+    #[cfg(any(
+        feature = "patch-payload-get",
+        feature = "patch-payload-update",
+        feature = "patch-collection"
+    ))]
+    pub(crate) fn to_entity<T, B, F>(&self, id: &ODataId, f: F) -> Result<T, Error<B>>
+    where
+        T: EntityTypeRef + for<'de> Deserialize<'de>,
+        B: Bmc,
+        F: FnOnce(JsonValue) -> JsonValue,
+    {
+        if self.is_reference() {
+            // Do not apply patches to the references.
+            return serde_json::from_value(self.0.clone()).map_err(Error::Json);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(odata_id = %id, "applying read patch");
+        let value = f(self.0.clone());
+        T::deserialize(&value).map_err(|err| match T::missing_required_property(&value) {
+            Some(property) => Error::MissingRequiredProperty(MissingRequiredProperty {
+                resource: id.clone(),
+                property,
+            }),
+            None => Error::Json(err),
+        })
+    }
+
+    /// Value of `@odata.id` of the payload (empty if not present).
+    #[cfg(feature = "patch-collection")]
+    pub(crate) fn odata_id(&self) -> ODataId {
+        self.0
+            .get("@odata.id")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+            .to_string()
+            .into()
+    }
+
+    #[cfg(any(
+        feature = "patch-payload-get",
+        feature = "patch-payload-update",
+        feature = "patch-collection"
+    ))]
+    fn is_reference(&self) -> bool {
+        self.0
+            .as_object()
+            .is_some_and(|obj| obj.len() == 1 && obj.contains_key("@odata.id"))
+    }
+
+    #[cfg(feature = "patch-collection")]
+    pub(crate) fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+
     #[cfg(feature = "patch-collection")]
     pub(crate) fn parse<T, B>(&self) -> Result<T, Error<B>>
     where
@@ -194,19 +329,18 @@ impl EntityTypeRef for Updator<'_> {
 impl Updator<'_> {
     async fn update<B, U, T, F>(
         &self,
-        bmc: &B,
+        bmc: &NvBmc<B>,
         update: &U,
         patch_fn: F,
     ) -> Result<ModificationResponse<T>, Error<B>>
     where
         B: Bmc,
         T: EntityTypeRef + for<'de> Deserialize<'de>,
-        U: Serialize + Send + Sync,
+        U: Serialize + MaybeSend + MaybeSync,
         F: Fn(JsonValue) -> JsonValue + Sync + Send,
     {
         bmc.update::<U, Payload>(self.odata_id(), self.etag(), update)
-            .await
-            .map_err(Error::Bmc)?
-            .try_map_entity(|payload| payload.to_target::<T, B, _>(&patch_fn))
+            .await?
+            .try_map_entity(|payload| payload.to_entity::<T, B, _>(self.odata_id(), &patch_fn))
     }
 }
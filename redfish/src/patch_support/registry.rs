@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::patch_support::JsonValue;
+use crate::patch_support::ReadPatchFn;
+use crate::patch_support::WritePatchFn;
+use std::any::type_name;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Read and write patches of resources keyed by resource (schema)
+/// type.
+///
+/// Patches are registered once when platform quirks are known and
+/// resources look up patches of their type instead of receiving
+/// patch functions through constructors. Several patches of the same
+/// type are applied in order of registration.
+///
+/// Every patch has a name. With `tracing` feature enabled, an event
+/// with patch name, resource type and `@odata.id` of the payload is
+/// emitted whenever the patch actually modifies the payload.
+#[derive(Clone, Default)]
+pub struct PatchRegistry {
+    read: HashMap<TypeId, Vec<NamedPatch>>,
+    write: HashMap<TypeId, Vec<NamedPatch>>,
+}
+
+#[derive(Clone)]
+struct NamedPatch {
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    name: &'static str,
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    resource_type: &'static str,
+    f: ReadPatchFn,
+}
+
+impl NamedPatch {
+    fn new<T: 'static>(name: &'static str, f: ReadPatchFn) -> Self {
+        Self {
+            name,
+            resource_type: type_name::<T>(),
+            f,
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    fn apply(&self, v: JsonValue) -> JsonValue {
+        let original = v.clone();
+        let patched = (self.f)(v);
+        if patched != original {
+            tracing::debug!(
+                patch = self.name,
+                resource_type = self.resource_type,
+                odata_id = original.get("@odata.id").and_then(JsonValue::as_str),
+                "patch modified payload"
+            );
+        }
+        patched
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn apply(&self, v: JsonValue) -> JsonValue {
+        (self.f)(v)
+    }
+}
+
+#[allow(dead_code)] // feature-enabled funcs
+impl PatchRegistry {
+    /// Create empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register patch `name` applied to payloads of resource type
+    /// `T` before deserialization.
+    pub fn add_read<T: 'static>(&mut self, name: &'static str, f: ReadPatchFn) {
+        self.read
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(NamedPatch::new::<T>(name, f));
+    }
+
+    /// Register patch `name` applied to bodies of PATCH and POST
+    /// requests that create or update resource type `T`.
+    pub fn add_write<T: 'static>(&mut self, name: &'static str, f: WritePatchFn) {
+        self.write
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(NamedPatch::new::<T>(name, f));
+    }
+
+    /// Read patch of resource type `T`. Returns `None` if no patches
+    /// are registered for the type.
+    #[must_use]
+    pub fn read<T: 'static>(&self) -> Option<ReadPatchFn> {
+        self.read
+            .get(&TypeId::of::<T>())
+            .map(Vec::as_slice)
+            .and_then(compose)
+    }
+
+    /// Write patch of resource type `T`. Returns `None` if no patches
+    /// are registered for the type.
+    #[must_use]
+    pub fn write<T: 'static>(&self) -> Option<WritePatchFn> {
+        self.write
+            .get(&TypeId::of::<T>())
+            .map(Vec::as_slice)
+            .and_then(compose)
+    }
+}
+
+fn compose(patches: &[NamedPatch]) -> Option<ReadPatchFn> {
+    if patches.is_empty() {
+        return None;
+    }
+    let patches = patches.to_vec();
+    Some(Arc::new(move |v| {
+        patches.iter().fold(v, |acc, patch| patch.apply(acc))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PatchRegistry;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    struct First;
+    struct Second;
+
+    #[test]
+    fn patches_are_applied_in_order_per_type() {
+        let mut registry = PatchRegistry::new();
+        registry.add_read::<First>("first", Arc::new(|v| json!([v, 1])));
+        registry.add_read::<First>("second", Arc::new(|v| json!([v, 2])));
+        registry.add_write::<Second>("wrap", Arc::new(|v| json!({ "Wrapped": v })));
+        let read = registry.read::<First>().expect("read patch");
+        assert_eq!(read(json!(0)), json!([[0, 1], 2]));
+        assert!(registry.read::<Second>().is_none());
+        assert!(registry.write::<First>().is_none());
+        let write = registry.write::<Second>().expect("write patch");
+        assert_eq!(write(json!(0)), json!({ "Wrapped": 0 }));
+    }
+}
@@ -16,26 +16,34 @@
 //! PCIe devices
 //!
 
+#[cfg(feature = "chassis")]
+#[cfg(feature = "chassis")]
+#[cfg(feature = "chassis")]
+#[cfg(feature = "chassis")]
+use crate::collection::RedfishCollection;
+#[cfg(feature = "chassis")]
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
 use crate::hardware_id::PartNumber as HardwareIdPartNumber;
 use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
+#[cfg(feature = "chassis")]
 use crate::schema::pcie_device::PcieDevice as PcieDeviceSchema;
 #[cfg(feature = "chassis")]
 use crate::schema::pcie_device_collection::PcieDeviceCollection as PcieDeviceCollectionSchema;
 #[cfg(feature = "chassis")]
 use crate::Error;
-#[cfg(feature = "chassis")]
 use crate::NvBmc;
+#[cfg(feature = "chassis")]
+use crate::RefreshableResource;
 use crate::Resource;
 use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
 use crate::ResourceStatusSchema;
+#[cfg(feature = "chassis")]
 use nv_redfish_core::Bmc;
 #[cfg(feature = "chassis")]
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -61,18 +69,23 @@ impl<B: Bmc> PcieDeviceCollection<B> {
             collection,
         })
     }
+}
 
-    /// List all managers available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching manager data fails.
-    pub async fn members(&self) -> Result<Vec<PcieDevice<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(PcieDevice::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+#[cfg(feature = "chassis")]
+impl<B: Bmc> RedfishCollection<B> for PcieDeviceCollection<B> {
+    type MemberSchema = PcieDeviceSchema;
+    type Member = PcieDevice<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<PcieDeviceSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(&self, nav: &NavProperty<PcieDeviceSchema>) -> Result<PcieDevice<B>, Error<B>> {
+        PcieDevice::new(&self.bmc, nav).await
     }
 }
 
@@ -104,8 +117,9 @@ pub enum FirmwareVersionTag {}
 ///
 /// Provides functions to access PCIe device data.
 pub struct PcieDevice<B: Bmc> {
+    #[allow(dead_code)] // used if chassis feature enabled.
+    bmc: NvBmc<B>,
     data: Arc<PcieDeviceSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> PcieDevice<B> {
@@ -119,8 +133,8 @@ impl<B: Bmc> PcieDevice<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -179,6 +193,17 @@ impl<B: Bmc> Resource for PcieDevice<B> {
     }
 }
 
+#[cfg(feature = "chassis")]
+impl<B: Bmc> RefreshableResource<B> for PcieDevice<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
+
 impl<B: Bmc> ResourceProvidesStatus for PcieDevice<B> {
     fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
         self.data.status.as_ref()
@@ -18,17 +18,20 @@
 //! This module provides typed access to Redfish `PowerEquipment` and the
 //! power shelf resources exposed through its `PowerShelves` collection.
 
+use crate::collection::RedfishCollection;
 use crate::core::NavProperty;
 use crate::schema::power_distribution::PowerDistribution as PowerDistributionSchema;
 use crate::schema::power_distribution_collection::PowerDistributionCollection as PowerDistributionCollectionSchema;
 use crate::schema::power_equipment::PowerEquipment as PowerEquipmentSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use crate::ServiceRoot;
 use nv_redfish_core::Bmc;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 #[doc(inline)]
@@ -112,19 +115,25 @@ impl<B: Bmc> PowerShelfCollection<B> {
             collection,
         })
     }
+}
 
-    /// List all power shelves available in this collection.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching power shelf data fails.
-    pub async fn members(&self) -> Result<Vec<PowerShelf<B>>, Error<B>> {
-        let mut members = Vec::with_capacity(self.collection.members.len());
-        for member in &self.collection.members {
-            members.push(PowerShelf::new(&self.bmc, member).await?);
-        }
-
-        Ok(members)
+impl<B: Bmc> RedfishCollection<B> for PowerShelfCollection<B> {
+    type MemberSchema = PowerDistributionSchema;
+    type Member = PowerShelf<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<PowerDistributionSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(
+        &self,
+        nav: &NavProperty<PowerDistributionSchema>,
+    ) -> Result<PowerShelf<B>, Error<B>> {
+        PowerShelf::new(&self.bmc, nav).await
     }
 }
 
@@ -133,8 +142,8 @@ impl<B: Bmc> PowerShelfCollection<B> {
 /// A power shelf is represented by the Redfish `PowerDistribution` schema with
 /// `EquipmentType` set to `PowerShelf`.
 pub struct PowerShelf<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<PowerDistributionSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> PowerShelf<B> {
@@ -144,8 +153,8 @@ impl<B: Bmc> PowerShelf<B> {
     ) -> Result<Self, Error<B>> {
         let data = nav.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
         Ok(Self {
+            bmc: bmc.clone(),
             data,
-            _marker: PhantomData,
         })
     }
 
@@ -164,3 +173,19 @@ impl<B: Bmc> Resource for PowerShelf<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> ResourceProvidesStatus for PowerShelf<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for PowerShelf<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
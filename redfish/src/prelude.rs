@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Commonly used types and traits
+//!
+//! ```ignore
+//! use nv_redfish::prelude::*;
+//! ```
+//!
+//! brings into scope the entry point (`ServiceRoot`), the `Bmc`
+//! trait, errors, wrappers of enabled services, tagged hardware
+//! identifiers and extension traits whose methods are used on
+//! wrappers. Wrappers are re-exported only when their feature is
+//! enabled.
+
+#[doc(no_inline)]
+pub use crate::Bmc;
+#[doc(no_inline)]
+pub use crate::Capabilities;
+#[doc(no_inline)]
+pub use crate::Error;
+#[doc(no_inline)]
+pub use crate::ServiceRoot;
+
+#[doc(no_inline)]
+pub use crate::core::EntityTypeRef;
+#[doc(no_inline)]
+pub use crate::core::ModificationResponse;
+#[doc(no_inline)]
+pub use crate::core::ODataId;
+
+#[doc(no_inline)]
+#[cfg(feature = "impl-entity-link")]
+pub use crate::entity_link::FromLink;
+#[doc(no_inline)]
+#[cfg(feature = "resource-status")]
+pub use crate::resource::Health;
+#[doc(no_inline)]
+pub use crate::DeletableResource;
+#[doc(no_inline)]
+pub use crate::RedfishCollection;
+#[doc(no_inline)]
+pub use crate::RefreshableResource;
+#[doc(no_inline)]
+pub use crate::Resource;
+#[doc(no_inline)]
+#[cfg(feature = "resource-status")]
+pub use crate::ResourceProvidesStatus;
+#[doc(no_inline)]
+pub use crate::SupportedMethods;
+
+#[doc(no_inline)]
+pub use crate::hardware_id::HardwareId;
+#[doc(no_inline)]
+pub use crate::hardware_id::HardwareIdRef;
+#[doc(no_inline)]
+pub use crate::hardware_id::Manufacturer;
+#[doc(no_inline)]
+pub use crate::hardware_id::Model;
+#[doc(no_inline)]
+pub use crate::hardware_id::PartNumber;
+#[doc(no_inline)]
+pub use crate::hardware_id::SerialNumber;
+#[doc(no_inline)]
+pub use crate::mac_address::MacAddress;
+
+#[doc(no_inline)]
+#[cfg(feature = "accounts")]
+pub use crate::account::Account;
+#[doc(no_inline)]
+#[cfg(feature = "accounts")]
+pub use crate::account::AccountService;
+#[doc(no_inline)]
+#[cfg(feature = "certificates")]
+pub use crate::certificate_service::CertificateService;
+#[doc(no_inline)]
+#[cfg(feature = "chassis")]
+pub use crate::chassis::Chassis;
+#[doc(no_inline)]
+#[cfg(feature = "chassis")]
+pub use crate::chassis::ChassisCollection;
+#[doc(no_inline)]
+#[cfg(feature = "computer-systems")]
+pub use crate::computer_system::ComputerSystem;
+#[doc(no_inline)]
+#[cfg(feature = "computer-systems")]
+pub use crate::computer_system::SystemCollection;
+#[doc(no_inline)]
+#[cfg(feature = "event-service")]
+pub use crate::event_service::EventService;
+#[doc(no_inline)]
+#[cfg(feature = "managers")]
+pub use crate::manager::Manager;
+#[doc(no_inline)]
+#[cfg(feature = "managers")]
+pub use crate::manager::ManagerCollection;
+#[doc(no_inline)]
+#[cfg(feature = "session-service")]
+pub use crate::session_service::SessionService;
+#[doc(no_inline)]
+#[cfg(feature = "task-service")]
+pub use crate::task_service::TaskService;
+#[doc(no_inline)]
+#[cfg(feature = "telemetry-service")]
+pub use crate::telemetry_service::TelemetryService;
+#[doc(no_inline)]
+#[cfg(feature = "update-service")]
+pub use crate::update_service::SoftwareInventory;
+#[doc(no_inline)]
+#[cfg(feature = "update-service")]
+pub use crate::update_service::UpdateService;
+
+#[doc(no_inline)]
+#[cfg(feature = "bmc-http")]
+pub use crate::bmc_http::BmcCredentials;
+#[doc(no_inline)]
+#[cfg(feature = "bmc-http")]
+pub use crate::bmc_http::HttpBmc;
@@ -18,6 +18,7 @@
 use crate::schema::service_root::Expand;
 use crate::schema::service_root::ProtocolFeaturesSupported;
 use std::convert::identity;
+use std::convert::TryFrom as _;
 
 /// Defines features supported by Redfish protocol. Provides helpers
 /// to write code that takes features in account.
@@ -41,11 +42,16 @@ impl ProtocolFeatures {
 }
 
 /// Expand query support.
+#[derive(Clone, Copy)]
 pub struct ExpandQueryFeatures {
     /// Indicates '*' support by the Server.
     pub expand_all: bool,
     /// Indicates '.' support by the Server.
     pub no_links: bool,
+    /// Indicates `$levels` support by the Server.
+    pub levels: bool,
+    /// Maximum number of levels that the Server can expand.
+    pub max_levels: Option<u32>,
 }
 
 // We want to have explicit defaults. Not language one. They are the
@@ -56,6 +62,8 @@ impl Default for ExpandQueryFeatures {
         Self {
             expand_all: false,
             no_links: false,
+            levels: false,
+            max_levels: None,
         }
     }
 }
@@ -65,6 +73,17 @@ impl ExpandQueryFeatures {
         Self {
             expand_all: f.expand_all.is_some_and(identity),
             no_links: f.no_links.is_some_and(identity),
+            levels: f.levels.is_some_and(identity),
+            max_levels: f.max_levels.and_then(|v| u32::try_from(v).ok()),
         }
     }
+
+    /// Number of levels that can be requested by `$levels`: `levels`
+    /// limited by `MaxLevels`. Returns `None` if the Server doesn't
+    /// support `$levels` or only one level can be expanded.
+    #[must_use]
+    pub fn supported_levels(&self, levels: u32) -> Option<u32> {
+        let levels = self.max_levels.map_or(levels, |max| levels.min(max));
+        (self.levels && levels > 1).then_some(levels)
+    }
 }
@@ -0,0 +1,765 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Registry of BMC platform classes and their quirks.
+//!
+//! On first root retrieval [`crate::ServiceRoot`] classifies the
+//! platform using [`QuirkRegistry`] and then applies workarounds
+//! ([`Quirk`]) of the matched [`PlatformClass`]. The default registry
+//! contains platforms known to `nv-redfish`. Applications can
+//! register additional classes for new vendor firmware:
+//!
+//! ```
+//! use nv_redfish::quirks::{Platform, Quirk, QuirkRegistry};
+//!
+//! let mut registry = QuirkRegistry::default();
+//! registry.register(Platform::new(
+//!     "ContosoBmc",
+//!     |info| info.vendor == Some("Contoso"),
+//!     [Quirk::HeadIsNotSupported, Quirk::ExpandIsNotWorkingProperly],
+//! ));
+//! ```
+//!
+//! Platform classes can also be loaded at runtime from a document
+//! with [`QuirkRules`], so that a fleet can work around vendor
+//! misbehavior without waiting for a crate release:
+//!
+//! ```
+//! use nv_redfish::quirks::{QuirkRegistry, QuirkRules};
+//!
+//! let rules = QuirkRules::from_json(
+//!     r#"{
+//!         "platform": [{
+//!             "name": "ContosoBmc",
+//!             "match": {"vendor": "Contoso", "oem": {"Contoso/Firmware": "2.1"}},
+//!             "quirks": ["HeadIsNotSupported"]
+//!         }]
+//!     }"#,
+//! )?;
+//! let mut registry = QuirkRegistry::default();
+//! registry.register_rules(rules);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Updated registry can be applied to a live root with
+//! [`crate::ServiceRoot::reload_quirk_registry`]. Resource handles
+//! obtained from the root use new quirks without being recreated.
+//!
+//! Behind an aggregator that proxies multiple downstream BMCs,
+//! quirks of a downstream platform can be scoped to the resources it
+//! serves with [`crate::ServiceRoot::scope_quirks`], or for all
+//! downstream BMCs classified by their managers with
+//! [`crate::ServiceRoot::scope_aggregated_quirks`].
+
+use crate::core::MaybeSend;
+use crate::core::MaybeSync;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::sync::Arc;
+
+#[cfg(feature = "quirks-toml")]
+use toml::de::Error as TomlError;
+
+/// Workaround for deviation of BMC implementation from the Redfish
+/// specification.
+///
+/// Quirks are (de)serialized by variant name, for example,
+/// `"HeadIsNotSupported"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Quirk {
+    /// `AccountType` is missing in accounts even though it is marked
+    /// with `Redfish.Required`.
+    NoAccountTypeInAccounts,
+    /// BMC cannot create or delete accounts but provides pre-created
+    /// accounts (slots) starting from slot 3. Accounts are "created"
+    /// by updating and enabling a slot and "deleted" by disabling it.
+    SlotDefinedUserAccounts,
+    /// `ReleaseDate` in firmware inventory is set to `00:00:00Z`.
+    FwInventoryWrongReleaseDate,
+    /// `Links.ContainedBy` of chassis contains additional fields.
+    InvalidContainedByFields,
+    /// Navigation properties are missing in the service root.
+    MissingRootNavProperties,
+    /// `ChassisType` is missing in chassis.
+    MissingChassisTypeField,
+    /// `Name` is missing in chassis.
+    MissingChassisNameField,
+    /// `UUID` of chassis and computer systems is an empty string.
+    EmptyUuidField,
+    /// `Name` is missing in update service.
+    MissingUpdateServiceNameField,
+    /// `LastResetTime` of computer system is
+    /// `0000-00-00T00:00:00+00:00`.
+    ComputerSystemsWrongLastResetTime,
+    /// Event records in SSE payload do not include `MemberId`.
+    EventServiceSseNoMemberId,
+    /// Event records in SSE payload use compact timezone offsets in
+    /// `EventTimestamp` (for example, `-0600`).
+    EventServiceSseWrongTimestampOffset,
+    /// Event records in SSE payload omit `EventType`.
+    EventServiceSseMissingEventType,
+    /// Computer system collection contains wrong members. Only
+    /// members ending with `/DGX` or `/HGX_Baseboard_0` are used.
+    FilterComputerSystems,
+    /// Manager collection contains wrong members. Only members
+    /// ending with `/BMC`, `/HGX_BMC_0` or `/HGX_FabricManager_0` are
+    /// used.
+    FilterManagers,
+    /// HEAD requests are answered with `405 Method Not Allowed`.
+    HeadIsNotSupported,
+    /// `$expand` responses drop required properties of embedded
+    /// members, so expand is emulated on the client side.
+    ExpandIsNotWorkingProperly,
+    /// `Name` is missing in account service.
+    MissingAccountServiceNameField,
+    /// `@odata.id` of OEM resources differs in letter case from the
+    /// links pointing to them (for example, `KCSInterface` and
+    /// `KcsInterface`). The link is used as `@odata.id` instead.
+    ODataIdCaseMismatch,
+    /// Arrays of navigation properties (collection `Members`,
+    /// `RelatedItem`, `Links` of computer systems) contain `null`
+    /// entries. The entries are removed.
+    NullEntriesInArrays,
+    /// `Members@odata.count` of collections is greater than number of
+    /// `Members` and the rest of members is not linked with
+    /// `Members@odata.nextLink`. Missing members are requested with
+    /// `$skip`.
+    MembersCountMismatch,
+    /// `@Redfish.Settings` is advertised but updates of the settings
+    /// object are ignored. Resources are updated directly.
+    SettingsObjectIgnored,
+    /// Updates of the settings object are applied only if the request
+    /// includes `@Redfish.SettingsApplyTime`. Updates are sent with
+    /// `OnReset` apply time.
+    SettingsApplyTimeRequired,
+    /// PATCH requests are rejected. Updates are sent with POST.
+    PatchIsNotSupported,
+    /// PATCH requests are rejected. Updates are sent with POST and
+    /// `X-HTTP-Method-Override: PATCH` header.
+    PatchRequiresMethodOverride,
+}
+
+/// Properties of the service root used to classify platform.
+#[derive(Debug, Clone, Copy)]
+pub struct PlatformInfo<'a> {
+    /// `Vendor` of the service root.
+    pub vendor: Option<&'a str>,
+    /// `Product` of the service root.
+    pub product: Option<&'a str>,
+    /// `RedfishVersion` of the service root.
+    pub redfish_version: Option<&'a str>,
+    /// `Oem` object of the service root.
+    pub oem: Option<&'a JsonValue>,
+}
+
+impl PlatformInfo<'_> {
+    /// Get string value of the OEM property of the service root by
+    /// path of property names.
+    #[must_use]
+    pub fn oem_str(&self, path: &[&str]) -> Option<&str> {
+        self.oem_value(path).and_then(JsonValue::as_str)
+    }
+
+    /// Get value of the OEM property of the service root by path of
+    /// property names.
+    #[must_use]
+    pub fn oem_value(&self, path: &[&str]) -> Option<&JsonValue> {
+        path.iter().try_fold(self.oem?, |v, name| v.get(name))
+    }
+
+    /// Check that `RedfishVersion` of the service root is in range
+    /// from `min` (inclusive) to `max` (exclusive). Versions are
+    /// compared by numeric components, so `1.10.0` is greater than
+    /// `1.9.0`. Returns `false` if the version is missing or is not
+    /// numeric.
+    #[must_use]
+    pub fn redfish_version_in(&self, min: &str, max: &str) -> bool {
+        match (
+            self.redfish_version.and_then(parse_version),
+            parse_version(min),
+            parse_version(max),
+        ) {
+            (Some(version), Some(min), Some(max)) => min <= version && version < max,
+            _ => false,
+        }
+    }
+}
+
+fn parse_version(version: &str) -> Option<Vec<u32>> {
+    version.split('.').map(|v| v.parse().ok()).collect()
+}
+
+/// Class of devices that have the same set of quirks.
+///
+/// Platform shouldn't be considered as vendor: one vendor may have
+/// several classes and one class may combine devices of different
+/// vendors.
+pub trait PlatformClass: MaybeSend + MaybeSync {
+    /// Name of the platform class.
+    fn name(&self) -> &str;
+
+    /// Check if the service root belongs to this class.
+    fn matches(&self, info: &PlatformInfo<'_>) -> bool;
+
+    /// Quirks of the platform class.
+    fn quirks(&self) -> &[Quirk];
+}
+
+/// Platform class defined by matching function and list of quirks.
+pub struct Platform {
+    name: String,
+    matcher: Box<dyn Fn(&PlatformInfo<'_>) -> bool + Send + Sync>,
+    quirks: Vec<Quirk>,
+}
+
+impl Platform {
+    /// Create new platform class.
+    pub fn new<F>(
+        name: impl Into<String>,
+        matcher: F,
+        quirks: impl IntoIterator<Item = Quirk>,
+    ) -> Self
+    where
+        F: Fn(&PlatformInfo<'_>) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            matcher: Box::new(matcher),
+            quirks: quirks.into_iter().collect(),
+        }
+    }
+}
+
+impl Debug for Platform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Platform")
+            .field("name", &self.name)
+            .field("quirks", &self.quirks)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PlatformClass for Platform {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn matches(&self, info: &PlatformInfo<'_>) -> bool {
+        (self.matcher)(info)
+    }
+
+    fn quirks(&self) -> &[Quirk] {
+        &self.quirks
+    }
+}
+
+/// Platform classes loaded from a JSON or TOML document.
+///
+/// Document contains list of `platform` entries. Each entry has a
+/// `name`, `match` conditions and list of enabled `quirks`. All
+/// specified conditions must match. Keys of `oem` conditions are
+/// `/`-separated paths inside `Oem` object of the service root. In
+/// TOML:
+///
+/// ```toml
+/// [[platform]]
+/// name = "ContosoBmc"
+/// quirks = ["HeadIsNotSupported", "ExpandIsNotWorkingProperly"]
+///
+/// [platform.match]
+/// vendor = "Contoso"
+/// redfish_version = "1.11.0"
+/// oem = { "Contoso/Firmware" = "2.1" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuirkRules {
+    /// Platform classes in order of increasing precedence.
+    #[serde(default, rename = "platform")]
+    pub platforms: Vec<PlatformRule>,
+}
+
+impl QuirkRules {
+    /// Parse rules from JSON document.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if document is not valid JSON or doesn't match
+    /// rules format (for example, contains unknown quirk).
+    pub fn from_json(document: &str) -> Result<Self, JsonError> {
+        serde_json::from_str(document)
+    }
+
+    /// Parse rules from TOML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if document is not valid TOML or doesn't match
+    /// rules format (for example, contains unknown quirk).
+    #[cfg(feature = "quirks-toml")]
+    pub fn from_toml(document: &str) -> Result<Self, TomlError> {
+        toml::from_str(document)
+    }
+}
+
+/// Platform class loaded from [`QuirkRules`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlatformRule {
+    /// Name of the platform class.
+    pub name: String,
+    /// Conditions that must all match the service root.
+    #[serde(default, rename = "match")]
+    pub conditions: PlatformConditions,
+    /// Quirks enabled for the platform class.
+    #[serde(default)]
+    pub quirks: Vec<Quirk>,
+}
+
+impl PlatformClass for PlatformRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn matches(&self, info: &PlatformInfo<'_>) -> bool {
+        self.conditions.matches(info)
+    }
+
+    fn quirks(&self) -> &[Quirk] {
+        &self.quirks
+    }
+}
+
+/// Match conditions of [`PlatformRule`]. Absent conditions match any
+/// service root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlatformConditions {
+    /// Expected `Vendor` of the service root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    /// Expected `Product` of the service root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+    /// Expected `RedfishVersion` of the service root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redfish_version: Option<String>,
+    /// Expected string values of OEM properties by `/`-separated
+    /// path.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub oem: BTreeMap<String, String>,
+}
+
+impl PlatformConditions {
+    fn matches(&self, info: &PlatformInfo<'_>) -> bool {
+        let eq = |expected: &Option<String>, value: Option<&str>| {
+            expected.as_ref().is_none_or(|v| Some(v.as_str()) == value)
+        };
+        eq(&self.vendor, info.vendor)
+            && eq(&self.product, info.product)
+            && eq(&self.redfish_version, info.redfish_version)
+            && self.oem.iter().all(|(path, expected)| {
+                let path = path.split('/').collect::<Vec<_>>();
+                info.oem_str(&path) == Some(expected.as_str())
+            })
+    }
+}
+
+/// Registry of platform classes.
+///
+/// Classes are checked in reverse order of registration, so a class
+/// registered later takes precedence over earlier ones (including
+/// built-in classes).
+#[derive(Clone)]
+pub struct QuirkRegistry {
+    classes: Vec<Arc<dyn PlatformClass>>,
+}
+
+impl QuirkRegistry {
+    /// Create empty registry. No quirks are applied for any platform.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            classes: Vec::new(),
+        }
+    }
+
+    /// Create registry with platform classes known to `nv-redfish`.
+    #[must_use]
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        // Generic classes are registered before specific ones to
+        // take lower precedence.
+        registry.register(Platform::new(
+            "Hpe",
+            |info| info.vendor == Some("HPE"),
+            [Quirk::NoAccountTypeInAccounts],
+        ));
+        registry.register(Platform::new(
+            "Dell",
+            |info| info.vendor == Some("Dell"),
+            [
+                Quirk::SlotDefinedUserAccounts,
+                Quirk::FwInventoryWrongReleaseDate,
+                Quirk::ComputerSystemsWrongLastResetTime,
+                Quirk::EventServiceSseWrongTimestampOffset,
+                Quirk::SettingsApplyTimeRequired,
+            ],
+        ));
+        registry.register(Platform::new(
+            "Supermicro",
+            |info| info.vendor == Some("Supermicro"),
+            [
+                Quirk::NoAccountTypeInAccounts,
+                Quirk::MissingAccountServiceNameField,
+                Quirk::ODataIdCaseMismatch,
+            ],
+        ));
+        // Generic AMI MegaRAC SP-X firmware exposes `Oem.Ami` in the
+        // service root. Products based on it (GB300, Viking) are
+        // registered below with their own workaround sets.
+        registry.register(Platform::new(
+            "AmiMegaRacSpx",
+            |info| {
+                info.vendor == Some("AMI")
+                    && info.oem_value(&["Ami"]).is_some_and(JsonValue::is_object)
+                    && info.redfish_version_in("1.8.0", "2.0.0")
+            },
+            [
+                Quirk::InvalidContainedByFields,
+                Quirk::MissingUpdateServiceNameField,
+            ],
+        ));
+        // The GB300 host BMC exposes an AMI OEM `RtpVersion` in the
+        // service root; use it to distinguish GB300 from other AMI
+        // BMCs so the expand workaround is not applied to every AMI
+        // platform.
+        registry.register(Platform::new(
+            "AmiGb300",
+            |info| {
+                info.vendor == Some("AMI")
+                    && info.oem_str(&["Ami", "RtpVersion"]) == Some("13.09.1")
+            },
+            [Quirk::ExpandIsNotWorkingProperly],
+        ));
+        registry.register(Platform::new(
+            "AmiViking",
+            |info| info.vendor == Some("AMI") && info.redfish_version == Some("1.11.0"),
+            [
+                Quirk::InvalidContainedByFields,
+                // There are situations when Viking doesn't provide
+                // any navigation properties in root before BMC reset.
+                Quirk::MissingRootNavProperties,
+                Quirk::MissingChassisTypeField,
+                Quirk::MissingChassisNameField,
+                Quirk::MissingUpdateServiceNameField,
+                Quirk::FilterComputerSystems,
+                Quirk::FilterManagers,
+                Quirk::HeadIsNotSupported,
+                Quirk::ExpandIsNotWorkingProperly,
+            ],
+        ));
+        registry.register(Platform::new(
+            "Nvidia",
+            |info| info.vendor == Some("NVIDIA"),
+            [
+                Quirk::EventServiceSseNoMemberId,
+                Quirk::EventServiceSseMissingEventType,
+            ],
+        ));
+        registry.register(Platform::new(
+            "NvSwitch",
+            |info| info.vendor == Some("NVIDIA") && info.product == Some("P3809"),
+            [],
+        ));
+        registry.register(Platform::new(
+            "NvidiaDpu",
+            |info| info.vendor == Some("Nvidia") && info.product == Some("Nvidia-BMCMezz"),
+            [Quirk::EmptyUuidField],
+        ));
+        // LiteonPowershelf doesn't provide Systems.
+        registry.register(Platform::new(
+            "Anonymous1_9_0",
+            |info| info.vendor.is_none() && info.redfish_version == Some("1.9.0"),
+            [Quirk::MissingRootNavProperties],
+        ));
+        registry
+    }
+
+    /// Register platform class. It takes precedence over all classes
+    /// registered before.
+    pub fn register(&mut self, class: impl PlatformClass + 'static) {
+        self.classes.push(Arc::new(class));
+    }
+
+    /// Register all platform classes of `rules`. Classes of the rules
+    /// take precedence over all classes registered before.
+    pub fn register_rules(&mut self, rules: QuirkRules) {
+        for rule in rules.platforms {
+            self.register(rule);
+        }
+    }
+
+    /// Find platform class by name. If several classes have the same
+    /// name, the one registered last is returned.
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<&dyn PlatformClass> {
+        self.classes
+            .iter()
+            .rev()
+            .find(|class| class.name() == name)
+            .map(AsRef::as_ref)
+    }
+
+    /// Find platform class of the service root.
+    #[must_use]
+    pub fn classify(&self, info: &PlatformInfo<'_>) -> Option<&dyn PlatformClass> {
+        self.classes
+            .iter()
+            .rev()
+            .find(|class| class.matches(info))
+            .map(AsRef::as_ref)
+    }
+}
+
+impl Default for QuirkRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl Debug for QuirkRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list()
+            .entries(self.classes.iter().map(|class| class.name()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn classify<'a>(registry: &'a QuirkRegistry, info: &PlatformInfo<'_>) -> Option<&'a str> {
+        registry.classify(info).map(PlatformClass::name)
+    }
+
+    const EMPTY: PlatformInfo<'static> = PlatformInfo {
+        vendor: None,
+        product: None,
+        redfish_version: None,
+        oem: None,
+    };
+
+    #[test]
+    fn builtin_classes() {
+        let registry = QuirkRegistry::default();
+        let oem = json!({"Ami": {"RtpVersion": "13.09.1"}});
+        let nvidia = PlatformInfo {
+            vendor: Some("NVIDIA"),
+            ..EMPTY
+        };
+        let ami = PlatformInfo {
+            vendor: Some("AMI"),
+            ..EMPTY
+        };
+        assert_eq!(classify(&registry, &nvidia), Some("Nvidia"));
+        assert_eq!(
+            classify(
+                &registry,
+                &PlatformInfo {
+                    vendor: Some("Supermicro"),
+                    ..EMPTY
+                }
+            ),
+            Some("Supermicro")
+        );
+        assert_eq!(
+            classify(
+                &registry,
+                &PlatformInfo {
+                    product: Some("P3809"),
+                    ..nvidia
+                }
+            ),
+            Some("NvSwitch")
+        );
+        assert_eq!(classify(&registry, &ami), None);
+        let spx = json!({"Ami": {"ManagerBootConfiguration": {}}});
+        assert_eq!(
+            classify(
+                &registry,
+                &PlatformInfo {
+                    redfish_version: Some("1.15.1"),
+                    oem: Some(&spx),
+                    ..ami
+                }
+            ),
+            Some("AmiMegaRacSpx")
+        );
+        assert_eq!(
+            classify(
+                &registry,
+                &PlatformInfo {
+                    redfish_version: Some("1.6.0"),
+                    oem: Some(&spx),
+                    ..ami
+                }
+            ),
+            None
+        );
+        assert_eq!(
+            classify(
+                &registry,
+                &PlatformInfo {
+                    redfish_version: Some("1.11.0"),
+                    oem: Some(&spx),
+                    ..ami
+                }
+            ),
+            Some("AmiViking")
+        );
+        assert_eq!(
+            classify(
+                &registry,
+                &PlatformInfo {
+                    oem: Some(&oem),
+                    ..ami
+                }
+            ),
+            Some("AmiGb300")
+        );
+        assert_eq!(
+            classify(
+                &registry,
+                &PlatformInfo {
+                    redfish_version: Some("1.11.0"),
+                    oem: Some(&oem),
+                    ..ami
+                }
+            ),
+            Some("AmiViking")
+        );
+        assert_eq!(classify(&registry, &EMPTY), None);
+    }
+
+    #[test]
+    fn registered_class_takes_precedence() {
+        let mut registry = QuirkRegistry::default();
+        registry.register(Platform::new(
+            "NewNvidia",
+            |info| info.vendor == Some("NVIDIA") && info.product == Some("New"),
+            [Quirk::HeadIsNotSupported],
+        ));
+        let info = PlatformInfo {
+            vendor: Some("NVIDIA"),
+            product: Some("New"),
+            ..EMPTY
+        };
+        let class = registry.classify(&info);
+        assert_eq!(class.map(PlatformClass::name), Some("NewNvidia"));
+        assert_eq!(
+            class.map(PlatformClass::quirks),
+            Some(&[Quirk::HeadIsNotSupported][..])
+        );
+        assert!(QuirkRegistry::new().classify(&info).is_none());
+        assert_eq!(
+            registry.find("Dell").map(PlatformClass::quirks),
+            Some(
+                &[
+                    Quirk::SlotDefinedUserAccounts,
+                    Quirk::FwInventoryWrongReleaseDate,
+                    Quirk::ComputerSystemsWrongLastResetTime,
+                    Quirk::EventServiceSseWrongTimestampOffset,
+                    Quirk::SettingsApplyTimeRequired,
+                ][..]
+            )
+        );
+        assert!(registry.find("Unknown").is_none());
+    }
+
+    #[test]
+    fn rules_from_json() {
+        let rules = QuirkRules::from_json(
+            r#"{
+                "platform": [{
+                    "name": "Gb300Hotfix",
+                    "match": {"vendor": "AMI", "oem": {"Ami/RtpVersion": "13.09.2"}},
+                    "quirks": ["ExpandIsNotWorkingProperly", "HeadIsNotSupported"]
+                }]
+            }"#,
+        )
+        .expect("valid rules");
+        let mut registry = QuirkRegistry::default();
+        registry.register_rules(rules);
+        let oem = json!({"Ami": {"RtpVersion": "13.09.2"}});
+        let info = PlatformInfo {
+            vendor: Some("AMI"),
+            oem: Some(&oem),
+            ..EMPTY
+        };
+        let class = registry.classify(&info).expect("classified");
+        assert_eq!(class.name(), "Gb300Hotfix");
+        assert_eq!(
+            class.quirks(),
+            &[Quirk::ExpandIsNotWorkingProperly, Quirk::HeadIsNotSupported]
+        );
+        let other = json!({"Ami": {"RtpVersion": "13.09.3"}});
+        let info = PlatformInfo {
+            oem: Some(&other),
+            ..info
+        };
+        assert!(registry.classify(&info).is_none());
+        assert!(
+            QuirkRules::from_json(r#"{"platform": [{"name": "X", "quirks": ["Unknown"]}]}"#)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "quirks-toml")]
+    #[test]
+    fn rules_from_toml() {
+        let rules = QuirkRules::from_toml(
+            r#"
+            [[platform]]
+            name = "ContosoBmc"
+            quirks = ["HeadIsNotSupported"]
+
+            [platform.match]
+            vendor = "Contoso"
+            redfish_version = "1.11.0"
+            "#,
+        )
+        .expect("valid rules");
+        assert_eq!(
+            rules.platforms,
+            vec![PlatformRule {
+                name: "ContosoBmc".into(),
+                conditions: PlatformConditions {
+                    vendor: Some("Contoso".into()),
+                    redfish_version: Some("1.11.0".into()),
+                    ..PlatformConditions::default()
+                },
+                quirks: vec![Quirk::HeadIsNotSupported],
+            }]
+        );
+    }
+}
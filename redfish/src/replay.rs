@@ -0,0 +1,428 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay of recorded BMC exchanges.
+//!
+//! [`ReplayBmc`] serves responses recorded by
+//! [`JournalBmc`](crate::journal::JournalBmc), so regression tests can
+//! be built from traffic captured against real hardware:
+//!
+//! ```ignore
+//! // Recording against the BMC.
+//! let journal = FileJournal::create("dell-r750.jsonl")?;
+//! let bmc = JournalBmc::new(bmc, journal).with_redaction(Redaction::None);
+//! let root = ServiceRoot::new(Arc::new(bmc)).await?;
+//! // ...
+//!
+//! // Replay in the test.
+//! let bmc = ReplayBmc::from_file("dell-r750.jsonl")?;
+//! let root = ServiceRoot::new(Arc::new(bmc)).await?;
+//! ```
+//!
+//! Requests are matched by HTTP method, operation and resource.
+//! Repeated requests to the same resource are served with recorded
+//! responses in the order of recording. Request bodies are not
+//! compared because they may be redacted in the recording.
+
+use crate::journal::JournalEntry;
+use crate::journal::JournalOutcome;
+use crate::journal::REDACTED;
+use futures_util::stream;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::AsyncTask;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::FilterQuery;
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartRequest;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UpdateMethod;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::error::Error as StdError;
+use std::fmt;
+use std::fs;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+/// Errors of [`ReplayBmc`].
+#[derive(Debug)]
+pub enum ReplayError {
+    /// No recorded response is left for the request.
+    NotRecorded {
+        /// HTTP method of the request.
+        method: String,
+        /// BMC operation of the request.
+        operation: String,
+        /// Resource or URI of the request.
+        odata_id: ODataId,
+    },
+    /// Request failed when it was recorded.
+    Recorded(String),
+    /// Response body was redacted when it was recorded.
+    Redacted(ODataId),
+    /// Recorded response doesn't match the requested type.
+    Json(JsonError),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotRecorded {
+                method,
+                operation,
+                odata_id,
+            } => write!(f, "no recorded response: {method} {odata_id} ({operation})"),
+            Self::Recorded(err) => write!(f, "recorded error: {err}"),
+            Self::Redacted(id) => write!(f, "response body is redacted: {id}"),
+            Self::Json(err) => write!(f, "JSON error: {err}"),
+        }
+    }
+}
+
+impl StdError for ReplayError {}
+
+type ExchangeKey = (String, String, ODataId);
+
+/// BMC that serves responses recorded in [`JournalEntry`] records.
+#[derive(Debug, Default)]
+pub struct ReplayBmc {
+    exchanges: Mutex<HashMap<ExchangeKey, VecDeque<JournalOutcome>>>,
+}
+
+impl ReplayBmc {
+    /// Create BMC that serves `entries`.
+    pub fn new(entries: impl IntoIterator<Item = JournalEntry>) -> Self {
+        let mut exchanges = HashMap::<_, VecDeque<_>>::new();
+        for entry in entries {
+            exchanges
+                .entry((entry.method, entry.operation, entry.odata_id))
+                .or_default()
+                .push_back(entry.outcome);
+        }
+        Self {
+            exchanges: Mutex::new(exchanges),
+        }
+    }
+
+    /// Create BMC that serves entries of the file written by
+    /// [`FileJournal`](crate::journal::FileJournal).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or a line is not a
+    /// valid journal entry.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, IoError> {
+        let content = fs::read_to_string(path)?;
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|err| IoError::new(ErrorKind::InvalidData, err))
+            })
+            .collect::<Result<Vec<JournalEntry>, _>>()
+            .map(Self::new)
+    }
+
+    /// Number of recorded responses that were not served yet.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.exchanges
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .map(VecDeque::len)
+            .sum()
+    }
+
+    fn next(
+        &self,
+        method: &str,
+        operation: &str,
+        id: &ODataId,
+    ) -> Result<JournalOutcome, ReplayError> {
+        let outcome = self
+            .exchanges
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(&(method.into(), operation.into(), id.clone()))
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| ReplayError::NotRecorded {
+                method: method.into(),
+                operation: operation.into(),
+                odata_id: id.clone(),
+            })?;
+        match outcome {
+            JournalOutcome::Error(err) => Err(ReplayError::Recorded(err)),
+            outcome => Ok(outcome),
+        }
+    }
+
+    fn body(id: &ODataId, outcome: JournalOutcome) -> Result<JsonValue, ReplayError> {
+        match outcome {
+            JournalOutcome::Entity(Some(body)) => Ok(body),
+            JournalOutcome::Entity(None) => Err(ReplayError::Redacted(id.clone())),
+            _ => Ok(JsonValue::Null),
+        }
+    }
+
+    fn read<T: for<'de> Deserialize<'de>>(
+        &self,
+        operation: &str,
+        id: &ODataId,
+    ) -> Result<Arc<T>, ReplayError> {
+        let body = Self::body(id, self.next("GET", operation, id)?)?;
+        T::deserialize(body)
+            .map(Arc::new)
+            .map_err(ReplayError::Json)
+    }
+
+    fn write<R: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        operation: &str,
+        id: &ODataId,
+    ) -> Result<ModificationResponse<R>, ReplayError> {
+        match self.next(method, operation, id)? {
+            JournalOutcome::Task(location) => Ok(ModificationResponse::Task(AsyncTask {
+                location: location.into(),
+                retry_after: None,
+            })),
+            JournalOutcome::Empty | JournalOutcome::Stream => Ok(ModificationResponse::Empty),
+            outcome => R::deserialize(Self::body(id, outcome)?)
+                .map(ModificationResponse::Entity)
+                .map_err(ReplayError::Json),
+        }
+    }
+}
+
+impl Bmc for ReplayBmc {
+    type Error = ReplayError;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        _query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.read("expand", id)
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.read("get", id)
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        _etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        match self.next("GET", "get_if_none_match", id)? {
+            JournalOutcome::Empty => Ok(None),
+            outcome => T::deserialize(Self::body(id, outcome)?)
+                .map(|entity| Some(Arc::new(entity)))
+                .map_err(ReplayError::Json),
+        }
+    }
+
+    async fn head(&self, id: &ODataId) -> Result<(), Self::Error> {
+        self.next("HEAD", "head", id).map(|_| ())
+    }
+
+    fn allowed_methods(&self, _id: &ODataId) -> Option<AllowedMethods> {
+        // Allowed methods are not journaled.
+        None
+    }
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        _query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.read("filter", id)
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        _query: PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.read("page", id)
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        _query: SelectQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.read("select", id)
+    }
+
+    async fn create<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        _query: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.write("POST", "create", id)
+    }
+
+    async fn create_session<
+        V: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        _query: &V,
+    ) -> Result<SessionCreateResponse<R>, Self::Error> {
+        let body = Self::body(id, self.next("POST", "create_session", id)?)?;
+        // Session token and location are not journaled.
+        let location = body
+            .get("@odata.id")
+            .and_then(JsonValue::as_str)
+            .map_or_else(|| id.clone(), |location| location.to_string().into());
+        Ok(SessionCreateResponse {
+            entity: R::deserialize(body).map_err(ReplayError::Json)?,
+            auth_token: REDACTED.into(),
+            location,
+        })
+    }
+
+    async fn update<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        _etag: Option<&ODataETag>,
+        _update: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.write("PATCH", "update", id)
+    }
+
+    async fn update_with_method<
+        V: MaybeSync + MaybeSend + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        _etag: Option<&ODataETag>,
+        _update: &V,
+        method: UpdateMethod,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let http_method = match method {
+            UpdateMethod::Patch => "PATCH",
+            UpdateMethod::Post | UpdateMethod::PostWithMethodOverride => "POST",
+        };
+        self.write(http_method, "update", id)
+    }
+
+    async fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
+        &self,
+        id: &ODataId,
+        _etag: Option<&ODataETag>,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.write("DELETE", "delete", id)
+    }
+
+    async fn action<
+        T: MaybeSend + MaybeSync + Serialize,
+        R: MaybeSend + MaybeSync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        action: &Action<T, R>,
+        _params: &T,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let target = ODataId::from(action.target.to_string());
+        self.write("POST", "action", &target)
+    }
+
+    async fn multipart_update<U, V, R>(
+        &self,
+        uri: &str,
+        _request: MultipartUpdateRequest<'_, U, V>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+        V: MaybeSend + MaybeSync + Serialize,
+    {
+        self.write("POST", "multipart_update", &uri.to_string().into())
+    }
+
+    async fn multipart_upload<R>(
+        &self,
+        uri: &str,
+        _request: MultipartRequest,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    {
+        self.write("POST", "multipart_upload", &uri.to_string().into())
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn http_push_uri_update<U, R>(
+        &self,
+        uri: &str,
+        _request: HttpPushUriUpdateRequest<U>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: MaybeSend + MaybeSync + for<'de> Deserialize<'de>,
+    {
+        self.write("POST", "http_push_uri_update", &uri.to_string().into())
+    }
+
+    async fn stream<T: Sized + for<'de> Deserialize<'de> + MaybeSend + 'static>(
+        &self,
+        uri: &str,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        // Streamed items are not journaled: replayed stream is empty.
+        self.next("GET", "stream", &uri.to_string().into())?;
+        Ok(Box::pin(stream::empty()))
+    }
+
+    async fn download(&self, uri: &str) -> Result<BoxTryStream<Vec<u8>, Self::Error>, Self::Error> {
+        self.next("GET", "download", &uri.to_string().into())?;
+        Ok(Box::pin(stream::empty()))
+    }
+}
@@ -15,9 +15,15 @@
 
 //! Redfish resource
 
+use crate::core::AllowedMethods;
+use crate::core::Bmc;
 use crate::core::EntityTypeRef as _;
+use crate::core::MaybeSend;
+use crate::core::ModificationResponse;
 use crate::core::ODataId;
+use crate::Error;
 use crate::ResourceSchema;
+use std::future::Future;
 use tagged_types::TaggedType;
 
 #[cfg(feature = "oem")]
@@ -25,7 +31,11 @@ use crate::oem::OemIdentifier;
 #[cfg(feature = "resource-status")]
 use crate::ResourceStatusSchema;
 #[cfg(feature = "resource-status")]
+use serde::Serialize;
+#[cfg(feature = "resource-status")]
 use std::convert::identity;
+#[cfg(feature = "resource-status")]
+use std::fmt;
 
 #[doc(inline)]
 #[cfg(feature = "resource-status")]
@@ -116,6 +126,70 @@ pub trait Resource {
     }
 }
 
+/// Represents Redfish resource that can be deleted.
+///
+/// Implemented by wrappers of resources whose schema allows DELETE
+/// (`Capabilities.DeleteRestrictions` of the CSDL). Resources that
+/// are not deletable according to the schema don't implement this
+/// trait. Wrappers also provide an inherent `delete` method, so the
+/// trait only needs to be imported for generic code.
+///
+/// The request is not sent when the `Allow` header of the response
+/// the resource was retrieved with doesn't list DELETE; in that case
+/// [`Error::DeleteNotAllowed`] is returned. Requests rejected by the
+/// service (for example, with `405 Method Not Allowed`) are reported
+/// as errors of the BMC.
+pub trait DeletableResource<B: Bmc>: Sized {
+    /// Delete the resource.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the resource returned
+    ///   by the server.
+    /// - `ModificationResponse::Task` identifies an asynchronous
+    ///   operation.
+    /// - `ModificationResponse::Empty` reports synchronous success
+    ///   without a response body, or completion of the task when task
+    ///   following is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deletion fails.
+    fn delete(
+        &self,
+    ) -> impl Future<Output = Result<ModificationResponse<Self>, Error<B>>> + MaybeSend;
+}
+
+/// Represents Redfish resource that can report HTTP methods allowed
+/// by the service.
+///
+/// Implemented by wrappers of resources that can be modified. It lets
+/// tools find out whether PATCH, POST or DELETE is permitted on the
+/// resource of a particular BMC before attempting the write.
+pub trait SupportedMethods<B: Bmc> {
+    /// Methods allowed for the resource according to the `Allow`
+    /// header of the response the resource was retrieved with.
+    /// Returns `None` if the BMC didn't report them. No request is
+    /// sent to the BMC.
+    fn supported_methods(&self) -> Option<AllowedMethods>;
+}
+
+/// Represents Redfish resource that can be fetched again.
+///
+/// Wrappers keep the data that was fetched when the wrapper was
+/// created. Actions and updates of settings don't change this data,
+/// so the resource must be fetched again to observe their effect.
+pub trait RefreshableResource<B: Bmc>: Sized {
+    /// Fetch the resource again by its `@odata.id` and return a new
+    /// handle with the current data. The handle itself is not
+    /// changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the resource fails.
+    fn refresh(&self) -> impl Future<Output = Result<Self, Error<B>>> + MaybeSend;
+}
+
 #[cfg(feature = "oem")]
 pub(crate) fn oem_id_from_resource(r: &ResourceSchema) -> Option<&str> {
     r.base
@@ -128,7 +202,7 @@ pub(crate) fn oem_id_from_resource(r: &ResourceSchema) -> Option<&str> {
 
 /// The status and health of a resource and its children.
 #[cfg(feature = "resource-status")]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Status {
     /// The state of the resource.
     pub state: Option<State>,
@@ -153,4 +227,45 @@ pub trait ResourceProvidesStatus {
             health_rollup: status.health_rollup.and_then(identity),
         })
     }
+
+    /// State of the resource if it is provided.
+    fn state(&self) -> Option<State> {
+        self.resource_status_ref()
+            .and_then(|status| status.state.and_then(identity))
+    }
+
+    /// Health of the resource in the absence of its dependent
+    /// resources if it is provided.
+    fn health(&self) -> Option<Health> {
+        self.resource_status_ref()
+            .and_then(|status| status.health.and_then(identity))
+    }
+
+    /// Overall health of the resource and its dependent resources if
+    /// it is provided.
+    fn health_rollup(&self) -> Option<Health> {
+        self.resource_status_ref()
+            .and_then(|status| status.health_rollup.and_then(identity))
+    }
+}
+
+/// Write one-line summary of the resource for logs and CLI output:
+/// `<kind> <id>: <detail> <value>, ..., state <state>`. Missing
+/// values are written as `-`.
+#[cfg(feature = "resource-status")]
+pub(crate) fn fmt_summary<R>(
+    f: &mut fmt::Formatter<'_>,
+    kind: &str,
+    resource: &R,
+    details: &[(&str, Option<&str>)],
+) -> fmt::Result
+where
+    R: Resource + ResourceProvidesStatus,
+{
+    write!(f, "{kind} {}:", resource.id())?;
+    for (name, value) in details {
+        write!(f, " {name} {},", value.unwrap_or("-"))?;
+    }
+    let state = resource.state().map(|state| format!("{state:?}"));
+    write!(f, " state {}", state.as_deref().unwrap_or("-"))
 }
@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Retries of failed requests.
+//!
+//! [`RetryBmc`] wraps any [`Bmc`] and sends failed requests again
+//! according to [`RetryPolicy`]. The policy sees only the kind of the
+//! request and [`BmcErrorInfo`] of the error, so the same policy works
+//! with every transport. It is usually configured with the service
+//! root:
+//!
+//! ```ignore
+//! let root = ServiceRoot::builder(bmc)
+//!     .retry(RetryPolicy::transient(), Sleep::new(tokio::time::sleep))
+//!     .build()
+//!     .await?;
+//! ```
+//!
+//! [`Bmc`]: nv_redfish_core::Bmc
+
+use crate::layer::BmcLayer;
+use crate::layer::Layered;
+use crate::layer::Request;
+use crate::layer::RequestKind;
+use crate::sleep::Sleep;
+use crate::sleep::SleepFuture;
+use nv_redfish_core::BmcErrorInfo;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use std::collections::hash_map::RandomState;
+use std::convert::TryFrom as _;
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::ready;
+use std::future::Future;
+use std::hash::BuildHasher as _;
+use std::hash::Hasher as _;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Classifier deciding whether a failed request should be retried.
+type RetryClassifier = dyn Fn(&Request<'_>, &dyn BmcErrorInfo) -> bool + Send + Sync;
+
+/// Retry policy with a configurable delay between attempts.
+///
+/// While retries remain, the classifier is called for every failed
+/// request and decides whether to retry it. Uploads are sent exactly
+/// once and never retried.
+///
+/// The delay between attempts is fixed unless [`Self::backoff`] is
+/// set, in which case it doubles after every attempt up to the given
+/// maximum. [`Self::jitter`] randomizes each delay between half and
+/// full value so that clients hitting the same BMC don't retry in
+/// lockstep. With [`Self::honor_retry_after`] the delay requested by
+/// the BMC (see [`BmcErrorInfo::retry_after`], for example, of a `503`
+/// returned while a task monitor or a settings resource is busy) is
+/// used instead.
+///
+/// [`Self::transient`] provides a policy for transient BMC failures.
+///
+/// # Examples
+///
+/// Retry only reads that fail with `503 Service Unavailable`. Writes
+/// such as `POST`, `PATCH`, or `DELETE` are never retried:
+///
+/// ```rust
+/// use nv_redfish::layer::RequestKind;
+/// use nv_redfish::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(|request, err| {
+///     request.kind == RequestKind::Read && err.status_code() == Some(503)
+/// })
+/// .max_retries(3)
+/// .delay(Duration::from_millis(500));
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Number of extra attempts after the first one.
+    max_retries: u32,
+    /// Sleep before the first retry; `None` retries immediately.
+    delay: Option<Duration>,
+    /// Maximum delay of exponential backoff; `None` keeps the delay fixed.
+    max_delay: Option<Duration>,
+    /// Randomize delays between half and full value.
+    jitter: bool,
+    /// Use delay requested by the BMC.
+    honor_retry_after: bool,
+    /// Decides whether a failed request should be retried.
+    classifier: Arc<RetryClassifier>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that retries requests whose errors are
+    /// accepted by `classifier`.
+    ///
+    /// By default no retries are performed; configure them with
+    /// [`Self::max_retries`] and [`Self::delay`].
+    #[must_use]
+    pub fn new<F>(classifier: F) -> Self
+    where
+        F: Fn(&Request<'_>, &dyn BmcErrorInfo) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            max_retries: 0,
+            delay: None,
+            max_delay: None,
+            jitter: false,
+            honor_retry_after: false,
+            classifier: Arc::new(classifier),
+        }
+    }
+
+    /// Creates a policy for transient BMC failures.
+    ///
+    /// Reads are retried up to 3 times when no response is received
+    /// (for example, on timeout) or the BMC responds with `429 Too
+    /// Many Requests`, `502 Bad Gateway` or `503 Service
+    /// Unavailable`. Delay starts at 500 ms and doubles up to 8 s,
+    /// with jitter. Delays requested by the BMC with `Retry-After` are
+    /// honored up to the same maximum.
+    #[must_use]
+    pub fn transient() -> Self {
+        Self::new(|request, err| {
+            request.kind == RequestKind::Read
+                && (err.is_transport() || matches!(err.status_code(), Some(429 | 502 | 503)))
+        })
+        .max_retries(3)
+        .delay(Duration::from_millis(500))
+        .backoff(Duration::from_secs(8))
+        .jitter(true)
+        .honor_retry_after(true)
+    }
+
+    /// Maximum number of extra attempts after the initial request.
+    #[must_use]
+    pub const fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Delay to sleep between attempts. With [`Self::backoff`] this is
+    /// the delay before the first retry.
+    #[must_use]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Double the delay after every attempt, up to `max_delay`.
+    #[must_use]
+    pub const fn backoff(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// Randomize every delay between half and full value.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sleep for the delay requested by the BMC in the `Retry-After`
+    /// header of the failed response instead of the configured delay.
+    /// The delay is limited by the maximum of [`Self::backoff`] if set.
+    #[must_use]
+    pub const fn honor_retry_after(mut self, honor_retry_after: bool) -> Self {
+        self.honor_retry_after = honor_retry_after;
+        self
+    }
+
+    /// Delay before retry number `attempt` (starting from 0) of a
+    /// request that failed with `retry_after` delay.
+    fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if let Some(retry_after) = retry_after.filter(|_| self.honor_retry_after) {
+            return Some(
+                self.max_delay
+                    .map_or(retry_after, |max_delay| retry_after.min(max_delay)),
+            );
+        }
+        let delay = self.delay?;
+        let delay = self.max_delay.map_or(delay, |max_delay| {
+            delay
+                .checked_mul(1_u32.checked_shl(attempt).unwrap_or(u32::MAX))
+                .map_or(max_delay, |delay| delay.min(max_delay))
+        });
+        if self.jitter {
+            let half = delay / 2;
+            let nanos = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+            let random = RandomState::new().build_hasher().finish();
+            Some(half + Duration::from_nanos(random % nanos.saturating_add(1)))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("delay", &self.delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("honor_retry_after", &self.honor_retry_after)
+            .field("classifier", &"<closure>")
+            .finish()
+    }
+}
+
+/// Layer that retries failed requests according to [`RetryPolicy`].
+#[derive(Clone, Debug)]
+pub struct Retry {
+    policy: RetryPolicy,
+    sleep: Sleep,
+}
+
+impl Retry {
+    /// Retry requests according to `policy` using `sleep` to wait
+    /// between attempts.
+    #[must_use]
+    pub const fn new(policy: RetryPolicy, sleep: Sleep) -> Self {
+        Self { policy, sleep }
+    }
+
+    /// Retry policy.
+    #[must_use]
+    pub const fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+impl<E> BmcLayer<E> for Retry
+where
+    E: StdError + BmcErrorInfo + MaybeSend + MaybeSync,
+{
+    type Error = E;
+
+    fn bmc_error(err: E) -> Self::Error {
+        err
+    }
+
+    async fn call<T, F>(&self, _: &Request<'_>, send: F) -> Result<T, Self::Error>
+    where
+        T: MaybeSend,
+        F: Future<Output = Result<T, E>> + MaybeSend,
+    {
+        send.await
+    }
+
+    fn retry(
+        &self,
+        request: &Request<'_>,
+        attempt: u32,
+        err: &Self::Error,
+    ) -> Option<impl Future<Output = ()> + MaybeSend> {
+        if attempt > self.policy.max_retries || !(self.policy.classifier)(request, err) {
+            return None;
+        }
+        let delay = self.policy.delay_for(attempt - 1, err.retry_after());
+        Some(delay.map_or_else(
+            || -> SleepFuture { Box::pin(ready(())) },
+            |delay| self.sleep.sleep(delay),
+        ))
+    }
+}
+
+/// BMC that retries failed requests of the wrapped BMC according to
+/// [`RetryPolicy`].
+pub type RetryBmc<B> = Layered<Retry, B>;
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn backoff_delays() {
+        let policy = RetryPolicy::new(|_, _| true)
+            .max_retries(5)
+            .delay(Duration::from_millis(100))
+            .backoff(Duration::from_millis(350));
+        let delays = (0..4)
+            .map(|attempt| policy.delay_for(attempt, None))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [100, 200, 350, 350]
+                .iter()
+                .map(|ms| Some(Duration::from_millis(*ms)))
+                .collect::<Vec<_>>()
+        );
+
+        let policy = policy.jitter(true);
+        for attempt in 0..4 {
+            let delay = policy.delay_for(attempt, None);
+            assert!(delay >= Some(Duration::from_millis(50)));
+            assert!(delay <= Some(Duration::from_millis(350)));
+        }
+
+        // Retry-After is ignored unless enabled and then replaces the
+        // computed delay, limited by the backoff maximum.
+        let retry_after = Some(Duration::from_millis(300));
+        assert!(policy.delay_for(0, retry_after) <= Some(Duration::from_millis(100)));
+        let policy = policy.honor_retry_after(true);
+        assert_eq!(
+            policy.delay_for(0, retry_after),
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(
+            policy.delay_for(0, Some(Duration::from_secs(5))),
+            Some(Duration::from_millis(350))
+        );
+    }
+}
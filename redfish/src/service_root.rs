@@ -13,23 +13,56 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use serde_json::Value as JsonValue;
+use std::iter;
 use std::sync::Arc;
 
+use crate::action_info::ActionParameter;
 use crate::bmc_quirks::BmcQuirks;
+use crate::cache::CacheBmc;
+#[cfg(feature = "impl-nv-bmc-expand")]
+use crate::core::query::ExpandQuery;
+use crate::core::Action;
+use crate::core::AllowedMethods;
 use crate::core::Bmc;
+use crate::core::BmcErrorInfo;
+use crate::core::EntityTypeRef as _;
+#[cfg(feature = "impl-nv-bmc-expand")]
+use crate::core::Expandable;
+use crate::core::MaybeSync;
+use crate::core::ModificationResponse;
 use crate::core::NavProperty;
 use crate::core::ODataId;
+use crate::core::SelectQuery;
+use crate::layer::BmcLayer;
+use crate::layer::Layered;
+use crate::limit::ConcurrencyLimit;
+use crate::limit::LimitedBmc;
+use crate::quirks::PlatformClass;
+use crate::quirks::PlatformInfo;
+use crate::quirks::Quirk;
+use crate::quirks::QuirkRegistry;
+use crate::retry::Retry;
+use crate::retry::RetryBmc;
+use crate::retry::RetryPolicy;
+use crate::schema::service_root::ProtocolFeaturesSupported;
 use crate::schema::service_root::ServiceRoot as SchemaServiceRoot;
+use crate::type_check::TypeCheckBmc;
+use crate::Capabilities;
 use crate::Error;
 use crate::NvBmc;
 use crate::ProtocolFeatures;
 use crate::Resource;
 use crate::ResourceSchema;
+use crate::Sleep;
+use crate::SupportedMethods;
 
 use tagged_types::TaggedType;
 
 #[cfg(feature = "accounts")]
 use crate::account::AccountService;
+#[cfg(feature = "certificates")]
+use crate::certificate_service::CertificateService;
 #[cfg(feature = "chassis")]
 use crate::chassis::ChassisCollection;
 #[cfg(feature = "chassis")]
@@ -49,6 +82,8 @@ use crate::power_equipment::PowerEquipment;
 #[cfg(feature = "session-service")]
 use crate::session_service::SessionService;
 #[cfg(feature = "task-service")]
+use crate::task_service::TaskFollowing;
+#[cfg(feature = "task-service")]
 use crate::task_service::TaskService;
 #[cfg(feature = "telemetry-service")]
 use crate::telemetry_service::TelemetryService;
@@ -82,12 +117,287 @@ pub type RedfishVersion<'a> = TaggedType<&'a str, RedfishVersionTag>;
 #[capability(inner_access, cloned)]
 pub enum RedfishVersionTag {}
 
+/// Builder of [`ServiceRoot`].
+///
+/// Collects client options in one place: retry policy, response
+/// caching, concurrency limits, strict or lenient handling of the
+/// BMC, member concurrency, expand behavior, task following and
+/// quirk overrides. Options that are not set keep defaults of
+/// [`ServiceRoot::new`].
+///
+/// Platform of the BMC is classified using the quirk registry
+/// unless it is forced with [`ServiceRootBuilder::force_platform`]
+/// or quirks are turned off with [`ServiceRootBuilder::strict`].
+/// Quirks enabled or disabled explicitly are applied on top of the
+/// quirks of the platform class; disabling takes precedence.
+///
+/// Retries, caching, concurrency limits and strict mode wrap the
+/// [`Bmc`] of the builder, and the root then sends requests through
+/// the wrapped [`Bmc`]. Other request middleware is added with
+/// [`ServiceRootBuilder::layer`]. A wrapper added later sees requests
+/// before wrappers added earlier, so retries added after the cache
+/// retry revalidation of cached resources too. Transport options
+/// such as timeouts belong to the [`Bmc`] implementation (for
+/// example, parameters of the HTTP client of `HttpBmc`) and are
+/// configured when it is created.
+///
+/// ```ignore
+/// let root = ServiceRoot::builder(bmc)
+///     .cache(1000)
+///     .max_concurrent_requests(4)
+///     .retry(RetryPolicy::transient(), Sleep::new(tokio::time::sleep))
+///     .member_concurrency(8)
+///     .disable_quirk(Quirk::ExpandIsNotWorkingProperly)
+///     .build()
+///     .await?;
+/// ```
+pub struct ServiceRootBuilder<B: Bmc> {
+    bmc: Arc<B>,
+    registry: QuirkRegistry,
+    overrides: QuirkOverrides,
+    member_concurrency: Option<usize>,
+    client_side_expand: bool,
+    restrict_expand: bool,
+    #[cfg(feature = "task-service")]
+    task_following: Option<TaskFollowing>,
+}
+
+/// Overrides of platform classification requested by the user. They
+/// are kept in the root to be applied again when quirk registry is
+/// reloaded.
+#[derive(Default)]
+struct QuirkOverrides {
+    strict: bool,
+    platform: Option<String>,
+    enabled_quirks: Vec<Quirk>,
+    disabled_quirks: Vec<Quirk>,
+}
+
+impl QuirkOverrides {
+    fn quirks<B: Bmc>(
+        &self,
+        root: &SchemaServiceRoot,
+        registry: &QuirkRegistry,
+    ) -> Result<BmcQuirks, Error<B>> {
+        let mut quirks = if self.strict {
+            BmcQuirks::from_class(None)
+        } else if let Some(name) = &self.platform {
+            let class = registry
+                .find(name)
+                .ok_or_else(|| Error::UnknownPlatform(name.clone()))?;
+            BmcQuirks::from_class(Some(class))
+        } else {
+            BmcQuirks::new(root, registry)
+        };
+        self.apply(&mut quirks);
+        Ok(quirks)
+    }
+
+    fn apply(&self, quirks: &mut BmcQuirks) {
+        for quirk in &self.enabled_quirks {
+            quirks.enable(*quirk);
+        }
+        for quirk in &self.disabled_quirks {
+            quirks.disable(*quirk);
+        }
+    }
+}
+
+impl<B: Bmc> ServiceRootBuilder<B> {
+    /// Classify platform using `registry` instead of built-in
+    /// platform classes.
+    #[must_use]
+    pub fn quirk_registry(self, registry: QuirkRegistry) -> Self {
+        Self { registry, ..self }
+    }
+
+    /// Use quirks of the platform class `name` of the quirk registry
+    /// instead of classifying the platform from the service root.
+    #[must_use]
+    pub fn force_platform(mut self, name: impl Into<String>) -> Self {
+        self.overrides.platform = Some(name.into());
+        self
+    }
+
+    /// Expect the BMC to comply with the Redfish specification.
+    /// Quirks of the platform class are not applied, and resources
+    /// are deserialized only if their `@odata.type` is the requested
+    /// type (see [`crate::type_check`]), so a payload of another
+    /// resource is rejected with [`TypeCheckError::Mismatch`]. Quirks
+    /// enabled explicitly with [`Self::enable_quirk`] are still
+    /// applied.
+    ///
+    /// [`TypeCheckError::Mismatch`]: crate::type_check::TypeCheckError::Mismatch
+    #[must_use]
+    pub fn strict(self) -> ServiceRootBuilder<TypeCheckBmc<B>>
+    where
+        B::Error: 'static,
+    {
+        let mut builder = self.wrap(TypeCheckBmc::new);
+        builder.overrides.strict = true;
+        builder
+    }
+
+    /// Enable `quirk` regardless of the platform class.
+    #[must_use]
+    pub fn enable_quirk(mut self, quirk: Quirk) -> Self {
+        self.overrides.enabled_quirks.push(quirk);
+        self
+    }
+
+    /// Disable `quirk` regardless of the platform class.
+    #[must_use]
+    pub fn disable_quirk(mut self, quirk: Quirk) -> Self {
+        self.overrides.disabled_quirks.push(quirk);
+        self
+    }
+
+    /// Set maximum number of collection members that are fetched
+    /// concurrently. See [`ServiceRoot::member_concurrency`].
+    #[must_use]
+    pub const fn member_concurrency(mut self, limit: usize) -> Self {
+        self.member_concurrency = Some(limit);
+        self
+    }
+
+    /// Don't emulate `$expand` on the client side if the BMC doesn't
+    /// support it. See [`ServiceRoot::disable_client_side_expand`].
+    #[must_use]
+    pub const fn disable_client_side_expand(mut self) -> Self {
+        self.client_side_expand = false;
+        self
+    }
+
+    /// Don't use `$expand` even if the BMC supports it. See
+    /// [`ServiceRoot::restrict_expand`].
+    #[must_use]
+    pub const fn restrict_expand(mut self) -> Self {
+        self.restrict_expand = true;
+        self
+    }
+
+    /// Follow tasks returned by write operations to completion. See
+    /// [`ServiceRoot::follow_tasks`].
+    #[cfg(feature = "task-service")]
+    #[must_use]
+    pub fn follow_tasks(mut self, following: TaskFollowing) -> Self {
+        self.task_following = Some(following);
+        self
+    }
+
+    /// Send all requests through `layer`, for example,
+    /// [`Deadlines`](crate::deadline::Deadlines). A layer added later
+    /// sees requests before layers added earlier.
+    #[must_use]
+    pub fn layer<L>(self, layer: L) -> ServiceRootBuilder<Layered<L, B>>
+    where
+        B::Error: 'static,
+        L: BmcLayer<B::Error> + 'static,
+        L::Error: 'static,
+    {
+        self.wrap(|bmc| Layered::new(bmc, layer))
+    }
+
+    /// Retry failed requests according to `policy`, using `sleep` to
+    /// wait between attempts. See [`crate::retry`].
+    #[must_use]
+    pub fn retry(self, policy: RetryPolicy, sleep: Sleep) -> ServiceRootBuilder<RetryBmc<B>>
+    where
+        B::Error: BmcErrorInfo + 'static,
+    {
+        self.layer(Retry::new(policy, sleep))
+    }
+
+    /// Cache at most `capacity` resources and revalidate them with
+    /// conditional requests. See [`crate::cache`].
+    #[must_use]
+    pub fn cache(self, capacity: usize) -> ServiceRootBuilder<CacheBmc<B>>
+    where
+        B::Error: 'static,
+    {
+        self.wrap(|bmc| CacheBmc::new(bmc, capacity))
+    }
+
+    /// Keep at most `limit` requests to the BMC in flight. Unlike
+    /// [`Self::member_concurrency`], it limits all requests of the
+    /// root and of resource handles obtained from it. See
+    /// [`crate::limit`].
+    #[must_use]
+    pub fn max_concurrent_requests(self, limit: usize) -> ServiceRootBuilder<LimitedBmc<B>>
+    where
+        B::Error: 'static,
+    {
+        self.layer(ConcurrencyLimit::new(limit))
+    }
+
+    fn wrap<C: Bmc>(self, wrap: impl FnOnce(Arc<B>) -> C) -> ServiceRootBuilder<C> {
+        ServiceRootBuilder {
+            bmc: Arc::new(wrap(self.bmc)),
+            registry: self.registry,
+            overrides: self.overrides,
+            member_concurrency: self.member_concurrency,
+            client_side_expand: self.client_side_expand,
+            restrict_expand: self.restrict_expand,
+            #[cfg(feature = "task-service")]
+            task_following: self.task_following,
+        }
+    }
+
+    /// Retrieve the service root and apply quirks of the platform.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the root path via Redfish fails or
+    /// if the forced platform class is not registered.
+    pub async fn build(self) -> Result<ServiceRoot<B>, Error<B>> {
+        let root = NavProperty::<SchemaServiceRoot>::new_reference(ODataId::service_root())
+            .get(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)?;
+        let quirks = self.overrides.quirks(&root, &self.registry)?;
+        let protocol_features = root
+            .protocol_features_supported
+            .as_ref()
+            .map(ProtocolFeatures::new)
+            .unwrap_or_default();
+
+        // Expand is emulated by `NvBmc` while the quirk is set.
+        #[cfg(feature = "tracing")]
+        if quirks.expand_is_not_working_properly() {
+            tracing::debug!(
+                quirk = "ExpandIsNotWorkingProperly",
+                "expand is emulated on the client side because of BMC quirk"
+            );
+        }
+
+        let mut bmc = NvBmc::new(self.bmc, protocol_features, quirks);
+        if let Some(limit) = self.member_concurrency {
+            bmc = bmc.member_concurrency(limit);
+        }
+        if !self.client_side_expand {
+            bmc = bmc.disable_client_side_expand();
+        }
+        if self.restrict_expand {
+            bmc = bmc.restrict_expand();
+        }
+        #[cfg(feature = "task-service")]
+        if let Some(following) = self.task_following {
+            bmc = bmc.follow_tasks(following);
+        }
+        Ok(ServiceRoot {
+            root,
+            bmc,
+            overrides: self.overrides.into(),
+        })
+    }
+}
+
 /// Represents `ServiceRoot` in the BMC model.
 pub struct ServiceRoot<B: Bmc> {
     /// Content of the root.
     pub root: Arc<SchemaServiceRoot>,
-    #[allow(dead_code)] // feature-enabled field
     bmc: NvBmc<B>,
+    overrides: Arc<QuirkOverrides>,
 }
 
 // Implement Clone manually to avoid requiring B: Clone; cloning only
@@ -97,6 +407,7 @@ impl<B: Bmc> Clone for ServiceRoot<B> {
         Self {
             root: self.root.clone(),
             bmc: self.bmc.clone(),
+            overrides: self.overrides.clone(),
         }
     }
 }
@@ -108,24 +419,40 @@ impl<B: Bmc> ServiceRoot<B> {
     ///
     /// Returns error if retrieving the root path via Redfish fails.
     pub async fn new(bmc: Arc<B>) -> Result<Self, Error<B>> {
-        let root = NavProperty::<SchemaServiceRoot>::new_reference(ODataId::service_root())
-            .get(bmc.as_ref())
-            .await
-            .map_err(Error::Bmc)?;
-        let quirks = BmcQuirks::new(&root);
-        let mut protocol_features = root
-            .protocol_features_supported
-            .as_ref()
-            .map(ProtocolFeatures::new)
-            .unwrap_or_default();
+        Self::builder(bmc).build().await
+    }
 
-        if quirks.expand_is_not_working_properly() {
-            protocol_features.expand.expand_all = false;
-            protocol_features.expand.no_links = false;
+    /// Create a builder of the service root that allows setting
+    /// client options and overriding automatic platform
+    /// classification.
+    #[must_use]
+    pub fn builder(bmc: Arc<B>) -> ServiceRootBuilder<B> {
+        ServiceRootBuilder {
+            bmc,
+            registry: QuirkRegistry::default(),
+            overrides: QuirkOverrides::default(),
+            member_concurrency: None,
+            client_side_expand: true,
+            restrict_expand: false,
+            #[cfg(feature = "task-service")]
+            task_following: None,
         }
+    }
 
-        let bmc = NvBmc::new(bmc, protocol_features, quirks);
-        Ok(Self { root, bmc })
+    /// Create a new service root. Platform of the BMC is classified
+    /// using `registry` instead of built-in platform classes.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the root path via Redfish fails.
+    pub async fn with_quirk_registry(
+        bmc: Arc<B>,
+        registry: &QuirkRegistry,
+    ) -> Result<Self, Error<B>> {
+        Self::builder(bmc)
+            .quirk_registry(registry.clone())
+            .build()
+            .await
     }
 
     /// Replace BMC in this root.
@@ -133,7 +460,65 @@ impl<B: Bmc> ServiceRoot<B> {
     pub fn replace_bmc(self, bmc: Arc<B>) -> Self {
         let root = self.root;
         let bmc = self.bmc.replace_bmc(bmc);
-        Self { root, bmc }
+        Self {
+            root,
+            bmc,
+            overrides: self.overrides,
+        }
+    }
+
+    /// Set maximum number of collection members that are fetched
+    /// concurrently. By default (value `1`) members are fetched one
+    /// by one.
+    #[must_use]
+    pub fn member_concurrency(self, limit: usize) -> Self {
+        let root = self.root;
+        let bmc = self.bmc.member_concurrency(limit);
+        Self {
+            root,
+            bmc,
+            overrides: self.overrides,
+        }
+    }
+
+    /// Maximum number of collection members that are fetched
+    /// concurrently.
+    #[cfg(feature = "inventory")]
+    pub(crate) const fn member_concurrency_limit(&self) -> usize {
+        self.bmc.member_concurrency_limit()
+    }
+
+    /// Don't emulate `$expand` on the client side if the BMC doesn't
+    /// support it. By default members of expanded collections are
+    /// fetched concurrently together with the collection and patched
+    /// as if they were read individually. Without emulation members
+    /// are fetched when they are accessed. Expand is always emulated
+    /// for resources where it is disabled by a quirk. Applies to
+    /// resources obtained from this root after the call.
+    #[must_use]
+    pub fn disable_client_side_expand(self) -> Self {
+        let root = self.root;
+        let bmc = self.bmc.disable_client_side_expand();
+        Self {
+            root,
+            bmc,
+            overrides: self.overrides,
+        }
+    }
+
+    /// Follow tasks returned by write operations to completion
+    /// instead of returning them to the caller. Applies to resources
+    /// obtained from this root after the call.
+    #[cfg(feature = "task-service")]
+    #[must_use]
+    pub fn follow_tasks(self, following: TaskFollowing) -> Self {
+        let root = self.root;
+        let bmc = self.bmc.follow_tasks(following);
+        Self {
+            root,
+            bmc,
+            overrides: self.overrides,
+        }
     }
 
     /// Restrict usage of expand.
@@ -141,7 +526,290 @@ impl<B: Bmc> ServiceRoot<B> {
     pub fn restrict_expand(self) -> Self {
         let root = self.root;
         let bmc = self.bmc.restrict_expand();
-        Self { root, bmc }
+        Self {
+            root,
+            bmc,
+            overrides: self.overrides,
+        }
+    }
+
+    /// Classify the platform again using `registry` and replace
+    /// quirks of the BMC, for example, after downloading updated
+    /// quirk rules. Forced platform and explicitly enabled or
+    /// disabled quirks of the builder are applied on top of the new
+    /// classification.
+    ///
+    /// New quirks are used for subsequent requests of this root and
+    /// of all resource handles obtained from it. Data that is
+    /// already retrieved is not patched again.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the forced platform class is not registered
+    /// in `registry`. Quirks are not changed in this case.
+    pub fn reload_quirk_registry(&self, registry: &QuirkRegistry) -> Result<(), Error<B>> {
+        let quirks = self.overrides.quirks(&self.root, registry)?;
+        self.bmc.reload_quirks(quirks);
+        Ok(())
+    }
+
+    /// Use quirks of platform `class` for resources in `subtree`
+    /// instead of quirks of the service root. It is intended for
+    /// aggregators that proxy multiple downstream BMCs: platform of
+    /// a downstream BMC can be resolved from its `AggregationSource`
+    /// or from the manager that manages the resources (for example,
+    /// with [`QuirkRegistry::classify`] or [`QuirkRegistry::find`]),
+    /// and its quirks are scoped to the resources it serves.
+    ///
+    /// Quirks explicitly enabled or disabled with the builder are
+    /// applied on top of quirks of `class`. If subtrees are nested,
+    /// quirks of the innermost subtree are used. Scoping the same
+    /// subtree again replaces its quirks. Scoped quirks are kept
+    /// when quirk registry is reloaded. See
+    /// [`Self::scope_aggregated_quirks`] to scope quirks of all
+    /// downstream BMCs.
+    pub fn scope_quirks(&self, subtree: ODataId, class: Option<&dyn PlatformClass>) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            subtree = %subtree,
+            platform = class.map(PlatformClass::name),
+            "quirks scoped to subtree"
+        );
+        let mut quirks = BmcQuirks::from_class(class);
+        self.overrides.apply(&mut quirks);
+        self.bmc.scope_quirks(subtree, quirks);
+    }
+
+    /// Scope quirks of downstream BMCs of an aggregator to the
+    /// resources they serve (see [`Self::scope_quirks`]). Each
+    /// manager is classified with `registry` by its `Manufacturer`,
+    /// `Model` and `Oem` that are matched as `Vendor`, `Product`
+    /// and `Oem` of the service root. Quirks of the platform of a
+    /// classified manager are scoped to the manager and to the
+    /// resources it manages (`ManagerForChassis` and
+    /// `ManagerForServers`, that link back to it with `ManagedBy`).
+    /// Resources accessed through an `AggregationSource` are scoped
+    /// with quirks of the classified manager among them. Resources
+    /// of managers that are not classified keep quirks of the
+    /// service root.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving managers or aggregation sources
+    /// fails.
+    pub async fn scope_aggregated_quirks(&self, registry: &QuirkRegistry) -> Result<(), Error<B>> {
+        let root = self.get_as::<JsonValue>(self.root.odata_id()).await?;
+        let mut classified = Vec::new();
+        for id in self.linked_members(&root, "/Managers").await? {
+            let manager = self.get_as::<JsonValue>(&id).await?;
+            let info = PlatformInfo {
+                vendor: manager.get("Manufacturer").and_then(JsonValue::as_str),
+                product: manager.get("Model").and_then(JsonValue::as_str),
+                redfish_version: None,
+                oem: manager.get("Oem"),
+            };
+            let Some(class) = registry.classify(&info) else {
+                continue;
+            };
+            let resources = odata_ids_at(&manager, "/Links/ManagerForChassis")
+                .chain(odata_ids_at(&manager, "/Links/ManagerForServers"));
+            for subtree in iter::once(id.clone()).chain(resources) {
+                self.scope_quirks(subtree, Some(class));
+            }
+            classified.push((id, class));
+        }
+        let Some(service_id) = odata_id_at(&root, "/AggregationService") else {
+            return Ok(());
+        };
+        let service = self.get_as::<JsonValue>(&service_id).await?;
+        for id in self.linked_members(&service, "/AggregationSources").await? {
+            let source = self.get_as::<JsonValue>(&id).await?;
+            let accessed = odata_ids_at(&source, "/Links/ResourcesAccessed").collect::<Vec<_>>();
+            let class = classified
+                .iter()
+                .find(|(manager, _)| accessed.contains(manager))
+                .map(|(_, class)| *class);
+            if let Some(class) = class {
+                for subtree in accessed {
+                    self.scope_quirks(subtree, Some(class));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Members of the collection linked by navigation property at
+    /// `pointer` of `resource`.
+    async fn linked_members(
+        &self,
+        resource: &JsonValue,
+        pointer: &str,
+    ) -> Result<Vec<ODataId>, Error<B>> {
+        let Some(id) = odata_id_at(resource, pointer) else {
+            return Ok(Vec::new());
+        };
+        let collection = self.get_as::<JsonValue>(&id).await?;
+        Ok(odata_ids_at(&collection, "/Members").collect())
+    }
+
+    /// Get only selected properties of the resource identified by
+    /// `id`. It is useful for polling of a few properties of large
+    /// resources, for example, `PowerState` of a computer system.
+    ///
+    /// `T` must accept absence of properties that are not selected,
+    /// including `Id` and `Name` of the resource. Use
+    /// [`serde_json::Value`] to get the payload as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the resource fails or if the
+    /// payload cannot be deserialized to `T`.
+    pub async fn get_with_select<T>(&self, id: &ODataId, query: SelectQuery) -> Result<T, Error<B>>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        self.bmc.get_with_select(id, query).await
+    }
+
+    /// Get resource identified by `id` and deserialize it to `T`. It
+    /// allows reaching resources that are not modeled by the crate
+    /// while still using quirks, caching and error handling of the
+    /// BMC.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the resource fails or if the
+    /// payload cannot be deserialized to `T`.
+    pub async fn get_as<T>(&self, id: &ODataId) -> Result<T, Error<B>>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        self.bmc.get_as(id).await
+    }
+
+    /// Update resource identified by `id` with raw JSON `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the update fails.
+    pub async fn patch_json(
+        &self,
+        id: &ODataId,
+        value: &serde_json::Value,
+    ) -> Result<ModificationResponse<serde_json::Value>, Error<B>> {
+        self.bmc.patch_json(id, value).await
+    }
+
+    /// POST raw JSON `value` to resource identified by `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the request fails.
+    pub async fn post_json(
+        &self,
+        id: &ODataId,
+        value: &serde_json::Value,
+    ) -> Result<ModificationResponse<serde_json::Value>, Error<B>> {
+        self.bmc.post_json(id, value).await
+    }
+
+    /// Check that the resource identified by `id` exists and is
+    /// accessible with current credentials without transferring its
+    /// body. It is useful for fast detection of capabilities of many
+    /// BMCs, for example, presence of an OEM resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the resource is not available.
+    pub async fn probe(&self, id: &ODataId) -> Result<(), Error<B>> {
+        self.bmc.probe(id).await
+    }
+
+    /// Parameters supported by the service for `action` according to
+    /// its `@Redfish.ActionInfo` resource. Returns `None` if the
+    /// action doesn't reference `ActionInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving `ActionInfo` fails.
+    pub async fn allowed_action_parameters<T: MaybeSync, R: MaybeSync>(
+        &self,
+        action: &Action<T, R>,
+    ) -> Result<Option<Vec<ActionParameter>>, Error<B>> {
+        self.bmc.allowed_action_parameters(action).await
+    }
+
+    /// Expand navigation property with the query provided by caller.
+    /// It allows to expand only specific navigation properties of fat
+    /// resources, for example `ExpandQuery::property("Members")`.
+    /// Levels of the query are limited by `MaxLevels` of the BMC.
+    /// The property is retrieved without expansion if the BMC doesn't
+    /// support `$expand`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the property fails.
+    #[cfg(feature = "impl-nv-bmc-expand")]
+    pub async fn expand_property_with_query<T: Expandable>(
+        &self,
+        nav: &NavProperty<T>,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Error<B>> {
+        self.bmc.expand_property_with_query(nav, query).await
+    }
+
+    /// Capabilities of the BMC: protocol features reported in the
+    /// service root adjusted by platform quirks and features of
+    /// update and event services.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving update service or event service
+    /// data fails.
+    // Nothing is awaited without services that are asked for their
+    // features.
+    #[cfg_attr(
+        not(any(feature = "update-service", feature = "event-service")),
+        allow(clippy::unused_async)
+    )]
+    pub async fn capabilities(&self) -> Result<Capabilities, Error<B>> {
+        let expand = self.bmc.expand_features(&ODataId::service_root());
+        let features = self.root.protocol_features_supported.as_ref();
+        let feature = |f: fn(&ProtocolFeaturesSupported) -> Option<bool>| {
+            features.and_then(f).unwrap_or(false)
+        };
+        #[cfg(feature = "update-service")]
+        let multipart_update = self
+            .update_service()
+            .await?
+            .is_some_and(|service| service.raw().multipart_http_push_uri.is_some());
+        #[cfg(not(feature = "update-service"))]
+        let multipart_update = false;
+        #[cfg(feature = "event-service")]
+        let sse = self
+            .event_service()
+            .await?
+            .is_some_and(|service| service.raw().server_sent_event_uri.is_some());
+        #[cfg(not(feature = "event-service"))]
+        let sse = false;
+        #[cfg(feature = "session-service")]
+        let sessions = self.root.session_service.is_some();
+        #[cfg(not(feature = "session-service"))]
+        let sessions = false;
+        Ok(Capabilities {
+            expand: expand.expand_all || expand.no_links,
+            expand_levels: expand.levels,
+            max_expand_levels: expand.max_levels,
+            client_side_expand: self.bmc.expands_on_client_side(&ODataId::service_root()),
+            select: feature(|f| f.select_query),
+            filter: feature(|f| f.filter_query),
+            top_skip: feature(|f| f.top_skip_query),
+            head: !self.bmc.quirks().head_is_not_supported(),
+            update_method: self.bmc.quirks().update_method(),
+            sessions,
+            multipart_update,
+            sse,
+        })
     }
 
     /// The vendor or manufacturer associated with this Redfish service.
@@ -184,6 +852,18 @@ impl<B: Bmc> ServiceRoot<B> {
         AccountService::new(&self.bmc, self).await
     }
 
+    /// Get certificate service in BMC
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose CertificateService.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving certificate service data fails.
+    #[cfg(feature = "certificates")]
+    pub async fn certificate_service(&self) -> Result<Option<CertificateService<B>>, Error<B>> {
+        CertificateService::new(&self.bmc, self).await
+    }
+
     /// Get chassis collection in BMC
     ///
     /// Returns `Ok(None)` when the BMC does not expose Chassis.
@@ -345,8 +1025,36 @@ impl<B: Bmc> ServiceRoot<B> {
     }
 }
 
+impl<B: Bmc> SupportedMethods<B> for ServiceRoot<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.root.odata_id())
+    }
+}
+
 impl<B: Bmc> Resource for ServiceRoot<B> {
     fn resource_ref(&self) -> &ResourceSchema {
         &self.root.as_ref().base
     }
 }
+
+/// `@odata.id` of navigation property at `pointer` of `resource`.
+fn odata_id_at(resource: &JsonValue, pointer: &str) -> Option<ODataId> {
+    resource
+        .pointer(pointer)
+        .and_then(|nav| nav.get("@odata.id"))
+        .and_then(JsonValue::as_str)
+        .map(|id| ODataId::from(id.to_string()))
+}
+
+/// `@odata.id` of navigation properties in array at `pointer` of
+/// `resource`.
+fn odata_ids_at<'a>(resource: &'a JsonValue, pointer: &str) -> impl Iterator<Item = ODataId> + 'a {
+    resource
+        .pointer(pointer)
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|nav| nav.get("@odata.id"))
+        .filter_map(JsonValue::as_str)
+        .map(|id| ODataId::from(id.to_string()))
+}
@@ -15,6 +15,7 @@
 
 //! Session collection utilities.
 
+use crate::collection::RedfishCollection;
 use crate::schema::session::Session as SessionSchema;
 use crate::schema::session_collection::SessionCollection as SessionCollectionSchema;
 use crate::session_service::Session;
@@ -43,19 +44,6 @@ impl<B: Bmc> SessionCollection<B> {
         Ok(Self { bmc, collection })
     }
 
-    /// List all sessions available in this BMC.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if fetching session data fails.
-    pub async fn members(&self) -> Result<Vec<Session<B>>, Error<B>> {
-        let mut members = Vec::with_capacity(self.collection.members.len());
-        for member in &self.collection.members {
-            members.push(Session::new(&self.bmc, member).await?);
-        }
-        Ok(members)
-    }
-
     /// Create a new session.
     ///
     /// # Errors
@@ -76,3 +64,20 @@ impl<B: Bmc> SessionCollection<B> {
         ))
     }
 }
+
+impl<B: Bmc> RedfishCollection<B> for SessionCollection<B> {
+    type MemberSchema = SessionSchema;
+    type Member = Session<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<SessionSchema>] {
+        &self.collection.members
+    }
+
+    async fn member(&self, nav: &NavProperty<SessionSchema>) -> Result<Session<B>, Error<B>> {
+        Session::new(&self.bmc, nav).await
+    }
+}
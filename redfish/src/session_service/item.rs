@@ -16,10 +16,14 @@
 //! Redfish Session - high-level wrapper.
 
 use crate::schema::session::Session as SessionSchema;
+use crate::DeletableResource;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
 use crate::ResourceSchema;
+use crate::SupportedMethods;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
@@ -83,7 +87,8 @@ impl<B: Bmc> Session<B> {
         self.delete_location.as_ref()
     }
 
-    /// Delete the current session.
+    /// Delete the current session at the `Location` returned on creation,
+    /// or at its `@odata.id` if the location is unknown.
     ///
     /// Returns one of the following modification outcomes:
     ///
@@ -95,19 +100,30 @@ impl<B: Bmc> Session<B> {
     ///
     /// # Errors
     ///
-    /// Returns an error if deletion fails.
+    /// Returns an error if deletion fails or if the service doesn't allow
+    /// deletion of the session.
     pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
         self.bmc
-            .as_ref()
-            .delete::<NavProperty<SessionSchema>>(
+            .delete_entity(
+                self.data.as_ref(),
                 self.delete_location
                     .as_ref()
                     .unwrap_or_else(|| self.data.odata_id()),
+                |nav| async move { Self::new(&self.bmc, &nav).await },
             )
             .await
-            .map_err(Error::Bmc)?
-            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
-            .await
+    }
+}
+
+impl<B: Bmc> DeletableResource<B> for Session<B> {
+    async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        Self::delete(self).await
+    }
+}
+
+impl<B: Bmc> SupportedMethods<B> for Session<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.data.odata_id())
     }
 }
 
@@ -116,3 +132,19 @@ impl<B: Bmc> Resource for Session<B> {
         &self.data.as_ref().base
     }
 }
+
+impl<B: Bmc> RefreshableResource<B> for Session<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        // Location and token are known only from the creation response.
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+        .map(|session| Self {
+            auth_token: self.auth_token.clone(),
+            delete_location: self.delete_location.clone(),
+            ..session
+        })
+    }
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime-agnostic sleep.
+//!
+//! Deadlines, task following and power state waiting need to sleep
+//! but the crate doesn't depend on a particular async runtime. The
+//! caller provides the sleep function of its runtime with [`Sleep`]:
+//!
+//! ```ignore
+//! let sleep = Sleep::new(tokio::time::sleep);
+//! let following = TaskFollowing::new(sleep.clone());
+//! let deadlines = Deadlines::new(sleep);
+//! ```
+
+use crate::core::MaybeSend;
+use crate::core::MaybeSync;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Future returned by the sleep function.
+#[cfg(not(feature = "unsend"))]
+pub(crate) type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Future returned by the sleep function.
+#[cfg(feature = "unsend")]
+pub(crate) type SleepFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+trait SleepFn: MaybeSend + MaybeSync {
+    fn sleep(&self, duration: Duration) -> SleepFuture;
+}
+
+impl<F, Fut> SleepFn for F
+where
+    F: Fn(Duration) -> Fut + MaybeSend + MaybeSync,
+    Fut: Future<Output = ()> + MaybeSend + 'static,
+{
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(self(duration))
+    }
+}
+
+/// Function that sleeps for the given duration.
+#[derive(Clone)]
+pub struct Sleep(Arc<dyn SleepFn>);
+
+impl Sleep {
+    /// Create sleep from the function of an async runtime, for
+    /// example `tokio::time::sleep`.
+    #[must_use]
+    pub fn new<F, Fut>(sleep: F) -> Self
+    where
+        F: Fn(Duration) -> Fut + MaybeSend + MaybeSync + 'static,
+        Fut: Future<Output = ()> + MaybeSend + 'static,
+    {
+        Self(Arc::new(sleep))
+    }
+
+    /// Sleep for `duration`.
+    pub(crate) fn sleep(&self, duration: Duration) -> SleepFuture {
+        self.0.sleep(duration)
+    }
+}
+
+impl fmt::Debug for Sleep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sleep(<closure>)")
+    }
+}
@@ -20,15 +20,22 @@
 //! transport. It validates task locations returned by asynchronous operations
 //! against this service's Tasks collection and returns lazy task links that can
 //! be fetched when polling is needed.
+//!
+//! [`TaskFollowing`] configures `ServiceRoot` to wait for tasks of write
+//! operations to finish instead of returning them to the caller.
 
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::core::Bmc;
 use crate::core::EntityTypeRef as _;
 use crate::core::NavProperty;
 use crate::entity_link::EntityLink;
 use crate::schema::task::Task as TaskSchema;
+use crate::schema::task::TaskState;
 use crate::schema::task_service::TaskService as TaskServiceSchema;
+use crate::sleep::Sleep;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
@@ -36,6 +43,9 @@ use crate::ResourceSchema;
 use crate::ServiceRoot;
 
 use nv_redfish_core::AsyncTask;
+use nv_redfish_core::ODataId;
+use serde::Deserialize as _;
+use serde_json::Value as JsonValue;
 
 /// Link to a Redfish Task returned by an asynchronous operation.
 pub type TaskLink<B> = EntityLink<B, TaskSchema>;
@@ -120,6 +130,120 @@ impl<B: Bmc> TaskService<B> {
     }
 }
 
+/// Settings of automatic following of asynchronous tasks.
+///
+/// When set with `ServiceRoot::follow_tasks`, write operations that
+/// return a task (`202 Accepted`) poll the task until it finishes and
+/// return the final entity instead of `ModificationResponse::Task`.
+/// Creates return the created entity when the service reports it,
+/// either in `Links.CreatedResources` of the completed task or as
+/// the final response of the task monitor. Operations that don't
+/// produce an entity (actions and deletes), and creates whose
+/// created resource is not reported, return
+/// `ModificationResponse::Empty` once the task is completed.
+///
+/// Task location can be a Redfish `Task` resource or a task monitor:
+/// a poll that returns a payload without `TaskState` is the final
+/// response of the operation. The [`Sleep`] function is provided by
+/// the caller so that following doesn't depend on a particular async
+/// runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// let following = TaskFollowing::new(Sleep::new(tokio::time::sleep))
+///     .timeout(Duration::from_secs(300));
+/// let root = ServiceRoot::new(bmc).await?.follow_tasks(following);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TaskFollowing {
+    timeout: Duration,
+    poll_interval: Duration,
+    sleep: Sleep,
+}
+
+impl TaskFollowing {
+    /// Create settings that use `sleep` to wait between polls. Tasks
+    /// are polled every 5 seconds for up to 10 minutes by default.
+    #[must_use]
+    pub const fn new(sleep: Sleep) -> Self {
+        Self {
+            timeout: Duration::from_mins(10),
+            poll_interval: Duration::from_secs(5),
+            sleep,
+        }
+    }
+
+    /// Maximum time to wait for a task to finish.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Interval between polls of the task. `Retry-After` of the
+    /// task response is used for the first poll if provided.
+    #[must_use]
+    pub const fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Poll `task` until it is finished. Returns the resource created
+    /// by the operation if the service reported it.
+    pub(crate) async fn wait<B: Bmc>(
+        &self,
+        bmc: &NvBmc<B>,
+        task: &AsyncTask,
+    ) -> Result<Option<ODataId>, Error<B>> {
+        let task_location = &task.location.0;
+        let started = Instant::now();
+        let mut delay = task.retry_after.unwrap_or(self.poll_interval);
+        loop {
+            if started.elapsed() + delay > self.timeout {
+                return Err(Error::TaskTimeout {
+                    task_location: task_location.clone(),
+                    timeout: self.timeout,
+                });
+            }
+            self.sleep.sleep(delay).await;
+            let payload = bmc.get_as::<JsonValue>(task_location).await?;
+            if payload.get("TaskState").is_none() {
+                // Task monitor returned the response of the finished
+                // operation.
+                return Ok(odata_id_at(&payload, ""));
+            }
+            let data = TaskSchema::deserialize(&payload).map_err(Error::Json)?;
+            match data.task_state {
+                Some(TaskState::Completed) => {
+                    return Ok(odata_id_at(&payload, "/Links/CreatedResources/0"))
+                }
+                Some(
+                    task_state @ (TaskState::Killed | TaskState::Exception | TaskState::Cancelled),
+                ) => {
+                    return Err(Error::TaskNotCompleted {
+                        task_location: task_location.clone(),
+                        task_state,
+                    })
+                }
+                _ => {}
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(task = %task_location, state = ?data.task_state, "task is not finished");
+            delay = self.poll_interval;
+        }
+    }
+}
+
+/// `@odata.id` of the object at JSON `pointer` of `payload`.
+fn odata_id_at(payload: &JsonValue, pointer: &str) -> Option<ODataId> {
+    payload
+        .pointer(pointer)?
+        .get("@odata.id")?
+        .as_str()
+        .map(|id| id.to_string().into())
+}
+
 impl<B: Bmc> Resource for TaskService<B> {
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
@@ -14,8 +14,11 @@
 // limitations under the License.
 
 use crate::schema::metric_definition::MetricDefinition as MetricDefinitionSchema;
+use crate::DeletableResource;
 use crate::Error;
 use crate::NvBmc;
+use crate::SupportedMethods;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
@@ -67,16 +70,24 @@ impl<B: Bmc> MetricDefinition<B> {
         &self,
         update: &MetricDefinitionUpdate,
     ) -> Result<ModificationResponse<Self>, Error<B>> {
-        self.bmc
-            .as_ref()
+        let response = self
+            .bmc
             .update::<_, NavProperty<MetricDefinitionSchema>>(
                 self.data.odata_id(),
                 self.data.etag(),
                 update,
             )
-            .await
-            .map_err(Error::Bmc)?
+            .await?
             .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await?;
+        self.bmc
+            .follow_task(response, || async {
+                Self::new(
+                    &self.bmc,
+                    &NavProperty::new_reference(self.data.odata_id().clone()),
+                )
+                .await
+            })
             .await
     }
 
@@ -92,14 +103,25 @@ impl<B: Bmc> MetricDefinition<B> {
     ///
     /// # Errors
     ///
-    /// Returns an error if deleting the entity fails.
+    /// Returns an error if deleting the entity fails or if the service
+    /// doesn't allow deletion of the entity.
     pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
         self.bmc
-            .as_ref()
-            .delete::<NavProperty<MetricDefinitionSchema>>(self.data.odata_id())
-            .await
-            .map_err(Error::Bmc)?
-            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .delete_entity(self.data.as_ref(), self.data.odata_id(), |nav| async move {
+                Self::new(&self.bmc, &nav).await
+            })
             .await
     }
 }
+
+impl<B: Bmc> DeletableResource<B> for MetricDefinition<B> {
+    async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        Self::delete(self).await
+    }
+}
+
+impl<B: Bmc> SupportedMethods<B> for MetricDefinition<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.data.odata_id())
+    }
+}
@@ -31,6 +31,7 @@ use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 use crate::ServiceRoot;
+use futures_util::TryStreamExt as _;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
@@ -109,26 +110,33 @@ impl<B: Bmc> TelemetryService<B> {
             .with_service_enabled(enabled)
             .build();
 
-        self.bmc
-            .as_ref()
+        let response = self
+            .bmc
             .update::<_, NavProperty<TelemetryServiceSchema>>(
                 self.data.odata_id(),
                 self.data.etag(),
                 &update,
             )
-            .await
-            .map_err(Error::Bmc)?
-            .try_map_entity_async(|nav| async move {
-                let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
-
-                Ok(Self {
-                    data,
-                    bmc: self.bmc.clone(),
-                })
+            .await?
+            .try_map_entity_async(|nav| self.with_data(nav))
+            .await?;
+        self.bmc
+            .follow_task(response, || {
+                self.with_data(NavProperty::new_reference(self.data.odata_id().clone()))
             })
             .await
     }
 
+    /// Fetch `nav` and create handle that shares this BMC.
+    async fn with_data(&self, nav: NavProperty<TelemetryServiceSchema>) -> Result<Self, Error<B>> {
+        let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+
+        Ok(Self {
+            data,
+            bmc: self.bmc.clone(),
+        })
+    }
+
     /// Get `Vec<MetricReportLink>` associated with this telemetry service.
     ///
     /// Fetches the metric report collection and returns a list of
@@ -173,12 +181,11 @@ impl<B: Bmc> TelemetryService<B> {
         if let Some(collection_ref) = &self.data.metric_definitions {
             let collection = self.bmc.expand_property(collection_ref).await?;
 
-            let mut items = Vec::with_capacity(collection.members.len());
-            for m in &collection.members {
-                items.push(MetricDefinition::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(items))
+            self.bmc
+                .members_stream(&collection.members, |m| MetricDefinition::new(&self.bmc, m))
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -200,12 +207,13 @@ impl<B: Bmc> TelemetryService<B> {
         if let Some(collection_ref) = &self.data.metric_report_definitions {
             let collection = self.bmc.expand_property(collection_ref).await?;
 
-            let mut items = Vec::with_capacity(collection.members.len());
-            for m in &collection.members {
-                items.push(MetricReportDefinition::new(&self.bmc, m).await?);
-            }
-
-            Ok(Some(items))
+            self.bmc
+                .members_stream(&collection.members, |m| {
+                    MetricReportDefinition::new(&self.bmc, m)
+                })
+                .try_collect()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -235,12 +243,18 @@ impl<B: Bmc> TelemetryService<B> {
             .as_ref()
             .ok_or(Error::MetricDefinitionsNotAvailable)?;
 
-        self.bmc
+        let response = self
+            .bmc
             .as_ref()
             .create::<_, NavProperty<MetricDefinitionSchema>>(collection_ref.id(), create)
             .await
             .map_err(Error::Bmc)?
             .try_map_entity_async(|nav| async move { MetricDefinition::new(&self.bmc, &nav).await })
+            .await?;
+        self.bmc
+            .follow_create_task(response, |created| async move {
+                MetricDefinition::new(&self.bmc, &NavProperty::new_reference(created)).await
+            })
             .await
     }
 
@@ -269,7 +283,8 @@ impl<B: Bmc> TelemetryService<B> {
             .as_ref()
             .ok_or(Error::MetricReportDefinitionsNotAvailable)?;
 
-        self.bmc
+        let response = self
+            .bmc
             .as_ref()
             .create::<_, NavProperty<MetricReportDefinitionSchema>>(collection_ref.id(), create)
             .await
@@ -277,6 +292,11 @@ impl<B: Bmc> TelemetryService<B> {
             .try_map_entity_async(|nav| async move {
                 MetricReportDefinition::new(&self.bmc, &nav).await
             })
+            .await?;
+        self.bmc
+            .follow_create_task(response, |created| async move {
+                MetricReportDefinition::new(&self.bmc, &NavProperty::new_reference(created)).await
+            })
             .await
     }
 }
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of `@odata.type` of responses.
+//!
+//! [`TypeCheckBmc`] wraps any [`Bmc`] and checks that resources it
+//! reads have `@odata.type` of the requested entity type or of one of
+//! its known derived types. BMCs that answer a wrong URI with an
+//! error body or with another resource produce
+//! [`TypeCheckError::Mismatch`] that names the received type instead
+//! of a generic JSON error:
+//!
+//! ```ignore
+//! let root = ServiceRoot::new(Arc::new(TypeCheckBmc::new(bmc))).await?;
+//! ```
+//!
+//! Requested types that don't list accepted type names (see
+//! [`EntityTypeRef::odata_type_names`]) are not checked. Responses of
+//! write requests are not checked.
+
+use crate::json_entity::JsonEntity;
+use crate::layer::forward_bmc;
+use futures_util::TryStreamExt as _;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BmcErrorInfo;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::FilterQuery;
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartRequest;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::ODataTypeMismatch;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::SelectQuery;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UpdateMethod;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Errors of [`TypeCheckBmc`].
+#[derive(Debug)]
+pub enum TypeCheckError<E> {
+    /// Error of the wrapped BMC.
+    Bmc(E),
+    /// Payload cannot be deserialized to the requested type.
+    Json(JsonError),
+    /// Payload has `@odata.type` of another type.
+    Mismatch(ODataTypeMismatch),
+}
+
+impl<E: fmt::Display> fmt::Display for TypeCheckError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bmc(err) => write!(f, "BMC error: {err}"),
+            Self::Json(err) => write!(f, "JSON error: {err}"),
+            Self::Mismatch(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<E: StdError> StdError for TypeCheckError<E> {}
+
+impl<E: BmcErrorInfo> BmcErrorInfo for TypeCheckError<E> {
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Bmc(err) => err.status_code(),
+            _ => None,
+        }
+    }
+
+    fn is_transport(&self) -> bool {
+        match self {
+            Self::Bmc(err) => err.is_transport(),
+            _ => false,
+        }
+    }
+
+    fn odata_id(&self) -> Option<ODataId> {
+        match self {
+            Self::Bmc(err) => err.odata_id(),
+            Self::Json(_) => None,
+            Self::Mismatch(err) => Some(err.resource.clone()),
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Bmc(err) => err.retry_after(),
+            _ => None,
+        }
+    }
+}
+
+/// BMC that checks `@odata.type` of resources read from the wrapped
+/// BMC.
+pub struct TypeCheckBmc<B: Bmc> {
+    bmc: Arc<B>,
+}
+
+impl<B: Bmc> TypeCheckBmc<B> {
+    /// Wrap `bmc`.
+    pub const fn new(bmc: Arc<B>) -> Self {
+        Self { bmc }
+    }
+
+    /// Wrapped BMC.
+    #[must_use]
+    pub fn inner(&self) -> &B {
+        self.bmc.as_ref()
+    }
+
+    fn check<T: EntityTypeRef + for<'de> Deserialize<'de>>(
+        id: &ODataId,
+        result: Result<Arc<JsonEntity>, B::Error>,
+    ) -> Result<Arc<T>, TypeCheckError<B::Error>> {
+        let entity = result.map_err(TypeCheckError::Bmc)?;
+        if let Some(mismatch) = ODataTypeMismatch::check(id, T::odata_type_names(), &entity.payload)
+        {
+            return Err(TypeCheckError::Mismatch(mismatch));
+        }
+        T::deserialize(&entity.payload)
+            .map(Arc::new)
+            .map_err(TypeCheckError::Json)
+    }
+}
+
+impl<B: Bmc> fmt::Debug for TypeCheckBmc<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypeCheckBmc").finish_non_exhaustive()
+    }
+}
+
+impl<B: Bmc> Bmc for TypeCheckBmc<B>
+where
+    B::Error: 'static,
+{
+    type Error = TypeCheckError<B::Error>;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        Self::check(id, self.bmc.expand::<JsonEntity>(id, query).await)
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        Self::check(id, self.bmc.get::<JsonEntity>(id).await)
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        self.bmc
+            .get_if_none_match::<JsonEntity>(id, etag)
+            .await
+            .transpose()
+            .map(|result| Self::check(id, result))
+            .transpose()
+    }
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        Self::check(id, self.bmc.filter::<JsonEntity>(id, query).await)
+    }
+
+    async fn page<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: PageQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        Self::check(id, self.bmc.page::<JsonEntity>(id, query).await)
+    }
+
+    async fn select<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: SelectQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        Self::check(id, self.bmc.select::<JsonEntity>(id, query).await)
+    }
+
+    forward_bmc!(TypeCheckError::Bmc;
+        head,
+        allowed_methods,
+        create,
+        create_session,
+        update,
+        update_with_method,
+        delete,
+        action,
+        multipart_update,
+        multipart_upload,
+        http_push_uri_update,
+        stream,
+        download,
+    );
+}
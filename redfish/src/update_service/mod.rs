@@ -23,9 +23,16 @@ mod software_inventory;
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::action_info::ActionParameter;
+use crate::bmc_quirks::BmcQuirks;
+use crate::collection::PartialMembers;
+use crate::collection::RedfishCollection as _;
 use crate::core::NavProperty;
+use crate::patch_support::NullEntries;
+use crate::patch_support::PatchRegistry;
 use crate::patch_support::Payload;
-use crate::patch_support::ReadPatchFn;
+use crate::patch_support::PropertyDefaults;
+use crate::schema::software_inventory::SoftwareInventory as SoftwareInventorySchema;
 use crate::schema::update_service::UpdateService as UpdateServiceSchema;
 use crate::schema::update_service::UpdateServiceSimpleUpdateAction;
 use crate::Error;
@@ -33,13 +40,17 @@ use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 use crate::ServiceRoot;
+use crate::SupportedMethods;
 
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::DataStream;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::EntityTypeRef as _;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::MaybeSend;
+use nv_redfish_core::MaybeSync;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::MultipartUpdateRequest;
 use nv_redfish_core::UploadReader;
@@ -68,7 +79,6 @@ pub use software_inventory::VersionRef;
 pub struct UpdateService<B: Bmc> {
     bmc: NvBmc<B>,
     data: Arc<UpdateServiceSchema>,
-    fw_inventory_read_patch_fn: Option<ReadPatchFn>,
 }
 
 impl<B: Bmc> UpdateService<B> {
@@ -77,46 +87,26 @@ impl<B: Bmc> UpdateService<B> {
         bmc: &NvBmc<B>,
         root: &ServiceRoot<B>,
     ) -> Result<Option<Self>, Error<B>> {
-        let mut service_patches = Vec::new();
-        if bmc.quirks.bug_missing_update_service_name_field() {
-            service_patches.push(add_default_update_service_name);
-        }
-        let service_patch_fn = (!service_patches.is_empty()).then(|| {
-            Arc::new(move |v| service_patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn
-        });
-
-        let mut fw_inventory_patches = Vec::new();
-        if bmc.quirks.fw_inventory_wrong_release_date() {
-            fw_inventory_patches.push(fw_inventory_patch_wrong_release_date);
-        }
-        let fw_inventory_read_patch_fn = (!fw_inventory_patches.is_empty()).then(|| {
-            Arc::new(move |v| fw_inventory_patches.iter().fold(v, |acc, f| f(acc))) as ReadPatchFn
-        });
-
-        if let Some(nav) = &root.root.update_service {
-            if let Some(service_patch_fn) = service_patch_fn {
-                Payload::get(bmc.as_ref(), nav, service_patch_fn.as_ref()).await
-            } else {
-                nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
+        let missing_root_nav;
+        let nav = match &root.root.update_service {
+            Some(nav) => nav,
+            None if bmc.quirks().bug_missing_root_nav_properties() => {
+                missing_root_nav =
+                    NavProperty::new_reference(format!("{}/UpdateService", root.odata_id()).into());
+                &missing_root_nav
             }
-            .map(Some)
-        } else if bmc.quirks.bug_missing_root_nav_properties() {
-            let nav =
-                NavProperty::new_reference(format!("{}/UpdateService", root.odata_id()).into());
-            if let Some(service_patch_fn) = service_patch_fn {
-                Payload::get(bmc.as_ref(), &nav, service_patch_fn.as_ref()).await
-            } else {
-                nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
-            }
-            .map(Some)
+            None => return Ok(None),
+        };
+        let service_patch_fn = bmc.patches_for(nav.id()).read::<UpdateServiceSchema>();
+        if let Some(service_patch_fn) = service_patch_fn {
+            Payload::get(bmc.as_ref(), nav, service_patch_fn.as_ref()).await
         } else {
-            Ok(None)
+            nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
         }
-        .map(|d| {
-            d.map(|data| Self {
+        .map(|data| {
+            Some(Self {
                 bmc: bmc.clone(),
                 data,
-                fw_inventory_read_patch_fn,
             })
         })
     }
@@ -141,15 +131,31 @@ impl<B: Bmc> UpdateService<B> {
         &self,
     ) -> Result<Option<Vec<SoftwareInventory<B>>>, Error<B>> {
         if let Some(collection_ref) = &self.data.firmware_inventory {
-            SoftwareInventoryCollection::new(
-                &self.bmc,
-                collection_ref,
-                self.fw_inventory_read_patch_fn.clone(),
-            )
-            .await?
-            .members()
-            .await
-            .map(Some)
+            SoftwareInventoryCollection::new(&self.bmc, collection_ref)
+                .await?
+                .members()
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// List firmware inventory items like
+    /// [`Self::firmware_inventories`], but don't stop on items that
+    /// cannot be fetched: their errors are returned along with the
+    /// fetched items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the firmware inventory collection
+    /// fails.
+    pub async fn try_firmware_inventories(
+        &self,
+    ) -> Result<Option<PartialMembers<SoftwareInventory<B>, B>>, Error<B>> {
+        if let Some(collection_ref) = &self.data.firmware_inventory {
+            let collection = SoftwareInventoryCollection::new(&self.bmc, collection_ref).await?;
+            Ok(Some(collection.try_members().await))
         } else {
             Ok(None)
         }
@@ -166,12 +172,11 @@ impl<B: Bmc> UpdateService<B> {
         &self,
     ) -> Result<Option<Vec<SoftwareInventory<B>>>, Error<B>> {
         if let Some(collection_ref) = &self.data.software_inventory {
-            let collection = self.bmc.expand_property(collection_ref).await?;
-            let mut items = Vec::new();
-            for item_ref in &collection.members {
-                items.push(SoftwareInventory::new(&self.bmc, item_ref, None).await?);
-            }
-            Ok(Some(items))
+            SoftwareInventoryCollection::new(&self.bmc, collection_ref)
+                .await?
+                .members()
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -223,7 +228,7 @@ impl<B: Bmc> UpdateService<B> {
             .as_ref()
             .ok_or(Error::ActionNotAvailable)?;
 
-        actions
+        let response = actions
             .simple_update(
                 self.bmc.as_ref(),
                 &UpdateServiceSimpleUpdateAction {
@@ -239,7 +244,26 @@ impl<B: Bmc> UpdateService<B> {
                 },
             )
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
+    }
+
+    /// Parameters of the `SimpleUpdate` action supported by the update service
+    /// according to `@Redfish.ActionInfo` of the action. Returns
+    /// `None` if the action doesn't reference `ActionInfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update service does not support the `SimpleUpdate`
+    /// action or if retrieving `ActionInfo` fails.
+    pub async fn simple_update_parameters(&self) -> Result<Option<Vec<ActionParameter>>, Error<B>> {
+        let action = self
+            .data
+            .actions
+            .as_ref()
+            .and_then(|actions| actions.simple_update.as_ref())
+            .ok_or(Error::ActionNotAvailable)?;
+        self.bmc.allowed_action_parameters(action).await
     }
 
     /// Start updates that have been previously invoked with an `OperationApplyTime` of
@@ -260,10 +284,11 @@ impl<B: Bmc> UpdateService<B> {
             .as_ref()
             .ok_or(Error::ActionNotAvailable)?;
 
-        actions
+        let response = actions
             .start_update(self.bmc.as_ref())
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
     }
 
     /// Update this service with deprecated generated `UpdateServiceUpdate` fields.
@@ -279,27 +304,35 @@ impl<B: Bmc> UpdateService<B> {
         &self,
         update: &UpdateServiceUpdate,
     ) -> Result<ModificationResponse<Self>, Error<B>> {
-        self.bmc
-            .as_ref()
+        let response = self
+            .bmc
             .update::<_, NavProperty<UpdateServiceSchema>>(
                 self.data.odata_id(),
                 self.data.etag(),
                 update,
             )
-            .await
-            .map_err(Error::Bmc)?
-            .try_map_entity_async(|nav| async move {
-                let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
-
-                Ok(Self {
-                    bmc: self.bmc.clone(),
-                    data,
-                    fw_inventory_read_patch_fn: self.fw_inventory_read_patch_fn.clone(),
-                })
+            .await?
+            .try_map_entity_async(|nav| self.with_data(nav))
+            .await?;
+        self.bmc
+            .follow_task(response, || {
+                self.with_data(NavProperty::new_reference(self.data.odata_id().clone()))
             })
             .await
     }
 
+    /// Fetch `nav` and create handle with this service settings.
+    #[cfg(feature = "update-service-deprecated")]
+    async fn with_data(&self, nav: NavProperty<UpdateServiceSchema>) -> Result<Self, Error<B>> {
+        let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+
+        Ok(Self {
+            bmc: self.bmc.clone(),
+            data,
+            fw_inventory_read_patch_fn: self.fw_inventory_read_patch_fn.clone(),
+        })
+    }
+
     /// Upload a raw binary stream using this service's deprecated `HttpPushUri`.
     ///
     /// The stream is sent as `application/octet-stream` without multipart
@@ -316,7 +349,7 @@ impl<B: Bmc> UpdateService<B> {
     ) -> Result<ModificationResponse<R>, Error<B>>
     where
         U: UploadReader,
-        R: Send + Sync + for<'de> serde::Deserialize<'de>,
+        R: MaybeSend + MaybeSync + for<'de> serde::Deserialize<'de>,
     {
         self.http_push_uri_update(HttpPushUriUpdateRequest {
             update_stream,
@@ -337,7 +370,7 @@ impl<B: Bmc> UpdateService<B> {
     ) -> Result<ModificationResponse<R>, Error<B>>
     where
         U: UploadReader,
-        R: Send + Sync + for<'de> serde::Deserialize<'de>,
+        R: MaybeSend + MaybeSync + for<'de> serde::Deserialize<'de>,
     {
         let http_push_uri = self
             .data
@@ -345,11 +378,13 @@ impl<B: Bmc> UpdateService<B> {
             .as_ref()
             .ok_or(Error::UpdateServiceHttpPushUriNotAvailable)?;
 
-        self.bmc
+        let response = self
+            .bmc
             .as_ref()
             .http_push_uri_update(http_push_uri, request)
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
     }
 
     /// Upload a named stream using this service's `MultipartHttpPushUri`.
@@ -368,8 +403,8 @@ impl<B: Bmc> UpdateService<B> {
     ) -> Result<ModificationResponse<R>, Error<B>>
     where
         U: UploadReader,
-        V: Send + Sync + serde::Serialize,
-        R: Send + Sync + for<'de> serde::Deserialize<'de>,
+        V: MaybeSend + MaybeSync + serde::Serialize,
+        R: MaybeSend + MaybeSync + for<'de> serde::Deserialize<'de>,
     {
         self.multipart_update(MultipartUpdateRequest {
             update_parameters,
@@ -393,8 +428,8 @@ impl<B: Bmc> UpdateService<B> {
     ) -> Result<ModificationResponse<R>, Error<B>>
     where
         U: UploadReader,
-        V: Send + Sync + serde::Serialize,
-        R: Send + Sync + for<'de> serde::Deserialize<'de>,
+        V: MaybeSend + MaybeSync + serde::Serialize,
+        R: MaybeSend + MaybeSync + for<'de> serde::Deserialize<'de>,
     {
         let multipart_uri = self
             .data
@@ -402,11 +437,19 @@ impl<B: Bmc> UpdateService<B> {
             .as_ref()
             .ok_or(Error::UpdateServiceMultipartHttpPushUriNotAvailable)?;
 
-        self.bmc
+        let response = self
+            .bmc
             .as_ref()
             .multipart_update(multipart_uri, request)
             .await
-            .map_err(Error::Bmc)
+            .map_err(Error::Bmc)?;
+        self.bmc.complete_task(response).await
+    }
+}
+
+impl<B: Bmc> SupportedMethods<B> for UpdateService<B> {
+    fn supported_methods(&self) -> Option<AllowedMethods> {
+        self.bmc.supported_methods(self.data.odata_id())
     }
 }
 
@@ -416,6 +459,29 @@ impl<B: Bmc> Resource for UpdateService<B> {
     }
 }
 
+/// Register patches of update service resources required by platform
+/// quirks.
+pub(crate) fn register_patches(quirks: &BmcQuirks, patches: &mut PatchRegistry) {
+    let mut service_defaults = PropertyDefaults::new();
+    if quirks.bug_missing_update_service_name_field() {
+        service_defaults = service_defaults.with("Name", "Unnamed update service");
+    }
+    if let Some(patch_fn) = service_defaults.into_patch_fn() {
+        patches.add_read::<UpdateServiceSchema>("update_service_defaults", patch_fn);
+    }
+    if quirks.null_entries_in_arrays() {
+        if let Some(patch_fn) = NullEntries::new().with("/RelatedItem").into_patch_fn() {
+            patches.add_read::<SoftwareInventorySchema>("remove_null_entries", patch_fn);
+        }
+    }
+    if quirks.fw_inventory_wrong_release_date() {
+        patches.add_read::<SoftwareInventorySchema>(
+            "fw_inventory_wrong_release_date",
+            Arc::new(fw_inventory_patch_wrong_release_date),
+        );
+    }
+}
+
 // `ReleaseDate` is marked as `edm.DateTimeOffset`, but some systems
 // puts "00:00:00Z" as ReleaseDate that is not conform to ABNF of the DateTimeOffset.
 // we delete such fields...
@@ -431,13 +497,3 @@ fn fw_inventory_patch_wrong_release_date(v: JsonValue) -> JsonValue {
         v
     }
 }
-
-fn add_default_update_service_name(v: JsonValue) -> JsonValue {
-    if let JsonValue::Object(mut obj) = v {
-        obj.entry("Name")
-            .or_insert(JsonValue::String("Unnamed update service".into()));
-        JsonValue::Object(obj)
-    } else {
-        v
-    }
-}
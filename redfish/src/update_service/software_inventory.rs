@@ -13,20 +13,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::collection::RedfishCollection;
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::Payload;
-use crate::patch_support::ReadPatchFn;
+use crate::resource::fmt_summary;
 use crate::schema::resource::ResourceCollection;
 use crate::schema::software_inventory::SoftwareInventory as SoftwareInventorySchema;
 use crate::schema::software_inventory_collection::SoftwareInventoryCollection as SoftwareInventoryCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
+use crate::RefreshableResource;
 use crate::Resource;
+use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EdmDateTimeOffset;
 use nv_redfish_core::NavProperty;
 use std::convert::identity;
+use std::fmt;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -64,9 +69,8 @@ impl<B: Bmc> SoftwareInventory<B> {
     pub(crate) async fn new(
         bmc: &NvBmc<B>,
         nav: &NavProperty<SoftwareInventorySchema>,
-        read_patch_fn: Option<&ReadPatchFn>,
     ) -> Result<Self, Error<B>> {
-        if let Some(read_patch_fn) = read_patch_fn {
+        if let Some(read_patch_fn) = bmc.patches_for(nav.id()).read::<SoftwareInventorySchema>() {
             Payload::get(bmc.as_ref(), nav, read_patch_fn.as_ref()).await
         } else {
             nav.get(bmc.as_ref()).await.map_err(Error::Bmc)
@@ -86,6 +90,13 @@ impl<B: Bmc> SoftwareInventory<B> {
         self.data.clone()
     }
 
+    /// One-line summary of the software inventory item for logs:
+    /// identifier, version and state. Same as the `Display` output.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+
     /// Get the version of software inventory item.
     #[must_use]
     pub fn version(&self) -> Option<VersionRef<'_>> {
@@ -112,10 +123,39 @@ impl<B: Bmc> Resource for SoftwareInventory<B> {
     }
 }
 
+impl<B: Bmc> ResourceProvidesStatus for SoftwareInventory<B> {
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> RefreshableResource<B> for SoftwareInventory<B> {
+    async fn refresh(&self) -> Result<Self, Error<B>> {
+        Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.odata_id().clone()),
+        )
+        .await
+    }
+}
+
+impl<B: Bmc> fmt::Display for SoftwareInventory<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_summary(
+            f,
+            "SoftwareInventory",
+            self,
+            &[(
+                "version",
+                self.data.version.as_ref().and_then(Option::as_deref),
+            )],
+        )
+    }
+}
+
 pub struct SoftwareInventoryCollection<B: Bmc> {
     bmc: NvBmc<B>,
     collection: Arc<SoftwareInventoryCollectionSchema>,
-    read_patch_fn: Option<ReadPatchFn>,
 }
 
 impl<B: Bmc> CollectionWithPatch<SoftwareInventoryCollectionSchema, SoftwareInventorySchema, B>
@@ -133,22 +173,35 @@ impl<B: Bmc> SoftwareInventoryCollection<B> {
     pub(crate) async fn new(
         bmc: &NvBmc<B>,
         collection_ref: &NavProperty<SoftwareInventoryCollectionSchema>,
-        read_patch_fn: Option<ReadPatchFn>,
     ) -> Result<Self, Error<B>> {
+        let read_patch_fn = bmc
+            .patches_for(collection_ref.id())
+            .read::<SoftwareInventorySchema>();
         let collection =
             Self::expand_collection(bmc, collection_ref, read_patch_fn.as_ref(), None).await?;
         Ok(Self {
             bmc: bmc.clone(),
             collection,
-            read_patch_fn,
         })
     }
+}
 
-    pub(crate) async fn members(&self) -> Result<Vec<SoftwareInventory<B>>, Error<B>> {
-        let mut items = Vec::new();
-        for nav in &self.collection.members {
-            items.push(SoftwareInventory::new(&self.bmc, nav, self.read_patch_fn.as_ref()).await?);
-        }
-        Ok(items)
+impl<B: Bmc> RedfishCollection<B> for SoftwareInventoryCollection<B> {
+    type MemberSchema = SoftwareInventorySchema;
+    type Member = SoftwareInventory<B>;
+
+    fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
+
+    fn members_nav(&self) -> &[NavProperty<SoftwareInventorySchema>] {
+        &self.collection.members
+    }
+
+    async fn member(
+        &self,
+        nav: &NavProperty<SoftwareInventorySchema>,
+    ) -> Result<SoftwareInventory<B>, Error<B>> {
+        SoftwareInventory::new(&self.bmc, nav).await
     }
 }
@@ -31,7 +31,7 @@ fn main() -> Result<(), Error> {
     process_command(&Commands::Compile {
         root: DEFAULT_ROOT.into(),
         output: out_dir().join("base_tests.rs"),
-        csdls: base_csdls,
+        csdls: base_csdls.clone(),
         entity_type_patterns: vec![],
         include_root_patterns: vec!["ServiceRoot.*.RootSetOnlyComplexType"
             .parse()
@@ -39,6 +39,23 @@ fn main() -> Result<(), Error> {
         rigid_array_patterns: vec!["ServiceRoot.*.ServiceRoot/RigidArrayValues"
             .parse()
             .expect("valid rigid array pattern")],
+        lean_property_patterns: vec![],
+        strict_references: false,
+        reference_cache: None,
+        download_references: false,
+        stats: None,
+    })?;
+
+    process_command(&Commands::Payloads {
+        root: DEFAULT_ROOT.into(),
+        include_root_patterns: vec![],
+        csdls: base_csdls,
+        output: out_dir().join("base_payloads.json"),
+        type_patterns: vec!["ServiceRoot.ServiceRoot"
+            .parse()
+            .expect("valid payload type pattern")],
+        count: 64,
+        seed: 0,
     })?;
     Ok(())
 }
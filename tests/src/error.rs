@@ -28,6 +28,7 @@ pub enum Error {
     ExpectedProperty(&'static str),
 }
 
+#[derive(Clone)]
 pub enum TestError {}
 
 impl Default for TestError {
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden snapshots of wrapper views.
+//!
+//! A view (hardware ids, status, summary assembled by a test) is
+//! serialized to pretty-printed JSON with sorted keys and compared
+//! with `tests/goldens/<name>.json`. Changes of schemas or quirks
+//! that change what wrappers report make the comparison fail.
+//!
+//! After an intended change, run tests with
+//! `NV_REDFISH_UPDATE_GOLDENS=1` to rewrite the goldens and review
+//! the difference.
+
+use std::env::var_os;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+/// Environment variable that makes `assert_golden` rewrite goldens.
+pub const UPDATE_GOLDENS_ENV: &str = "NV_REDFISH_UPDATE_GOLDENS";
+
+/// Path of the golden `name`.
+#[must_use]
+pub fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/goldens")).join(format!("{name}.json"))
+}
+
+/// Assert that `view` serializes to the golden `name`.
+///
+/// # Panics
+///
+/// Panics if the golden is missing or differs from `view`.
+pub fn assert_golden(name: &str, view: &impl Serialize) {
+    // Value keeps keys of objects sorted, so the output doesn't
+    // depend on order of struct fields or of the payload.
+    let value = serde_json::to_value(view).expect("view must be serializable");
+    let actual = serde_json::to_string_pretty(&value).expect("json serializable") + "\n";
+    let path = golden_path(name);
+    if var_os(UPDATE_GOLDENS_ENV).is_some() {
+        fs::create_dir_all(path.parent().expect("golden has directory"))
+            .and_then(|()| fs::write(&path, &actual))
+            .unwrap_or_else(|err| panic!("cannot write golden {}: {err}", path.display()));
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "cannot read golden {}: {err} (set {UPDATE_GOLDENS_ENV} to create it)",
+            path.display()
+        )
+    });
+    assert!(
+        expected == actual,
+        "view differs from golden {} (set {UPDATE_GOLDENS_ENV} to update it)\n\
+         expected:\n{expected}\nactual:\n{actual}",
+        path.display()
+    );
+}
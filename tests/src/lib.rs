@@ -19,13 +19,23 @@
 pub mod base;
 /// Errors used in tests.
 pub mod error;
+/// Golden snapshots of wrapper views.
+pub mod golden;
 /// Expectations in tests.
 pub mod json_merge;
 
 #[doc(inline)]
 pub use error::Error;
 #[doc(inline)]
+pub use golden::assert_golden;
+#[doc(inline)]
 pub use json_merge::json_merge;
+#[doc(inline)]
+pub use nv_redfish_bmc_mock::BodyMatcher;
+#[doc(inline)]
+pub use nv_redfish_bmc_mock::ResponseStatus;
+#[doc(inline)]
+pub use nv_redfish_bmc_mock::TaskMonitor;
 
 /// Used in tests for `@odata.id` fields.
 pub const ODATA_ID: &str = "@odata.id";
@@ -38,6 +48,10 @@ use error::TestError;
 
 use nv_redfish_bmc_mock::Bmc as MockBmc;
 use nv_redfish_bmc_mock::Expect as MockExpect;
+use nv_redfish_bmc_mock::Mockup;
+use nv_redfish_bmc_mock::MockupError;
+use nv_redfish_bmc_mock::Scenario;
+use nv_redfish_bmc_mock::ScenarioError;
 use nv_redfish_core::AsyncTask;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
@@ -48,6 +62,22 @@ use serde_json::Value;
 pub type Bmc = MockBmc<TestError>;
 pub type Expect = MockExpect<TestError>;
 
+/// Create BMC that serves resources of the mockup `name` from
+/// `tests/mockups`.
+pub fn mockup_bmc(name: &str) -> Result<Bmc, MockupError> {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/mockups");
+    Mockup::load(format!("{dir}/{name}")).map(Bmc::with_mockup)
+}
+
+/// Create BMC that expects requests of the scenario `name` from
+/// `tests/scenarios`.
+pub fn scenario_bmc(name: &str) -> Result<Bmc, ScenarioError> {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/scenarios");
+    let bmc = Bmc::default();
+    bmc.expect_scenario(&Scenario::load(format!("{dir}/{name}.json"))?)?;
+    Ok(bmc)
+}
+
 pub fn async_task(location: &str, retry_after_secs: u64) -> AsyncTask {
     AsyncTask {
         location: ODataId::from(location.to_string()).into(),
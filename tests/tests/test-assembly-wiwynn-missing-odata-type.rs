@@ -18,6 +18,7 @@ use nv_redfish::chassis::Chassis;
 use nv_redfish::hardware_id::Model;
 use nv_redfish::hardware_id::PartNumber;
 use nv_redfish::hardware_id::SerialNumber;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
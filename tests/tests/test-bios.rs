@@ -16,6 +16,7 @@
 
 use nv_redfish::computer_system::Bios;
 use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::EdmPrimitiveType;
 use nv_redfish_core::ODataId;
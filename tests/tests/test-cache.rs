@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for conditional GET caching.
+
+use nv_redfish::cache::CacheBmc;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+
+fn service_root(version: &str) -> Value {
+    json!({
+        ODATA_ID: ODataId::service_root(),
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "RedfishVersion": version,
+    })
+}
+
+#[test]
+async fn not_modified_resources_are_reused() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(ODataId::service_root(), service_root("1.21.1")).with_etag("\"1\""));
+    bmc.expect(Expect::get(ODataId::service_root(), service_root("1.21.1")).with_etag("\"1\""));
+    bmc.expect(Expect::get(ODataId::service_root(), service_root("1.22.0")).with_etag("\"2\""));
+    let cache = Arc::new(CacheBmc::new(bmc, 16));
+
+    let first = ServiceRoot::new(Arc::clone(&cache)).await?;
+    let second = ServiceRoot::new(Arc::clone(&cache)).await?;
+    assert!(Arc::ptr_eq(&first.root, &second.root));
+
+    let modified = ServiceRoot::new(Arc::clone(&cache)).await?;
+    assert!(!Arc::ptr_eq(&first.root, &modified.root));
+    assert_eq!(modified.root.redfish_version.as_deref(), Some("1.22.0"));
+    assert_eq!(cache.len(), 1);
+    Ok(())
+}
+
+#[test]
+async fn zero_capacity_disables_caching() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(ODataId::service_root(), service_root("1.21.1")).with_etag("\"1\""));
+    bmc.expect(Expect::get(ODataId::service_root(), service_root("1.21.1")).with_etag("\"1\""));
+    let cache = Arc::new(CacheBmc::new(bmc, 0));
+
+    let first = ServiceRoot::new(Arc::clone(&cache)).await?;
+    let second = ServiceRoot::new(Arc::clone(&cache)).await?;
+    assert!(!Arc::ptr_eq(&first.root, &second.root));
+    assert!(cache.is_empty());
+    Ok(())
+}
@@ -15,6 +15,7 @@
 //! Integration tests for LiteOn OEM power supply links via chassis.
 
 use nv_redfish::chassis::Chassis;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::anonymous_1_9_service_root;
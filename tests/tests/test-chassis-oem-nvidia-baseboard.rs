@@ -15,6 +15,7 @@
 //! Integration tests for NVIDIA Baseboard CBC chassis OEM extension.
 
 use nv_redfish::chassis::Chassis;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
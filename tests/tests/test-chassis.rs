@@ -20,8 +20,14 @@ use std::sync::Arc;
 use nv_redfish::chassis::Chassis;
 use nv_redfish::chassis::PowerSupply;
 use nv_redfish::control::ControlUpdate;
+use nv_redfish::resource::Health;
 use nv_redfish::resource::ResetType;
+use nv_redfish::resource::State;
+use nv_redfish::RedfishCollection as _;
+use nv_redfish::Resource as _;
+use nv_redfish::ResourceProvidesStatus as _;
 use nv_redfish::ServiceRoot;
+use nv_redfish_bmc_mock::Fault;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::ami_service_root;
@@ -152,9 +158,9 @@ async fn ami_viking_missing_root_chassis_nav_workaround() -> Result<(), Box<dyn
     let ids = ids();
     let root = expect_viking_service_root(bmc.clone(), &ids, json!({})).await?;
     expect_chassis_collection(bmc.clone(), &ids);
+    expect_chassis_get(bmc.clone(), &ids, valid_chassis_payload(&ids));
 
     let collection = root.chassis().await?.unwrap();
-    expect_chassis_get(bmc.clone(), &ids, valid_chassis_payload(&ids));
     let members = collection.members().await?;
     assert_eq!(members.len(), 1);
 
@@ -264,8 +270,6 @@ async fn ami_viking_invalid_contained_by_fields_workaround() -> Result<(), Box<d
     )
     .await?;
     expect_chassis_collection(bmc.clone(), &ids);
-
-    let collection = root.chassis().await?.unwrap();
     expect_chassis_get(
         bmc.clone(),
         &ids,
@@ -283,6 +287,8 @@ async fn ami_viking_invalid_contained_by_fields_workaround() -> Result<(), Box<d
             }
         }),
     );
+
+    let collection = root.chassis().await?.unwrap();
     let members = collection.members().await?;
     assert_eq!(members.len(), 1);
 
@@ -302,8 +308,6 @@ async fn ami_viking_missing_chassis_type_workaround() -> Result<(), Box<dyn StdE
     )
     .await?;
     expect_chassis_collection(bmc.clone(), &ids);
-
-    let collection = root.chassis().await?.unwrap();
     expect_chassis_get(
         bmc.clone(),
         &ids,
@@ -314,6 +318,8 @@ async fn ami_viking_missing_chassis_type_workaround() -> Result<(), Box<dyn StdE
             "Name": "Chassis"
         }),
     );
+
+    let collection = root.chassis().await?.unwrap();
     let members = collection.members().await?;
     assert_eq!(members.len(), 1);
 
@@ -333,8 +339,6 @@ async fn ami_viking_missing_chassis_name_workaround() -> Result<(), Box<dyn StdE
     )
     .await?;
     expect_chassis_collection(bmc.clone(), &ids);
-
-    let collection = root.chassis().await?.unwrap();
     expect_chassis_get(
         bmc.clone(),
         &ids,
@@ -345,6 +349,8 @@ async fn ami_viking_missing_chassis_name_workaround() -> Result<(), Box<dyn StdE
             "ChassisType": "RackMount"
         }),
     );
+
+    let collection = root.chassis().await?.unwrap();
     let members = collection.members().await?;
     assert_eq!(members.len(), 1);
 
@@ -356,7 +362,8 @@ async fn ami_gb300_disables_expand_for_chassis_collection() -> Result<(), Box<dy
     // Platform under test: Grace-based NVIDIA GB300 host BMC (AMI, RtpVersion 13.09.1).
     // Quirk under test: its `$expand` drops Required fields (Id/Name/ChassisType)
     // from embedded members, so the collection is fetched with a plain GET and
-    // members are fetched individually (complete) rather than via `$expand`.
+    // members are fetched individually (complete) together with it rather than
+    // via `$expand`.
     let bmc = Arc::new(Bmc::default());
     let ids = ids();
     let root = expect_gb300_service_root(
@@ -371,11 +378,39 @@ async fn ami_gb300_disables_expand_for_chassis_collection() -> Result<(), Box<dy
     // collection would be fetched via `$expand` and this expectation would not
     // match.
     expect_chassis_collection(bmc.clone(), &ids);
+    expect_chassis_get(bmc.clone(), &ids, valid_chassis_payload(&ids));
 
     let collection = root.chassis().await?.unwrap();
+    // No requests are issued when members are accessed.
+    let members = collection.members().await?;
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].raw().base.name, "Chassis");
+
+    Ok(())
+}
+
+#[test]
+async fn client_side_expand_fetches_members_with_collection() -> Result<(), Box<dyn StdError>> {
+    // The BMC doesn't support `$expand`. Expand is emulated on the
+    // client side by default: members are fetched together with the
+    // collection and no requests are issued when members are accessed.
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_anonymous_1_9_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+        }),
+    )
+    .await?;
+    expect_chassis_collection(bmc.clone(), &ids);
     expect_chassis_get(bmc.clone(), &ids, valid_chassis_payload(&ids));
+
+    let collection = root.chassis().await?.unwrap();
     let members = collection.members().await?;
     assert_eq!(members.len(), 1);
+    assert_eq!(members[0].raw().base.name, "Chassis");
 
     Ok(())
 }
@@ -452,6 +487,198 @@ async fn anonymous_1_9_0_wrong_chassis_status_state_workaround() -> Result<(), B
     Ok(())
 }
 
+// Check that status helpers report fields of the chassis status.
+#[test]
+async fn chassis_provides_status() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Status": {
+                    "Health": "Warning",
+                    "HealthRollup": "Critical",
+                    "State": "Enabled"
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    assert_eq!(chassis.state(), Some(State::Enabled));
+    assert_eq!(chassis.health(), Some(Health::Warning));
+    assert_eq!(chassis.health_rollup(), Some(Health::Critical));
+    let status = chassis.status().expect("status");
+    assert_eq!(status.health, chassis.health());
+
+    Ok(())
+}
+
+// Check that summary of the chassis contains identifier, model,
+// serial number and state, and missing values are shown as `-`.
+#[test]
+async fn chassis_summary() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Model": "3500RX",
+                "Status": { "State": "Enabled" }
+            }),
+        ),
+    )
+    .await?;
+
+    assert_eq!(
+        chassis.summary(),
+        "Chassis 1: model 3500RX, serial -, state Enabled"
+    );
+    assert_eq!(chassis.to_string(), chassis.summary());
+
+    Ok(())
+}
+
+#[test]
+async fn try_members_returns_fetched_chassis_with_errors() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_anonymous_1_9_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+        }),
+    )
+    .await?;
+    bmc.expect(Expect::get(
+        &ids.chassis_collection_id,
+        json!({
+            ODATA_ID: &ids.chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Id": "Chassis",
+            "Name": "Chassis Collection",
+            "Members": [
+                { ODATA_ID: &ids.chassis_id },
+                { ODATA_ID: &ids.container_chassis_id }
+            ]
+        }),
+    ));
+    let collection = root.chassis().await?.unwrap();
+    expect_chassis_get(bmc.clone(), &ids, valid_chassis_payload(&ids));
+    bmc.expect(Expect::fault(
+        &ids.container_chassis_id,
+        Fault::ConnectionReset,
+    ));
+
+    let partial = collection.try_members().await;
+    assert!(!partial.is_complete());
+    assert_eq!(partial.members.len(), 1);
+    assert_eq!(partial.members[0].odata_id().to_string(), ids.chassis_id);
+    assert_eq!(partial.errors.len(), 1);
+    assert_eq!(partial.errors[0].0.to_string(), ids.container_chassis_id);
+
+    Ok(())
+}
+
+#[test]
+async fn topology_builds_containment_tree() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let sled_id = format!("{}/2", ids.chassis_collection_id);
+    let root = expect_viking_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+        }),
+    )
+    .await?;
+    bmc.expect(Expect::get(
+        &ids.chassis_collection_id,
+        json!({
+            ODATA_ID: &ids.chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Id": "Chassis",
+            "Name": "Chassis Collection",
+            "Members": [
+                { ODATA_ID: &sled_id },
+                { ODATA_ID: &ids.chassis_id },
+                { ODATA_ID: &ids.container_chassis_id }
+            ]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &sled_id,
+        json!({
+            ODATA_ID: &sled_id,
+            ODATA_TYPE: CHASSIS_DATA_TYPE,
+            "Id": "2",
+            "Name": "Sled",
+            "ChassisType": "Sled"
+        }),
+    ));
+    expect_chassis_get(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Links": {
+                    "ContainedBy": {
+                        ODATA_ID: &ids.container_chassis_id,
+                        "InvalidField": "invalid"
+                    },
+                    "Contains": [{ ODATA_ID: &sled_id }]
+                }
+            }),
+        ),
+    );
+    bmc.expect(Expect::get(
+        &ids.container_chassis_id,
+        json!({
+            ODATA_ID: &ids.container_chassis_id,
+            ODATA_TYPE: CHASSIS_DATA_TYPE,
+            "Id": "0",
+            "Name": "Rack",
+            "ChassisType": "Rack"
+        }),
+    ));
+
+    let collection = root.chassis().await?.unwrap();
+    let topology = collection.topology().await?;
+    assert_eq!(topology.len(), 1);
+    let rack = &topology[0];
+    assert_eq!(
+        rack.chassis().odata_id().to_string(),
+        ids.container_chassis_id
+    );
+    assert_eq!(rack.children().len(), 1);
+    let chassis = &rack.children()[0];
+    assert_eq!(chassis.chassis().odata_id().to_string(), ids.chassis_id);
+    assert_eq!(
+        chassis
+            .chassis()
+            .contained_by()
+            .map(|link| link.odata_id().to_string()),
+        Some(ids.container_chassis_id.clone())
+    );
+    assert_eq!(chassis.children().len(), 1);
+    assert_eq!(
+        chassis.children()[0].chassis().odata_id().to_string(),
+        sled_id
+    );
+    assert!(chassis.children()[0].children().is_empty());
+
+    Ok(())
+}
+
 #[test]
 async fn nvidia_dpu_empty_chassis_uuid_in_expanded_members_workaround(
 ) -> Result<(), Box<dyn StdError>> {
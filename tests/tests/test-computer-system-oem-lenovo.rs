@@ -17,6 +17,7 @@
 use nv_redfish::computer_system::ComputerSystem;
 use nv_redfish::oem::lenovo::computer_system::FpMode;
 use nv_redfish::oem::lenovo::computer_system::PortSwitchingTo;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
@@ -16,6 +16,7 @@
 
 use nv_redfish::computer_system::ComputerSystem;
 use nv_redfish::oem::nvidia::bluefield::nvidia_computer_system::Mode;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
@@ -16,13 +16,20 @@
 
 use std::error::Error as StdError;
 use std::sync::Arc;
+use std::time::Duration;
 
+use nv_redfish::action_info::ParameterType;
 use nv_redfish::computer_system::BootOptionReference;
 use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::computer_system::PowerWaiting;
 use nv_redfish::computer_system::SystemCollection;
+use nv_redfish::resource::PowerState;
 use nv_redfish::resource::ResetType;
+use nv_redfish::RedfishCollection as _;
+use nv_redfish::RefreshableResource as _;
 use nv_redfish::Resource;
 use nv_redfish::ServiceRoot;
+use nv_redfish::Sleep;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::ami_viking_service_root;
@@ -79,6 +86,77 @@ async fn reset_invokes_computer_system_reset_action() -> Result<(), Box<dyn StdE
     Ok(())
 }
 
+#[test]
+async fn reset_parameters_are_read_from_action_info() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let action_info_id = format!("{}/ResetActionInfo", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "Actions": {
+                    "#ComputerSystem.Reset": {
+                        "target": &action_target,
+                        "@Redfish.ActionInfo": &action_info_id,
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &action_info_id,
+        json!({
+            ODATA_ID: &action_info_id,
+            ODATA_TYPE: "#ActionInfo.v1_1_2.ActionInfo",
+            "Id": "ResetActionInfo",
+            "Name": "Reset Action Info",
+            "Parameters": [{
+                "Name": "ResetType",
+                "Required": true,
+                "DataType": "String",
+                "AllowableValues": ["On", "ForceOff", "GracefulRestart"]
+            }]
+        }),
+    ));
+
+    let parameters = system.reset_parameters().await?.unwrap();
+    assert_eq!(parameters.len(), 1);
+    let reset_type = &parameters[0];
+    assert_eq!(reset_type.name, "ResetType");
+    assert!(reset_type.is_required());
+    assert_eq!(reset_type.data_type, Some(ParameterType::String));
+    assert!(reset_type.allows_value("GracefulRestart"));
+    assert!(!reset_type.allows_value("PowerCycle"));
+
+    Ok(())
+}
+
+#[test]
+async fn reset_parameters_without_action_info() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            redfish_action_payload("ComputerSystem.Reset", &action_target),
+        ),
+    )
+    .await?;
+
+    assert!(system.reset_parameters().await?.is_none());
+
+    Ok(())
+}
+
 #[test]
 async fn set_boot_order_preserves_task_and_empty_responses() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -121,6 +199,146 @@ async fn set_boot_order_preserves_task_and_empty_responses() -> Result<(), Box<d
     Ok(())
 }
 
+// Check that boot option is moved to the first position of the boot
+// order only if it is one of the boot options of the system.
+#[test]
+async fn move_boot_option_first_validates_reference() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let boot_options_id = format!("{}/BootOptions", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, boot_fields(&boot_options_id)),
+    )
+    .await?;
+
+    expect_boot_options(&bmc, &boot_options_id);
+    bmc.expect(Expect::update_empty(
+        &ids.system_id,
+        json!({ "Boot": { "BootOrder": ["Boot0003", "Boot0001", "Boot0002"] } }),
+    ));
+    assert_empty(
+        system
+            .move_boot_option_first(BootOptionReference::new("Boot0003"))
+            .await?,
+    );
+
+    expect_boot_options(&bmc, &boot_options_id);
+    assert!(matches!(
+        system
+            .move_boot_option_first(BootOptionReference::new("Boot0009"))
+            .await,
+        Err(nv_redfish::Error::BootOptionNotFound(reference)) if reference == "Boot0009"
+    ));
+
+    Ok(())
+}
+
+// Check that selected boot options are moved to the beginning of the
+// boot order preserving relative order.
+#[test]
+async fn reorder_boot_moves_selected_options_first() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let boot_options_id = format!("{}/BootOptions", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, boot_fields(&boot_options_id)),
+    )
+    .await?;
+
+    expect_boot_options(&bmc, &boot_options_id);
+    bmc.expect(Expect::update_empty(
+        &ids.system_id,
+        json!({ "Boot": { "BootOrder": ["Boot0002", "Boot0003", "Boot0001"] } }),
+    ));
+    assert_empty(
+        system
+            .reorder_boot(|option| {
+                option
+                    .display_name()
+                    .is_some_and(|name| name.inner().starts_with("PXE"))
+            })
+            .await?,
+    );
+
+    Ok(())
+}
+
+// Check that boot order of the system itself is updated with its
+// ETag as precondition.
+#[test]
+async fn set_boot_order_sends_system_etag() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default().with_if_match());
+    let ids = computer_system_ids();
+    let fields = computer_system(&ids, json!({ "Boot": { "BootOrder": ["Boot0001"] } }));
+    let system = get_system(bmc.clone(), &ids, fields.clone()).await?;
+
+    bmc.expect(Expect::get(&ids.system_id, fields).with_etag("W/\"1\""));
+    let system = system.refresh().await?;
+    bmc.expect(Expect::update_empty(
+        &ids.system_id,
+        json!({ "Boot": { "BootOrder": ["Boot0002"] } }),
+    ));
+    assert_empty(
+        system
+            .set_boot_order(vec![BootOptionReference::new("Boot0002".into())])
+            .await?,
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn set_boot_order_uses_settings_object_apply_strategy() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let settings_id = format!("{}/Settings", ids.system_id);
+    let fields = json!({
+        "Boot": { "BootOrder": ["Boot0001"] },
+        "@Redfish.Settings": {
+            "SettingsObject": { ODATA_ID: &settings_id },
+        },
+    });
+
+    let system = get_system(bmc.clone(), &ids, computer_system(&ids, fields.clone())).await?;
+    bmc.expect(Expect::update_empty(
+        &settings_id,
+        json!({ "Boot": { "BootOrder": ["Boot0002"] } }),
+    ));
+    assert_empty(
+        system
+            .set_boot_order(vec![BootOptionReference::new("Boot0002".into())])
+            .await?,
+    );
+
+    // Dell applies pending settings only with explicit apply time.
+    let systems = get_systems(
+        bmc.clone(),
+        &ids,
+        "Dell",
+        vec![computer_system(&ids, fields)],
+    )
+    .await?;
+    let system = systems.members().await?.pop().expect("computer system");
+    bmc.expect(Expect::update_empty(
+        &settings_id,
+        json!({
+            "Boot": { "BootOrder": ["Boot0002"] },
+            "@Redfish.SettingsApplyTime": { "ApplyTime": "OnReset" },
+        }),
+    ));
+    assert_empty(
+        system
+            .set_boot_order(vec![BootOptionReference::new("Boot0002".into())])
+            .await?,
+    );
+
+    Ok(())
+}
+
 #[test]
 async fn reset_returns_action_not_available_when_computer_system_reset_is_absent(
 ) -> Result<(), Box<dyn StdError>> {
@@ -141,6 +359,160 @@ async fn reset_returns_action_not_available_when_computer_system_reset_is_absent
     Ok(())
 }
 
+// Check that power off invokes the reset action and polls the system
+// until it reports the target power state.
+#[test]
+async fn power_off_waits_for_power_state() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let actions = redfish_action_payload("ComputerSystem.Reset", &action_target);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, json_merge([&actions, &json!({ "PowerState": "On" })])),
+    )
+    .await?;
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("GracefulShutdown"));
+    for power_state in ["PoweringOff", "Off"] {
+        bmc.expect(Expect::get(
+            &ids.system_id,
+            computer_system(
+                &ids,
+                json_merge([&actions, &json!({ "PowerState": power_state })]),
+            ),
+        ));
+    }
+    let waiting = PowerWaiting::new(Sleep::new(|_| async {})).poll_interval(Duration::ZERO);
+    let system = system.power_off(true, &waiting).await?;
+    assert_eq!(system.power_state(), Some(PowerState::Off));
+
+    Ok(())
+}
+
+// Check that restart waits for the system to leave `On` power state
+// before waiting for `On` again.
+#[test]
+async fn restart_waits_for_power_state_transition() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let actions = redfish_action_payload("ComputerSystem.Reset", &action_target);
+    let last_reset_time = json!({ "LastResetTime": "2026-01-01T00:00:00+00:00" });
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json_merge([&actions, &last_reset_time, &json!({ "PowerState": "On" })]),
+        ),
+    )
+    .await?;
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("ForceRestart"));
+    for power_state in ["On", "PoweringOff", "Off", "PoweringOn", "On"] {
+        bmc.expect(Expect::get(
+            &ids.system_id,
+            computer_system(
+                &ids,
+                json_merge([
+                    &actions,
+                    &last_reset_time,
+                    &json!({ "PowerState": power_state }),
+                ]),
+            ),
+        ));
+    }
+    let waiting = PowerWaiting::new(Sleep::new(|_| async {})).poll_interval(Duration::ZERO);
+    let system = system.restart(false, &waiting).await?;
+    assert_eq!(system.power_state(), Some(PowerState::On));
+    bmc.verify();
+
+    Ok(())
+}
+
+// Check that restart finishes when the system keeps reporting `On`
+// power state but its `LastResetTime` changes.
+#[test]
+async fn restart_finishes_on_last_reset_time_change() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let actions = redfish_action_payload("ComputerSystem.Reset", &action_target);
+    let on_since = |last_reset_time: &str| {
+        computer_system(
+            &ids,
+            json_merge([
+                &actions,
+                &json!({ "PowerState": "On", "LastResetTime": last_reset_time }),
+            ]),
+        )
+    };
+    let system = get_system(bmc.clone(), &ids, on_since("2026-01-01T00:00:00+00:00")).await?;
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("GracefulRestart"));
+    for last_reset_time in ["2026-01-01T00:00:00+00:00", "2026-01-01T00:05:00+00:00"] {
+        bmc.expect(Expect::get(&ids.system_id, on_since(last_reset_time)));
+    }
+    let waiting = PowerWaiting::new(Sleep::new(|_| async {})).poll_interval(Duration::ZERO);
+    let system = system.restart(true, &waiting).await?;
+    assert_eq!(system.power_state(), Some(PowerState::On));
+    bmc.verify();
+
+    Ok(())
+}
+
+// Check that restart of a system that reports neither a power state
+// transition nor `LastResetTime` finishes once the system reports `On`.
+#[test]
+async fn restart_without_last_reset_time_finishes_on_power_state_on(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let actions = redfish_action_payload("ComputerSystem.Reset", &action_target);
+    let on = computer_system(&ids, json_merge([&actions, &json!({ "PowerState": "On" })]));
+    let system = get_system(bmc.clone(), &ids, on.clone()).await?;
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("ForceRestart"));
+    bmc.expect(Expect::get(&ids.system_id, on));
+    let waiting = PowerWaiting::new(Sleep::new(|_| async {})).poll_interval(Duration::ZERO);
+    let system = system.restart(false, &waiting).await?;
+    assert_eq!(system.power_state(), Some(PowerState::On));
+    bmc.verify();
+
+    Ok(())
+}
+
+// Check that waiting for the power state fails when the system
+// doesn't reach it within the timeout.
+#[test]
+async fn power_on_reports_timeout() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let actions = redfish_action_payload("ComputerSystem.Reset", &action_target);
+    let off = computer_system(
+        &ids,
+        json_merge([&actions, &json!({ "PowerState": "Off" })]),
+    );
+    let system = get_system(bmc.clone(), &ids, off.clone()).await?;
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("On"));
+    bmc.expect(Expect::get(&ids.system_id, off));
+    let waiting = PowerWaiting::new(Sleep::new(|_| async {})).timeout(Duration::ZERO);
+    assert!(matches!(
+        system.power_on(&waiting).await,
+        Err(nv_redfish::Error::PowerStateTimeout {
+            power_state: PowerState::On,
+            ..
+        })
+    ));
+
+    Ok(())
+}
+
 #[test]
 async fn dell_wrong_last_reset_time_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -163,7 +535,9 @@ async fn dell_wrong_last_reset_time_workaround() -> Result<(), Box<dyn StdError>
 async fn ami_viking_missing_root_systems_nav_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
     let ids = computer_system_ids();
-    let computer_system = computer_system(&ids, json!({}));
+    // Viking filters out systems other than DGX and HGX_Baseboard_0.
+    let dgx_id = format!("{}/DGX", ids.systems_id);
+    let computer_system = computer_system(&ids, json!({ ODATA_ID: &dgx_id }));
     let service_root = expect_viking_service_root_without_systems(bmc.clone(), &ids).await?;
     bmc.expect(Expect::get(
         &ids.systems_id,
@@ -420,7 +794,7 @@ async fn viking_with_garbage_in_computer_systems() -> Result<(), Box<dyn StdErro
         }),
     ));
 
-    let systems = service_root.systems().await?.unwrap();
+    // Members are fetched with the collection, except the garbage entry.
     bmc.expect(Expect::get(
         &dgx_id,
         computer_system(&ids, json!({ ODATA_ID: &dgx_id })),
@@ -429,6 +803,7 @@ async fn viking_with_garbage_in_computer_systems() -> Result<(), Box<dyn StdErro
         &hgx_id,
         computer_system(&ids, json!({ ODATA_ID: &hgx_id })),
     ));
+    let systems = service_root.systems().await?.unwrap();
 
     let members = systems.members().await?;
 
@@ -460,6 +835,44 @@ fn computer_system_ids() -> ComputerSystemIds {
     }
 }
 
+fn boot_fields(boot_options_id: &str) -> Value {
+    json!({
+        "Boot": {
+            "BootOrder": ["Boot0001", "Boot0002", "Boot0003"],
+            "BootOptions": { ODATA_ID: boot_options_id }
+        }
+    })
+}
+
+fn expect_boot_options(bmc: &Bmc, boot_options_id: &str) {
+    let members = [
+        ("Boot0001", "Hard Disk"),
+        ("Boot0002", "PXE IPv4"),
+        ("Boot0003", "PXE IPv6"),
+    ]
+    .iter()
+    .map(|(reference, display_name)| {
+        json!({
+            ODATA_ID: format!("{boot_options_id}/{reference}"),
+            ODATA_TYPE: "#BootOption.v1_0_4.BootOption",
+            "Id": reference,
+            "Name": display_name,
+            "BootOptionReference": reference,
+            "DisplayName": display_name
+        })
+    })
+    .collect::<Vec<_>>();
+    bmc.expect(Expect::expand(
+        boot_options_id,
+        json!({
+            ODATA_ID: boot_options_id,
+            ODATA_TYPE: "#BootOptionCollection.BootOptionCollection",
+            "Name": "Boot Options",
+            "Members": members
+        }),
+    ));
+}
+
 fn resource_name(id: &str) -> &str {
     id.rsplit('/').next().unwrap_or(id)
 }
@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for per-request deadlines and cancellation.
+
+use futures_util::future::ready;
+use nv_redfish::deadline::Cancellation;
+use nv_redfish::deadline::DeadlineBmc;
+use nv_redfish::deadline::DeadlineError;
+use nv_redfish::deadline::Deadlines;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish::Sleep;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+
+#[test]
+async fn requests_pass_through_deadline_bmc() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let deadlines = Deadlines::new(Sleep::new(|_| ready(()))).timeout(Duration::from_secs(1));
+    let root = get_root(bmc.clone(), deadlines).await?;
+    let widget_id = ODataId::from("/redfish/v1/Oem/Vendor/Widget".to_string());
+
+    bmc.expect(Expect::get(
+        &widget_id,
+        json!({ ODATA_ID: &widget_id, "Name": "Widget" }),
+    ));
+    let widget = root.get_as::<Value>(&widget_id).await?;
+    assert_eq!(widget["Name"], "Widget");
+
+    Ok(())
+}
+
+#[test]
+async fn cancelled_requests_are_not_sent() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let cancellation = Cancellation::new();
+    let deadlines = Deadlines::new(Sleep::new(|_| ready(()))).cancellation(cancellation.clone());
+    let root = get_root(bmc.clone(), deadlines).await?;
+    let widget_id = ODataId::from("/redfish/v1/Oem/Vendor/Widget".to_string());
+
+    cancellation.cancel();
+    let result = root.get_as::<Value>(&widget_id).await;
+    assert!(matches!(result, Err(Error::Bmc(DeadlineError::Cancelled))));
+
+    Ok(())
+}
+
+async fn get_root(
+    bmc: Arc<Bmc>,
+    deadlines: Deadlines,
+) -> Result<ServiceRoot<DeadlineBmc<Bmc>>, Box<dyn StdError>> {
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "Root Service",
+            "RedfishVersion": "1.21.1",
+        }),
+    ));
+    ServiceRoot::new(Arc::new(DeadlineBmc::new(bmc, deadlines)))
+        .await
+        .map_err(Into::into)
+}
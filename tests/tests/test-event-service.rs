@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for EventService SSE streams.
+
+use futures_util::StreamExt as _;
+use nv_redfish::event_service::EventStreamPayload;
+use nv_redfish::quirks::Quirk;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_bmc_mock::Error as BmcError;
+use nv_redfish_bmc_mock::SseItem;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SSE_URI: &str = "/redfish/v1/EventService/SSE";
+
+#[test]
+async fn sse_events_are_patched_until_disconnect() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let service_id = format!("{root_id}/EventService");
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: "#ServiceRoot.v1_17_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "EventService": { ODATA_ID: &service_id },
+            "Links": {
+                "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+            },
+        }),
+    ));
+    let root = ServiceRoot::builder(bmc.clone())
+        .enable_quirk(Quirk::EventServiceSseNoMemberId)
+        .build()
+        .await?;
+    bmc.expect(Expect::get(
+        &service_id,
+        json!({
+            ODATA_ID: &service_id,
+            ODATA_TYPE: "#EventService.v1_10_0.EventService",
+            "Id": "EventService",
+            "Name": "Event Service",
+            "ServerSentEventUri": SSE_URI,
+        }),
+    ));
+    let service = root.event_service().await?.expect("event service");
+
+    bmc.expect(Expect::sse(
+        SSE_URI,
+        [
+            SseItem::event(event("1")),
+            SseItem::Disconnect,
+            SseItem::event(event("2")),
+        ],
+    ));
+    let events = service.events().await?.collect::<Vec<_>>().await;
+    assert_eq!(events.len(), 2);
+    // `@odata.id` and `MemberId` are added by the quirk patches.
+    assert!(matches!(events[0], Ok(EventStreamPayload::Event(_))));
+    assert!(matches!(
+        events[1],
+        Err(Error::Bmc(BmcError::StreamDisconnected))
+    ));
+
+    Ok(())
+}
+
+fn event(id: &str) -> serde_json::Value {
+    json!({
+        ODATA_TYPE: "#Event.v1_6_0.Event",
+        "Id": id,
+        "Name": "Event Array",
+        "Events": [{
+            "EventId": id,
+            "EventType": "Alert",
+            "Message": "The resource has been removed successfully.",
+            "MessageId": "ResourceEvent.1.2.ResourceRemoved",
+        }],
+    })
+}
@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests of expansion of navigation properties.
+
+use nv_redfish::RedfishCollection as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+const CHASSIS_COLLECTION_DATA_TYPE: &str = "#ChassisCollection.ChassisCollection";
+const CHASSIS_DATA_TYPE: &str = "#Chassis.v1_22_0.Chassis";
+
+#[test]
+async fn expand_property_with_query_limits_levels_by_max_levels() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(
+        bmc.clone(),
+        Some(json!({ "NoLinks": true, "Levels": true, "MaxLevels": 2 })),
+    )
+    .await?;
+    let nav = root.root.chassis.clone().expect("chassis link");
+
+    bmc.expect(Expect::expand_with_query(
+        collection_id(),
+        &ExpandQuery::no_links().levels(2),
+        chassis_collection(vec![]),
+    ));
+    let collection = root
+        .expand_property_with_query(&nav, ExpandQuery::no_links().levels(5))
+        .await?;
+    assert!(collection.members.is_empty());
+    bmc.verify();
+
+    Ok(())
+}
+
+#[test]
+async fn expand_property_with_query_expands_one_level_without_levels(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone(), Some(json!({ "NoLinks": true }))).await?;
+    let nav = root.root.chassis.clone().expect("chassis link");
+
+    bmc.expect(Expect::expand_with_query(
+        collection_id(),
+        &ExpandQuery::property("Members").levels(1),
+        chassis_collection(vec![]),
+    ));
+    root.expand_property_with_query(&nav, ExpandQuery::property("Members").levels(3))
+        .await?;
+    bmc.verify();
+
+    Ok(())
+}
+
+#[test]
+async fn expand_property_prefers_no_links_query() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(
+        bmc.clone(),
+        Some(json!({ "ExpandAll": true, "NoLinks": true, "Levels": true })),
+    )
+    .await?;
+
+    bmc.expect(Expect::expand_with_query(
+        collection_id(),
+        &ExpandQuery::no_links(),
+        chassis_collection(vec![chassis("1")]),
+    ));
+    let members = root.chassis().await?.expect("chassis").members().await?;
+    assert_eq!(members.len(), 1);
+    bmc.verify();
+
+    Ok(())
+}
+
+#[test]
+async fn expand_property_fetches_members_on_client_side() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone(), None).await?;
+
+    bmc.expect(Expect::get(
+        collection_id(),
+        chassis_collection(vec![
+            json!({ ODATA_ID: format!("{}/1", collection_id()) }),
+            json!({ ODATA_ID: format!("{}/2", collection_id()) }),
+        ]),
+    ));
+    bmc.expect(Expect::get(format!("{}/1", collection_id()), chassis("1")));
+    bmc.expect(Expect::get(format!("{}/2", collection_id()), chassis("2")));
+    let members = root.chassis().await?.expect("chassis").members().await?;
+    assert_eq!(members.len(), 2);
+    bmc.verify();
+
+    Ok(())
+}
+
+#[test]
+async fn disabled_client_side_expand_fetches_members_on_access() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone(), None)
+        .await?
+        .disable_client_side_expand();
+
+    bmc.expect(Expect::get(
+        collection_id(),
+        chassis_collection(vec![json!({ ODATA_ID: format!("{}/1", collection_id()) })]),
+    ));
+    let collection = root.chassis().await?.expect("chassis");
+    bmc.verify();
+
+    bmc.expect(Expect::get(format!("{}/1", collection_id()), chassis("1")));
+    let members = collection.members().await?;
+    assert_eq!(members.len(), 1);
+    bmc.verify();
+
+    Ok(())
+}
+
+async fn get_root(
+    bmc: Arc<Bmc>,
+    expand: Option<Value>,
+) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    let root_id = ODataId::service_root();
+    let mut root = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "Chassis": { ODATA_ID: collection_id() },
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+            }
+        },
+    });
+    if let Some(expand) = expand {
+        root["ProtocolFeaturesSupported"] = json!({ "ExpandQuery": expand });
+    }
+    bmc.expect(Expect::get(&root_id, root));
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+fn collection_id() -> String {
+    format!("{}/Chassis", ODataId::service_root())
+}
+
+fn chassis_collection(members: Vec<Value>) -> Value {
+    json!({
+        ODATA_ID: collection_id(),
+        ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+        "Id": "Chassis",
+        "Name": "Chassis Collection",
+        "Members": members,
+    })
+}
+
+fn chassis(id: &str) -> Value {
+    json!({
+        ODATA_ID: format!("{}/{id}", collection_id()),
+        ODATA_TYPE: CHASSIS_DATA_TYPE,
+        "Id": id,
+        "Name": id,
+        "ChassisType": "RackMount",
+    })
+}
@@ -0,0 +1,186 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of ordering and counting of expectations and
+//! of injected faults.
+
+use std::error::Error as StdError;
+use std::future::ready;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use nv_redfish::Error;
+use nv_redfish::ErrorKind;
+use nv_redfish::ServiceRoot;
+use nv_redfish_bmc_mock::Error as BmcError;
+use nv_redfish_bmc_mock::ExpectOrder;
+use nv_redfish_bmc_mock::Fault;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use tokio::test;
+
+const CHASSIS_PATH: &str = "/redfish/v1/Chassis";
+const SYSTEMS_PATH: &str = "/redfish/v1/Systems";
+
+#[test]
+async fn strict_order_rejects_reordered_requests() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(&bmc).await?;
+    bmc.expect(Expect::head(CHASSIS_PATH));
+    bmc.expect(Expect::head(SYSTEMS_PATH));
+
+    let result = root.probe(&ODataId::from(SYSTEMS_PATH.to_string())).await;
+    assert!(matches!(
+        result,
+        Err(Error::Bmc(BmcError::UnexpectedHead(..)))
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn per_resource_order_accepts_requests_to_other_resources() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default().with_order(ExpectOrder::PerResource));
+    let root = service_root(&bmc).await?;
+    bmc.expect(Expect::head(CHASSIS_PATH));
+    bmc.expect(Expect::head(SYSTEMS_PATH));
+
+    root.probe(&ODataId::from(SYSTEMS_PATH.to_string())).await?;
+    root.probe(&ODataId::from(CHASSIS_PATH.to_string())).await?;
+    bmc.verify();
+
+    Ok(())
+}
+
+#[test]
+async fn times_expects_exact_number_of_requests() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(&bmc).await?;
+    let id = ODataId::from(CHASSIS_PATH.to_string());
+    bmc.expect_all(Expect::head(&id).times(2));
+
+    root.probe(&id).await?;
+    root.probe(&id).await?;
+    bmc.verify();
+    assert!(matches!(
+        root.probe(&id).await,
+        Err(Error::Bmc(BmcError::NothingIsExpected))
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "expected requests were not issued")]
+async fn verify_fails_on_missing_requests() {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(&bmc).await.expect("service root");
+    let id = ODataId::from(CHASSIS_PATH.to_string());
+    bmc.expect_all(Expect::head(&id).times(2));
+
+    root.probe(&id).await.expect("probed");
+    bmc.verify();
+}
+
+#[test]
+async fn faults_are_injected_before_response() -> Result<(), Box<dyn StdError>> {
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let recorded = delays.clone();
+    let bmc = Arc::new(Bmc::default().with_sleep(move |delay| {
+        recorded.lock().expect("not poisoned").push(delay);
+        Box::pin(ready(()))
+    }));
+    let root = service_root(&bmc).await?;
+    let id = ODataId::from(CHASSIS_PATH.to_string());
+    let retry_after = Some(Duration::from_secs(5));
+    bmc.expect(Expect::fault(&id, Fault::Latency(Duration::from_secs(2))));
+    bmc.expect(Expect::fault(
+        &id,
+        Fault::ServiceUnavailable { retry_after },
+    ));
+    bmc.expect(Expect::fault(&id, Fault::ConnectionReset));
+    bmc.expect(Expect::head(&id));
+
+    assert!(matches!(
+        root.probe(&id).await,
+        Err(Error::Bmc(BmcError::ServiceUnavailable(delay))) if delay == retry_after
+    ));
+    assert!(matches!(
+        root.probe(&id).await,
+        Err(Error::Bmc(BmcError::ConnectionReset))
+    ));
+    root.probe(&id).await?;
+    bmc.verify();
+    assert_eq!(
+        *delays.lock().expect("not poisoned"),
+        [Duration::from_secs(2)]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn injected_faults_are_classified() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(&bmc).await?;
+    let id = ODataId::from(CHASSIS_PATH.to_string());
+    bmc.expect(Expect::fault(
+        &id,
+        Fault::ServiceUnavailable { retry_after: None },
+    ));
+    bmc.expect(Expect::fault(&id, Fault::ConnectionReset));
+
+    let err = root.probe(&id).await.expect_err("service unavailable");
+    assert_eq!(err.kind(), ErrorKind::Service);
+    assert_eq!(err.status_code(), Some(503));
+    assert!(err.is_retryable());
+
+    let err = root.probe(&id).await.expect_err("connection reset");
+    assert_eq!(err.kind(), ErrorKind::Transport);
+    assert_eq!(err.status_code(), None);
+    assert!(err.is_retryable());
+
+    bmc.expect(Expect::head(&id));
+    let other = ODataId::from(SYSTEMS_PATH.to_string());
+    let err = root.probe(&other).await.expect_err("unexpected request");
+    assert_eq!(err.odata_id(), Some(other));
+    assert!(!err.is_retryable());
+
+    Ok(())
+}
+
+async fn service_root(bmc: &Arc<Bmc>) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: "#ServiceRoot.v1_17_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+                }
+            },
+        }),
+    ));
+    Ok(ServiceRoot::new(bmc.clone()).await?)
+}
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden snapshots of wrapper views of DMTF mockup.
+
+use nv_redfish::inventory::Inventory;
+use nv_redfish::inventory::Options;
+use nv_redfish::RedfishCollection as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_tests::assert_golden;
+use nv_redfish_tests::mockup_bmc;
+use serde_json::json;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+#[test]
+async fn chassis_hardware_ids_golden() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+    let chassis = root
+        .chassis()
+        .await?
+        .expect("chassis collection")
+        .members()
+        .await?;
+    let ids = chassis.iter().map(|c| c.hardware_id()).collect::<Vec<_>>();
+    assert_golden("contoso-chassis-hardware-ids", &ids);
+    Ok(())
+}
+
+#[test]
+async fn systems_summary_golden() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+    let systems = root
+        .systems()
+        .await?
+        .expect("systems collection")
+        .members()
+        .await?;
+    let summary = systems
+        .iter()
+        .map(|system| {
+            json!({
+                "hardware_id": system.hardware_id(),
+                "power_state": system.power_state(),
+            })
+        })
+        .collect::<Vec<_>>();
+    assert_golden("contoso-systems-summary", &summary);
+    Ok(())
+}
+
+#[test]
+async fn inventory_golden() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+    let inventory = Inventory::collect(&root, &Options::default()).await?;
+    assert_golden("contoso-inventory", &inventory);
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests of inventory snapshot.
+
+use nv_redfish::inventory::Inventory;
+use nv_redfish::inventory::Kind;
+use nv_redfish::inventory::Options;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::mockup_bmc;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+// Check that inventory contains items of provided subsystems ordered
+// by identifiers.
+#[test]
+async fn inventory_collects_service_tree() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+
+    let inventory = Inventory::collect(&root, &Options::default()).await?;
+    let ids = inventory
+        .items
+        .iter()
+        .map(|item| item.odata_id.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["/redfish/v1/Chassis/1", "/redfish/v1/Systems/1"]);
+
+    let chassis = inventory
+        .items_of(Kind::Chassis)
+        .next()
+        .expect("chassis item");
+    assert_eq!(chassis.parent, None);
+    assert_eq!(chassis.model.as_deref(), Some("3500RX"));
+    assert_eq!(chassis.serial_number.as_deref(), Some("437XR1138R2"));
+
+    let system = inventory
+        .items_of(Kind::System)
+        .next()
+        .expect("system item");
+    assert_eq!(system.name, "WebFrontEnd483");
+    assert_eq!(system.manufacturer.as_deref(), Some("Contoso"));
+
+    Ok(())
+}
+
+// Check that disabled subsystems are not walked.
+#[test]
+async fn inventory_skips_disabled_subsystems() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+
+    let options = Options {
+        systems: false,
+        network: false,
+        ..Options::default()
+    };
+    let inventory = Inventory::collect(&root, &options).await?;
+    assert_eq!(inventory.items.len(), 1);
+    assert_eq!(
+        inventory.items[0].odata_id,
+        ODataId::from("/redfish/v1/Chassis/1".to_string())
+    );
+
+    Ok(())
+}
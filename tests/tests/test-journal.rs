@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for the journal of BMC exchanges.
+
+use nv_redfish::journal::FileJournal;
+use nv_redfish::journal::JournalBmc;
+use nv_redfish::journal::JournalOutcome;
+use nv_redfish::journal::MemoryJournal;
+use nv_redfish::journal::REDACTED;
+use nv_redfish::replay::ReplayBmc;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::env;
+use std::error::Error as StdError;
+use std::fs;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+
+#[test]
+async fn exchanges_are_journaled() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let journal = MemoryJournal::new();
+    let root_id = ODataId::service_root();
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "RedfishVersion": "1.21.1",
+    });
+    bmc.expect(Expect::get(&root_id, &root_json));
+    let root = ServiceRoot::new(Arc::new(JournalBmc::new(bmc.clone(), journal.clone()))).await?;
+
+    let account_id = ODataId::from("/redfish/v1/AccountService/Accounts/1".to_string());
+    let update = json!({ "Password": "secret" });
+    bmc.expect(Expect::update(
+        &account_id,
+        &update,
+        json!({ ODATA_ID: &account_id, "UserName": "admin" }),
+    ));
+    root.patch_json(&account_id, &update).await?;
+    assert!(root.get_as::<Value>(&account_id).await.is_err());
+
+    let entries = journal.take();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].method, "GET");
+    assert_eq!(entries[0].odata_id, root_id);
+    assert_eq!(entries[0].outcome, JournalOutcome::Entity(Some(root_json)));
+    assert_eq!(entries[1].method, "PATCH");
+    assert_eq!(entries[1].request, Some(json!({ "Password": REDACTED })));
+    assert!(matches!(entries[2].outcome, JournalOutcome::Error(_)));
+    Ok(())
+}
+
+#[test]
+async fn recorded_exchanges_are_replayed() -> Result<(), Box<dyn StdError>> {
+    let path = env::temp_dir().join(format!("nv-redfish-replay-{}.jsonl", std::process::id()));
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "RedfishVersion": "1.21.1",
+    });
+    let account_id = ODataId::from("/redfish/v1/AccountService/Accounts/1".to_string());
+    let update = json!({ "Enabled": false });
+    let account_json = json!({ ODATA_ID: &account_id, "UserName": "admin" });
+    bmc.expect(Expect::get(&root_id, &root_json));
+    bmc.expect(Expect::update(&account_id, &update, &account_json));
+    {
+        let journal = FileJournal::create(&path)?;
+        let root = ServiceRoot::new(Arc::new(JournalBmc::new(bmc, journal))).await?;
+        root.patch_json(&account_id, &update).await?;
+        assert!(root.get_as::<Value>(&account_id).await.is_err());
+    }
+
+    let replay = Arc::new(ReplayBmc::from_file(&path)?);
+    fs::remove_file(&path)?;
+    assert_eq!(replay.remaining(), 3);
+    let root = ServiceRoot::new(replay.clone()).await?;
+    let response = root.patch_json(&account_id, &update).await?;
+    assert!(matches!(response, ModificationResponse::Entity(body) if body == account_json));
+    assert!(root.get_as::<Value>(&account_id).await.is_err());
+    assert_eq!(replay.remaining(), 0);
+    Ok(())
+}
@@ -19,6 +19,7 @@ use nv_redfish::oem::ami::config_bmc::LockdownBiosSettingsChangeState;
 use nv_redfish::oem::ami::config_bmc::LockdownBiosUpgradeDowngradeState;
 use nv_redfish::oem::ami::config_bmc::LockoutBiosVariableWriteMode;
 use nv_redfish::oem::ami::config_bmc::LockoutHostControlState;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
@@ -15,6 +15,7 @@
 //! Integration tests for Manager DellAttributes OEM extension.
 
 use nv_redfish::manager::Manager;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
@@ -16,6 +16,7 @@
 
 use nv_redfish::manager::Manager;
 use nv_redfish::oem::supermicro::Privilege;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
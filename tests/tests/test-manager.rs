@@ -20,6 +20,7 @@ use std::sync::Arc;
 use nv_redfish::manager::Manager;
 use nv_redfish::manager::ManagerResetToDefaultsType;
 use nv_redfish::resource::ResetType;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::Resource;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ModificationResponse;
@@ -202,7 +203,9 @@ async fn ami_viking_missing_root_managers_nav_workaround() -> Result<(), Box<dyn
             ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
             "Id": "Managers",
             "Name": "Manager Collection",
-            "Members": [manager_payload(&ids)]
+            // Viking filters out managers other than BMC,
+            // HGX_BMC_0 and HGX_FabricManager_0.
+            "Members": [manager_payload_with_id(&format!("{}/BMC", ids.managers_id))]
         }),
     ));
 
@@ -298,9 +301,8 @@ async fn viking_with_garbage_in_managers() -> Result<(), Box<dyn StdError>> {
         }),
     ));
 
-    let collection = service_root.managers().await?.unwrap();
-
-    // Expect GET requests only for valid managers
+    // Expect GET requests only for valid managers, they are fetched
+    // with the collection.
     bmc.expect(Expect::get(&bmc_id, manager_payload_with_id(&bmc_id)));
     bmc.expect(Expect::get(
         &hgx_bmc_id,
@@ -310,6 +312,7 @@ async fn viking_with_garbage_in_managers() -> Result<(), Box<dyn StdError>> {
         &fabric_mgr_id,
         manager_payload_with_id(&fabric_mgr_id),
     ));
+    let collection = service_root.managers().await?.unwrap();
 
     let members = collection.members().await?;
 
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for client-side request metrics.
+
+use nv_redfish::metrics::MetricsBmc;
+use nv_redfish::metrics::RequestMetrics;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+
+#[test]
+async fn requests_are_counted() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let metrics = RequestMetrics::new();
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "Root Service",
+            "RedfishVersion": "1.21.1",
+        }),
+    ));
+    let root = ServiceRoot::new(Arc::new(
+        MetricsBmc::new(bmc.clone(), metrics.clone()).with_error_classifier(|_| "mock"),
+    ))
+    .await?;
+
+    let widget_id = ODataId::from("/redfish/v1/Oem/Vendor/Widget".to_string());
+    bmc.expect(Expect::get(
+        &widget_id,
+        json!({ ODATA_ID: &widget_id, "Name": "Widget" }),
+    ));
+    root.get_as::<Value>(&widget_id).await?;
+    assert!(root.get_as::<Value>(&widget_id).await.is_err());
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.requests["get"], 3);
+    assert_eq!(snapshot.errors["mock"], 1);
+    assert_eq!(snapshot.latencies["ServiceRoot"].count, 1);
+    Ok(())
+}
+
+// Check that latencies are measured with the provided clock.
+#[test]
+async fn latencies_are_measured_with_clock() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let metrics = RequestMetrics::new();
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "Root Service",
+            "RedfishVersion": "1.21.1",
+        }),
+    ));
+    // Every reading of the clock advances it by 20 ms.
+    let ticks = AtomicU64::new(0);
+    let clock = move || Duration::from_millis(20 * ticks.fetch_add(1, Ordering::Relaxed));
+    ServiceRoot::new(Arc::new(
+        MetricsBmc::new(bmc.clone(), metrics.clone()).with_clock(clock),
+    ))
+    .await?;
+
+    let latencies = &metrics.snapshot().latencies["ServiceRoot"];
+    assert_eq!(latencies.sum, Duration::from_millis(20));
+    assert_eq!(latencies.buckets[1], 0);
+    assert_eq!(latencies.buckets[2], 1);
+    Ok(())
+}
@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests that use service tree of DMTF mockup.
+
+use futures_util::TryStreamExt as _;
+use nv_redfish::hardware_id::Model;
+use nv_redfish::resource::PowerState;
+use nv_redfish::resource::ResourceIdRef;
+use nv_redfish::RedfishCollection;
+use nv_redfish::RefreshableResource as _;
+use nv_redfish::Resource as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::mockup_bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+#[test]
+async fn mockup_serves_service_tree() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+
+    let systems = root
+        .systems()
+        .await?
+        .expect("systems collection")
+        .members()
+        .await?;
+    assert_eq!(systems.len(), 1);
+    assert_eq!(systems[0].power_state(), Some(PowerState::On));
+
+    let chassis = root
+        .chassis()
+        .await?
+        .expect("chassis collection")
+        .members()
+        .await?;
+    assert_eq!(chassis.len(), 1);
+    assert_eq!(chassis[0].hardware_id().model, Some(Model::new("3500RX")));
+
+    Ok(())
+}
+
+#[test]
+async fn expectation_takes_precedence_over_mockup() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    let id = ODataId::from("/redfish/v1/Chassis/1".to_string());
+    bmc.expect(Expect::get(
+        &id,
+        json!({
+            ODATA_ID: &id,
+            ODATA_TYPE: "#Chassis.v1_23_0.Chassis",
+            "Id": "1",
+            "Name": "Computer System Chassis",
+            "ChassisType": "RackMount",
+            "Model": "3600RX",
+        }),
+    ));
+    let chassis = root
+        .chassis()
+        .await?
+        .expect("chassis collection")
+        .members()
+        .await?;
+    assert_eq!(chassis[0].hardware_id().model, Some(Model::new("3600RX")));
+
+    Ok(())
+}
+
+// Check that members_stream yields the same members as members.
+#[test]
+async fn members_stream_yields_members() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+
+    let collection = root.chassis().await?.expect("chassis collection");
+    let chassis = collection.members_stream().try_collect::<Vec<_>>().await?;
+    assert_eq!(chassis.len(), 1);
+    assert_eq!(chassis[0].hardware_id().model, Some(Model::new("3500RX")));
+
+    Ok(())
+}
+
+async fn first_member_id<B, C>(collection: &C) -> Result<Option<String>, nv_redfish::Error<B>>
+where
+    B: nv_redfish::Bmc,
+    C: RedfishCollection<B>,
+{
+    let Some(odata_id) = collection.odata_ids().first().copied() else {
+        return Ok(None);
+    };
+    let id = odata_id.last_segment().expect("member id");
+    let member = collection.get(ResourceIdRef::new(id)).await?;
+    Ok(member.map(|m| m.id().to_string()))
+}
+
+// Check that collections can be handled by generic code.
+#[test]
+async fn collections_are_uniform() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+
+    let chassis = root.chassis().await?.expect("chassis collection");
+    assert_eq!(RedfishCollection::len(&chassis), 1);
+    assert_eq!(first_member_id(&chassis).await?, Some("1".into()));
+    assert!(chassis.get(ResourceIdRef::new("missing")).await?.is_none());
+
+    let systems = root.systems().await?.expect("systems collection");
+    assert!(!systems.is_empty());
+    assert_eq!(first_member_id(&systems).await?, Some("1".into()));
+
+    Ok(())
+}
+
+// Check that refresh fetches the current data of the resource.
+#[test]
+async fn refresh_fetches_resource_again() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc.clone()).await?;
+    let chassis = root
+        .chassis()
+        .await?
+        .expect("chassis collection")
+        .members()
+        .await?
+        .remove(0);
+    assert_eq!(chassis.hardware_id().model, Some(Model::new("3500RX")));
+
+    let id = ODataId::from("/redfish/v1/Chassis/1".to_string());
+    bmc.expect(Expect::get(
+        &id,
+        json!({
+            ODATA_ID: &id,
+            ODATA_TYPE: "#Chassis.v1_23_0.Chassis",
+            "Id": "1",
+            "Name": "Computer System Chassis",
+            "ChassisType": "RackMount",
+            "Model": "3600RX",
+        }),
+    ));
+    let refreshed = chassis.refresh().await?;
+    assert_eq!(refreshed.hardware_id().model, Some(Model::new("3600RX")));
+    assert_eq!(chassis.hardware_id().model, Some(Model::new("3500RX")));
+
+    Ok(())
+}
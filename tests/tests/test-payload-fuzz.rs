@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deserialization and patch pipeline of generated types checked
+//! with randomized schema-shaped payloads (generated by build script).
+
+use nv_redfish_core::merge_patch;
+use nv_redfish_tests::base::redfish::service_root::ServiceRoot;
+use nv_redfish_tests::base::redfish::service_root::ServiceRootUpdate;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+const PAYLOADS: &str = include_str!(concat!(env!("OUT_DIR"), "/base_payloads.json"));
+
+#[derive(Deserialize)]
+struct Payload {
+    payload: Value,
+    markers: Vec<String>,
+}
+
+fn service_root_payloads() -> Vec<Payload> {
+    let mut payloads: BTreeMap<String, Vec<Payload>> =
+        serde_json::from_str(PAYLOADS).expect("valid payloads");
+    let payloads = payloads
+        .remove("ServiceRoot.ServiceRoot")
+        .expect("service root payloads");
+    assert!(!payloads.is_empty());
+    payloads
+}
+
+// Check that every generated payload is deserialized and no string
+// value is silently dropped.
+#[test]
+fn fuzz_deserialize_keeps_data() {
+    for Payload { payload, markers } in service_root_payloads() {
+        let root = serde_json::from_value::<ServiceRoot>(payload.clone())
+            .unwrap_or_else(|err| panic!("failed to deserialize {payload}: {err}"));
+        let debug = format!("{root:?}");
+        for marker in markers {
+            assert!(
+                debug.contains(&marker),
+                "{marker} is lost in deserialization of {payload}"
+            );
+        }
+    }
+}
+
+// Check that writable properties of every generated payload are
+// read into update, survive serialization round trip and produce
+// no patch when unchanged.
+#[test]
+fn fuzz_patch_pipeline() {
+    for Payload { payload, .. } in service_root_payloads() {
+        let current = merge_patch::writable::<ServiceRootUpdate>(&payload)
+            .unwrap_or_else(|err| panic!("failed to read writable of {payload}: {err}"));
+        let json = serde_json::to_value(&current).expect("serializable update");
+        let restored = serde_json::from_value::<ServiceRootUpdate>(json.clone())
+            .unwrap_or_else(|err| panic!("failed to restore update {json}: {err}"));
+        assert_eq!(
+            serde_json::to_value(&restored).expect("serializable update"),
+            json
+        );
+        assert!(merge_patch::update_diff(&current, &restored)
+            .expect("valid update")
+            .is_none());
+    }
+}
@@ -16,6 +16,7 @@
 //! Integration tests for standard PowerEquipment and PowerShelves.
 
 use nv_redfish::power_equipment::PowerEquipmentType;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::Resource as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of common imports.
+
+use nv_redfish::prelude::*;
+use nv_redfish_tests::mockup_bmc;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+// Check that wrappers are used with prelude imports only.
+#[test]
+async fn prelude_reads_service_tree() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(mockup_bmc("contoso")?);
+    let root = ServiceRoot::new(bmc).await?;
+    let chassis: Vec<Chassis<_>> = root
+        .chassis()
+        .await?
+        .expect("chassis collection")
+        .members()
+        .await?;
+    let id: HardwareId<_> = chassis[0].hardware_id().cloned();
+    assert_eq!(id.model, Some(Model::new("3500RX".to_string())));
+    assert_eq!(
+        chassis[0].odata_id(),
+        &ODataId::from("/redfish/v1/Chassis/1".to_string())
+    );
+    Ok(())
+}
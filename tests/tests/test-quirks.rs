@@ -0,0 +1,391 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for overriding platform classification.
+
+use nv_redfish::quirks::Platform;
+use nv_redfish::quirks::Quirk;
+use nv_redfish::quirks::QuirkRegistry;
+use nv_redfish::type_check::TypeCheckError;
+use nv_redfish::Error;
+use nv_redfish::RedfishCollection as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::PageQuery;
+use nv_redfish_core::UpdateMethod;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+
+#[test]
+async fn registered_platform_is_applied() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let mut registry = QuirkRegistry::default();
+    registry.register(Platform::new(
+        "Contoso",
+        |info| info.vendor == Some("Contoso"),
+        [Quirk::HeadIsNotSupported],
+    ));
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        root_payload("Contoso"),
+    ));
+    let root = ServiceRoot::with_quirk_registry(bmc.clone(), &registry).await?;
+    expect_probe_with_get(&bmc, &root).await
+}
+
+#[test]
+async fn forced_platform_is_applied() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        root_payload("Contoso"),
+    ));
+    let root = ServiceRoot::builder(bmc.clone())
+        .force_platform("AmiViking")
+        .build()
+        .await?;
+    expect_probe_with_get(&bmc, &root).await
+}
+
+#[test]
+async fn enabled_and_disabled_quirks_override_platform() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        root_payload("Contoso"),
+    ));
+    let root = ServiceRoot::builder(bmc.clone())
+        .enable_quirk(Quirk::HeadIsNotSupported)
+        .build()
+        .await?;
+    expect_probe_with_get(&bmc, &root).await?;
+
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        root_payload("Contoso"),
+    ));
+    let root = ServiceRoot::builder(bmc.clone())
+        .force_platform("AmiViking")
+        .disable_quirk(Quirk::HeadIsNotSupported)
+        .build()
+        .await?;
+    let id = ODataId::from("/redfish/v1/Oem/Contoso".to_string());
+    bmc.expect(Expect::head(&id));
+    root.probe(&id).await?;
+
+    Ok(())
+}
+
+#[test]
+async fn strict_builder_ignores_platform_quirks() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(ODataId::service_root(), root_payload("AMI")));
+    let root = ServiceRoot::builder(bmc.clone())
+        .force_platform("AmiViking")
+        .strict()
+        .member_concurrency(2)
+        .build()
+        .await?;
+    let capabilities = root.capabilities().await?;
+    assert!(capabilities.head);
+    assert!(capabilities.client_side_expand);
+
+    let id = ODataId::from("/redfish/v1/Oem/Contoso".to_string());
+    bmc.expect(Expect::head(&id));
+    root.probe(&id).await?;
+
+    Ok(())
+}
+
+#[test]
+async fn strict_builder_rejects_resources_of_other_types() {
+    let bmc = Arc::new(Bmc::default());
+    let mut payload = root_payload("Contoso");
+    payload[ODATA_TYPE] = "#ComputerSystem.v1_20_0.ComputerSystem".into();
+    bmc.expect(Expect::get(ODataId::service_root(), payload));
+    let result = ServiceRoot::builder(bmc).strict().build().await;
+    assert!(matches!(
+        result,
+        Err(Error::Bmc(TypeCheckError::Mismatch(mismatch)))
+            if mismatch.expected.contains(&"ServiceRoot")
+    ));
+}
+
+#[test]
+async fn reloaded_registry_is_applied() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        root_payload("Contoso"),
+    ));
+    let root = ServiceRoot::with_quirk_registry(bmc.clone(), &QuirkRegistry::new()).await?;
+    let handle = root.clone();
+    let id = ODataId::from("/redfish/v1/Oem/Contoso".to_string());
+    bmc.expect(Expect::head(&id));
+    handle.probe(&id).await?;
+
+    let mut registry = QuirkRegistry::new();
+    registry.register(Platform::new(
+        "Contoso",
+        |info| info.vendor == Some("Contoso"),
+        [Quirk::HeadIsNotSupported],
+    ));
+    root.reload_quirk_registry(&registry)?;
+    expect_probe_with_get(&bmc, &handle).await
+}
+
+#[test]
+async fn scoped_quirks_apply_to_subtree() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        root_payload("Contoso"),
+    ));
+    let root = ServiceRoot::with_quirk_registry(bmc.clone(), &QuirkRegistry::new()).await?;
+    let downstream = Platform::new("Downstream", |_| false, [Quirk::HeadIsNotSupported]);
+    root.scope_quirks(
+        ODataId::from("/redfish/v1/Oem".to_string()),
+        Some(&downstream),
+    );
+    expect_probe_with_get(&bmc, &root).await?;
+
+    let id = ODataId::from("/redfish/v1/Chassis".to_string());
+    bmc.expect(Expect::head(&id));
+    root.probe(&id).await?;
+
+    Ok(())
+}
+
+#[test]
+async fn aggregated_quirks_apply_to_managed_resources() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let managers_id = format!("{root_id}/Managers");
+    let manager_id = format!("{managers_id}/Downstream");
+    let chassis_id = format!("{root_id}/Chassis/Downstream");
+    let mut root = root_payload("Contoso");
+    root["Managers"] = json!({ ODATA_ID: &managers_id });
+    bmc.expect(Expect::get(&root_id, root.clone()));
+    let root_service = ServiceRoot::with_quirk_registry(bmc.clone(), &QuirkRegistry::new()).await?;
+
+    let mut registry = QuirkRegistry::new();
+    registry.register(Platform::new(
+        "Downstream",
+        |info| info.vendor == Some("Downstream"),
+        [Quirk::HeadIsNotSupported],
+    ));
+    bmc.expect(Expect::get(&root_id, root));
+    bmc.expect(Expect::get(
+        &managers_id,
+        json!({
+            ODATA_ID: &managers_id,
+            ODATA_TYPE: "#ManagerCollection.ManagerCollection",
+            "Name": "Manager Collection",
+            "Members": [{ ODATA_ID: &manager_id }],
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &manager_id,
+        json!({
+            ODATA_ID: &manager_id,
+            ODATA_TYPE: "#Manager.v1_14_0.Manager",
+            "Id": "Downstream",
+            "Name": "Downstream Manager",
+            "Manufacturer": "Downstream",
+            "Links": {
+                "ManagerForChassis": [{ ODATA_ID: &chassis_id }],
+            },
+        }),
+    ));
+    root_service.scope_aggregated_quirks(&registry).await?;
+
+    let id = ODataId::from(format!("{chassis_id}/Sensors"));
+    bmc.expect(Expect::get(
+        &id,
+        json!({
+            ODATA_ID: &id,
+            "Id": "Sensors",
+        }),
+    ));
+    root_service.probe(&id).await?;
+
+    let id = ODataId::from(format!("{root_id}/Chassis"));
+    bmc.expect(Expect::head(&id));
+    root_service.probe(&id).await?;
+
+    Ok(())
+}
+
+#[test]
+async fn unknown_forced_platform_is_error() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        root_payload("Contoso"),
+    ));
+    let result = ServiceRoot::builder(bmc)
+        .force_platform("Unknown")
+        .build()
+        .await;
+    assert!(matches!(result, Err(Error::UnknownPlatform(name)) if name == "Unknown"));
+
+    Ok(())
+}
+
+#[test]
+async fn members_count_mismatch_fetches_missing_members() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let collection_id = format!("{root_id}/Chassis");
+    let mut root = root_payload("Contoso");
+    root["Chassis"] = json!({ ODATA_ID: &collection_id });
+    bmc.expect(Expect::get(&root_id, root));
+    // Members are fetched after missing pages, not with the collection.
+    let root = ServiceRoot::builder(bmc.clone())
+        .enable_quirk(Quirk::MembersCountMismatch)
+        .disable_client_side_expand()
+        .build()
+        .await?;
+
+    let members = |ids: &[&str]| {
+        ids.iter()
+            .map(|id| json!({ ODATA_ID: format!("{collection_id}/{id}") }))
+            .collect::<Vec<_>>()
+    };
+    bmc.expect(Expect::get(
+        &collection_id,
+        json!({
+            ODATA_ID: &collection_id,
+            ODATA_TYPE: "#ChassisCollection.ChassisCollection",
+            "Name": "Chassis Collection",
+            "Members": members(&["1"]),
+            "Members@odata.count": 3,
+        }),
+    ));
+    // BMC ignores `$skip`; members that are already known are dropped.
+    bmc.expect(Expect::page(
+        &collection_id,
+        &PageQuery::top(2).skip(1),
+        json!({
+            ODATA_ID: &collection_id,
+            ODATA_TYPE: "#ChassisCollection.ChassisCollection",
+            "Name": "Chassis Collection",
+            "Members": members(&["1", "2"]),
+            "Members@odata.count": 3,
+        }),
+    ));
+    bmc.expect(Expect::page(
+        &collection_id,
+        &PageQuery::top(1).skip(2),
+        json!({
+            ODATA_ID: &collection_id,
+            ODATA_TYPE: "#ChassisCollection.ChassisCollection",
+            "Name": "Chassis Collection",
+            "Members": members(&["3"]),
+            "Members@odata.count": 3,
+        }),
+    ));
+    let collection = root.chassis().await?.expect("chassis collection");
+
+    for id in ["1", "2", "3"] {
+        let chassis_id = format!("{collection_id}/{id}");
+        bmc.expect(Expect::get(
+            &chassis_id,
+            json!({
+                ODATA_ID: &chassis_id,
+                ODATA_TYPE: "#Chassis.v1_23_0.Chassis",
+                "Id": id,
+                "Name": "Chassis",
+                "ChassisType": "RackMount",
+            }),
+        ));
+    }
+    let chassis = collection.members().await?;
+    assert_eq!(chassis.len(), 3);
+
+    Ok(())
+}
+
+#[test]
+async fn patch_quirks_select_update_method() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let id = ODataId::from("/redfish/v1/Oem/Contoso".to_string());
+    let update = json!({ "Enabled": true });
+    for (quirk, method) in [
+        (Quirk::PatchIsNotSupported, UpdateMethod::Post),
+        (
+            Quirk::PatchRequiresMethodOverride,
+            UpdateMethod::PostWithMethodOverride,
+        ),
+    ] {
+        bmc.expect(Expect::get(
+            ODataId::service_root(),
+            root_payload("Contoso"),
+        ));
+        let root = ServiceRoot::builder(bmc.clone())
+            .enable_quirk(quirk)
+            .build()
+            .await?;
+        bmc.expect(Expect::update_with_method_empty(&id, method, &update));
+        assert!(matches!(
+            root.patch_json(&id, &update).await?,
+            ModificationResponse::Empty
+        ));
+    }
+
+    Ok(())
+}
+
+async fn expect_probe_with_get(
+    bmc: &Arc<Bmc>,
+    root: &ServiceRoot<Bmc>,
+) -> Result<(), Box<dyn StdError>> {
+    let id = ODataId::from("/redfish/v1/Oem/Contoso".to_string());
+    bmc.expect(Expect::get(
+        &id,
+        json!({
+            ODATA_ID: &id,
+            "Id": "Contoso",
+        }),
+    ));
+    root.probe(&id).await?;
+    Ok(())
+}
+
+fn root_payload(vendor: &str) -> Value {
+    let root_id = ODataId::service_root();
+    json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "RedfishVersion": "1.21.1",
+        "Vendor": vendor,
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+            }
+        },
+    })
+}
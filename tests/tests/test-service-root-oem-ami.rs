@@ -86,6 +86,40 @@ async fn service_root_ami_malformed_oem_returns_parse_error() -> Result<(), Box<
     Ok(())
 }
 
+#[test]
+async fn probe_uses_head() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone(), root_payload(None)).await?;
+    let oem_id = ODataId::from("/redfish/v1/Oem/Ami".to_string());
+
+    bmc.expect(Expect::head(&oem_id));
+    root.probe(&oem_id).await?;
+
+    Ok(())
+}
+
+#[test]
+async fn probe_falls_back_to_get_on_viking() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(
+        bmc.clone(),
+        json_merge([&root_payload(None), &json!({ "RedfishVersion": "1.11.0" })]),
+    )
+    .await?;
+    let oem_id = ODataId::from("/redfish/v1/Oem/Ami".to_string());
+
+    bmc.expect(Expect::get(
+        &oem_id,
+        json!({
+            ODATA_ID: &oem_id,
+            "Id": "Ami",
+        }),
+    ));
+    root.probe(&oem_id).await?;
+
+    Ok(())
+}
+
 async fn get_root(bmc: Arc<Bmc>, payload: Value) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
     bmc.expect(Expect::get(ODataId::service_root(), payload));
     ServiceRoot::new(bmc).await.map_err(Into::into)
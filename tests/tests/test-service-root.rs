@@ -0,0 +1,262 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for raw access to resources through ServiceRoot.
+
+use nv_redfish::quirks::Quirk;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::SelectQuery;
+use nv_redfish_core::UpdateMethod;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde::Deserialize;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Widget {
+    name: String,
+    speed: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SelectedPower {
+    power_state: String,
+}
+
+#[test]
+async fn get_as_deserializes_unmodeled_resource() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone()).await?;
+    let widget_id = ODataId::from("/redfish/v1/Oem/Vendor/Widget".to_string());
+
+    bmc.expect(Expect::get(
+        &widget_id,
+        json!({
+            ODATA_ID: &widget_id,
+            ODATA_TYPE: "#Widget.v1_0_0.Widget",
+            "Name": "Widget",
+            "Speed": 42,
+        }),
+    ));
+    let widget = root.get_as::<Widget>(&widget_id).await?;
+    assert_eq!(widget.name, "Widget");
+    assert_eq!(widget.speed, 42);
+
+    Ok(())
+}
+
+#[test]
+async fn get_as_reports_json_error() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone()).await?;
+    let widget_id = ODataId::from("/redfish/v1/Oem/Vendor/Widget".to_string());
+
+    bmc.expect(Expect::get(
+        &widget_id,
+        json!({
+            ODATA_ID: &widget_id,
+            "Name": "Widget",
+            "Speed": "fast",
+        }),
+    ));
+    let err = root
+        .get_as::<Widget>(&widget_id)
+        .await
+        .err()
+        .expect("payload must not be parsed");
+    assert!(
+        err.to_string().contains("JSON error"),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn get_with_select_returns_selected_properties() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone()).await?;
+    let system_id = ODataId::from("/redfish/v1/Systems/1".to_string());
+    let query = SelectQuery::property(&"PowerState");
+    let selected = json!({
+        ODATA_ID: &system_id,
+        "PowerState": "On",
+    });
+
+    bmc.expect(Expect::select(&system_id, &query, selected.clone()));
+    let value = root
+        .get_with_select::<Value>(&system_id, query.clone())
+        .await?;
+    assert_eq!(value, selected);
+    bmc.expect(Expect::select(&system_id, &query, selected));
+    let power = root
+        .get_with_select::<SelectedPower>(&system_id, query)
+        .await?;
+    assert_eq!(power.power_state, "On");
+    bmc.verify();
+
+    Ok(())
+}
+
+#[test]
+async fn patch_and_post_json() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone()).await?;
+    let widget_id = ODataId::from("/redfish/v1/Oem/Vendor/Widget".to_string());
+    let patch = json!({ "Speed": 10 });
+    let updated = json!({ ODATA_ID: &widget_id, "Name": "Widget", "Speed": 10 });
+
+    bmc.expect(Expect::update(&widget_id, &patch, &updated));
+    match root.patch_json(&widget_id, &patch).await? {
+        ModificationResponse::Entity(v) => assert_eq!(v, updated),
+        _ => panic!("expected updated entity"),
+    }
+
+    let action_id = ODataId::from(format!("{widget_id}/Actions/Widget.Spin"));
+    let request = json!({ "Direction": "Forward" });
+    bmc.expect(Expect::create_empty(&action_id, &request));
+    assert!(matches!(
+        root.post_json(&action_id, &request).await?,
+        ModificationResponse::Empty
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn capabilities_combine_features_quirks_and_services() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let update_service_id = format!("{root_id}/UpdateService");
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "Root Service",
+            "RedfishVersion": "1.21.1",
+            "ProtocolFeaturesSupported": {
+                "ExpandQuery": {
+                    "NoLinks": true,
+                    "Levels": true,
+                    "MaxLevels": 3
+                },
+                "SelectQuery": true
+            },
+            "UpdateService": { ODATA_ID: &update_service_id },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+                }
+            },
+        }),
+    ));
+    let root = ServiceRoot::builder(bmc.clone())
+        .enable_quirk(Quirk::HeadIsNotSupported)
+        .build()
+        .await?;
+
+    bmc.expect(Expect::get(
+        &update_service_id,
+        json!({
+            ODATA_ID: &update_service_id,
+            ODATA_TYPE: "#UpdateService.v1_9_0.UpdateService",
+            "Id": "UpdateService",
+            "Name": "Update Service",
+            "MultipartHttpPushUri": "/redfish/v1/UpdateService/upload",
+        }),
+    ));
+    let capabilities = root.capabilities().await?;
+    assert!(capabilities.expand);
+    assert!(capabilities.expand_levels);
+    assert_eq!(capabilities.max_expand_levels, Some(3));
+    assert!(capabilities.client_side_expand);
+    assert!(capabilities.select);
+    assert!(!capabilities.filter);
+    assert!(!capabilities.top_skip);
+    assert!(!capabilities.head);
+    assert_eq!(capabilities.update_method, UpdateMethod::Patch);
+    assert!(!capabilities.sessions);
+    assert!(capabilities.multipart_update);
+    assert!(!capabilities.sse);
+
+    Ok(())
+}
+
+#[test]
+async fn builder_caches_and_limits_requests() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(ODataId::service_root(), root_payload()));
+    let root = ServiceRoot::builder(bmc.clone())
+        .cache(16)
+        .max_concurrent_requests(2)
+        .build()
+        .await?;
+    let widget_id = ODataId::from("/redfish/v1/Oem/Vendor/Widget".to_string());
+    let widget = |speed| {
+        Expect::get(
+            &widget_id,
+            json!({
+                ODATA_ID: &widget_id,
+                ODATA_TYPE: "#Widget.v1_0_0.Widget",
+                "Name": "Widget",
+                "Speed": speed,
+            }),
+        )
+        .with_etag("\"1\"")
+    };
+
+    bmc.expect(widget(42));
+    assert_eq!(root.get_as::<Widget>(&widget_id).await?.speed, 42);
+    // The resource is not modified according to its ETag, so the
+    // cached payload is used.
+    bmc.expect(widget(43));
+    assert_eq!(root.get_as::<Widget>(&widget_id).await?.speed, 42);
+
+    Ok(())
+}
+
+async fn get_root(bmc: Arc<Bmc>) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(ODataId::service_root(), root_payload()));
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+fn root_payload() -> Value {
+    let root_id = ODataId::service_root();
+    json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "RedfishVersion": "1.21.1",
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+            }
+        },
+    })
+}
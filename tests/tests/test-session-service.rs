@@ -16,13 +16,22 @@
 //! Integration tests of Session Service.
 
 use std::error::Error as StdError;
+use std::future::ready;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
 
 use nv_redfish::session_service::SessionCollection;
 use nv_redfish::session_service::SessionCreate;
 use nv_redfish::session_service::SessionService;
 use nv_redfish::session_service::SessionTypes;
+use nv_redfish::task_service::TaskFollowing;
+use nv_redfish::Error;
+use nv_redfish::RedfishCollection as _;
 use nv_redfish::ServiceRoot;
+use nv_redfish::Sleep;
+use nv_redfish::SupportedMethods as _;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::assert_empty;
@@ -30,6 +39,8 @@ use nv_redfish_tests::assert_task;
 use nv_redfish_tests::async_task;
 use nv_redfish_tests::Bmc;
 use nv_redfish_tests::Expect;
+use nv_redfish_tests::ResponseStatus;
+use nv_redfish_tests::TaskMonitor;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 
@@ -187,9 +198,176 @@ async fn delete_session_preserves_async_task() -> Result<(), Box<dyn StdError>>
     Ok(())
 }
 
+#[test]
+async fn delete_session_with_status() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let session_service = get_session_service(bmc.clone(), &root_id).await?;
+    let session_id = format!(
+        "{}/Sessions/1234567890ABCDEF",
+        session_service.raw().odata_id()
+    );
+    let sessions = get_session_collection(
+        bmc.clone(),
+        &session_service,
+        json!([{
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "1234567890ABCDEF",
+            "Name": "User Session",
+            "UserName": "Administrator",
+            "SessionType": "ManagerConsole"
+        }]),
+    )
+    .await?;
+
+    let session = sessions.members().await?.into_iter().next().unwrap();
+    let task_id = "/redfish/v1/TaskService/Tasks/52";
+
+    bmc.expect(
+        Expect::delete(&session_id).with_status(ResponseStatus::Accepted(async_task(task_id, 6))),
+    );
+    assert_task(session.delete().await?, task_id, 6);
+
+    bmc.expect(Expect::delete(&session_id).with_status(ResponseStatus::Ok));
+    assert_empty(session.delete().await?);
+
+    Ok(())
+}
+
+#[test]
+async fn delete_session_follows_task_until_completed() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let recorded = delays.clone();
+    let following = TaskFollowing::new(Sleep::new(move |delay| {
+        recorded.lock().expect("not poisoned").push(delay);
+        ready(())
+    }))
+    .poll_interval(Duration::from_secs(2));
+    let session_service =
+        get_session_service_with(bmc.clone(), &root_id, |root| root.follow_tasks(following))
+            .await?;
+    let session_id = format!(
+        "{}/Sessions/1234567890ABCDEF",
+        session_service.raw().odata_id()
+    );
+    let sessions = get_session_collection(
+        bmc.clone(),
+        &session_service,
+        json!([{
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "1234567890ABCDEF",
+            "Name": "User Session",
+            "UserName": "Administrator",
+            "SessionType": "ManagerConsole"
+        }]),
+    )
+    .await?;
+    let session = sessions.members().await?.into_iter().next().unwrap();
+
+    let task = TaskMonitor::new("/redfish/v1/TaskService/Tasks/51")
+        .retry_after(Duration::from_secs(6))
+        .running(10)
+        .running(60)
+        .completed();
+    bmc.expect(Expect::delete_task(&session_id, task.task()));
+    bmc.expect_all(task.polls());
+
+    assert_empty(session.delete().await?);
+    assert_eq!(
+        *delays.lock().expect("not poisoned"),
+        [6, 2, 2].map(Duration::from_secs)
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn session_supported_methods() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let session_service = get_session_service(bmc.clone(), &root_id).await?;
+    let session_id = format!(
+        "{}/Sessions/1234567890ABCDEF",
+        session_service.raw().odata_id()
+    );
+    let sessions = get_session_collection(
+        bmc.clone(),
+        &session_service,
+        json!([{
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "1234567890ABCDEF",
+            "Name": "User Session",
+            "UserName": "Administrator",
+            "SessionType": "ManagerConsole"
+        }]),
+    )
+    .await?;
+
+    let session = sessions.members().await?.into_iter().next().unwrap();
+    assert!(session.supported_methods().is_none());
+
+    // Allowed methods are reported with the resource, no request is
+    // sent.
+    bmc.allow(&session_id, "GET, HEAD, DELETE");
+    let allowed = session.supported_methods().unwrap();
+    assert!(allowed.contains(AllowedMethods::DELETE));
+    assert!(!allowed.contains(AllowedMethods::PATCH));
+    bmc.verify();
+
+    Ok(())
+}
+
+#[test]
+async fn delete_session_not_allowed() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let session_service = get_session_service(bmc.clone(), &root_id).await?;
+    let session_id = format!(
+        "{}/Sessions/1234567890ABCDEF",
+        session_service.raw().odata_id()
+    );
+    let sessions = get_session_collection(
+        bmc.clone(),
+        &session_service,
+        json!([{
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "1234567890ABCDEF",
+            "Name": "User Session",
+            "UserName": "Administrator",
+            "SessionType": "ManagerConsole"
+        }]),
+    )
+    .await?;
+
+    let session = sessions.members().await?.into_iter().next().unwrap();
+    // DELETE is not sent when the Allow header doesn't list it.
+    bmc.allow(&session_id, "GET, HEAD");
+    assert!(matches!(
+        session.delete().await,
+        Err(Error::DeleteNotAllowed(id)) if id.to_string() == session_id
+    ));
+    bmc.verify();
+
+    Ok(())
+}
+
 async fn get_session_service(
     bmc: Arc<Bmc>,
     root_id: &ODataId,
+) -> Result<SessionService<Bmc>, Box<dyn StdError>> {
+    get_session_service_with(bmc, root_id, |root| root).await
+}
+
+async fn get_session_service_with(
+    bmc: Arc<Bmc>,
+    root_id: &ODataId,
+    f: impl FnOnce(ServiceRoot<Bmc>) -> ServiceRoot<Bmc>,
 ) -> Result<SessionService<Bmc>, Box<dyn StdError>> {
     let session_service_id = format!("{root_id}/SessionService");
     let sessions_id = format!("{session_service_id}/Sessions");
@@ -215,7 +393,7 @@ async fn get_session_service(
             },
         }),
     ));
-    let service_root = ServiceRoot::new(bmc.clone()).await?;
+    let service_root = f(ServiceRoot::new(bmc.clone()).await?);
 
     bmc.expect(Expect::get(
         &session_service_id,
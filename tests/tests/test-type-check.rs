@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for `@odata.type` validation.
+
+use nv_redfish::type_check::TypeCheckBmc;
+use nv_redfish::type_check::TypeCheckError;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+const SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_20_0.ComputerSystem";
+
+fn service_root(odata_type: &str) -> Value {
+    json!({
+        ODATA_ID: ODataId::service_root(),
+        ODATA_TYPE: odata_type,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "RedfishVersion": "1.21.1",
+    })
+}
+
+#[test]
+async fn expected_type_is_accepted() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        service_root(SERVICE_ROOT_DATA_TYPE),
+    ));
+    let root = ServiceRoot::new(Arc::new(TypeCheckBmc::new(bmc))).await?;
+    assert!(root.redfish_version().is_some());
+    Ok(())
+}
+
+#[test]
+async fn unexpected_type_is_reported() {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        ODataId::service_root(),
+        service_root(SYSTEM_DATA_TYPE),
+    ));
+    let result = ServiceRoot::new(Arc::new(TypeCheckBmc::new(bmc))).await;
+    assert!(matches!(
+        result,
+        Err(Error::Bmc(TypeCheckError::Mismatch(mismatch)))
+            if mismatch.received.as_deref() == Some(SYSTEM_DATA_TYPE)
+                && mismatch.expected.contains(&"ServiceRoot")
+    ));
+}
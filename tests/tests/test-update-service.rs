@@ -37,6 +37,7 @@ use nv_redfish_core::OemMultipartPart;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::UploadStream;
 use nv_redfish_tests::ami_viking_service_root;
+use nv_redfish_tests::scenario_bmc;
 use nv_redfish_tests::Bmc;
 use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
@@ -282,6 +283,47 @@ async fn uses_multipart_http_push_uri() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+// Firmware update orchestration described by scenario: inventory
+// reports new version after the update.
+#[tokio::test]
+async fn firmware_update_scenario() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(scenario_bmc("firmware-update")?);
+    let bmc_fw_id = "/redfish/v1/UpdateService/FirmwareInventory/BMC";
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let update_service = root
+        .update_service()
+        .await?
+        .ok_or("expected update service")?;
+    let inventories = update_service
+        .firmware_inventories()
+        .await?
+        .ok_or("expected firmware inventory")?;
+    assert_eq!(inventories[0].raw().version, Some(Some("1.0.0".into())));
+
+    let parameters = MultipartUpdateParameters::builder()
+        .with_force_update(true)
+        .with_targets(vec![bmc_fw_id.to_string()])
+        .build();
+    update_service
+        .multipart_update_from_reader::<_, _, serde_json::Value>(
+            &parameters,
+            DataStream::new("bmc-2.0.0.bin", Cursor::new(b"firmware".to_vec()))
+                .with_content_length(8),
+            Duration::from_secs(600),
+        )
+        .await?;
+
+    let inventories = update_service
+        .firmware_inventories()
+        .await?
+        .ok_or("expected firmware inventory")?;
+    assert_eq!(inventories[0].raw().version, Some(Some("2.0.0".into())));
+    bmc.verify();
+
+    Ok(())
+}
+
 #[cfg(feature = "update-service-deprecated")]
 #[tokio::test]
 async fn uses_http_push_uri_without_update_parameters() -> Result<(), Box<dyn StdError>> {
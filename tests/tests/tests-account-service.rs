@@ -15,7 +15,9 @@
 
 //! Integration tests of Account Service.
 
+use std::convert::identity;
 use std::error::Error as StdError;
+use std::future::ready;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -24,7 +26,11 @@ use nv_redfish::account::AccountService;
 use nv_redfish::account::AccountTypes;
 use nv_redfish::account::ManagerAccountCreate;
 use nv_redfish::account::ManagerAccountUpdate;
+use nv_redfish::account::Privilege;
+use nv_redfish::account::RoleId;
+use nv_redfish::task_service::TaskFollowing;
 use nv_redfish::ServiceRoot;
+use nv_redfish::Sleep;
 use nv_redfish_core::AsyncTask;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::ModificationResponse;
@@ -180,6 +186,16 @@ async fn get_account_service(
     bmc: Arc<Bmc>,
     root_id: &ODataId,
     vendor: &str,
+) -> Result<AccountService<Bmc>, Box<dyn StdError>> {
+    get_account_service_with(bmc, root_id, vendor, json!({}), identity).await
+}
+
+async fn get_account_service_with(
+    bmc: Arc<Bmc>,
+    root_id: &ODataId,
+    vendor: &str,
+    service_fields: JsonValue,
+    configure: impl FnOnce(ServiceRoot<Bmc>) -> ServiceRoot<Bmc>,
 ) -> Result<AccountService<Bmc>, Box<dyn StdError>> {
     let account_service_id = format!("{root_id}/AccountService");
     let data_type = "#ServiceRoot.v1_13_0.ServiceRoot";
@@ -207,20 +223,23 @@ async fn get_account_service(
             },
         }),
     ));
-    let service_root = ServiceRoot::new(bmc.clone()).await?;
+    let service_root = configure(ServiceRoot::new(bmc.clone()).await?);
 
     let accounts_id = format!("{account_service_id}/Accounts");
     bmc.expect(Expect::get(
         &account_service_id,
-        json!({
-            ODATA_ID: &account_service_id,
-            ODATA_TYPE: &ACCOUNT_SERVICE_DATA_TYPE,
-            "Id": "AccountService",
-            "Name": "AccountService",
-            "Accounts": {
-                ODATA_ID: &accounts_id,
-            },
-        }),
+        json_merge([
+            &json!({
+                ODATA_ID: &account_service_id,
+                ODATA_TYPE: &ACCOUNT_SERVICE_DATA_TYPE,
+                "Id": "AccountService",
+                "Name": "AccountService",
+                "Accounts": {
+                    ODATA_ID: &accounts_id,
+                },
+            }),
+            &service_fields,
+        ]),
     ));
     Ok(service_root.account_service().await?.unwrap())
 }
@@ -439,6 +458,128 @@ async fn create_account_dell_slot_defined_first_available() -> TestResult<()> {
     Ok(())
 }
 
+// Check that standard roles and privileges are parsed and custom
+// values are preserved.
+#[test]
+async fn role_ids_and_privileges() -> TestResult<()> {
+    assert_eq!(RoleId::from("ReadOnly"), RoleId::ReadOnly);
+    assert_eq!(
+        RoleId::from("OemRole"),
+        RoleId::Other("OemRole".to_string())
+    );
+    assert_eq!(
+        serde_json::from_value::<RoleId>(json!("Administrator"))?,
+        RoleId::Administrator
+    );
+    assert_eq!(
+        serde_json::to_value(RoleId::Other("OemRole".into()))?,
+        json!("OemRole")
+    );
+    assert_eq!(
+        RoleId::ReadOnly.standard_privileges(),
+        Some(&[Privilege::Login, Privilege::ConfigureSelf][..])
+    );
+    assert_eq!(RoleId::from("OemRole").standard_privileges(), None);
+    assert_eq!(
+        serde_json::from_value::<Vec<Privilege>>(json!(["ConfigureUsers", "OemPrivilege"]))?,
+        [
+            Privilege::ConfigureUsers,
+            Privilege::Other("OemPrivilege".into())
+        ]
+    );
+
+    Ok(())
+}
+
+// Create account with builder: password length is checked against
+// the policy of the account service before the request is sent.
+#[test]
+async fn create_builder_validates_password_policy() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service_with(
+        bmc.clone(),
+        &root_id,
+        "Contoso",
+        json!({ "MinPasswordLength": 8, "MaxPasswordLength": 12 }),
+        identity,
+    )
+    .await?;
+    let accounts = get_account_collection(bmc.clone(), &account_service, json!([])).await?;
+    let accounts_id = accounts.odata_id().to_string();
+
+    for password in ["short", "much-too-long-password"] {
+        let result = accounts
+            .create_builder()
+            .user_name("user")
+            .password(password)
+            .role("Operator")
+            .create()
+            .await;
+        assert!(matches!(result, Err(nv_redfish::Error::InvalidAccount(_))));
+    }
+
+    let create_req =
+        ManagerAccountCreate::builder("password".into(), "user".into(), "Operator".into())
+            .with_account_types(vec![AccountTypes::Redfish])
+            .build();
+    bmc.expect(Expect::create(
+        &accounts_id,
+        serde_json::to_value(&create_req).unwrap(),
+        json!({
+            ODATA_ID: format!("{accounts_id}/1"),
+            ODATA_TYPE: MANAGER_ACCOUNT_DATA_TYPE,
+            "Id": "1",
+            "Name": "User Account",
+            "UserName": "user",
+            "RoleId": "Operator",
+            "AccountTypes": ["Redfish"]
+        }),
+    ));
+    let account = accounts
+        .create_builder()
+        .user_name("user")
+        .password("password")
+        .role(RoleId::Operator)
+        .account_types(vec![AccountTypes::Redfish])
+        .create()
+        .await?;
+    let account = into_entity(account);
+    assert_eq!(account.raw().user_name, Some("user".into()));
+    assert_eq!(account.role(), Some(RoleId::Operator));
+
+    Ok(())
+}
+
+// Create account with builder (Dell slot-defined): required properties
+// must be set and slots cannot be taken as disabled accounts.
+#[test]
+async fn create_builder_validates_slot_defined_accounts() -> TestResult<()> {
+    let (_bmc, _accounts_id, accounts) =
+        account_fixture("Dell", &[(1, true, "root"), (3, false, "")]).await?;
+
+    assert!(matches!(
+        accounts
+            .create_builder()
+            .user_name("user")
+            .password("password")
+            .build(),
+        Err(nv_redfish::Error::InvalidAccount(_))
+    ));
+    assert!(matches!(
+        accounts
+            .create_builder()
+            .user_name("user")
+            .password("password")
+            .role("Operator")
+            .enabled(false)
+            .build(),
+        Err(nv_redfish::Error::InvalidAccount(_))
+    ));
+
+    Ok(())
+}
+
 #[test]
 async fn create_account_slot_defined_preserves_async_task() -> TestResult<()> {
     let (bmc, accounts_id, accounts) =
@@ -508,6 +649,149 @@ async fn update_account_preserves_task_and_empty_responses() -> TestResult<()> {
     Ok(())
 }
 
+fn task(location: &str, state: &str) -> JsonValue {
+    json!({
+        ODATA_ID: location,
+        ODATA_TYPE: "#Task.v1_4_3.Task",
+        "Id": "46",
+        "Name": "Task 46",
+        "TaskState": state,
+    })
+}
+
+#[test]
+async fn update_account_follows_task_to_completion() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let following = TaskFollowing::new(Sleep::new(|_| ready(()))).poll_interval(Duration::ZERO);
+    let account_service =
+        get_account_service_with(bmc.clone(), &root_id, "Contoso", json!({}), |root| {
+            root.follow_tasks(following)
+        })
+        .await?;
+    let accounts_id = format!("{}/Accounts", account_service.raw().odata_id());
+    let members = json!([slot_member(&accounts_id, 1, true, "user")]);
+    let account = get_account_collection(bmc.clone(), &account_service, members)
+        .await?
+        .all_accounts_data()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or("missing account")?;
+
+    let account_id = format!("{accounts_id}/1");
+    let update_req = ManagerAccountUpdate::builder()
+        .with_password("new-password".into())
+        .build();
+    let update_json = serde_json::to_value(&update_req)?;
+    let task_id = "/redfish/v1/TaskService/Tasks/46";
+
+    bmc.expect(Expect::update_task(
+        &account_id,
+        &update_json,
+        async_task(task_id, 0),
+    ));
+    bmc.expect(Expect::get(task_id, task(task_id, "Running")));
+    bmc.expect(Expect::get(task_id, task(task_id, "Completed")));
+    bmc.expect(Expect::get(
+        &account_id,
+        slot_member(&accounts_id, 1, true, "user"),
+    ));
+
+    let updated = into_entity(account.update(&update_req).await?);
+    assert_eq!(updated.raw().odata_id().to_string(), account_id);
+
+    bmc.expect(Expect::update_task(
+        &account_id,
+        &update_json,
+        async_task(task_id, 0),
+    ));
+    bmc.expect(Expect::get(task_id, task(task_id, "Exception")));
+
+    let Err(err) = account.update(&update_req).await else {
+        panic!("expected failed task");
+    };
+    assert_eq!(
+        err.to_string(),
+        "Task /redfish/v1/TaskService/Tasks/46 finished in state Exception"
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn create_account_follows_task_to_created_account() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let following = TaskFollowing::new(Sleep::new(|_| ready(()))).poll_interval(Duration::ZERO);
+    let account_service =
+        get_account_service_with(bmc.clone(), &root_id, "Contoso", json!({}), |root| {
+            root.follow_tasks(following)
+        })
+        .await?;
+    let accounts_id = format!("{}/Accounts", account_service.raw().odata_id());
+    let accounts = get_account_collection(bmc.clone(), &account_service, json!([])).await?;
+
+    // Created resource is reported in the completed task.
+    let create_req = create_request("user");
+    let create_json = serde_json::to_value(&create_req)?;
+    let task_id = "/redfish/v1/TaskService/Tasks/46";
+    let account_id = format!("{accounts_id}/2");
+    let mut completed = task(task_id, "Completed");
+    completed["Links"] = json!({ "CreatedResources": [{ ODATA_ID: &account_id }] });
+
+    bmc.expect(Expect::create_task(
+        &accounts_id,
+        &create_json,
+        async_task(task_id, 0),
+    ));
+    bmc.expect(Expect::get(task_id, task(task_id, "Running")));
+    bmc.expect(Expect::get(task_id, completed));
+    bmc.expect(Expect::get(
+        &account_id,
+        slot_member(&accounts_id, 2, true, "user"),
+    ));
+
+    let created = into_entity(accounts.create_account(create_req).await?);
+    assert_eq!(created.raw().odata_id().to_string(), account_id);
+
+    // Task monitor returns the created resource once the operation
+    // is finished.
+    let create_req = create_request("user");
+    let monitor_id = "/redfish/v1/TaskService/TaskMonitors/47";
+
+    bmc.expect(Expect::create_task(
+        &accounts_id,
+        &create_json,
+        async_task(monitor_id, 0),
+    ));
+    bmc.expect(Expect::get(
+        monitor_id,
+        slot_member(&accounts_id, 2, true, "user"),
+    ));
+    bmc.expect(Expect::get(
+        &account_id,
+        slot_member(&accounts_id, 2, true, "user"),
+    ));
+
+    let created = into_entity(accounts.create_account(create_req).await?);
+    assert_eq!(created.raw().odata_id().to_string(), account_id);
+
+    // Created resource is not reported.
+    let create_req = create_request("user");
+    bmc.expect(Expect::create_task(
+        &accounts_id,
+        &create_json,
+        async_task(task_id, 0),
+    ));
+    bmc.expect(Expect::get(task_id, task(task_id, "Completed")));
+
+    assert_empty(accounts.create_account(create_req).await?);
+    bmc.verify();
+
+    Ok(())
+}
+
 #[test]
 async fn delete_account_preserves_task_and_empty_responses() -> TestResult<()> {
     let (bmc, _, accounts) =
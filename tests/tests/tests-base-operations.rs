@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use nv_redfish_bmc_mock::Error as BmcError;
+use nv_redfish_core::merge_patch;
 use nv_redfish_core::Creatable;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::ModificationResponse;
@@ -22,22 +24,31 @@ use nv_redfish_core::RedfishSettings;
 use nv_redfish_core::Reference;
 use nv_redfish_core::ReferenceLeaf;
 use nv_redfish_core::Updatable;
+use nv_redfish_core::Validate as _;
+use nv_redfish_core::ValidationError;
 use nv_redfish_tests::base::expect_root;
 use nv_redfish_tests::base::expect_root_srv;
 use nv_redfish_tests::base::get_service_root;
 use nv_redfish_tests::base::nav_service_root;
 use nv_redfish_tests::base::redfish::service_root::ActionType;
+use nv_redfish_tests::base::redfish::service_root::CapabilityFlags;
+use nv_redfish_tests::base::redfish::service_root::DefaultValueLimits;
 use nv_redfish_tests::base::redfish::service_root::ReadOnlyComplexTypeUpdate;
 use nv_redfish_tests::base::redfish::service_root::RootSetOnlyComplexType;
 use nv_redfish_tests::base::redfish::service_root::ServiceRootUpdate;
+use nv_redfish_tests::base::redfish::service_root::ServiceRootVendor;
+use nv_redfish_tests::base::redfish::service_root::TestActionsService;
 use nv_redfish_tests::base::redfish::service_root::TestActionsServiceOemActions;
 use nv_redfish_tests::base::redfish::service_root::TestActionsServiceTestSerializationActionAction;
 use nv_redfish_tests::base::redfish::service_root::TestCollectionMemberCreate;
 use nv_redfish_tests::base::redfish::test_vendor::TestActionsServiceTestActionAction as VendorTestAction;
 use nv_redfish_tests::json_merge;
 use nv_redfish_tests::Bmc;
+use nv_redfish_tests::BodyMatcher;
 use nv_redfish_tests::Error;
 use nv_redfish_tests::Expect;
+use nv_redfish_tests::ResponseStatus;
+use nv_redfish_tests::TaskMonitor;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 use serde_json::json;
@@ -65,6 +76,37 @@ async fn get_service_root_test() -> Result<(), Error> {
     Ok(())
 }
 
+// Check that Bmc records properties of served payloads for the
+// schema coverage report.
+#[test]
+async fn served_properties_coverage_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        root_id.clone(),
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: "#ServiceRoot.v1_0_0.ServiceRoot",
+            "RedfishVersion": "1.0.0",
+            "ReadOnlyComplex": { "Required": "value" },
+        }),
+    ));
+    get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    let coverage = bmc.coverage();
+    let properties = coverage
+        .properties("ServiceRoot.ServiceRoot")
+        .expect("service root is recorded");
+    assert_eq!(
+        properties.iter().map(String::as_str).collect::<Vec<_>>(),
+        vec![
+            "ReadOnlyComplex",
+            "ReadOnlyComplex/Required",
+            "RedfishVersion"
+        ]
+    );
+    Ok(())
+}
+
 // Check that nullable optional property is represent by
 // Option<Option<T>> and implementation can distinguish `"field:
 // null"` from absense of `field`.
@@ -310,6 +352,8 @@ async fn update_property_test() -> Result<(), Error> {
                 read_only_complex: None,
                 rigid_array_values: None,
                 updatable_guid: Some(uuid_value),
+                updatable_count: None,
+                updatable_version: None,
                 write_only: None,
             },
         )
@@ -338,6 +382,8 @@ async fn update_property_test() -> Result<(), Error> {
                 read_only_complex: None,
                 rigid_array_values: None,
                 updatable_guid: None,
+                updatable_count: None,
+                updatable_version: None,
                 write_only: Some(value.clone()),
             },
         )
@@ -349,6 +395,141 @@ async fn update_property_test() -> Result<(), Error> {
     Ok(())
 }
 
+// Check that update computed from modified copy of writable
+// properties contains only changed properties.
+#[test]
+async fn update_diff_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let root_id = ODataId::service_root();
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: &data_type,
+        "RedfishVersion": "1.0.0",
+        "Updatable": "Value",
+        "UpdatableCount": 1,
+    });
+    bmc.expect(Expect::get(root_id.clone(), &root_json));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+
+    // Read-only properties are not part of the update.
+    let current =
+        merge_patch::writable::<ServiceRootUpdate>(&root_json).expect("writable properties");
+    assert_eq!(current.updatable.as_deref(), Some("Value"));
+    let mut modified = current.clone();
+    assert!(merge_patch::update_diff(&current, &modified)
+        .expect("valid update")
+        .is_none());
+
+    modified.updatable_count = Some(2);
+    let update = merge_patch::update_diff(&current, &modified)
+        .expect("valid update")
+        .ok_or(Error::ExpectedProperty("update"))?;
+    bmc.expect(Expect::update(
+        root_id.clone(),
+        json!({ "UpdatableCount": 2 }),
+        json_merge([&root_json, &json!({ "UpdatableCount": 2 })]),
+    ));
+    let response = service_root
+        .update(&bmc, &update)
+        .await
+        .map_err(Error::Bmc)?;
+    assert!(matches!(response, ModificationResponse::Entity(_)));
+
+    Ok(())
+}
+
+// Check that PATCH body is matched as JSON subset or by predicate
+// and that mismatch is reported.
+#[test]
+async fn patch_body_matcher_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let root_id = ODataId::service_root();
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: &data_type,
+        "RedfishVersion": "1.0.0",
+    });
+    bmc.expect(Expect::get(root_id.clone(), &root_json));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    let update = ServiceRootUpdate {
+        updatable: Some("Value".into()),
+        updatable_count: Some(2),
+        ..ServiceRootUpdate::default()
+    };
+
+    bmc.expect(
+        Expect::patch(
+            root_id.clone(),
+            BodyMatcher::subset(json!({ "UpdatableCount": 2 })),
+        )
+        .with_response(json_merge([&root_json, &json!({ "UpdatableCount": 2 })])),
+    );
+    let response = service_root
+        .update(&bmc, &update)
+        .await
+        .map_err(Error::Bmc)?;
+    assert!(matches!(response, ModificationResponse::Entity(_)));
+
+    bmc.expect(Expect::patch(
+        root_id.clone(),
+        BodyMatcher::predicate(|body| body["Updatable"] == "Value"),
+    ));
+    let response = service_root
+        .update(&bmc, &update)
+        .await
+        .map_err(Error::Bmc)?;
+    assert!(matches!(response, ModificationResponse::Empty));
+
+    bmc.expect(Expect::patch(
+        root_id.clone(),
+        BodyMatcher::exact(json!({ "UpdatableCount": 2 })),
+    ));
+    assert!(service_root.update(&bmc, &update).await.is_err());
+
+    Ok(())
+}
+
+// Check that update carries ETag of the served resource as
+// `If-Match` and that stale ETag is rejected.
+#[test]
+async fn update_if_match_test() -> Result<(), Error> {
+    let bmc = Bmc::default().with_if_match();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let root_id = ODataId::service_root();
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: &data_type,
+        "RedfishVersion": "1.0.0",
+    });
+    let update = ServiceRootUpdate {
+        updatable_count: Some(2),
+        ..ServiceRootUpdate::default()
+    };
+    bmc.expect(Expect::get(root_id.clone(), &root_json).with_etag("W/\"1\""));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+
+    bmc.expect(Expect::update_empty(
+        root_id.clone(),
+        json!({ "UpdatableCount": 2 }),
+    ));
+    service_root
+        .update(&bmc, &update)
+        .await
+        .map_err(Error::Bmc)?;
+
+    // Resource is changed after the first read.
+    bmc.expect(Expect::get(root_id.clone(), &root_json).with_etag("W/\"2\""));
+    get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    assert!(matches!(
+        service_root.update(&bmc, &update).await,
+        Err(BmcError::PreconditionFailed(..))
+    ));
+
+    Ok(())
+}
+
 // Check updatable for navigation property.
 #[test]
 async fn update_using_nav_property_test() -> Result<(), Error> {
@@ -375,6 +556,8 @@ async fn update_using_nav_property_test() -> Result<(), Error> {
                 read_only_complex: None,
                 rigid_array_values: None,
                 updatable_guid: None,
+                updatable_count: None,
+                updatable_version: None,
                 write_only: None,
             },
         )
@@ -423,6 +606,8 @@ async fn update_rigid_array_property_test() -> Result<(), Error> {
                 read_only_complex: None,
                 rigid_array_values: Some(updated_payload.clone()),
                 updatable_guid: None,
+                updatable_count: None,
+                updatable_version: None,
                 write_only: None,
             },
         )
@@ -447,6 +632,8 @@ async fn update_rigid_array_property_test() -> Result<(), Error> {
                 read_only_complex: None,
                 rigid_array_values: None,
                 updatable_guid: None,
+                updatable_count: None,
+                updatable_version: None,
                 write_only: None,
             },
         )
@@ -553,6 +740,105 @@ async fn create_collection_member_test() -> Result<(), Error> {
     Ok(())
 }
 
+// Check that POST expectation answers collection member creation and
+// action with the requested status.
+#[test]
+async fn post_status_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let collection_id = format!("{root_id}/TestCollection");
+    let member_id = format!("{collection_id}/1");
+    bmc.expect(expect_root_srv("TestCollection", &collection_id));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    bmc.expect(Expect::get(
+        &collection_id,
+        json!({
+            ODATA_ID: &collection_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestCollection",
+            "Members": [],
+        }),
+    ));
+    let collection = service_root
+        .test_collection
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("test_collection"))?
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+    let create = TestCollectionMemberCreate::builder(
+        "required value".into(),
+        ReadOnlyComplexTypeUpdate::builder()
+            .with_required("nested required value".into())
+            .build(),
+    )
+    .build();
+
+    bmc.expect(
+        Expect::post(
+            &collection_id,
+            BodyMatcher::subset(json!({ "RequiredOnCreate": "required value" })),
+        )
+        .with_status(ResponseStatus::created(&member_id))
+        .with_response(json!({
+            ODATA_ID: &member_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestCollectionMember",
+        })),
+    );
+    let ModificationResponse::Entity(member) =
+        collection.create(&bmc, &create).await.map_err(Error::Bmc)?
+    else {
+        return Err(Error::ExpectedProperty("member"));
+    };
+    assert_eq!(member.odata_id().to_string(), member_id);
+
+    bmc.expect(Expect::post(
+        &collection_id,
+        BodyMatcher::predicate(|_| true),
+    ));
+    assert!(matches!(
+        collection.create(&bmc, &create).await.map_err(Error::Bmc)?,
+        ModificationResponse::Empty
+    ));
+
+    let service_id = format!("{root_id}/TestActionsService");
+    let action_target = format!("{service_id}/Actions/ServiceRoot.TestAction");
+    let task = TaskMonitor::new("/redfish/v1/TaskService/Tasks/1").task();
+    bmc.expect(Expect::get(
+        &service_id,
+        json!({
+            ODATA_ID: &service_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestActionsService",
+            "Actions": {
+                "#ServiceRoot.TestAction": { "target": &action_target }
+            }
+        }),
+    ));
+    let service = NavProperty::<TestActionsService>::new_reference(service_id.into())
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+    bmc.expect(
+        Expect::post(
+            &action_target,
+            BodyMatcher::exact(json!({ "ActionType": "Option1" })),
+        )
+        .with_status(ResponseStatus::Accepted(task.clone())),
+    );
+    let response = service
+        .actions
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("actions"))?
+        .test_action(&bmc, Some(ActionType::Option1))
+        .await
+        .map_err(Error::Bmc)?;
+    assert!(matches!(
+        response,
+        ModificationResponse::Task(t) if t.location == task.location
+    ));
+    bmc.verify();
+    Ok(())
+}
+
 #[test]
 async fn create_struct_required_on_create_and_writable_fields_test() -> Result<(), Error> {
     let create = TestCollectionMemberCreate::builder(
@@ -662,6 +948,79 @@ async fn action_method_test() -> Result<(), Error> {
     Ok(())
 }
 
+// Check that action results are parsed into ReturnType of the action.
+#[test]
+async fn action_return_type_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let service_name = "TestActionsService";
+    let service_id = format!("{root_id}/{service_name}");
+    let service_data_type = format!("ServiceRoot.v1_0_0.{service_name}");
+    let result_target = format!("{service_id}/Actions/ServiceRoot.TestResultAction");
+    let entity_target = format!("{service_id}/Actions/ServiceRoot.TestEntityResultAction");
+    let entity_id = format!("{service_id}/Results/1");
+    bmc.expect(expect_root_srv(service_name, &service_id));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+
+    bmc.expect(Expect::get(
+        &service_id,
+        json!({
+            ODATA_ID: &service_id,
+            ODATA_TYPE: &service_data_type,
+            "Actions": {
+                "#ServiceRoot.TestResultAction": { "target": &result_target },
+                "#ServiceRoot.TestEntityResultAction": { "target": &entity_target },
+            }
+        }),
+    ));
+    let service = service_root
+        .test_actions_service
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("test_actions_service"))?
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+    let service_actions = service
+        .actions
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("actions"))?;
+
+    bmc.expect(Expect::action(
+        &result_target,
+        json!({}),
+        json!({ "Status": "Done", "Values": [1, 2] }),
+    ));
+    let ModificationResponse::Entity(result) = service_actions
+        .test_result_action(&bmc)
+        .await
+        .map_err(Error::Bmc)?
+    else {
+        return Err(Error::ExpectedProperty("result"));
+    };
+    assert_eq!(result.status, "Done");
+    assert_eq!(result.values, Some(vec![1, 2]));
+
+    bmc.expect(Expect::action(
+        &entity_target,
+        json!({}),
+        json!({
+            ODATA_ID: &entity_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestActionResultEntity",
+            "Value": "Created",
+        }),
+    ));
+    let ModificationResponse::Entity(entity) = service_actions
+        .test_entity_result_action(&bmc)
+        .await
+        .map_err(Error::Bmc)?
+    else {
+        return Err(Error::ExpectedProperty("entity"));
+    };
+    assert_eq!(entity.odata_id().to_string(), entity_id);
+    assert_eq!(entity.value, Some("Created".into()));
+    Ok(())
+}
+
 #[test]
 async fn action_parameter_serialization_test() -> Result<(), Error> {
     struct TestCase {
@@ -911,6 +1270,148 @@ async fn redfish_settings_absent_test() -> Result<(), Error> {
     Ok(())
 }
 
+// Auto-expanded navigation properties are deserialized inline
+#[test]
+async fn auto_expand_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let service_id = format!("{}/TestAutoExpandService", root_id);
+    bmc.expect(Expect::get(
+        root_id.clone(),
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: &data_type,
+            "TestAutoExpandService": { ODATA_ID: &service_id },
+        }),
+    ));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+
+    let item_id = format!("{}/Items/0", service_id);
+    bmc.expect(Expect::get(
+        &service_id,
+        json!({
+            ODATA_ID: &service_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestAutoExpandService",
+            "Items": [{
+                ODATA_ID: &item_id,
+                ODATA_TYPE: "ServiceRoot.v1_0_0.TestAutoExpandItem",
+                "Value": "V",
+            }],
+        }),
+    ));
+    let service = service_root
+        .test_auto_expand_service
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("test_auto_expand_service"))?
+        .get(&bmc)
+        .await
+        .map_err(Error::Bmc)?;
+    let items = service
+        .items
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("items"))?;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].odata_id().to_string(), item_id);
+    assert_eq!(items[0].value, Some("V".into()));
+    Ok(())
+}
+
+// Missing properties are filled from DefaultValue of the schema
+#[test]
+async fn default_value_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let service_id = format!("{}/TestDefaultValueService", root_id);
+    bmc.expect(Expect::get(
+        root_id.clone(),
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: &data_type,
+            "TestDefaultValueService": { ODATA_ID: &service_id },
+        }),
+    ));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    let nav = service_root
+        .test_default_value_service
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("test_default_value_service"))?;
+
+    bmc.expect(Expect::get(
+        &service_id,
+        json!({
+            ODATA_ID: &service_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestDefaultValueService",
+        }),
+    ));
+    let service = nav.get(&bmc).await.map_err(Error::Bmc)?;
+    assert!(service.enabled);
+    assert_eq!(service.count, 4);
+    assert_eq!(service.label, Some("none".into()));
+    assert_eq!(service.r#type, ActionType::Option2);
+    assert!(service.limits.is_none());
+
+    bmc.expect(Expect::get(
+        &service_id,
+        json!({
+            ODATA_ID: &service_id,
+            ODATA_TYPE: "ServiceRoot.v1_0_0.TestDefaultValueService",
+            "Enabled": false,
+            "Label": null,
+            "Limits": { "Maximum": 8 },
+        }),
+    ));
+    let service = nav.get(&bmc).await.map_err(Error::Bmc)?;
+    assert!(!service.enabled);
+    assert_eq!(service.label, None);
+    let limits = service
+        .limits
+        .as_ref()
+        .ok_or(Error::ExpectedProperty("limits"))?;
+    assert!((limits.ratio - 0.5).abs() < f64::EPSILON);
+    assert_eq!(limits.maximum, Some(8));
+    assert!(DefaultValueLimits::default().maximum.is_none());
+    Ok(())
+}
+
+// Check client-side validation of Validation.* annotations.
+#[test]
+async fn update_validation_test() -> Result<(), Error> {
+    let update = ServiceRootUpdate::builder()
+        .with_updatable_count(16)
+        .with_updatable_version("1.2.3".into());
+    assert_eq!(update.validate(), Ok(()));
+    assert_eq!(
+        ServiceRootUpdate::builder()
+            .with_updatable_count(0)
+            .validate(),
+        Err(ValidationError::Minimum {
+            property: "UpdatableCount",
+            minimum: 1
+        })
+    );
+    assert_eq!(
+        ServiceRootUpdate::builder()
+            .with_updatable_count(17)
+            .validate(),
+        Err(ValidationError::Maximum {
+            property: "UpdatableCount",
+            maximum: 16
+        })
+    );
+    assert!(matches!(
+        ServiceRootUpdate::builder()
+            .with_updatable_version("1.2".into())
+            .validate(),
+        Err(ValidationError::Pattern {
+            property: "UpdatableVersion",
+            ..
+        })
+    ));
+    Ok(())
+}
+
 // Excerpt view tests: verify inline excerpt copies and direct read
 #[test]
 async fn excerpt_views_test() -> Result<(), Error> {
@@ -951,11 +1452,13 @@ async fn excerpt_views_test() -> Result<(), Error> {
     });
     let basic = json!({
       "Always": "A",
-      "BasicProp": "B"
+      "BasicProp": "B",
+      "SharedProp": "S"
     });
     let details = json!({
       "Always": "A",
-      "DetailsProp": "D"
+      "DetailsProp": "D",
+      "SharedProp": "S"
     });
 
     bmc.expect(Expect::get(
@@ -987,6 +1490,14 @@ async fn excerpt_views_test() -> Result<(), Error> {
         ref_svc.excerpt_details.as_ref().unwrap().details_prop,
         Some("D".into())
     );
+    assert_eq!(
+        ref_svc.excerpt_basic.as_ref().unwrap().shared_prop,
+        Some("S".into())
+    );
+    assert_eq!(
+        ref_svc.excerpt_details.as_ref().unwrap().shared_prop,
+        Some("S".into())
+    );
 
     // Fetch ExcerptEntity directly and verify full entity contains Hidden
     let tgt_id = format!("{}/ExcerptEntity", root_id);
@@ -1030,6 +1541,117 @@ async fn enum_unknown_value_falls_back_to_unsupported_value() {
     assert_eq!(serialized, json!("UnsupportedValue"));
 }
 
+// Check that `IsFlags` enums hold combinations of members encoded as
+// comma-separated member names.
+#[test]
+async fn flags_enum_combination_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    bmc.expect(Expect::get(
+        root_id.clone(),
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: &data_type,
+            "Capabilities": "Read,Execute",
+        }),
+    ));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    let capabilities = service_root
+        .capabilities
+        .expect("capabilities must be deserialized");
+    assert_eq!(
+        capabilities,
+        CapabilityFlags::READ | CapabilityFlags::EXECUTE
+    );
+    assert!(capabilities.contains(CapabilityFlags::READ));
+    assert!(!capabilities.contains(CapabilityFlags::WRITE));
+
+    let with_unknown: CapabilityFlags = serde_json::from_value(json!("Write, FutureMember"))
+        .expect("unknown members must be ignored");
+    assert_eq!(with_unknown, CapabilityFlags::WRITE);
+
+    assert_eq!(
+        serde_json::to_value(capabilities).expect("flags must serialize"),
+        json!("Read,Execute")
+    );
+    assert_eq!(
+        serde_json::to_value(CapabilityFlags::empty()).expect("flags must serialize"),
+        json!("None")
+    );
+    Ok(())
+}
+
+// Check that string properties with `Redfish.Enumeration` are typed
+// enums that keep unlisted values.
+#[test]
+async fn string_enumeration_property_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: &data_type,
+    });
+    bmc.expect(Expect::get(
+        root_id.clone(),
+        json_merge([&root_json, &json!({ "Vendor": "Contoso" })]),
+    ));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    assert_eq!(service_root.vendor, Some(ServiceRootVendor::Contoso));
+
+    bmc.expect(Expect::get(
+        root_id.clone(),
+        json_merge([&root_json, &json!({ "Vendor": "Acme" })]),
+    ));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    assert_eq!(
+        service_root.vendor,
+        Some(ServiceRootVendor::Other("Acme".into()))
+    );
+    assert_eq!(
+        serde_json::to_value(ServiceRootVendor::Other("Acme".into())).expect("enum must serialize"),
+        json!("Acme")
+    );
+    Ok(())
+}
+
+// Check that properties of open types that are not declared are
+// collected into dynamic properties as JSON values.
+#[test]
+async fn open_type_dynamic_properties_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let root_id = ODataId::service_root();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    bmc.expect(Expect::get(
+        root_id.clone(),
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: &data_type,
+            "OpenAttributes": {
+                "Declared": "Value",
+                "Count": 2,
+                "Modes": ["A", "B"],
+                "Nested": { "Enabled": true },
+                "Unset": null,
+            },
+        }),
+    ));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+    let attrs = service_root
+        .open_attributes
+        .as_ref()
+        .expect("open attributes must be deserialized");
+    assert_eq!(attrs.declared, Some(Some("Value".into())));
+    let map = &attrs.dynamic_properties;
+    assert_eq!(map.len(), 4);
+    assert_eq!(map.get("Count"), Some(&Some(json!(2))));
+    assert_eq!(map.get("Modes"), Some(&Some(json!(["A", "B"]))));
+    assert_eq!(map.get("Nested"), Some(&Some(json!({ "Enabled": true }))));
+    assert_eq!(map.get("Unset"), Some(&None));
+    Ok(())
+}
+
 // Check that standalone complex types matched by root set patterns are generated.
 #[test]
 async fn root_set_complex_type_is_generated_test() {